@@ -0,0 +1,44 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant}
+};
+use simmer::{PorterStemmer, Stem};
+
+// Constant
+const VOCABULARY: &str = include_str!("../tests/data/voc.txt");
+const ITERATIONS: u32 = 10;
+
+/// Run a benchmark and print the average duration of an iteration
+///
+/// # Arguments
+///
+/// * `name` - &str
+/// * `process` - F
+fn bench<F: FnMut()>(name: &str, mut process: F) -> Duration {
+    // warm up
+    process();
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        process();
+    }
+
+    let elapsed = start.elapsed() / ITERATIONS;
+    println!("{name:<32} {elapsed:>12.3?}");
+
+    elapsed
+}
+
+fn main() {
+    let words: Vec<&str> = VOCABULARY.lines().collect();
+    println!("{} words, average of {ITERATIONS} iterations", words.len());
+
+    // the whole stemmer is measured as the measure is computed by each step
+    let elapsed = bench("stem vocabulary", || {
+        for word in &words {
+            black_box(PorterStemmer.stem(word).unwrap());
+        }
+    });
+
+    println!("{:.2} M words/s", words.len() as f64 / elapsed.as_secs_f64() / 1e6);
+}
@@ -0,0 +1,42 @@
+use std::{hint::black_box, time::Instant};
+use simmer::{PorterStemmer, Stem, Stemmer};
+
+// Constant
+const VOCABULARY: &str = include_str!("../tests/data/voc.txt");
+const ITERATIONS: u32 = 20;
+
+/// Stem the words with a function and print the throughput
+///
+/// # Arguments
+///
+/// * `name` - &str
+/// * `words` - &[&str]
+/// * `stem` - F
+fn bench<F: Fn(&str) -> String>(name: &str, words: &[&str], stem: F) {
+    let bytes: usize = words.iter().map(|word| word.len()).sum();
+    // warm up
+    words.iter().for_each(|word| { black_box(stem(word)); });
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        for word in words {
+            black_box(stem(word));
+        }
+    }
+
+    let elapsed = start.elapsed().as_secs_f64() / ITERATIONS as f64;
+    println!(
+        "{name:<28} {:>8.2} M words/s {:>8.2} MB/s",
+        words.len() as f64 / elapsed / 1e6,
+        bytes as f64 / elapsed / 1e6
+    );
+}
+
+fn main() {
+    let words: Vec<&str> = VOCABULARY.lines().collect();
+    println!("{} words, average of {ITERATIONS} iterations", words.len());
+
+    bench("ascii (fast path)", &words, |word| PorterStemmer.stem(word).unwrap());
+    // the same words are forced through the general path
+    bench("general path", &words, |word| Stemmer::new(&word.to_lowercase()).unwrap().stem().unwrap());
+}
@@ -0,0 +1,78 @@
+use alloc::{boxed::Box, vec::Vec};
+use crate::{
+    filter::{CharFilter, TokenFilter},
+    stemmer::Stem,
+    tokenizer::{Tokenizer, WhitespaceTokenizer}
+};
+use super::Analyzer;
+
+/// Compose the char filters, the tokenizer, the token filters and the stemmer of an Analyzer
+pub struct AnalyzerBuilder {
+    char_filters: Vec<Box<dyn CharFilter>>,
+    tokenizer: Box<dyn Tokenizer>,
+    token_filters: Vec<Box<dyn TokenFilter>>,
+    stemmer: Option<Box<dyn Stem>>
+}
+
+impl Default for AnalyzerBuilder {
+    fn default() -> Self {
+        AnalyzerBuilder {
+            char_filters: Vec::new(),
+            tokenizer: Box::new(WhitespaceTokenizer),
+            token_filters: Vec::new(),
+            stemmer: None
+        }
+    }
+}
+
+impl AnalyzerBuilder {
+    /// Add a char filter. Char filters are applied in the order they're added
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - F
+    pub fn char_filter<F: CharFilter + 'static>(mut self, filter: F) -> Self {
+        self.char_filters.push(Box::new(filter));
+        self
+    }
+
+    /// Set the tokenizer. Default to the WhitespaceTokenizer
+    ///
+    /// # Arguments
+    ///
+    /// * `tokenizer` - T
+    pub fn tokenizer<T: Tokenizer + 'static>(mut self, tokenizer: T) -> Self {
+        self.tokenizer = Box::new(tokenizer);
+        self
+    }
+
+    /// Add a token filter. Token filters are applied in the order they're added
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - F
+    pub fn token_filter<F: TokenFilter + 'static>(mut self, filter: F) -> Self {
+        self.token_filters.push(Box::new(filter));
+        self
+    }
+
+    /// Set the stemmer applied on each token once every token filters has been applied
+    ///
+    /// # Arguments
+    ///
+    /// * `stemmer` - S
+    pub fn stemmer<S: Stem + 'static>(mut self, stemmer: S) -> Self {
+        self.stemmer = Some(Box::new(stemmer));
+        self
+    }
+
+    /// Build the Analyzer
+    pub fn build(self) -> Analyzer {
+        Analyzer {
+            char_filters: self.char_filters,
+            tokenizer: self.tokenizer,
+            token_filters: self.token_filters,
+            stemmer: self.stemmer
+        }
+    }
+}
@@ -0,0 +1,309 @@
+mod builder;
+mod report;
+
+use alloc::{boxed::Box, string::{String, ToString}, vec::Vec};
+use crate::{
+    error::SimmerError,
+    filter::{CharFilter, TokenFilter},
+    stemmer::Stem,
+    tokenizer::{Token, Tokenizer}
+};
+
+pub use self::builder::AnalyzerBuilder;
+pub use self::report::{Analysis, ErrorMode, ErrorSummary, TokenError};
+
+/// A reusable analysis pipeline which is composed of
+///
+/// - char filters which are applied on the text
+/// - a tokenizer which split the filtered text into tokens
+/// - token filters which are applied on the tokens in order
+/// - a stemmer which set the stem of each word which isn't a keyword
+pub struct Analyzer {
+    char_filters: Vec<Box<dyn CharFilter>>,
+    tokenizer: Box<dyn Tokenizer>,
+    token_filters: Vec<Box<dyn TokenFilter>>,
+    stemmer: Option<Box<dyn Stem>>
+}
+
+/// Text produced by the char filters with the offsets of the original characters
+struct FilteredText {
+    text: String,
+    // byte offsets in the original text of the character which produced each byte of the filtered text
+    starts: Vec<usize>,
+    ends: Vec<usize>
+}
+
+impl FilteredText {
+    /// Convert the span of the filtered text to a span of the original text
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - usize
+    /// * `end` - usize
+    fn original_span(&self, start: usize, end: usize) -> (usize, usize) {
+        let original_start = self.starts.get(start).copied().unwrap_or_else(|| self.ends.last().copied().unwrap_or(0));
+        if end <= start {
+            return (original_start, original_start);
+        }
+
+        (original_start, self.ends[end - 1])
+    }
+}
+
+impl Analyzer {
+    /// Create an AnalyzerBuilder
+    pub fn builder() -> AnalyzerBuilder {
+        AnalyzerBuilder::default()
+    }
+
+    /// Apply the char filters on each character of the text
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - &str
+    fn apply_char_filters(&self, text: &str) -> FilteredText {
+        let mut filtered = FilteredText {
+            text: String::with_capacity(text.len()),
+            starts: Vec::with_capacity(text.len()),
+            ends: Vec::with_capacity(text.len())
+        };
+
+        for (idx, c) in text.char_indices() {
+            let mut current = c.to_string();
+            for char_filter in &self.char_filters {
+                let mut next = String::new();
+                current.chars().for_each(|ch| char_filter.filter(ch, &mut next));
+                current = next;
+            }
+
+            filtered.text.push_str(&current);
+            for _ in 0..current.len() {
+                filtered.starts.push(idx);
+                filtered.ends.push(idx + c.len_utf8());
+            }
+        }
+
+        filtered
+    }
+
+    /// Analyze a text and return the list of tokens. Offsets of each token refer to the original text
+    /// The analysis stop at the first token which can't be stemmed
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - &str
+    pub fn analyze(&self, text: &str) -> Result<Vec<Token>, SimmerError> {
+        let analysis = self.analyze_with(text, ErrorMode::Strict)?;
+
+        Ok(analysis.tokens)
+    }
+
+    /// Analyze a text and handle the tokens which can't be stemmed depending on the ErrorMode
+    /// An error is only returned with the ErrorMode::Strict
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - &str
+    /// * `mode` - ErrorMode
+    pub fn analyze_with(&self, text: &str, mode: ErrorMode) -> Result<Analysis, SimmerError> {
+        let mut tokens = self.tokenize(text);
+        for token_filter in &self.token_filters {
+            tokens = token_filter.filter(tokens);
+        }
+
+        self.stem_tokens(tokens, mode)
+    }
+
+    /// Analyze a chunk of a larger text and get the position which the token following the chunk would have
+    /// once the filters have been applied. The position can't be deduced from the last token as the filters
+    /// may remove, insert or renumber the tokens which is why each filter moves it
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - &str
+    #[cfg(feature = "std")]
+    pub(crate) fn analyze_chunk(&self, text: &str) -> Result<(Vec<Token>, usize), SimmerError> {
+        let mut tokens = self.tokenize(text);
+        let mut position = tokens.last().map_or(0, |t| t.position + 1);
+
+        for token_filter in &self.token_filters {
+            (tokens, position) = token_filter.filter_chunk(tokens, position);
+        }
+
+        let analysis = self.stem_tokens(tokens, ErrorMode::Strict)?;
+
+        Ok((analysis.tokens, position))
+    }
+
+    /// Apply the char filters and split the text into tokens whose offsets refer to the original text
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - &str
+    fn tokenize(&self, text: &str) -> Vec<Token> {
+        match self.char_filters.is_empty() {
+            true => self.tokenizer.tokenize(text),
+            false => {
+                let filtered = self.apply_char_filters(text);
+                let mut tokens = self.tokenizer.tokenize(&filtered.text);
+                for token in tokens.iter_mut() {
+                    (token.start, token.end) = filtered.original_span(token.start, token.end);
+                    token.surface = text[token.start..token.end].to_string();
+                }
+
+                tokens
+            }
+        }
+    }
+
+    /// Stem the words of the filtered tokens and handle the tokens which can't be stemmed depending on the ErrorMode
+    ///
+    /// # Arguments
+    ///
+    /// * `tokens` - Vec<Token>
+    /// * `mode` - ErrorMode
+    fn stem_tokens(&self, tokens: Vec<Token>, mode: ErrorMode) -> Result<Analysis, SimmerError> {
+        let Some(stemmer) = &self.stemmer else {
+            return Ok(Analysis { tokens, errors: Vec::new() });
+        };
+
+        let mut analysis = Analysis {
+            tokens: Vec::with_capacity(tokens.len()),
+            errors: Vec::new()
+        };
+
+        for mut token in tokens {
+            if token.keyword || token.skipped || !token.is_word() {
+                analysis.tokens.push(token);
+                continue;
+            }
+
+            match (stemmer.stem(&token.text), mode) {
+                (Ok(stem), _) => {
+                    token.stem = Some(stem);
+                    analysis.tokens.push(token);
+                },
+                (Err(err), ErrorMode::Strict) => return Err(err),
+                (Err(err), ErrorMode::PassThrough) => {
+                    analysis.errors.push(TokenError::new(&token, err));
+                    analysis.tokens.push(token);
+                },
+                (Err(err), ErrorMode::Collect) => analysis.errors.push(TokenError::new(&token, err))
+            }
+        }
+
+        Ok(analysis)
+    }
+
+    /// Analyze a text and return the term of each token
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - &str
+    pub fn terms(&self, text: &str) -> Result<Vec<String>, SimmerError> {
+        let terms = self.analyze(text)?
+            .iter()
+            .map(|token| token.term().to_string())
+            .collect();
+
+        Ok(terms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        filter::{AsciiPunctuationFilter, LowercaseFilter, MinLengthFilter, StemOverrideFilter, StopWordFilter},
+        stemmer::PorterStemmer,
+        testing::FailingStemmer
+    };
+
+    fn is_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn expect_analyzer_to_be_send_sync() {
+        is_send_sync::<Analyzer>();
+    }
+
+    #[test]
+    fn expect_to_analyze_like_stem_sentence() {
+        let analyzer = Analyzer::builder()
+            .char_filter(AsciiPunctuationFilter)
+            .token_filter(LowercaseFilter)
+            .stemmer(PorterStemmer)
+            .build();
+
+        let terms = analyzer.terms("His eyes were dancing with humor.").unwrap();
+
+        assert_eq!(terms.join(" "), "hi eye were danc with humor");
+    }
+
+    #[test]
+    fn expect_to_keep_original_offsets_with_char_filters() {
+        let text = "Alex, was an excellent dancer.";
+        let analyzer = Analyzer::builder()
+            .char_filter(AsciiPunctuationFilter)
+            .build();
+
+        let tokens = analyzer.analyze(text).unwrap();
+
+        assert_eq!(tokens[0].text, "Alex");
+        assert_eq!(tokens[0].surface, "Alex");
+        assert_eq!(&text[tokens[0].start..tokens[0].end], "Alex");
+        assert_eq!(&text[tokens[4].start..tokens[4].end], "dancer");
+    }
+
+    #[test]
+    fn expect_to_compose_token_filters() {
+        let analyzer = Analyzer::builder()
+            .token_filter(LowercaseFilter)
+            .token_filter(StopWordFilter::new(["was", "an"]))
+            .token_filter(MinLengthFilter::new(2))
+            .token_filter(StemOverrideFilter::new([("alex", "alex")]))
+            .stemmer(PorterStemmer)
+            .build();
+
+        let tokens = analyzer.analyze("Alex was an excellent dancer x").unwrap();
+        let terms: Vec<&str> = tokens.iter().map(|t| t.term()).collect();
+
+        assert_eq!(terms, vec!["alex", "excel", "dancer"]);
+        assert_eq!(tokens[1].text, "excellent");
+        assert_eq!(tokens[1].position, 3);
+    }
+
+    #[test]
+    fn expect_strict_mode_to_fail() {
+        let analyzer = Analyzer::builder().stemmer(FailingStemmer).build();
+
+        assert!(analyzer.analyze("dancing with humor").is_err());
+        assert!(analyzer.analyze_with("dancing with humor", ErrorMode::Strict).is_err());
+    }
+
+    #[test]
+    fn expect_lenient_modes_to_keep_going() {
+        let analyzer = Analyzer::builder().stemmer(FailingStemmer).build();
+
+        let passed = analyzer.analyze_with("humor and dancing", ErrorMode::PassThrough).unwrap();
+        let terms: Vec<&str> = passed.tokens.iter().map(|t| t.term()).collect();
+        assert_eq!(terms, vec!["humor", "and", "danc"]);
+        assert_eq!(passed.tokens[0].stem, None);
+        assert_eq!((passed.tokens[0].surface.as_str(), passed.tokens[0].text.as_str()), ("humor", "humor"));
+        assert_eq!(passed.errors.len(), 1);
+        assert_eq!(passed.errors[0].error, SimmerError::Character);
+
+        let collected = analyzer.analyze_with("humor and dancing", ErrorMode::Collect).unwrap();
+        let terms: Vec<&str> = collected.tokens.iter().map(|t| t.term()).collect();
+        assert_eq!(terms, vec!["and", "danc"]);
+        assert_eq!(collected.tokens[0].position, 1);
+        assert_eq!(collected.errors.len(), 1);
+        assert_eq!(collected.errors[0].text, "humor");
+        assert_eq!(collected.errors[0].error, SimmerError::Character);
+        assert_eq!((collected.errors[0].start, collected.errors[0].end, collected.errors[0].position), (0, 5, 0));
+
+        let summary = collected.summary();
+        assert_eq!(summary.processed, 3);
+        assert_eq!(summary.failed, 1);
+        assert_eq!(summary.reasons, vec![(SimmerError::Character, 1)]);
+    }
+}
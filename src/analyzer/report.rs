@@ -0,0 +1,111 @@
+use alloc::{string::String, vec::Vec};
+use crate::{
+    error::SimmerError,
+    tokenizer::Token
+};
+
+/// How the analysis behave when the stemmer fail on a token
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorMode {
+    /// Stop the analysis and return the error of the first token which failed
+    #[default]
+    Strict,
+    /// Keep the tokens which failed unchanged in the output and record their errors
+    PassThrough,
+    /// Remove the tokens which failed from the output and record their errors
+    Collect
+}
+
+/// Error of the stemmer on a token
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenError {
+    pub text: String,
+    pub start: usize,
+    pub end: usize,
+    pub position: usize,
+    pub error: SimmerError
+}
+
+/// Result of a lenient analysis
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Analysis {
+    pub tokens: Vec<Token>,
+    pub errors: Vec<TokenError>
+}
+
+/// Summary of the errors of an analysis
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ErrorSummary {
+    /// Number of tokens which have been processed by the stemmer
+    pub processed: usize,
+    /// Number of tokens which failed
+    pub failed: usize,
+    /// Number of tokens which failed for each error
+    pub reasons: Vec<(SimmerError, usize)>
+}
+
+impl TokenError {
+    /// Create a TokenError from the token which failed
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - &Token
+    /// * `error` - SimmerError
+    pub(crate) fn new(token: &Token, error: SimmerError) -> TokenError {
+        TokenError {
+            text: token.text.clone(),
+            start: token.start,
+            end: token.end,
+            position: token.position,
+            error
+        }
+    }
+}
+
+impl Analysis {
+    /// Summarize the errors of the analysis
+    pub fn summary(&self) -> ErrorSummary {
+        let mut reasons: Vec<(SimmerError, usize)> = Vec::new();
+        for token_error in &self.errors {
+            match reasons.iter_mut().find(|(error, _)| *error == token_error.error) {
+                Some((_, count)) => *count += 1,
+                None => reasons.push((token_error.error.clone(), 1))
+            }
+        }
+
+        let failed = self.errors.len();
+        let processed = failed + self.tokens.iter().filter(|t| t.stem.is_some() && !t.keyword).count();
+
+        ErrorSummary {
+            processed,
+            failed,
+            reasons
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_to_summarize_errors() {
+        let mut stemmed = Token::new("dancing", 0, 7, 0);
+        stemmed.stem = Some("danc".to_string());
+        let failed = Token::new("humor", 8, 13, 1);
+
+        let analysis = Analysis {
+            tokens: vec![stemmed],
+            errors: vec![
+                TokenError::new(&failed, SimmerError::Character),
+                TokenError::new(&failed, SimmerError::Character)
+            ]
+        };
+
+        let summary = analysis.summary();
+
+        assert_eq!(summary.processed, 3);
+        assert_eq!(summary.failed, 2);
+        assert_eq!(summary.reasons, vec![(SimmerError::Character, 2)]);
+    }
+}
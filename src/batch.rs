@@ -0,0 +1,111 @@
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use crate::{
+    analyzer::Analyzer,
+    error::SimmerError,
+    stemmer::{PorterStemmer, Stem},
+    tokenizer::Token
+};
+
+/// Apply a function on each item and keep the order of the items. The items are processed in parallel
+/// with rayon when the rayon feature is enabled and sequentially otherwise
+///
+/// # Arguments
+///
+/// * `items` - &[T]
+/// * `process` - F
+fn map_ordered<T, R, F>(items: &[T], process: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync + Send
+{
+    #[cfg(feature = "rayon")]
+    {
+        items.par_iter().map(process).collect()
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    {
+        items.iter().map(process).collect()
+    }
+}
+
+/// Stem a list of words with the porter stemmer. The result of each word is at the index of the word
+///
+/// # Arguments
+///
+/// * `words` - `&[W]`
+pub fn stem_words<W: AsRef<str> + Sync>(words: &[W]) -> Vec<Result<String, SimmerError>> {
+    stem_words_with(&PorterStemmer, words)
+}
+
+/// Stem a list of words with a stemmer. The result of each word is at the index of the word
+///
+/// # Arguments
+///
+/// * `stemmer` - &S
+/// * `words` - `&[W]`
+pub fn stem_words_with<S, W>(stemmer: &S, words: &[W]) -> Vec<Result<String, SimmerError>>
+where
+    S: Stem + ?Sized,
+    W: AsRef<str> + Sync
+{
+    map_ordered(words, |word| stemmer.stem(word.as_ref()))
+}
+
+/// Stem a list of documents the same way as `stem_sentence`. The result of each document is at the index of the document
+///
+/// # Arguments
+///
+/// * `documents` - `&[D]`
+pub fn stem_documents<D: AsRef<str> + Sync>(documents: &[D]) -> Vec<Result<Vec<String>, SimmerError>> {
+    map_ordered(documents, |document| crate::stem_sentence(document.as_ref()))
+}
+
+/// Analyze a list of documents with an analyzer. The result of each document is at the index of the document
+///
+/// # Arguments
+///
+/// * `analyzer` - &Analyzer
+/// * `documents` - `&[D]`
+pub fn analyze_documents<D: AsRef<str> + Sync>(analyzer: &Analyzer, documents: &[D]) -> Vec<Result<Vec<Token>, SimmerError>> {
+    map_ordered(documents, |document| analyzer.analyze(document.as_ref()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::FailingStemmer;
+
+    #[test]
+    fn expect_to_keep_order_of_words() {
+        let words: Vec<String> = (0..1000).map(|idx| format!("dancing{idx}s")).collect();
+        let stems = stem_words(&words);
+
+        for (word, stem) in words.iter().zip(stems) {
+            assert_eq!(stem.unwrap(), PorterStemmer.stem(word).unwrap());
+        }
+    }
+
+    #[test]
+    fn expect_to_report_errors_per_input() {
+        let stems = stem_words_with(&FailingStemmer, &["dancing", "humor", "eyes"]);
+
+        assert_eq!(stems, vec![Ok("danc".to_string()), Err(SimmerError::Character), Ok("eye".to_string())]);
+    }
+
+    #[test]
+    fn expect_to_stem_documents() {
+        let documents = ["His eyes were dancing with humor.", "", "Alex was an excellent dancer"];
+        let stems = stem_documents(&documents);
+
+        assert_eq!(stems.len(), 3);
+        assert_eq!(stems[0].as_ref().unwrap().join(" "), "hi eye were danc with humor");
+        assert!(stems[1].as_ref().unwrap().is_empty());
+
+        let analyzed = analyze_documents(&crate::build_sentence_analyzer(), &documents);
+        assert_eq!(analyzed[2].as_ref().unwrap()[4].term(), "dancer");
+    }
+}
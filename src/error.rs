@@ -1,14 +1,33 @@
-#[derive(Debug)]
+use alloc::string::String;
+#[cfg(feature = "std")]
+use alloc::string::ToString;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum SimmerError {
-    Character
+    Character,
+    Io(String),
+    Utf8(String),
+    WordTooLong(usize)
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for SimmerError {}
 
-impl std::fmt::Display for SimmerError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for SimmerError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
-            SimmerError::Character => write!(f, "Unable to get the current character while creating the CVC tree")
+            SimmerError::Character => write!(f, "Unable to get the current character while creating the CVC tree"),
+            SimmerError::Io(reason) => write!(f, "Unable to read the input: {reason}"),
+            SimmerError::Utf8(location) => write!(f, "Unable to decode the input as utf8 at the {location}"),
+            SimmerError::WordTooLong(offset) => write!(f, "The word at the byte offset {offset} is too long to be streamed")
         }
     }
 }
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for SimmerError {
+    fn from(err: std::io::Error) -> Self {
+        SimmerError::Io(err.to_string())
+    }
+}
@@ -0,0 +1,207 @@
+use alloc::{format, string::{String, ToString}, vec::Vec};
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Steps of the porter stemmer algorithm
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Step {
+    OneA,
+    OneB,
+    OneC,
+    Two,
+    Three,
+    Four,
+    Five
+}
+
+/// Kind of a run of characters in the `[C](VC)^m[V]` decomposition of a word
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SegmentKind {
+    Consonent,
+    Vowel
+}
+
+/// A run of consonents or vowels
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Segment {
+    pub kind: SegmentKind,
+    pub text: String
+}
+
+/// A measure computed by the stemmer while processing a step
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Measure {
+    /// Word on which the measure has been computed. This is the last word parsed by the stemmer
+    pub word: String,
+    pub measure: i32
+}
+
+/// A rule which matched the word and whether it has been applied
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RuleDecision {
+    /// Condition of the rule such as (m>0) or (*v*). Empty when the rule is unconditional
+    pub condition: String,
+    pub suffix: String,
+    pub replacement: String,
+    pub applied: bool,
+    /// Measure checked by the condition of the rule if any
+    pub measure: Option<i32>,
+    /// Details on why the rule has been applied or rejected
+    pub reason: String
+}
+
+/// Trace of a single step of the algorithm
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StepTrace {
+    pub step: Step,
+    /// Word before the step
+    pub input: String,
+    /// Measures computed during the step in the order they've been computed
+    pub measures: Vec<Measure>,
+    /// Rules which matched the word during the step
+    pub rules: Vec<RuleDecision>,
+    /// Word after the step
+    pub output: String
+}
+
+/// Explanation of how the porter stemmer processed a word
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Explanation {
+    pub word: String,
+    /// `[C](VC)^m[V]` decomposition of the word
+    pub segments: Vec<Segment>,
+    /// Measure of the word
+    pub measure: i32,
+    pub steps: Vec<StepTrace>,
+    pub stem: String
+}
+
+impl StepTrace {
+    /// Create a new StepTrace with the word before the step
+    ///
+    /// # Arguments
+    ///
+    /// * `step` - Step
+    /// * `input` - &str
+    pub(crate) fn new(step: Step, input: &str) -> StepTrace {
+        StepTrace {
+            step,
+            input: input.to_string(),
+            measures: Vec::new(),
+            rules: Vec::new(),
+            output: input.to_string()
+        }
+    }
+}
+
+impl Explanation {
+    /// Format the segments with the `[C](VC)^m[V]` notation. i.e: `trouble` -> `[tr](ou bl)[e]`
+    pub fn form(&self) -> String {
+        let mut form = String::new();
+        let mut segments = self.segments.iter().peekable();
+
+        if let Some(first) = segments.next_if(|s| s.kind == SegmentKind::Consonent) {
+            form.push_str(&format!("[{}]", first.text));
+        }
+
+        while let Some(segment) = segments.next() {
+            match segments.next() {
+                Some(consonent) => form.push_str(&format!("({} {})", segment.text, consonent.text)),
+                None => form.push_str(&format!("[{}]", segment.text))
+            }
+        }
+
+        form
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Step::OneA => "1a",
+            Step::OneB => "1b",
+            Step::OneC => "1c",
+            Step::Two => "2",
+            Step::Three => "3",
+            Step::Four => "4",
+            Step::Five => "5"
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+impl fmt::Display for RuleDecision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rule: Vec<&str> = [self.condition.as_str(), self.suffix.as_str(), "->", self.replacement.as_str()]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .collect();
+
+        let status = if self.applied { "applied" } else { "rejected" };
+        write!(f, "{}: {status}", rule.join(" "))?;
+
+        let details: Vec<String> = self.measure
+            .map(|m| format!("m={m}"))
+            .into_iter()
+            .chain((!self.reason.is_empty()).then(|| self.reason.clone()))
+            .collect();
+
+        if !details.is_empty() {
+            write!(f, " ({})", details.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} {} m={}", self.word, self.form(), self.measure)?;
+
+        for step in &self.steps {
+            writeln!(f, "step {}: {} -> {}", step.step, step.input, step.output)?;
+            for measure in &step.measures {
+                writeln!(f, "    m({})={}", measure.word, measure.measure)?;
+            }
+
+            for rule in &step.rules {
+                writeln!(f, "    {rule}")?;
+            }
+        }
+
+        write!(f, "stem: {}", self.stem)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_to_format_segments() {
+        let explanation = Explanation {
+            word: "trouble".to_string(),
+            segments: vec![
+                Segment { kind: SegmentKind::Consonent, text: "tr".to_string() },
+                Segment { kind: SegmentKind::Vowel, text: "ou".to_string() },
+                Segment { kind: SegmentKind::Consonent, text: "bl".to_string() },
+                Segment { kind: SegmentKind::Vowel, text: "e".to_string() }
+            ],
+            measure: 1,
+            steps: Vec::new(),
+            stem: "troubl".to_string()
+        };
+
+        assert_eq!(explanation.form(), "[tr](ou bl)[e]");
+    }
+}
@@ -0,0 +1,48 @@
+use alloc::vec::Vec;
+use crate::{stemmer::is_acronym, tokenizer::Token};
+use super::TokenFilter;
+
+/// Mark the acronyms as keywords so that they aren't stemmed. i.e: US isn't stemmed to u and AIDS to aid
+/// The acronyms are detected on the surface of the token so the filter can be placed after the LowercaseFilter
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AcronymFilter;
+
+impl TokenFilter for AcronymFilter {
+    fn filter(&self, tokens: Vec<Token>) -> Vec<Token> {
+        tokens
+            .into_iter()
+            .map(|mut token| {
+                if token.is_word() && is_acronym(&token.surface) {
+                    token.keyword = true;
+                }
+
+                token
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        analyzer::Analyzer,
+        filter::LowercaseFilter,
+        stemmer::PorterStemmer
+    };
+
+    #[test]
+    fn expect_to_keep_acronyms_unstemmed() {
+        let analyzer = Analyzer::builder()
+            .token_filter(LowercaseFilter)
+            .token_filter(AcronymFilter)
+            .stemmer(PorterStemmer)
+            .build();
+
+        let tokens = analyzer.analyze("AIDS research in the US Excellent").unwrap();
+        let terms: Vec<String> = tokens.iter().map(|t| t.cased_term()).collect();
+
+        assert_eq!(terms, vec!["AIDS", "research", "in", "the", "US", "Excel"]);
+        assert_eq!(tokens[0].term(), "aids");
+    }
+}
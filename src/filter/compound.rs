@@ -0,0 +1,152 @@
+use alloc::{string::{String, ToString}, vec::Vec};
+use crate::tokenizer::Token;
+use super::{expand_tokens, part_token, TokenFilter};
+
+// Constant
+const HYPHENS: [char; 3] = ['-', '\u{2010}', '\u{2011}'];
+
+/// How the HyphenFilter handle an hyphenated compound such as state-of-the-art
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompoundMode {
+    /// Emit each part with its own position. i.e: state of the art
+    Split,
+    /// Emit the concatenation of the parts. i.e: stateoftheart
+    Join,
+    /// Emit each part and the concatenation at the position of the first part
+    Both
+}
+
+/// Handle the hyphenated compounds. The tokenizer must keep the hyphens inside of the tokens
+/// which is the case of the RuleTokenizer or the WhitespaceTokenizer
+#[derive(Debug, Clone, Copy)]
+pub struct HyphenFilter {
+    mode: CompoundMode
+}
+
+impl HyphenFilter {
+    /// Create a new HyphenFilter
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - CompoundMode
+    pub fn new(mode: CompoundMode) -> HyphenFilter {
+        HyphenFilter { mode }
+    }
+
+    /// Get the parts of a compound with their byte offsets in the word
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - &str
+    fn parts(word: &str) -> Vec<(usize, &str)> {
+        let mut parts = Vec::new();
+        let mut start = 0;
+
+        for (idx, c) in word.char_indices() {
+            if HYPHENS.contains(&c) {
+                parts.push((start, &word[start..idx]));
+                start = idx + c.len_utf8();
+            }
+        }
+        parts.push((start, &word[start..]));
+
+        parts.into_iter().filter(|(_, part)| !part.is_empty()).collect()
+    }
+}
+
+impl TokenFilter for HyphenFilter {
+    fn filter(&self, tokens: Vec<Token>) -> Vec<Token> {
+        self.filter_chunk(tokens, 0).0
+    }
+
+    fn filter_chunk(&self, tokens: Vec<Token>, position: usize) -> (Vec<Token>, usize) {
+        let (filtered, inserted) = expand_tokens(tokens, |mut token, filtered| {
+            let parts = Self::parts(&token.text);
+            if !token.is_word() || parts.len() < 2 {
+                filtered.push(token);
+                return;
+            }
+
+            let joined: String = parts.iter().map(|(_, part)| *part).collect();
+            if self.mode == CompoundMode::Join {
+                token.text = joined;
+                filtered.push(token);
+                return;
+            }
+
+            for (idx, (offset, part)) in parts.iter().enumerate() {
+                let range = *offset..offset + part.len();
+                filtered.push(part_token(&token, part.to_string(), range, token.position + idx));
+
+                if idx == 0 && self.mode == CompoundMode::Both {
+                    let mut joined_token = token.clone();
+                    joined_token.text = joined.clone();
+                    filtered.push(joined_token);
+                }
+            }
+        });
+
+        (filtered, position + inserted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        analyzer::Analyzer,
+        stemmer::PorterStemmer,
+        tokenizer::RuleTokenizer
+    };
+
+    fn analyze(mode: CompoundMode, text: &str) -> Vec<(String, usize)> {
+        let analyzer = Analyzer::builder()
+            .tokenizer(RuleTokenizer::new())
+            .token_filter(HyphenFilter::new(mode))
+            .stemmer(PorterStemmer)
+            .build();
+
+        analyzer.analyze(text)
+            .unwrap()
+            .into_iter()
+            .map(|t| (t.term().to_string(), t.position))
+            .collect()
+    }
+
+    #[test]
+    fn expect_to_split_compounds() {
+        let terms = analyze(CompoundMode::Split, "state-of-the-art dancing");
+
+        assert_eq!(terms, vec![
+            ("state".to_string(), 0),
+            ("of".to_string(), 1),
+            ("the".to_string(), 2),
+            ("art".to_string(), 3),
+            ("danc".to_string(), 4)
+        ]);
+    }
+
+    #[test]
+    fn expect_to_join_compounds() {
+        let terms = analyze(CompoundMode::Join, "well-dressed dancers");
+
+        assert_eq!(terms, vec![("welldress".to_string(), 0), ("dancer".to_string(), 1)]);
+    }
+
+    #[test]
+    fn expect_to_emit_both() {
+        let text = "well-dressed dancers";
+        let terms = analyze(CompoundMode::Both, text);
+
+        assert_eq!(terms, vec![
+            ("well".to_string(), 0),
+            ("welldress".to_string(), 0),
+            ("dress".to_string(), 1),
+            ("dancer".to_string(), 2)
+        ]);
+
+        let tokens = HyphenFilter::new(CompoundMode::Both).filter(vec![Token::new("well-dressed", 0, 12, 0)]);
+        assert_eq!(&text[tokens[2].start..tokens[2].end], "dressed");
+        assert_eq!((tokens[1].start, tokens[1].end), (0, 12));
+    }
+}
@@ -0,0 +1,195 @@
+use alloc::{format, string::{String, ToString}, vec::Vec};
+use crate::tokenizer::Token;
+use super::{expand_tokens, part_token, TokenFilter};
+
+// Constant
+const CLITICS: [&str; 8] = ["n't", "'s", "'m", "'re", "'ve", "'ll", "'d", "'"];
+const NEGATION_BASES: [(&str, &str); 3] = [("ca", "can"), ("wo", "will"), ("sha", "shall")];
+const CLITIC_EXPANSIONS: [(&str, &str); 6] = [
+    ("n't", "not"), ("'m", "am"), ("'re", "are"), ("'ve", "have"), ("'ll", "will"), ("'d", "would")
+];
+// words for which 's is a contraction of "is" rather than a possessive
+const IS_CONTRACTIONS: [&str; 10] = ["it", "he", "she", "that", "what", "there", "here", "who", "where", "how"];
+
+/// How the ContractionFilter handle the english contractions and possessives
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContractionMode {
+    /// Expand the contractions. i.e: don't -> do not, it's -> it is. Possessives are dropped i.e: John's -> John
+    Expand,
+    /// Split the contractions following the Penn Treebank convention. i.e: don't -> do n't, John's -> John 's
+    Split,
+    /// Only drop the possessives 's and ' at the end of a word as done by the Porter2 stemmer
+    DropPossessive
+}
+
+/// Handle the english contractions and possessives. Both the straight (') and the curly (’) apostrophes are supported
+#[derive(Debug, Clone, Copy)]
+pub struct ContractionFilter {
+    mode: ContractionMode
+}
+
+impl ContractionFilter {
+    /// Create a new ContractionFilter
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - ContractionMode
+    pub fn new(mode: ContractionMode) -> ContractionFilter {
+        ContractionFilter { mode }
+    }
+
+    /// Get the byte index where the clitic start in the word if the word end with the clitic
+    /// The comparison ignore the ascii case and the curly apostrophe match the straight apostrophe
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - &str
+    /// * `clitic` - &str
+    fn find_clitic(word: &str, clitic: &str) -> Option<usize> {
+        let mut chars = word.char_indices().rev();
+        let mut idx = word.len();
+
+        for expected in clitic.chars().rev() {
+            let (i, c) = chars.next()?;
+            let c = if c == '’' { '\'' } else { c };
+            if !c.eq_ignore_ascii_case(&expected) {
+                return None;
+            }
+
+            idx = i;
+        }
+
+        // the clitic must be attached to a word
+        match idx > 0 {
+            true => Some(idx),
+            false => None
+        }
+    }
+
+    /// Split a word into its base and its clitic
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - &str
+    fn split_clitic(word: &str) -> Option<(usize, &'static str)> {
+        CLITICS
+            .iter()
+            .find_map(|clitic| Self::find_clitic(word, clitic).map(|idx| (idx, *clitic)))
+    }
+
+    /// Get the words which replace the base and the clitic
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - &str
+    /// * `clitic` - &str
+    /// * `raw_clitic` - &str
+    fn replacements(&self, base: &str, clitic: &str, raw_clitic: &str) -> (String, Option<String>) {
+        let lower_base = base.to_lowercase();
+
+        match self.mode {
+            ContractionMode::DropPossessive => match clitic {
+                "'s" | "'" => (base.to_string(), None),
+                _ => (format!("{base}{raw_clitic}"), None)
+            },
+            ContractionMode::Split => (base.to_string(), Some(raw_clitic.replace('’', "'"))),
+            ContractionMode::Expand => match clitic {
+                "n't" => {
+                    let base = NEGATION_BASES
+                        .iter()
+                        .find(|(short, _)| *short == lower_base)
+                        .map(|(_, full)| full.to_string())
+                        .unwrap_or(base.to_string());
+
+                    (base, Some("not".to_string()))
+                },
+                "'s" if lower_base == "let" => (base.to_string(), Some("us".to_string())),
+                "'s" if IS_CONTRACTIONS.contains(&lower_base.as_str()) => (base.to_string(), Some("is".to_string())),
+                "'s" | "'" => (base.to_string(), None),
+                _ => {
+                    let expansion = CLITIC_EXPANSIONS
+                        .iter()
+                        .find(|(c, _)| *c == clitic)
+                        .map(|(_, expansion)| expansion.to_string());
+
+                    (base.to_string(), expansion)
+                }
+            }
+        }
+    }
+}
+
+impl TokenFilter for ContractionFilter {
+    fn filter(&self, tokens: Vec<Token>) -> Vec<Token> {
+        self.filter_chunk(tokens, 0).0
+    }
+
+    fn filter_chunk(&self, tokens: Vec<Token>, position: usize) -> (Vec<Token>, usize) {
+        let (filtered, inserted) = expand_tokens(tokens, |token, filtered| {
+            let clitic = token.is_word()
+                .then(|| Self::split_clitic(&token.text))
+                .flatten();
+
+            let Some((idx, clitic)) = clitic else {
+                filtered.push(token);
+                return;
+            };
+
+            let (base, rest) = token.text.split_at(idx);
+            let (base_text, clitic_text) = self.replacements(base, clitic, rest);
+
+            // the base keep the whole span when the clitic is dropped
+            let len = token.text.len();
+            let base_end = if clitic_text.is_some() { idx } else { len };
+            filtered.push(part_token(&token, base_text, 0..base_end, token.position));
+
+            if let Some(text) = clitic_text {
+                filtered.push(part_token(&token, text, idx..len, token.position + 1));
+            }
+        });
+
+        (filtered, position + inserted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenizer::{Tokenizer, UnicodeTokenizer};
+
+    fn filter(mode: ContractionMode, text: &str) -> Vec<Token> {
+        ContractionFilter::new(mode).filter(UnicodeTokenizer.tokenize(text))
+    }
+
+    fn texts(tokens: &[Token]) -> Vec<&str> {
+        tokens.iter().map(|t| t.text.as_str()).collect()
+    }
+
+    #[test]
+    fn expect_to_expand_contractions() {
+        let tokens = filter(ContractionMode::Expand, "I don’t think it's John's, they won't");
+
+        assert_eq!(texts(&tokens), vec!["I", "do", "not", "think", "it", "is", "John", "they", "will", "not"]);
+        assert_eq!(tokens[2].position, 2);
+        assert_eq!(tokens[9].position, 9);
+    }
+
+    #[test]
+    fn expect_to_split_contractions() {
+        let text = "don’t touch John's dogs'";
+        let tokens = filter(ContractionMode::Split, text);
+
+        assert_eq!(texts(&tokens), vec!["do", "n't", "touch", "John", "'s", "dogs"]);
+        assert_eq!(&text[tokens[1].start..tokens[1].end], "n’t");
+        assert_eq!(tokens[1].surface, "n’t");
+        assert_eq!(tokens[0].surface, "do");
+    }
+
+    #[test]
+    fn expect_to_drop_possessives() {
+        let tokens = filter(ContractionMode::DropPossessive, "John’s dog isn't the dogs' friend");
+
+        assert_eq!(texts(&tokens), vec!["John", "dog", "isn't", "the", "dogs", "friend"]);
+        assert_eq!(tokens[0].end, "John’s".len());
+    }
+}
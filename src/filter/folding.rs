@@ -0,0 +1,87 @@
+use alloc::{string::String, vec::Vec};
+use unicode_normalization::char::{decompose_compatible, is_combining_mark};
+use crate::tokenizer::Token;
+use super::{CharFilter, TokenFilter};
+
+// Constant
+// letters which aren't decomposed by NFKD
+const SPECIAL_LETTERS: [(char, &str); 22] = [
+    ('æ', "ae"), ('Æ', "AE"), ('œ', "oe"), ('Œ', "OE"), ('ß', "ss"), ('ẞ', "SS"),
+    ('ø', "o"), ('Ø', "O"), ('ł', "l"), ('Ł', "L"), ('đ', "d"), ('Đ', "D"),
+    ('ð', "d"), ('Ð', "D"), ('þ', "th"), ('Þ', "TH"), ('ħ', "h"), ('Ħ', "H"),
+    ('ı', "i"), ('ŀ', "l"), ('Ŀ', "L"), ('ŧ', "t")
+];
+
+/// Fold the accented letters, the ligatures and the special latin letters to their ascii equivalent. i.e: café -> cafe, œuvre -> oeuvre
+/// The characters are decomposed with the unicode NFKD normalization and the combining marks are removed.
+/// The filter should be placed before the stemmer which only knows the ascii letters
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AsciiFoldingFilter;
+
+impl AsciiFoldingFilter {
+    /// Fold a text
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - &str
+    pub fn fold(text: &str) -> String {
+        let mut out = String::with_capacity(text.len());
+        text.chars().for_each(|c| CharFilter::filter(&AsciiFoldingFilter, c, &mut out));
+
+        out
+    }
+}
+
+impl CharFilter for AsciiFoldingFilter {
+    fn filter(&self, c: char, out: &mut String) {
+        if c.is_ascii() {
+            out.push(c);
+            return;
+        }
+
+        decompose_compatible(c, |d| {
+            match SPECIAL_LETTERS.iter().find(|(letter, _)| *letter == d) {
+                Some((_, replacement)) => out.push_str(replacement),
+                None if is_combining_mark(d) => {},
+                None => out.push(d)
+            }
+        });
+    }
+}
+
+impl TokenFilter for AsciiFoldingFilter {
+    fn filter(&self, tokens: Vec<Token>) -> Vec<Token> {
+        tokens
+            .into_iter()
+            .map(|mut token| {
+                if token.is_word() && !token.text.is_ascii() {
+                    token.text = AsciiFoldingFilter::fold(&token.text);
+                }
+
+                token
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stemmer::{PorterStemmer, Stem};
+
+    #[test]
+    fn expect_to_fold_diacritics_and_ligatures() {
+        assert_eq!(AsciiFoldingFilter::fold("Café naïve façade"), "Cafe naive facade");
+        assert_eq!(AsciiFoldingFilter::fold("Æsir œuvre Straße Øresund łódź"), "AEsir oeuvre Strasse Oresund lodz");
+        assert_eq!(AsciiFoldingFilter::fold("ﬁle ①"), "file 1");
+    }
+
+    #[test]
+    fn expect_to_stem_folded_tokens() {
+        let tokens = TokenFilter::filter(&AsciiFoldingFilter, vec![Token::new("naïve", 0, 6, 0)]);
+
+        assert_eq!(tokens[0].text, "naive");
+        assert_eq!(tokens[0].surface, "naïve");
+        assert_eq!(PorterStemmer.stem(&tokens[0].text), PorterStemmer.stem("naive"));
+    }
+}
@@ -0,0 +1,47 @@
+use alloc::vec::Vec;
+use crate::tokenizer::Token;
+use super::TokenFilter;
+
+/// Remove the tokens which have less characters than the minimum length
+#[derive(Debug, Clone, Copy)]
+pub struct MinLengthFilter {
+    min: usize
+}
+
+impl MinLengthFilter {
+    /// Create a new MinLengthFilter
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - usize
+    pub fn new(min: usize) -> MinLengthFilter {
+        MinLengthFilter { min }
+    }
+}
+
+impl TokenFilter for MinLengthFilter {
+    fn filter(&self, tokens: Vec<Token>) -> Vec<Token> {
+        tokens
+            .into_iter()
+            .filter(|token| token.text.chars().count() >= self.min)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_to_remove_short_tokens() {
+        let tokens = vec![
+            Token::new("an", 0, 2, 0),
+            Token::new("été", 3, 8, 1)
+        ];
+
+        let filtered = MinLengthFilter::new(3).filter(tokens);
+
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].text, "été");
+    }
+}
@@ -0,0 +1,34 @@
+use alloc::vec::Vec;
+use crate::tokenizer::Token;
+use super::TokenFilter;
+
+/// Lowercase the text of each token
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LowercaseFilter;
+
+impl TokenFilter for LowercaseFilter {
+    fn filter(&self, tokens: Vec<Token>) -> Vec<Token> {
+        tokens
+            .into_iter()
+            .map(|mut token| {
+                if token.is_word() {
+                    token.text = token.text.to_lowercase();
+                }
+
+                token
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_to_lowercase_tokens() {
+        let tokens = LowercaseFilter.filter(vec![Token::new("MULTIDIMENSIONAL", 0, 16, 0)]);
+
+        assert_eq!(tokens[0].text, "multidimensional");
+    }
+}
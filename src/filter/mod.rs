@@ -0,0 +1,106 @@
+mod acronym;
+mod compound;
+mod contraction;
+mod folding;
+mod length;
+mod lowercase;
+mod overrides;
+mod punctuation;
+mod stop_lists;
+mod stop_words;
+
+use alloc::{string::{String, ToString}, vec::Vec};
+use core::ops::Range;
+use crate::tokenizer::Token;
+
+pub use self::acronym::AcronymFilter;
+pub use self::compound::{CompoundMode, HyphenFilter};
+pub use self::contraction::{ContractionFilter, ContractionMode};
+pub use self::folding::AsciiFoldingFilter;
+pub use self::length::MinLengthFilter;
+pub use self::lowercase::LowercaseFilter;
+pub use self::overrides::StemOverrideFilter;
+pub use self::punctuation::AsciiPunctuationFilter;
+pub use self::stop_words::{StopList, StopWordFilter, StopWordMode};
+
+/// Transform the characters of a text before it's tokenized
+pub trait CharFilter: Send + Sync {
+    /// Push the replacement of the character into the output. Nothing is pushed when the character is removed
+    ///
+    /// # Arguments
+    ///
+    /// * `c` - char
+    /// * `out` - &mut String
+    fn filter(&self, c: char, out: &mut String);
+}
+
+/// Transform, remove or add tokens produced by a tokenizer
+pub trait TokenFilter: Send + Sync {
+    /// Filter the tokens produced by the tokenizer or by a previous filter
+    ///
+    /// # Arguments
+    ///
+    /// * `tokens` - `Vec<Token>`
+    fn filter(&self, tokens: Vec<Token>) -> Vec<Token>;
+
+    /// Filter the tokens of a chunk of a larger text and move the position which follow the chunk by the positions
+    /// the filter inserted or freed. The filters which only transform or remove tokens without renumbering the others
+    /// keep the position
+    ///
+    /// # Arguments
+    ///
+    /// * `tokens` - `Vec<Token>`
+    /// * `position` - usize
+    fn filter_chunk(&self, tokens: Vec<Token>, position: usize) -> (Vec<Token>, usize) {
+        (self.filter(tokens), position)
+    }
+}
+
+/// Replace each token by the tokens pushed by a closure. The next tokens are shifted by the number of positions
+/// which the pushed tokens take after the position of the replaced token. Return the number of inserted positions
+///
+/// # Arguments
+///
+/// * `tokens` - `Vec<Token>`
+/// * `expand` - F
+fn expand_tokens<F>(tokens: Vec<Token>, mut expand: F) -> (Vec<Token>, usize)
+where
+    F: FnMut(Token, &mut Vec<Token>)
+{
+    let mut expanded = Vec::with_capacity(tokens.len());
+    // number of positions inserted so far which shift the position of the next tokens
+    let mut shift = 0;
+
+    for mut token in tokens {
+        token.position += shift;
+        let (from, next) = (expanded.len(), token.position + 1);
+
+        expand(token, &mut expanded);
+        let end = expanded[from..].iter().map(|t: &Token| t.position + 1).max().unwrap_or(next);
+        shift += end.saturating_sub(next);
+    }
+
+    (expanded, shift)
+}
+
+/// Create the token of a part of a token from the byte range of the part in the text of the token.
+/// The span of the part can only be computed if the text hasn't been modified, otherwise it keeps the span of the token
+///
+/// # Arguments
+///
+/// * `token` - &Token
+/// * `text` - String
+/// * `range` - `Range<usize>`
+/// * `position` - usize
+fn part_token(token: &Token, text: String, range: Range<usize>, position: usize) -> Token {
+    let mut part = token.clone();
+    if token.surface == token.text {
+        part.start = token.start + range.start;
+        part.end = token.start + range.end;
+        part.surface = token.surface[range].to_string();
+    }
+    part.text = text;
+    part.position = position;
+
+    part
+}
@@ -0,0 +1,60 @@
+use alloc::{borrow::ToOwned, collections::BTreeMap, string::String, vec::Vec};
+use crate::tokenizer::Token;
+use super::TokenFilter;
+
+/// Set the stem of the tokens which match a dictionary and protect them from the stemmer
+#[derive(Debug, Clone, Default)]
+pub struct StemOverrideFilter {
+    overrides: BTreeMap<String, String>
+}
+
+impl StemOverrideFilter {
+    /// Create a new StemOverrideFilter from a list of (word, stem)
+    ///
+    /// # Arguments
+    ///
+    /// * `overrides` - I
+    pub fn new<I, K, V>(overrides: I) -> StemOverrideFilter
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>
+    {
+        StemOverrideFilter {
+            overrides: overrides
+                .into_iter()
+                .map(|(word, stem)| (word.into(), stem.into()))
+                .collect()
+        }
+    }
+}
+
+impl TokenFilter for StemOverrideFilter {
+    fn filter(&self, tokens: Vec<Token>) -> Vec<Token> {
+        tokens
+            .into_iter()
+            .map(|mut token| {
+                if let Some(stem) = self.overrides.get(&token.text) {
+                    token.stem = Some(stem.to_owned());
+                    token.keyword = true;
+                }
+
+                token
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_to_override_stem() {
+        let tokens = vec![Token::new("mice", 0, 4, 0)];
+        let filtered = StemOverrideFilter::new([("mice", "mouse")]).filter(tokens);
+
+        assert_eq!(filtered[0].stem, Some("mouse".to_string()));
+        assert!(filtered[0].keyword);
+    }
+}
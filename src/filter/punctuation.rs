@@ -0,0 +1,62 @@
+use alloc::{string::String, vec::Vec};
+use crate::tokenizer::Token;
+use crate::util::AsciiUtil;
+use super::{CharFilter, TokenFilter};
+
+/// Remove the ascii punctuation from a text or from the tokens.
+/// When used as a TokenFilter, the tokens which only contains punctuation are marked as skipped
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AsciiPunctuationFilter;
+
+impl CharFilter for AsciiPunctuationFilter {
+    fn filter(&self, c: char, out: &mut String) {
+        if !c.is_ascii_punctuation() {
+            out.push(c);
+        }
+    }
+}
+
+impl TokenFilter for AsciiPunctuationFilter {
+    fn filter(&self, tokens: Vec<Token>) -> Vec<Token> {
+        tokens
+            .into_iter()
+            .map(|mut token| {
+                if !token.is_word() {
+                    return token;
+                }
+
+                token.text = token.text.remove_ascii_punctuation();
+                token.skipped = token.skipped || token.text.is_empty();
+
+                token
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_to_remove_punctuation() {
+        let mut out = String::new();
+        "humor.".chars().for_each(|c| CharFilter::filter(&AsciiPunctuationFilter, c, &mut out));
+
+        assert_eq!(out, "humor");
+    }
+
+    #[test]
+    fn expect_to_skip_punctuation_tokens() {
+        let tokens = vec![
+            Token::new("humor.", 0, 6, 0),
+            Token::new("--", 7, 9, 1)
+        ];
+
+        let filtered = TokenFilter::filter(&AsciiPunctuationFilter, tokens);
+
+        assert_eq!(filtered[0].text, "humor");
+        assert!(!filtered[0].skipped);
+        assert!(filtered[1].skipped);
+    }
+}
@@ -0,0 +1,258 @@
+use alloc::{collections::BTreeSet, string::String, vec::Vec};
+#[cfg(feature = "std")]
+use alloc::{format, string::ToString};
+#[cfg(feature = "std")]
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    path::Path
+};
+#[cfg(feature = "std")]
+use crate::error::SimmerError;
+use crate::tokenizer::Token;
+use super::{stop_lists, TokenFilter};
+
+/// Embedded lists of stop words
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopList {
+    /// The 174 english stop words of the Snowball project
+    EnglishSnowball,
+    /// The 570 english stop words of the SMART information retrieval system
+    EnglishSmart
+}
+
+/// What the StopWordFilter do with the position of the removed tokens
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum StopWordMode {
+    /// Keep the position of the other tokens so that the removed tokens leave a gap. i.e: for phrase queries
+    #[default]
+    PreserveGaps,
+    /// Renumber the position of the other tokens as if the removed tokens were never there
+    Remove
+}
+
+/// Remove the tokens which are part of a list of stop words. The comparison is done on the text of the token
+/// which means that the filter should be placed after the normalization filters (i.e: LowercaseFilter) and runs before the stemmer
+#[derive(Debug, Clone, Default)]
+pub struct StopWordFilter {
+    words: BTreeSet<String>,
+    mode: StopWordMode
+}
+
+impl StopList {
+    /// Get the words of the list
+    pub fn words(&self) -> &'static [&'static str] {
+        match self {
+            StopList::EnglishSnowball => &stop_lists::ENGLISH_SNOWBALL,
+            StopList::EnglishSmart => &stop_lists::ENGLISH_SMART
+        }
+    }
+}
+
+impl StopWordFilter {
+    /// Create a new StopWordFilter
+    ///
+    /// # Arguments
+    ///
+    /// * `words` - I
+    pub fn new<I, S>(words: I) -> StopWordFilter
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>
+    {
+        StopWordFilter {
+            words: words.into_iter().map(Into::into).collect(),
+            mode: StopWordMode::default()
+        }
+    }
+
+    /// Create a new StopWordFilter with an embedded list
+    ///
+    /// # Arguments
+    ///
+    /// * `list` - StopList
+    pub fn from_list(list: StopList) -> StopWordFilter {
+        StopWordFilter::new(list.words().iter().copied())
+    }
+
+    /// Create a new StopWordFilter from a reader. The reader contains one word per line,
+    /// the empty lines and the lines starting with a # are ignored
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - R
+    #[cfg(feature = "std")]
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<StopWordFilter, SimmerError> {
+        let mut words = BTreeSet::new();
+        for (idx, line) in reader.split(b'\n').enumerate() {
+            let line = String::from_utf8(line?).map_err(|_| SimmerError::Utf8(format!("line {}", idx + 1)))?;
+            let word = line.trim();
+            if !word.is_empty() && !word.starts_with('#') {
+                words.insert(word.to_string());
+            }
+        }
+
+        Ok(StopWordFilter::new(words))
+    }
+
+    /// Create a new StopWordFilter from a file. See `from_reader` for the format of the file
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - P
+    #[cfg(feature = "std")]
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<StopWordFilter, SimmerError> {
+        let file = File::open(path)?;
+
+        StopWordFilter::from_reader(BufReader::new(file))
+    }
+
+    /// Add words to the list of stop words
+    ///
+    /// # Arguments
+    ///
+    /// * `words` - I
+    pub fn words<I, S>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>
+    {
+        self.words.extend(words.into_iter().map(Into::into));
+        self
+    }
+
+    /// Set what is done with the position of the removed tokens
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - StopWordMode
+    pub fn mode(mut self, mode: StopWordMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Remove the stop words and renumber the other tokens depending on the mode. Return the number of freed positions
+    ///
+    /// # Arguments
+    ///
+    /// * `tokens` - `Vec<Token>`
+    fn remove(&self, tokens: Vec<Token>) -> (Vec<Token>, usize) {
+        let (kept, removed): (Vec<Token>, Vec<Token>) = tokens
+            .into_iter()
+            .partition(|token| !token.is_word() || !self.words.contains(&token.text));
+
+        if self.mode == StopWordMode::PreserveGaps {
+            return (kept, 0);
+        }
+
+        // a position is only freed when every token at this position has been removed
+        let kept_positions: BTreeSet<usize> = kept.iter().map(|t| t.position).collect();
+        let mut freed: Vec<usize> = removed
+            .iter()
+            .map(|t| t.position)
+            .filter(|position| !kept_positions.contains(position))
+            .collect();
+        freed.sort_unstable();
+        freed.dedup();
+
+        let kept = kept
+            .into_iter()
+            .map(|mut token| {
+                token.position -= freed.partition_point(|position| *position < token.position);
+                token
+            })
+            .collect();
+
+        (kept, freed.len())
+    }
+}
+
+impl TokenFilter for StopWordFilter {
+    fn filter(&self, tokens: Vec<Token>) -> Vec<Token> {
+        self.remove(tokens).0
+    }
+
+    fn filter_chunk(&self, tokens: Vec<Token>, position: usize) -> (Vec<Token>, usize) {
+        let (kept, freed) = self.remove(tokens);
+
+        (kept, position - freed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        analyzer::Analyzer,
+        filter::LowercaseFilter,
+        stemmer::PorterStemmer,
+        tokenizer::{Tokenizer, UnicodeTokenizer}
+    };
+
+    #[test]
+    fn expect_to_remove_stop_words() {
+        let tokens = vec![
+            Token::new("alex", 0, 4, 0),
+            Token::new("was", 5, 8, 1),
+            Token::new("an", 9, 11, 2)
+        ];
+
+        let filtered = StopWordFilter::new(["was", "an"]).filter(tokens);
+
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].text, "alex");
+    }
+
+    #[test]
+    fn expect_to_filter_before_stemming() {
+        let analyzer = Analyzer::builder()
+            .tokenizer(UnicodeTokenizer)
+            .token_filter(LowercaseFilter)
+            .token_filter(StopWordFilter::from_list(StopList::EnglishSnowball))
+            .stemmer(PorterStemmer)
+            .build();
+
+        let terms: Vec<(String, usize)> = analyzer
+            .analyze("Alex was an excellent dancer")
+            .unwrap()
+            .into_iter()
+            .map(|t| (t.term().to_string(), t.position))
+            .collect();
+
+        assert_eq!(terms, vec![("alex".to_string(), 0), ("excel".to_string(), 3), ("dancer".to_string(), 4)]);
+    }
+
+    #[test]
+    fn expect_to_renumber_positions() {
+        let tokens = UnicodeTokenizer.tokenize("alex was an excellent dancer");
+        let filtered = StopWordFilter::from_list(StopList::EnglishSmart)
+            .mode(StopWordMode::Remove)
+            .filter(tokens);
+
+        let positions: Vec<(&str, usize)> = filtered.iter().map(|t| (t.text.as_str(), t.position)).collect();
+
+        assert_eq!(positions, vec![("alex", 0), ("excellent", 1), ("dancer", 2)]);
+        assert!(StopList::EnglishSmart.words().contains(&"whereupon"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn expect_to_load_list_from_reader() {
+        let list = "# custom list\nalex\n\n  dancer  \n";
+        let filter = StopWordFilter::from_reader(list.as_bytes()).unwrap().words(["was"]);
+        let filtered = filter.filter(UnicodeTokenizer.tokenize("alex was an excellent dancer"));
+
+        assert_eq!(filtered.iter().map(|t| t.text.as_str()).collect::<Vec<_>>(), vec!["an", "excellent"]);
+        assert!(matches!(StopWordFilter::from_file("missing/stop_words.txt"), Err(SimmerError::Io(_))));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn expect_to_report_invalid_utf8_list() {
+        let list = [b"alex\n".as_slice(), &[0xFF, 0xFE], b"\ndancer"].concat();
+        let err = StopWordFilter::from_reader(list.as_slice()).unwrap_err();
+
+        assert_eq!(err, SimmerError::Utf8("line 2".to_string()));
+        assert_eq!(err.to_string(), "Unable to decode the input as utf8 at the line 2");
+    }
+}
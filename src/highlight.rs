@@ -0,0 +1,299 @@
+use alloc::{collections::BTreeSet, string::{String, ToString}, vec::Vec};
+use crate::{analyzer::Analyzer, error::SimmerError};
+
+// Constant
+const DEFAULT_FRAGMENT_SIZE: usize = 100;
+const DEFAULT_MAX_SNIPPETS: usize = 3;
+const DEFAULT_PRE_TAG: &str = "<em>";
+const DEFAULT_POST_TAG: &str = "</em>";
+
+/// A word of the document which match a term of the query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    /// The term shared by the query and the word. i.e: run for running and runs
+    pub term: String,
+    /// Byte offset where the word start in the document
+    pub start: usize,
+    /// Byte offset where the word end in the document
+    pub end: usize
+}
+
+/// A fragment of the document where the matches are surrounded by the markers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snippet {
+    pub text: String,
+    /// Byte offset where the fragment start in the document
+    pub start: usize,
+    /// Byte offset where the fragment end in the document
+    pub end: usize
+}
+
+/// Highlight the words of a document which share a term with the query. The query and the document are analyzed
+/// with the same analyzer which means that searching for "running" highlight "runs" and "run"
+#[derive(Clone)]
+pub struct Highlighter<'a> {
+    analyzer: &'a Analyzer,
+    fragment_size: usize,
+    max_snippets: usize,
+    pre_tag: String,
+    post_tag: String
+}
+
+#[cfg(feature = "std")]
+impl Default for Highlighter<'static> {
+    fn default() -> Self {
+        Highlighter::new(crate::sentence_analyzer())
+    }
+}
+
+impl<'a> Highlighter<'a> {
+    /// Create a new Highlighter. Use Highlighter::default() to analyze the texts the same way as `stem_sentence`
+    ///
+    /// # Arguments
+    ///
+    /// * `analyzer` - &'a Analyzer
+    pub fn new(analyzer: &'a Analyzer) -> Highlighter<'a> {
+        Highlighter {
+            analyzer,
+            fragment_size: DEFAULT_FRAGMENT_SIZE,
+            max_snippets: DEFAULT_MAX_SNIPPETS,
+            pre_tag: DEFAULT_PRE_TAG.to_string(),
+            post_tag: DEFAULT_POST_TAG.to_string()
+        }
+    }
+
+    /// Set the approximative size in bytes of a snippet. A snippet is never cut inside of a word
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - usize
+    pub fn fragment_size(mut self, size: usize) -> Self {
+        self.fragment_size = size;
+        self
+    }
+
+    /// Set the maximum number of snippets
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - usize
+    pub fn max_snippets(mut self, max: usize) -> Self {
+        self.max_snippets = max;
+        self
+    }
+
+    /// Set the markers which surround the matches
+    ///
+    /// # Arguments
+    ///
+    /// * `pre_tag` - &str
+    /// * `post_tag` - &str
+    pub fn markers(mut self, pre_tag: &str, post_tag: &str) -> Self {
+        self.pre_tag = pre_tag.to_string();
+        self.post_tag = post_tag.to_string();
+        self
+    }
+
+    /// Get the words of the document which match a term of the query sorted by their offsets
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - &str
+    /// * `document` - &str
+    pub fn matches(&self, query: &str, document: &str) -> Result<Vec<Match>, SimmerError> {
+        let terms: BTreeSet<String> = self.analyzer
+            .analyze(query)?
+            .into_iter()
+            .filter(|token| !token.skipped)
+            .map(|token| token.term().to_string())
+            .collect();
+
+        let mut matches: Vec<Match> = self.analyzer
+            .analyze(document)?
+            .into_iter()
+            .filter(|token| !token.skipped && terms.contains(token.term()))
+            .map(|token| Match {
+                term: token.term().to_string(),
+                start: token.start,
+                end: token.end
+            })
+            .collect();
+
+        // filters such as the HyphenFilter can emit several tokens for the same span
+        matches.sort_by_key(|m| (m.start, m.end));
+        matches.dedup_by(|next, previous| next.start < previous.end);
+
+        Ok(matches)
+    }
+
+    /// Surround the matches of the whole document with the markers
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - &str
+    /// * `document` - &str
+    pub fn highlight(&self, query: &str, document: &str) -> Result<String, SimmerError> {
+        let matches = self.matches(query, document)?;
+
+        Ok(self.mark(document, 0, document.len(), &matches))
+    }
+
+    /// Get the fragments of the document which contain the matches. Each fragment start around its first match
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - &str
+    /// * `document` - &str
+    pub fn snippets(&self, query: &str, document: &str) -> Result<Vec<Snippet>, SimmerError> {
+        let matches = self.matches(query, document)?;
+        let mut snippets = Vec::new();
+        let mut idx = 0;
+
+        while idx < matches.len() && snippets.len() < self.max_snippets {
+            let first = &matches[idx];
+            let context = self.fragment_size.saturating_sub(first.end - first.start) / 2;
+            let start = word_start(document, first.start.saturating_sub(context), first.start);
+            let end = word_end(document, start + self.fragment_size, first.end);
+
+            let fragment: Vec<Match> = matches[idx..]
+                .iter()
+                .take_while(|m| m.end <= end)
+                .cloned()
+                .collect();
+            idx += fragment.len();
+
+            snippets.push(Snippet {
+                text: self.mark(document, start, end, &fragment),
+                start,
+                end
+            });
+        }
+
+        Ok(snippets)
+    }
+
+    /// Copy the part of the document between start and end and surround the matches with the markers
+    ///
+    /// # Arguments
+    ///
+    /// * `document` - &str
+    /// * `start` - usize
+    /// * `end` - usize
+    /// * `matches` - &[Match]
+    fn mark(&self, document: &str, start: usize, end: usize, matches: &[Match]) -> String {
+        let mut out = String::with_capacity(end - start);
+        let mut cursor = start;
+
+        for m in matches.iter().filter(|m| m.start >= start && m.end <= end) {
+            out.push_str(&document[cursor..m.start]);
+            out.push_str(&self.pre_tag);
+            out.push_str(&document[m.start..m.end]);
+            out.push_str(&self.post_tag);
+            cursor = m.end;
+        }
+        out.push_str(&document[cursor..end]);
+
+        out
+    }
+}
+
+/// Move the start of a fragment forward to the start of a word. The start never move past the limit
+///
+/// # Arguments
+///
+/// * `document` - &str
+/// * `start` - usize
+/// * `limit` - usize
+fn word_start(document: &str, start: usize, limit: usize) -> usize {
+    let mut start = start;
+    while !document.is_char_boundary(start) {
+        start += 1;
+    }
+
+    let in_word = start > 0 && !document[..start].ends_with(char::is_whitespace);
+    if !in_word {
+        return start;
+    }
+
+    match document[start..limit].find(char::is_whitespace) {
+        Some(idx) => {
+            let rest = &document[start + idx..limit];
+            start + idx + rest.len() - rest.trim_start().len()
+        },
+        None => limit
+    }
+}
+
+/// Move the end of a fragment backward to the end of a word. The end never move before the limit
+///
+/// # Arguments
+///
+/// * `document` - &str
+/// * `end` - usize
+/// * `limit` - usize
+fn word_end(document: &str, end: usize, limit: usize) -> usize {
+    if end >= document.len() {
+        return document.len();
+    }
+
+    let mut end = end.max(limit);
+    while !document.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    let in_word = !document[end..].starts_with(char::is_whitespace);
+    if !in_word {
+        return end;
+    }
+
+    match document[limit..end].rfind(char::is_whitespace) {
+        Some(idx) => document[..limit + idx].trim_end().len(),
+        None => end + document[end..].find(char::is_whitespace).unwrap_or(document.len() - end)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_to_match_inflections() {
+        let document = "She runs every day. Running is fun, so run!";
+        let matches = Highlighter::default().matches("running", document).unwrap();
+
+        let words: Vec<&str> = matches.iter().map(|m| &document[m.start..m.end]).collect();
+        assert_eq!(words, vec!["runs", "Running", "run"]);
+        assert!(matches.iter().all(|m| m.term == "run"));
+    }
+
+    #[test]
+    fn expect_to_highlight_with_markers() {
+        let highlighter = Highlighter::default().markers("[", "]");
+        let highlighted = highlighter.highlight("dancers", "Alex was an excellent dancer, he loved dancing.").unwrap();
+
+        assert_eq!(highlighted, "Alex was an excellent [dancer], he loved dancing.");
+    }
+
+    #[test]
+    fn expect_to_generate_snippets() {
+        let document = "The café opened early. Many people came to see the dancers. \
+            After a long night the crowd left and nobody stayed. Later that week a dancer came back to the café.";
+        let highlighter = Highlighter::default()
+            .fragment_size(30)
+            .markers("*", "*");
+
+        let snippets = highlighter.snippets("dancer", document).unwrap();
+
+        assert_eq!(snippets.len(), 2);
+        assert!(snippets[0].text.contains("*dancers*"));
+        assert!(snippets[1].text.contains("*dancer*"));
+        for snippet in &snippets {
+            let fragment = &document[snippet.start..snippet.end];
+            assert_eq!(fragment.trim(), fragment);
+            assert_eq!(snippet.text.replace('*', ""), fragment);
+        }
+
+        let limited = highlighter.max_snippets(1).snippets("dancer", document).unwrap();
+        assert_eq!(limited.len(), 1);
+    }
+}
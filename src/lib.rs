@@ -1,10 +1,51 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
 mod stemmer;
+#[cfg(test)]
+mod testing;
 mod util;
+pub mod analyzer;
+pub mod batch;
 pub mod error;
+pub mod explain;
+pub mod filter;
+pub mod highlight;
+pub mod sentence;
+#[cfg(feature = "std")]
+pub mod stream;
+pub mod tokenizer;
 
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+use analyzer::{Analysis, ErrorMode};
 use error::SimmerError;
-use stemmer::Stemmer;
-use util::AsciiUtil;
+use explain::Explanation;
+use sentence::SentenceSplitter;
+use filter::{AsciiPunctuationFilter, ContractionFilter, ContractionMode, LowercaseFilter};
+use tokenizer::{SpecialTokenizer, Token};
+
+pub use analyzer::Analyzer;
+pub use stemmer::{
+    is_acronym,
+    restore_case,
+    CasePattern,
+    CasedStemmer,
+    CharRun,
+    LenientStemIter,
+    PorterStemmer,
+    Stem,
+    StemExt,
+    StemIter,
+    WordShape
+};
+#[cfg(feature = "std")]
+pub use stemmer::{CacheStats, CachedStemmer, SharedCachedStemmer};
+// the general path of the stemmer is only exposed for the benchmarks
+#[doc(hidden)]
+pub use stemmer::Stemmer;
 
 /// Get the stem from a word
 ///
@@ -12,34 +53,125 @@ use util::AsciiUtil;
 ///
 /// * `word` - &str
 pub fn stem(word: &str) -> Result<String, SimmerError> {
+    PorterStemmer.stem(word)
+}
+
+/// Explain each step taken by the porter stemmer to get the stem of a word
+///
+/// # Arguments
+///
+/// * `word` - &str
+pub fn stem_explain(word: &str) -> Result<Explanation, SimmerError> {
     let mut stemmer = Stemmer::new(&word.to_lowercase())?;
-    let res = stemmer.stem()?;
 
-    Ok(res)
+    stemmer.explain()
+}
+
+/// Build the analyzer used to stem a sentence
+fn build_sentence_analyzer() -> Analyzer {
+    Analyzer::builder()
+        .tokenizer(SpecialTokenizer::default())
+        .token_filter(ContractionFilter::new(ContractionMode::Expand))
+        .token_filter(AsciiPunctuationFilter)
+        .token_filter(LowercaseFilter)
+        .stemmer(PorterStemmer)
+        .build()
+}
+
+/// Get the analyzer used to stem a sentence
+#[cfg(feature = "std")]
+pub(crate) fn sentence_analyzer() -> &'static Analyzer {
+    static ANALYZER: OnceLock<Analyzer> = OnceLock::new();
+
+    ANALYZER.get_or_init(build_sentence_analyzer)
 }
 
-/// Stem a sentence by splitting the sentence by whitespace
-/// If the sentence contains ascii punctuation the word will be skipped
+/// Call a function with the analyzer used to stem a sentence. Without std the analyzer
+/// can't be shared by the calls which means that it's built on each call
+///
+/// # Arguments
+///
+/// * `f` - F
+fn with_sentence_analyzer<T, F: FnOnce(&Analyzer) -> T>(f: F) -> T {
+    #[cfg(feature = "std")]
+    {
+        f(sentence_analyzer())
+    }
+
+    #[cfg(not(feature = "std"))]
+    {
+        f(&build_sentence_analyzer())
+    }
+}
+
+/// Stem a sentence by splitting the sentence into words with the Unicode word boundaries rules (UAX #29)
+/// Numbers, urls, emails, hashtags and mentions are kept verbatim and are not stemmed
+/// The english contractions are expanded (i.e: don't -> do not) and the possessives are removed
+/// The ascii punctuation of each word is removed before stemming the word
 ///
 /// # Arguments
 ///
 /// * `sentence` - &str
 pub fn stem_sentence(sentence: &str) -> Result<Vec<String>, SimmerError> {
-    // split the sentence by spaces and remove words which has a punctuation
-    let words: Vec<String> = sentence.split_whitespace()
-        .map(|w| w.remove_ascii_punctuation())
+    let stemmed = stem_sentence_tokens(sentence)?
+        .into_iter()
+        .map(|token| match token.stem {
+            Some(stem) => stem,
+            None => token.text
+        })
         .collect();
 
-    let mut stemmed = Vec::new();
-    // call the stemmer for each words
-    for word in words {
-        let mut stemmer = Stemmer::new(&word.to_lowercase())?;
-        let stem = stemmer.stem()?;
+    Ok(stemmed)
+}
+
+/// Stem a sentence the same way as `stem_sentence` and handle the words which can't be stemmed depending on the ErrorMode
+///
+/// # Arguments
+///
+/// * `sentence` - &str
+/// * `mode` - ErrorMode
+pub fn stem_sentence_with(sentence: &str, mode: ErrorMode) -> Result<Analysis, SimmerError> {
+    with_sentence_analyzer(|analyzer| analyzer.analyze_with(sentence, mode))
+}
+
+/// Stem a sentence the same way as `stem_sentence` but return the tokens which keep the surface text,
+/// the normalized text, the stem, the byte offsets and the position of each word in the sentence
+///
+/// # Arguments
+///
+/// * `sentence` - &str
+pub fn stem_sentence_tokens(sentence: &str) -> Result<Vec<Token>, SimmerError> {
+    with_sentence_analyzer(|analyzer| analyzer.analyze(sentence))
+}
+
+/// Split a text into sentences and stem each sentence the same way as `stem_sentence_tokens`
+/// The offsets of the tokens refer to the text
+///
+/// # Arguments
+///
+/// * `text` - &str
+pub fn stem_sentences(text: &str) -> Result<Vec<Vec<Token>>, SimmerError> {
+    #[cfg(feature = "std")]
+    let splitter = {
+        static SPLITTER: OnceLock<SentenceSplitter> = OnceLock::new();
+        SPLITTER.get_or_init(SentenceSplitter::new)
+    };
+    #[cfg(not(feature = "std"))]
+    let splitter = &SentenceSplitter::new();
+
+    let mut sentences = Vec::new();
+
+    for sentence in splitter.split(text) {
+        let mut tokens = stem_sentence_tokens(sentence.text)?;
+        for token in tokens.iter_mut() {
+            token.start += sentence.start;
+            token.end += sentence.start;
+        }
 
-        stemmed.push(stem);
+        sentences.push(tokens);
     }
 
-    Ok(stemmed)
+    Ok(sentences)
 }
 
 #[cfg(test)]
@@ -80,6 +212,45 @@ mod tests {
         assert_eq!(stemmed, corrects);
     }
 
+    #[test]
+    fn expect_to_explain_stem() {
+        let explanation = stem_explain("Relational").unwrap();
+
+        assert_eq!(explanation.word, "relational");
+        assert_eq!(explanation.form(), "[r](e l)(a t)(io n)(a l)");
+        assert_eq!(explanation.measure, 4);
+        assert_eq!(explanation.stem, stem("relational").unwrap());
+
+        let step_two = &explanation.steps[3];
+        assert_eq!(step_two.output, "relate");
+        assert_eq!(step_two.rules[0].suffix, "ational");
+        assert!(step_two.rules[0].applied);
+
+        let intermediates: Vec<&str> = explanation.steps.iter().map(|s| s.output.as_str()).collect();
+        assert_eq!(intermediates, vec!["relational", "relational", "relational", "relate", "relate", "relate", "relat"]);
+    }
+
+    #[test]
+    fn expect_to_explain_rejected_rule() {
+        let explanation = stem_explain("feed").unwrap();
+        let rule = &explanation.steps[1].rules[0];
+
+        assert!(!rule.applied);
+        assert_eq!(rule.measure, Some(0));
+        assert_eq!(rule.to_string(), "(m>0) eed -> ee: rejected (m=0)");
+        assert!(explanation.to_string().ends_with("stem: feed"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn expect_to_serialize_explanation() {
+        let explanation = stem_explain("feed").unwrap();
+        let json = serde_json::to_string(&explanation).unwrap();
+        let deserialized: Explanation = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, explanation);
+    }
+
     #[test]
     fn expect_to_stem_sentence() {
         let sentence = "His eyes were dancing with humor.";
@@ -89,4 +260,70 @@ mod tests {
 
         assert_eq!(stem_sentence, "hi eye were danc with humor")
     }
+
+    #[test]
+    fn expect_lenient_modes_to_match_strict_mode_when_nothing_fail() {
+        // the words which reach the stemmer of stem_sentence can always be stemmed, the error modes
+        // are exercised with a failing stemmer in the tests of the analyzer
+        let sentence = "Alex aed dancing";
+        let expected = super::stem_sentence_tokens(sentence).unwrap();
+
+        for mode in [ErrorMode::Strict, ErrorMode::PassThrough, ErrorMode::Collect] {
+            let analysis = super::stem_sentence_with(sentence, mode).unwrap();
+
+            assert_eq!(analysis.tokens, expected);
+            assert!(analysis.errors.is_empty());
+        }
+    }
+
+    #[test]
+    fn expect_to_stem_sentence_tokens() {
+        let sentence = "His eyes -- were Dancing";
+        let tokens = super::stem_sentence_tokens(sentence).unwrap();
+
+        let eyes = &tokens[1];
+        assert_eq!(eyes.surface, "eyes");
+        assert_eq!(eyes.stem, Some("eye".to_string()));
+        assert_eq!(&sentence[eyes.start..eyes.end], "eyes");
+        assert_eq!(eyes.position, 1);
+
+        let dancing = &tokens[3];
+        assert_eq!(dancing.surface, "Dancing");
+        assert_eq!(dancing.text, "dancing");
+        assert_eq!(dancing.stem, Some("danc".to_string()));
+        assert_eq!(super::stem_sentence(sentence).unwrap(), vec!["hi", "eye", "were", "danc"]);
+    }
+
+    #[test]
+    fn expect_to_expand_contractions_before_stemming() {
+        let stems = super::stem_sentence("John's dogs don’t bark, it's quiet").unwrap();
+
+        assert_eq!(stems, vec!["john", "dog", "do", "not", "bark", "it", "i", "quiet"]);
+    }
+
+    #[test]
+    fn expect_to_keep_special_tokens_in_sentence() {
+        let stems = super::stem_sentence("@Alice visited https://example.com/items for 3.14 dollars").unwrap();
+
+        assert_eq!(stems, vec!["@Alice", "visit", "https://example.com/items", "for", "3.14", "dollar"]);
+    }
+
+    #[test]
+    fn expect_to_stem_each_sentence() {
+        let text = "Dr. Smith was dancing. The eyes were dancing too.";
+        let sentences = super::stem_sentences(text).unwrap();
+
+        assert_eq!(sentences.len(), 2);
+        let eyes = &sentences[1][1];
+        assert_eq!(eyes.term(), "eye");
+        assert_eq!(&text[eyes.start..eyes.end], "eyes");
+        assert_eq!(eyes.position, 1);
+    }
+
+    #[test]
+    fn expect_to_split_sentence_on_unicode_word_boundaries() {
+        let stems = super::stem_sentence("dancing,humor\u{00A0}eyes\u{3000}flies").unwrap();
+
+        assert_eq!(stems, vec!["danc", "humor", "eye", "fli"]);
+    }
 }
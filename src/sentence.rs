@@ -0,0 +1,232 @@
+use alloc::{collections::BTreeSet, string::{String, ToString}, vec::Vec};
+
+// Constant
+const ABBREVIATIONS: [&str; 52] = [
+    "mr", "mrs", "ms", "dr", "prof", "sr", "jr", "st", "mt", "rev", "gen", "gov", "sen", "rep",
+    "capt", "col", "lt", "sgt", "messrs", "vs", "etc", "e.g", "i.e", "cf", "approx", "dept",
+    "fig", "vol", "inc", "ltd", "corp", "ave", "blvd", "rd", "a.m", "p.m",
+    "u.s", "u.k", "u.n", "jan", "feb", "mar", "apr", "jun", "jul", "aug", "sep", "sept", "oct",
+    "nov", "dec", "ph.d"
+];
+// common words which are only abbreviations before a number. i.e: "No. 5", "est. 1998"
+const NUMBER_ABBREVIATIONS: [&str; 2] = ["no", "est"];
+const TERMINATORS: [char; 4] = ['.', '!', '?', '…'];
+const CLOSERS: [char; 8] = ['"', '\'', '”', '’', ')', ']', '}', '»'];
+const OPENERS: [char; 8] = ['"', '\'', '“', '‘', '(', '[', '{', '«'];
+
+/// A sentence of a text with its byte offsets in the text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sentence<'a> {
+    pub text: &'a str,
+    pub start: usize,
+    pub end: usize
+}
+
+/// Split a text into sentences. The splitter handle
+///
+/// - abbreviations (Dr., e.g., U.S.) and initials (J. Smith) which don't end a sentence
+/// - words which are only abbreviations in their context (No. 5, et al.) and end a sentence otherwise
+/// - decimals (3.14) and ellipses which are only a boundary when the next sentence start with an uppercase letter
+/// - quotes and closing brackets which belong to the sentence they close
+#[derive(Debug, Clone)]
+pub struct SentenceSplitter {
+    abbreviations: BTreeSet<String>
+}
+
+impl Default for SentenceSplitter {
+    fn default() -> Self {
+        SentenceSplitter {
+            abbreviations: ABBREVIATIONS.iter().map(|a| a.to_string()).collect()
+        }
+    }
+}
+
+impl SentenceSplitter {
+    /// Create a new SentenceSplitter with the embedded list of english abbreviations
+    pub fn new() -> SentenceSplitter {
+        SentenceSplitter::default()
+    }
+
+    /// Add abbreviations to the list of abbreviations. Abbreviations are case insensitive and written without the final period. i.e: "dr", "e.g"
+    ///
+    /// # Arguments
+    ///
+    /// * `abbreviations` - I
+    pub fn abbreviations<I, S>(mut self, abbreviations: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>
+    {
+        self.abbreviations.extend(
+            abbreviations
+                .into_iter()
+                .map(|a| a.as_ref().trim_end_matches('.').to_lowercase())
+        );
+
+        self
+    }
+
+    /// Whether the word before a period is an abbreviation or an initial
+    ///
+    /// # Arguments
+    ///
+    /// * `before` - &str
+    /// * `next` - char
+    fn is_abbreviation(&self, before: &str, next: char) -> bool {
+        let mut words = before.rsplit(char::is_whitespace);
+        let word = words.next().unwrap_or("").trim_start_matches(OPENERS);
+        let mut chars = word.chars();
+        let is_initial = matches!((chars.next(), chars.next()), (Some(c), None) if c.is_uppercase());
+
+        let word = word.to_lowercase();
+        let is_contextual = match word.as_str() {
+            w if NUMBER_ABBREVIATIONS.contains(&w) => next.is_numeric(),
+            "al" => words.find(|w| !w.is_empty()).is_some_and(|w| w.eq_ignore_ascii_case("et")),
+            _ => false
+        };
+
+        is_initial || is_contextual || self.abbreviations.contains(&word)
+    }
+
+    /// Split the text into sentences
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - &'a str
+    pub fn split<'a>(&self, text: &'a str) -> Vec<Sentence<'a>> {
+        let mut sentences = Vec::new();
+        let chars: Vec<(usize, char)> = text.char_indices().collect();
+        let mut start = 0;
+        let mut idx = 0;
+
+        while idx < chars.len() {
+            let (offset, c) = chars[idx];
+            if !TERMINATORS.contains(&c) {
+                idx += 1;
+                continue;
+            }
+
+            // consume the run of terminators such as ?! or ... and the closing quotes or brackets
+            let mut end_idx = idx;
+            while end_idx + 1 < chars.len() && TERMINATORS.contains(&chars[end_idx + 1].1) {
+                end_idx += 1;
+            }
+            while end_idx + 1 < chars.len() && CLOSERS.contains(&chars[end_idx + 1].1) {
+                end_idx += 1;
+            }
+
+            let end = chars[end_idx].0 + chars[end_idx].1.len_utf8();
+            let is_single_period = c == '.' && (end_idx == idx || !TERMINATORS.contains(&chars[idx + 1].1));
+            let is_ellipsis = c == '…' || (end_idx > idx && chars[idx + 1].1 == '.');
+
+            // a boundary must be followed by whitespace and by the start of a new sentence
+            let next = chars[end_idx + 1..].iter().map(|(_, c)| *c);
+            let mut has_space = false;
+            let mut next_char = None;
+            for n in next {
+                match n {
+                    n if n.is_whitespace() => has_space = true,
+                    n if OPENERS.contains(&n) => {},
+                    n => {
+                        next_char = Some(n);
+                        break;
+                    }
+                }
+            }
+
+            let is_boundary = match next_char {
+                None => true,
+                Some(_) if !has_space => false,
+                Some(n) if is_single_period && self.is_abbreviation(&text[..offset], n) => false,
+                Some(n) if is_ellipsis || is_single_period => n.is_uppercase() || n.is_numeric(),
+                Some(n) => !n.is_lowercase()
+            };
+
+            if is_boundary {
+                push_sentence(text, start, end, &mut sentences);
+                start = end;
+            }
+
+            idx = end_idx + 1;
+        }
+
+        push_sentence(text, start, text.len(), &mut sentences);
+
+        sentences
+    }
+}
+
+/// Push the trimmed sentence if it isn't empty
+///
+/// # Arguments
+///
+/// * `text` - &'a str
+/// * `start` - usize
+/// * `end` - usize
+/// * `sentences` - &mut Vec<Sentence<'a>>
+fn push_sentence<'a>(text: &'a str, start: usize, end: usize, sentences: &mut Vec<Sentence<'a>>) {
+    let slice = &text[start..end];
+    let trimmed_start = start + slice.len() - slice.trim_start().len();
+    let trimmed = slice.trim();
+
+    if !trimmed.is_empty() {
+        sentences.push(Sentence {
+            text: trimmed,
+            start: trimmed_start,
+            end: trimmed_start + trimmed.len()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(text: &str) -> Vec<&str> {
+        SentenceSplitter::new().split(text).iter().map(|s| s.text).collect()
+    }
+
+    #[test]
+    fn expect_to_split_sentences() {
+        let sentences = split("His eyes were dancing with humor. Alex was an excellent dancer! Was he?");
+
+        assert_eq!(sentences, vec!["His eyes were dancing with humor.", "Alex was an excellent dancer!", "Was he?"]);
+    }
+
+    #[test]
+    fn expect_to_handle_abbreviations_and_decimals() {
+        let sentences = split("Dr. Smith paid 3.14 dollars, e.g. for the U.S. Army. J. Doe agreed.");
+
+        assert_eq!(sentences, vec!["Dr. Smith paid 3.14 dollars, e.g. for the U.S. Army.", "J. Doe agreed."]);
+    }
+
+    #[test]
+    fn expect_to_handle_abbreviations_in_context() {
+        let sentences = split("See No. 5 by Smith et al. Then I said no. It was co. The shop est. 1998 was the best. Fine");
+
+        assert_eq!(sentences, vec![
+            "See No. 5 by Smith et al. Then I said no.",
+            "It was co.",
+            "The shop est. 1998 was the best.",
+            "Fine"
+        ]);
+    }
+
+    #[test]
+    fn expect_to_handle_ellipses_quotes_and_brackets() {
+        let sentences = split("Wait... and then? \"It works!\" (He left.) The end…  Really");
+
+        assert_eq!(sentences, vec!["Wait... and then?", "\"It works!\"", "(He left.)", "The end…", "Really"]);
+    }
+
+    #[test]
+    fn expect_to_keep_offsets_and_custom_abbreviations() {
+        let text = "  See approx. Fig. Two. Cust. Smith left. ";
+        let splitter = SentenceSplitter::new().abbreviations(["Cust."]);
+        let sentences = splitter.split(text);
+
+        assert_eq!(sentences.len(), 2);
+        assert_eq!(&text[sentences[1].start..sentences[1].end], sentences[1].text);
+        assert_eq!(sentences[1].text, "Cust. Smith left.");
+    }
+}
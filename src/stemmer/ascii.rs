@@ -0,0 +1,339 @@
+use alloc::string::String;
+use super::{
+    steps::{SUFFIX_FOUR, SUFFIX_THREE, SUFFIX_TWO},
+    suffix::SuffixTrie,
+    AVOID_CONSONENTS
+};
+
+// Constant
+// longer words are processed by the general path
+pub(crate) const MAX_ASCII_LEN: usize = 64;
+const VOWELS: [u8; 6] = [b'a', b'e', b'i', b'o', b'u', b'y'];
+
+/// Whether a byte is a consonent. Any byte which isn't a consonent is a vowel like in Kind::from
+///
+/// # Arguments
+///
+/// * `b` - u8
+fn is_consonent(b: u8) -> bool {
+    b.is_ascii_lowercase() && !VOWELS.contains(&b)
+}
+
+/// Porter stemmer working in place on the bytes of an ascii word. It produces the same stems as the Stemmer
+/// including the measure which is computed on the word which was measured last in steps 2, 3 and 5b
+struct AsciiStemmer {
+    word: [u8; MAX_ASCII_LEN],
+    len: usize,
+    // measure of the first n bytes at the index n which are valid up to computed
+    measures: [u8; MAX_ASCII_LEN + 1],
+    computed: usize,
+    // measure of the word which was measured last
+    measure: u8
+}
+
+impl AsciiStemmer {
+    /// Create a new AsciiStemmer from a lowercased ascii word
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - &[u8]
+    fn new(word: &[u8]) -> AsciiStemmer {
+        let mut stemmer = AsciiStemmer {
+            word: [0; MAX_ASCII_LEN],
+            len: word.len(),
+            measures: [0; MAX_ASCII_LEN + 1],
+            computed: 0,
+            measure: 0
+        };
+        stemmer.word[..word.len()].copy_from_slice(word);
+        stemmer.measure = stemmer.measure_prefix(word.len());
+
+        stemmer
+    }
+
+    /// Get the current word
+    fn word(&self) -> &[u8] {
+        &self.word[..self.len]
+    }
+
+    /// Get the measure of the first len bytes of the word
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - usize
+    fn measure_prefix(&mut self, len: usize) -> u8 {
+        while self.computed < len {
+            let idx = self.computed;
+            let vc = idx > 0 && !is_consonent(self.word[idx - 1]) && is_consonent(self.word[idx]);
+            self.measures[idx + 1] = self.measures[idx] + vc as u8;
+            self.computed += 1;
+        }
+
+        self.measures[len]
+    }
+
+    /// Truncate the word to len bytes and measure the truncated word. The bytes after len are kept
+    /// in order to restore the original word
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - usize
+    fn measure_truncated(&mut self, len: usize) -> u8 {
+        self.len = len;
+        self.measure = self.measure_prefix(len);
+
+        self.measure
+    }
+
+    /// Append a suffix at the end of the word
+    ///
+    /// # Arguments
+    ///
+    /// * `suffix` - &[u8]
+    fn push(&mut self, suffix: &[u8]) {
+        self.word[self.len..self.len + suffix.len()].copy_from_slice(suffix);
+        self.computed = self.computed.min(self.len);
+        self.len += suffix.len();
+    }
+
+    /// Get the length of a word once a suffix is removed as many times as the word end with it like trim_end_matches
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - &[u8]
+    /// * `suffix` - &[u8]
+    fn trim_len(word: &[u8], suffix: &[u8]) -> usize {
+        let mut len = word.len();
+        while word[..len].ends_with(suffix) {
+            len -= suffix.len();
+        }
+
+        len
+    }
+
+    /// Whether the word end with a double consonent (*d)
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - &[u8]
+    fn end_with_double_consonent(word: &[u8]) -> bool {
+        match word {
+            [.., a, b] => a == b && is_consonent(*a),
+            _ => false
+        }
+    }
+
+    /// Whether the word end with consonent -> vowel -> consonent where the last consonent is not W, X or Y (*o)
+    fn check_cvc_pattern(&self) -> bool {
+        match self.word() {
+            [.., c1, v, c2] => {
+                is_consonent(*c1) && !is_consonent(*v) && is_consonent(*c2) &&
+                    !AVOID_CONSONENTS.contains(&(*c2 as char))
+            },
+            _ => false
+        }
+    }
+
+    // Step 1a
+    fn step_one_a(&mut self) {
+        let word = self.word();
+        if word.ends_with(b"sses") || word.ends_with(b"ies") {
+            self.len -= 2;
+        } else if !word.ends_with(b"ss") && word.ends_with(b"s") {
+            self.len -= 1;
+        }
+    }
+
+    // Step 1b
+    fn step_one_b(&mut self) {
+        if self.word().ends_with(b"eed") {
+            let original = self.len;
+            let trimmed = Self::trim_len(self.word(), b"eed");
+            if self.measure_truncated(trimmed) > 0 {
+                self.push(b"ee");
+            } else {
+                self.len = original;
+            }
+
+            return;
+        }
+
+        for suffix in [&b"ed"[..], &b"ing"[..]] {
+            if !self.word().ends_with(suffix) {
+                continue;
+            }
+
+            let trimmed = Self::trim_len(self.word(), suffix);
+            if self.word[..trimmed].iter().any(|b| VOWELS.contains(b)) {
+                self.step_one_b_intermediary(trimmed);
+                return;
+            }
+        }
+    }
+
+    fn step_one_b_intermediary(&mut self, trimmed: usize) {
+        let stem = &self.word[..trimmed];
+        if stem.ends_with(b"at") || stem.ends_with(b"bl") || stem.ends_with(b"iz") {
+            self.len = trimmed;
+            self.push(b"e");
+            return;
+        }
+
+        if Self::end_with_double_consonent(stem) && !matches!(stem.last(), Some(b'l' | b's' | b'z')) {
+            self.len = trimmed - 1;
+            return;
+        }
+
+        // the word stay truncated even if the condition doesn't match
+        if self.measure_truncated(trimmed) == 1 && self.check_cvc_pattern() {
+            self.push(b"e");
+        }
+    }
+
+    // Step 1c
+    fn step_one_c(&mut self) {
+        if self.word().ends_with(b"y") {
+            self.len = Self::trim_len(self.word(), b"y");
+            self.push(b"i");
+        }
+    }
+
+    // Step 2 and 3
+    fn step_two_and_three<const N: usize>(&mut self, rules: &SuffixTrie<N>) {
+        if let Some((rule, replacement)) = rules.longest_match(self.word()) {
+            if self.measure > 0 {
+                self.len -= rule.len();
+                self.push(replacement.as_bytes());
+            }
+        }
+    }
+
+    // Step 4
+    fn step_four(&mut self) {
+        let original = self.len;
+        if let Some((rule, _)) = SUFFIX_FOUR.longest_match(self.word()) {
+            if self.measure_truncated(original - rule.len()) <= 1 {
+                self.len = original;
+            }
+
+            return;
+        }
+
+        if self.word().ends_with(b"ion") {
+            let measure = self.measure_truncated(original - b"ion".len());
+            let end_with_st = matches!(self.word().last(), Some(b's' | b't'));
+            if measure <= 1 || !end_with_st {
+                self.len = original;
+            }
+        }
+    }
+
+    // Step 5
+    fn step_five(&mut self) {
+        let original = self.len;
+
+        // Step 5a
+        if self.word().ends_with(b"e") {
+            let measure = self.measure_truncated(original - 1);
+            if measure > 1 || (measure == 1 && !self.check_cvc_pattern()) {
+                return;
+            }
+
+            self.len = original;
+        }
+
+        // Step 5b
+        if self.measure > 1 && Self::end_with_double_consonent(self.word()) && self.word().ends_with(b"l") {
+            self.len -= 1;
+        }
+    }
+}
+
+/// Stem an ascii word without allocating until the stem is returned. None is returned when the word
+/// isn't ascii or is longer than MAX_ASCII_LEN in which case the general path must be used
+///
+/// # Arguments
+///
+/// * `word` - &[u8]
+pub(crate) fn stem(word: &[u8]) -> Option<String> {
+    let mut out = String::new();
+
+    stem_into(word, &mut out).then_some(out)
+}
+
+/// Stem an ascii word and push the stem into the output without allocating. False is returned when the word
+/// isn't ascii or is longer than MAX_ASCII_LEN in which case nothing is pushed and the general path must be used
+///
+/// # Arguments
+///
+/// * `word` - &[u8]
+/// * `out` - &mut String
+pub(crate) fn stem_into(word: &[u8], out: &mut String) -> bool {
+    if !word.is_ascii() || word.len() > MAX_ASCII_LEN {
+        return false;
+    }
+
+    let mut lowercased = [0; MAX_ASCII_LEN];
+    lowercased[..word.len()].copy_from_slice(word);
+    lowercased[..word.len()].make_ascii_lowercase();
+
+    let mut stemmer = AsciiStemmer::new(&lowercased[..word.len()]);
+    stemmer.step_one_a();
+    stemmer.step_one_b();
+    stemmer.step_one_c();
+    stemmer.step_two_and_three(&SUFFIX_TWO);
+    stemmer.step_two_and_three(&SUFFIX_THREE);
+    stemmer.step_four();
+    stemmer.step_five();
+
+    // the bytes are ascii which means that they are valid utf8
+    match core::str::from_utf8(stemmer.word()) {
+        Ok(stem) => {
+            out.push_str(stem);
+            true
+        },
+        Err(_) => false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stemmer::Stemmer;
+
+    const VOCABULARY: &str = include_str!("../../tests/data/voc.txt");
+
+    fn general(word: &str) -> String {
+        Stemmer::new(&word.to_lowercase()).unwrap().stem().unwrap()
+    }
+
+    #[test]
+    fn expect_same_output_as_general_path_on_vocabulary() {
+        for word in VOCABULARY.lines() {
+            assert_eq!(stem(word.as_bytes()).as_deref(), Some(general(word).as_str()), "{word}");
+
+            let upper = word.to_uppercase();
+            assert_eq!(stem(upper.as_bytes()).as_deref(), Some(general(&upper).as_str()), "{upper}");
+        }
+    }
+
+    #[test]
+    fn expect_same_output_on_edge_cases() {
+        let words = [
+            "", "s", "ss", "y", "yy", "sayy", "singing", "eed", "feedeed", "agreed", "bleed", "filing",
+            "hopping", "falling", "aed", "ion", "connection", "adoption", "rate", "cease", "controll",
+            "roll", "a1b2c3ing", "don't", "e-mail", "it's", "generalizations"
+        ];
+
+        for word in words {
+            assert_eq!(stem(word.as_bytes()).as_deref(), Some(general(word).as_str()), "{word}");
+        }
+    }
+
+    #[test]
+    fn expect_to_fallback_on_general_path() {
+        assert_eq!(stem("naïve".as_bytes()), None);
+        assert_eq!(stem("a".repeat(MAX_ASCII_LEN + 1).as_bytes()), None);
+        assert!(stem("a".repeat(MAX_ASCII_LEN).as_bytes()).is_some());
+    }
+}
@@ -0,0 +1,421 @@
+use std::{
+    collections::{hash_map::RandomState, HashMap},
+    hash::BuildHasher,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex
+    }
+};
+use crate::error::SimmerError;
+use super::{PorterStemmer, Stem};
+
+// Constant
+const DEFAULT_SHARDS: usize = 16;
+
+/// Statistics of a stemmer cache
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Number of words found in the cache
+    pub hits: u64,
+    /// Number of words which had to be stemmed
+    pub misses: u64,
+    /// Number of words in the cache
+    pub len: usize,
+    /// Maximum number of words in the cache
+    pub capacity: usize
+}
+
+impl CacheStats {
+    /// Get the ratio of words found in the cache
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            total => self.hits as f64 / total as f64
+        }
+    }
+}
+
+/// Entry of the ClockCache
+#[derive(Debug, Clone)]
+struct ClockEntry {
+    word: String,
+    stem: String,
+    // set when the entry is read and cleared when the hand pass over the entry
+    referenced: bool
+}
+
+/// Cache bounded to a capacity which evict the entries with the CLOCK algorithm. The entries are stored in a ring
+/// and the hand evict the first entry which hasn't been read since the last time the hand passed over it
+#[derive(Debug, Clone)]
+struct ClockCache {
+    entries: Vec<ClockEntry>,
+    index: HashMap<String, usize>,
+    hand: usize,
+    capacity: usize
+}
+
+impl ClockCache {
+    /// Create a new ClockCache
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - usize
+    fn new(capacity: usize) -> ClockCache {
+        ClockCache {
+            entries: Vec::with_capacity(capacity),
+            index: HashMap::with_capacity(capacity),
+            hand: 0,
+            capacity
+        }
+    }
+
+    /// Get the stem of a word and mark the entry as referenced
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - &str
+    fn get(&mut self, word: &str) -> Option<String> {
+        let idx = *self.index.get(word)?;
+        let entry = &mut self.entries[idx];
+        entry.referenced = true;
+
+        Some(entry.stem.clone())
+    }
+
+    /// Insert the stem of a word and evict an entry if the cache is full
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - &str
+    /// * `stem` - &str
+    fn insert(&mut self, word: &str, stem: &str) {
+        if self.capacity == 0 || self.index.contains_key(word) {
+            return;
+        }
+
+        let entry = ClockEntry {
+            word: word.to_string(),
+            stem: stem.to_string(),
+            referenced: false
+        };
+
+        if self.entries.len() < self.capacity {
+            self.index.insert(entry.word.clone(), self.entries.len());
+            self.entries.push(entry);
+            return;
+        }
+
+        // give a second chance to the entries which have been read
+        while self.entries[self.hand].referenced {
+            self.entries[self.hand].referenced = false;
+            self.hand = (self.hand + 1) % self.capacity;
+        }
+
+        self.index.remove(&self.entries[self.hand].word);
+        self.index.insert(entry.word.clone(), self.hand);
+        self.entries[self.hand] = entry;
+        self.hand = (self.hand + 1) % self.capacity;
+    }
+
+    /// Remove every entries
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.index.clear();
+        self.hand = 0;
+    }
+}
+
+/// Stemmer which memoize the stems of the inner stemmer in a cache bounded to a number of words.
+/// The cache can't be shared between threads, use the SharedCachedStemmer in this case.
+/// As its stem method takes &mut self it doesn't implement Stem and can't be given to `AnalyzerBuilder::stemmer`,
+/// the SharedCachedStemmer implement Stem and can be used by an Analyzer
+#[derive(Debug, Clone)]
+pub struct CachedStemmer<S: Stem = PorterStemmer> {
+    inner: S,
+    cache: ClockCache,
+    hits: u64,
+    misses: u64
+}
+
+impl<S: Stem> CachedStemmer<S> {
+    /// Create a new CachedStemmer
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - S
+    /// * `capacity` - usize
+    pub fn new(inner: S, capacity: usize) -> CachedStemmer<S> {
+        CachedStemmer {
+            inner,
+            cache: ClockCache::new(capacity),
+            hits: 0,
+            misses: 0
+        }
+    }
+
+    /// Get the stem of a word from the cache or from the inner stemmer. The errors aren't cached
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - &str
+    pub fn stem(&mut self, word: &str) -> Result<String, SimmerError> {
+        if let Some(stem) = self.cache.get(word) {
+            self.hits += 1;
+            return Ok(stem);
+        }
+
+        self.misses += 1;
+        let stem = self.inner.stem(word)?;
+        self.cache.insert(word, &stem);
+
+        Ok(stem)
+    }
+
+    /// Stem a list of words in order to fill the cache. i.e: with the most frequent words of a corpus
+    /// The statistics aren't updated
+    ///
+    /// # Arguments
+    ///
+    /// * `words` - I
+    pub fn prewarm<I, W>(&mut self, words: I) -> Result<(), SimmerError>
+    where
+        I: IntoIterator<Item = W>,
+        W: AsRef<str>
+    {
+        for word in words {
+            let word = word.as_ref();
+            if self.cache.get(word).is_none() {
+                let stem = self.inner.stem(word)?;
+                self.cache.insert(word, &stem);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get the statistics of the cache
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits,
+            misses: self.misses,
+            len: self.cache.entries.len(),
+            capacity: self.cache.capacity
+        }
+    }
+
+    /// Remove the words of the cache and reset the statistics
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+}
+
+/// Thread safe version of the CachedStemmer. The cache is split into shards which are locked independently
+/// so that many threads can stem words at the same time
+#[derive(Debug)]
+pub struct SharedCachedStemmer<S: Stem = PorterStemmer> {
+    inner: S,
+    shards: Vec<Mutex<ClockCache>>,
+    hasher: RandomState,
+    hits: AtomicU64,
+    misses: AtomicU64
+}
+
+impl<S: Stem> SharedCachedStemmer<S> {
+    /// Create a new SharedCachedStemmer with 16 shards
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - S
+    /// * `capacity` - usize
+    pub fn new(inner: S, capacity: usize) -> SharedCachedStemmer<S> {
+        SharedCachedStemmer::with_shards(inner, capacity, DEFAULT_SHARDS)
+    }
+
+    /// Create a new SharedCachedStemmer. The capacity is split evenly between the shards and the remainder is given
+    /// to the first shards which means that the cache never hold more than capacity words
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - S
+    /// * `capacity` - usize
+    /// * `shards` - usize
+    pub fn with_shards(inner: S, capacity: usize, shards: usize) -> SharedCachedStemmer<S> {
+        let shards = shards.max(1);
+        let (shard_capacity, remainder) = (capacity / shards, capacity % shards);
+
+        SharedCachedStemmer {
+            inner,
+            shards: (0..shards)
+                .map(|idx| Mutex::new(ClockCache::new(shard_capacity + (idx < remainder) as usize)))
+                .collect(),
+            hasher: RandomState::new(),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0)
+        }
+    }
+
+    /// Get the shard which store a word
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - &str
+    fn shard(&self, word: &str) -> &Mutex<ClockCache> {
+        let idx = self.hasher.hash_one(word) as usize % self.shards.len();
+
+        &self.shards[idx]
+    }
+
+    /// Stem a list of words in order to fill the cache. The statistics aren't updated
+    ///
+    /// # Arguments
+    ///
+    /// * `words` - I
+    pub fn prewarm<I, W>(&self, words: I) -> Result<(), SimmerError>
+    where
+        I: IntoIterator<Item = W>,
+        W: AsRef<str>
+    {
+        for word in words {
+            let word = word.as_ref();
+            let stem = self.inner.stem(word)?;
+            // a poisoned shard only means that another thread panicked while holding the lock
+            let mut shard = self.shard(word).lock().unwrap_or_else(|err| err.into_inner());
+            shard.insert(word, &stem);
+        }
+
+        Ok(())
+    }
+
+    /// Get the statistics of the cache
+    pub fn stats(&self) -> CacheStats {
+        let mut stats = CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            ..CacheStats::default()
+        };
+
+        for shard in &self.shards {
+            let shard = shard.lock().unwrap_or_else(|err| err.into_inner());
+            stats.len += shard.entries.len();
+            stats.capacity += shard.capacity;
+        }
+
+        stats
+    }
+
+    /// Remove the words of the cache and reset the statistics
+    pub fn clear(&self) {
+        for shard in &self.shards {
+            shard.lock().unwrap_or_else(|err| err.into_inner()).clear();
+        }
+
+        self.hits.store(0, Ordering::Relaxed);
+        self.misses.store(0, Ordering::Relaxed);
+    }
+}
+
+impl<S: Stem> Stem for SharedCachedStemmer<S> {
+    fn stem(&self, word: &str) -> Result<String, SimmerError> {
+        let shard = self.shard(word);
+        if let Some(stem) = shard.lock().unwrap_or_else(|err| err.into_inner()).get(word) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(stem);
+        }
+
+        // the lock isn't held while stemming so that the other words of the shard can be read
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let stem = self.inner.stem(word)?;
+        shard.lock().unwrap_or_else(|err| err.into_inner()).insert(word, &stem);
+
+        Ok(stem)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_to_count_hits_and_misses() {
+        let mut stemmer = CachedStemmer::new(PorterStemmer, 10);
+
+        for word in ["dancing", "humor", "dancing", "dancing"] {
+            assert_eq!(stemmer.stem(word).unwrap(), PorterStemmer.stem(word).unwrap());
+        }
+
+        let stats = stemmer.stats();
+        assert_eq!((stats.hits, stats.misses, stats.len), (2, 2, 2));
+        assert_eq!(stats.hit_rate(), 0.5);
+    }
+
+    #[test]
+    fn expect_to_bound_and_evict_unreferenced_words() {
+        let mut stemmer = CachedStemmer::new(PorterStemmer, 2);
+
+        stemmer.stem("dancing").unwrap();
+        stemmer.stem("humor").unwrap();
+        // dancing is referenced which gives it a second chance
+        stemmer.stem("dancing").unwrap();
+        stemmer.stem("eyes").unwrap();
+        stemmer.stem("eyes").unwrap();
+
+        assert_eq!(stemmer.stats().len, 2);
+        assert_eq!(stemmer.stats().hits, 2);
+
+        stemmer.clear();
+        assert_eq!(stemmer.stats(), CacheStats { capacity: 2, ..CacheStats::default() });
+    }
+
+    #[test]
+    fn expect_to_prewarm_cache() {
+        let mut stemmer = CachedStemmer::new(PorterStemmer, 100);
+        stemmer.prewarm(["dancing", "humor"]).unwrap();
+
+        stemmer.stem("humor").unwrap();
+
+        assert_eq!(stemmer.stats().hits, 1);
+        assert_eq!(stemmer.stats().misses, 0);
+    }
+
+    #[test]
+    fn expect_shared_cache_to_be_used_by_many_threads() {
+        let stemmer = SharedCachedStemmer::with_shards(PorterStemmer, 64, 4);
+        stemmer.prewarm(["dancing"]).unwrap();
+
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for word in ["dancing", "humor", "excellent", "humor"] {
+                        assert_eq!(stemmer.stem(word).unwrap(), PorterStemmer.stem(word).unwrap());
+                    }
+                });
+            }
+        });
+
+        let stats = stemmer.stats();
+        assert_eq!(stats.hits + stats.misses, 16);
+        assert!(stats.hits >= 8);
+        assert_eq!(stats.len, 3);
+        assert_eq!(stats.capacity, 64);
+    }
+
+    #[test]
+    fn expect_shared_cache_to_not_exceed_capacity() {
+        let words = ["dancing", "humor", "excellent", "eyes", "were", "dancer", "running", "generalizations"];
+
+        for (capacity, shards) in [(1, 16), (10, 16), (10, 3), (0, 4)] {
+            let stemmer = SharedCachedStemmer::with_shards(PorterStemmer, capacity, shards);
+            for _ in 0..4 {
+                for word in words {
+                    stemmer.stem(word).unwrap();
+                }
+            }
+
+            let stats = stemmer.stats();
+            assert_eq!(stats.capacity, capacity, "capacity {capacity}, shards {shards}");
+            assert!(stats.len <= capacity, "capacity {capacity}, shards {shards}");
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use alloc::string::{String, ToString};
+use crate::error::SimmerError;
+use super::{PorterStemmer, Stem};
+
+/// Casing pattern of a word
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CasePattern {
+    /// i.e: excellent
+    Lower,
+    /// i.e: Excellent
+    Title,
+    /// i.e: NASA
+    Upper,
+    /// Any other pattern which is restored character by character. i.e: iPhones
+    Mixed
+}
+
+impl CasePattern {
+    /// Get the casing pattern of a word
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - &str
+    pub fn of(word: &str) -> CasePattern {
+        let uppers = word.chars().filter(|c| c.is_uppercase()).count();
+        let lowers = word.chars().filter(|c| c.is_lowercase()).count();
+        let first_upper = word.chars().next().map(char::is_uppercase).unwrap_or(false);
+
+        match (uppers, lowers) {
+            (0, _) => CasePattern::Lower,
+            (1, _) if first_upper => CasePattern::Title,
+            (2.., 0) => CasePattern::Upper,
+            _ => CasePattern::Mixed
+        }
+    }
+
+    /// Apply the casing pattern of the original word on a lowercased stem
+    ///
+    /// # Arguments
+    ///
+    /// * `original` - &str
+    /// * `stem` - &str
+    pub fn apply(&self, original: &str, stem: &str) -> String {
+        match self {
+            CasePattern::Lower => stem.to_string(),
+            CasePattern::Upper => stem.to_uppercase(),
+            CasePattern::Title => {
+                let mut chars = stem.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect(),
+                    None => String::new()
+                }
+            },
+            CasePattern::Mixed => {
+                let mut pattern = original.chars();
+                stem.chars()
+                    .map(|c| match pattern.next() {
+                        Some(p) if p.is_uppercase() => c.to_uppercase().to_string(),
+                        _ => c.to_string()
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Whether a word is an acronym which is a word of at least two letters written in uppercase. i.e: US, AIDS
+///
+/// # Arguments
+///
+/// * `word` - &str
+pub fn is_acronym(word: &str) -> bool {
+    CasePattern::of(word) == CasePattern::Upper && word.chars().all(|c| c.is_alphabetic() || c == '.')
+}
+
+/// Map a lowercased stem back onto the casing of the original word. i.e: (Excellent, excel) -> Excel
+///
+/// # Arguments
+///
+/// * `original` - &str
+/// * `stem` - &str
+pub fn restore_case(original: &str, stem: &str) -> String {
+    CasePattern::of(original).apply(original, stem)
+}
+
+/// Stemmer which restore the casing of the original word on the stem produced by the inner stemmer.
+/// Meant to display the stems, i.e: in an autocomplete. The stems shouldn't be indexed as their casing vary
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CasedStemmer<S: Stem = PorterStemmer> {
+    inner: S,
+    acronyms: bool
+}
+
+impl<S: Stem> CasedStemmer<S> {
+    /// Create a new CasedStemmer
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - S
+    pub fn new(inner: S) -> CasedStemmer<S> {
+        CasedStemmer {
+            inner,
+            acronyms: false
+        }
+    }
+
+    /// Leave the acronyms unstemmed. Otherwise US is stemmed to U and AIDS to AID
+    ///
+    /// # Arguments
+    ///
+    /// * `acronyms` - bool
+    pub fn acronyms(mut self, acronyms: bool) -> Self {
+        self.acronyms = acronyms;
+        self
+    }
+}
+
+impl<S: Stem> Stem for CasedStemmer<S> {
+    fn stem(&self, word: &str) -> Result<String, SimmerError> {
+        if self.acronyms && is_acronym(word) {
+            return Ok(word.to_string());
+        }
+
+        let stem = self.inner.stem(word)?;
+
+        Ok(restore_case(word, &stem))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_to_detect_case_patterns() {
+        assert_eq!(CasePattern::of("excellent"), CasePattern::Lower);
+        assert_eq!(CasePattern::of("Excellent"), CasePattern::Title);
+        assert_eq!(CasePattern::of("NASA"), CasePattern::Upper);
+        assert_eq!(CasePattern::of("iPhones"), CasePattern::Mixed);
+        assert_eq!(CasePattern::of("I"), CasePattern::Title);
+    }
+
+    #[test]
+    fn expect_to_restore_case() {
+        let stemmer = CasedStemmer::new(PorterStemmer);
+
+        assert_eq!(stemmer.stem("Excellent").unwrap(), "Excel");
+        assert_eq!(stemmer.stem("DANCING").unwrap(), "DANC");
+        assert_eq!(stemmer.stem("iPhones").unwrap(), "iPhon");
+        assert_eq!(stemmer.stem("AIDS").unwrap(), "AID");
+        assert_eq!(stemmer.stem("humor").unwrap(), "humor");
+    }
+
+    #[test]
+    fn expect_to_leave_acronyms_unstemmed() {
+        let stemmer = CasedStemmer::new(PorterStemmer).acronyms(true);
+
+        assert_eq!(stemmer.stem("US").unwrap(), "US");
+        assert_eq!(stemmer.stem("AIDS").unwrap(), "AIDS");
+        assert_eq!(stemmer.stem("Dancing").unwrap(), "Danc");
+        assert!(!is_acronym("A"));
+    }
+}
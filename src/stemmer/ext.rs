@@ -0,0 +1,159 @@
+use alloc::string::{String, ToString};
+use crate::error::SimmerError;
+use super::Stem;
+
+/// Iterator which stem the words of an inner iterator and yield the result of each word
+#[derive(Debug, Clone)]
+pub struct StemIter<'a, I, S: ?Sized> {
+    words: I,
+    stemmer: &'a S,
+    buffer: String
+}
+
+/// Iterator which stem the words of an inner iterator and yield the word unchanged when it can't be stemmed
+#[derive(Debug, Clone)]
+pub struct LenientStemIter<'a, I, S: ?Sized> {
+    inner: StemIter<'a, I, S>
+}
+
+/// Extend the iterators of words with lazy stemming
+pub trait StemExt: Iterator + Sized
+where
+    Self::Item: AsRef<str>
+{
+    /// Stem each word with a stemmer and yield the result of each word
+    ///
+    /// # Arguments
+    ///
+    /// * `stemmer` - &S
+    fn stem_with<S: Stem + ?Sized>(self, stemmer: &S) -> StemIter<'_, Self, S> {
+        StemIter {
+            words: self,
+            stemmer,
+            buffer: String::new()
+        }
+    }
+
+    /// Stem each word with a stemmer and yield the stem or the word unchanged when it can't be stemmed
+    ///
+    /// # Arguments
+    ///
+    /// * `stemmer` - &S
+    fn stem_lenient_with<S: Stem + ?Sized>(self, stemmer: &S) -> LenientStemIter<'_, Self, S> {
+        LenientStemIter {
+            inner: self.stem_with(stemmer)
+        }
+    }
+}
+
+impl<I> StemExt for I
+where
+    I: Iterator,
+    I::Item: AsRef<str>
+{}
+
+impl<I, S> StemIter<'_, I, S>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+    S: Stem + ?Sized
+{
+    /// Stem the next word into the internal buffer and borrow the stem. Unlike `next` no String is allocated
+    /// for each word as the buffer is reused by every call
+    pub fn next_stem(&mut self) -> Option<Result<&str, SimmerError>> {
+        let word = self.words.next()?;
+
+        self.buffer.clear();
+        let stemmed = self.stemmer.stem_into(word.as_ref(), &mut self.buffer);
+
+        Some(stemmed.map(|_| self.buffer.as_str()))
+    }
+}
+
+impl<I, S> Iterator for StemIter<'_, I, S>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+    S: Stem + ?Sized
+{
+    type Item = Result<String, SimmerError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // the word is stemmed into the reused buffer but the yielded stem is a copy. Use next_stem to avoid the allocation
+        let stem = self.next_stem()?;
+
+        Some(stem.map(str::to_string))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.words.size_hint()
+    }
+}
+
+impl<I, S> Iterator for LenientStemIter<'_, I, S>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+    S: Stem + ?Sized
+{
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let word = self.inner.words.next()?;
+        let word = word.as_ref();
+
+        self.inner.buffer.clear();
+        match self.inner.stemmer.stem_into(word, &mut self.inner.buffer) {
+            Ok(()) => Some(self.inner.buffer.clone()),
+            Err(_) => Some(word.to_string())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        stemmer::PorterStemmer,
+        testing::FailingStemmer
+    };
+
+    #[test]
+    fn expect_to_stem_lazily() {
+        let stems: Vec<String> = "His eyes were dancing"
+            .split_whitespace()
+            .stem_with(&PorterStemmer)
+            .filter_map(Result::ok)
+            .filter(|stem| stem.len() > 2)
+            .collect();
+
+        assert_eq!(stems, vec!["eye", "were", "danc"]);
+    }
+
+    #[test]
+    fn expect_to_yield_errors_or_words() {
+        let words = vec!["dancing".to_string(), "humor".to_string()];
+
+        let results: Vec<Result<String, SimmerError>> = words.iter().stem_with(&FailingStemmer).collect();
+        assert_eq!(results, vec![Ok("danc".to_string()), Err(SimmerError::Character)]);
+
+        let lenient: Vec<String> = words.into_iter().stem_lenient_with(&FailingStemmer).collect();
+        assert_eq!(lenient, vec!["danc", "humor"]);
+    }
+
+    #[test]
+    fn expect_to_reuse_buffer() {
+        let mut stems = ["generalizations", "Naïvely", "dancing"].into_iter().stem_with(&PorterStemmer);
+
+        assert_eq!(stems.next_stem(), Some(Ok("gener")));
+        let capacity = stems.buffer.capacity();
+        assert_eq!(stems.next_stem(), Some(Ok("naïv")));
+        assert_eq!(stems.next_stem(), Some(Ok("danc")));
+        assert_eq!(stems.buffer.capacity(), capacity);
+        assert_eq!(stems.next_stem(), None);
+    }
+}
@@ -1,10 +1,11 @@
+use alloc::vec::Vec;
 use super::porter::ParsedWord;
 
 // Constant
 const CONSONENT_LIST: [char; 20] = ['b', 'c', 'd', 'f', 'g', 'h', 'j', 'k', 'l', 'm', 'n', 'p', 'q', 'r', 's', 't', 'v', 'w', 'x', 'z'];
 const VOWEL_LIST: [char; 6] = ['a', 'e', 'i', 'o', 'u', 'y'];
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub(crate) enum Kind {
     Consonent,
     Vowel,
@@ -34,7 +35,9 @@ impl Kind {
     /// * `word` - &str
     pub fn end_with_double_consonent(word: &str) -> bool {
         // get the last two char of the word
-        let two_end_character = word.get(word.len() - 2..);
+        let two_end_character = word.len()
+            .checked_sub(2)
+            .and_then(|idx| word.get(idx..));
         if let Some(end) = two_end_character {
             // split the two chacter into char
             let chars: Vec<char> = end.chars()
@@ -91,4 +94,10 @@ mod tests {
 
         assert_eq!(res, false);
     }
+
+    #[test]
+    fn expect_short_word_to_not_have_double_consonents() {
+        assert!(!Kind::end_with_double_consonent("a"));
+        assert!(!Kind::end_with_double_consonent(""));
+    }
 }
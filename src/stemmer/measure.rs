@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use super::{
     porter::ParsedWord,
     kind::Kind
@@ -33,10 +34,127 @@ pub fn compute_measures(parsed_words: &Vec<ParsedWord>) -> i32 {
     measured
 }
 
+/// Classification of each character of a word into consonent or vowel with the measure of each prefix of the word.
+/// The characters are classified once and a new word only classify the characters after the prefix it shares with the previous one
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PrefixMeasure {
+    chars: Vec<char>,
+    kinds: Vec<Kind>,
+    // measure of the first n characters at the index n
+    measures: Vec<i32>
+}
+
+impl PrefixMeasure {
+    /// Classify the characters of a word
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - &str
+    pub fn new(word: &str) -> PrefixMeasure {
+        let mut prefix = PrefixMeasure {
+            chars: Vec::with_capacity(word.len()),
+            kinds: Vec::with_capacity(word.len()),
+            measures: Vec::with_capacity(word.len() + 1)
+        };
+        prefix.measures.push(0);
+        prefix.sync(word);
+
+        prefix
+    }
+
+    /// Update the classification with a new word and return the number of characters of the word.
+    /// The classification of a longer word sharing the same prefix is kept in order to answer the measure of both words
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - &str
+    pub fn sync(&mut self, word: &str) -> usize {
+        let mut len = 0;
+        for c in word.chars() {
+            if self.chars.get(len) != Some(&c) {
+                self.truncate(len);
+                self.push(c);
+            }
+
+            len += 1;
+        }
+
+        len
+    }
+
+    /// Classify a character added at the end of the word
+    ///
+    /// # Arguments
+    ///
+    /// * `c` - char
+    fn push(&mut self, c: char) {
+        let kind = Kind::from(c);
+        let previous = self.measures.last().copied().unwrap_or(0);
+        let measure = match self.kinds.last() {
+            Some(Kind::Vowel) if kind == Kind::Consonent => previous + 1,
+            _ => previous
+        };
+
+        self.chars.push(c);
+        self.kinds.push(kind);
+        self.measures.push(measure);
+    }
+
+    /// Remove the classification of the characters after len
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - usize
+    fn truncate(&mut self, len: usize) {
+        self.chars.truncate(len);
+        self.kinds.truncate(len);
+        self.measures.truncate(len + 1);
+    }
+
+    /// Get the measure of the first len characters in O(1)
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - usize
+    pub fn measure(&self, len: usize) -> i32 {
+        self.measures[len.min(self.chars.len())]
+    }
+
+    /// Get the first len characters with their kind
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - usize
+    pub fn prefix(&self, len: usize) -> impl Iterator<Item = (char, Kind)> + '_ {
+        self.chars.iter().copied().zip(self.kinds.iter().copied()).take(len)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn expect_prefix_measure_to_match_parsed_measure() {
+        let prefix = PrefixMeasure::new("troubles");
+
+        for len in 0..="troubles".len() {
+            let parsed = ParsedWord::parse(&"troubles"[..len]).unwrap();
+            assert_eq!(prefix.measure(len), compute_measures(&parsed));
+        }
+    }
+
+    #[test]
+    fn expect_prefix_measure_to_only_classify_new_suffix() {
+        let mut prefix = PrefixMeasure::new("generalization");
+
+        assert_eq!(prefix.sync("general"), 7);
+        assert_eq!(prefix.measure(14), 6);
+        assert_eq!(prefix.sync("generalize"), 10);
+        assert_eq!(prefix.measure(10), 4);
+        assert_eq!(prefix.measure(14), 4);
+    }
+
     #[test]
     fn expect_to_measure_zero_combination() {
         let pw = vec![
@@ -1,27 +1,119 @@
+mod ascii;
+#[cfg(feature = "std")]
+mod cache;
+mod case;
+mod ext;
 mod kind;
 mod measure;
 mod porter;
+mod shape;
 mod steps;
+mod suffix;
+#[cfg(feature = "stem-table")]
+mod table;
 
+use alloc::{string::{String, ToString}, vec::Vec};
 use crate::error::SimmerError;
+use crate::explain::{Explanation, Measure, RuleDecision, Segment, SegmentKind, Step, StepTrace};
 use self::kind::Kind;
-use self::porter::ParsedWord;
+use self::measure::PrefixMeasure;
+#[cfg(feature = "std")]
+pub use self::cache::{CacheStats, CachedStemmer, SharedCachedStemmer};
+pub use self::case::{is_acronym, restore_case, CasePattern, CasedStemmer};
+pub use self::ext::{LenientStemIter, StemExt, StemIter};
+pub use self::shape::{CharRun, WordShape};
 use self::steps::{
     PorterStemmerStep1,
     PorterStemmerStep2And3,
     PorterStemmerStep4,
     PorterStemmerStep5,
-    RULES_TWO_SUFFIX,
-    RULES_THREE_SUFFIX
+    SUFFIX_TWO,
+    SUFFIX_THREE
 };
 
 // Constant
 const AVOID_CONSONENTS: [char; 3] = ['w', 'x', 'y'];
 
+/// A stemming algorithm which can be shared and reused to stem many words
+pub trait Stem: Send + Sync {
+    /// Get the stem of a word
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - &str
+    fn stem(&self, word: &str) -> Result<String, SimmerError>;
+
+    /// Push the stem of a word into the output. A stemmer can override it in order to reuse the buffer of the output
+    /// instead of allocating a String for each word. Nothing is pushed when an error is returned
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - &str
+    /// * `out` - &mut String
+    fn stem_into(&self, word: &str, out: &mut String) -> Result<(), SimmerError> {
+        out.push_str(&self.stem(word)?);
+
+        Ok(())
+    }
+}
+
+/// Stem english words with the porter stemmer algorithm
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PorterStemmer;
+
+impl PorterStemmer {
+    /// Stem a word with the algorithm without looking up the precomputed table
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - &str
+    pub(crate) fn stem_algorithm(word: &str) -> Result<String, SimmerError> {
+        // most of the english words are ascii which are stemmed in place on their bytes
+        if let Some(stem) = ascii::stem(word.as_bytes()) {
+            return Ok(stem);
+        }
+
+        let mut stemmer = Stemmer::new(&word.to_lowercase())?;
+
+        stemmer.stem()
+    }
+}
+
+impl Stem for PorterStemmer {
+    fn stem(&self, word: &str) -> Result<String, SimmerError> {
+        #[cfg(feature = "stem-table")]
+        if let Some(stem) = table::lookup(word) {
+            return Ok(stem.to_string());
+        }
+
+        PorterStemmer::stem_algorithm(word)
+    }
+
+    fn stem_into(&self, word: &str, out: &mut String) -> Result<(), SimmerError> {
+        #[cfg(feature = "stem-table")]
+        if let Some(stem) = table::lookup(word) {
+            out.push_str(stem);
+            return Ok(());
+        }
+
+        if !ascii::stem_into(word.as_bytes(), out) {
+            let mut stemmer = Stemmer::new(&word.to_lowercase())?;
+            out.push_str(&stemmer.stem()?);
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug)]
 pub struct Stemmer {
     word: String,
-    porter_stemmer: Vec<ParsedWord>
+    // classification of the characters of the word which was measured last
+    prefix: PrefixMeasure,
+    // number of characters of the word which was measured last
+    measured: usize,
+    // trace of each step which is only recorded when explaining a word
+    trace: Option<Vec<StepTrace>>
 }
 
 impl Stemmer {
@@ -31,11 +123,14 @@ impl Stemmer {
     ///
     /// * `word` - &'a str
     pub fn new(word: &str) -> Result<Stemmer, SimmerError>{
-        let porter_stemmer = ParsedWord::parse(word)?;
+        let prefix = PrefixMeasure::new(word);
+        let measured = word.chars().count();
 
         Ok(Stemmer {
             word: word.to_string(),
-            porter_stemmer
+            prefix,
+            measured,
+            trace: None
         })
     }
 
@@ -103,27 +198,113 @@ impl Stemmer {
     fn get_measure<T: ToString>(&mut self, word: Option<T>) -> Result<i32, SimmerError> {
         if let Some(w) = word {
             self.word = w.to_string();
-            self.porter_stemmer = ParsedWord::parse(&self.word)?;
+            self.measured = self.prefix.sync(&self.word);
         }
 
-        let weight = measure::compute_measures(&self.porter_stemmer);
+        let weight = self.prefix.measure(self.measured);
+        if let Some(step) = self.trace.as_mut().and_then(|t| t.last_mut()) {
+            // the measure is computed on the word which was measured last which might differ from the current word
+            let word = self.prefix.prefix(self.measured).map(|(c, _)| c).collect();
+
+            step.measures.push(Measure { word, measure: weight });
+        }
 
         Ok(weight)
     }
 
+    /// Record a rule which matched the word in the current step when the stemmer is explaining a word
+    ///
+    /// # Arguments
+    ///
+    /// * `condition` - &str
+    /// * `suffix` - &str
+    /// * `replacement` - &str
+    /// * `applied` - bool
+    /// * `measure` - Option<i32>
+    /// * `reason` - &str
+    fn trace_rule(&mut self, condition: &str, suffix: &str, replacement: &str, applied: bool, measure: Option<i32>, reason: &str) {
+        if let Some(step) = self.trace.as_mut().and_then(|t| t.last_mut()) {
+            step.rules.push(RuleDecision {
+                condition: condition.to_string(),
+                suffix: suffix.to_string(),
+                replacement: replacement.to_string(),
+                applied,
+                measure,
+                reason: reason.to_string()
+            });
+        }
+    }
+
+    /// Run a step while recording the word before and after the step
+    ///
+    /// # Arguments
+    ///
+    /// * `step` - Step
+    /// * `process` - F
+    fn trace_step<F>(&mut self, step: Step, process: F) -> Result<(), SimmerError>
+    where
+        F: FnOnce(&mut Stemmer) -> Result<(), SimmerError>
+    {
+        self.trace
+            .get_or_insert_with(Vec::new)
+            .push(StepTrace::new(step, &self.word));
+
+        process(self)?;
+
+        if let Some(trace) = self.trace.as_mut().and_then(|t| t.last_mut()) {
+            trace.output = self.word.clone();
+        }
+
+        Ok(())
+    }
+
     /// Process each step of the porter stemmer
     pub fn stem(&mut self) -> Result<String, SimmerError> {
         let result = self
             .process_step_one_a()
             .process_step_one_b()?
             .process_step_one_c()
-            .process_step_two_and_three(&RULES_TWO_SUFFIX)?
-            .process_step_two_and_three(&RULES_THREE_SUFFIX)?
+            .process_step_two_and_three(&SUFFIX_TWO)?
+            .process_step_two_and_three(&SUFFIX_THREE)?
             .process_step_four()?
             .process_step_fifth()?;
 
         Ok(result)
     }
+
+    /// Process each step of the porter stemmer and explain the decision taken by each step
+    pub fn explain(&mut self) -> Result<Explanation, SimmerError> {
+        let word = self.word.clone();
+        let measure = self.prefix.measure(self.measured);
+        let mut segments: Vec<Segment> = Vec::new();
+        for (c, kind) in self.prefix.prefix(self.measured) {
+            let kind = match kind {
+                Kind::Consonent => SegmentKind::Consonent,
+                _ => SegmentKind::Vowel
+            };
+
+            match segments.last_mut() {
+                Some(segment) if segment.kind == kind => segment.text.push(c),
+                _ => segments.push(Segment { kind, text: c.to_string() })
+            }
+        }
+
+        self.trace_step(Step::OneA, |s| { s.process_step_one_a(); Ok(()) })?;
+        self.trace_step(Step::OneB, |s| s.process_step_one_b().map(|_| ()))?;
+        self.trace_step(Step::OneC, |s| { s.process_step_one_c(); Ok(()) })?;
+        self.trace_step(Step::Two, |s| s.process_step_two_and_three(&SUFFIX_TWO).map(|_| ()))?;
+        self.trace_step(Step::Three, |s| s.process_step_two_and_three(&SUFFIX_THREE).map(|_| ()))?;
+        self.trace_step(Step::Four, |s| s.process_step_four().map(|_| ()))?;
+        self.trace_step(Step::Five, |s| s.process_step_fifth().map(|_| ()))?;
+
+        Ok(Explanation {
+            word,
+            segments,
+            measure,
+            steps: self.trace.take().unwrap_or_default(),
+            stem: self.word.clone()
+        })
+    }
 }
 
 #[cfg(test)]
@@ -1,3 +1,4 @@
+use alloc::{borrow::ToOwned, vec::Vec};
 use crate::error::SimmerError;
 use super::kind::Kind;
 
@@ -0,0 +1,193 @@
+use alloc::{string::{String, ToString}, vec::Vec};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use crate::error::SimmerError;
+use crate::explain::SegmentKind;
+use super::{
+    kind::Kind,
+    measure::{self, PrefixMeasure},
+    porter::ParsedWord,
+    AVOID_CONSONENTS
+};
+
+/// A run of consonents or vowels of a word with its byte offsets in the word
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CharRun {
+    pub kind: SegmentKind,
+    pub start: usize,
+    pub end: usize
+}
+
+/// Phonological shape of a word as seen by the porter stemmer. The shape is computed once
+/// when the WordShape is created. The word is expected to be lowercased as uppercase letters are considered as vowels
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WordShape {
+    word: String,
+    runs: Vec<CharRun>,
+    measure: i32,
+    // measure of the first n characters at the index n
+    prefix_measures: Vec<i32>,
+    has_vowel: bool,
+    double_consonent: bool,
+    cvc: bool
+}
+
+impl WordShape {
+    /// Compute the shape of a word
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - &str
+    pub fn new(word: &str) -> Result<WordShape, SimmerError> {
+        let parsed = ParsedWord::parse(word)?;
+        let measure = measure::compute_measures(&parsed);
+        let prefix = PrefixMeasure::new(word);
+        let prefix_measures = (0..=word.chars().count()).map(|len| prefix.measure(len)).collect();
+
+        let mut runs = Vec::with_capacity(parsed.len());
+        let mut start = 0;
+        for pw in &parsed {
+            let (kind, chars) = match pw {
+                ParsedWord::C(chars) => (SegmentKind::Consonent, chars),
+                ParsedWord::V(chars) => (SegmentKind::Vowel, chars),
+                ParsedWord::None => continue
+            };
+
+            let end = start + chars.iter().map(|c| c.len_utf8()).sum::<usize>();
+            runs.push(CharRun { kind, start, end });
+            start = end;
+        }
+
+        // kinds of the last three characters of the word
+        let ending: Vec<(char, Kind)> = word.chars()
+            .rev()
+            .take(3)
+            .map(|c| (c, Kind::from(c)))
+            .collect();
+
+        let double_consonent = match ending.as_slice() {
+            [(last, Kind::Consonent), (previous, Kind::Consonent), ..] => last == previous,
+            _ => false
+        };
+
+        let cvc = match ending.as_slice() {
+            [(last, Kind::Consonent), (_, Kind::Vowel), (_, Kind::Consonent)] => !AVOID_CONSONENTS.contains(last),
+            _ => false
+        };
+
+        Ok(WordShape {
+            word: word.to_string(),
+            runs,
+            measure,
+            prefix_measures,
+            has_vowel: Kind::has_vowel(word),
+            double_consonent,
+            cvc
+        })
+    }
+
+    /// Get the word
+    pub fn word(&self) -> &str {
+        &self.word
+    }
+
+    /// Get the runs of consonents and vowels of the word
+    pub fn runs(&self) -> &[CharRun] {
+        &self.runs
+    }
+
+    /// Get the text of a run
+    ///
+    /// # Arguments
+    ///
+    /// * `run` - &CharRun
+    pub fn run_text(&self, run: &CharRun) -> &str {
+        &self.word[run.start..run.end]
+    }
+
+    /// Get the measure m of the word in the `[C](VC)^m[V]` model
+    pub fn measure(&self) -> i32 {
+        self.measure
+    }
+
+    /// Get the measure of the first len characters of the word in O(1). i.e: the measure of the stem once a suffix is removed
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - usize
+    pub fn measure_prefix(&self, len: usize) -> i32 {
+        self.prefix_measures[len.min(self.prefix_measures.len() - 1)]
+    }
+
+    /// Whether the word contains a vowel (*v*)
+    pub fn has_vowel(&self) -> bool {
+        self.has_vowel
+    }
+
+    /// Whether the word ends with a double consonent (*d)
+    pub fn ends_with_double_consonent(&self) -> bool {
+        self.double_consonent
+    }
+
+    /// Whether the word ends with consonent -> vowel -> consonent where the last consonent is not W, X or Y (*o)
+    pub fn ends_with_cvc(&self) -> bool {
+        self.cvc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_to_get_runs_with_offsets() {
+        let shape = WordShape::new("trouble").unwrap();
+        let runs: Vec<(SegmentKind, &str)> = shape.runs()
+            .iter()
+            .map(|run| (run.kind, shape.run_text(run)))
+            .collect();
+
+        assert_eq!(runs, vec![
+            (SegmentKind::Consonent, "tr"),
+            (SegmentKind::Vowel, "ou"),
+            (SegmentKind::Consonent, "bl"),
+            (SegmentKind::Vowel, "e")
+        ]);
+        assert_eq!(shape.measure(), 1);
+        assert!(shape.has_vowel());
+    }
+
+    #[test]
+    fn expect_to_compute_conditions() {
+        let hopp = WordShape::new("hopp").unwrap();
+        let hop = WordShape::new("hop").unwrap();
+        let snow = WordShape::new("snow").unwrap();
+
+        assert!(hopp.ends_with_double_consonent());
+        assert!(!hopp.ends_with_cvc());
+        assert!(hop.ends_with_cvc());
+        assert!(!snow.ends_with_cvc());
+    }
+
+    #[test]
+    fn expect_to_measure_prefixes() {
+        let shape = WordShape::new("troubles").unwrap();
+
+        assert_eq!(shape.measure_prefix(0), 0);
+        assert_eq!(shape.measure_prefix(5), 1);
+        assert_eq!(shape.measure_prefix(8), shape.measure());
+        assert_eq!(shape.measure_prefix(20), shape.measure());
+    }
+
+    #[test]
+    fn expect_to_handle_multibyte_characters() {
+        let shape = WordShape::new("naïve").unwrap();
+        let last = shape.runs().last().unwrap();
+
+        assert_eq!(shape.run_text(&shape.runs()[1]), "aï");
+        assert_eq!(last.end, "naïve".len());
+        assert!(!WordShape::new("").unwrap().ends_with_double_consonent());
+    }
+}
@@ -1,6 +1,8 @@
+use alloc::{borrow::ToOwned, format, string::{String, ToString}};
 use crate::error::SimmerError;
 use super::{
     kind::Kind,
+    suffix::{trie_size, SuffixTrie},
     Stemmer,
 };
 
@@ -9,20 +11,24 @@ const SUFFIX_STEP_1B: [&str; 2] = ["ed", "ing"];
 const END_LETTERS_LSZ: [&str; 3] = ["l", "s", "z"];
 const END_LETTERS_ST: [&str; 2] = ["s", "t"];
 const END_LETTERS_L: [&str; 1] = ["l"];
-pub const RULES_TWO_SUFFIX: [(&str, &str); 20] = [
+const RULES_TWO_SUFFIX: [(&str, &str); 20] = [
     ("ational", "ate"), ("tional", "tion"), ("enci", "ence"), ("anci", "ance"), ("izer", "ize"),
     ("abli", "able"), ("alli", "al"), ("entli", "ent"), ("eli", "e"), ("ousli", "ous"),
     ("ization", "ize"), ("ation", "aze"), ("ator", "ate"), ("alism", "al"), ("iveness", "ive"),
     ("fulness", "ful"), ("ousness", "ous"), ("aliti", "al"), ("iviti", "ive"), ("biliti", "ble")
 ];
-pub const RULES_THREE_SUFFIX: [(&str, &str); 7] = [
+const RULES_THREE_SUFFIX: [(&str, &str); 7] = [
     ("icate", "ic"), ("ative", ""), ("alize", "al"), ("iciti", "ic"), ("ical", "ic"),
     ("ful", ""), ("ness", "")
 ];
-const RULES_FOUR_SUFFIX: [&str; 18] = [
-    "al", "ance", "ence", "er", "ic", "able", "ible", "ant", "ement",
-    "ment", "ent", "ou", "ism", "ate", "iti", "ous", "ive", "ize"
+const RULES_FOUR_SUFFIX: [(&str, &str); 18] = [
+    ("al", ""), ("ance", ""), ("ence", ""), ("er", ""), ("ic", ""), ("able", ""), ("ible", ""), ("ant", ""),
+    ("ement", ""), ("ment", ""), ("ent", ""), ("ou", ""), ("ism", ""), ("ate", ""), ("iti", ""), ("ous", ""),
+    ("ive", ""), ("ize", "")
 ];
+pub static SUFFIX_TWO: SuffixTrie<{ trie_size(&RULES_TWO_SUFFIX) }> = SuffixTrie::new(&RULES_TWO_SUFFIX);
+pub static SUFFIX_THREE: SuffixTrie<{ trie_size(&RULES_THREE_SUFFIX) }> = SuffixTrie::new(&RULES_THREE_SUFFIX);
+pub static SUFFIX_FOUR: SuffixTrie<{ trie_size(&RULES_FOUR_SUFFIX) }> = SuffixTrie::new(&RULES_FOUR_SUFFIX);
 
 pub(crate) trait PorterStemmerStep1 {
     /// Process step 1a is to remove the plural (s) from a Stemmer
@@ -46,16 +52,16 @@ pub(crate) trait PorterStemmerStep1 {
 }
 
 pub(crate) trait PorterStemmerStep2And3 {
-    /// Step 2 and 3 replace the suffix by checking each rules on the targeted word if only M > 0
+    /// Step 2 and 3 replace the longest suffix of the targeted word which match a rule if only M > 0
     ///
     /// # Arguments
     ///
-    /// * `rules` - &[(&str, &str)]
-    fn process_step_two_and_three(&mut self, rules: &[(&str, &str)]) -> Result<&mut Stemmer, SimmerError>;
+    /// * `rules` - &SuffixTrie<N>
+    fn process_step_two_and_three<const N: usize>(&mut self, rules: &SuffixTrie<N>) -> Result<&mut Stemmer, SimmerError>;
 }
 
 pub(crate) trait PorterStemmerStep4 {
-    /// Step 4 replace the suffix with a set of rules if M > 1
+    /// Step 4 remove the longest suffix which match a rule if M > 1
     fn process_step_four(&mut self) -> Result<&mut Stemmer, SimmerError>;
 }
 
@@ -67,15 +73,18 @@ pub(crate) trait PorterStemmerStep5 {
 impl PorterStemmerStep1 for Stemmer {
     // Step 1a
     fn process_step_one_a(&mut self) -> &mut Self {
-        let word = match &self.word {
-            w if w.ends_with("sses") => w.trim_end_matches("es"),
-            w if w.ends_with("ies") => w.trim_end_matches("es"),
-            w if w.ends_with("ss") => w,
-            w if w.ends_with('s') => w.trim_end_matches('s'),
-            _ => &self.word
+        let (word, rule) = match &self.word {
+            w if w.ends_with("sses") => (w.trim_end_matches("es"), Some(("sses", "ss"))),
+            w if w.ends_with("ies") => (w.trim_end_matches("es"), Some(("ies", "i"))),
+            w if w.ends_with("ss") => (w.as_str(), Some(("ss", "ss"))),
+            w if w.ends_with('s') => (w.trim_end_matches('s'), Some(("s", ""))),
+            _ => (self.word.as_str(), None)
         };
 
         self.word = word.to_string();
+        if let Some((suffix, replacement)) = rule {
+            self.trace_rule("", suffix, replacement, true, None, "");
+        }
 
         self
     }
@@ -90,7 +99,10 @@ impl PorterStemmerStep1 for Stemmer {
 
             // trim the word
             let trimmed = self.word.to_owned().trim_end_matches("eed").to_string();
-            if self.get_measure(Some(&trimmed))? > 0 {
+            let measure = self.get_measure(Some(&trimmed))?;
+            self.trace_rule("(m>0)", "eed", "ee", measure > 0, Some(measure), "");
+
+            if measure > 0 {
                 // feed -> feed
                 // agreed -> agree
                 // in this case we can only trim the d this will return the 'ee'
@@ -110,7 +122,11 @@ impl PorterStemmerStep1 for Stemmer {
                 // trim the end
                 let trimmed = self.word.trim_end_matches(suffix).to_owned();
                 // check if the trimmed word is a vowel
-                if Kind::has_vowel(&trimmed) {
+                let has_vowel = Kind::has_vowel(&trimmed);
+                let reason = if has_vowel { "stem has a vowel" } else { "stem has no vowel" };
+                self.trace_rule("(*v*)", suffix, "", has_vowel, None, reason);
+
+                if has_vowel {
                     // process the intermediary externally
                     self.process_step_one_b_intermediary(&trimmed)?;
 
@@ -127,8 +143,10 @@ impl PorterStemmerStep1 for Stemmer {
         // - AT
         // - BL
         // - IZ
-        if trimmed.ends_with("at") || trimmed.ends_with("bl") || trimmed.ends_with("iz") {
+        if let Some(suffix) = ["at", "bl", "iz"].into_iter().find(|suffix| trimmed.ends_with(suffix)) {
             self.word = format!("{trimmed}e");
+            self.trace_rule("", suffix, &format!("{suffix}e"), true, None, "");
+
             return Ok(self);
         }
 
@@ -136,18 +154,12 @@ impl PorterStemmerStep1 for Stemmer {
         // we remove the last consonent
         if Kind::end_with_double_consonent(trimmed) &&
         !Stemmer::check_end_letter(trimmed, &END_LETTERS_LSZ) {
-            let exploded: Vec<char> = trimmed.chars()
-                .enumerate()
-                .filter_map(|(idx, c)| {
-                    if idx < trimmed.len() - 1 {
-                        Some(c)
-                    } else {
-                        None
-                    }
-                })
-                .collect();
-
-            self.word = exploded.iter().collect();
+            // remove the last char rather than the last byte as the word may not be ascii
+            let mut word = trimmed.to_string();
+            word.pop();
+
+            self.word = word;
+            self.trace_rule("(*d and not (*l or *s or *z))", "", "single letter", true, None, "");
 
             return Ok(self);
         }
@@ -155,6 +167,7 @@ impl PorterStemmerStep1 for Stemmer {
         // last check (m=1 and *o) -> E
         if self.get_measure(Some(trimmed))? == 1 && self.check_cvc_pattern() {
             self.word = format!("{trimmed}e");
+            self.trace_rule("(m=1 and *o)", "", "e", true, Some(1), "");
 
             return Ok(self);
         }
@@ -164,8 +177,14 @@ impl PorterStemmerStep1 for Stemmer {
 
     // Step 1c
     fn process_step_one_c(&mut self) -> &mut Self {
-        if Kind::has_vowel(&self.word) && self.word.ends_with('y') {
-            self.word = format!("{}i", self.word.trim_end_matches('y'))
+        if self.word.ends_with('y') {
+            let has_vowel = Kind::has_vowel(&self.word);
+            let reason = if has_vowel { "word has a vowel" } else { "word has no vowel" };
+            self.trace_rule("(*v*)", "y", "i", has_vowel, None, reason);
+
+            if has_vowel {
+                self.word = format!("{}i", self.word.trim_end_matches('y'))
+            }
         }
 
         self
@@ -174,18 +193,16 @@ impl PorterStemmerStep1 for Stemmer {
 
 impl PorterStemmerStep2And3 for Stemmer {
     // Step 2
-    fn process_step_two_and_three(&mut self, rules: &[(&str, &str)]) -> Result<&mut Stemmer, SimmerError> {
-        if self.get_measure::<String>(None)? > 0 {
-            rules
-                .iter()
-                .for_each(|(rule, replacement)| {
-                    if self.word.ends_with(rule) {
-                        let mut word = self.word.trim_end_matches(rule).to_string();
-                        word.push_str(replacement);
-
-                        self.word = word;
-                    }
-                });
+    fn process_step_two_and_three<const N: usize>(&mut self, rules: &SuffixTrie<N>) -> Result<&mut Stemmer, SimmerError> {
+        let measure = self.get_measure::<String>(None)?;
+
+        // only the longest suffix is replaced which means that a replacement can't match another rule
+        if let Some((rule, replacement)) = rules.longest_match(self.word.as_bytes()) {
+            self.trace_rule("(m>0)", rule, replacement, measure > 0, Some(measure), "");
+            if measure > 0 {
+                self.word.truncate(self.word.len() - rule.len());
+                self.word.push_str(replacement);
+            }
         }
 
         Ok(self)
@@ -196,22 +213,28 @@ impl PorterStemmerStep4 for Stemmer {
     fn process_step_four(&mut self) -> Result<&mut Stemmer, SimmerError> {
         let original = self.word.to_string();
 
-        for rule in RULES_FOUR_SUFFIX {
-            if self.word.ends_with(rule) {
-                let trimmed = self.word.trim_end_matches(rule).to_string();
-                if self.get_measure(Some(trimmed))? > 1 {
-                    return Ok(self);
-                } else {
-                    self.word = original;
-                    return Ok(self);
-                }
+        if let Some((rule, _)) = SUFFIX_FOUR.longest_match(self.word.as_bytes()) {
+            // the suffix is stripped once even if it's repeated. i.e: bitterer -> bitter
+            let trimmed = self.word[..self.word.len() - rule.len()].to_string();
+            let measure = self.get_measure(Some(trimmed))?;
+            self.trace_rule("(m>1)", rule, "", measure > 1, Some(measure), "");
+
+            if measure <= 1 {
+                self.word = original;
             }
+
+            return Ok(self);
         }
 
         // Special case of *S or *T and finish by ion
         if self.word.ends_with("ion") {
-            let trimmed = self.word.trim_end_matches("ion").to_string();
-            if self.get_measure(Some(trimmed))? > 1 && Stemmer::check_end_letter(&self.word, &END_LETTERS_ST) {
+            let trimmed = self.word[..self.word.len() - "ion".len()].to_string();
+            let measure = self.get_measure(Some(trimmed))?;
+            let end_with_st = Stemmer::check_end_letter(&self.word, &END_LETTERS_ST);
+            let reason = if end_with_st { "" } else { "stem does not end with s or t" };
+            self.trace_rule("(m>1 and (*s or *t))", "ion", "", measure > 1 && end_with_st, Some(measure), reason);
+
+            if measure > 1 && end_with_st {
                 return Ok(self);
             } else {
                 self.word = original;
@@ -231,9 +254,17 @@ impl PorterStemmerStep5 for Stemmer {
             let mut popped = self.word.clone();
             popped.pop();
 
-            if self.get_measure(Some(popped))? > 1 ||
-                self.get_measure::<String>(None)? == 1 &&
-                !self.check_cvc_pattern() {
+            let measure = self.get_measure(Some(popped))?;
+            if measure > 1 {
+                self.trace_rule("(m>1)", "e", "", true, Some(measure), "");
+                return Ok(self.word.to_owned());
+            }
+
+            let is_cvc = self.check_cvc_pattern();
+            let reason = if is_cvc { "stem end with cvc" } else { "" };
+            self.trace_rule("(m=1 and not *o)", "e", "", measure == 1 && !is_cvc, Some(measure), reason);
+
+            if measure == 1 && !is_cvc {
                 return Ok(self.word.to_owned());
             } else {
                 self.word = original;
@@ -245,6 +276,7 @@ impl PorterStemmerStep5 for Stemmer {
             Kind::end_with_double_consonent(&self.word) &&
             Stemmer::check_end_letter(&self.word, &END_LETTERS_L) {
                 self.word.pop();
+                self.trace_rule("(m>1 and *d and *l)", "ll", "l", true, None, "");
         }
 
         Ok(self.word.to_string())
@@ -369,9 +401,9 @@ mod tests {
             .process_step_one_b()
             .unwrap()
             .process_step_one_c()
-            .process_step_two_and_three(&RULES_TWO_SUFFIX)
+            .process_step_two_and_three(&SUFFIX_TWO)
             .unwrap()
-            .process_step_two_and_three(&RULES_THREE_SUFFIX)
+            .process_step_two_and_three(&SUFFIX_THREE)
             .unwrap();
 
         assert_eq!(processed.word, "decisive");
@@ -386,9 +418,9 @@ mod tests {
             .process_step_one_b()
             .unwrap()
             .process_step_one_c()
-            .process_step_two_and_three(&RULES_TWO_SUFFIX)
+            .process_step_two_and_three(&SUFFIX_TWO)
             .unwrap()
-            .process_step_two_and_three(&RULES_THREE_SUFFIX)
+            .process_step_two_and_three(&SUFFIX_THREE)
             .unwrap()
             .process_step_four()
             .unwrap();
@@ -405,9 +437,9 @@ mod tests {
             .process_step_one_b()
             .unwrap()
             .process_step_one_c()
-            .process_step_two_and_three(&RULES_TWO_SUFFIX)
+            .process_step_two_and_three(&SUFFIX_TWO)
             .unwrap()
-            .process_step_two_and_three(&RULES_THREE_SUFFIX)
+            .process_step_two_and_three(&SUFFIX_THREE)
             .unwrap()
             .process_step_four()
             .unwrap()
@@ -426,9 +458,9 @@ mod tests {
             .process_step_one_b()
             .unwrap()
             .process_step_one_c()
-            .process_step_two_and_three(&RULES_TWO_SUFFIX)
+            .process_step_two_and_three(&SUFFIX_TWO)
             .unwrap()
-            .process_step_two_and_three(&RULES_THREE_SUFFIX)
+            .process_step_two_and_three(&SUFFIX_THREE)
             .unwrap()
             .process_step_four()
             .unwrap()
@@ -437,4 +469,23 @@ mod tests {
 
         assert_eq!(processed, "sensat");
     }
+
+    #[test]
+    fn expect_to_remove_double_consonent_of_non_ascii_word() {
+        let mut word = Stemmer::new("überstopping").unwrap();
+
+        assert_eq!(word.process_step_one_b().unwrap().word, "überstop");
+        assert_eq!(crate::stem("Überstopping").unwrap(), "überstop");
+        assert_eq!(crate::stem("overstopping").unwrap(), "overstop");
+    }
+
+    #[test]
+    fn expect_step_four_to_strip_suffix_once() {
+        // a repeated suffix is only removed once
+        let mut bitterer = Stemmer::new("bitterer").unwrap();
+        let mut deliverer = Stemmer::new("deliverer").unwrap();
+
+        assert_eq!(bitterer.process_step_four().unwrap().word, "bitter");
+        assert_eq!(deliverer.process_step_four().unwrap().word, "deliver");
+    }
 }
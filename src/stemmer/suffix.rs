@@ -0,0 +1,127 @@
+// Constant
+const ALPHABET_SIZE: usize = 26;
+const MAX_NODES: usize = u8::MAX as usize;
+
+/// Get the number of nodes needed by a SuffixTrie to store the suffixes of the rules
+///
+/// # Arguments
+///
+/// * `rules` - &[(&str, &str)]
+pub(crate) const fn trie_size(rules: &[(&str, &str)]) -> usize {
+    let mut size = 1;
+    let mut idx = 0;
+    while idx < rules.len() {
+        size += rules[idx].0.len();
+        idx += 1;
+    }
+
+    size
+}
+
+/// Trie of the reversed suffixes of a rule table which is built at compile time.
+/// The longest suffix which match a word is found in a single backward pass over the word whatever the size of the table
+#[derive(Debug)]
+pub(crate) struct SuffixTrie<const N: usize> {
+    // child of each node for each lowercase ascii letter. The root is the node 0 which means that 0 is no child
+    children: [[u8; ALPHABET_SIZE]; N],
+    // rule (suffix, replacement) which end at each node
+    rules: [Option<(&'static str, &'static str)>; N]
+}
+
+impl<const N: usize> SuffixTrie<N> {
+    /// Build the trie of a rule table. The suffixes must be made of lowercase ascii letters
+    ///
+    /// # Arguments
+    ///
+    /// * `rules` - &[(&'static str, &'static str)]
+    pub(crate) const fn new(rules: &[(&'static str, &'static str)]) -> SuffixTrie<N> {
+        assert!(N <= MAX_NODES, "the rule table is too large");
+
+        let mut trie = SuffixTrie {
+            children: [[0; ALPHABET_SIZE]; N],
+            rules: [None; N]
+        };
+        let mut len = 1;
+
+        let mut idx = 0;
+        while idx < rules.len() {
+            let suffix = rules[idx].0.as_bytes();
+            let mut node = 0;
+
+            // insert the letters of the suffix from the last one to the first one
+            let mut pos = suffix.len();
+            while pos > 0 {
+                pos -= 1;
+                assert!(suffix[pos].is_ascii_lowercase(), "a suffix must be made of lowercase ascii letters");
+
+                let letter = (suffix[pos] - b'a') as usize;
+                if trie.children[node][letter] == 0 {
+                    assert!(len < N, "the size of the trie is too small");
+                    trie.children[node][letter] = len as u8;
+                    len += 1;
+                }
+
+                node = trie.children[node][letter] as usize;
+            }
+
+            assert!(trie.rules[node].is_none(), "a suffix must be listed once");
+            trie.rules[node] = Some(rules[idx]);
+
+            idx += 1;
+        }
+
+        trie
+    }
+
+    /// Get the rule with the longest suffix which end the word
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - &[u8]
+    pub(crate) fn longest_match(&self, word: &[u8]) -> Option<(&'static str, &'static str)> {
+        let mut node = 0;
+        let mut longest = None;
+
+        for &byte in word.iter().rev() {
+            if !byte.is_ascii_lowercase() {
+                break;
+            }
+
+            node = self.children[node][(byte - b'a') as usize] as usize;
+            if node == 0 {
+                break;
+            }
+
+            // the nodes are visited from the shortest suffix to the longest one
+            if self.rules[node].is_some() {
+                longest = self.rules[node];
+            }
+        }
+
+        longest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RULES: [(&str, &str); 4] = [("ent", "1"), ("ment", "2"), ("ement", "3"), ("al", "4")];
+    static TRIE: SuffixTrie<{ trie_size(&RULES) }> = SuffixTrie::new(&RULES);
+
+    #[test]
+    fn expect_to_find_longest_rule() {
+        assert_eq!(TRIE.longest_match(b"replacement"), Some(("ement", "3")));
+        assert_eq!(TRIE.longest_match(b"argument"), Some(("ment", "2")));
+        assert_eq!(TRIE.longest_match(b"different"), Some(("ent", "1")));
+        assert_eq!(TRIE.longest_match(b"revival"), Some(("al", "4")));
+    }
+
+    #[test]
+    fn expect_to_not_match() {
+        assert_eq!(TRIE.longest_match(b"dancing"), None);
+        assert_eq!(TRIE.longest_match(b"nt"), None);
+        assert_eq!(TRIE.longest_match(b""), None);
+        assert_eq!(TRIE.longest_match("naïvement".as_bytes()), Some(("ement", "3")));
+    }
+}
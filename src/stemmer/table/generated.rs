@@ -0,0 +1,29873 @@
+// @generated by the generate_table test of src/stemmer/table/mod.rs from tests/data/voc.txt
+// The vocabulary comes from the snowball-data repository (https://github.com/snowballstem/snowball-data)
+// under the BSD license
+// Do not edit this file manually
+
+pub(super) static DISPLACEMENTS: [u32; 7351] = [
+    0, 1, 56, 13, 163, 97, 6, 33, 7, 0, 57, 30, 75, 19, 5, 173,
+    2, 578, 36, 11, 57, 6, 0, 0, 21, 91, 9, 83, 1, 226, 0, 12,
+    0, 23, 14, 204, 11, 17, 2, 41, 0, 14, 187, 8, 27, 40, 0, 0,
+    40, 187, 122, 52, 116, 36, 184, 2, 0, 88, 80, 22, 0, 35, 91, 281,
+    30, 2, 41, 3, 1, 36, 192, 19, 50, 27, 8, 0, 0, 3, 7, 102,
+    8, 2, 19, 50, 15, 39, 506, 304, 87, 0, 0, 24, 5, 0, 8, 5,
+    14, 0, 4, 0, 69, 0, 10, 0, 23, 110, 2, 13, 10, 200, 4, 8,
+    1, 36, 13, 0, 6, 96, 14, 23, 90, 4, 3, 50, 11, 31, 14, 78,
+    462, 14, 47, 142, 91, 1, 1, 108, 1, 68, 1, 23, 0, 11, 4, 13,
+    28, 0, 44, 15, 144, 20, 1, 3, 34, 63, 0, 7, 60, 70, 2, 3,
+    2, 5, 0, 3, 34, 11, 4, 0, 8, 0, 4, 0, 16, 31, 8, 2,
+    384, 209, 5, 4, 10, 212, 16, 56, 1, 231, 19, 30, 0, 1, 0, 1,
+    106, 62, 1, 0, 35, 198, 29, 1, 13, 14, 0, 29, 72, 188, 77, 268,
+    21, 158, 9, 2, 22, 58, 12, 24, 64, 4, 1, 70, 151, 2, 24, 3,
+    205, 9, 191, 110, 13, 0, 68, 2, 22, 0, 36, 26, 12, 15, 2, 29,
+    85, 21, 1, 4, 78, 24, 2, 25, 71, 0, 73, 159, 0, 18, 21, 28,
+    0, 105, 14, 45, 0, 124, 27, 0, 214, 11, 100, 76, 225, 6, 40, 211,
+    0, 37, 0, 9, 180, 3, 1, 1, 166, 10, 304, 58, 55, 0, 2, 136,
+    2, 48, 106, 0, 7, 0, 11, 43, 229, 12, 72, 57, 0, 29, 17, 40,
+    9, 24, 29, 2, 0, 3, 142, 1, 82, 2, 6, 0, 14, 54, 127, 113,
+    5, 191, 288, 12, 0, 24, 0, 20, 2, 116, 14, 140, 1, 0, 4, 0,
+    27, 3, 102, 4, 101, 3, 39, 12, 57, 7, 7, 490, 4, 34, 0, 14,
+    75, 0, 159, 9, 0, 54, 23, 68, 65, 6, 14, 23, 1, 89, 38, 22,
+    1, 65, 2, 11, 0, 9, 18, 1, 174, 23, 6, 5, 57, 1, 18, 40,
+    1, 18, 93, 4, 8, 2, 185, 7, 1, 6, 595, 24, 0, 1, 1, 10,
+    1, 83, 47, 42, 105, 108, 22, 10, 29, 194, 20, 3, 11, 3, 21, 1,
+    8, 124, 20, 77, 1, 0, 1, 0, 218, 19, 10, 0, 38, 3, 4, 83,
+    23, 5, 27, 0, 33, 14, 10, 49, 28, 35, 17, 29, 44, 182, 67, 14,
+    58, 90, 34, 2, 41, 2, 0, 13, 79, 141, 1, 50, 131, 54, 185, 15,
+    42, 109, 42, 66, 13, 117, 78, 5, 3, 1, 9, 2, 171, 10, 8, 32,
+    210, 1, 1, 11, 22, 0, 12, 49, 118, 94, 173, 0, 145, 1, 0, 0,
+    1, 24, 6, 131, 0, 240, 38, 7, 10, 50, 19, 29, 3, 16, 0, 30,
+    63, 261, 18, 247, 149, 168, 4, 2, 2, 429, 9, 33, 1, 407, 0, 10,
+    186, 5, 0, 0, 16, 372, 1, 0, 0, 13, 245, 0, 12, 32, 4, 0,
+    3, 2, 0, 96, 0, 104, 11, 159, 32, 0, 0, 1, 4, 0, 0, 67,
+    8, 1, 159, 27, 9, 6, 3, 29, 152, 39, 475, 90, 0, 474, 0, 50,
+    239, 24, 143, 0, 18, 33, 0, 8, 76, 49, 98, 8, 169, 283, 0, 8,
+    80, 10, 0, 0, 2, 91, 78, 81, 4, 140, 13, 5, 42, 22, 15, 66,
+    2, 0, 0, 6, 15, 0, 0, 46, 166, 249, 49, 7, 36, 19, 26, 44,
+    12, 1, 129, 6, 183, 3, 19, 3, 0, 15, 49, 12, 1, 106, 46, 25,
+    3, 0, 0, 136, 5, 11, 3, 92, 0, 0, 217, 54, 0, 9, 1, 5,
+    89, 1, 1, 0, 35, 12, 22, 0, 41, 1, 7, 0, 2, 202, 9, 140,
+    11, 2, 31, 23, 58, 19, 1, 29, 68, 1, 10, 22, 36, 1, 43, 0,
+    6, 1, 19, 14, 3, 47, 5, 0, 68, 24, 1, 3, 100, 10, 106, 1,
+    13, 177, 3, 0, 44, 189, 1, 100, 35, 0, 61, 30, 14, 7, 5, 277,
+    165, 7, 46, 0, 32, 289, 57, 16, 2, 1, 16, 1, 28, 10, 22, 489,
+    8, 56, 125, 5, 4, 1, 0, 9, 1, 180, 0, 11, 293, 9, 116, 234,
+    46, 187, 29, 9, 83, 71, 8, 0, 48, 3, 178, 0, 56, 2, 14, 34,
+    2, 62, 1, 115, 1, 34, 370, 28, 5, 6, 39, 96, 65, 0, 46, 50,
+    1, 41, 55, 7, 28, 2, 24, 1, 4, 23, 4, 77, 0, 0, 0, 91,
+    12, 549, 2, 45, 13, 52, 20, 15, 0, 8, 1, 1, 38, 2, 176, 37,
+    44, 9, 38, 388, 1, 0, 219, 0, 2, 8, 50, 3, 0, 11, 3, 128,
+    100, 73, 1, 19, 12, 11, 11, 1, 1, 1, 0, 109, 6, 3, 0, 220,
+    1, 158, 7, 95, 2, 1, 0, 51, 0, 146, 218, 42, 0, 6, 0, 0,
+    15, 6, 20, 0, 0, 6, 46, 0, 259, 27, 1, 6, 0, 21, 81, 249,
+    22, 57, 20, 34, 18, 9, 133, 52, 399, 144, 7, 0, 45, 21, 15, 2,
+    4, 1, 15, 21, 144, 10, 174, 62, 81, 56, 168, 1, 2, 15, 106, 5,
+    33, 46, 57, 99, 13, 78, 9, 2, 55, 9, 14, 33, 3, 35, 0, 14,
+    3, 175, 60, 89, 56, 35, 22, 30, 3, 20, 2, 1, 51, 36, 20, 48,
+    84, 39, 5, 123, 151, 28, 247, 46, 314, 98, 1, 93, 0, 15, 0, 117,
+    48, 2, 1, 125, 435, 8, 1, 6, 46, 164, 41, 33, 3, 15, 43, 118,
+    49, 12, 3, 17, 24, 84, 15, 1, 6, 19, 0, 21, 34, 27, 4, 108,
+    90, 140, 0, 2, 68, 315, 23, 450, 3, 14, 13, 1, 9, 4, 275, 1,
+    378, 55, 8, 27, 12, 0, 0, 15, 62, 0, 28, 9, 36, 6, 3, 47,
+    190, 69, 1, 114, 418, 37, 414, 2, 38, 2, 100, 0, 80, 62, 1, 3,
+    3, 1, 147, 103, 0, 35, 78, 238, 0, 1, 0, 26, 74, 0, 0, 77,
+    55, 10, 36, 35, 0, 826, 23, 105, 26, 24, 98, 9, 89, 78, 66, 12,
+    85, 33, 69, 68, 89, 51, 23, 10, 0, 167, 146, 91, 23, 12, 90, 54,
+    9, 3, 328, 50, 3, 139, 0, 130, 52, 138, 53, 78, 0, 1, 18, 37,
+    13, 306, 5, 10, 183, 720, 7, 178, 28, 141, 7, 9, 1, 7, 3, 7,
+    65, 114, 7, 7, 16, 437, 262, 7, 0, 1, 28, 90, 225, 4, 19, 0,
+    71, 373, 19, 76, 100, 41, 305, 2, 4, 0, 18, 0, 0, 16, 27, 0,
+    50, 28, 95, 15, 2, 36, 0, 74, 14, 106, 7, 9, 154, 6, 103, 212,
+    66, 7, 155, 22, 14, 1, 507, 246, 5, 0, 74, 4, 9, 88, 0, 62,
+    0, 101, 30, 6, 267, 447, 111, 1, 27, 112, 84, 12, 92, 21, 79, 0,
+    16, 82, 62, 5, 11, 42, 155, 475, 0, 4, 53, 14, 12, 19, 59, 0,
+    0, 7, 19, 62, 21, 3, 81, 6, 4, 33, 14, 60, 0, 253, 164, 189,
+    3, 4, 236, 145, 21, 3, 46, 12, 4, 0, 3, 252, 97, 324, 63, 3,
+    18, 0, 42, 192, 4, 9, 94, 17, 0, 0, 0, 48, 2, 14, 91, 23,
+    365, 0, 4, 4, 51, 60, 4, 46, 55, 0, 173, 2, 115, 30, 0, 154,
+    50, 555, 26, 3, 47, 148, 3, 89, 19, 35, 17, 1, 1, 68, 0, 81,
+    67, 0, 15, 0, 9, 114, 1, 0, 6, 31, 122, 1, 54, 132, 114, 0,
+    38, 68, 85, 89, 1, 3, 0, 202, 146, 122, 18, 7, 0, 19, 1, 1,
+    7, 8, 35, 186, 2, 59, 119, 53, 21, 678, 178, 15, 0, 0, 3, 41,
+    1, 0, 615, 9, 39, 3, 0, 0, 16, 2, 127, 0, 0, 92, 3, 21,
+    0, 353, 102, 10, 43, 166, 95, 218, 2, 28, 23, 0, 15, 39, 154, 385,
+    4, 106, 0, 3, 44, 4, 78, 5, 1, 2, 5, 1, 34, 0, 28, 232,
+    8, 2, 3, 111, 61, 97, 126, 4, 25, 11, 0, 132, 0, 0, 271, 6,
+    15, 2, 233, 0, 221, 526, 8, 18, 25, 82, 7, 0, 1, 0, 25, 0,
+    395, 23, 1, 25, 3, 4, 6, 2, 0, 135, 5, 0, 27, 26, 78, 149,
+    7, 26, 10, 1, 36, 10, 14, 53, 91, 0, 53, 33, 1, 4, 112, 0,
+    168, 46, 373, 19, 166, 97, 170, 132, 20, 0, 14, 31, 0, 0, 9, 3,
+    21, 254, 4, 4, 8, 20, 95, 4, 99, 24, 86, 0, 11, 231, 79, 0,
+    3, 2, 341, 0, 14, 97, 8, 39, 75, 0, 17, 2, 7, 25, 24, 0,
+    161, 21, 104, 29, 177, 41, 74, 2, 67, 14, 4, 210, 19, 18, 0, 416,
+    6, 22, 148, 175, 1, 101, 306, 1, 0, 62, 37, 0, 13, 0, 125, 49,
+    65, 166, 2, 3, 2, 7, 10, 96, 45, 119, 4, 4, 0, 0, 2, 109,
+    0, 38, 8, 115, 80, 60, 39, 192, 19, 148, 15, 0, 444, 0, 1, 183,
+    3, 170, 4, 3, 11, 57, 1, 0, 20, 12, 141, 87, 156, 6, 112, 10,
+    1, 77, 0, 10, 8, 1, 90, 1, 1, 3, 344, 14, 0, 110, 0, 29,
+    90, 4, 8, 0, 116, 0, 713, 0, 27, 0, 2, 204, 10, 238, 0, 472,
+    68, 73, 64, 1, 44, 163, 257, 0, 0, 0, 50, 25, 21, 8, 20, 1,
+    38, 36, 0, 43, 0, 4, 5, 121, 300, 196, 0, 35, 1, 1, 9, 135,
+    0, 70, 528, 219, 39, 44, 28, 9, 17, 39, 4, 55, 13, 0, 8, 177,
+    145, 94, 6, 21, 29, 650, 12, 22, 24, 83, 16, 6, 30, 444, 3, 1,
+    16, 2, 171, 8, 239, 204, 33, 5, 6, 83, 40, 0, 6, 10, 90, 1,
+    210, 59, 0, 1, 69, 33, 30, 43, 17, 19, 39, 4, 118, 3, 1, 73,
+    7, 0, 32, 9, 159, 35, 76, 0, 4, 61, 0, 84, 15, 56, 345, 3,
+    46, 4, 1, 139, 5, 32, 1, 16, 1, 50, 0, 21, 92, 2, 204, 1,
+    39, 10, 34, 101, 0, 2, 1, 48, 46, 40, 63, 6, 22, 7, 0, 70,
+    0, 28, 0, 18, 15, 9, 8, 589, 4, 5, 81, 2, 217, 228, 1, 2,
+    4, 7, 3, 94, 12, 2, 9, 0, 59, 376, 44, 26, 42, 26, 138, 387,
+    2, 0, 0, 1, 165, 12, 60, 8, 7, 71, 20, 1, 0, 157, 322, 16,
+    11, 0, 33, 33, 50, 177, 18, 3, 2, 1, 9, 104, 404, 0, 7, 5,
+    126, 20, 3, 2, 272, 35, 4, 13, 132, 14, 55, 278, 14, 27, 135, 7,
+    34, 357, 2, 4, 10, 154, 0, 48, 0, 73, 21, 77, 154, 0, 29, 306,
+    9, 392, 14, 66, 8, 90, 8, 52, 1, 33, 170, 319, 15, 3, 283, 21,
+    0, 271, 176, 1, 128, 19, 3, 1, 10, 5, 15, 64, 7, 0, 163, 29,
+    30, 74, 695, 3, 3, 12, 28, 2, 3, 30, 8, 14, 0, 6, 1, 25,
+    5, 21, 95, 5, 15, 98, 37, 80, 0, 28, 114, 21, 2, 40, 10, 1,
+    1, 5, 1, 32, 6, 90, 4, 441, 1, 54, 20, 406, 0, 0, 181, 295,
+    148, 141, 9, 403, 201, 3, 127, 78, 42, 146, 16, 147, 13, 11, 4, 57,
+    329, 5, 13, 19, 3, 18, 9, 0, 6, 91, 120, 3, 23, 134, 68, 2,
+    87, 641, 21, 0, 119, 7, 54, 32, 4, 692, 206, 14, 14, 46, 23, 34,
+    70, 19, 296, 4, 9, 77, 131, 530, 203, 102, 198, 4, 3, 194, 43, 140,
+    5, 1, 67, 324, 4, 0, 175, 25, 0, 16, 6, 5, 20, 31, 4, 8,
+    285, 1, 11, 141, 6, 48, 182, 0, 91, 332, 52, 119, 613, 45, 177, 66,
+    672, 17, 119, 41, 82, 0, 77, 0, 21, 2, 46, 1, 10, 0, 27, 33,
+    652, 5, 14, 40, 4, 35, 388, 69, 86, 9, 1, 24, 2, 6, 0, 137,
+    168, 2, 81, 1, 1, 107, 10, 0, 0, 55, 78, 0, 11, 1, 102, 2,
+    139, 270, 38, 337, 96, 48, 176, 0, 205, 0, 37, 16, 124, 83, 64, 5,
+    1, 226, 225, 6, 1, 3, 62, 3, 84, 85, 356, 153, 9, 6, 21, 26,
+    128, 3, 3, 1, 15, 10, 10, 12, 57, 56, 0, 31, 0, 18, 2, 8,
+    156, 1, 0, 24, 0, 0, 8, 55, 51, 83, 47, 14, 0, 136, 0, 169,
+    9, 2, 89, 292, 60, 175, 1, 1, 119, 0, 0, 0, 33, 13, 75, 0,
+    228, 54, 71, 229, 2, 7, 1, 9, 1, 18, 210, 193, 5, 47, 88, 81,
+    214, 3, 10, 0, 1, 3, 86, 2, 0, 3, 30, 160, 6, 98, 1, 0,
+    0, 3, 86, 99, 0, 7, 1, 4, 96, 0, 44, 156, 3, 43, 0, 59,
+    116, 55, 4, 76, 0, 54, 18, 33, 195, 3, 189, 28, 376, 183, 45, 1,
+    1, 7, 0, 17, 8, 7, 10, 15, 4, 154, 5, 10, 19, 42, 3, 55,
+    341, 11, 25, 353, 0, 28, 73, 15, 6, 10, 428, 2, 1, 8, 0, 83,
+    129, 28, 751, 2, 17, 281, 14, 68, 2, 8, 5, 33, 74, 0, 2, 1,
+    111, 0, 151, 64, 15, 19, 183, 10, 47, 126, 64, 1, 1, 139, 0, 19,
+    10, 7, 31, 105, 336, 14, 1, 7, 0, 179, 12, 0, 178, 1, 334, 20,
+    122, 7, 229, 66, 7, 40, 4, 0, 31, 1, 15, 30, 198, 92, 10, 21,
+    1, 0, 32, 148, 36, 1, 5, 16, 47, 176, 3, 24, 0, 7, 69, 0,
+    39, 0, 415, 187, 111, 57, 120, 75, 0, 320, 17, 62, 8, 2, 25, 118,
+    4, 46, 32, 14, 372, 1, 263, 0, 4, 97, 24, 2, 79, 450, 32, 28,
+    24, 55, 1, 7, 19, 72, 3, 399, 238, 22, 9, 114, 5, 16, 8, 82,
+    50, 1, 0, 303, 41, 10, 19, 10, 185, 287, 129, 33, 26, 9, 8, 72,
+    31, 10, 9, 176, 3, 195, 29, 401, 0, 5, 335, 30, 2, 115, 79, 42,
+    0, 53, 32, 12, 578, 64, 311, 79, 939, 20, 277, 120, 31, 14, 283, 33,
+    45, 123, 536, 97, 0, 182, 55, 106, 109, 81, 114, 303, 0, 102, 0, 145,
+    6, 25, 130, 7, 16, 48, 0, 12, 35, 72, 110, 612, 29, 3, 9, 75,
+    5, 66, 21, 22, 4, 0, 37, 5, 7, 20, 20, 100, 2, 438, 93, 1,
+    5, 59, 9, 24, 2, 25, 14, 57, 9, 6, 0, 202, 6, 1, 21, 493,
+    50, 2, 102, 3, 37, 6, 32, 359, 86, 309, 0, 0, 328, 155, 57, 98,
+    298, 10, 27, 294, 150, 109, 73, 1, 20, 1, 4, 45, 65, 0, 121, 111,
+    5, 59, 66, 21, 166, 1, 100, 10, 280, 15, 71, 101, 252, 336, 43, 8,
+    136, 70, 20, 2, 639, 568, 79, 37, 15, 0, 5, 2, 0, 0, 3, 359,
+    145, 31, 10, 9, 16, 260, 195, 116, 326, 34, 63, 130, 4, 260, 39, 15,
+    89, 108, 19, 14, 54, 5, 3, 2, 18, 8, 1, 7, 96, 39, 107, 3,
+    58, 10, 382, 47, 101, 229, 242, 1, 288, 0, 21, 72, 185, 10, 77, 0,
+    5, 28, 51, 171, 3, 34, 11, 23, 27, 840, 239, 24, 341, 473, 175, 2,
+    184, 157, 2, 25, 3, 5, 14, 17, 146, 29, 86, 8, 56, 134, 9, 379,
+    2, 0, 71, 0, 0, 61, 312, 67, 324, 6, 0, 67, 6, 759, 865, 164,
+    163, 80, 3, 211, 199, 4, 0, 8, 222, 35, 92, 20, 0, 0, 3, 9,
+    128, 29, 359, 93, 0, 0, 9, 89, 223, 597, 558, 3, 34, 603, 80, 315,
+    9, 132, 21, 55, 0, 2, 0, 19, 2, 29, 0, 4, 1, 0, 15, 0,
+    2, 17, 0, 79, 9, 11, 74, 79, 146, 92, 214, 20, 86, 1, 23, 681,
+    6, 39, 81, 398, 12, 148, 61, 2, 79, 164, 120, 5, 39, 8, 105, 0,
+    3, 18, 6, 8, 271, 5, 65, 93, 3, 5, 175, 94, 10, 37, 310, 39,
+    15, 26, 83, 81, 347, 124, 8, 1, 41, 23, 33, 48, 126, 92, 71, 275,
+    195, 179, 185, 497, 2, 187, 116, 487, 39, 24, 74, 32, 0, 177, 16, 117,
+    10, 91, 955, 12, 361, 5, 929, 87, 4, 168, 18, 170, 22, 1, 3, 0,
+    30, 18, 24, 221, 7, 5, 2, 2, 0, 1, 384, 9, 45, 26, 19, 18,
+    0, 3, 113, 31, 0, 5, 201, 44, 120, 16, 0, 9, 3, 1, 255, 112,
+    274, 4, 349, 214, 1, 8, 141, 456, 27, 597, 18, 29, 338, 94, 259, 479,
+    0, 14, 24, 43, 24, 44, 76, 6, 0, 291, 12, 124, 5, 393, 229, 131,
+    3, 1, 99, 21, 10, 13, 1, 1, 241, 7, 139, 424, 86, 67, 14, 128,
+    26, 5, 7, 65, 58, 717, 2, 1, 0, 53, 3, 509, 183, 172, 92, 13,
+    4, 6, 3, 298, 50, 1, 25, 78, 42, 1, 8, 38, 48, 99, 78, 6,
+    19, 74, 227, 1, 15, 8, 55, 52, 1, 0, 245, 3, 60, 275, 4, 24,
+    340, 320, 93, 3, 43, 64, 198, 178, 2, 573, 50, 3, 5, 429, 2, 14,
+    152, 18, 7, 5, 46, 1, 125, 80, 156, 3, 14, 7, 408, 7, 32, 0,
+    111, 44, 22, 153, 11, 76, 36, 94, 19, 78, 32, 57, 3, 1, 61, 5,
+    64, 17, 25, 154, 0, 70, 0, 4, 29, 107, 23, 57, 22, 350, 355, 4,
+    13, 40, 166, 21, 221, 1, 41, 463, 137, 13, 9, 1, 7, 69, 22, 13,
+    97, 2, 21, 1, 671, 1, 25, 104, 14, 4, 539, 33, 230, 299, 3, 1057,
+    19, 431, 2, 91, 311, 25, 74, 10, 12, 16, 7, 16, 56, 87, 53, 2,
+    29, 49, 0, 11, 0, 616, 39, 0, 72, 58, 52, 592, 112, 137, 287, 31,
+    101, 0, 0, 26, 100, 275, 136, 470, 76, 17, 166, 144, 11, 0, 968, 0,
+    32, 35, 57, 140, 123, 86, 84, 141, 129, 187, 11, 141, 274, 421, 54, 8,
+    56, 93, 0, 42, 194, 55, 17, 23, 412, 5, 34, 22, 3, 6, 24, 39,
+    0, 27, 35, 7, 1, 5, 11, 6, 0, 72, 24, 154, 144, 0, 30, 5,
+    66, 212, 242, 1, 1, 37, 1154, 292, 181, 76, 711, 103, 147, 29, 19, 195,
+    2, 2, 1, 17, 146, 81, 40, 351, 4, 92, 14, 42, 217, 25, 135, 138,
+    23, 281, 41, 0, 0, 111, 12, 8, 16, 109, 0, 1, 0, 436, 5, 151,
+    420, 20, 5, 89, 6, 16, 53, 3, 12, 109, 2, 13, 63, 155, 28, 134,
+    16, 0, 15, 17, 72, 30, 15, 3, 255, 4, 1, 1165, 2, 29, 0, 11,
+    24, 5, 42, 0, 0, 91, 1, 292, 32, 843, 12, 46, 2, 10, 187, 24,
+    30, 12, 95, 274, 0, 11, 13, 32, 3, 114, 157, 108, 37, 39, 53, 2,
+    28, 98, 46, 32, 254, 560, 35, 264, 3, 3, 781, 64, 163, 141, 68, 8,
+    2, 73, 576, 0, 19, 44, 6, 49, 0, 111, 7, 1, 20, 150, 83, 0,
+    267, 17, 16, 52, 563, 17, 4, 2, 1, 174, 486, 632, 2, 648, 81, 61,
+    6, 205, 165, 12, 19, 0, 42, 99, 36, 349, 0, 131, 70, 19, 10, 9,
+    304, 188, 437, 43, 121, 4, 29, 573, 0, 0, 3, 5, 8, 0, 230, 93,
+    29, 214, 215, 12, 269, 329, 171, 0, 18, 2, 37, 574, 29, 173, 165, 14,
+    15, 2, 12, 24, 65, 226, 53, 6, 271, 58, 6, 9, 124, 0, 112, 45,
+    0, 73, 0, 42, 102, 4, 16, 32, 1, 74, 2, 0, 0, 13, 134, 1,
+    96, 14, 3, 303, 700, 0, 21, 232, 28, 115, 353, 1, 141, 65, 76, 62,
+    1, 172, 15, 141, 6, 0, 67, 14, 0, 662, 9, 90, 0, 113, 9, 12,
+    71, 54, 0, 12, 2, 43, 441, 76, 103, 0, 2, 47, 2, 1, 0, 1,
+    1147, 0, 28, 26, 169, 2, 0, 3, 119, 184, 50, 28, 87, 2, 35, 7,
+    2, 40, 74, 0, 6, 222, 86, 445, 0, 39, 592, 15, 29, 257, 45, 48,
+    0, 1, 2, 5, 116, 18, 123, 50, 69, 0, 113, 16, 55, 174, 0, 16,
+    112, 12, 21, 289, 172, 45, 18, 48, 419, 0, 74, 0, 0, 49, 176, 1,
+    1, 446, 0, 679, 0, 8, 152, 25, 144, 64, 16, 0, 444, 12, 206, 77,
+    101, 44, 165, 184, 39, 32, 94, 0, 70, 1, 17, 74, 0, 0, 18, 28,
+    40, 51, 1, 16, 21, 14, 11, 42, 135, 35, 2, 3, 3, 13, 127, 343,
+    648, 40, 15, 30, 1299, 400, 132, 8, 428, 2, 29, 8, 1, 126, 146, 151,
+    19, 339, 151, 266, 10, 21, 309, 1, 14, 7, 3, 0, 0, 3, 68, 144,
+    67, 15, 8, 1, 93, 0, 16, 20, 1, 47, 458, 189, 141, 576, 25, 33,
+    56, 9, 1, 0, 16, 19, 168, 11, 52, 0, 2, 264, 14, 153, 79, 174,
+    8, 107, 0, 5, 22, 0, 14, 20, 50, 350, 12, 106, 15, 85, 29, 0,
+    17, 2, 0, 135, 28, 2, 3, 31, 1456, 3, 7, 3, 236, 44, 67, 68,
+    68, 182, 0, 191, 14, 129, 12, 1, 125, 11, 19, 14, 39, 11, 143, 58,
+    23, 2, 91, 14, 0, 35, 21, 152, 20, 3, 151, 29, 15, 114, 45, 296,
+    96, 561, 3, 146, 34, 166, 0, 319, 267, 144, 126, 5, 11, 92, 5, 0,
+    2, 1, 36, 538, 14, 0, 0, 25, 0, 4, 27, 1, 21, 80, 15, 126,
+    0, 14, 1, 34, 63, 6, 9, 292, 9, 227, 502, 40, 787, 0, 17, 268,
+    112, 84, 0, 1, 2, 2, 0, 29, 53, 102, 3, 191, 12, 96, 2, 55,
+    7, 1585, 211, 98, 9, 285, 2, 1, 477, 37, 17, 61, 0, 5, 397, 43,
+    56, 41, 37, 236, 89, 35, 26, 603, 103, 14, 13, 45, 1, 272, 222, 199,
+    15, 9, 106, 70, 33, 1, 132, 984, 1, 0, 35, 820, 9, 0, 40, 474,
+    206, 140, 125, 13, 342, 18, 227, 0, 24, 0, 12, 8, 19, 18, 148, 170,
+    43, 938, 108, 72, 0, 51, 1, 82, 64, 34, 27, 735, 154, 75, 89, 6,
+    6, 6, 7, 0, 23, 4, 3, 11, 168, 4, 26, 269, 92, 52, 144, 326,
+    954, 125, 87, 365, 0, 9, 37, 0, 51, 3, 281, 0, 0, 10, 32, 42,
+    37, 18, 957, 453, 3, 454, 310, 256, 15, 108, 5, 62, 0, 8, 17, 3,
+    20, 264, 0, 312, 169, 1, 56, 383, 154, 30, 0, 2, 29, 74, 47, 80,
+    25, 50, 280, 189, 2, 134, 12, 7, 3, 11, 523, 172, 152, 357, 25, 54,
+    177, 4, 654, 5, 4, 77, 898, 279, 0, 95, 3, 66, 9, 67, 129, 128,
+    2, 265, 653, 27, 326, 58, 0, 0, 255, 8, 146, 176, 162, 40, 4, 197,
+    90, 10, 35, 22, 0, 79, 547, 577, 13, 204, 318, 0, 0, 3, 367, 39,
+    1, 4, 374, 3, 110, 0, 1, 6, 12, 420, 22, 214, 226, 1185, 17, 285,
+    10, 1389, 60, 7, 4, 5, 60, 84, 852, 1255, 36, 538, 123, 2, 3, 199,
+    340, 236, 294, 36, 1, 16, 1, 6, 2, 82, 108, 99, 55, 80, 0, 17,
+    25, 20, 0, 48, 1022, 65, 1, 37, 16, 26, 448, 100, 1, 164, 6, 141,
+    110, 115, 5, 217, 3, 8, 0, 0, 39, 123, 52, 0, 13, 77, 180, 122,
+    234, 10, 40, 340, 230, 42, 26, 481, 219, 7, 233, 0, 468, 102, 1, 185,
+    13, 23, 3, 290, 55, 1, 628, 78, 141, 89, 85, 48, 150, 81, 209, 42,
+    3, 985, 112, 175, 3, 0, 9, 53, 0, 77, 246, 52, 53, 132, 3, 112,
+    0, 116, 69, 24, 201, 762, 1, 153, 2, 11, 194, 0, 99, 342, 84, 209,
+    17, 7, 192, 661, 448, 10, 258, 355, 141, 11, 3, 2, 2, 260, 21, 7,
+    827, 36, 145, 60, 66, 1, 0, 203, 18, 62, 77, 367, 23, 348, 25, 11,
+    232, 67, 139, 1, 392, 37, 95, 10, 1, 163, 0, 32, 844, 1520, 417, 0,
+    364, 13, 621, 72, 342, 159, 36, 520, 0, 94, 74, 25, 3, 4, 42, 3,
+    198, 182, 0, 1, 36, 12, 8, 562, 3, 95, 95, 310, 64, 411, 12, 0,
+    345, 148, 22, 45, 286, 0, 19, 44, 137, 13, 25, 1, 24, 210, 98, 49,
+    0, 1063, 19, 67, 75, 4, 253, 1, 1, 549, 530, 23, 0, 13, 0, 0,
+    420, 296, 13, 14, 14, 722, 45, 12, 28, 637, 272, 424, 9, 18, 110, 0,
+    56, 31, 92, 73, 32, 20, 26, 1, 17, 6, 2278, 0, 123, 4, 168, 0,
+    479, 9, 1, 335, 3, 80, 148, 676, 27, 211, 8, 1, 0, 0, 62, 1,
+    0, 0, 426, 17, 0, 2, 312, 262, 92, 370, 1, 275, 4, 4, 2, 56,
+    21, 2, 293, 125, 64, 280, 29, 73, 700, 93, 30, 108, 3, 87, 18, 1258,
+    289, 60, 15, 72, 69, 942, 41, 2001, 31, 10, 38, 3, 17, 263, 204, 186,
+    492, 136, 28, 1878, 38, 145, 14, 30, 70, 179, 29, 448, 84, 324, 131, 10,
+    54, 4, 132, 575, 30, 34, 1, 52, 77, 18, 82, 390, 1, 72, 1163, 10,
+    18, 21, 1, 84, 14, 171, 565, 56, 195, 257, 0, 108, 2341, 253, 51, 178,
+    151, 6, 4, 176, 30, 0, 6, 27, 288, 7, 172, 198, 163, 21, 1036, 38,
+    2, 82, 5, 1, 63, 35, 0, 146, 79, 12, 2, 61, 1235, 1, 4, 1001,
+    109, 42, 38, 18, 14, 98, 250, 731, 407, 280, 0, 6, 93, 1428, 133, 2,
+    0, 0, 78, 54, 88, 5, 6, 95, 122, 52, 39, 93, 450, 52, 0, 9,
+    33, 343, 12, 2, 16, 99, 39, 208, 17, 285, 1534, 217, 188, 21, 513, 35,
+    22, 9, 9, 185, 248, 60, 76, 76, 4, 27, 265, 8, 85, 1, 23, 172,
+    120, 42, 290, 255, 11, 292, 255, 608, 107, 49, 113, 309, 130, 88, 1, 6,
+    544, 4, 466, 1, 64, 13, 16, 0, 1, 147, 350, 285, 0, 66, 199, 103,
+    120, 311, 269, 3, 135, 33, 39, 34, 148, 26, 2, 157, 405, 0, 567, 71,
+    4, 27, 199, 0, 3, 10, 11, 65, 47, 319, 778, 91, 235, 68, 226, 1,
+    0, 59, 3, 44, 315, 6, 30, 2, 569, 31, 4, 136, 18, 8, 2, 64,
+    105, 21, 10, 373, 22, 0, 0, 4, 0, 117, 1, 9, 33, 626, 8, 170,
+    181, 24, 914, 61, 426, 2, 337, 367, 6, 600, 1, 46, 861, 904, 5, 3,
+    57, 86, 96, 21, 4, 2, 41, 114, 115, 30, 3, 746, 35, 172, 11, 14,
+    83, 479, 5, 0, 329, 136, 68, 600, 68, 137, 577, 8, 6, 2, 1, 1902,
+    153, 99, 215, 119, 15, 15, 0, 3, 389, 5, 50, 64, 66, 21, 35, 5,
+    19, 7, 571, 153, 0, 28, 5, 0, 1, 177, 5, 34, 40, 30, 47, 422,
+    211, 174, 327, 0, 0, 1106, 2, 235, 27, 2, 2, 0, 0, 147, 533, 246,
+    44, 620, 2, 687, 30, 115, 7, 19, 26, 39, 41, 0, 299, 6, 51, 179,
+    203, 6, 0, 77, 274, 5, 150, 135, 50, 471, 10, 1199, 3, 251, 98, 2,
+    202, 19, 19, 53, 7, 178, 1, 204, 28, 24, 6, 52, 2, 10, 1, 569,
+    114, 407, 153, 244, 117, 64, 100, 7, 165, 9, 31, 117, 1, 1, 65, 48,
+    0, 7, 6, 2, 1023, 5, 0, 7, 88, 862, 0, 35, 121, 110, 19, 131,
+    158, 10, 4, 218, 71, 55, 11, 2, 87, 722, 113, 170, 63, 859, 0, 608,
+    5, 15, 461, 228, 165, 428, 22, 7, 662, 84, 227, 298, 2567, 31, 980, 3,
+    35, 5, 1514, 856, 102, 45, 317, 90, 25, 197, 42, 761, 566, 1, 17, 35,
+    219, 0, 2, 10, 8, 52, 0, 39, 4, 256, 214, 0, 3, 138, 1, 175,
+    107, 1, 0, 53, 107, 0, 350, 2, 144, 7, 42, 311, 11, 26, 0, 66,
+    1, 12, 0, 125, 74, 1033, 335, 44, 44, 100, 499, 460, 244, 209, 80, 55,
+    5, 99, 90, 328, 810, 11, 7, 2, 163, 30, 525, 7, 30, 1115, 106, 31,
+    570, 5, 38, 80, 18, 84, 2, 0, 342, 24, 3, 39, 9, 95, 113, 441,
+    35, 0, 36, 342, 10, 4, 0, 24, 1, 728, 165, 55, 17, 90, 34, 705,
+    14, 1, 5, 14, 0, 60, 3, 0, 183, 271, 140, 19, 58, 175, 157, 508,
+    568, 0, 26, 26, 1, 84, 1078, 636, 0, 112, 60, 22, 136, 170, 2, 88,
+    5, 19, 3, 106, 88, 0, 254, 4, 0, 7, 37, 80, 17, 204, 121, 15,
+    972, 44, 0, 13, 22, 172, 596, 208, 364, 2, 72, 145, 2, 52, 45, 293,
+    45, 48, 201, 32, 12, 24, 71, 273, 83, 289, 651, 25, 138, 81, 1, 3,
+    2, 47, 16, 11, 0, 0, 6, 139, 21, 9, 24, 94, 338, 0, 1, 4,
+    17, 125, 198, 176, 350, 3, 1, 10, 35, 63, 526, 18, 41, 178, 4, 80,
+    193, 443, 31, 6, 10, 255, 364, 396, 47, 33, 4, 2, 0, 9, 29, 43,
+    3, 0, 0, 49, 396, 131, 49, 1, 75, 197, 5, 380, 3, 47, 281, 16,
+    305, 51, 77, 953, 1643, 809, 158, 97, 185, 19, 5, 240, 5, 35, 4, 275,
+    49, 5, 69, 0, 279, 37, 454, 5, 3, 148, 0, 0, 160, 140, 2, 85,
+    1, 79, 296, 84, 20, 7, 130, 118, 133, 4, 0, 650, 2, 1, 163, 490,
+    14, 12, 11, 1, 158, 1, 74, 23, 710, 310, 14, 0, 50, 120, 707, 23,
+    27, 15, 2, 5, 868, 18, 8, 8, 726, 1702, 477, 17, 53, 632, 1, 146,
+    290, 693, 0, 430, 194, 248, 7, 3, 0, 0, 30, 108, 9, 0, 772, 23,
+    375, 774, 429, 0, 181, 0, 1129, 266, 32, 5, 238, 53, 1, 247, 15, 1248,
+    127, 150, 75, 305, 28, 0, 17, 21, 142, 47, 140, 88, 872, 432, 64, 91,
+    1194, 217, 1157, 21, 235, 444, 82, 0, 25, 240, 65, 49, 240, 595, 188, 150,
+    0, 37, 782, 130, 2, 417, 485, 7, 43, 41, 5, 9, 64, 84, 73, 79,
+    5, 408, 1716, 20, 1, 157, 112, 572, 35, 46, 239, 24, 250, 113, 242, 0,
+    2, 3, 327, 4, 12, 0, 3, 12, 73, 446, 120, 1406, 166, 26, 397, 485,
+    27, 0, 423, 3, 0, 129, 2668, 10, 84, 135, 1, 282, 170, 28, 19, 422,
+    36, 9, 660, 484, 368, 369, 0, 22, 556, 41, 143, 152, 1, 18, 472, 2,
+    1, 426, 0, 185, 37, 106, 331, 9, 12, 6, 0, 2, 35, 909, 585, 29,
+    60, 3, 542, 73, 73, 105, 1859, 1704, 63, 0, 1, 6, 31, 3260, 663, 423,
+    11, 139, 340, 177, 42, 1068, 609, 0, 97, 2820, 273, 195, 338, 4, 0, 56,
+    9, 1226, 21, 2, 3, 87, 264, 135, 897, 14, 187, 1, 1006, 66, 26, 7,
+    0, 228, 0, 178, 148, 62, 1390, 83, 584, 754, 49, 48, 1514, 17, 1193, 59,
+    0, 570, 3, 142, 203, 249, 2, 132, 383, 339, 0, 68, 0, 150, 10, 301,
+    1, 0, 314, 900, 0, 3, 281, 27, 703, 1, 373, 1271, 57, 262, 1341, 259,
+    32, 5, 0, 805, 365, 1135, 602, 5, 487, 53, 219, 71, 44, 155, 0, 828,
+    49, 16, 411, 77, 14, 34, 13, 20, 160, 30, 9, 273, 31, 554, 268, 0,
+    1, 10, 493, 83, 45, 63, 30, 623, 311, 32, 0, 151, 2, 19, 116, 4660,
+    457, 14, 925, 493, 177, 1, 59, 89, 0, 54, 93, 169, 31, 10, 14, 1,
+    1613, 17, 1989, 8, 1080, 86, 59, 41, 97, 89, 81, 27, 34, 586, 103, 1,
+    7, 49, 0, 2, 5, 0, 161, 0, 341, 33, 0, 43, 298, 0, 46, 659,
+    0, 56, 513, 9, 100, 139, 57, 198, 12, 0, 15, 1993, 0, 1855, 0, 62,
+    514, 0, 338, 454, 13, 464, 8, 624, 4, 325, 0, 13, 114, 5, 14, 52,
+    97, 7, 142, 28, 5, 799, 102, 1, 176, 118, 0, 171, 99, 39, 54, 15,
+    314, 30, 499, 1, 45, 0, 117, 15, 3009, 48, 2, 234, 0, 32, 48, 110,
+    3, 13, 102, 278, 0, 7, 1111, 104, 1135, 72, 26, 44, 17, 1, 28, 113,
+    138, 5, 31, 1, 486, 200, 52, 3, 648, 690, 105, 9, 1, 775, 197, 129,
+    1026, 150, 3, 91, 12, 107, 171, 394, 623, 227, 387, 327, 34, 0, 26, 714,
+    33, 661, 200, 202, 21, 0, 13, 59, 72, 60, 0, 3605, 121, 79, 18, 31,
+    19, 71, 259, 347, 78, 1308, 0, 160, 639, 0, 486, 34, 0, 23, 2, 58,
+    10, 591, 122, 604, 67, 26, 380, 10, 47, 101, 0, 32, 0, 1, 1, 1,
+    39, 118, 16, 20, 166, 5, 572, 768, 678, 1266, 283, 353, 74, 881, 6, 78,
+    2543, 104, 0, 1, 45, 0, 74, 0, 3, 105, 507, 0, 791, 0, 120, 117,
+    10, 1369, 629, 66, 13, 30, 3, 33, 75, 784, 21, 23, 0, 70, 653, 39,
+    140, 2, 30, 17, 33, 48, 1002, 25, 83, 375, 13, 926, 901, 8, 22, 12,
+    1286, 495, 35, 4, 16, 7, 3, 1267, 0, 9, 907, 6, 801, 6, 1078, 60,
+    0, 376, 32, 9, 0, 379, 257, 17, 2, 0, 1426, 23, 320, 5, 45, 316,
+    2, 141, 636, 805, 0, 162, 7, 187, 34, 0, 212, 10, 28, 474, 107, 6,
+    106, 0, 0, 48, 1885, 25, 168, 41, 4, 70, 61, 267, 84, 14, 14, 1,
+    270, 194, 14, 383, 19, 47, 131, 1884, 154, 98, 21, 474, 24, 6, 145, 51,
+    1, 16, 4, 7, 884, 3, 34, 0, 52, 50, 342, 166, 71, 9, 340, 1,
+    134, 26, 13, 180, 203, 367, 103, 570, 6, 1429, 213, 218, 45, 93, 3, 2,
+    1, 23, 31, 134, 1128, 76, 1631, 10, 157, 13, 201, 7, 60, 71, 734, 703,
+    34, 33, 15, 11, 625, 156, 2679, 224, 334, 1, 129, 1780, 21, 155, 51, 51,
+    10, 8, 453, 1199, 445, 91, 24, 0, 165, 986, 760, 413, 449, 4, 1, 278,
+    12, 0, 45, 687, 1, 1, 54, 396, 377, 0, 2238, 32, 2346, 2, 2, 37,
+    201, 462, 1545, 0, 248, 96, 20, 26, 666, 66, 868, 1437, 2, 34, 36, 4,
+    10, 1, 94, 129, 29, 758, 5, 6, 576, 175, 6, 151, 463, 0, 61, 33,
+    85, 3, 5, 1, 237, 617, 16, 1089, 15, 41, 8, 5, 298, 218, 140, 188,
+    5, 41, 650, 22, 518, 58, 143, 10, 0, 75, 2, 2, 12, 16, 157, 30,
+    388, 265, 1876, 1031, 0, 10, 924, 20, 885, 9, 8, 14, 27, 526, 8, 1,
+    674, 113, 0, 32, 14, 3, 125, 68, 151, 707, 311, 200, 20, 1209, 1255, 127,
+    235, 1131, 1296, 219, 1204, 9, 201, 5, 0, 102, 38, 1133, 99, 563, 318, 0,
+    171, 0, 5, 0, 322, 84, 41, 27, 204, 1051, 501, 40, 1378, 28, 448, 588,
+    0, 107, 0, 27, 395, 0, 2, 9, 15, 247, 101, 0, 79, 185, 387, 167,
+    6, 2833, 284, 1918, 377, 2494, 69, 0, 29, 1, 59, 86, 38, 0, 513, 349,
+    5, 0, 1125, 334, 108, 11, 420, 490, 8, 350, 58, 1, 921, 0, 32, 20,
+    441, 56, 25, 15, 10, 20, 269, 110, 2, 45, 19, 85, 32, 946, 61, 682,
+    186, 2791, 1772, 537, 6, 9, 1, 73, 1078, 66, 314, 43, 115, 150, 6, 1571,
+    689, 0, 0, 18, 365, 859, 19, 9, 57, 461, 706, 45, 20, 362, 184, 226,
+    25, 27, 506, 2, 17, 0, 1264, 60, 333, 7, 609, 76, 14, 244, 330, 915,
+    14, 0, 33, 119, 28, 124, 1231, 570, 10, 38, 141, 233, 9, 83, 36, 242,
+    781, 251, 1, 40, 402, 1489, 0, 0, 205, 293, 52, 39, 71, 62, 7, 0,
+    758, 17, 96, 16, 27, 23, 10, 51, 626, 656, 0, 5, 39, 215, 0, 168,
+    704, 1388, 1, 802, 0, 231, 1240, 306, 1006, 73, 6, 865, 89, 1545, 85, 91,
+    2, 46, 2, 4, 321, 19, 10, 453, 348, 66, 39, 70, 32, 4, 69, 484,
+    2, 3, 2410, 325, 83, 3, 1069, 0, 37, 87, 273, 394, 309, 145, 0, 243,
+    43, 756, 187, 162, 242, 29, 525, 55, 1, 1339, 1, 129, 18, 6, 78, 4,
+    264, 38, 2, 347, 0, 24, 1, 0, 31, 132, 5, 4, 35, 3, 93, 15,
+    351, 7, 2, 432, 357, 419, 40, 69, 153, 2, 66, 41, 79, 1057, 81, 49,
+    423, 17, 211, 354, 14, 157, 248, 366, 125, 3, 962, 3935, 22, 7, 33, 532,
+    886, 0, 50, 291, 12, 0, 338, 68, 881, 1038, 31, 343, 4, 360, 1765, 6,
+    49, 15, 3, 19, 3, 108, 185, 39, 297, 1112, 16, 48, 5410, 59, 367, 13,
+    605, 50, 438, 302, 125, 826, 1078, 0, 53, 489, 282, 32, 8, 519, 476, 23,
+    359, 5, 117, 73, 589, 10, 471, 28, 427, 8, 13, 65, 405, 271, 178, 3,
+    69, 1, 4, 105, 163, 233, 891, 7, 480, 0, 429, 58, 118, 199, 1792, 80,
+    3, 1185, 364, 10, 24, 23, 6, 2, 1318, 351, 59, 185, 6, 271, 724, 465,
+    2, 136, 186, 12, 25, 496, 199, 0, 138, 11, 1582, 985, 0, 248, 404, 15,
+    4, 0, 69, 302, 1053, 1249, 0, 3159, 9, 7, 593, 1, 5, 310, 0, 0,
+    41, 11, 0, 2, 162, 93, 54, 892, 13, 24, 13, 882, 21, 149, 1931, 331,
+    277, 392, 7, 15, 346, 700, 96, 1375, 378, 133, 132, 54, 8, 42, 339, 120,
+    311, 65, 554, 2, 20, 306, 609, 6334, 99, 369, 3, 1685, 7, 352, 523, 51,
+    62, 1071, 12, 62, 91, 430, 137, 1, 0, 118, 145, 339, 10, 276, 30, 137,
+    109, 5, 11, 1909, 1279, 77, 65, 2145, 0, 169, 1, 148, 68, 7, 1648, 0,
+    29, 1118, 142, 1, 0, 1134, 14, 2480, 437, 445, 218, 336, 282, 4107, 391, 128,
+    210, 125, 82, 0, 310, 699, 126, 0, 434, 7, 1379, 35, 1, 2, 0, 11,
+    0, 0, 47, 22, 322, 18, 741, 323, 713, 3286, 723, 1, 0, 5, 809, 18,
+    238, 9, 1269, 841, 1676, 828, 62, 1, 6, 27, 4, 147, 842, 3, 84, 69,
+    659, 10, 516, 756, 4, 628, 389, 539, 21, 377, 185, 402, 205, 211, 116, 278,
+    108, 351, 123, 1109, 104, 154, 4, 18, 335, 0, 214, 1, 294, 10, 16, 171,
+    313, 122, 105, 2853, 7, 793, 2, 414, 2457, 24, 373, 3811, 12, 933, 2, 2,
+    167, 325, 96, 1100, 323, 377, 55, 357, 108, 0, 0, 10, 9, 344, 214, 266,
+    17, 1254, 19, 1993, 667, 840, 15, 1, 706, 338, 2368, 237, 366, 28, 282, 27,
+    92, 89, 2, 168, 202, 0, 102, 209, 358, 292, 476, 1, 1, 738, 5, 36,
+    61, 36, 817, 541, 4, 1362, 14, 0, 13, 0, 66, 186, 29, 12, 2219, 0,
+    2, 76, 1575, 46, 397, 1, 222, 4165, 0, 1008, 80, 416, 90, 1638, 750, 23,
+    1245, 309, 1022, 3414, 2, 65, 933, 98, 10, 0, 44, 103, 33, 125, 1380, 1,
+    60, 48, 1678, 416, 1, 7, 25, 744, 0, 266, 845, 220, 125, 0, 2316, 78,
+    314, 175, 1, 60, 730, 3, 518, 0, 1, 13, 39, 185, 135, 1, 2139, 63,
+    3, 192, 2277, 36, 43, 0, 1085, 1463, 329, 33, 0, 17, 71, 129, 0, 365,
+    2472, 10, 48, 0, 159, 201, 0, 714, 243, 184, 1714, 4330, 223, 1050, 192, 47,
+    16, 408, 605, 3, 945, 8, 49, 622, 381, 379, 10, 87, 36, 67, 2044, 11,
+    598, 56, 11, 518, 38, 336, 894, 225, 1114, 2, 3, 42, 20, 1270, 389, 1414,
+    97, 1915, 1, 1564, 57, 138, 1931, 520, 1261, 2266, 1, 68, 99, 735, 4, 314,
+    3207, 1, 107, 1, 372, 335, 4304, 36, 111, 303, 18, 865, 275, 197, 36, 478,
+    260, 216, 2050, 1818, 473, 527, 229, 9, 86, 18, 157, 1872, 621, 69, 1960, 2,
+    0, 307, 1120, 0, 348, 376, 4822, 5549, 362, 2776, 0, 1064, 232, 5427, 1, 8,
+    32, 4, 3, 314, 39, 6, 93, 297, 6, 326, 367, 7, 72, 733, 274, 227,
+    225, 0, 954, 15, 59, 226, 10, 12, 0, 5201, 593, 1674, 203, 282, 2308, 10,
+    346, 0, 450, 0, 8, 307, 3, 778, 15, 14, 244, 0, 44, 10, 13, 140,
+    1319, 309, 13, 87, 6, 144, 686, 67, 2, 3407, 449, 90, 56, 1264, 156, 216,
+    351, 1003, 1215, 90, 176, 745, 0, 24, 1, 2, 12, 3925, 380, 46, 17, 3,
+    399, 21, 66, 261, 19, 503, 397, 115, 122, 1682, 0, 1487, 95, 2355, 808, 2039,
+    196, 221, 2802, 255, 0, 2, 1958, 2081, 1032, 498, 78, 0, 62, 1049, 31, 489,
+    171, 1084, 69, 2203, 57, 7, 285, 1, 1826, 34, 16, 0, 766, 98, 3, 2000,
+    1373, 295, 377, 262, 10, 2, 15, 105, 4301, 193, 274, 134, 1025, 0, 157, 810,
+    193, 423, 225, 3586, 258, 284, 780, 93, 498, 57, 11, 542, 1856, 990, 1, 920,
+    22, 831, 23, 98, 3, 1, 3501, 328, 5, 1561, 887, 1281, 75, 977, 21, 190,
+    196, 279, 480, 411, 207, 9, 6, 5, 834, 11, 26, 325, 10, 2766, 4233, 1472,
+    7, 11, 127, 10, 710, 304, 17, 411, 13, 1660, 128, 129, 1, 3, 126, 9,
+    2, 1142, 436, 81, 627, 43, 2562, 2870, 8, 2588, 1195, 426, 5, 1516, 6865, 12,
+    537, 0, 207, 151, 205, 3125, 558, 0, 42, 297, 252, 108, 20, 118, 151, 271,
+    0, 1, 3, 6, 9, 38, 378, 134, 1019, 55, 169, 425, 702, 27, 1097, 25,
+    0, 146, 1428, 10, 0, 3084, 70, 196, 1149, 2145, 3672, 46, 24, 51, 214, 428,
+    34, 449, 37, 175, 23, 1636, 14, 0, 300, 55, 18, 153, 0, 3, 953, 998,
+    86, 797, 114, 1092, 747, 588, 34, 561, 3, 705, 367, 2, 234, 0, 3, 201,
+    654, 1, 186, 1837, 1, 55, 0, 985, 3056, 224, 0, 19, 17, 3709, 259, 5,
+    8, 330, 2548, 53, 0, 16, 0, 12, 49, 3916, 1888, 1029, 1633, 2993, 544, 21,
+    561, 774, 268, 267, 18, 178, 136, 40, 8215, 5, 54, 2289, 2070, 2164, 156, 615,
+    61, 1, 4190, 19, 39, 117, 1116, 731, 1117, 151, 734, 167, 415, 40, 86, 50,
+    2607, 722, 63, 508, 107, 4146, 0, 103, 724, 1715, 578, 82, 2, 0, 272, 104,
+    245, 1431, 2, 45, 638, 9, 398, 28, 854, 1, 147, 119, 6, 1561, 23, 167,
+    5, 3476, 8, 429, 20, 93, 6, 19, 6, 164, 72, 188, 1623, 306, 0, 0,
+    3338, 289, 1336, 23, 413, 187, 138, 103, 485, 773, 76, 613, 22, 1596, 588, 20,
+    7423, 2400, 2639, 1, 5439, 4305, 72, 4234, 0, 7, 6, 4970, 4, 4030, 3, 22,
+    61, 3394, 0, 2138, 39, 0, 5, 3, 1622, 1605, 1, 495, 29, 105, 1841, 43,
+    4714, 72, 11, 1753, 1, 22, 495, 1530, 5980, 0, 0, 282, 858, 8, 9, 5,
+    13, 1267, 124, 84, 100, 888, 5, 25, 9, 316, 2065, 2939, 29, 126, 25, 170,
+    2, 79, 16003, 0, 132, 932, 0, 189, 53, 5244, 479, 2091, 225, 0, 0, 4096,
+    25539, 3, 0, 4655, 82, 38, 851, 2, 5167, 1, 55, 656, 595, 146, 253, 5191,
+    1126, 320, 26, 1784, 278, 3057, 7628, 708, 20, 128, 5035, 4810, 112, 304, 841, 105,
+    1142, 32, 159, 4, 350, 5895, 4098, 45, 1071, 168, 2, 82, 1, 9, 0, 5705,
+    7, 8, 2110, 735, 257, 15, 10203,
+];
+
+pub(super) static ENTRIES: [(&str, &str); 29403] = [
+    ("dizzy", "dizzi"),
+    ("contenting", "content"),
+    ("breeder", "breeder"),
+    ("absenting", "absent"),
+    ("cargoes", "cargo"),
+    ("bowers", "bower"),
+    ("smilin", "smilin"),
+    ("madchen", "madchen"),
+    ("resurrections", "resurrect"),
+    ("lancerated", "lancer"),
+    ("snowed", "snow"),
+    ("sevastopol", "sevastopol"),
+    ("fried", "fri"),
+    ("softening", "soften"),
+    ("mortifying", "mortifi"),
+    ("rallus", "rallu"),
+    ("loonching", "loonch"),
+    ("instigated", "instig"),
+    ("jug", "jug"),
+    ("expressing", "express"),
+    ("manfully", "manfulli"),
+    ("pomp", "pomp"),
+    ("task", "task"),
+    ("motive", "motiv"),
+    ("dimpling", "dimpl"),
+    ("precedent", "preced"),
+    ("harnessing", "har"),
+    ("stubble", "stubbl"),
+    ("revivingly", "revivingli"),
+    ("civilised", "civilis"),
+    ("forecast", "forecast"),
+    ("putridity", "putrid"),
+    ("explain", "explain"),
+    ("colloquy", "colloqui"),
+    ("singular", "singular"),
+    ("biding", "bide"),
+    ("swayed", "swai"),
+    ("completest", "completest"),
+    ("layin", "layin"),
+    ("regression", "regress"),
+    ("ungrateful", "ungrat"),
+    ("venetian", "venetian"),
+    ("avoided", "avoid"),
+    ("renew", "renew"),
+    ("fossiles", "fossil"),
+    ("ironically", "iron"),
+    ("manor", "manor"),
+    ("part", "part"),
+    ("sloping", "slope"),
+    ("duke", "duke"),
+    ("austell", "austel"),
+    ("bulwarks", "bulwark"),
+    ("bencher", "bencher"),
+    ("oxidate", "oxid"),
+    ("belvawney", "belvawnei"),
+    ("bahia", "bahia"),
+    ("lazzeretto", "lazzeretto"),
+    ("butterfly", "butterfli"),
+    ("revisited", "revisit"),
+    ("trooper", "trooper"),
+    ("politely", "polit"),
+    ("weedur", "weedur"),
+    ("siberia", "siberia"),
+    ("dishevelled", "dishevel"),
+    ("mahomet", "mahomet"),
+    ("enrobed", "enrob"),
+    ("presented", "present"),
+    ("arn", "arn"),
+    ("drank", "drank"),
+    ("adultery", "adulteri"),
+    ("grafts", "graft"),
+    ("housebuilder", "housebuild"),
+    ("jennys", "jenni"),
+    ("kneaded", "knead"),
+    ("suds", "sud"),
+    ("foreman", "foreman"),
+    ("storms", "storm"),
+    ("imprinting", "imprint"),
+    ("countess", "countess"),
+    ("slugs", "slug"),
+    ("disappointing", "disappoint"),
+    ("hence", "henc"),
+    ("victoria", "victoria"),
+    ("overheard", "overheard"),
+    ("imperious", "imperi"),
+    ("animadvert", "animadvert"),
+    ("parry", "parri"),
+    ("calumny", "calumni"),
+    ("majority", "major"),
+    ("perchance", "perchanc"),
+    ("cinereus", "cinereu"),
+    ("chy", "chi"),
+    ("foresee", "forese"),
+    ("waged", "wage"),
+    ("reconcilable", "reconcil"),
+    ("installed", "instal"),
+    ("heave", "heav"),
+    ("beknown", "beknown"),
+    ("striking", "strike"),
+    ("soluble", "solubl"),
+    ("denizens", "denizen"),
+    ("realit", "realit"),
+    ("genesis", "genesi"),
+    ("fit", "fit"),
+    ("imparting", "impart"),
+    ("censures", "censur"),
+    ("harriet", "harriet"),
+    ("electrical", "electr"),
+    ("resolved", "resolv"),
+    ("thus", "thu"),
+    ("noised", "nois"),
+    ("redounded", "redound"),
+    ("advocating", "advoc"),
+    ("characters", "charact"),
+    ("dine", "dine"),
+    ("hoops", "hoop"),
+    ("vixenish", "vixenish"),
+    ("minds", "mind"),
+    ("bristled", "bristl"),
+    ("politician", "politician"),
+    ("crib", "crib"),
+    ("tremendous", "tremend"),
+    ("quoodle", "quoodl"),
+    ("bin", "bin"),
+    ("nt", "nt"),
+    ("melvilleson", "melvilleson"),
+    ("preoccupation", "preoccupaz"),
+    ("dulness", "dul"),
+    ("pillowed", "pillow"),
+    ("unwashed", "unwash"),
+    ("shingle", "shingl"),
+    ("circumambient", "circumambi"),
+    ("prussian", "prussian"),
+    ("connivance", "conniv"),
+    ("youthfulness", "youth"),
+    ("crushes", "crush"),
+    ("jose", "jose"),
+    ("choruses", "chorus"),
+    ("halfpence", "halfpenc"),
+    ("coin", "coin"),
+    ("soliloquize", "soliloqu"),
+    ("grist", "grist"),
+    ("dissent", "dissent"),
+    ("potations", "potaz"),
+    ("grouped", "group"),
+    ("bungle", "bungl"),
+    ("designing", "design"),
+    ("stocks", "stock"),
+    ("sharp", "sharp"),
+    ("bacon", "bacon"),
+    ("planning", "plan"),
+    ("attired", "attir"),
+    ("instituted", "institut"),
+    ("jocoseness", "jocos"),
+    ("ambassadress", "ambassadress"),
+    ("conchological", "concholog"),
+    ("champion", "champion"),
+    ("amassed", "amass"),
+    ("pleasantness", "pleasant"),
+    ("macphersons", "macpherson"),
+    ("ludship", "ludship"),
+    ("swain", "swain"),
+    ("urbanity", "urban"),
+    ("stinking", "stink"),
+    ("exterior", "exterior"),
+    ("torches", "torch"),
+    ("unfavourable", "unfavour"),
+    ("pointed", "point"),
+    ("examining", "examin"),
+    ("quints", "quint"),
+    ("accuracy", "accuraci"),
+    ("prompts", "prompt"),
+    ("gorged", "gorg"),
+    ("enslaving", "enslav"),
+    ("abyssinia", "abyssinia"),
+    ("shake", "shake"),
+    ("bromley", "bromlei"),
+    ("cinq", "cinq"),
+    ("diaries", "diari"),
+    ("boldness", "bold"),
+    ("dirtiest", "dirtiest"),
+    ("towering", "tower"),
+    ("loudly", "loudli"),
+    ("deputations", "deputaz"),
+    ("patula", "patula"),
+    ("purple", "purpl"),
+    ("benefactor", "benefactor"),
+    ("stranding", "strand"),
+    ("disbelief", "disbelief"),
+    ("inferior", "inferior"),
+    ("underbred", "underbr"),
+    ("defacing", "defac"),
+    ("sun", "sun"),
+    ("attempt", "attempt"),
+    ("chinchilloides", "chinchilloid"),
+    ("transaction", "transact"),
+    ("bottomless", "bottomless"),
+    ("greased", "greas"),
+    ("diffusing", "diffus"),
+    ("corns", "corn"),
+    ("dictatorial", "dictatori"),
+    ("toward", "toward"),
+    ("hoof", "hoof"),
+    ("walked", "walk"),
+    ("heighth", "heighth"),
+    ("addled", "addl"),
+    ("heedlessness", "heedless"),
+    ("sage", "sage"),
+    ("argues", "argu"),
+    ("hast", "hast"),
+    ("effectually", "effectu"),
+    ("elysian", "elysian"),
+    ("sleepiness", "sleepi"),
+    ("wit", "wit"),
+    ("testator", "testat"),
+    ("schilleresque", "schilleresqu"),
+    ("antipodes", "antipod"),
+    ("handing", "hand"),
+    ("makers", "maker"),
+    ("blind", "blind"),
+    ("receded", "rece"),
+    ("lessons", "lesson"),
+    ("sweepers", "sweeper"),
+    ("vendors", "vendor"),
+    ("soothing", "sooth"),
+    ("profoundest", "profoundest"),
+    ("poked", "poke"),
+    ("suited", "suit"),
+    ("ditches", "ditch"),
+    ("sunning", "sun"),
+    ("strangled", "strangl"),
+    ("discouraging", "discourag"),
+    ("patter", "patter"),
+    ("seemingly", "seemingli"),
+    ("settlement", "settlement"),
+    ("voters", "voter"),
+    ("curtaining", "curtain"),
+    ("conscious", "consciou"),
+    ("occasionally", "occasion"),
+    ("cloves", "clove"),
+    ("took", "took"),
+    ("harness", "har"),
+    ("darya", "darya"),
+    ("sphinxes", "sphinx"),
+    ("insistence", "insist"),
+    ("journal", "journal"),
+    ("oddness", "odd"),
+    ("zorillo", "zorillo"),
+    ("acquaintance", "acquaint"),
+    ("helper", "helper"),
+    ("kerrig", "kerrig"),
+    ("dupe", "dupe"),
+    ("referring", "refer"),
+    ("solicitous", "solicit"),
+    ("eastward", "eastward"),
+    ("surcingle", "surcingl"),
+    ("steadily", "steadili"),
+    ("manners", "manner"),
+    ("shaven", "shaven"),
+    ("deering", "deer"),
+    ("weevle", "weevl"),
+    ("petty", "petti"),
+    ("whitford", "whitford"),
+    ("tapping", "tap"),
+    ("miseries", "miseri"),
+    ("tubes", "tube"),
+    ("gravamen", "gravamen"),
+    ("emptiness", "empti"),
+    ("conclave", "conclav"),
+    ("impulsively", "impuls"),
+    ("water", "water"),
+    ("wherewith", "wherewith"),
+    ("relented", "relent"),
+    ("glows", "glow"),
+    ("histories", "histori"),
+    ("wetting", "wet"),
+    ("episodes", "episod"),
+    ("dissension", "dissens"),
+    ("gladiator", "gladiat"),
+    ("liberties", "liberti"),
+    ("supercilious", "supercili"),
+    ("condign", "condign"),
+    ("sublimity", "sublim"),
+    ("example", "exampl"),
+    ("ferocious", "feroci"),
+    ("vented", "vent"),
+    ("smouldering", "smoulder"),
+    ("sails", "sail"),
+    ("tart", "tart"),
+    ("lawk", "lawk"),
+    ("dugong", "dugong"),
+    ("spray", "sprai"),
+    ("temptation", "temptaz"),
+    ("midnight", "midnight"),
+    ("mounds", "mound"),
+    ("indubitable", "indubit"),
+    ("everywhere", "everywher"),
+    ("afflict", "afflict"),
+    ("emitting", "emit"),
+    ("lady", "ladi"),
+    ("reflects", "reflect"),
+    ("strata", "strata"),
+    ("glaringly", "glaringli"),
+    ("caste", "cast"),
+    ("spinster", "spinster"),
+    ("nightmare", "nightmar"),
+    ("smoothly", "smoothli"),
+    ("medicinal", "medicin"),
+    ("patent", "patent"),
+    ("portugal", "portug"),
+    ("unwearied", "unweari"),
+    ("staggers", "stagger"),
+    ("detailed", "detail"),
+    ("excellency", "excel"),
+    ("time", "time"),
+    ("impelled", "impel"),
+    ("elbow", "elbow"),
+    ("stealth", "stealth"),
+    ("artevelde", "arteveld"),
+    ("guzzling", "guzzl"),
+    ("wakefulness", "wake"),
+    ("insistently", "insist"),
+    ("area", "area"),
+    ("penetrate", "penetr"),
+    ("ourselves", "ourselv"),
+    ("penn", "penn"),
+    ("civilities", "civil"),
+    ("bachelorship", "bachelorship"),
+    ("seem", "seem"),
+    ("signatures", "signatur"),
+    ("fairies", "fairi"),
+    ("governesses", "gover"),
+    ("pronounced", "pronounc"),
+    ("blown", "blown"),
+    ("sailors", "sailor"),
+    ("acquiescing", "acquiesc"),
+    ("sittings", "sit"),
+    ("elated", "elat"),
+    ("birmingham", "birmingham"),
+    ("wages", "wage"),
+    ("deepens", "deepen"),
+    ("obstinate", "obstin"),
+    ("pervaded", "pervad"),
+    ("disinherits", "disinherit"),
+    ("gas", "ga"),
+    ("quay", "quai"),
+    ("revolving", "revolv"),
+    ("toleration", "toleraz"),
+    ("execution", "execut"),
+    ("unconsciously", "unconsci"),
+    ("particulars", "particular"),
+    ("rapidly", "rapidli"),
+    ("ventriloquists", "ventriloquist"),
+    ("esteem", "esteem"),
+    ("admirably", "admir"),
+    ("wicious", "wiciou"),
+    ("maculata", "maculata"),
+    ("recklessly", "recklessli"),
+    ("allies", "al"),
+    ("greedy", "greedi"),
+    ("persia", "persia"),
+    ("apologized", "apolog"),
+    ("constitutionally", "constitution"),
+    ("centered", "center"),
+    ("parisians", "parisian"),
+    ("stories", "stori"),
+    ("lashes", "lash"),
+    ("caffre", "caffr"),
+    ("dished", "dish"),
+    ("commo", "commo"),
+    ("hookites", "hookit"),
+    ("spin", "spin"),
+    ("trilling", "trill"),
+    ("coverts", "covert"),
+    ("taunto", "taunto"),
+    ("arrest", "arrest"),
+    ("westward", "westward"),
+    ("tinae", "tina"),
+    ("conveniently", "conveni"),
+    ("dependence", "depend"),
+    ("vue", "vue"),
+    ("percha", "percha"),
+    ("disputed", "disput"),
+    ("uniting", "unit"),
+    ("merciless", "merciless"),
+    ("tirade", "tirad"),
+    ("craftier", "craftier"),
+    ("fluctuated", "fluctuat"),
+    ("oneself", "oneself"),
+    ("scored", "score"),
+    ("combusting", "combust"),
+    ("superintending", "superintend"),
+    ("shrivelled", "shrivel"),
+    ("lounges", "loung"),
+    ("talguen", "talguen"),
+    ("crowds", "crowd"),
+    ("visited", "visit"),
+    ("alabaster", "alabast"),
+    ("coverings", "cover"),
+    ("othello", "othello"),
+    ("richly", "richli"),
+    ("finer", "finer"),
+    ("inroad", "inroad"),
+    ("shrugging", "shrug"),
+    ("inducements", "induc"),
+    ("remark", "remark"),
+    ("forewoman", "forewoman"),
+    ("mysterieuse", "mysterieus"),
+    ("dilating", "dilat"),
+    ("data", "data"),
+    ("usborne", "usborn"),
+    ("create", "creat"),
+    ("marker", "marker"),
+    ("albino", "albino"),
+    ("ornamenting", "ornament"),
+    ("balked", "balk"),
+    ("conjured", "conjur"),
+    ("unquestionable", "unquestion"),
+    ("embellishes", "embellish"),
+    ("latreille", "latreill"),
+    ("accompanied", "accompani"),
+    ("stoutly", "stoutli"),
+    ("garlanded", "garland"),
+    ("debasing", "debas"),
+    ("repents", "repent"),
+    ("distances", "distanc"),
+    ("rosas", "rosa"),
+    ("inserting", "insert"),
+    ("incitement", "incit"),
+    ("animate", "anim"),
+    ("awe", "aw"),
+    ("ombu", "ombu"),
+    ("globules", "globul"),
+    ("worthies", "worthi"),
+    ("pinned", "pin"),
+    ("cognovits", "cognovit"),
+    ("wa", "wa"),
+    ("ete", "et"),
+    ("loads", "load"),
+    ("vendome", "vendom"),
+    ("dom", "dom"),
+    ("worid", "worid"),
+    ("legend", "legend"),
+    ("litigious", "litigi"),
+    ("lately", "late"),
+    ("repudiated", "repudi"),
+    ("inquiries", "inquiri"),
+    ("adorations", "adoraz"),
+    ("outwards", "outward"),
+    ("expand", "expand"),
+    ("combative", "comb"),
+    ("bathes", "bath"),
+    ("angered", "anger"),
+    ("lark", "lark"),
+    ("impel", "impel"),
+    ("ranges", "rang"),
+    ("mottled", "mottl"),
+    ("eminently", "emin"),
+    ("aspires", "aspir"),
+    ("herbs", "herb"),
+    ("martha", "martha"),
+    ("blulfy", "blulfi"),
+    ("skirmishes", "skirmish"),
+    ("shaw", "shaw"),
+    ("wooden", "wooden"),
+    ("coriolanus", "coriolanu"),
+    ("bigness", "big"),
+    ("bruce", "bruce"),
+    ("vaughans", "vaughan"),
+    ("firing", "fire"),
+    ("moorings", "moor"),
+    ("apologetically", "apologet"),
+    ("exhalations", "exhalaz"),
+    ("could", "could"),
+    ("fossil", "fossil"),
+    ("specify", "specifi"),
+    ("yes", "ye"),
+    ("fee", "fee"),
+    ("honourably", "honour"),
+    ("spots", "spot"),
+    ("leone", "leon"),
+    ("spermaceti", "spermaceti"),
+    ("conversations", "conversaz"),
+    ("played", "plai"),
+    ("privileged", "privileg"),
+    ("finally", "final"),
+    ("realisation", "realisaz"),
+    ("initial", "initi"),
+    ("argillaceous", "argillac"),
+    ("soodden", "soodden"),
+    ("embrace", "embrac"),
+    ("misdemeanour", "misdemeanour"),
+    ("insane", "insan"),
+    ("pipe", "pipe"),
+    ("craze", "craze"),
+    ("elves", "elv"),
+    ("cicidae", "cicida"),
+    ("concretions", "concret"),
+    ("antecedent", "anteced"),
+    ("ramirez", "ramirez"),
+    ("paved", "pave"),
+    ("feeckle", "feeckl"),
+    ("sufficing", "suffic"),
+    ("fees", "fee"),
+    ("calendar", "calendar"),
+    ("tube", "tube"),
+    ("woful", "wo"),
+    ("noah", "noah"),
+    ("concebida", "concebida"),
+    ("satellites", "satellit"),
+    ("loses", "lose"),
+    ("limped", "limp"),
+    ("assented", "assent"),
+    ("tucks", "tuck"),
+    ("adorned", "adorn"),
+    ("drays", "drai"),
+    ("malte", "malt"),
+    ("worn", "worn"),
+    ("patronise", "patronis"),
+    ("subscription", "subscript"),
+    ("pumice", "pumic"),
+    ("unprovided", "unprovid"),
+    ("candidateship", "candidateship"),
+    ("waterworn", "waterworn"),
+    ("indefinably", "indefin"),
+    ("enabling", "enabl"),
+    ("edict", "edict"),
+    ("topped", "top"),
+    ("unverified", "unverifi"),
+    ("distanced", "distanc"),
+    ("seeds", "seed"),
+    ("unpensioning", "unpens"),
+    ("january", "januari"),
+    ("clutches", "clutch"),
+    ("roses", "rose"),
+    ("disadvantage", "disadvantag"),
+    ("straw", "straw"),
+    ("growled", "growl"),
+    ("canvas", "canva"),
+    ("cones", "cone"),
+    ("harboured", "harbour"),
+    ("spectator", "spectat"),
+    ("hurrying", "hurri"),
+    ("largeness", "larg"),
+    ("hooded", "hood"),
+    ("apology", "apologi"),
+    ("quartz", "quartz"),
+    ("mastadon", "mastadon"),
+    ("pitfall", "pitfal"),
+    ("replying", "repli"),
+    ("centrifugal", "centrifug"),
+    ("excessive", "excess"),
+    ("contribute", "contribut"),
+    ("buffoon", "buffoon"),
+    ("turbulent", "turbul"),
+    ("humiliated", "humili"),
+    ("mondays", "mondai"),
+    ("parks", "park"),
+    ("squeezed", "squeez"),
+    ("sprawler", "sprawler"),
+    ("gregarious", "gregari"),
+    ("grovelling", "grovel"),
+    ("praia", "praia"),
+    ("environ", "environ"),
+    ("deceivers", "deceiv"),
+    ("excursions", "excurs"),
+    ("aggressor", "aggressor"),
+    ("parterre", "parterr"),
+    ("honeysuckle", "honeysuckl"),
+    ("harmonised", "harmonis"),
+    ("contrivances", "contriv"),
+    ("displease", "displeas"),
+    ("preserve", "preserv"),
+    ("rabidly", "rabidli"),
+    ("moving", "move"),
+    ("insides", "insid"),
+    ("banishing", "banish"),
+    ("subordinate", "subordin"),
+    ("simplicity", "simplic"),
+    ("medium", "medium"),
+    ("fears", "fear"),
+    ("concentrate", "concentr"),
+    ("bias", "bia"),
+    ("yams", "yam"),
+    ("chancing", "chanc"),
+    ("pinches", "pinch"),
+    ("squabbling", "squabbl"),
+    ("rummiest", "rummiest"),
+    ("sprout", "sprout"),
+    ("crossings", "cross"),
+    ("ace", "ac"),
+    ("pass", "pass"),
+    ("westmoreland", "westmoreland"),
+    ("bulph", "bulph"),
+    ("painting", "paint"),
+    ("tasks", "task"),
+    ("seetzen", "seetzen"),
+    ("buzzard", "buzzard"),
+    ("veal", "veal"),
+    ("stow", "stow"),
+    ("tenaciously", "tenaci"),
+    ("brandishing", "brandish"),
+    ("behaved", "behav"),
+    ("draught", "draught"),
+    ("utterly", "utterli"),
+    ("outrageously", "outrag"),
+    ("shrunken", "shrunken"),
+    ("melodious", "melodi"),
+    ("ply", "pli"),
+    ("cochlogena", "cochlogena"),
+    ("certhidea", "certhidea"),
+    ("unconfinable", "unconfin"),
+    ("zones", "zone"),
+    ("si", "si"),
+    ("cowards", "coward"),
+    ("inn", "inn"),
+    ("poring", "pore"),
+    ("supplication", "supplicaz"),
+    ("improved", "improv"),
+    ("disenchantment", "disenchant"),
+    ("economical", "econom"),
+    ("sinecure", "sinecur"),
+    ("polling", "poll"),
+    ("periodical", "period"),
+    ("prorogation", "prorogaz"),
+    ("yellowed", "yellow"),
+    ("era", "era"),
+    ("init", "init"),
+    ("commemorative", "commemor"),
+    ("schwach", "schwach"),
+    ("additions", "addit"),
+    ("potted", "pot"),
+    ("size", "size"),
+    ("covetous", "covet"),
+    ("amoncelees", "amoncele"),
+    ("gentlest", "gentlest"),
+    ("losses", "loss"),
+    ("flutings", "flute"),
+    ("gipsy", "gipsi"),
+    ("wildness", "wild"),
+    ("barrister", "barrist"),
+    ("tiers", "tier"),
+    ("whitewashing", "whitewash"),
+    ("youngsters", "youngster"),
+    ("kneeling", "kneel"),
+    ("brachiotis", "brachioti"),
+    ("coupled", "coupl"),
+    ("shopofwomen", "shopofwomen"),
+    ("referable", "refer"),
+    ("incorrigible", "incorrig"),
+    ("valued", "valu"),
+    ("sharpers", "sharper"),
+    ("prostration", "prostraz"),
+    ("stocked", "stock"),
+    ("mixes", "mix"),
+    ("heard", "heard"),
+    ("burden", "burden"),
+    ("enlivening", "enliven"),
+    ("soobjact", "soobjact"),
+    ("hooray", "hoorai"),
+    ("slug", "slug"),
+    ("organizations", "organ"),
+    ("det", "det"),
+    ("commonest", "commonest"),
+    ("negligently", "neglig"),
+    ("become", "becom"),
+    ("chastening", "chasten"),
+    ("vaults", "vault"),
+    ("unthrifty", "unthrifti"),
+    ("excluding", "exclud"),
+    ("doubleday", "doubledai"),
+    ("pointing", "point"),
+    ("cabeza", "cabeza"),
+    ("symmetrical", "symmetr"),
+    ("obesity", "obes"),
+    ("quality", "qual"),
+    ("cascades", "cascad"),
+    ("servts", "servt"),
+    ("vases", "vase"),
+    ("pittance", "pittanc"),
+    ("hybrida", "hybrida"),
+    ("remaining", "remain"),
+    ("moun", "moun"),
+    ("antarctica", "antarctica"),
+    ("surprisingly", "surprisingli"),
+    ("painted", "paint"),
+    ("dingey", "dingei"),
+    ("despoiled", "despoil"),
+    ("understands", "understand"),
+    ("perplexity", "perplex"),
+    ("relenting", "relent"),
+    ("assigns", "assign"),
+    ("nursing", "nurs"),
+    ("fancied", "fanc"),
+    ("accessory", "accessori"),
+    ("conversion", "convers"),
+    ("shakespeare", "shakespear"),
+    ("tremendously", "tremend"),
+    ("climes", "clime"),
+    ("chilotans", "chilotan"),
+    ("scrutinizing", "scrutin"),
+    ("odiously", "odious"),
+    ("sturt", "sturt"),
+    ("pensions", "pension"),
+    ("cadet", "cadet"),
+    ("fixity", "fixiti"),
+    ("quaccha", "quaccha"),
+    ("thrilled", "thrill"),
+    ("assiduous", "assidu"),
+    ("obtainable", "obtain"),
+    ("loquacious", "loquaci"),
+    ("patiently", "patient"),
+    ("lignum", "lignum"),
+    ("lecherousness", "lecher"),
+    ("scamps", "scamp"),
+    ("popery", "poperi"),
+    ("jagged", "jag"),
+    ("sediment", "sediment"),
+    ("mole", "mole"),
+    ("deputies", "deputi"),
+    ("experimental", "experiment"),
+    ("notes", "note"),
+    ("socially", "social"),
+    ("tarradiddles", "tarradiddl"),
+    ("awkward", "awkward"),
+    ("flexible", "flexibl"),
+    ("recurrent", "recurr"),
+    ("hermit", "hermit"),
+    ("noxious", "noxiou"),
+    ("exhaustion", "exhaust"),
+    ("screamed", "scream"),
+    ("bunches", "bunch"),
+    ("valparaiso", "valparaiso"),
+    ("domineering", "domin"),
+    ("separate", "separ"),
+    ("saliferous", "salifer"),
+    ("chilotan", "chilotan"),
+    ("habitual", "habitu"),
+    ("whitey", "whitei"),
+    ("stocking", "stock"),
+    ("backing", "back"),
+    ("insecure", "insecur"),
+    ("recurred", "recur"),
+    ("manoeuvring", "manoeuvr"),
+    ("alludes", "allud"),
+    ("comes", "come"),
+    ("cruellest", "cruellest"),
+    ("unsatisfactory", "unsatisfactori"),
+    ("diemen", "diemen"),
+    ("vicissitudes", "vicissitud"),
+    ("sedulously", "sedul"),
+    ("spluttering", "splutter"),
+    ("discharging", "discharg"),
+    ("slaughtering", "slaughter"),
+    ("tray", "trai"),
+    ("weasen", "weasen"),
+    ("breasts", "breast"),
+    ("bullying", "bulli"),
+    ("enfeebled", "enfeebl"),
+    ("sobriety", "sobrieti"),
+    ("lumbagers", "lumbag"),
+    ("callous", "callou"),
+    ("watchings", "watch"),
+    ("surmounting", "surmount"),
+    ("bucket", "bucket"),
+    ("persuasive", "persuas"),
+    ("pertinaciously", "pertinaci"),
+    ("dancers", "dancer"),
+    ("stif", "stif"),
+    ("advertisement", "advertis"),
+    ("perch", "perch"),
+    ("calodera", "calodera"),
+    ("unthought", "unthought"),
+    ("pots", "pot"),
+    ("pennyworth", "pennyworth"),
+    ("cared", "care"),
+    ("mathematically", "mathemat"),
+    ("nurse", "nurs"),
+    ("ryde", "ryde"),
+    ("slowly", "slowli"),
+    ("vii", "vii"),
+    ("cellular", "cellular"),
+    ("booking", "book"),
+    ("shareholder", "sharehold"),
+    ("megapodius", "megapodiu"),
+    ("prevaricate", "prevar"),
+    ("lappel", "lappel"),
+    ("interrupts", "interrupt"),
+    ("ashore", "ashor"),
+    ("pummelled", "pummel"),
+    ("gleams", "gleam"),
+    ("propertyless", "propertyless"),
+    ("porto", "porto"),
+    ("perthshire", "perthshir"),
+    ("returned", "return"),
+    ("quiescence", "quiescenc"),
+    ("remembered", "rememb"),
+    ("colliery", "collieri"),
+    ("disputing", "disput"),
+    ("wits", "wit"),
+    ("pinnacle", "pinnacl"),
+    ("quotations", "quotaz"),
+    ("cramble", "crambl"),
+    ("peninsula", "peninsula"),
+    ("addressed", "address"),
+    ("carrying", "carri"),
+    ("unscrupulous", "unscrupul"),
+    ("blames", "blame"),
+    ("reclosing", "reclos"),
+    ("penitential", "penitenti"),
+    ("circulated", "circul"),
+    ("bakaleyev", "bakaleyev"),
+    ("interferes", "interfer"),
+    ("wailing", "wail"),
+    ("aready", "areadi"),
+    ("niata", "niata"),
+    ("immersed", "immers"),
+    ("howled", "howl"),
+    ("employ", "emploi"),
+    ("beguile", "beguil"),
+    ("mrs", "mr"),
+    ("wording", "word"),
+    ("oeuvre", "oeuvr"),
+    ("favouring", "favour"),
+    ("blunderbuss", "blunderbuss"),
+    ("hirrold", "hirrold"),
+    ("pestilent", "pestil"),
+    ("footway", "footwai"),
+    ("transpired", "transpir"),
+    ("contagious", "contagi"),
+    ("muscle", "muscl"),
+    ("tradespeople", "tradespeopl"),
+    ("escutcheons", "escutcheon"),
+    ("moustaches", "moustach"),
+    ("holman", "holman"),
+    ("keys", "kei"),
+    ("disproved", "disprov"),
+    ("encompassing", "encompass"),
+    ("pasthry", "pasthri"),
+    ("marfa", "marfa"),
+    ("falseness", "fals"),
+    ("resisted", "resist"),
+    ("briskness", "brisk"),
+    ("courcy", "courci"),
+    ("sheaves", "sheav"),
+    ("proper", "proper"),
+    ("warily", "warili"),
+    ("sells", "sell"),
+    ("barricaded", "barricad"),
+    ("nags", "nag"),
+    ("imprisonment", "imprison"),
+    ("attendance", "attend"),
+    ("skrimmage", "skrimmag"),
+    ("wave", "wave"),
+    ("crowding", "crowd"),
+    ("sorest", "sorest"),
+    ("depression", "depress"),
+    ("habitable", "habit"),
+    ("just", "just"),
+    ("pie", "pie"),
+    ("gallop", "gallop"),
+    ("prepared", "prepar"),
+    ("glossy", "glossi"),
+    ("dial", "dial"),
+    ("submarine", "submarin"),
+    ("workings", "work"),
+    ("incapacity", "incapac"),
+    ("succeeds", "succe"),
+    ("indurated", "indur"),
+    ("peludo", "peludo"),
+    ("end", "end"),
+    ("woman", "woman"),
+    ("argument", "argument"),
+    ("blinkers", "blinker"),
+    ("spurred", "spur"),
+    ("bullets", "bullet"),
+    ("connoisseur", "connoisseur"),
+    ("ousting", "oust"),
+    ("sooty", "sooti"),
+    ("magellanicus", "magellanicu"),
+    ("hitherto", "hitherto"),
+    ("rag", "rag"),
+    ("vastly", "vastli"),
+    ("truncheons", "truncheon"),
+    ("institutions", "institut"),
+    ("absolute", "absolut"),
+    ("plumbago", "plumbago"),
+    ("swansea", "swansea"),
+    ("competitions", "competit"),
+    ("hap", "hap"),
+    ("sprightliness", "sprightli"),
+    ("george", "georg"),
+    ("acorn", "acorn"),
+    ("search", "search"),
+    ("deflected", "deflect"),
+    ("raised", "rais"),
+    ("threshed", "thresh"),
+    ("bags", "bag"),
+    ("ushers", "usher"),
+    ("amiabilities", "amiabl"),
+    ("management", "manag"),
+    ("mouldering", "moulder"),
+    ("confined", "confin"),
+    ("mister", "mister"),
+    ("strung", "strung"),
+    ("handles", "handl"),
+    ("quadrille", "quadrill"),
+    ("daggers", "dagger"),
+    ("lumb", "lumb"),
+    ("mastering", "master"),
+    ("sentenced", "sentenc"),
+    ("traffic", "traffic"),
+    ("ignorant", "ignor"),
+    ("flicking", "flick"),
+    ("boastfully", "boastfulli"),
+    ("alongside", "alongsid"),
+    ("plat", "plat"),
+    ("clinked", "clink"),
+    ("wisher", "wisher"),
+    ("hedgerows", "hedgerow"),
+    ("represents", "repres"),
+    ("sofya", "sofya"),
+    ("tfoo", "tfoo"),
+    ("minced", "minc"),
+    ("woolwich", "woolwich"),
+    ("intermarriages", "intermarriag"),
+    ("unsupportable", "unsupport"),
+    ("seclude", "seclud"),
+    ("formal", "formal"),
+    ("flake", "flake"),
+    ("testifies", "testifi"),
+    ("despatched", "despatch"),
+    ("bankruptcy", "bankruptci"),
+    ("invested", "invest"),
+    ("imposition", "imposit"),
+    ("avail", "avail"),
+    ("candidly", "candidli"),
+    ("badges", "badg"),
+    ("viz", "viz"),
+    ("felon", "felon"),
+    ("proprietor", "proprietor"),
+    ("undulation", "undulaz"),
+    ("amicability", "amic"),
+    ("slipshod", "slipshod"),
+    ("indigestion", "indigest"),
+    ("hawed", "haw"),
+    ("pilots", "pilot"),
+    ("bifurcation", "bifurcaz"),
+    ("captivater", "captivat"),
+    ("uttering", "utter"),
+    ("flows", "flow"),
+    ("earnestness", "earnest"),
+    ("vituperation", "vituperaz"),
+    ("soft", "soft"),
+    ("scaly", "scali"),
+    ("soundings", "sound"),
+    ("exactness", "exact"),
+    ("purses", "purs"),
+    ("testimonial", "testimoni"),
+    ("dries", "dri"),
+    ("brick", "brick"),
+    ("cloisters", "cloister"),
+    ("bondage", "bondag"),
+    ("christening", "christen"),
+    ("pelisse", "peliss"),
+    ("flapping", "flap"),
+    ("influential", "influenti"),
+    ("wood", "wood"),
+    ("scissors", "scissor"),
+    ("ica", "ica"),
+    ("beauchamp", "beauchamp"),
+    ("manner", "manner"),
+    ("reefs", "reef"),
+    ("carking", "cark"),
+    ("clue", "clue"),
+    ("casuists", "casuist"),
+    ("penance", "penanc"),
+    ("stolidity", "stolid"),
+    ("flying", "fli"),
+    ("lounged", "loung"),
+    ("cycle", "cycl"),
+    ("carmen", "carmen"),
+    ("peony", "peoni"),
+    ("expressionless", "expressionless"),
+    ("rained", "rain"),
+    ("bridesmaids", "bridesmaid"),
+    ("disorganization", "disorgan"),
+    ("unsuccessful", "unsuccess"),
+    ("indifferently", "indiffer"),
+    ("lateness", "late"),
+    ("professes", "profess"),
+    ("rely", "re"),
+    ("imported", "import"),
+    ("perjury", "perjuri"),
+    ("accidents", "accid"),
+    ("orchids", "orchid"),
+    ("praying", "prai"),
+    ("mingles", "mingl"),
+    ("tremulous", "tremul"),
+    ("sassafras", "sassafra"),
+    ("recompense", "recompens"),
+    ("vouchsafing", "vouchsaf"),
+    ("expostulated", "expostul"),
+    ("flooded", "flood"),
+    ("bristly", "bristli"),
+    ("aperture", "apertur"),
+    ("persevering", "persev"),
+    ("rewarded", "reward"),
+    ("flatterers", "flatter"),
+    ("elliptic", "ellipt"),
+    ("sluices", "sluic"),
+    ("guardians", "guardian"),
+    ("pothouse", "pothous"),
+    ("compliments", "compliment"),
+    ("bathed", "bath"),
+    ("grained", "grain"),
+    ("ingeniously", "ingeni"),
+    ("pietras", "pietra"),
+    ("aborigines", "aborigin"),
+    ("melting", "melt"),
+    ("sondern", "sondern"),
+    ("owned", "own"),
+    ("mite", "mite"),
+    ("seeker", "seeker"),
+    ("veinous", "veinou"),
+    ("chargers", "charger"),
+    ("ta", "ta"),
+    ("fronted", "front"),
+    ("forests", "forest"),
+    ("brained", "brain"),
+    ("willow", "willow"),
+    ("wry", "wri"),
+    ("remembering", "rememb"),
+    ("serjeant", "serjeant"),
+    ("structures", "structur"),
+    ("ama", "ama"),
+    ("alarmed", "alarm"),
+    ("soles", "sole"),
+    ("bucaniers", "bucani"),
+    ("hewing", "hew"),
+    ("incubus", "incubu"),
+    ("tor", "tor"),
+    ("gaily", "gaili"),
+    ("subtle", "subtl"),
+    ("illig", "illig"),
+    ("persisting", "persist"),
+    ("beware", "bewar"),
+    ("motley", "motlei"),
+    ("pettishly", "pettishli"),
+    ("vessels", "vessel"),
+    ("storing", "store"),
+    ("cinderella", "cinderella"),
+    ("wildly", "wildli"),
+    ("falsify", "falsifi"),
+    ("gravel", "gravel"),
+    ("barbarian", "barbarian"),
+    ("admiration", "admiraz"),
+    ("jaguar", "jaguar"),
+    ("meditation", "meditaz"),
+    ("majestically", "majest"),
+    ("survived", "surviv"),
+    ("tension", "tension"),
+    ("gash", "gash"),
+    ("glaring", "glare"),
+    ("cologne", "cologn"),
+    ("afer", "afer"),
+    ("pry", "pri"),
+    ("contemplated", "contempl"),
+    ("fondly", "fondli"),
+    ("awkwardly", "awkwardli"),
+    ("toon", "toon"),
+    ("choicest", "choicest"),
+    ("crummles", "crumml"),
+    ("abnegation", "abnegaz"),
+    ("paris", "pari"),
+    ("saracen", "saracen"),
+    ("leek", "leek"),
+    ("botany", "botani"),
+    ("confectioner", "confection"),
+    ("pebble", "pebbl"),
+    ("brimstone", "brimston"),
+    ("jumble", "jumbl"),
+    ("mourner", "mourner"),
+    ("amateur", "amateur"),
+    ("do", "do"),
+    ("cellaria", "cellaria"),
+    ("suffrage", "suffrag"),
+    ("ignoramuses", "ignoramus"),
+    ("zimmerman", "zimmerman"),
+    ("septuagenarian", "septuagenarian"),
+    ("rear", "rear"),
+    ("inevitable", "inevit"),
+    ("attain", "attain"),
+    ("gannets", "gannet"),
+    ("felicitations", "felicitaz"),
+    ("dingo", "dingo"),
+    ("apar", "apar"),
+    ("indefatigable", "indefatig"),
+    ("rage", "rage"),
+    ("immolation", "immolaz"),
+    ("slightest", "slightest"),
+    ("sociable", "sociabl"),
+    ("hurts", "hurt"),
+    ("rave", "rave"),
+    ("doctors", "doctor"),
+    ("rouge", "roug"),
+    ("nil", "nil"),
+    ("kattymaly", "kattymali"),
+    ("oxides", "oxid"),
+    ("cartloads", "cartload"),
+    ("pers", "per"),
+    ("dispel", "dispel"),
+    ("watery", "wateri"),
+    ("rd", "rd"),
+    ("brak", "brak"),
+    ("inopportunely", "inopportun"),
+    ("matthew", "matthew"),
+    ("ixion", "ixion"),
+    ("banquet", "banquet"),
+    ("paraguay", "paraguai"),
+    ("deferential", "deferenti"),
+    ("violent", "violent"),
+    ("tattooing", "tattoo"),
+    ("dumbbell", "dumbbel"),
+    ("laurence", "laurenc"),
+    ("infidel", "infidel"),
+    ("wo", "wo"),
+    ("abdicating", "abdic"),
+    ("assist", "assist"),
+    ("pills", "pill"),
+    ("conferring", "confer"),
+    ("solen", "solen"),
+    ("washed", "wash"),
+    ("felicity", "felic"),
+    ("blurts", "blurt"),
+    ("honourmg", "honourmg"),
+    ("resound", "resound"),
+    ("vis", "vi"),
+    ("hate", "hate"),
+    ("codes", "code"),
+    ("knavish", "knavish"),
+    ("haymarket", "haymarket"),
+    ("conceit", "conceit"),
+    ("ought", "ought"),
+    ("enclosing", "enclos"),
+    ("carriages", "carriag"),
+    ("battered", "batter"),
+    ("tenth", "tenth"),
+    ("tartly", "tartli"),
+    ("mar", "mar"),
+    ("convict", "convict"),
+    ("generosity", "generos"),
+    ("estancia", "estancia"),
+    ("crowquill", "crowquil"),
+    ("clap", "clap"),
+    ("hope", "hope"),
+    ("clutched", "clutch"),
+    ("mitrofanievsky", "mitrofanievski"),
+    ("disperses", "dispers"),
+    ("revengeful", "reveng"),
+    ("windows", "window"),
+    ("tenanted", "tenant"),
+    ("canons", "canon"),
+    ("freeze", "freez"),
+    ("unequivocally", "unequivoc"),
+    ("randal", "randal"),
+    ("perseverance", "persever"),
+    ("penned", "pen"),
+    ("seaweed", "seawe"),
+    ("scoundrels", "scoundrel"),
+    ("blanco", "blanco"),
+    ("disliked", "dislik"),
+    ("aquiline", "aquilin"),
+    ("remembrance", "remembr"),
+    ("beam", "beam"),
+    ("weel", "weel"),
+    ("enters", "enter"),
+    ("grandfathers", "grandfath"),
+    ("william", "william"),
+    ("function", "function"),
+    ("spaces", "space"),
+    ("southerly", "southerli"),
+    ("corrobery", "corroberi"),
+    ("provoked", "provok"),
+    ("immovably", "immov"),
+    ("dead", "dead"),
+    ("oyster", "oyster"),
+    ("misbestowed", "misbestow"),
+    ("retribution", "retribut"),
+    ("star", "star"),
+    ("hurt", "hurt"),
+    ("chiduco", "chiduco"),
+    ("maypu", "maypu"),
+    ("indignation", "indignaz"),
+    ("attached", "attach"),
+    ("animates", "anim"),
+    ("technicalities", "technic"),
+    ("conjuncture", "conjunctur"),
+    ("security", "secur"),
+    ("tybalt", "tybalt"),
+    ("neck", "neck"),
+    ("bradshaw", "bradshaw"),
+    ("mlud", "mlud"),
+    ("sometime", "sometim"),
+    ("avowal", "avow"),
+    ("bedchambers", "bedchamb"),
+    ("legislation", "legislaz"),
+    ("reserving", "reserv"),
+    ("firewood", "firewood"),
+    ("fuegia", "fuegia"),
+    ("adjust", "adjust"),
+    ("marsden", "marsden"),
+    ("shrubberies", "shrubberi"),
+    ("big", "big"),
+    ("weariness", "weari"),
+    ("unnaturally", "unnatur"),
+    ("turrets", "turret"),
+    ("exempted", "exempt"),
+    ("purpurea", "purpurea"),
+    ("alternation", "alternaz"),
+    ("bobster", "bobster"),
+    ("indescribably", "indescrib"),
+    ("kindness", "kind"),
+    ("transportable", "transport"),
+    ("bookcase", "bookcas"),
+    ("doris", "dori"),
+    ("skilful", "skil"),
+    ("flare", "flare"),
+    ("behalf", "behalf"),
+    ("citadel", "citadel"),
+    ("yelled", "yell"),
+    ("bedecks", "bedeck"),
+    ("frisk", "frisk"),
+    ("misplaced", "misplac"),
+    ("buffer", "buffer"),
+    ("pipeclayed", "pipeclai"),
+    ("down", "down"),
+    ("squandered", "squander"),
+    ("unshaved", "unshav"),
+    ("seams", "seam"),
+    ("thins", "thin"),
+    ("escorted", "escort"),
+    ("betrayal", "betrayal"),
+    ("megalosaurus", "megalosauru"),
+    ("died", "di"),
+    ("angelica", "angelica"),
+    ("darwinian", "darwinian"),
+    ("pork", "pork"),
+    ("burned", "burn"),
+    ("delicate", "delic"),
+    ("gleaned", "glean"),
+    ("manne", "mann"),
+    ("woodpecker", "woodpeck"),
+    ("haggard", "haggard"),
+    ("palmerston", "palmerston"),
+    ("packages", "packag"),
+    ("corunda", "corunda"),
+    ("chandelier", "chandeli"),
+    ("value", "valu"),
+    ("funerals", "funer"),
+    ("mess", "mess"),
+    ("smoker", "smoker"),
+    ("foreshadowing", "foreshadow"),
+    ("monument", "monument"),
+    ("empire", "empir"),
+    ("hammers", "hammer"),
+    ("bathers", "bather"),
+    ("directress", "directress"),
+    ("me", "me"),
+    ("overflow", "overflow"),
+    ("belvedere", "belveder"),
+    ("available", "avail"),
+    ("measured", "measur"),
+    ("degenerating", "degener"),
+    ("gray", "grai"),
+    ("nips", "nip"),
+    ("wretches", "wretch"),
+    ("persecutions", "persecut"),
+    ("chaplain", "chaplain"),
+    ("version", "version"),
+    ("track", "track"),
+    ("furies", "furi"),
+    ("bolabola", "bolabola"),
+    ("iniquity", "iniqu"),
+    ("reassures", "reassur"),
+    ("interrupted", "interrupt"),
+    ("snowflakes", "snowflak"),
+    ("refusing", "refus"),
+    ("slide", "slide"),
+    ("harpalus", "harpalu"),
+    ("jading", "jade"),
+    ("averred", "aver"),
+    ("admits", "admit"),
+    ("caracara", "caracara"),
+    ("afforded", "afford"),
+    ("kirby", "kirbi"),
+    ("recantation", "recantaz"),
+    ("puente", "puent"),
+    ("start", "start"),
+    ("relax", "relax"),
+    ("unrelenting", "unrel"),
+    ("cultivation", "cultivaz"),
+    ("encourage", "encourag"),
+    ("repel", "repel"),
+    ("malignity", "malign"),
+    ("discharged", "discharg"),
+    ("perceptibly", "perceptibli"),
+    ("stallion", "stallion"),
+    ("jammed", "jam"),
+    ("undersoil", "undersoil"),
+    ("impeded", "imp"),
+    ("hempen", "hempen"),
+    ("authentic", "authent"),
+    ("plicata", "plicata"),
+    ("repaired", "repair"),
+    ("repeats", "repeat"),
+    ("espouse", "espous"),
+    ("unwritten", "unwritten"),
+    ("expensively", "expens"),
+    ("abuse", "abus"),
+    ("mettle", "mettl"),
+    ("fructifying", "fructifi"),
+    ("trimming", "trim"),
+    ("polite", "polit"),
+    ("paraded", "parad"),
+    ("malgre", "malgr"),
+    ("pshaw", "pshaw"),
+    ("bad", "bad"),
+    ("marstones", "marston"),
+    ("mutiny", "mutini"),
+    ("gane", "gane"),
+    ("drives", "drive"),
+    ("exacting", "exact"),
+    ("whichsoever", "whichsoev"),
+    ("listens", "listen"),
+    ("demure", "demur"),
+    ("footfall", "footfal"),
+    ("rectitude", "rectitud"),
+    ("luminary", "luminari"),
+    ("bullock", "bullock"),
+    ("judged", "judg"),
+    ("good", "good"),
+    ("handkerchief", "handkerchief"),
+    ("manager", "manag"),
+    ("twelve", "twelv"),
+    ("variety", "varieti"),
+    ("columns", "column"),
+    ("talkative", "talk"),
+    ("bundles", "bundl"),
+    ("foundation", "foundaz"),
+    ("wagers", "wager"),
+    ("architects", "architect"),
+    ("prevented", "prevent"),
+    ("mounts", "mount"),
+    ("rainbow", "rainbow"),
+    ("minnit", "minnit"),
+    ("patched", "patch"),
+    ("thinly", "thinli"),
+    ("saddened", "sadden"),
+    ("ruck", "ruck"),
+    ("plantations", "plantaz"),
+    ("snuffim", "snuffim"),
+    ("rudeness", "rude"),
+    ("wayfarer", "wayfar"),
+    ("dislocated", "disloc"),
+    ("arranged", "arrang"),
+    ("smilingly", "smilingli"),
+    ("iou", "iou"),
+    ("beaks", "beak"),
+    ("descendants", "descend"),
+    ("things", "thing"),
+    ("rascally", "rascal"),
+    ("simpler", "simpler"),
+    ("brings", "bring"),
+    ("remaine", "remain"),
+    ("permit", "permit"),
+    ("successful", "success"),
+    ("reliance", "relianc"),
+    ("dukes", "duke"),
+    ("pleased", "pleas"),
+    ("pays", "pai"),
+    ("matting", "mat"),
+    ("twitchings", "twitch"),
+    ("tucking", "tuck"),
+    ("diligent", "dilig"),
+    ("menchicoff", "menchicoff"),
+    ("announced", "announc"),
+    ("chary", "chari"),
+    ("porson", "porson"),
+    ("milder", "milder"),
+    ("ushered", "usher"),
+    ("sooffer", "sooffer"),
+    ("splendours", "splendour"),
+    ("lapsed", "laps"),
+    ("vow", "vow"),
+    ("crystal", "crystal"),
+    ("shoots", "shoot"),
+    ("park", "park"),
+    ("mothers", "mother"),
+    ("undoing", "undo"),
+    ("inaptly", "inaptli"),
+    ("constantinople", "constantinopl"),
+    ("baguet", "baguet"),
+    ("ready", "readi"),
+    ("minaret", "minaret"),
+    ("suitors", "suitor"),
+    ("embarrassed", "embarrass"),
+    ("mo", "mo"),
+    ("pomps", "pomp"),
+    ("rent", "rent"),
+    ("headmost", "headmost"),
+    ("elections", "elect"),
+    ("brooding", "brood"),
+    ("extenuation", "extenuaz"),
+    ("fletcherites", "fletcherit"),
+    ("drifting", "drift"),
+    ("shore", "shore"),
+    ("spurned", "spurn"),
+    ("young", "young"),
+    ("damages", "damag"),
+    ("footpath", "footpath"),
+    ("silurus", "siluru"),
+    ("voskresensky", "voskresenski"),
+    ("watchmaker", "watchmak"),
+    ("ripe", "ripe"),
+    ("mightn", "mightn"),
+    ("gait", "gait"),
+    ("dawns", "dawn"),
+    ("mattered", "matter"),
+    ("acapulco", "acapulco"),
+    ("caravan", "caravan"),
+    ("groomed", "groom"),
+    ("metropolitan", "metropolitan"),
+    ("itch", "itch"),
+    ("allurements", "allur"),
+    ("numbed", "numb"),
+    ("idol", "idol"),
+    ("behaves", "behav"),
+    ("everybodys", "everybodi"),
+    ("peeling", "peel"),
+    ("agents", "agent"),
+    ("stiffly", "stiffli"),
+    ("incumbrances", "incumbr"),
+    ("listless", "listless"),
+    ("fillip", "fillip"),
+    ("sponsorial", "sponsori"),
+    ("wrath", "wrath"),
+    ("lycurgus", "lycurgu"),
+    ("sellings", "sell"),
+    ("fanciful", "fanci"),
+    ("molestation", "molestaz"),
+    ("deservedly", "deservedli"),
+    ("stubbly", "stubbli"),
+    ("courtiers", "courtier"),
+    ("infallible", "infal"),
+    ("enderby", "enderbi"),
+    ("barbecued", "barbecu"),
+    ("humour", "humour"),
+    ("huffily", "huffili"),
+    ("muster", "muster"),
+    ("ambition", "ambit"),
+    ("helena", "helena"),
+    ("contriving", "contriv"),
+    ("present", "present"),
+    ("reminds", "remind"),
+    ("degree", "degre"),
+    ("phil", "phil"),
+    ("tantalised", "tantalis"),
+    ("chattels", "chattel"),
+    ("diamond", "diamond"),
+    ("defective", "defect"),
+    ("sickens", "sicken"),
+    ("puppet", "puppet"),
+    ("pish", "pish"),
+    ("duration", "duraz"),
+    ("dolly", "dolli"),
+    ("crowning", "crown"),
+    ("arachnidae", "arachnida"),
+    ("foes", "foe"),
+    ("starved", "starv"),
+    ("convolvulus", "convolvulu"),
+    ("unbonneted", "unbonnet"),
+    ("offshoots", "offshoot"),
+    ("vainglory", "vainglori"),
+    ("cap", "cap"),
+    ("loathsomeness", "loathsom"),
+    ("livelihood", "livelihood"),
+    ("tricks", "trick"),
+    ("martin", "martin"),
+    ("lumps", "lump"),
+    ("overstating", "overst"),
+    ("success", "success"),
+    ("glorious", "gloriou"),
+    ("doctoring", "doctor"),
+    ("combed", "comb"),
+    ("encourages", "encourag"),
+    ("meditatively", "medit"),
+    ("freshen", "freshen"),
+    ("closeted", "closet"),
+    ("amalgamation", "amalgamaz"),
+    ("monopolize", "monopol"),
+    ("ed", "ed"),
+    ("matvey", "matvei"),
+    ("looming", "loom"),
+    ("client", "client"),
+    ("snatched", "snatch"),
+    ("discredit", "discredit"),
+    ("buoyed", "buoi"),
+    ("nevsky", "nevski"),
+    ("unsteadiness", "unsteadi"),
+    ("schoolmasters", "schoolmast"),
+    ("trivial", "trivial"),
+    ("traps", "trap"),
+    ("softens", "soften"),
+    ("beagle", "beagl"),
+    ("privateer", "privat"),
+    ("beyond", "beyond"),
+    ("tiaauru", "tiaauru"),
+    ("cinnamon", "cinnamon"),
+    ("collnet", "collnet"),
+    ("bareheaded", "barehead"),
+    ("bandboxes", "bandbox"),
+    ("portillo", "portillo"),
+    ("hater", "hater"),
+    ("devonian", "devonian"),
+    ("waiters", "waiter"),
+    ("siren", "siren"),
+    ("murdering", "murder"),
+    ("winded", "wind"),
+    ("impute", "imput"),
+    ("roast", "roast"),
+    ("louis", "loui"),
+    ("portionless", "portionless"),
+    ("shrink", "shrink"),
+    ("prints", "print"),
+    ("transformation", "transformaz"),
+    ("sportsman", "sportsman"),
+    ("pselaphus", "pselaphu"),
+    ("gorgeously", "gorgeous"),
+    ("individuals", "individu"),
+    ("imbibed", "imbib"),
+    ("machinery", "machineri"),
+    ("hypochondriac", "hypochondriac"),
+    ("asseverations", "asseveraz"),
+    ("ditching", "ditch"),
+    ("xix", "xix"),
+    ("witches", "witch"),
+    ("instrumentality", "instrument"),
+    ("holuthuriae", "holuthuria"),
+    ("neighbour", "neighbour"),
+    ("mistily", "mistili"),
+    ("uncrossed", "uncross"),
+    ("uneven", "uneven"),
+    ("nearl", "nearl"),
+    ("appearance", "appear"),
+    ("reddy", "reddi"),
+    ("international", "intern"),
+    ("mobbed", "mob"),
+    ("warmly", "warmli"),
+    ("pilfering", "pilfer"),
+    ("physiognomy", "physiognomi"),
+    ("loiterers", "loiter"),
+    ("tramping", "tramp"),
+    ("acunha", "acunha"),
+    ("buoyancy", "buoyanc"),
+    ("madcaps", "madcap"),
+    ("maintaining", "maintain"),
+    ("britain", "britain"),
+    ("ravenous", "raven"),
+    ("solicitation", "solicitaz"),
+    ("aller", "aller"),
+    ("stinks", "stink"),
+    ("sooth", "sooth"),
+    ("twelfth", "twelfth"),
+    ("sank", "sank"),
+    ("incredulously", "incredul"),
+    ("wows", "wow"),
+    ("iridescent", "iridesc"),
+    ("repenteth", "repenteth"),
+    ("controlled", "control"),
+    ("unclasp", "unclasp"),
+    ("interfering", "interf"),
+    ("dispose", "dispos"),
+    ("depository", "depositori"),
+    ("denial", "denial"),
+    ("earned", "earn"),
+    ("depreciating", "depreci"),
+    ("suspense", "suspens"),
+    ("irrevocable", "irrevoc"),
+    ("investigators", "investig"),
+    ("rover", "rover"),
+    ("capybara", "capybara"),
+    ("assumption", "assumpt"),
+    ("darned", "darn"),
+    ("douches", "douch"),
+    ("vicarage", "vicarag"),
+    ("uncle", "uncl"),
+    ("wur", "wur"),
+    ("breathed", "breath"),
+    ("raged", "rage"),
+    ("settlements", "settlement"),
+    ("mere", "mere"),
+    ("stubborn", "stubborn"),
+    ("theatrically", "theatric"),
+    ("tout", "tout"),
+    ("sald", "sald"),
+    ("gourmands", "gourmand"),
+    ("officious", "offici"),
+    ("withdraw", "withdraw"),
+    ("misspent", "misspent"),
+    ("dutiful", "duti"),
+    ("gulp", "gulp"),
+    ("unshod", "unshod"),
+    ("collars", "collar"),
+    ("relaxation", "relaxaz"),
+    ("concluding", "conclud"),
+    ("toil", "toil"),
+    ("goal", "goal"),
+    ("aloud", "aloud"),
+    ("oscillated", "oscil"),
+    ("imagines", "imagin"),
+    ("inconveniently", "inconveni"),
+    ("perceives", "perceiv"),
+    ("ferried", "ferri"),
+    ("replaces", "replac"),
+    ("forced", "forc"),
+    ("diminution", "diminut"),
+    ("scraping", "scrape"),
+    ("cabman", "cabman"),
+    ("stitching", "stitch"),
+    ("jewess", "jewess"),
+    ("indian", "indian"),
+    ("eustace", "eustac"),
+    ("ungainly", "ungainli"),
+    ("entreaty", "entreati"),
+    ("cheeks", "cheek"),
+    ("mulita", "mulita"),
+    ("falter", "falter"),
+    ("adding", "ad"),
+    ("cobweb", "cobweb"),
+    ("provision", "provis"),
+    ("gardener", "garden"),
+    ("jones", "jone"),
+    ("above", "abov"),
+    ("studiousness", "studious"),
+    ("clefts", "cleft"),
+    ("attends", "attend"),
+    ("privy", "privi"),
+    ("officiously", "offici"),
+    ("partitioned", "partit"),
+    ("gainsay", "gainsai"),
+    ("candles", "candl"),
+    ("closets", "closet"),
+    ("thirteen", "thirteen"),
+    ("momentarily", "momentarili"),
+    ("propagation", "propagaz"),
+    ("mysteriously", "mysteri"),
+    ("smearing", "smear"),
+    ("declaration", "declaraz"),
+    ("kuffy", "kuffi"),
+    ("suddenly", "suddenli"),
+    ("plaited", "plait"),
+    ("cellar", "cellar"),
+    ("purblind", "purblind"),
+    ("exquisitely", "exquisit"),
+    ("forgeries", "forgeri"),
+    ("unwise", "unwis"),
+    ("bran", "bran"),
+    ("ruffian", "ruffian"),
+    ("sonourous", "sonour"),
+    ("gates", "gate"),
+    ("pepper", "pepper"),
+    ("remanded", "remand"),
+    ("prentice", "prentic"),
+    ("wielded", "wield"),
+    ("mun", "mun"),
+    ("unfortnet", "unfortnet"),
+    ("incipient", "incipi"),
+    ("curmudgeon", "curmudgeon"),
+    ("finger", "finger"),
+    ("spade", "spade"),
+    ("fun", "fun"),
+    ("holly", "holli"),
+    ("coachman", "coachman"),
+    ("delicater", "delicat"),
+    ("slouch", "slouch"),
+    ("san", "san"),
+    ("decorous", "decor"),
+    ("reposed", "repos"),
+    ("divides", "divid"),
+    ("gaslights", "gaslight"),
+    ("ill", "ill"),
+    ("furry", "furri"),
+    ("batch", "batch"),
+    ("disentangle", "disentangl"),
+    ("scaled", "scale"),
+    ("auks", "auk"),
+    ("boungites", "boungit"),
+    ("southward", "southward"),
+    ("asia", "asia"),
+    ("sweetbrier", "sweetbrier"),
+    ("effervescence", "effervesc"),
+    ("criminal", "crimin"),
+    ("movables", "movabl"),
+    ("rambled", "rambl"),
+    ("owes", "ow"),
+    ("squeamishly", "squeamishli"),
+    ("skins", "skin"),
+    ("topics", "topic"),
+    ("comparative", "compar"),
+    ("stood", "stood"),
+    ("defer", "defer"),
+    ("coarsened", "coarsen"),
+    ("ungirdles", "ungirdl"),
+    ("ribs", "rib"),
+    ("enrichment", "enrich"),
+    ("gape", "gape"),
+    ("hermitage", "hermitag"),
+    ("reparation", "reparaz"),
+    ("strengthen", "strengthen"),
+    ("thud", "thud"),
+    ("rosary", "rosari"),
+    ("displaced", "displac"),
+    ("migrated", "migrat"),
+    ("experiences", "experi"),
+    ("fevered", "fever"),
+    ("robing", "robe"),
+    ("bandmaster", "bandmast"),
+    ("associating", "associ"),
+    ("positive", "posit"),
+    ("entangled", "entangl"),
+    ("essence", "essenc"),
+    ("busy", "busi"),
+    ("tehuelches", "tehuelch"),
+    ("whims", "whim"),
+    ("lined", "line"),
+    ("solent", "solent"),
+    ("metal", "metal"),
+    ("dangling", "dangl"),
+    ("sheake", "sheak"),
+    ("receiver", "receiv"),
+    ("yew", "yew"),
+    ("postage", "postag"),
+    ("alights", "alight"),
+    ("indistinctness", "indistinct"),
+    ("chokes", "choke"),
+    ("lucifers", "lucif"),
+    ("premier", "premier"),
+    ("forks", "fork"),
+    ("uselessly", "uselessli"),
+    ("veined", "vein"),
+    ("fres", "fre"),
+    ("droonk", "droonk"),
+    ("glaciers", "glacier"),
+    ("spoons", "spoon"),
+    ("amerique", "ameriqu"),
+    ("horsecloths", "horsecloth"),
+    ("righted", "right"),
+    ("slash", "slash"),
+    ("ripened", "ripen"),
+    ("erudition", "erudit"),
+    ("advocate", "advoc"),
+    ("chadband", "chadband"),
+    ("quarrelsome", "quarrelsom"),
+    ("counters", "counter"),
+    ("slanderers", "slander"),
+    ("clipping", "clip"),
+    ("affliction", "afflict"),
+    ("exhibited", "exhibit"),
+    ("divn", "divn"),
+    ("saul", "saul"),
+    ("en", "en"),
+    ("malt", "malt"),
+    ("pane", "pane"),
+    ("beautifullest", "beautifullest"),
+    ("wi", "wi"),
+    ("ridiculously", "ridicul"),
+    ("my", "mi"),
+    ("bestowal", "bestow"),
+    ("elude", "elud"),
+    ("eleventh", "eleventh"),
+    ("swimming", "swim"),
+    ("chilean", "chilean"),
+    ("chords", "chord"),
+    ("tutbury", "tutburi"),
+    ("warring", "war"),
+    ("disciplined", "disciplin"),
+    ("mmd", "mmd"),
+    ("hussar", "hussar"),
+    ("prussians", "prussian"),
+    ("turnpike", "turnpik"),
+    ("cascade", "cascad"),
+    ("lending", "lend"),
+    ("restraining", "restrain"),
+    ("pouting", "pout"),
+    ("unes", "un"),
+    ("humiliate", "humili"),
+    ("dissolution", "dissolut"),
+    ("ravenously", "raven"),
+    ("jot", "jot"),
+    ("battles", "battl"),
+    ("misrepresentations", "misrepresentaz"),
+    ("composure", "composur"),
+    ("manoeuvred", "manoeuvr"),
+    ("duck", "duck"),
+    ("aiming", "aim"),
+    ("convince", "convinc"),
+    ("refreshment", "refresh"),
+    ("impart", "impart"),
+    ("traitors", "traitor"),
+    ("penury", "penuri"),
+    ("mewlinn", "mewlinn"),
+    ("recommendation", "recommendaz"),
+    ("reining", "rein"),
+    ("revive", "reviv"),
+    ("cancer", "cancer"),
+    ("complies", "compli"),
+    ("gladsomeness", "gladsom"),
+    ("roon", "roon"),
+    ("dress", "dress"),
+    ("dishonouring", "dishonour"),
+    ("criminations", "criminaz"),
+    ("inborn", "inborn"),
+    ("excitable", "excit"),
+    ("statu", "statu"),
+    ("coyness", "coy"),
+    ("er", "er"),
+    ("unwelcome", "unwelcom"),
+    ("tough", "tough"),
+    ("ennoble", "ennobl"),
+    ("significancy", "signific"),
+    ("rick", "rick"),
+    ("rend", "rend"),
+    ("watershed", "watersh"),
+    ("astray", "astrai"),
+    ("superiors", "superior"),
+    ("dialects", "dialect"),
+    ("scowling", "scowl"),
+    ("unanswerable", "unanswer"),
+    ("check", "check"),
+    ("agoutis", "agouti"),
+    ("tempt", "tempt"),
+    ("warranted", "warrant"),
+    ("cogitation", "cogitaz"),
+    ("arbours", "arbour"),
+    ("waves", "wave"),
+    ("discriminate", "discrimin"),
+    ("list", "list"),
+    ("fluidified", "fluidifi"),
+    ("outlet", "outlet"),
+    ("pouches", "pouch"),
+    ("fluently", "fluent"),
+    ("yammerschooner", "yammerschoon"),
+    ("discourteous", "discourt"),
+    ("preside", "presid"),
+    ("cramping", "cramp"),
+    ("prison", "prison"),
+    ("xxvii", "xxvii"),
+    ("bookseller", "booksel"),
+    ("capriciously", "caprici"),
+    ("conceiving", "conceiv"),
+    ("poor", "poor"),
+    ("impairing", "impair"),
+    ("abounded", "abound"),
+    ("fewness", "few"),
+    ("livid", "livid"),
+    ("satan", "satan"),
+    ("accommodated", "accommod"),
+    ("mihail", "mihail"),
+    ("evident", "evid"),
+    ("insatiable", "insati"),
+    ("february", "februari"),
+    ("notwithstanding", "notwithstand"),
+    ("nivalis", "nivali"),
+    ("amiable", "amiabl"),
+    ("tongue", "tongu"),
+    ("generalities", "gener"),
+    ("connubialities", "connubi"),
+    ("impenetrable", "impenetr"),
+    ("pay", "pai"),
+    ("soie", "soie"),
+    ("reinforced", "reinforc"),
+    ("frantsovna", "frantsovna"),
+    ("tangle", "tangl"),
+    ("disdaining", "disdain"),
+    ("arrows", "arrow"),
+    ("abeyance", "abeyanc"),
+    ("abipones", "abipon"),
+    ("desks", "desk"),
+    ("gifted", "gift"),
+    ("upland", "upland"),
+    ("halting", "halt"),
+    ("accident", "accid"),
+    ("bluff", "bluff"),
+    ("ostentation", "ostentaz"),
+    ("smothering", "smother"),
+    ("relies", "re"),
+    ("perishing", "perish"),
+    ("fortification", "fortificaz"),
+    ("incidental", "incident"),
+    ("inharmonious", "inharmoni"),
+    ("bolder", "bolder"),
+    ("graduate", "graduat"),
+    ("wolves", "wolv"),
+    ("cathartes", "cathart"),
+    ("joneses", "jones"),
+    ("cesspools", "cesspool"),
+    ("courteous", "courteou"),
+    ("twinkled", "twinkl"),
+    ("incog", "incog"),
+    ("ointment", "ointment"),
+    ("gannet", "gannet"),
+    ("reanimate", "reanim"),
+    ("wonted", "wont"),
+    ("foe", "foe"),
+    ("combines", "combin"),
+    ("romance", "romanc"),
+    ("editor", "editor"),
+    ("turpentining", "turpentin"),
+    ("murders", "murder"),
+    ("frying", "fri"),
+    ("physiology", "physiologi"),
+    ("eternal", "etern"),
+    ("customary", "customari"),
+    ("wilson", "wilson"),
+    ("fixture", "fixtur"),
+    ("tragic", "tragic"),
+    ("plata", "plata"),
+    ("detract", "detract"),
+    ("maggot", "maggot"),
+    ("physiological", "physiolog"),
+    ("bringing", "bringing"),
+    ("expel", "expel"),
+    ("adapt", "adapt"),
+    ("unfresh", "unfresh"),
+    ("carts", "cart"),
+    ("annum", "annum"),
+    ("pleaded", "plead"),
+    ("carnage", "carnag"),
+    ("coco", "coco"),
+    ("spotless", "spotless"),
+    ("guardage", "guardag"),
+    ("dilated", "dilat"),
+    ("domination", "dominaz"),
+    ("incorrect", "incorrect"),
+    ("gushing", "gush"),
+    ("scholar", "scholar"),
+    ("belt", "belt"),
+    ("hams", "ham"),
+    ("anchors", "anchor"),
+    ("unconcealed", "unconc"),
+    ("indistinguishable", "indistinguish"),
+    ("liquors", "liquor"),
+    ("especially", "especi"),
+    ("dusk", "dusk"),
+    ("contemplative", "contempl"),
+    ("unpleasantnesses", "unpleas"),
+    ("bawling", "bawl"),
+    ("engaddi", "engaddi"),
+    ("ripen", "ripen"),
+    ("detrimental", "detriment"),
+    ("degeneration", "degeneraz"),
+    ("binds", "bind"),
+    ("cake", "cake"),
+    ("immensus", "immensu"),
+    ("somebodies", "somebodi"),
+    ("penknife", "penknif"),
+    ("cider", "cider"),
+    ("flinders", "flinder"),
+    ("boasts", "boast"),
+    ("obtain", "obtain"),
+    ("navel", "navel"),
+    ("frigidity", "frigid"),
+    ("inscribed", "inscrib"),
+    ("dandified", "dandifi"),
+    ("tumbles", "tumbl"),
+    ("asset", "asset"),
+    ("paler", "paler"),
+    ("translator", "translat"),
+    ("jellyby", "jellybi"),
+    ("criticisms", "critic"),
+    ("descries", "descri"),
+    ("billeted", "billet"),
+    ("appeased", "appeas"),
+    ("completeness", "complet"),
+    ("calmest", "calmest"),
+    ("guayavita", "guayavita"),
+    ("formations", "formaz"),
+    ("tiliareus", "tiliareu"),
+    ("armhole", "armhol"),
+    ("naturalist", "naturalist"),
+    ("small", "small"),
+    ("constables", "constabl"),
+    ("granting", "grant"),
+    ("brothers", "brother"),
+    ("arrived", "arriv"),
+    ("barrows", "barrow"),
+    ("wafer", "wafer"),
+    ("govett", "govett"),
+    ("berthelot", "berthelot"),
+    ("anthem", "anthem"),
+    ("stir", "stir"),
+    ("tacit", "tacit"),
+    ("justified", "justifi"),
+    ("work", "work"),
+    ("eagerly", "eagerli"),
+    ("reinstated", "reinstat"),
+    ("certificate", "certif"),
+    ("non", "non"),
+    ("feasible", "feasibl"),
+    ("buzzing", "buzz"),
+    ("epochs", "epoch"),
+    ("munificence", "munific"),
+    ("temper", "temper"),
+    ("citigrade", "citigrad"),
+    ("shiploads", "shipload"),
+    ("cowed", "cow"),
+    ("knowa", "knowa"),
+    ("exchanges", "exchang"),
+    ("sting", "sting"),
+    ("catchings", "catch"),
+    ("foreheads", "forehead"),
+    ("skittle", "skittl"),
+    ("despicable", "despic"),
+    ("sulphuric", "sulphur"),
+    ("burrowings", "burrow"),
+    ("denudation", "denudaz"),
+    ("ungenial", "ungeni"),
+    ("existed", "exist"),
+    ("ages", "ag"),
+    ("ailment", "ailment"),
+    ("vi", "vi"),
+    ("nikiforovna", "nikiforovna"),
+    ("davy", "davi"),
+    ("outcry", "outcri"),
+    ("roasted", "roast"),
+    ("martindale", "martindal"),
+    ("charms", "charm"),
+    ("unfeeling", "unfeel"),
+    ("racket", "racket"),
+    ("godless", "godless"),
+    ("hazel", "hazel"),
+    ("argumentative", "argument"),
+    ("reconsigning", "reconsign"),
+    ("high", "high"),
+    ("package", "packag"),
+    ("courtesies", "courtesi"),
+    ("guayaquil", "guayaquil"),
+    ("prodigality", "prodig"),
+    ("slender", "slender"),
+    ("huskiness", "huski"),
+    ("philandering", "philand"),
+    ("massacred", "massacr"),
+    ("overlookers", "overlook"),
+    ("buch", "buch"),
+    ("lips", "lip"),
+    ("wight", "wight"),
+    ("stinginess", "stingi"),
+    ("persecutor", "persecutor"),
+    ("screwdrivers", "screwdriv"),
+    ("indecorous", "indecor"),
+    ("sublime", "sublim"),
+    ("unimpeachable", "unimpeach"),
+    ("senor", "senor"),
+    ("anchovies", "anchovi"),
+    ("housetops", "housetop"),
+    ("uprooting", "uproot"),
+    ("divinity", "divin"),
+    ("phaeton", "phaeton"),
+    ("howitt", "howitt"),
+    ("perilously", "peril"),
+    ("bulged", "bulg"),
+    ("chucked", "chuck"),
+    ("scan", "scan"),
+    ("warlike", "warlik"),
+    ("supple", "suppl"),
+    ("massacre", "massacr"),
+    ("bloody", "bloodi"),
+    ("knows", "know"),
+    ("curiosities", "curios"),
+    ("hampton", "hampton"),
+    ("inkstands", "inkstand"),
+    ("theresa", "theresa"),
+    ("smirking", "smirk"),
+    ("saves", "save"),
+    ("repined", "repin"),
+    ("hers", "her"),
+    ("conviviality", "convivi"),
+    ("fowl", "fowl"),
+    ("retinue", "retinu"),
+    ("seventysix", "seventysix"),
+    ("buckler", "buckler"),
+    ("yorick", "yorick"),
+    ("rousing", "rous"),
+    ("jaunty", "jaunti"),
+    ("palate", "palat"),
+    ("sultry", "sultri"),
+    ("reservoir", "reservoir"),
+    ("shining", "shine"),
+    ("eighteenpence", "eighteenp"),
+    ("inexpressive", "inexpress"),
+    ("posted", "post"),
+    ("performances", "perform"),
+    ("goodwill", "goodwil"),
+    ("congenial", "congeni"),
+    ("windpipe", "windpip"),
+    ("ben", "ben"),
+    ("smifligation", "smifligaz"),
+    ("cutting", "cut"),
+    ("cheeryble", "cheerybl"),
+    ("swooning", "swoon"),
+    ("legible", "legibl"),
+    ("mudlike", "mudlik"),
+    ("pricks", "prick"),
+    ("artists", "artist"),
+    ("moisten", "moisten"),
+    ("consults", "consult"),
+    ("pteropoda", "pteropoda"),
+    ("couldst", "couldst"),
+    ("extant", "extant"),
+    ("formidable", "formid"),
+    ("french", "french"),
+    ("sober", "sober"),
+    ("failed", "fail"),
+    ("unfurled", "unfurl"),
+    ("aphodius", "aphodiu"),
+    ("declined", "declin"),
+    ("carefully", "carefulli"),
+    ("dictum", "dictum"),
+    ("ugh", "ugh"),
+    ("wench", "wench"),
+    ("hospitals", "hospit"),
+    ("section", "section"),
+    ("lash", "lash"),
+    ("cupboards", "cupboard"),
+    ("nemophilas", "nemophila"),
+    ("octave", "octav"),
+    ("settle", "settl"),
+    ("hatch", "hatch"),
+    ("catholic", "cathol"),
+    ("imperturbable", "imperturb"),
+    ("berryin", "berryin"),
+    ("energetic", "energet"),
+    ("inexorable", "inexor"),
+    ("tease", "teas"),
+    ("pitfalls", "pitfal"),
+    ("department", "depart"),
+    ("uncourteous", "uncourt"),
+    ("engenhodo", "engenhodo"),
+    ("tributaries", "tributari"),
+    ("represent", "repres"),
+    ("reproduce", "reproduc"),
+    ("disgusting", "disgust"),
+    ("overpowering", "overpow"),
+    ("shuffling", "shuffl"),
+    ("fools", "fool"),
+    ("lud", "lud"),
+    ("pained", "pain"),
+    ("reliefs", "relief"),
+    ("laziness", "lazi"),
+    ("flourishes", "flourish"),
+    ("improves", "improv"),
+    ("withstood", "withstood"),
+    ("unbending", "unbend"),
+    ("piety", "pieti"),
+    ("genelman", "genelman"),
+    ("unseemly", "unseemli"),
+    ("delight", "delight"),
+    ("transplant", "transplant"),
+    ("usurp", "usurp"),
+    ("drummonds", "drummond"),
+    ("envy", "envi"),
+    ("chasing", "chase"),
+    ("remorseful", "remors"),
+    ("communications", "communicaz"),
+    ("dray", "drai"),
+    ("harrington", "harrington"),
+    ("salvation", "salvaz"),
+    ("twitched", "twitch"),
+    ("arctic", "arctic"),
+    ("boston", "boston"),
+    ("belong", "belong"),
+    ("starts", "start"),
+    ("collections", "collect"),
+    ("rays", "rai"),
+    ("warmed", "warm"),
+    ("aquinas", "aquina"),
+    ("recovering", "recov"),
+    ("pliability", "pliabl"),
+    ("coherent", "coher"),
+    ("vibrates", "vibrat"),
+    ("deserts", "desert"),
+    ("duel", "duel"),
+    ("overwhelm", "overwhelm"),
+    ("ringed", "ring"),
+    ("ham", "ham"),
+    ("listenings", "listen"),
+    ("trigonomical", "trigonom"),
+    ("incoherently", "incoher"),
+    ("thrills", "thrill"),
+    ("forcible", "forcibl"),
+    ("prawns", "prawn"),
+    ("rigamarole", "rigamarol"),
+    ("extorting", "extort"),
+    ("unintelligible", "unintellig"),
+    ("discomforts", "discomfort"),
+    ("humouring", "humour"),
+    ("connections", "connect"),
+    ("umb", "umb"),
+    ("potter", "potter"),
+    ("superficial", "superfici"),
+    ("thumps", "thump"),
+    ("bosom", "bosom"),
+    ("dean", "dean"),
+    ("stock", "stock"),
+    ("bawl", "bawl"),
+    ("conciliated", "concili"),
+    ("exactitude", "exactitud"),
+    ("kitchin", "kitchin"),
+    ("scale", "scale"),
+    ("poems", "poem"),
+    ("men", "men"),
+    ("rusty", "rusti"),
+    ("severed", "sever"),
+    ("charon", "charon"),
+    ("cupola", "cupola"),
+    ("conspire", "conspir"),
+    ("subterfuge", "subterfug"),
+    ("curtained", "curtain"),
+    ("bestowed", "bestow"),
+    ("pink", "pink"),
+    ("mineral", "miner"),
+    ("creep", "creep"),
+    ("uncleanliness", "uncleanli"),
+    ("circumstance", "circumst"),
+    ("drone", "drone"),
+    ("supremely", "suprem"),
+    ("gup", "gup"),
+    ("reduvius", "reduviu"),
+    ("gallinazos", "gallinazo"),
+    ("migratorius", "migratoriu"),
+    ("infection", "infect"),
+    ("blurs", "blur"),
+    ("unpacking", "unpack"),
+    ("slighting", "slight"),
+    ("amply", "ampli"),
+    ("refrained", "refrain"),
+    ("penultimate", "penultim"),
+    ("messengers", "messeng"),
+    ("faineants", "faineant"),
+    ("forded", "ford"),
+    ("choosing", "choos"),
+    ("stables", "stabl"),
+    ("fulcrum", "fulcrum"),
+    ("baynoe", "bayno"),
+    ("travelers", "travel"),
+    ("severe", "sever"),
+    ("karl", "karl"),
+    ("jowl", "jowl"),
+    ("infra", "infra"),
+    ("vacillated", "vacil"),
+    ("every", "everi"),
+    ("permanent", "perman"),
+    ("recollected", "recollect"),
+    ("saladillo", "saladillo"),
+    ("auxiliary", "auxiliari"),
+    ("sabbatarian", "sabbatarian"),
+    ("justifying", "justifi"),
+    ("moodiness", "moodi"),
+    ("cur", "cur"),
+    ("jewby", "jewbi"),
+    ("deaths", "death"),
+    ("significantly", "significantli"),
+    ("jerry", "jerri"),
+    ("pursuit", "pursuit"),
+    ("transmutes", "transmut"),
+    ("surest", "surest"),
+    ("whips", "whip"),
+    ("deck", "deck"),
+    ("tasteful", "tast"),
+    ("picturesque", "picturesqu"),
+    ("nominee", "nomine"),
+    ("parishes", "parish"),
+    ("spice", "spice"),
+    ("diluted", "dilut"),
+    ("breakfasting", "breakfast"),
+    ("nobodys", "nobodi"),
+    ("tbe", "tbe"),
+    ("charges", "charg"),
+    ("fascinations", "fascinaz"),
+    ("shambles", "shambl"),
+    ("pulses", "puls"),
+    ("traverse", "travers"),
+    ("pro", "pro"),
+    ("perusing", "perus"),
+    ("text", "text"),
+    ("homoptera", "homoptera"),
+    ("nonce", "nonc"),
+    ("clawing", "claw"),
+    ("thwarted", "thwart"),
+    ("bod", "bod"),
+    ("coquetry", "coquetri"),
+    ("sparrow", "sparrow"),
+    ("lodges", "lodg"),
+    ("endowment", "endow"),
+    ("denmark", "denmark"),
+    ("honysuckles", "honysuckl"),
+    ("gruffly", "gruffli"),
+    ("springing", "springing"),
+    ("apply", "appli"),
+    ("courtly", "courtli"),
+    ("parenchymatous", "parenchymat"),
+    ("shal", "shal"),
+    ("probable", "probabl"),
+    ("scuttle", "scuttl"),
+    ("huntsmen", "huntsmen"),
+    ("inelegance", "ineleg"),
+    ("snaps", "snap"),
+    ("subgroup", "subgroup"),
+    ("dissipation", "dissipaz"),
+    ("practiser", "practis"),
+    ("bien", "bien"),
+    ("fowls", "fowl"),
+    ("villain", "villain"),
+    ("firmaments", "firmament"),
+    ("superlative", "superl"),
+    ("room", "room"),
+    ("alphabet", "alphabet"),
+    ("courses", "cours"),
+    ("serpulae", "serpula"),
+    ("vigilant", "vigil"),
+    ("barracks", "barrack"),
+    ("chaff", "chaff"),
+    ("percolates", "percol"),
+    ("book", "book"),
+    ("baking", "bake"),
+    ("pronouncing", "pronounc"),
+    ("fourteenth", "fourteenth"),
+    ("incurious", "incuri"),
+    ("current", "current"),
+    ("toiler", "toiler"),
+    ("bunting", "bunt"),
+    ("pomarre", "pomarr"),
+    ("raise", "rais"),
+    ("elicited", "elicit"),
+    ("negotiable", "negoti"),
+    ("lovable", "lovabl"),
+    ("costume", "costum"),
+    ("winkles", "winkl"),
+    ("redouble", "redoubl"),
+    ("benches", "bench"),
+    ("muy", "mui"),
+    ("jean", "jean"),
+    ("boat", "boat"),
+    ("helmets", "helmet"),
+    ("sistine", "sistin"),
+    ("greshambury", "greshamburi"),
+    ("fine", "fine"),
+    ("buffeting", "buffet"),
+    ("wagon", "wagon"),
+    ("queerness", "queer"),
+    ("faintly", "faintli"),
+    ("gulps", "gulp"),
+    ("reckoned", "reckon"),
+    ("violation", "violaz"),
+    ("wallenstein", "wallenstein"),
+    ("isidro", "isidro"),
+    ("koeldwethout", "koeldwethout"),
+    ("whitish", "whitish"),
+    ("forth", "forth"),
+    ("waking", "wake"),
+    ("unceremonious", "unceremoni"),
+    ("fox", "fox"),
+    ("dares", "dare"),
+    ("agai", "agai"),
+    ("celebes", "celeb"),
+    ("gentlefolks", "gentlefolk"),
+    ("freaks", "freak"),
+    ("intelligible", "intellig"),
+    ("desertion", "desert"),
+    ("pedagogue", "pedagogu"),
+    ("leave", "leav"),
+    ("rigour", "rigour"),
+    ("blots", "blot"),
+    ("apires", "apir"),
+    ("brun", "brun"),
+    ("sedately", "sedat"),
+    ("shawls", "shawl"),
+    ("resides", "resid"),
+    ("mutter", "mutter"),
+    ("bartholomew", "bartholomew"),
+    ("blandly", "blandli"),
+    ("tempered", "temper"),
+    ("luscious", "lusciou"),
+    ("lassitude", "lassitud"),
+    ("estimating", "estim"),
+    ("fiance", "fianc"),
+    ("uplifted", "uplift"),
+    ("glum", "glum"),
+    ("hoarded", "hoard"),
+    ("chanced", "chanc"),
+    ("looseness", "loos"),
+    ("drinkable", "drinkabl"),
+    ("merits", "merit"),
+    ("hemispheres", "hemispher"),
+    ("partitions", "partit"),
+    ("proteus", "proteu"),
+    ("quantite", "quantit"),
+    ("gate", "gate"),
+    ("bully", "bulli"),
+    ("forbid", "forbid"),
+    ("spur", "spur"),
+    ("astonishing", "astonish"),
+    ("panic", "panic"),
+    ("busies", "busi"),
+    ("folds", "fold"),
+    ("suspiciously", "suspici"),
+    ("opinionated", "opinion"),
+    ("rapturously", "raptur"),
+    ("often", "often"),
+    ("rodionovitch", "rodionovitch"),
+    ("listener", "listen"),
+    ("mule", "mule"),
+    ("movements", "movement"),
+    ("thunderbolt", "thunderbolt"),
+    ("mutterings", "mutter"),
+    ("voyages", "voyag"),
+    ("catacomb", "catacomb"),
+    ("opaque", "opaqu"),
+    ("noblemen", "noblemen"),
+    ("farthings", "farth"),
+    ("beastly", "beastli"),
+    ("elucidated", "elucid"),
+    ("fearfulty", "fearfulti"),
+    ("cuttle", "cuttl"),
+    ("liar", "liar"),
+    ("angry", "angri"),
+    ("discoverable", "discover"),
+    ("atmosphere", "atmospher"),
+    ("reticent", "retic"),
+    ("inclusive", "inclus"),
+    ("slighted", "slight"),
+    ("packs", "pack"),
+    ("unlearn", "unlearn"),
+    ("greedily", "greedili"),
+    ("slaves", "slave"),
+    ("creditably", "credit"),
+    ("woodcot", "woodcot"),
+    ("barmaid", "barmaid"),
+    ("expressively", "express"),
+    ("suspects", "suspect"),
+    ("bethany", "bethani"),
+    ("bamboo", "bamboo"),
+    ("miste", "mist"),
+    ("desiring", "desir"),
+    ("bundle", "bundl"),
+    ("vanessa", "vanessa"),
+    ("goodly", "goodli"),
+    ("virtuous", "virtuou"),
+    ("brazilians", "brazilian"),
+    ("brute", "brute"),
+    ("serces", "serc"),
+    ("athenaeum", "athenaeum"),
+    ("vagueness", "vagu"),
+    ("commonly", "commonli"),
+    ("businesslike", "businesslik"),
+    ("inspected", "inspect"),
+    ("grudge", "grudg"),
+    ("watts", "watt"),
+    ("sepultus", "sepultu"),
+    ("swallering", "swaller"),
+    ("timorous", "timor"),
+    ("stead", "stead"),
+    ("farmhouse", "farmhous"),
+    ("indiscriminating", "indiscrimin"),
+    ("deceptive", "decept"),
+    ("acquaint", "acquaint"),
+    ("trickling", "trickl"),
+    ("ruffianly", "ruffianli"),
+    ("unpretending", "unpretend"),
+    ("brethren", "brethren"),
+    ("divert", "divert"),
+    ("cooked", "cook"),
+    ("stifled", "stifl"),
+    ("noways", "nowai"),
+    ("governor", "governor"),
+    ("stouter", "stouter"),
+    ("retrieve", "retriev"),
+    ("icy", "ici"),
+    ("feints", "feint"),
+    ("hampstead", "hampstead"),
+    ("huddled", "huddl"),
+    ("punish", "punish"),
+    ("eucalypti", "eucalypti"),
+    ("prepare", "prepar"),
+    ("contemning", "contemn"),
+    ("madrid", "madrid"),
+    ("guanacos", "guanaco"),
+    ("mental", "mental"),
+    ("spitting", "spit"),
+    ("willingiy", "willingii"),
+    ("indebted", "indebt"),
+    ("individua", "individua"),
+    ("amorites", "amorit"),
+    ("unanimously", "unanim"),
+    ("iris", "iri"),
+    ("exaggeration", "exaggeraz"),
+    ("justest", "justest"),
+    ("kissing", "kiss"),
+    ("shirking", "shirk"),
+    ("thawing", "thaw"),
+    ("novices", "novic"),
+    ("lioness", "lio"),
+    ("acquire", "acquir"),
+    ("florist", "florist"),
+    ("making", "make"),
+    ("clearest", "clearest"),
+    ("duns", "dun"),
+    ("maidservants", "maidserv"),
+    ("shyness", "shy"),
+    ("clumsily", "clumsili"),
+    ("schonsten", "schonsten"),
+    ("tony", "toni"),
+    ("barricade", "barricad"),
+    ("employing", "emploi"),
+    ("coaxed", "coax"),
+    ("alters", "alter"),
+    ("grigorievitch", "grigorievitch"),
+    ("knaves", "knave"),
+    ("dacelo", "dacelo"),
+    ("inconvenience", "inconveni"),
+    ("stepdaughter", "stepdaught"),
+    ("sleeves", "sleev"),
+    ("expedient", "expedi"),
+    ("sierra", "sierra"),
+    ("reverberation", "reverberaz"),
+    ("innate", "innat"),
+    ("pence", "penc"),
+    ("opinions", "opinion"),
+    ("unperformed", "unperform"),
+    ("reposes", "repos"),
+    ("thaw", "thaw"),
+    ("limb", "limb"),
+    ("clock", "clock"),
+    ("bide", "bide"),
+    ("averting", "avert"),
+    ("scrap", "scrap"),
+    ("pewter", "pewter"),
+    ("discoverin", "discoverin"),
+    ("tigers", "tiger"),
+    ("serjeants", "serjeant"),
+    ("acutely", "acut"),
+    ("gadfly", "gadfli"),
+    ("dizziness", "dizzi"),
+    ("exemplifies", "exemplifi"),
+    ("grime", "grime"),
+    ("subsisted", "subsist"),
+    ("pathetics", "pathet"),
+    ("hangdog", "hangdog"),
+    ("mortar", "mortar"),
+    ("mimosas", "mimosa"),
+    ("laden", "laden"),
+    ("repugnant", "repugn"),
+    ("implicated", "implic"),
+    ("mildest", "mildest"),
+    ("scoured", "scour"),
+    ("sheath", "sheath"),
+    ("pose", "pose"),
+    ("unchallengeable", "unchalleng"),
+    ("enthusiasm", "enthusiasm"),
+    ("dishonest", "dishonest"),
+    ("dexterous", "dexter"),
+    ("juncus", "juncu"),
+    ("hoffmanseggi", "hoffmanseggi"),
+    ("cornish", "cornish"),
+    ("purposely", "purpos"),
+    ("tameness", "tame"),
+    ("shameful", "shame"),
+    ("unemployed", "unemploi"),
+    ("forgiving", "forgiv"),
+    ("engaged", "engag"),
+    ("feted", "fete"),
+    ("entree", "entre"),
+    ("abolishing", "abolish"),
+    ("uncivilized", "uncivil"),
+    ("runs", "run"),
+    ("fieldingsby", "fieldingsbi"),
+    ("cartridge", "cartridg"),
+    ("casual", "casual"),
+    ("engage", "engag"),
+    ("revelation", "revelaz"),
+    ("nihilists", "nihilist"),
+    ("squirearchy", "squirearchi"),
+    ("torn", "torn"),
+    ("walnut", "walnut"),
+    ("writers", "writer"),
+    ("grandmama", "grandmama"),
+    ("molluscs", "mollusc"),
+    ("open", "open"),
+    ("fridolin", "fridolin"),
+    ("liberates", "liber"),
+    ("daren", "daren"),
+    ("accusations", "accusaz"),
+    ("muffle", "muffl"),
+    ("druidical", "druidic"),
+    ("industrious", "industri"),
+    ("compliment", "compliment"),
+    ("seeks", "seek"),
+    ("believes", "believ"),
+    ("candlestick", "candlestick"),
+    ("delicacies", "delicaci"),
+    ("spinning", "spin"),
+    ("naive", "naiv"),
+    ("loftiness", "lofti"),
+    ("giddy", "giddi"),
+    ("reappearing", "reappear"),
+    ("lend", "lend"),
+    ("counteract", "counteract"),
+    ("political", "polit"),
+    ("slower", "slower"),
+    ("confronted", "confront"),
+    ("sneeze", "sneez"),
+    ("hastened", "hasten"),
+    ("presses", "press"),
+    ("jugs", "jug"),
+    ("bairn", "bairn"),
+    ("finis", "fini"),
+    ("ninevite", "ninevit"),
+    ("latitude", "latitud"),
+    ("ti", "ti"),
+    ("earlier", "earlier"),
+    ("mutinous", "mutin"),
+    ("quebrantahuesos", "quebrantahueso"),
+    ("elms", "elm"),
+    ("sprightly", "sprightli"),
+    ("friar", "friar"),
+    ("catherine", "catherin"),
+    ("flagged", "flag"),
+    ("aims", "aim"),
+    ("olivia", "olivia"),
+    ("pouch", "pouch"),
+    ("admonitory", "admonitori"),
+    ("essay", "essai"),
+    ("palm", "palm"),
+    ("direfully", "direfulli"),
+    ("bulgaria", "bulgaria"),
+    ("heinous", "heinou"),
+    ("killer", "killer"),
+    ("pronoun", "pronoun"),
+    ("deterred", "deter"),
+    ("warning", "warn"),
+    ("shamefaced", "shamefac"),
+    ("pigs", "pig"),
+    ("shrillest", "shrillest"),
+    ("enlivenment", "enliven"),
+    ("conjecturing", "conjectur"),
+    ("idolized", "idol"),
+    ("soirees", "soire"),
+    ("cleanest", "cleanest"),
+    ("poultry", "poultri"),
+    ("glance", "glanc"),
+    ("originating", "origin"),
+    ("astride", "astrid"),
+    ("pump", "pump"),
+    ("anchored", "anchor"),
+    ("noise", "nois"),
+    ("radiating", "radiat"),
+    ("galling", "gall"),
+    ("lieutenancies", "lieuten"),
+    ("nettles", "nettl"),
+    ("offender", "offend"),
+    ("chests", "chest"),
+    ("bermudas", "bermuda"),
+    ("squashed", "squash"),
+    ("scabra", "scabra"),
+    ("squabs", "squab"),
+    ("peculiarly", "peculiarli"),
+    ("sterling", "sterl"),
+    ("balm", "balm"),
+    ("notary", "notari"),
+    ("crooks", "crook"),
+    ("shamming", "sham"),
+    ("heaved", "heav"),
+    ("stroll", "stroll"),
+    ("lagging", "lag"),
+    ("dalliance", "dallianc"),
+    ("annals", "annal"),
+    ("sinless", "sinless"),
+    ("bullies", "bulli"),
+    ("furbished", "furbish"),
+    ("memoir", "memoir"),
+    ("soaking", "soak"),
+    ("disrespectful", "disrespect"),
+    ("drunkards", "drunkard"),
+    ("spicy", "spici"),
+    ("warms", "warm"),
+    ("stripping", "strip"),
+    ("centra", "centra"),
+    ("perplexities", "perplex"),
+    ("males", "male"),
+    ("sell", "sell"),
+    ("pumping", "pump"),
+    ("mentions", "mention"),
+    ("calibre", "calibr"),
+    ("asserts", "assert"),
+    ("cottagers", "cottag"),
+    ("drigg", "drigg"),
+    ("ceedingly", "ceedingli"),
+    ("gerous", "gerou"),
+    ("conservatives", "conserv"),
+    ("superfluities", "superflu"),
+    ("godlike", "godlik"),
+    ("dishabille", "dishabill"),
+    ("cowboy", "cowboi"),
+    ("withal", "withal"),
+    ("parade", "parad"),
+    ("grubbed", "grub"),
+    ("physical", "physic"),
+    ("spun", "spun"),
+    ("patronising", "patronis"),
+    ("credentials", "credenti"),
+    ("sphinx", "sphinx"),
+    ("cabriolet", "cabriolet"),
+    ("dispensation", "dispensaz"),
+    ("croaks", "croak"),
+    ("advantages", "advantag"),
+    ("spasmodically", "spasmod"),
+    ("defunct", "defunct"),
+    ("fragm", "fragm"),
+    ("flats", "flat"),
+    ("visitors", "visitor"),
+    ("reconsignment", "reconsign"),
+    ("raft", "raft"),
+    ("fugitive", "fugit"),
+    ("ringer", "ringer"),
+    ("smiffeld", "smiffeld"),
+    ("bulbous", "bulbou"),
+    ("peacocks", "peacock"),
+    ("struggles", "struggl"),
+    ("nine", "nine"),
+    ("assizes", "assiz"),
+    ("scamper", "scamper"),
+    ("affections", "affect"),
+    ("notches", "notch"),
+    ("volley", "vollei"),
+    ("nationality", "nation"),
+    ("jemmy", "jemmi"),
+    ("education", "educaz"),
+    ("filled", "fill"),
+    ("undeceiving", "undeceiv"),
+    ("syllable", "syllabl"),
+    ("coypus", "coypu"),
+    ("chastisement", "chastis"),
+    ("dea", "dea"),
+    ("heads", "head"),
+    ("kidnapper", "kidnapp"),
+    ("indicated", "indic"),
+    ("infatuation", "infatuaz"),
+    ("omnivorous", "omnivor"),
+    ("disreputable", "disreput"),
+    ("gi", "gi"),
+    ("gillies", "gilli"),
+    ("sulked", "sulk"),
+    ("due", "due"),
+    ("mascariensis", "mascariensi"),
+    ("horsemanship", "horsemanship"),
+    ("hoped", "hope"),
+    ("dissolved", "dissolv"),
+    ("qualify", "qualifi"),
+    ("stress", "stress"),
+    ("acceptance", "accept"),
+    ("footmen", "footmen"),
+    ("unhonoured", "unhonour"),
+    ("christians", "christian"),
+    ("hereticks", "heretick"),
+    ("browndock", "browndock"),
+    ("blest", "blest"),
+    ("emit", "emit"),
+    ("chairs", "chair"),
+    ("lesson", "lesson"),
+    ("recorded", "record"),
+    ("joined", "join"),
+    ("zaraisk", "zaraisk"),
+    ("smoothness", "smooth"),
+    ("uncommunicative", "uncommun"),
+    ("paviour", "paviour"),
+    ("blotches", "blotch"),
+    ("wice", "wice"),
+    ("scrub", "scrub"),
+    ("candidate", "candid"),
+    ("unfilled", "unfil"),
+    ("herein", "herein"),
+    ("mildmay", "mildmai"),
+    ("embroidering", "embroid"),
+    ("rigorous", "rigor"),
+    ("reasoners", "reason"),
+    ("representatives", "repres"),
+    ("ripple", "rippl"),
+    ("sullenness", "sullen"),
+    ("resume", "resum"),
+    ("insolvent", "insolv"),
+    ("jauntiness", "jaunti"),
+    ("tyrannical", "tyrann"),
+    ("defraying", "defrai"),
+    ("purposed", "purpos"),
+    ("substituted", "substitut"),
+    ("conjectured", "conjectur"),
+    ("goods", "good"),
+    ("armadillo", "armadillo"),
+    ("ridding", "rid"),
+    ("penetrates", "penetr"),
+    ("seducer", "seduc"),
+    ("replacing", "replac"),
+    ("latticed", "lattic"),
+    ("aspects", "aspect"),
+    ("loose", "loos"),
+    ("action", "action"),
+    ("obligingly", "obligingli"),
+    ("extinguishers", "extinguish"),
+    ("threescore", "threescor"),
+    ("pledged", "pledg"),
+    ("presentime", "presentim"),
+    ("admonishes", "admonish"),
+    ("unpaid", "unpaid"),
+    ("punctured", "punctur"),
+    ("quickest", "quickest"),
+    ("ask", "ask"),
+    ("indians", "indian"),
+    ("painfullest", "painfullest"),
+    ("pe", "pe"),
+    ("decay", "decai"),
+    ("rammer", "rammer"),
+    ("blackguard", "blackguard"),
+    ("sheltered", "shelter"),
+    ("axis", "axi"),
+    ("teak", "teak"),
+    ("border", "border"),
+    ("furnish", "furnish"),
+    ("uncared", "uncar"),
+    ("provisions", "provis"),
+    ("dissuasions", "dissuas"),
+    ("husbanded", "husband"),
+    ("instigator", "instig"),
+    ("covetousness", "covet"),
+    ("tighter", "tighter"),
+    ("tantalising", "tantalis"),
+    ("plants", "plant"),
+    ("supped", "sup"),
+    ("consultations", "consultaz"),
+    ("fatten", "fatten"),
+    ("panther", "panther"),
+    ("packet", "packet"),
+    ("lure", "lure"),
+    ("predominant", "predomin"),
+    ("serenity", "seren"),
+    ("matters", "matter"),
+    ("along", "along"),
+    ("cambridgeshire", "cambridgeshir"),
+    ("overloaded", "overload"),
+    ("already", "alreadi"),
+    ("orchards", "orchard"),
+    ("graciousness", "gracious"),
+    ("elwes", "elw"),
+    ("skinned", "skin"),
+    ("mameluke", "mameluk"),
+    ("hailed", "hail"),
+    ("bleak", "bleak"),
+    ("trigonocephalus", "trigonocephalu"),
+    ("conceivable", "conceiv"),
+    ("promenade", "promenad"),
+    ("stretched", "stretch"),
+    ("bridges", "bridg"),
+    ("gardeners", "garden"),
+    ("willst", "willst"),
+    ("madeira", "madeira"),
+    ("fait", "fait"),
+    ("cords", "cord"),
+    ("grenadiers", "grenadi"),
+    ("achieves", "achiev"),
+    ("remonstrates", "remonstr"),
+    ("respite", "respit"),
+    ("overshadowing", "overshadow"),
+    ("wofully", "wofulli"),
+    ("hobbledehoy", "hobbledehoi"),
+    ("flirts", "flirt"),
+    ("prophetical", "prophet"),
+    ("undisguisedly", "undisguisedli"),
+    ("wipes", "wipe"),
+    ("profess", "profess"),
+    ("atone", "aton"),
+    ("interests", "interest"),
+    ("stubbornness", "stubborn"),
+    ("dollar", "dollar"),
+    ("falsely", "fals"),
+    ("outburst", "outburst"),
+    ("culprit", "culprit"),
+    ("stretchings", "stretch"),
+    ("availed", "avail"),
+    ("forrenner", "forrenn"),
+    ("fen", "fen"),
+    ("insinuating", "insinu"),
+    ("peewit", "peewit"),
+    ("prickles", "prickl"),
+    ("headaches", "headach"),
+    ("convenient", "conveni"),
+    ("eruptions", "erupt"),
+    ("pimples", "pimpl"),
+    ("inspiration", "inspiraz"),
+    ("genealogical", "genealog"),
+    ("altered", "alter"),
+    ("pin", "pin"),
+    ("condemns", "condemn"),
+    ("vacations", "vacaz"),
+    ("deserving", "deserv"),
+    ("crippler", "crippler"),
+    ("acquainting", "acquaint"),
+    ("provincial", "provinci"),
+    ("dreadnought", "dreadnought"),
+    ("shoemaker", "shoemak"),
+    ("euphorbiaceae", "euphorbiacea"),
+    ("architect", "architect"),
+    ("flattering", "flatter"),
+    ("pattering", "patter"),
+    ("disconnected", "disconnect"),
+    ("sir", "sir"),
+    ("traits", "trait"),
+    ("manufactory", "manufactori"),
+    ("moulds", "mould"),
+    ("jackanapes", "jackanap"),
+    ("uneasiness", "uneasi"),
+    ("withdrawing", "withdraw"),
+    ("participation", "participaz"),
+    ("blessedness", "blessed"),
+    ("rimsky", "rimski"),
+    ("tenfold", "tenfold"),
+    ("sneaks", "sneak"),
+    ("aggrandizement", "aggrandiz"),
+    ("moodle", "moodl"),
+    ("cute", "cute"),
+    ("guardia", "guardia"),
+    ("sak", "sak"),
+    ("hymenopterous", "hymenopter"),
+    ("higgenbottom", "higgenbottom"),
+    ("carrots", "carrot"),
+    ("accelerate", "acceler"),
+    ("oozed", "ooz"),
+    ("nooks", "nook"),
+    ("preserved", "preserv"),
+    ("grimble", "grimbl"),
+    ("dissolve", "dissolv"),
+    ("yusupov", "yusupov"),
+    ("restore", "restor"),
+    ("humorously", "humor"),
+    ("mizzle", "mizzl"),
+    ("desolate", "desol"),
+    ("deead", "deead"),
+    ("acquiring", "acquir"),
+    ("solitary", "solitari"),
+    ("boards", "board"),
+    ("agean", "agean"),
+    ("roan", "roan"),
+    ("whip", "whip"),
+    ("reprobates", "reprob"),
+    ("ja", "ja"),
+    ("conclusion", "conclus"),
+    ("prolong", "prolong"),
+    ("calculation", "calculaz"),
+    ("significance", "signific"),
+    ("chrysomelidae", "chrysomelida"),
+    ("deliciously", "delici"),
+    ("trellis", "trelli"),
+    ("temples", "templ"),
+    ("recalled", "recal"),
+    ("draughts", "draught"),
+    ("enlivened", "enliven"),
+    ("gunwale", "gunwal"),
+    ("loser", "loser"),
+    ("nearsighted", "nearsight"),
+    ("tried", "tri"),
+    ("licking", "lick"),
+    ("economy", "economi"),
+    ("housewife", "housewif"),
+    ("pursing", "purs"),
+    ("friends", "friend"),
+    ("revolved", "revolv"),
+    ("roughest", "roughest"),
+    ("govvernor", "govvernor"),
+    ("mildewed", "mildew"),
+    ("possessed", "possess"),
+    ("vexatious", "vexati"),
+    ("slice", "slice"),
+    ("labourers", "labour"),
+    ("abuses", "abus"),
+    ("july", "juli"),
+    ("hearers", "hearer"),
+    ("probing", "probe"),
+    ("housebreaker", "housebreak"),
+    ("declining", "declin"),
+    ("charley", "charlei"),
+    ("wai", "wai"),
+    ("salary", "salari"),
+    ("wise", "wise"),
+    ("habituated", "habitu"),
+    ("cleft", "cleft"),
+    ("posting", "post"),
+    ("mooning", "moon"),
+    ("situated", "situat"),
+    ("choked", "choke"),
+    ("turveydrops", "turveydrop"),
+    ("physique", "physiqu"),
+    ("cauquenes", "cauquen"),
+    ("hond", "hond"),
+    ("silenced", "silenc"),
+    ("prospectus", "prospectu"),
+    ("consume", "consum"),
+    ("prancing", "pranc"),
+    ("spines", "spine"),
+    ("prophecy", "propheci"),
+    ("chatterbox", "chatterbox"),
+    ("loins", "loin"),
+    ("backslidings", "backslid"),
+    ("fabulously", "fabul"),
+    ("beroe", "bero"),
+    ("avatar", "avatar"),
+    ("cyprus", "cypru"),
+    ("abolish", "abolish"),
+    ("grapevine", "grapevin"),
+    ("undistinguishable", "undistinguish"),
+    ("ajax", "ajax"),
+    ("nominative", "nomin"),
+    ("delicious", "delici"),
+    ("neatest", "neatest"),
+    ("rummy", "rummi"),
+    ("tiny", "tini"),
+    ("denies", "deni"),
+    ("reverses", "revers"),
+    ("recesses", "recess"),
+    ("cormoran", "cormoran"),
+    ("avignon", "avignon"),
+    ("attics", "attic"),
+    ("extra", "extra"),
+    ("inland", "inland"),
+    ("pelagic", "pelag"),
+    ("popping", "pop"),
+    ("drawbacks", "drawback"),
+    ("razumihins", "razumihin"),
+    ("bulletins", "bulletin"),
+    ("shopmen", "shopmen"),
+    ("agree", "agre"),
+    ("expedition", "expedit"),
+    ("blunderbus", "blunderbu"),
+    ("lock", "lock"),
+    ("himalaya", "himalaya"),
+    ("consecutive", "consecut"),
+    ("irish", "irish"),
+    ("musing", "muse"),
+    ("thinner", "thinner"),
+    ("bones", "bone"),
+    ("consisted", "consist"),
+    ("nobility", "nobl"),
+    ("forwards", "forward"),
+    ("scourges", "scourg"),
+    ("preacher", "preacher"),
+    ("transformed", "transform"),
+    ("disputations", "disputaz"),
+    ("disaster", "disast"),
+    ("cubic", "cubic"),
+    ("chem", "chem"),
+    ("tempers", "temper"),
+    ("touched", "touch"),
+    ("morton", "morton"),
+    ("cottager", "cottag"),
+    ("dally", "dal"),
+    ("forcibly", "forcibli"),
+    ("lets", "let"),
+    ("column", "column"),
+    ("discounting", "discount"),
+    ("whet", "whet"),
+    ("humblest", "humblest"),
+    ("commence", "commenc"),
+    ("tumblings", "tumbl"),
+    ("inseparable", "insepar"),
+    ("dwelt", "dwelt"),
+    ("away", "awai"),
+    ("coveted", "covet"),
+    ("radiate", "radiat"),
+    ("shop", "shop"),
+    ("scratches", "scratch"),
+    ("oscillating", "oscil"),
+    ("rushes", "rush"),
+    ("acts", "act"),
+    ("pastime", "pastim"),
+    ("germany", "germani"),
+    ("crawling", "crawl"),
+    ("carpets", "carpet"),
+    ("volcano", "volcano"),
+    ("phew", "phew"),
+    ("screw", "screw"),
+    ("perpetration", "perpetraz"),
+    ("constantly", "constantli"),
+    ("phosphuretted", "phosphuret"),
+    ("retaliate", "retali"),
+    ("arab", "arab"),
+    ("microbes", "microb"),
+    ("anima", "anima"),
+    ("nicety", "niceti"),
+    ("shaves", "shave"),
+    ("smithfield", "smithfield"),
+    ("shareholders", "sharehold"),
+    ("swords", "sword"),
+    ("nicer", "nicer"),
+    ("farmer", "farmer"),
+    ("cocking", "cock"),
+    ("searched", "search"),
+    ("novice", "novic"),
+    ("waders", "wader"),
+    ("trader", "trader"),
+    ("papawa", "papawa"),
+    ("bounding", "bound"),
+    ("heroine", "heroin"),
+    ("diagonal", "diagon"),
+    ("banks", "bank"),
+    ("scions", "scion"),
+    ("combats", "combat"),
+    ("ordinarily", "ordinarili"),
+    ("prehensile", "prehensil"),
+    ("parts", "part"),
+    ("sneers", "sneer"),
+    ("lose", "lose"),
+    ("siunmerson", "siunmerson"),
+    ("ordinance", "ordin"),
+    ("cive", "cive"),
+    ("encamps", "encamp"),
+    ("a", "a"),
+    ("bolting", "bolt"),
+    ("recollecting", "recollect"),
+    ("daring", "dare"),
+    ("firearms", "firearm"),
+    ("scrutinise", "scrutinis"),
+    ("fitzgibbon", "fitzgibbon"),
+    ("darwinii", "darwinii"),
+    ("executed", "execut"),
+    ("agua", "agua"),
+    ("necessitated", "necessit"),
+    ("thinned", "thin"),
+    ("loodgin", "loodgin"),
+    ("arguments", "argument"),
+    ("class", "class"),
+    ("remove", "remov"),
+    ("rupture", "ruptur"),
+    ("grisly", "grisli"),
+    ("unreserved", "unreserv"),
+    ("prayfession", "prayfess"),
+    ("anemone", "anemon"),
+    ("brass", "brass"),
+    ("chooses", "choos"),
+    ("unclasped", "unclasp"),
+    ("senseless", "senseless"),
+    ("hardihood", "hardihood"),
+    ("lovingness", "loving"),
+    ("apostrophe", "apostroph"),
+    ("merry", "merri"),
+    ("tutor", "tutor"),
+    ("shoving", "shove"),
+    ("complete", "complet"),
+    ("tack", "tack"),
+    ("adoration", "adoraz"),
+    ("stains", "stain"),
+    ("religion", "religion"),
+    ("evenly", "evenli"),
+    ("kammerjunker", "kammerjunk"),
+    ("chew", "chew"),
+    ("pla", "pla"),
+    ("staffordshire", "staffordshir"),
+    ("hesitations", "hesitaz"),
+    ("improbability", "improb"),
+    ("caylen", "caylen"),
+    ("sufferers", "suffer"),
+    ("teeth", "teeth"),
+    ("sorrel", "sorrel"),
+    ("diagnosed", "diagnos"),
+    ("crucifying", "crucifi"),
+    ("served", "serv"),
+    ("resumes", "resum"),
+    ("beldame", "beldam"),
+    ("exserted", "exsert"),
+    ("deaden", "deaden"),
+    ("cultivated", "cultiv"),
+    ("lxiv", "lxiv"),
+    ("brightly", "brightli"),
+    ("intercepts", "intercept"),
+    ("twirling", "twirl"),
+    ("perils", "peril"),
+    ("purser", "purser"),
+    ("neighbourly", "neighbourli"),
+    ("felicitous", "felicit"),
+    ("wholly", "wholli"),
+    ("theristicus", "theristicu"),
+    ("computation", "computaz"),
+    ("judges", "judg"),
+    ("shouting", "shout"),
+    ("broide", "broid"),
+    ("retired", "retir"),
+    ("edification", "edificaz"),
+    ("philosophers", "philosoph"),
+    ("statesmen", "statesmen"),
+    ("extol", "extol"),
+    ("flirtation", "flirtaz"),
+    ("profane", "profan"),
+    ("devotedly", "devotedli"),
+    ("thine", "thine"),
+    ("undismayed", "undismai"),
+    ("arguing", "argu"),
+    ("purport", "purport"),
+    ("untouched", "untouch"),
+    ("carpeted", "carpet"),
+    ("surviving", "surviv"),
+    ("among", "among"),
+    ("sevres", "sevr"),
+    ("descen", "descen"),
+    ("glue", "glue"),
+    ("possibilities", "possibl"),
+    ("caus", "cau"),
+    ("thickened", "thicken"),
+    ("pleace", "pleac"),
+    ("bells", "bell"),
+    ("incas", "inca"),
+    ("edition", "edit"),
+    ("obsequious", "obsequi"),
+    ("flames", "flame"),
+    ("veranda", "veranda"),
+    ("ridiculed", "ridicul"),
+    ("meal", "meal"),
+    ("nipping", "nip"),
+    ("reginald", "reginald"),
+    ("came", "came"),
+    ("blows", "blow"),
+    ("counterfeiting", "counterfeit"),
+    ("indigent", "indig"),
+    ("elongated", "elong"),
+    ("devoted", "devot"),
+    ("archway", "archwai"),
+    ("flaco", "flaco"),
+    ("bricklayers", "bricklayer"),
+    ("petersham", "petersham"),
+    ("chapter", "chapter"),
+    ("under", "under"),
+    ("sacredly", "sacredli"),
+    ("storey", "storei"),
+    ("last", "last"),
+    ("fainted", "faint"),
+    ("courier", "courier"),
+    ("woa", "woa"),
+    ("sulphureous", "sulphur"),
+    ("rejoin", "rejoin"),
+    ("flee", "flee"),
+    ("charlatanism", "charlatan"),
+    ("surprise", "surpris"),
+    ("elevate", "elev"),
+    ("endearment", "endear"),
+    ("interpreted", "interpret"),
+    ("bravassa", "bravassa"),
+    ("bubbles", "bubbl"),
+    ("tavern", "tavern"),
+    ("inequalities", "inequ"),
+    ("pretended", "pretend"),
+    ("testacea", "testacea"),
+    ("underscored", "underscor"),
+    ("voraciously", "voraci"),
+    ("fronting", "front"),
+    ("bushes", "bush"),
+    ("kid", "kid"),
+    ("stirring", "stir"),
+    ("snawley", "snawlei"),
+    ("gurgled", "gurgl"),
+    ("proficient", "profici"),
+    ("expenditure", "expenditur"),
+    ("churning", "churn"),
+    ("toilsome", "toilsom"),
+    ("spoonfuls", "spoon"),
+    ("und", "und"),
+    ("craven", "craven"),
+    ("pageant", "pageant"),
+    ("deteriorate", "deterior"),
+    ("macadam", "macadam"),
+    ("vibratory", "vibratori"),
+    ("bravard", "bravard"),
+    ("bons", "bon"),
+    ("erythraeum", "erythraeum"),
+    ("crept", "crept"),
+    ("ale", "al"),
+    ("soaked", "soak"),
+    ("amusements", "amus"),
+    ("formation", "formaz"),
+    ("leaned", "lean"),
+    ("strenuously", "strenuous"),
+    ("steady", "steadi"),
+    ("weazened", "weazen"),
+    ("burns", "burn"),
+    ("pritchards", "pritchard"),
+    ("bereft", "bereft"),
+    ("caterpillars", "caterpillar"),
+    ("jaundiced", "jaundic"),
+    ("profanation", "profanaz"),
+    ("malta", "malta"),
+    ("chick", "chick"),
+    ("tschudi", "tschudi"),
+    ("sufferer", "suffer"),
+    ("mediaeval", "mediaev"),
+    ("blood", "blood"),
+    ("vacillations", "vacillaz"),
+    ("saeugethiere", "saeugethier"),
+    ("madre", "madr"),
+    ("resource", "resourc"),
+    ("regent", "regent"),
+    ("gootther", "gootther"),
+    ("onto", "onto"),
+    ("interchanging", "interchang"),
+    ("histrionic", "histrion"),
+    ("embellished", "embellish"),
+    ("keep", "keep"),
+    ("toughey", "toughei"),
+    ("wollaston", "wollaston"),
+    ("lasted", "last"),
+    ("redeeming", "redeem"),
+    ("clime", "clime"),
+    ("subscriptions", "subscript"),
+    ("brit", "brit"),
+    ("unpleasing", "unpleas"),
+    ("impenetrabilities", "impenetr"),
+    ("beetle", "beetl"),
+    ("smiling", "smile"),
+    ("sincere", "sincer"),
+    ("kneel", "kneel"),
+    ("assize", "assiz"),
+    ("linea", "linea"),
+    ("georgina", "georgina"),
+    ("russet", "russet"),
+    ("geschichte", "geschicht"),
+    ("pot", "pot"),
+    ("prett", "prett"),
+    ("sixes", "six"),
+    ("possession", "possess"),
+    ("depend", "depend"),
+    ("mohair", "mohair"),
+    ("imperturbably", "imperturb"),
+    ("bubble", "bubbl"),
+    ("lilac", "lilac"),
+    ("caution", "caution"),
+    ("formally", "formal"),
+    ("orphanhood", "orphanhood"),
+    ("sanguine", "sanguin"),
+    ("adjacent", "adjac"),
+    ("immature", "immatur"),
+    ("observation", "observaz"),
+    ("priesthood", "priesthood"),
+    ("dearly", "dearli"),
+    ("strangulation", "strangulaz"),
+    ("snugly", "snugli"),
+    ("boorioboola", "boorioboola"),
+    ("parlez", "parlez"),
+    ("everyday", "everydai"),
+    ("showd", "showd"),
+    ("benediction", "benedict"),
+    ("spartan", "spartan"),
+    ("happiness", "happi"),
+    ("softened", "soften"),
+    ("france", "franc"),
+    ("cayanus", "cayanu"),
+    ("tower", "tower"),
+    ("quinchao", "quinchao"),
+    ("emaciated", "emaci"),
+    ("wolds", "wold"),
+    ("raindrops", "raindrop"),
+    ("trio", "trio"),
+    ("imprudent", "imprud"),
+    ("aristocratic", "aristocrat"),
+    ("mart", "mart"),
+    ("picking", "pick"),
+    ("benet", "benet"),
+    ("sulk", "sulk"),
+    ("familiaris", "familiari"),
+    ("subtile", "subtil"),
+    ("elected", "elect"),
+    ("remotely", "remot"),
+    ("incoming", "incom"),
+    ("inestimable", "inestim"),
+    ("forsworn", "forsworn"),
+    ("hindu", "hindu"),
+    ("intrigued", "intrigu"),
+    ("hobbled", "hobbl"),
+    ("evermore", "evermor"),
+    ("journeyed", "journei"),
+    ("platform", "platform"),
+    ("expended", "expend"),
+    ("dram", "dram"),
+    ("pleasurable", "pleasur"),
+    ("progress", "progress"),
+    ("unhappily", "unhappili"),
+    ("capital", "capit"),
+    ("stations", "staze"),
+    ("undefinable", "undefin"),
+    ("neat", "neat"),
+    ("meddling", "meddl"),
+    ("noteworthy", "noteworthi"),
+    ("errand", "errand"),
+    ("cherizette", "cherizett"),
+    ("preparation", "preparaz"),
+    ("piebald", "piebald"),
+    ("revival", "reviv"),
+    ("seizure", "seizur"),
+    ("leagues", "leagu"),
+    ("directors", "director"),
+    ("deceives", "deceiv"),
+    ("shaping", "shape"),
+    ("orifice", "orific"),
+    ("heern", "heern"),
+    ("bulimus", "bulimu"),
+    ("pumpkin", "pumpkin"),
+    ("hoold", "hoold"),
+    ("british", "british"),
+    ("knowin", "knowin"),
+    ("little", "littl"),
+    ("plug", "plug"),
+    ("rubs", "rub"),
+    ("distress", "distress"),
+    ("precipices", "precipic"),
+    ("mixer", "mixer"),
+    ("coldest", "coldest"),
+    ("discernible", "discern"),
+    ("committees", "committe"),
+    ("vagabonds", "vagabond"),
+    ("carboniferous", "carbonifer"),
+    ("deep", "deep"),
+    ("dove", "dove"),
+    ("shepherds", "shepherd"),
+    ("effectual", "effectu"),
+    ("sprinkled", "sprinkl"),
+    ("consecrated", "consecr"),
+    ("reclines", "reclin"),
+    ("yells", "yell"),
+    ("hooked", "hook"),
+    ("memorial", "memori"),
+    ("circumstantially", "circumstanti"),
+    ("entomol", "entomol"),
+    ("instanced", "instanc"),
+    ("narrows", "narrow"),
+    ("svidrigrailovs", "svidrigrailov"),
+    ("salting", "salt"),
+    ("when", "when"),
+    ("fodere", "foder"),
+    ("labelled", "label"),
+    ("schoolmate", "schoolmat"),
+    ("combination", "combinaz"),
+    ("steamer", "steamer"),
+    ("stentorian", "stentorian"),
+    ("arthur", "arthur"),
+    ("sparkled", "sparkl"),
+    ("raps", "rap"),
+    ("tobacco", "tobacco"),
+    ("bravado", "bravado"),
+    ("tropillas", "tropilla"),
+    ("marrows", "marrow"),
+    ("actuating", "actuat"),
+    ("impartiality", "imparti"),
+    ("believed", "believ"),
+    ("eats", "eat"),
+    ("direcfly", "direcfli"),
+    ("shadowless", "shadowless"),
+    ("butts", "butt"),
+    ("pervading", "pervad"),
+    ("certainty", "certainti"),
+    ("outlay", "outlai"),
+    ("animating", "anim"),
+    ("suffer", "suffer"),
+    ("smirched", "smirch"),
+    ("port", "port"),
+    ("shaded", "shade"),
+    ("erichson", "erichson"),
+    ("mending", "mend"),
+    ("charged", "charg"),
+    ("vogue", "vogu"),
+    ("inquired", "inquir"),
+    ("octavia", "octavia"),
+    ("sike", "sike"),
+    ("lies", "li"),
+    ("perpetuating", "perpetu"),
+    ("manliness", "manli"),
+    ("fender", "fender"),
+    ("perhaps", "perhap"),
+    ("military", "militari"),
+    ("irretrievably", "irretriev"),
+    ("unsay", "unsai"),
+    ("prejudice", "prejudic"),
+    ("digitatus", "digitatu"),
+    ("berg", "berg"),
+    ("detective", "detect"),
+    ("west", "west"),
+    ("summit", "summit"),
+    ("sevens", "seven"),
+    ("aptnesses", "apt"),
+    ("weak", "weak"),
+    ("acacia", "acacia"),
+    ("gnawers", "gnawer"),
+    ("verbenas", "verbena"),
+    ("passer", "passer"),
+    ("fixtures", "fixtur"),
+    ("existe", "exist"),
+    ("grounds", "ground"),
+    ("clamour", "clamour"),
+    ("stoppages", "stoppag"),
+    ("sex", "sex"),
+    ("promptly", "promptli"),
+    ("laburnum", "laburnum"),
+    ("clergymen", "clergymen"),
+    ("wisk", "wisk"),
+    ("accommodating", "accommod"),
+    ("mountebanks", "mountebank"),
+    ("enforced", "enforc"),
+    ("forthwith", "forthwith"),
+    ("silencing", "silenc"),
+    ("guiltless", "guiltless"),
+    ("trolling", "troll"),
+    ("mend", "mend"),
+    ("attacking", "attack"),
+    ("ayres", "ayr"),
+    ("summarily", "summarili"),
+    ("overhangs", "overhang"),
+    ("dazed", "daze"),
+    ("renounce", "renounc"),
+    ("unfortunately", "unfortun"),
+    ("preceded", "prece"),
+    ("cucumber", "cucumb"),
+    ("gullet", "gullet"),
+    ("medical", "medic"),
+    ("pains", "pain"),
+    ("olivasea", "olivasea"),
+    ("soight", "soight"),
+    ("bask", "bask"),
+    ("accent", "accent"),
+    ("agitating", "agit"),
+    ("viper", "viper"),
+    ("uncomfortably", "uncomfort"),
+    ("um", "um"),
+    ("who", "who"),
+    ("ordered", "order"),
+    ("confabulation", "confabulaz"),
+    ("falls", "fall"),
+    ("princess", "princess"),
+    ("colliers", "collier"),
+    ("length", "length"),
+    ("dilapidated", "dilapid"),
+    ("shy", "shi"),
+    ("prawn", "prawn"),
+    ("swims", "swim"),
+    ("shallowest", "shallowest"),
+    ("disfigurement", "disfigur"),
+    ("unloosened", "unloosen"),
+    ("undeceived", "undeceiv"),
+    ("carbury", "carburi"),
+    ("orbigny", "orbigni"),
+    ("wing", "wing"),
+    ("slices", "slice"),
+    ("describing", "describ"),
+    ("sayings", "sai"),
+    ("forwarding", "forward"),
+    ("objects", "object"),
+    ("overtaken", "overtaken"),
+    ("hollowness", "hollow"),
+    ("shoeless", "shoeless"),
+    ("gloated", "gloat"),
+    ("injection", "inject"),
+    ("source", "sourc"),
+    ("circulates", "circul"),
+    ("vampire", "vampir"),
+    ("upheaving", "upheav"),
+    ("aground", "aground"),
+    ("younger", "younger"),
+    ("bleached", "bleach"),
+    ("arithmeticians", "arithmetician"),
+    ("freely", "free"),
+    ("coral", "coral"),
+    ("pods", "pod"),
+    ("profitless", "profitless"),
+    ("oursel", "oursel"),
+    ("monopolist", "monopolist"),
+    ("inoffensive", "inoffens"),
+    ("alyona", "alyona"),
+    ("sown", "sown"),
+    ("derivation", "derivaz"),
+    ("americas", "america"),
+    ("haciendero", "haciendero"),
+    ("pretexts", "pretext"),
+    ("fitzgibbons", "fitzgibbon"),
+    ("eyelash", "eyelash"),
+    ("veniso", "veniso"),
+    ("extremely", "extrem"),
+    ("acquirements", "acquir"),
+    ("tilly", "tilli"),
+    ("scalesia", "scalesia"),
+    ("unfrequented", "unfrequ"),
+    ("outbreak", "outbreak"),
+    ("ing", "ing"),
+    ("greek", "greek"),
+    ("expression", "express"),
+    ("decomposed", "decompos"),
+    ("ports", "port"),
+    ("practical", "practic"),
+    ("mall", "mall"),
+    ("engagingness", "engaging"),
+    ("oddity", "odditi"),
+    ("mills", "mill"),
+    ("evenness", "even"),
+    ("shine", "shine"),
+    ("cup", "cup"),
+    ("burnet", "burnet"),
+    ("fleece", "fleec"),
+    ("convictions", "convict"),
+    ("elocution", "elocut"),
+    ("artistically", "artist"),
+    ("cucumbers", "cucumb"),
+    ("invigorating", "invigor"),
+    ("bennett", "bennett"),
+    ("throughout", "throughout"),
+    ("senses", "sens"),
+    ("steadied", "steadi"),
+    ("linnaean", "linnaean"),
+    ("superlativest", "superlativest"),
+    ("crack", "crack"),
+    ("handsome", "handsom"),
+    ("encyclop", "encyclop"),
+    ("enter", "enter"),
+    ("compassionate", "compassion"),
+    ("teased", "teas"),
+    ("equator", "equat"),
+    ("til", "til"),
+    ("muriate", "muriat"),
+    ("double", "doubl"),
+    ("inaugurated", "inaugur"),
+    ("astrolabe", "astrolab"),
+    ("giver", "giver"),
+    ("true", "true"),
+    ("wink", "wink"),
+    ("trappings", "trap"),
+    ("whipped", "whip"),
+    ("moderated", "moder"),
+    ("stuart", "stuart"),
+    ("interloper", "interlop"),
+    ("sitiwation", "sitiwaz"),
+    ("fastidious", "fastidi"),
+    ("courtships", "courtship"),
+    ("draws", "draw"),
+    ("fernal", "fernal"),
+    ("closes", "close"),
+    ("egregious", "egregi"),
+    ("symmetrically", "symmetr"),
+    ("awaiting", "await"),
+    ("retraces", "retrac"),
+    ("answer", "answer"),
+    ("corrupting", "corrupt"),
+    ("hive", "hive"),
+    ("motionless", "motionless"),
+    ("forewarned", "forewarn"),
+    ("unread", "unread"),
+    ("cantrips", "cantrip"),
+    ("sexty", "sexti"),
+    ("coquettish", "coquettish"),
+    ("merriment", "merriment"),
+    ("soars", "soar"),
+    ("consul", "consul"),
+    ("vive", "vive"),
+    ("marshes", "marsh"),
+    ("megatherium", "megatherium"),
+    ("contributors", "contributor"),
+    ("reticence", "retic"),
+    ("ash", "ash"),
+    ("tinderidica", "tinderidica"),
+    ("heartburns", "heartburn"),
+    ("efficacy", "efficaci"),
+    ("dotheboys", "dotheboi"),
+    ("whittington", "whittington"),
+    ("dismissing", "dismiss"),
+    ("cringing", "cringing"),
+    ("select", "select"),
+    ("squeeze", "squeez"),
+    ("underdone", "underdon"),
+    ("whig", "whig"),
+    ("squealed", "squeal"),
+    ("snout", "snout"),
+    ("squaring", "squar"),
+    ("swung", "swung"),
+    ("unfeminine", "unfeminin"),
+    ("disapproval", "disapprov"),
+    ("humanum", "humanum"),
+    ("employer", "employer"),
+    ("stage", "stage"),
+    ("cozened", "cozen"),
+    ("stockingless", "stockingless"),
+    ("province", "provinc"),
+    ("spilled", "spill"),
+    ("elector", "elector"),
+    ("tantalizing", "tantal"),
+    ("lie", "lie"),
+    ("hysteric", "hyster"),
+    ("snappish", "snappish"),
+    ("grandiloquently", "grandiloqu"),
+    ("skipped", "skip"),
+    ("macrocystis", "macrocysti"),
+    ("accountabilities", "account"),
+    ("supreme", "suprem"),
+    ("deceitfulness", "deceit"),
+    ("constrains", "constrain"),
+    ("noggs", "nogg"),
+    ("squander", "squander"),
+    ("carabidae", "carabida"),
+    ("crook", "crook"),
+    ("bitterly", "bitterli"),
+    ("realise", "realis"),
+    ("homeless", "homeless"),
+    ("drum", "drum"),
+    ("cough", "cough"),
+    ("abet", "abet"),
+    ("praised", "prais"),
+    ("nou", "nou"),
+    ("viands", "viand"),
+    ("casks", "cask"),
+    ("applauded", "applaud"),
+    ("tide", "tide"),
+    ("perforce", "perforc"),
+    ("whilst", "whilst"),
+    ("floured", "flour"),
+    ("fields", "field"),
+    ("sleeps", "sleep"),
+    ("crost", "crost"),
+    ("uneatable", "uneat"),
+    ("treasur", "treasur"),
+    ("stem", "stem"),
+    ("precipice", "precipic"),
+    ("pitiable", "pitiabl"),
+    ("admiring", "admir"),
+    ("throug", "throug"),
+    ("rounds", "round"),
+    ("blankets", "blanket"),
+    ("troops", "troop"),
+    ("hatchment", "hatchment"),
+    ("stag", "stag"),
+    ("snap", "snap"),
+    ("versts", "verst"),
+    ("criterion", "criterion"),
+    ("cloth", "cloth"),
+    ("ranchos", "rancho"),
+    ("quarther", "quarther"),
+    ("exercise", "exercis"),
+    ("waited", "wait"),
+    ("ungraciously", "ungraci"),
+    ("brier", "brier"),
+    ("fisted", "fist"),
+    ("transpire", "transpir"),
+    ("mediocrity", "mediocr"),
+    ("partook", "partook"),
+    ("beggary", "beggari"),
+    ("fou", "fou"),
+    ("cura", "cura"),
+    ("laced", "lace"),
+    ("tinochorus", "tinochoru"),
+    ("relieved", "reliev"),
+    ("garniture", "garnitur"),
+    ("happening", "happen"),
+    ("quenching", "quench"),
+    ("breastwork", "breastwork"),
+    ("antiquities", "antiqu"),
+    ("hills", "hill"),
+    ("crowed", "crow"),
+    ("impair", "impair"),
+    ("recover", "recov"),
+    ("apostolica", "apostolica"),
+    ("drunken", "drunken"),
+    ("forming", "form"),
+    ("dec", "dec"),
+    ("seekers", "seeker"),
+    ("accents", "accent"),
+    ("clutching", "clutch"),
+    ("boarder", "boarder"),
+    ("overcast", "overcast"),
+    ("ejaculates", "ejacul"),
+    ("abandons", "abandon"),
+    ("scoondrels", "scoondrel"),
+    ("coffin", "coffin"),
+    ("affectionate", "affection"),
+    ("smike", "smike"),
+    ("zaharovitch", "zaharovitch"),
+    ("attaining", "attain"),
+    ("horrible", "horribl"),
+    ("reassured", "reassur"),
+    ("statenland", "statenland"),
+    ("palaces", "palac"),
+    ("baron", "baron"),
+    ("wanton", "wanton"),
+    ("snatch", "snatch"),
+    ("openness", "open"),
+    ("towels", "towel"),
+    ("ease", "eas"),
+    ("seated", "seat"),
+    ("aspect", "aspect"),
+    ("romancing", "romanc"),
+    ("crumpling", "crumpl"),
+    ("towelling", "towel"),
+    ("guster", "guster"),
+    ("enacted", "enact"),
+    ("sugarscaps", "sugarscap"),
+    ("undescribed", "undescrib"),
+    ("establishing", "establish"),
+    ("mad", "mad"),
+    ("cleaning", "clean"),
+    ("huckster", "huckster"),
+    ("permanently", "perman"),
+    ("awa", "awa"),
+    ("limits", "limit"),
+    ("resinous", "resin"),
+    ("muddy", "muddi"),
+    ("saline", "salin"),
+    ("gone", "gone"),
+    ("miser", "miser"),
+    ("steed", "steed"),
+    ("trunks", "trunk"),
+    ("dryly", "dryli"),
+    ("inquiring", "inquir"),
+    ("saltpetre", "saltpetr"),
+    ("argillaceo", "argillaceo"),
+    ("charqui", "charqui"),
+    ("predicaments", "predica"),
+    ("wailed", "wail"),
+    ("wrought", "wrought"),
+    ("puttin", "puttin"),
+    ("aboot", "aboot"),
+    ("grievous", "grievou"),
+    ("league", "leagu"),
+    ("proportioned", "proport"),
+    ("nereidous", "nereid"),
+    ("mari", "mari"),
+    ("tunic", "tunic"),
+    ("calumniously", "calumni"),
+    ("uproar", "uproar"),
+    ("stings", "sting"),
+    ("school", "school"),
+    ("legibly", "legibli"),
+    ("succumbing", "succumb"),
+    ("vermiform", "vermiform"),
+    ("groping", "grope"),
+    ("earthenware", "earthenwar"),
+    ("dinnot", "dinnot"),
+    ("impecuniosity", "impecunios"),
+    ("wantonly", "wantonli"),
+    ("inflexam", "inflexam"),
+    ("upsets", "upset"),
+    ("mincing", "minc"),
+    ("clear", "clear"),
+    ("recollection", "recollect"),
+    ("practicality", "practic"),
+    ("glod", "glod"),
+    ("tuff", "tuff"),
+    ("storehouses", "storehous"),
+    ("forging", "forg"),
+    ("appreciated", "appreci"),
+    ("scaffolds", "scaffold"),
+    ("fash", "fash"),
+    ("angula", "angula"),
+    ("girlish", "girlish"),
+    ("dont", "dont"),
+    ("compound", "compound"),
+    ("compensated", "compens"),
+    ("windy", "windi"),
+    ("facilitates", "facilit"),
+    ("aloft", "aloft"),
+    ("scimitar", "scimitar"),
+    ("giuseppe", "giusepp"),
+    ("hanging", "hang"),
+    ("explains", "explain"),
+    ("third", "third"),
+    ("ekaterininsky", "ekaterininski"),
+    ("triangle", "triangl"),
+    ("boughs", "bough"),
+    ("lucid", "lucid"),
+    ("cage", "cage"),
+    ("cushion", "cushion"),
+    ("hound", "hound"),
+    ("illuminate", "illumin"),
+    ("prop", "prop"),
+    ("galled", "gall"),
+    ("defile", "defil"),
+    ("imbued", "imbu"),
+    ("takkin", "takkin"),
+    ("concur", "concur"),
+    ("anticipation", "anticipaz"),
+    ("lucifer", "lucif"),
+    ("unlicensed", "unlicens"),
+    ("magdalen", "magdalen"),
+    ("tufted", "tuft"),
+    ("discontinue", "discontinu"),
+    ("termination", "terminaz"),
+    ("practise", "practis"),
+    ("xii", "xii"),
+    ("gust", "gust"),
+    ("regions", "region"),
+    ("ivory", "ivori"),
+    ("resignation", "resignaz"),
+    ("pensive", "pensiv"),
+    ("infirmity", "infirm"),
+    ("dourov", "dourov"),
+    ("blankly", "blankli"),
+    ("breaker", "breaker"),
+    ("fixed", "fix"),
+    ("soothed", "sooth"),
+    ("sympathise", "sympathis"),
+    ("emphasis", "emphasi"),
+    ("assignment", "assign"),
+    ("exalted", "exalt"),
+    ("elscholchias", "elscholchia"),
+    ("earthquake", "earthquak"),
+    ("subsequent", "subsequ"),
+    ("carcass", "carcass"),
+    ("footsteps", "footstep"),
+    ("comforted", "comfort"),
+    ("degenerated", "degener"),
+    ("pang", "pang"),
+    ("spouts", "spout"),
+    ("sitters", "sitter"),
+    ("foind", "foind"),
+    ("approving", "approv"),
+    ("slyness", "sly"),
+    ("hallooing", "halloo"),
+    ("hollowed", "hollow"),
+    ("stating", "state"),
+    ("propose", "propos"),
+    ("originatinin", "originatinin"),
+    ("futur", "futur"),
+    ("fulgurites", "fulgurit"),
+    ("vermilion", "vermilion"),
+    ("espagne", "espagn"),
+    ("darts", "dart"),
+    ("clustered", "cluster"),
+    ("dismounted", "dismount"),
+    ("raining", "rain"),
+    ("humours", "humour"),
+    ("asking", "ask"),
+    ("rippling", "rippl"),
+    ("chance", "chanc"),
+    ("jeanie", "jeani"),
+    ("insolent", "insol"),
+    ("festivals", "festiv"),
+    ("waters", "water"),
+    ("encumbered", "encumb"),
+    ("poised", "pois"),
+    ("righteousness", "righteous"),
+    ("garran", "garran"),
+    ("inkstand", "inkstand"),
+    ("moralizes", "moral"),
+    ("sized", "size"),
+    ("darwin", "darwin"),
+    ("calamity", "calam"),
+    ("publish", "publish"),
+    ("ouse", "ous"),
+    ("sweets", "sweet"),
+    ("uneducated", "uneduc"),
+    ("sunrise", "sunris"),
+    ("echoed", "echo"),
+    ("fright", "fright"),
+    ("confused", "confus"),
+    ("adjective", "adject"),
+    ("discover", "discov"),
+    ("thronging", "throng"),
+    ("helpmate", "helpmat"),
+    ("amounting", "amount"),
+    ("hoarser", "hoarser"),
+    ("reiteration", "reiteraz"),
+    ("saddler", "saddler"),
+    ("topsail", "topsail"),
+    ("conciliatory", "conciliatori"),
+    ("lunnun", "lunnun"),
+    ("outspread", "outspread"),
+    ("manchester", "manchest"),
+    ("bairns", "bairn"),
+    ("heather", "heather"),
+    ("stunned", "stun"),
+    ("plainer", "plainer"),
+    ("munificent", "munific"),
+    ("pretension", "pretens"),
+    ("dismissed", "dismiss"),
+    ("tends", "tend"),
+    ("timidly", "timidli"),
+    ("charlotte", "charlott"),
+    ("mantalini", "mantalini"),
+    ("trepidation", "trepidaz"),
+    ("georges", "georg"),
+    ("repines", "repin"),
+    ("bribing", "bribe"),
+    ("president", "presid"),
+    ("eluded", "elud"),
+    ("surmount", "surmount"),
+    ("farce", "farc"),
+    ("mov", "mov"),
+    ("inmates", "inmat"),
+    ("seminarists", "seminarist"),
+    ("teel", "teel"),
+    ("milliner", "millin"),
+    ("mahomedan", "mahomedan"),
+    ("jumped", "jump"),
+    ("joked", "joke"),
+    ("fanned", "fan"),
+    ("south", "south"),
+    ("dawlish", "dawlish"),
+    ("baptizing", "baptiz"),
+    ("reviewing", "review"),
+    ("unharnessing", "unhar"),
+    ("theayter", "theayter"),
+    ("safe", "safe"),
+    ("suitably", "suitabl"),
+    ("absence", "absenc"),
+    ("formalities", "formal"),
+    ("safest", "safest"),
+    ("poplars", "poplar"),
+    ("load", "load"),
+    ("sniffed", "snif"),
+    ("readily", "readili"),
+    ("cowardly", "cowardli"),
+    ("adimonia", "adimonia"),
+    ("resurrection", "resurrect"),
+    ("sour", "sour"),
+    ("intrepid", "intrepid"),
+    ("alfred", "alfr"),
+    ("inundated", "inund"),
+    ("larvae", "larva"),
+    ("carlos", "carlo"),
+    ("hippopotamus", "hippopotamu"),
+    ("actress", "actress"),
+    ("effulgence", "effulg"),
+    ("violoncellos", "violoncello"),
+    ("bloom", "bloom"),
+    ("whispers", "whisper"),
+    ("bought", "bought"),
+    ("dart", "dart"),
+    ("whatsername", "whatsernam"),
+    ("industriously", "industri"),
+    ("tapirs", "tapir"),
+    ("contemned", "contemn"),
+    ("overwhelmed", "overwhelm"),
+    ("rehearsal", "rehears"),
+    ("aspired", "aspir"),
+    ("curries", "curri"),
+    ("unloving", "unlov"),
+    ("haughty", "haughti"),
+    ("spout", "spout"),
+    ("adverse", "advers"),
+    ("disowned", "disown"),
+    ("lawrence", "lawrenc"),
+    ("perturbed", "perturb"),
+    ("benguela", "benguela"),
+    ("tambour", "tambour"),
+    ("frugal", "frugal"),
+    ("calumniated", "calumni"),
+    ("organically", "organ"),
+    ("subside", "subsid"),
+    ("sunshiny", "sunshini"),
+    ("ilya", "ilya"),
+    ("xli", "xli"),
+    ("chancellors", "chancellor"),
+    ("door", "door"),
+    ("discovers", "discov"),
+    ("compared", "compar"),
+    ("counterfeit", "counterfeit"),
+    ("corpulent", "corpul"),
+    ("readil", "readil"),
+    ("minutely", "minut"),
+    ("humbled", "humbl"),
+    ("holding", "hold"),
+    ("sobre", "sobr"),
+    ("tribe", "tribe"),
+    ("bred", "bred"),
+    ("shortest", "shortest"),
+    ("broadsword", "broadsword"),
+    ("gentleman", "gentleman"),
+    ("prettinesses", "pretti"),
+    ("repeatually", "repeatu"),
+    ("conurus", "conuru"),
+    ("wunst", "wunst"),
+    ("outdie", "outdi"),
+    ("pteroptochos", "pteroptocho"),
+    ("undeserved", "undeserv"),
+    ("oblong", "oblong"),
+    ("joining", "join"),
+    ("rumour", "rumour"),
+    ("ambling", "ambl"),
+    ("robinson", "robinson"),
+    ("wagging", "wag"),
+    ("wean", "wean"),
+    ("marked", "mark"),
+    ("hopped", "hop"),
+    ("topping", "top"),
+    ("marys", "mari"),
+    ("noddies", "noddi"),
+    ("illiterate", "illiter"),
+    ("purchase", "purchas"),
+    ("meandering", "meander"),
+    ("terribly", "terribli"),
+    ("carping", "carp"),
+    ("effectiveness", "effect"),
+    ("injustice", "injustic"),
+    ("nobleman", "nobleman"),
+    ("navigators", "navig"),
+    ("touchstone", "touchston"),
+    ("melodramatic", "melodramat"),
+    ("nymphs", "nymph"),
+    ("sirrah", "sirrah"),
+    ("extinct", "extinct"),
+    ("balls", "ball"),
+    ("audubon", "audubon"),
+    ("gaming", "game"),
+    ("haughtinesses", "haughti"),
+    ("conventionally", "convention"),
+    ("soiree", "soire"),
+    ("blotting", "blot"),
+    ("reduces", "reduc"),
+    ("impostors", "impostor"),
+    ("gesture", "gestur"),
+    ("rags", "rag"),
+    ("estacado", "estacado"),
+    ("highly", "highli"),
+    ("expostulating", "expostul"),
+    ("include", "includ"),
+    ("gaspingly", "gaspingli"),
+    ("intellect", "intellect"),
+    ("denouncing", "denounc"),
+    ("unpunished", "unpunish"),
+    ("stimulus", "stimulu"),
+    ("adhering", "adher"),
+    ("taper", "taper"),
+    ("suffice", "suffic"),
+    ("yerba", "yerba"),
+    ("unopenable", "unopen"),
+    ("magog", "magog"),
+    ("eggshells", "eggshel"),
+    ("detaining", "detain"),
+    ("twirls", "twirl"),
+    ("dusty", "dusti"),
+    ("aggravated", "aggrav"),
+    ("scalding", "scald"),
+    ("dross", "dross"),
+    ("promote", "promot"),
+    ("bun", "bun"),
+    ("breasted", "breast"),
+    ("coleoptera", "coleoptera"),
+    ("incisions", "incis"),
+    ("bridge", "bridg"),
+    ("pranks", "prank"),
+    ("awfullest", "awfullest"),
+    ("unconfined", "unconfin"),
+    ("numb", "numb"),
+    ("herb", "herb"),
+    ("entomological", "entomolog"),
+    ("cartel", "cartel"),
+    ("interposition", "interposit"),
+    ("adviser", "advis"),
+    ("coachmaker", "coachmak"),
+    ("bursting", "burst"),
+    ("repulse", "repuls"),
+    ("chimborazo", "chimborazo"),
+    ("moothers", "moother"),
+    ("bellows", "bellow"),
+    ("riot", "riot"),
+    ("gogol", "gogol"),
+    ("mirrored", "mirror"),
+    ("resources", "resourc"),
+    ("z", "z"),
+    ("spindle", "spindl"),
+    ("perforated", "perfor"),
+    ("drunk", "drunk"),
+    ("langsdorff", "langsdorff"),
+    ("ancients", "ancient"),
+    ("sensualist", "sensualist"),
+    ("conspiracy", "conspiraci"),
+    ("extension", "extens"),
+    ("reducing", "reduc"),
+    ("remember", "rememb"),
+    ("limes", "lime"),
+    ("monoceros", "monocero"),
+    ("unimpeached", "unimpeach"),
+    ("rallying", "ral"),
+    ("unfounded", "unfound"),
+    ("tenths", "tenth"),
+    ("dressmakers", "dressmak"),
+    ("disengaged", "disengag"),
+    ("face", "face"),
+    ("courtship", "courtship"),
+    ("appalling", "appal"),
+    ("gigantea", "gigantea"),
+    ("dealing", "deal"),
+    ("patron", "patron"),
+    ("sovereignest", "sovereignest"),
+    ("xxvi", "xxvi"),
+    ("innuendo", "innuendo"),
+    ("cash", "cash"),
+    ("sheriff", "sheriff"),
+    ("speed", "speed"),
+    ("uncoils", "uncoil"),
+    ("prosecutor", "prosecutor"),
+    ("bordered", "border"),
+    ("vixen", "vixen"),
+    ("ferdinand", "ferdinand"),
+    ("outline", "outlin"),
+    ("gladly", "gladli"),
+    ("astronomy", "astronomi"),
+    ("tapers", "taper"),
+    ("possesses", "possess"),
+    ("smartened", "smarten"),
+    ("kitchens", "kitchen"),
+    ("bedside", "bedsid"),
+    ("begun", "begun"),
+    ("enormity", "enorm"),
+    ("mandetiba", "mandetiba"),
+    ("tempts", "tempt"),
+    ("tatters", "tatter"),
+    ("nowheres", "nowher"),
+    ("professionals", "profession"),
+    ("trochi", "trochi"),
+    ("opuntias", "opuntia"),
+    ("reassure", "reassur"),
+    ("dovetailedness", "dovetailed"),
+    ("chuckling", "chuckl"),
+    ("hold", "hold"),
+    ("feudal", "feudal"),
+    ("boyish", "boyish"),
+    ("tierra", "tierra"),
+    ("stake", "stake"),
+    ("beckon", "beckon"),
+    ("tyerman", "tyerman"),
+    ("squeal", "squeal"),
+    ("instruction", "instruct"),
+    ("hits", "hit"),
+    ("slab", "slab"),
+    ("simmering", "simmer"),
+    ("impervious", "impervi"),
+    ("milestones", "mileston"),
+    ("isles", "isl"),
+    ("disgorged", "disgorg"),
+    ("ceremony", "ceremoni"),
+    ("inflates", "inflat"),
+    ("effloresce", "effloresc"),
+    ("stumbling", "stumbl"),
+    ("spasm", "spasm"),
+    ("protect", "protect"),
+    ("shocking", "shock"),
+    ("brake", "brake"),
+    ("reaction", "reaction"),
+    ("regretful", "regret"),
+    ("resultant", "result"),
+    ("albiceps", "albicep"),
+    ("charred", "char"),
+    ("staunchly", "staunchli"),
+    ("dallied", "dal"),
+    ("cylinder", "cylind"),
+    ("garnett", "garnett"),
+    ("demoniacs", "demoniac"),
+    ("demented", "dement"),
+    ("toothful", "tooth"),
+    ("wills", "will"),
+    ("dew", "dew"),
+    ("rawest", "rawest"),
+    ("lloyd", "lloyd"),
+    ("an", "an"),
+    ("criers", "crier"),
+    ("hoor", "hoor"),
+    ("expostulation", "expostulaz"),
+    ("tape", "tape"),
+    ("dissembler", "dissembl"),
+    ("stoutish", "stoutish"),
+    ("portmanteau", "portmanteau"),
+    ("shuffles", "shuffl"),
+    ("smashing", "smash"),
+    ("untying", "unti"),
+    ("allusion", "allus"),
+    ("challenge", "challeng"),
+    ("feury", "feuri"),
+    ("eimeo", "eimeo"),
+    ("imperative", "imper"),
+    ("quillota", "quillota"),
+    ("padlock", "padlock"),
+    ("toucher", "toucher"),
+    ("conservatories", "conservatori"),
+    ("casting", "cast"),
+    ("willinwodd", "willinwodd"),
+    ("dewy", "dewi"),
+    ("attaches", "attach"),
+    ("acquiesce", "acquiesc"),
+    ("calumnies", "calumni"),
+    ("rollicking", "rollick"),
+    ("uncut", "uncut"),
+    ("ravaged", "ravag"),
+    ("phosphorus", "phosphoru"),
+    ("unalloyed", "unalloi"),
+    ("albury", "alburi"),
+    ("enlistment", "enlist"),
+    ("passee", "passe"),
+    ("furrows", "furrow"),
+    ("grund", "grund"),
+    ("vents", "vent"),
+    ("conqueror", "conqueror"),
+    ("explore", "explor"),
+    ("fraternity", "fratern"),
+    ("frantsovnas", "frantsovna"),
+    ("woollya", "woollya"),
+    ("baying", "bai"),
+    ("plies", "pli"),
+    ("boldly", "boldli"),
+    ("pertinacious", "pertinaci"),
+    ("vasya", "vasya"),
+    ("tore", "tore"),
+    ("seeing", "see"),
+    ("overtask", "overtask"),
+    ("influence", "influenc"),
+    ("petting", "pet"),
+    ("screening", "screen"),
+    ("dye", "dye"),
+    ("dramatise", "dramatis"),
+    ("decisions", "decis"),
+    ("counterpane", "counterpan"),
+    ("kindhearted", "kindheart"),
+    ("perennially", "perenni"),
+    ("entreaties", "entreati"),
+    ("ware", "ware"),
+    ("baleful", "bale"),
+    ("medal", "medal"),
+    ("filched", "filch"),
+    ("hammering", "hammer"),
+    ("speckelation", "speckelaz"),
+    ("goaded", "goad"),
+    ("pout", "pout"),
+    ("performed", "perform"),
+    ("indies", "indi"),
+    ("racking", "rack"),
+    ("ridicule", "ridicul"),
+    ("rickety", "ricketi"),
+    ("wading", "wade"),
+    ("thre", "thre"),
+    ("earthquakes", "earthquak"),
+    ("tickle", "tickl"),
+    ("surf", "surf"),
+    ("higher", "higher"),
+    ("typical", "typic"),
+    ("chasm", "chasm"),
+    ("swoln", "swoln"),
+    ("gorge", "gorg"),
+    ("straightway", "straightwai"),
+    ("cleverly", "cleverli"),
+    ("sein", "sein"),
+    ("negotiation", "negotiaz"),
+    ("thumped", "thump"),
+    ("awry", "awri"),
+    ("fragmentary", "fragmentari"),
+    ("toomultuous", "toomultu"),
+    ("facings", "face"),
+    ("convulsively", "convuls"),
+    ("expedients", "expedi"),
+    ("saisis", "saisi"),
+    ("wittily", "wittili"),
+    ("pallisers", "pallis"),
+    ("skimming", "skim"),
+    ("levers", "lever"),
+    ("carpacho", "carpacho"),
+    ("astute", "astut"),
+    ("clumsy", "clumsi"),
+    ("confiding", "confid"),
+    ("matrix", "matrix"),
+    ("requiring", "requir"),
+    ("harn", "harn"),
+    ("pitching", "pitch"),
+    ("professorship", "professorship"),
+    ("gained", "gain"),
+    ("darting", "dart"),
+    ("pickles", "pickl"),
+    ("trump", "trump"),
+    ("slight", "slight"),
+    ("fullness", "ful"),
+    ("beckoned", "beckon"),
+    ("title", "titl"),
+    ("inaudible", "inaud"),
+    ("detain", "detain"),
+    ("quelled", "quell"),
+    ("eels", "eel"),
+    ("exasperated", "exasper"),
+    ("welveteen", "welveteen"),
+    ("respective", "respect"),
+    ("jamaica", "jamaica"),
+    ("seizing", "seiz"),
+    ("arriving", "arriv"),
+    ("suppress", "suppress"),
+    ("ensues", "ensu"),
+    ("publicly", "publicli"),
+    ("scholars", "scholar"),
+    ("wingfield", "wingfield"),
+    ("cordillera", "cordillera"),
+    ("demersa", "demersa"),
+    ("humid", "humid"),
+    ("lucre", "lucr"),
+    ("vane", "vane"),
+    ("effeminate", "effemin"),
+    ("recovers", "recov"),
+    ("publications", "publicaz"),
+    ("filigree", "filigre"),
+    ("warder", "warder"),
+    ("lashing", "lash"),
+    ("hangs", "hang"),
+    ("unblushing", "unblush"),
+    ("traversed", "travers"),
+    ("whartons", "wharton"),
+    ("than", "than"),
+    ("vowed", "vow"),
+    ("stranger", "stranger"),
+    ("wearied", "weari"),
+    ("taking", "take"),
+    ("plaintively", "plaintiv"),
+    ("duncan", "duncan"),
+    ("concealing", "conceal"),
+    ("cloudily", "cloudili"),
+    ("borough", "borough"),
+    ("diary", "diari"),
+    ("rustling", "rustl"),
+    ("delinquency", "delinqu"),
+    ("inefficient", "ineffici"),
+    ("playful", "play"),
+    ("beneath", "beneath"),
+    ("chested", "chest"),
+    ("barnet", "barnet"),
+    ("christabel", "christabel"),
+    ("bustling", "bustl"),
+    ("pushing", "push"),
+    ("manslaughtering", "manslaught"),
+    ("snorting", "snort"),
+    ("swiftness", "swift"),
+    ("betrays", "betrai"),
+    ("sooner", "sooner"),
+    ("fawns", "fawn"),
+    ("diminutive", "diminut"),
+    ("wren", "wren"),
+    ("unequal", "unequ"),
+    ("contemplations", "contemplaz"),
+    ("flinging", "flinging"),
+    ("substituting", "substitut"),
+    ("bald", "bald"),
+    ("semyonovna", "semyonovna"),
+    ("lumber", "lumber"),
+    ("lustrous", "lustrou"),
+    ("unequalled", "unequal"),
+    ("tryst", "tryst"),
+    ("loosely", "loos"),
+    ("giggle", "giggl"),
+    ("abject", "abject"),
+    ("unmanned", "unman"),
+    ("riddle", "riddl"),
+    ("poisonous", "poison"),
+    ("getting", "get"),
+    ("accede", "acced"),
+    ("unrelieved", "unreliev"),
+    ("tenement", "tenement"),
+    ("unaided", "unaid"),
+    ("labillardiere", "labillardier"),
+    ("fabric", "fabric"),
+    ("frighten", "frighten"),
+    ("saffron", "saffron"),
+    ("krook", "krook"),
+    ("balance", "balanc"),
+    ("aesop", "aesop"),
+    ("rib", "rib"),
+    ("abrogated", "abrog"),
+    ("sauce", "sauc"),
+    ("turnip", "turnip"),
+    ("shot", "shot"),
+    ("guardhouse", "guardhous"),
+    ("loggerheaded", "loggerhead"),
+    ("farrago", "farrago"),
+    ("frivolities", "frivol"),
+    ("silliman", "silliman"),
+    ("percolating", "percol"),
+    ("fuentes", "fuent"),
+    ("membranous", "membran"),
+    ("aye", "aye"),
+    ("matching", "match"),
+    ("perpendicular", "perpendicular"),
+    ("marvellous", "marvel"),
+    ("ermine", "ermin"),
+    ("resounded", "resound"),
+    ("sternest", "sternest"),
+    ("grottoes", "grotto"),
+    ("portended", "portend"),
+    ("capitulate", "capitul"),
+    ("accompaniments", "accompani"),
+    ("bankrupts", "bankrupt"),
+    ("disguise", "disguis"),
+    ("bailiff", "bailiff"),
+    ("attribute", "attribut"),
+    ("ceased", "ceas"),
+    ("presidency", "presid"),
+    ("stand", "stand"),
+    ("cladonia", "cladonia"),
+    ("risking", "risk"),
+    ("brewing", "brew"),
+    ("out", "out"),
+    ("red", "red"),
+    ("of", "of"),
+    ("amancaes", "amanca"),
+    ("quickness", "quick"),
+    ("belligerents", "belliger"),
+    ("determination", "determinaz"),
+    ("doorway", "doorwai"),
+    ("proofs", "proof"),
+    ("filtering", "filter"),
+    ("flexibility", "flexibl"),
+    ("softeners", "soften"),
+    ("preciousest", "preciousest"),
+    ("ponchos", "poncho"),
+    ("fatherless", "fatherless"),
+    ("christ", "christ"),
+    ("glitter", "glitter"),
+    ("heater", "heater"),
+    ("pitch", "pitch"),
+    ("admonished", "admonish"),
+    ("insular", "insular"),
+    ("gropes", "grope"),
+    ("stricken", "stricken"),
+    ("uprose", "upros"),
+    ("malefactors", "malefactor"),
+    ("suite", "suit"),
+    ("carvings", "carv"),
+    ("heavens", "heaven"),
+    ("varies", "vari"),
+    ("april", "april"),
+    ("abimee", "abime"),
+    ("bourgeois", "bourgeoi"),
+    ("rouncewells", "rouncewel"),
+    ("denounce", "denounc"),
+    ("repaid", "repaid"),
+    ("lasting", "last"),
+    ("countries", "countri"),
+    ("fleecy", "fleeci"),
+    ("provisional", "provision"),
+    ("dirty", "dirti"),
+    ("martindales", "martindal"),
+    ("allotted", "allot"),
+    ("fungus", "fungu"),
+    ("prescriptive", "prescript"),
+    ("snobb", "snobb"),
+    ("agate", "agat"),
+    ("raging", "rage"),
+    ("sullen", "sullen"),
+    ("crew", "crew"),
+    ("repetition", "repetit"),
+    ("sang", "sang"),
+    ("krestovsky", "krestovski"),
+    ("silurian", "silurian"),
+    ("cucao", "cucao"),
+    ("childishly", "childishli"),
+    ("packed", "pack"),
+    ("stealings", "steal"),
+    ("fell", "fell"),
+    ("kapernaumov", "kapernaumov"),
+    ("mocking", "mock"),
+    ("gracious", "graciou"),
+    ("grass", "grass"),
+    ("guaranteed", "guarante"),
+    ("mourns", "mourn"),
+    ("release", "releas"),
+    ("legal", "legal"),
+    ("insistes", "insist"),
+    ("predicting", "predict"),
+    ("davis", "davi"),
+    ("hysterically", "hyster"),
+    ("pluming", "plume"),
+    ("cornwall", "cornwal"),
+    ("kisses", "kiss"),
+    ("traditions", "tradit"),
+    ("upholstery", "upholsteri"),
+    ("hash", "hash"),
+    ("urchin", "urchin"),
+    ("asiatic", "asiat"),
+    ("fond", "fond"),
+    ("situ", "situ"),
+    ("ejaculate", "ejacul"),
+    ("quietly", "quietli"),
+    ("creed", "creed"),
+    ("decipher", "deciph"),
+    ("sympathetic", "sympathet"),
+    ("hiring", "hire"),
+    ("conciliations", "conciliaz"),
+    ("verulam", "verulam"),
+    ("wrappers", "wrapper"),
+    ("accumulate", "accumul"),
+    ("quailed", "quail"),
+    ("flaps", "flap"),
+    ("lice", "lice"),
+    ("sprite", "sprite"),
+    ("frogs", "frog"),
+    ("buffon", "buffon"),
+    ("thought", "thought"),
+    ("mouthfuls", "mouth"),
+    ("incorruptible", "incorrupt"),
+    ("untiring", "untir"),
+    ("perdition", "perdit"),
+    ("parapets", "parapet"),
+    ("sago", "sago"),
+    ("declared", "declar"),
+    ("secreting", "secret"),
+    ("unasked", "unask"),
+    ("cups", "cup"),
+    ("indiscriminately", "indiscrimin"),
+    ("salutations", "salutaz"),
+    ("career", "career"),
+    ("throve", "throve"),
+    ("excluded", "exclud"),
+    ("offered", "offer"),
+    ("romping", "romp"),
+    ("studiously", "studious"),
+    ("forbidden", "forbidden"),
+    ("motions", "motion"),
+    ("emboldens", "embolden"),
+    ("solicitors", "solicitor"),
+    ("revenge", "reveng"),
+    ("chocolate", "chocol"),
+    ("eightpence", "eightpenc"),
+    ("haunt", "haunt"),
+    ("rozario", "rozario"),
+    ("exerted", "exert"),
+    ("viola", "viola"),
+    ("branc", "branc"),
+    ("pelt", "pelt"),
+    ("dishonestly", "dishonestli"),
+    ("percent", "percent"),
+    ("intertwined", "intertwin"),
+    ("slits", "slit"),
+    ("enslaver", "enslav"),
+    ("activity", "activ"),
+    ("acrimoniously", "acrimoni"),
+    ("blighting", "blight"),
+    ("logs", "log"),
+    ("rhinoceroses", "rhinoceros"),
+    ("retirements", "retir"),
+    ("hose", "hose"),
+    ("puffinus", "puffinu"),
+    ("buena", "buena"),
+    ("vacas", "vaca"),
+    ("undisguised", "undisguis"),
+    ("shaft", "shaft"),
+    ("remnants", "remnant"),
+    ("chronometrical", "chronometr"),
+    ("ni", "ni"),
+    ("barristers", "barrist"),
+    ("growling", "growl"),
+    ("pope", "pope"),
+    ("rails", "rail"),
+    ("swart", "swart"),
+    ("generate", "gener"),
+    ("sleepers", "sleeper"),
+    ("drags", "drag"),
+    ("humidity", "humid"),
+    ("winked", "wink"),
+    ("goodbye", "goodby"),
+    ("alluvial", "alluvi"),
+    ("pockets", "pocket"),
+    ("leads", "lead"),
+    ("inefficiency", "ineffici"),
+    ("accomplices", "accomplic"),
+    ("exclaimed", "exclaim"),
+    ("honourable", "honour"),
+    ("caracaras", "caracara"),
+    ("overcoat", "overcoat"),
+    ("pawned", "pawn"),
+    ("past", "past"),
+    ("decorated", "decor"),
+    ("rattlers", "rattler"),
+    ("chaquaio", "chaquaio"),
+    ("insertion", "insert"),
+    ("postpones", "postpon"),
+    ("refinement", "refin"),
+    ("stoutest", "stoutest"),
+    ("esteemed", "esteem"),
+    ("confers", "confer"),
+    ("propensity", "propens"),
+    ("insignificant", "insignific"),
+    ("abstracts", "abstract"),
+    ("bolivia", "bolivia"),
+    ("laws", "law"),
+    ("inkwhich", "inkwhich"),
+    ("hinges", "hing"),
+    ("insufficient", "insuffici"),
+    ("mingling", "mingl"),
+    ("interrupting", "interrupt"),
+    ("thoroughgoing", "thoroughgo"),
+    ("guffy", "guffi"),
+    ("garth", "garth"),
+    ("arches", "arch"),
+    ("pentonville", "pentonvill"),
+    ("grievance", "grievanc"),
+    ("identifles", "identifl"),
+    ("careers", "career"),
+    ("respectability", "respect"),
+    ("inquiringly", "inquiringli"),
+    ("besieged", "besieg"),
+    ("thumping", "thump"),
+    ("punishing", "punish"),
+    ("relatives", "rel"),
+    ("skimmed", "skim"),
+    ("confessions", "confess"),
+    ("organic", "organ"),
+    ("innocuous", "innocu"),
+    ("agreeable", "agreeabl"),
+    ("cistern", "cistern"),
+    ("pock", "pock"),
+    ("barons", "baron"),
+    ("mammy", "mammi"),
+    ("mirrors", "mirror"),
+    ("wiss", "wiss"),
+    ("process", "process"),
+    ("farthest", "farthest"),
+    ("abominated", "abomin"),
+    ("condescend", "condescend"),
+    ("tally", "tal"),
+    ("transparent", "transpar"),
+    ("gripping", "grip"),
+    ("meddlesome", "meddlesom"),
+    ("nefarious", "nefari"),
+    ("ardent", "ardent"),
+    ("drury", "druri"),
+    ("ly", "li"),
+    ("downcast", "downcast"),
+    ("nourishment", "nourish"),
+    ("medicines", "medicin"),
+    ("titian", "titian"),
+    ("rampant", "rampant"),
+    ("pillars", "pillar"),
+    ("demnebly", "demnebli"),
+    ("fished", "fish"),
+    ("issue", "issu"),
+    ("infested", "infest"),
+    ("wrinkle", "wrinkl"),
+    ("poppet", "poppet"),
+    ("undefined", "undefin"),
+    ("obeyeth", "obeyeth"),
+    ("rap", "rap"),
+    ("uncontrollable", "uncontrol"),
+    ("misconstructions", "misconstruct"),
+    ("contrasts", "contrast"),
+    ("pinched", "pinch"),
+    ("pitied", "piti"),
+    ("merid", "merid"),
+    ("enunciation", "enunciaz"),
+    ("overdriven", "overdriven"),
+    ("feigned", "feign"),
+    ("invaded", "invad"),
+    ("allay", "allai"),
+    ("improvements", "improv"),
+    ("obscenity", "obscen"),
+    ("maclaren", "maclaren"),
+    ("rafters", "rafter"),
+    ("frog", "frog"),
+    ("unlovely", "unlov"),
+    ("observable", "observ"),
+    ("loov", "loov"),
+    ("clasp", "clasp"),
+    ("faneant", "faneant"),
+    ("veracity", "verac"),
+    ("generated", "gener"),
+    ("sits", "sit"),
+    ("behests", "behest"),
+    ("musquitoes", "musquito"),
+    ("unison", "unison"),
+    ("defend", "defend"),
+    ("cynara", "cynara"),
+    ("elevation", "elevaz"),
+    ("unhappier", "unhappi"),
+    ("bleakness", "bleak"),
+    ("dragged", "drag"),
+    ("hannah", "hannah"),
+    ("relief", "relief"),
+    ("pouce", "pouc"),
+    ("campaign", "campaign"),
+    ("lank", "lank"),
+    ("steeled", "steel"),
+    ("essex", "essex"),
+    ("pave", "pave"),
+    ("nervures", "nervur"),
+    ("abstaining", "abstain"),
+    ("justify", "justifi"),
+    ("leben", "leben"),
+    ("sups", "sup"),
+    ("juvenile", "juvenil"),
+    ("mincingly", "mincingli"),
+    ("listening", "listen"),
+    ("caressingly", "caressingli"),
+    ("assuming", "assum"),
+    ("swings", "swing"),
+    ("reason", "reason"),
+    ("chapel", "chapel"),
+    ("dost", "dost"),
+    ("seriousness", "serious"),
+    ("duodecimos", "duodecimo"),
+    ("pointedly", "pointedli"),
+    ("sandy", "sandi"),
+    ("ransacking", "ransack"),
+    ("theme", "theme"),
+    ("cottoned", "cotton"),
+    ("peg", "peg"),
+    ("psychologically", "psycholog"),
+    ("corned", "corn"),
+    ("haycock", "haycock"),
+    ("declaratory", "declaratori"),
+    ("shelly", "shelli"),
+    ("anstice", "anstic"),
+    ("coats", "coat"),
+    ("seaward", "seaward"),
+    ("anadeers", "anad"),
+    ("refolds", "refold"),
+    ("conversing", "convers"),
+    ("newton", "newton"),
+    ("hardest", "hardest"),
+    ("tugging", "tug"),
+    ("rebellyon", "rebellyon"),
+    ("indecency", "indec"),
+    ("polar", "polar"),
+    ("cryptogamic", "cryptogam"),
+    ("librarian", "librarian"),
+    ("stationery", "stationeri"),
+    ("finance", "financ"),
+    ("unsettles", "unsettl"),
+    ("peffer", "peffer"),
+    ("impediment", "impedi"),
+    ("extensive", "extens"),
+    ("vanity", "vaniti"),
+    ("sellers", "seller"),
+    ("radicalism", "radic"),
+    ("profit", "profit"),
+    ("opportunities", "opportun"),
+    ("masterly", "masterli"),
+    ("taxation", "taxaz"),
+    ("established", "establish"),
+    ("zigzags", "zigzag"),
+    ("wafered", "wafer"),
+    ("attempts", "attempt"),
+    ("loiter", "loiter"),
+    ("boisterously", "boister"),
+    ("cutler", "cutler"),
+    ("exclusion", "exclus"),
+    ("cheeseming", "cheesem"),
+    ("provocation", "provocaz"),
+    ("skewer", "skewer"),
+    ("rockets", "rocket"),
+    ("whimper", "whimper"),
+    ("sucking", "suck"),
+    ("scrawls", "scrawl"),
+    ("deposited", "deposit"),
+    ("slippered", "slipper"),
+    ("mill", "mill"),
+    ("chambers", "chamber"),
+    ("progressively", "progress"),
+    ("deigning", "deign"),
+    ("labourer", "labour"),
+    ("chink", "chink"),
+    ("jarnders", "jarnder"),
+    ("laughing", "laugh"),
+    ("gardening", "garden"),
+    ("betty", "betti"),
+    ("infinitum", "infinitum"),
+    ("withdrawed", "withdraw"),
+    ("gay", "gai"),
+    ("balloons", "balloon"),
+    ("indios", "indio"),
+    ("convulsed", "convuls"),
+    ("mucker", "mucker"),
+    ("feelingly", "feelingli"),
+    ("named", "name"),
+    ("paladin", "paladin"),
+    ("combatants", "combat"),
+    ("champagne", "champagn"),
+    ("buttons", "button"),
+    ("ikon", "ikon"),
+    ("disciples", "discipl"),
+    ("gazette", "gazett"),
+    ("kiln", "kiln"),
+    ("cindery", "cinderi"),
+    ("bread", "bread"),
+    ("toothpick", "toothpick"),
+    ("secede", "seced"),
+    ("contradict", "contradict"),
+    ("puzzle", "puzzl"),
+    ("ineffectiveness", "ineffect"),
+    ("absurdly", "absurdli"),
+    ("melindres", "melindr"),
+    ("noiselessly", "noiselessli"),
+    ("wanderer", "wander"),
+    ("denuded", "denud"),
+    ("reject", "reject"),
+    ("deedn", "deedn"),
+    ("revoke", "revok"),
+    ("cornelian", "cornelian"),
+    ("originality", "origin"),
+    ("dirtied", "dirti"),
+    ("quantity", "quantiti"),
+    ("twine", "twine"),
+    ("ovens", "oven"),
+    ("rogue", "rogu"),
+    ("lawyer", "lawyer"),
+    ("concurrence", "concurr"),
+    ("verandah", "verandah"),
+    ("unwholesome", "unwholesom"),
+    ("phenomenal", "phenomen"),
+    ("glimmered", "glimmer"),
+    ("wile", "wile"),
+    ("gashed", "gash"),
+    ("expressive", "express"),
+    ("precision", "precis"),
+    ("cheering", "cheer"),
+    ("dwarf", "dwarf"),
+    ("additional", "addit"),
+    ("inconsistently", "inconsist"),
+    ("popped", "pop"),
+    ("enlarging", "enlarg"),
+    ("facilitating", "facilit"),
+    ("sports", "sport"),
+    ("dreaded", "dread"),
+    ("ey", "ei"),
+    ("cherty", "cherti"),
+    ("peaty", "peati"),
+    ("robed", "robe"),
+    ("believest", "believest"),
+    ("puppies", "puppi"),
+    ("shows", "show"),
+    ("test", "test"),
+    ("antiquated", "antiqu"),
+    ("goeth", "goeth"),
+    ("fusibility", "fusibl"),
+    ("superintends", "superintend"),
+    ("stiff", "stiff"),
+    ("cervicem", "cervicem"),
+    ("dismisses", "dismiss"),
+    ("crutches", "crutch"),
+    ("gusher", "gusher"),
+    ("excitements", "excit"),
+    ("err", "err"),
+    ("abrolhos", "abrolho"),
+    ("mused", "muse"),
+    ("serious", "seriou"),
+    ("faints", "faint"),
+    ("ganging", "gang"),
+    ("eight", "eight"),
+    ("believers", "believ"),
+    ("fig", "fig"),
+    ("horizontal", "horizont"),
+    ("bounded", "bound"),
+    ("club", "club"),
+    ("learnt", "learnt"),
+    ("chastise", "chastis"),
+    ("cigar", "cigar"),
+    ("vindicated", "vindic"),
+    ("mongrels", "mongrel"),
+    ("surreptitiously", "surreptiti"),
+    ("petrovitch", "petrovitch"),
+    ("meddlers", "meddler"),
+    ("paddled", "paddl"),
+    ("totally", "total"),
+    ("disconsolately", "disconsol"),
+    ("ver", "ver"),
+    ("hygrometer", "hygromet"),
+    ("bared", "bare"),
+    ("urgency", "urgenc"),
+    ("augmented", "augment"),
+    ("generation", "generaz"),
+    ("hump", "hump"),
+    ("signature", "signatur"),
+    ("flaws", "flaw"),
+    ("ensued", "ensu"),
+    ("rengger", "rengger"),
+    ("miry", "miri"),
+    ("fault", "fault"),
+    ("basking", "bask"),
+    ("ditto", "ditto"),
+    ("thereto", "thereto"),
+    ("swiftly", "swiftli"),
+    ("pony", "poni"),
+    ("binn", "binn"),
+    ("subsides", "subsid"),
+    ("units", "unit"),
+    ("marlborough", "marlborough"),
+    ("dilatation", "dilataz"),
+    ("isthmus", "isthmu"),
+    ("sherry", "sherri"),
+    ("albatross", "albatross"),
+    ("definitions", "definit"),
+    ("courteously", "courteous"),
+    ("similarity", "similar"),
+    ("subacid", "subacid"),
+    ("recommending", "recommend"),
+    ("manger", "manger"),
+    ("amass", "amass"),
+    ("swelling", "swell"),
+    ("louder", "louder"),
+    ("seventhly", "seventhli"),
+    ("upraised", "uprais"),
+    ("hussy", "hussi"),
+    ("monter", "monter"),
+    ("participated", "particip"),
+    ("accounts", "account"),
+    ("groans", "groan"),
+    ("mogley", "moglei"),
+    ("pretensions", "pretens"),
+    ("noticing", "notic"),
+    ("percepts", "percept"),
+    ("captivity", "captiv"),
+    ("guesses", "guess"),
+    ("eighteenpences", "eighteenp"),
+    ("cope", "cope"),
+    ("vacant", "vacant"),
+    ("tipped", "tip"),
+    ("coroners", "coron"),
+    ("migrations", "migraz"),
+    ("boarded", "board"),
+    ("titled", "titl"),
+    ("attentive", "attent"),
+    ("landed", "land"),
+    ("mussel", "mussel"),
+    ("ide", "id"),
+    ("puzzled", "puzzl"),
+    ("loikewise", "loikewis"),
+    ("moped", "mope"),
+    ("disposing", "dispos"),
+    ("loss", "loss"),
+    ("bart", "bart"),
+    ("entwines", "entwin"),
+    ("absorbent", "absorb"),
+    ("pictorial", "pictori"),
+    ("millionaire", "millionair"),
+    ("expostulate", "expostul"),
+    ("braggadocio", "braggadocio"),
+    ("night", "night"),
+    ("converting", "convert"),
+    ("misgivings", "misgiv"),
+    ("tabooed", "taboo"),
+    ("clangour", "clangour"),
+    ("knoweth", "knoweth"),
+    ("wiolincellers", "wiolincel"),
+    ("rummage", "rummag"),
+    ("shave", "shave"),
+    ("pyrifera", "pyrifera"),
+    ("disquiet", "disquiet"),
+    ("kolomensky", "kolomenski"),
+    ("embedded", "embed"),
+    ("loading", "load"),
+    ("directs", "direct"),
+    ("lumpkin", "lumpkin"),
+    ("enticed", "entic"),
+    ("payments", "payment"),
+    ("emotion", "emot"),
+    ("unhurt", "unhurt"),
+    ("manageress", "manageress"),
+    ("discoloration", "discoloraz"),
+    ("jocularly", "jocularli"),
+    ("competitor", "competitor"),
+    ("triumphing", "triumph"),
+    ("according", "accord"),
+    ("fitly", "fitli"),
+    ("demolition", "demolit"),
+    ("broach", "broach"),
+    ("receipts", "receipt"),
+    ("haunted", "haunt"),
+    ("potentate", "potent"),
+    ("stare", "stare"),
+    ("purse", "purs"),
+    ("burnt", "burnt"),
+    ("dawned", "dawn"),
+    ("phairy", "phairi"),
+    ("regulates", "regul"),
+    ("replenishing", "replenish"),
+    ("denied", "deni"),
+    ("equivocal", "equivoc"),
+    ("hammercloths", "hammercloth"),
+    ("tupungato", "tupungato"),
+    ("sowing", "sow"),
+    ("overleaping", "overleap"),
+    ("poncho", "poncho"),
+    ("greensward", "greensward"),
+    ("feareth", "feareth"),
+    ("abutting", "abut"),
+    ("accomplish", "accomplish"),
+    ("jeering", "jeer"),
+    ("disseminated", "dissemin"),
+    ("archery", "archeri"),
+    ("palmes", "palm"),
+    ("stalwart", "stalwart"),
+    ("lastly", "lastli"),
+    ("huntsman", "huntsman"),
+    ("lad", "lad"),
+    ("lime", "lime"),
+    ("coarseness", "coars"),
+    ("moping", "mope"),
+    ("inside", "insid"),
+    ("dushkin", "dushkin"),
+    ("diplomacy", "diplomaci"),
+    ("unluckily", "unluckili"),
+    ("engineer", "engin"),
+    ("ant", "ant"),
+    ("unmanly", "unmanli"),
+    ("destro", "destro"),
+    ("trampled", "trampl"),
+    ("warburton", "warburton"),
+    ("overset", "overset"),
+    ("greeting", "greet"),
+    ("slocomb", "slocomb"),
+    ("dreary", "dreari"),
+    ("reconsidering", "reconsid"),
+    ("india", "india"),
+    ("irate", "irat"),
+    ("weals", "weal"),
+    ("dejection", "deject"),
+    ("overthrew", "overthrew"),
+    ("trailing", "trail"),
+    ("repudiation", "repudiaz"),
+    ("rarity", "rariti"),
+    ("miners", "miner"),
+    ("senate", "senat"),
+    ("occupants", "occup"),
+    ("lads", "lad"),
+    ("sprinkling", "sprinkl"),
+    ("pulcheria", "pulcheria"),
+    ("guatemala", "guatemala"),
+    ("refusest", "refusest"),
+    ("steam", "steam"),
+    ("season", "season"),
+    ("rudiments", "rudiment"),
+    ("sparrows", "sparrow"),
+    ("corduroy", "corduroi"),
+    ("budding", "bud"),
+    ("deafening", "deafen"),
+    ("banish", "banish"),
+    ("brimful", "brim"),
+    ("bean", "bean"),
+    ("midst", "midst"),
+    ("lichen", "lichen"),
+    ("paces", "pace"),
+    ("late", "late"),
+    ("uncritically", "uncrit"),
+    ("indisposed", "indispos"),
+    ("scoffers", "scoffer"),
+    ("member", "member"),
+    ("slopseller", "slopsel"),
+    ("desired", "desir"),
+    ("indecision", "indecis"),
+    ("opportunely", "opportun"),
+    ("ravines", "ravin"),
+    ("eater", "eater"),
+    ("reliant", "reliant"),
+    ("animal", "anim"),
+    ("believing", "believ"),
+    ("authority", "author"),
+    ("breaths", "breath"),
+    ("contagion", "contagion"),
+    ("igneous", "igneou"),
+    ("simpering", "simper"),
+    ("melolonthidae", "melolonthida"),
+    ("poniard", "poniard"),
+    ("frame", "frame"),
+    ("payable", "payabl"),
+    ("routes", "rout"),
+    ("unlatched", "unlatch"),
+    ("adrift", "adrift"),
+    ("throwed", "throw"),
+    ("taradiddle", "taradiddl"),
+    ("frilled", "frill"),
+    ("dreading", "dread"),
+    ("banking", "bank"),
+    ("intricacies", "intricaci"),
+    ("rein", "rein"),
+    ("accuse", "accus"),
+    ("acuter", "acut"),
+    ("hippish", "hippish"),
+    ("conforming", "conform"),
+    ("shouldered", "shoulder"),
+    ("gravestone", "graveston"),
+    ("livery", "liveri"),
+    ("per", "per"),
+    ("forehead", "forehead"),
+    ("expanse", "expans"),
+    ("expeditions", "expedit"),
+    ("arrive", "arriv"),
+    ("statesmanlike", "statesmanlik"),
+    ("copecks", "copeck"),
+    ("dockyard", "dockyard"),
+    ("comprehending", "comprehend"),
+    ("ram", "ram"),
+    ("noblest", "noblest"),
+    ("funereal", "funer"),
+    ("arborescent", "arboresc"),
+    ("pore", "pore"),
+    ("penetration", "penetraz"),
+    ("brig", "brig"),
+    ("cheerless", "cheerless"),
+    ("economically", "econom"),
+    ("dispraise", "disprais"),
+    ("removal", "remov"),
+    ("outliers", "outlier"),
+    ("hasten", "hasten"),
+    ("harp", "harp"),
+    ("traditional", "tradit"),
+    ("fleas", "flea"),
+    ("challenger", "challeng"),
+    ("mortgaging", "mortgag"),
+    ("stain", "stain"),
+    ("bohemond", "bohemond"),
+    ("fetlocks", "fetlock"),
+    ("redound", "redound"),
+    ("excellencies", "excel"),
+    ("flavoured", "flavour"),
+    ("contorted", "contort"),
+    ("recognized", "recogn"),
+    ("spittoon", "spittoon"),
+    ("gifts", "gift"),
+    ("fraught", "fraught"),
+    ("compromise", "compromis"),
+    ("german", "german"),
+    ("personal", "person"),
+    ("gutters", "gutter"),
+    ("guerre", "guerr"),
+    ("densest", "densest"),
+    ("alto", "alto"),
+    ("astern", "astern"),
+    ("laudamus", "laudamu"),
+    ("rubbishly", "rubbishli"),
+    ("frontier", "frontier"),
+    ("nevertheless", "nevertheless"),
+    ("follower", "follow"),
+    ("add", "add"),
+    ("s", ""),
+    ("myths", "myth"),
+    ("signalling", "signal"),
+    ("siamese", "siames"),
+    ("compassionated", "compassion"),
+    ("presentiments", "presenti"),
+    ("frenzied", "frenzi"),
+    ("lungs", "lung"),
+    ("distinguish", "distinguish"),
+    ("plots", "plot"),
+    ("slangular", "slangular"),
+    ("jewels", "jewel"),
+    ("brickmaker", "brickmak"),
+    ("offices", "offic"),
+    ("rampart", "rampart"),
+    ("changeling", "changel"),
+    ("geraniums", "geranium"),
+    ("sedimentary", "sedimentari"),
+    ("gibraltar", "gibraltar"),
+    ("adage", "adag"),
+    ("befall", "befal"),
+    ("tradesmen", "tradesmen"),
+    ("castro", "castro"),
+    ("shakings", "shake"),
+    ("elite", "elit"),
+    ("fissurellae", "fissurella"),
+    ("dowagers", "dowag"),
+    ("gad", "gad"),
+    ("appointment", "appoint"),
+    ("busying", "busi"),
+    ("idiots", "idiot"),
+    ("armadillos", "armadillo"),
+    ("wold", "wold"),
+    ("coalitions", "coalit"),
+    ("suit", "suit"),
+    ("unpleasantly", "unpleasantli"),
+    ("alternately", "altern"),
+    ("enemy", "enemi"),
+    ("consult", "consult"),
+    ("charming", "charm"),
+    ("naughtiness", "naughti"),
+    ("stiffer", "stiffer"),
+    ("serve", "serv"),
+    ("probability", "probabl"),
+    ("breadth", "breadth"),
+    ("clauses", "claus"),
+    ("legacy", "legaci"),
+    ("cuffy", "cuffi"),
+    ("wells", "well"),
+    ("nutshell", "nutshel"),
+    ("artifice", "artific"),
+    ("acceptation", "acceptaz"),
+    ("teru", "teru"),
+    ("monuments", "monument"),
+    ("honestly", "honestli"),
+    ("corrective", "correct"),
+    ("erysipelas", "erysipela"),
+    ("vitally", "vital"),
+    ("hare", "hare"),
+    ("spanish", "spanish"),
+    ("chap", "chap"),
+    ("fis", "fi"),
+    ("adjusting", "adjust"),
+    ("serfdom", "serfdom"),
+    ("practices", "practic"),
+    ("uninterrupted", "uninterrupt"),
+    ("grandson", "grandson"),
+    ("scripter", "scripter"),
+    ("moulder", "moulder"),
+    ("inquire", "inquir"),
+    ("project", "project"),
+    ("deliver", "deliv"),
+    ("negligemment", "negligem"),
+    ("ave", "av"),
+    ("suddenl", "suddenl"),
+    ("chasms", "chasm"),
+    ("commonwealth", "commonwealth"),
+    ("nerve", "nerv"),
+    ("curved", "curv"),
+    ("woolen", "woolen"),
+    ("pallas", "palla"),
+    ("teaspoon", "teaspoon"),
+    ("conductors", "conductor"),
+    ("arbour", "arbour"),
+    ("patronize", "patron"),
+    ("dethronement", "dethron"),
+    ("leanness", "lean"),
+    ("accepts", "accept"),
+    ("snorts", "snort"),
+    ("decimal", "decim"),
+    ("stagnant", "stagnant"),
+    ("contumelious", "contumeli"),
+    ("page", "page"),
+    ("partner", "partner"),
+    ("peacock", "peacock"),
+    ("compn", "compn"),
+    ("philanthropists", "philanthropist"),
+    ("unallowable", "unallow"),
+    ("likely", "like"),
+    ("magnifying", "magnifi"),
+    ("marica", "marica"),
+    ("wine", "wine"),
+    ("trickled", "trickl"),
+    ("broiling", "broil"),
+    ("dermestes", "dermest"),
+    ("anas", "ana"),
+    ("hole", "hole"),
+    ("addressing", "address"),
+    ("unconsidered", "unconsid"),
+    ("humain", "humain"),
+    ("inanities", "inan"),
+    ("begs", "beg"),
+    ("concludes", "conclud"),
+    ("los", "lo"),
+    ("megalonyx", "megalonyx"),
+    ("signal", "signal"),
+    ("souring", "sour"),
+    ("persuasions", "persuas"),
+    ("aits", "ait"),
+    ("kitchen", "kitchen"),
+    ("indubitably", "indubit"),
+    ("squire", "squir"),
+    ("elle", "ell"),
+    ("assumptions", "assumpt"),
+    ("range", "rang"),
+    ("insipid", "insipid"),
+    ("gum", "gum"),
+    ("grea", "grea"),
+    ("erstan", "erstan"),
+    ("sar", "sar"),
+    ("bearish", "bearish"),
+    ("peeled", "peel"),
+    ("once", "onc"),
+    ("posed", "pose"),
+    ("mero", "mero"),
+    ("peccaries", "peccari"),
+    ("whale", "whale"),
+    ("exuberant", "exuber"),
+    ("durability", "durabl"),
+    ("dangers", "danger"),
+    ("rumoured", "rumour"),
+    ("laurel", "laurel"),
+    ("gaiters", "gaiter"),
+    ("conversed", "convers"),
+    ("christi", "christi"),
+    ("works", "work"),
+    ("mildly", "mildli"),
+    ("perpetuate", "perpetu"),
+    ("scrofulous", "scroful"),
+    ("schoolroom", "schoolroom"),
+    ("erectness", "erect"),
+    ("corallines", "corallin"),
+    ("thames", "thame"),
+    ("anticipate", "anticip"),
+    ("inconsiderate", "inconsider"),
+    ("sanctified", "sanctifi"),
+    ("whoosh", "whoosh"),
+    ("matrimonial", "matrimoni"),
+    ("len", "len"),
+    ("kryukov", "kryukov"),
+    ("spires", "spire"),
+    ("reverently", "rever"),
+    ("rattles", "rattl"),
+    ("odder", "odder"),
+    ("russia", "russia"),
+    ("arched", "arch"),
+    ("shields", "shield"),
+    ("linnets", "linnet"),
+    ("tampering", "tamper"),
+    ("reluctant", "reluct"),
+    ("vassalage", "vassalag"),
+    ("snipe", "snipe"),
+    ("brevity", "breviti"),
+    ("unequally", "unequ"),
+    ("wissen", "wissen"),
+    ("infamous", "infam"),
+    ("sedley", "sedlei"),
+    ("slime", "slime"),
+    ("lookers", "looker"),
+    ("rubbishing", "rubbish"),
+    ("mendoza", "mendoza"),
+    ("chaos", "chao"),
+    ("communicated", "commun"),
+    ("shutter", "shutter"),
+    ("shelf", "shelf"),
+    ("extemporized", "extempor"),
+    ("premises", "premis"),
+    ("receives", "receiv"),
+    ("weighty", "weighti"),
+    ("immured", "immur"),
+    ("liana", "liana"),
+    ("blankness", "blank"),
+    ("assassin", "assassin"),
+    ("hourly", "hourli"),
+    ("frenchmen", "frenchmen"),
+    ("gowns", "gown"),
+    ("distracting", "distract"),
+    ("deuce", "deuc"),
+    ("graceless", "graceless"),
+    ("chrysalis", "chrysali"),
+    ("classical", "classic"),
+    ("kauri", "kauri"),
+    ("forgiven", "forgiven"),
+    ("canary", "canari"),
+    ("unprofitable", "unprofit"),
+    ("lobster", "lobster"),
+    ("annual", "annual"),
+    ("fatter", "fatter"),
+    ("bombs", "bomb"),
+    ("abnormal", "abnorm"),
+    ("working", "work"),
+    ("jacks", "jack"),
+    ("strategy", "strategi"),
+    ("chaperons", "chaperon"),
+    ("choke", "choke"),
+    ("consists", "consist"),
+    ("tawny", "tawni"),
+    ("decrease", "decreas"),
+    ("bulldog", "bulldog"),
+    ("pharisees", "pharise"),
+    ("lewes", "lew"),
+    ("apologetic", "apologet"),
+    ("courtyards", "courtyard"),
+    ("deduced", "deduc"),
+    ("jargon", "jargon"),
+    ("dilate", "dilat"),
+    ("hoorly", "hoorli"),
+    ("kindly", "kindli"),
+    ("coursing", "cours"),
+    ("rarer", "rarer"),
+    ("persuading", "persuad"),
+    ("eard", "eard"),
+    ("sixpences", "sixpenc"),
+    ("nous", "nou"),
+    ("moralising", "moralis"),
+    ("ears", "ear"),
+    ("brutally", "brutal"),
+    ("reef", "reef"),
+    ("dickens", "dicken"),
+    ("heaped", "heap"),
+    ("thundered", "thunder"),
+    ("inspired", "inspir"),
+    ("pittances", "pittanc"),
+    ("trotty", "trotti"),
+    ("writhings", "writh"),
+    ("rattling", "rattl"),
+    ("loo", "loo"),
+    ("flattening", "flatten"),
+    ("terrify", "terrifi"),
+    ("roll", "roll"),
+    ("undertook", "undertook"),
+    ("outer", "outer"),
+    ("lounge", "loung"),
+    ("vegetable", "veget"),
+    ("mag", "mag"),
+    ("rex", "rex"),
+    ("unsettle", "unsettl"),
+    ("marsh", "marsh"),
+    ("savour", "savour"),
+    ("grumbler", "grumbler"),
+    ("deducted", "deduct"),
+    ("atom", "atom"),
+    ("stimulant", "stimul"),
+    ("zoophyte", "zoophyt"),
+    ("convalescent", "convalesc"),
+    ("grose", "grose"),
+    ("two", "two"),
+    ("broadcast", "broadcast"),
+    ("prophesying", "prophesi"),
+    ("perverse", "pervers"),
+    ("perpetrators", "perpetr"),
+    ("winking", "wink"),
+    ("converge", "converg"),
+    ("immaculate", "immacul"),
+    ("asylums", "asylum"),
+    ("pleadingly", "pleadingli"),
+    ("bowed", "bow"),
+    ("demneble", "demnebl"),
+    ("cosa", "cosa"),
+    ("roundly", "roundli"),
+    ("fastest", "fastest"),
+    ("grub", "grub"),
+    ("stick", "stick"),
+    ("perishable", "perish"),
+    ("extracted", "extract"),
+    ("fumigation", "fumigaz"),
+    ("aristocrat", "aristocrat"),
+    ("odoriferous", "odorifer"),
+    ("bewitching", "bewitch"),
+    ("abortions", "abort"),
+    ("turnips", "turnip"),
+    ("aldgate", "aldgat"),
+    ("thrusts", "thrust"),
+    ("moot", "moot"),
+    ("shades", "shade"),
+    ("glow", "glow"),
+    ("chizzle", "chizzl"),
+    ("jordan", "jordan"),
+    ("corral", "corral"),
+    ("transparenfly", "transparenfli"),
+    ("metropolis", "metropoli"),
+    ("unconcernedly", "unconcernedli"),
+    ("grumbling", "grumbl"),
+    ("comedy", "comedi"),
+    ("lightheaded", "lighthead"),
+    ("deer", "deer"),
+    ("bluish", "bluish"),
+    ("lx", "lx"),
+    ("tasking", "task"),
+    ("smelt", "smelt"),
+    ("item", "item"),
+    ("rev", "rev"),
+    ("conceded", "conc"),
+    ("frankness", "frank"),
+    ("erme", "erm"),
+    ("scene", "scene"),
+    ("readjusted", "readjust"),
+    ("rare", "rare"),
+    ("autocratic", "autocrat"),
+    ("wailings", "wail"),
+    ("unpalatable", "unpalat"),
+    ("disadvantages", "disadvantag"),
+    ("shady", "shadi"),
+    ("crabberies", "crabberi"),
+    ("mistakes", "mistak"),
+    ("implored", "implor"),
+    ("boilers", "boiler"),
+    ("freshest", "freshest"),
+    ("regained", "regain"),
+    ("coldness", "cold"),
+    ("propoundlng", "propoundlng"),
+    ("interstratified", "interstratifi"),
+    ("flecked", "fleck"),
+    ("wails", "wail"),
+    ("quand", "quand"),
+    ("ojection", "oject"),
+    ("buying", "bui"),
+    ("horatio", "horatio"),
+    ("garnishing", "garnish"),
+    ("click", "click"),
+    ("coronet", "coronet"),
+    ("food", "food"),
+    ("vacillatory", "vacillatori"),
+    ("figs", "fig"),
+    ("caring", "care"),
+    ("thistles", "thistl"),
+    ("richmond", "richmond"),
+    ("gain", "gain"),
+    ("undertakes", "undertak"),
+    ("outlines", "outlin"),
+    ("reflection", "reflect"),
+    ("ravaging", "ravag"),
+    ("margin", "margin"),
+    ("casket", "casket"),
+    ("scrambled", "scrambl"),
+    ("anti", "anti"),
+    ("nata", "nata"),
+    ("pattening", "patten"),
+    ("pitted", "pit"),
+    ("manured", "manur"),
+    ("wengeance", "wengeanc"),
+    ("similar", "similar"),
+    ("exact", "exact"),
+    ("publicans", "publican"),
+    ("neglect", "neglect"),
+    ("lunatic", "lunat"),
+    ("rivals", "rival"),
+    ("wil", "wil"),
+    ("treacled", "treacl"),
+    ("cicadae", "cicada"),
+    ("crowbar", "crowbar"),
+    ("fastening", "fasten"),
+    ("intelligently", "intellig"),
+    ("scouring", "scour"),
+    ("somewhither", "somewhith"),
+    ("footguards", "footguard"),
+    ("intoxicated", "intox"),
+    ("fought", "fought"),
+    ("embarrassment", "embarrass"),
+    ("plate", "plate"),
+    ("pul", "pul"),
+    ("unventilated", "unventil"),
+    ("exit", "exit"),
+    ("disable", "disabl"),
+    ("pan", "pan"),
+    ("stritched", "stritch"),
+    ("demoralize", "demor"),
+    ("abershaw", "abershaw"),
+    ("thence", "thenc"),
+    ("balcarce", "balcarc"),
+    ("catch", "catch"),
+    ("scorned", "scorn"),
+    ("unmanageable", "unmanag"),
+    ("wisdom", "wisdom"),
+    ("keenly", "keenli"),
+    ("infame", "infam"),
+    ("regulated", "regul"),
+    ("muddiest", "muddiest"),
+    ("ghastlier", "ghastlier"),
+    ("electric", "electr"),
+    ("morning", "morn"),
+    ("intombed", "intomb"),
+    ("preferment", "prefer"),
+    ("prior", "prior"),
+    ("compel", "compel"),
+    ("leaper", "leaper"),
+    ("dames", "dame"),
+    ("schone", "schone"),
+    ("jams", "jam"),
+    ("devices", "devic"),
+    ("nets", "net"),
+    ("thrice", "thrice"),
+    ("dodging", "dodg"),
+    ("glades", "glade"),
+    ("anne", "ann"),
+    ("dormant", "dormant"),
+    ("spicula", "spicula"),
+    ("bleared", "blear"),
+    ("candidature", "candidatur"),
+    ("remiss", "remiss"),
+    ("drove", "drove"),
+    ("reprehensible", "reprehens"),
+    ("ox", "ox"),
+    ("interest", "interest"),
+    ("pierre", "pierr"),
+    ("clara", "clara"),
+    ("flanks", "flank"),
+    ("metaphysics", "metaphys"),
+    ("hounded", "hound"),
+    ("nowise", "nowis"),
+    ("somethings", "someth"),
+    ("instinctive", "instinct"),
+    ("unravelment", "unravel"),
+    ("rhyncophora", "rhyncophora"),
+    ("retrenchments", "retrench"),
+    ("chonos", "chono"),
+    ("torturing", "tortur"),
+    ("eyeglass", "eyeglass"),
+    ("arouse", "arous"),
+    ("monats", "monat"),
+    ("reviled", "revil"),
+    ("vicuna", "vicuna"),
+    ("surfaces", "surfac"),
+    ("agility", "agil"),
+    ("herries", "herri"),
+    ("holily", "holili"),
+    ("goblets", "goblet"),
+    ("uncanny", "uncanni"),
+    ("greenhorn", "greenhorn"),
+    ("obstructed", "obstruct"),
+    ("disapproves", "disapprov"),
+    ("unoccupied", "unoccupi"),
+    ("tonight", "tonight"),
+    ("capable", "capabl"),
+    ("annihilate", "annihil"),
+    ("hampering", "hamper"),
+    ("streets", "street"),
+    ("produce", "produc"),
+    ("pettifoggers", "pettifogg"),
+    ("seaman", "seaman"),
+    ("healthful", "health"),
+    ("sipping", "sip"),
+    ("throng", "throng"),
+    ("cheats", "cheat"),
+    ("concerts", "concert"),
+    ("trotted", "trot"),
+    ("revolution", "revolut"),
+    ("measurements", "measur"),
+    ("lat", "lat"),
+    ("theodore", "theodor"),
+    ("chopped", "chop"),
+    ("surrendered", "surrend"),
+    ("proposals", "propos"),
+    ("coppers", "copper"),
+    ("profitable", "profit"),
+    ("oasis", "oasi"),
+    ("gawky", "gawki"),
+    ("godfrey", "godfrei"),
+    ("professionaly", "professionali"),
+    ("kindling", "kindl"),
+    ("tell", "tell"),
+    ("lamarck", "lamarck"),
+    ("disagreeable", "disagre"),
+    ("cannibalism", "cannib"),
+    ("guayatecas", "guayateca"),
+    ("nerving", "nerv"),
+    ("lix", "lix"),
+    ("scintillation", "scintillaz"),
+    ("bitter", "bitter"),
+    ("appropriating", "appropri"),
+    ("viazemsky", "viazemski"),
+    ("shaggy", "shaggi"),
+    ("latitudes", "latitud"),
+    ("hurrah", "hurrah"),
+    ("migratory", "migratori"),
+    ("pounce", "pounc"),
+    ("k", "k"),
+    ("traitorous", "traitor"),
+    ("enthralled", "enthral"),
+    ("regularity", "regular"),
+    ("verses", "vers"),
+    ("broadened", "broaden"),
+    ("perpetual", "perpetu"),
+    ("alcohol", "alcohol"),
+    ("weighed", "weigh"),
+    ("herald", "herald"),
+    ("vancouver", "vancouv"),
+    ("prevails", "prevail"),
+    ("ell", "ell"),
+    ("squalid", "squalid"),
+    ("salado", "salado"),
+    ("gleam", "gleam"),
+    ("voznesensky", "voznesenski"),
+    ("redoubled", "redoubl"),
+    ("sheen", "sheen"),
+    ("wlll", "wlll"),
+    ("wardrobe", "wardrob"),
+    ("intrusion", "intrus"),
+    ("cruel", "cruel"),
+    ("embraced", "embrac"),
+    ("assemblies", "assembli"),
+    ("vith", "vith"),
+    ("whiskers", "whisker"),
+    ("building", "build"),
+    ("steal", "steal"),
+    ("habited", "habit"),
+    ("feathers", "feather"),
+    ("rodents", "rodent"),
+    ("appreciation", "appreciaz"),
+    ("feasting", "feast"),
+    ("xl", "xl"),
+    ("battlements", "battlement"),
+    ("doggedly", "doggedli"),
+    ("crocking", "crock"),
+    ("cohesion", "cohes"),
+    ("fearless", "fearless"),
+    ("contemporaneousness", "contemporan"),
+    ("mogul", "mogul"),
+    ("stomachic", "stomach"),
+    ("unadorned", "unadorn"),
+    ("hustle", "hustl"),
+    ("partnership", "partnership"),
+    ("revulsion", "revuls"),
+    ("relict", "relict"),
+    ("writing", "write"),
+    ("rueful", "rue"),
+    ("sh", "sh"),
+    ("progne", "progn"),
+    ("consols", "consol"),
+    ("reprovingly", "reprovingli"),
+    ("uninviting", "uninvit"),
+    ("unsuitable", "unsuit"),
+    ("endearments", "endear"),
+    ("bodice", "bodic"),
+    ("surveillance", "surveil"),
+    ("subordinates", "subordin"),
+    ("collapse", "collaps"),
+    ("edible", "edibl"),
+    ("acrimonious", "acrimoni"),
+    ("glee", "glee"),
+    ("elaborate", "elabor"),
+    ("cookshop", "cookshop"),
+    ("invests", "invest"),
+    ("contracts", "contract"),
+    ("sousing", "sous"),
+    ("drover", "drover"),
+    ("barking", "bark"),
+    ("deference", "defer"),
+    ("mollifying", "mollifi"),
+    ("monogram", "monogram"),
+    ("smelting", "smelt"),
+    ("inducted", "induct"),
+    ("radish", "radish"),
+    ("satisfy", "satisfi"),
+    ("lattle", "lattl"),
+    ("freshness", "fresh"),
+    ("twixt", "twixt"),
+    ("enshrouded", "enshroud"),
+    ("counterfeited", "counterfeit"),
+    ("creation", "creaz"),
+    ("integral", "integr"),
+    ("bounds", "bound"),
+    ("saucy", "sauci"),
+    ("knick", "knick"),
+    ("husband", "husband"),
+    ("placidity", "placid"),
+    ("oscillations", "oscillaz"),
+    ("wintry", "wintri"),
+    ("consonant", "conson"),
+    ("edgeless", "edgeless"),
+    ("zoological", "zoolog"),
+    ("screened", "screen"),
+    ("alteration", "alteraz"),
+    ("taunt", "taunt"),
+    ("morality", "moral"),
+    ("emergency", "emerg"),
+    ("suppose", "suppos"),
+    ("involuntary", "involuntari"),
+    ("dishonoured", "dishonour"),
+    ("lawstationers", "lawstation"),
+    ("outskirts", "outskirt"),
+    ("recollects", "recollect"),
+    ("beads", "bead"),
+    ("actuated", "actuat"),
+    ("j", "j"),
+    ("prepossessions", "prepossess"),
+    ("vans", "van"),
+    ("luncheon", "luncheon"),
+    ("denouement", "denouement"),
+    ("proceed", "proce"),
+    ("stupid", "stupid"),
+    ("sentences", "sentenc"),
+    ("mansions", "mansion"),
+    ("necesary", "necesari"),
+    ("portmanteaus", "portmanteau"),
+    ("rodentia", "rodentia"),
+    ("sidling", "sidl"),
+    ("cellars", "cellar"),
+    ("snorted", "snort"),
+    ("revolve", "revolv"),
+    ("confute", "confut"),
+    ("mammiferous", "mammifer"),
+    ("legibility", "legibl"),
+    ("blunders", "blunder"),
+    ("avoient", "avoient"),
+    ("decided", "decid"),
+    ("exertion", "exert"),
+    ("requisition", "requisit"),
+    ("wackford", "wackford"),
+    ("frosts", "frost"),
+    ("sensuality", "sensual"),
+    ("cunningest", "cunningest"),
+    ("atacama", "atacama"),
+    ("underling", "underl"),
+    ("manufactured", "manufactur"),
+    ("emoluments", "emolu"),
+    ("raving", "rave"),
+    ("tasteless", "tasteless"),
+    ("huapi", "huapi"),
+    ("performing", "perform"),
+    ("unpicturesque", "unpicturesqu"),
+    ("easterly", "easterli"),
+    ("epoch", "epoch"),
+    ("excepting", "except"),
+    ("querulous", "querul"),
+    ("melons", "melon"),
+    ("hilltops", "hilltop"),
+    ("amalgamate", "amalgam"),
+    ("punched", "punch"),
+    ("barrett", "barrett"),
+    ("pantaloons", "pantaloon"),
+    ("shroud", "shroud"),
+    ("overreaching", "overreach"),
+    ("plymouth", "plymouth"),
+    ("capitals", "capit"),
+    ("infuriated", "infuri"),
+    ("swirling", "swirl"),
+    ("fourteen", "fourteen"),
+    ("observing", "observ"),
+    ("calling", "call"),
+    ("scot", "scot"),
+    ("mozart", "mozart"),
+    ("afford", "afford"),
+    ("squeedgin", "squeedgin"),
+    ("obscured", "obscur"),
+    ("intonation", "intonaz"),
+    ("amd", "amd"),
+    ("earthy", "earthi"),
+    ("orgies", "orgi"),
+    ("bidden", "bidden"),
+    ("cowering", "cower"),
+    ("testimony", "testimoni"),
+    ("battering", "batter"),
+    ("pitt", "pitt"),
+    ("cookery", "cookeri"),
+    ("avow", "avow"),
+    ("unabashed", "unabash"),
+    ("extends", "extend"),
+    ("monk", "monk"),
+    ("happier", "happier"),
+    ("xiii", "xiii"),
+    ("aversion", "avers"),
+    ("childishness", "childish"),
+    ("hasn", "hasn"),
+    ("preserver", "preserv"),
+    ("clatter", "clatter"),
+    ("ex", "ex"),
+    ("cooped", "coop"),
+    ("spoilt", "spoilt"),
+    ("impending", "impend"),
+    ("breezes", "breez"),
+    ("hottentots", "hottentot"),
+    ("woollen", "woollen"),
+    ("notebook", "notebook"),
+    ("venomous", "venom"),
+    ("mystification", "mystificaz"),
+    ("devourers", "devour"),
+    ("sixpen", "sixpen"),
+    ("unaware", "unawar"),
+    ("fewest", "fewest"),
+    ("plying", "pli"),
+    ("yoke", "yok"),
+    ("screams", "scream"),
+    ("fins", "fin"),
+    ("extricate", "extric"),
+    ("passports", "passport"),
+    ("adult", "adult"),
+    ("tailed", "tail"),
+    ("patronizingly", "patronizingli"),
+    ("colonists", "colonist"),
+    ("drifte", "drift"),
+    ("dynasty", "dynasti"),
+    ("supplied", "suppli"),
+    ("eclipsed", "eclips"),
+    ("kite", "kite"),
+    ("boung", "boung"),
+    ("auk", "auk"),
+    ("scoffed", "scof"),
+    ("deadlock", "deadlock"),
+    ("quotes", "quot"),
+    ("depreciated", "depreci"),
+    ("towards", "toward"),
+    ("adopting", "adopt"),
+    ("aboriginally", "aborigin"),
+    ("crucify", "crucifi"),
+    ("thongs", "thong"),
+    ("xlviii", "xlviii"),
+    ("reminded", "remind"),
+    ("diplomats", "diplomat"),
+    ("commemorated", "commemor"),
+    ("presenfty", "presenfti"),
+    ("drubbing", "drub"),
+    ("calculate", "calcul"),
+    ("swells", "swell"),
+    ("torrents", "torrent"),
+    ("mummery", "mummeri"),
+    ("handkercher", "handkerch"),
+    ("angles", "angl"),
+    ("void", "void"),
+    ("lustily", "lustili"),
+    ("countenance", "counten"),
+    ("slabs", "slab"),
+    ("carving", "carv"),
+    ("flags", "flag"),
+    ("slander", "slander"),
+    ("engages", "engag"),
+    ("seizes", "seiz"),
+    ("undertaken", "undertaken"),
+    ("aus", "au"),
+    ("went", "went"),
+    ("tended", "tend"),
+    ("tramp", "tramp"),
+    ("abilities", "abl"),
+    ("conclaves", "conclav"),
+    ("youngster", "youngster"),
+    ("collects", "collect"),
+    ("humiliation", "humiliaz"),
+    ("pericardium", "pericardium"),
+    ("turvy", "turvi"),
+    ("ruddle", "ruddl"),
+    ("outweighing", "outweigh"),
+    ("perquisitions", "perquisit"),
+    ("bitted", "bit"),
+    ("arrogance", "arrog"),
+    ("acid", "acid"),
+    ("haunting", "haunt"),
+    ("slang", "slang"),
+    ("hideous", "hideou"),
+    ("oneness", "on"),
+    ("immaterial", "immateri"),
+    ("sidles", "sidl"),
+    ("isaiah", "isaiah"),
+    ("cloaks", "cloak"),
+    ("pulp", "pulp"),
+    ("jour", "jour"),
+    ("partly", "partli"),
+    ("sharply", "sharpli"),
+    ("expansions", "expans"),
+    ("trespass", "trespass"),
+    ("fatness", "fat"),
+    ("walls", "wall"),
+    ("heavenly", "heavenli"),
+    ("seraphim", "seraphim"),
+    ("here", "here"),
+    ("junior", "junior"),
+    ("intestinal", "intestin"),
+    ("cabalistic", "cabalist"),
+    ("presence", "presenc"),
+    ("snails", "snail"),
+    ("abnormally", "abnorm"),
+    ("mantled", "mantl"),
+    ("equally", "equal"),
+    ("miasma", "miasma"),
+    ("frankest", "frankest"),
+    ("undignified", "undignifi"),
+    ("aridity", "arid"),
+    ("slips", "slip"),
+    ("hoisted", "hoist"),
+    ("clanging", "clang"),
+    ("dread", "dread"),
+    ("bidding", "bid"),
+    ("construction", "construct"),
+    ("witnesses", "wit"),
+    ("pickling", "pickl"),
+    ("suffered", "suffer"),
+    ("horrid", "horrid"),
+    ("coincides", "coincid"),
+    ("mantelshelf", "mantelshelf"),
+    ("rectifying", "rectifi"),
+    ("operated", "oper"),
+    ("defeat", "defeat"),
+    ("shortcomings", "shortcom"),
+    ("caves", "cave"),
+    ("lining", "line"),
+    ("bull", "bull"),
+    ("plentifully", "plentifulli"),
+    ("unhappy", "unhappi"),
+    ("faire", "fair"),
+    ("perwerse", "perwers"),
+    ("denominated", "denomin"),
+    ("foregone", "foregon"),
+    ("renunciation", "renunciaz"),
+    ("sly", "sli"),
+    ("prosperity", "prosper"),
+    ("brewery", "breweri"),
+    ("festivities", "festiv"),
+    ("yesterday", "yesterdai"),
+    ("tests", "test"),
+    ("recoiled", "recoil"),
+    ("areco", "areco"),
+    ("universe", "univers"),
+    ("perpetrated", "perpetr"),
+    ("cachapual", "cachapu"),
+    ("pugstyles", "pugstyl"),
+    ("immersion", "immers"),
+    ("stilts", "stilt"),
+    ("crannies", "cranni"),
+    ("prosecute", "prosecut"),
+    ("lowered", "lower"),
+    ("lustfahrt", "lustfahrt"),
+    ("thickest", "thickest"),
+    ("crisia", "crisia"),
+    ("spinners", "spinner"),
+    ("vanes", "vane"),
+    ("lament", "lament"),
+    ("hook", "hook"),
+    ("dabblers", "dabbler"),
+    ("wind", "wind"),
+    ("mockeries", "mockeri"),
+    ("overpoweringly", "overpoweringli"),
+    ("fingerless", "fingerless"),
+    ("cleopatra", "cleopatra"),
+    ("marital", "marit"),
+    ("gallons", "gallon"),
+    ("denunciations", "denunciaz"),
+    ("refunded", "refund"),
+    ("mended", "mend"),
+    ("ulvae", "ulva"),
+    ("marine", "marin"),
+    ("lectured", "lectur"),
+    ("indiscriminate", "indiscrimin"),
+    ("scientific", "scientif"),
+    ("conceives", "conceiv"),
+    ("kennel", "kennel"),
+    ("lots", "lot"),
+    ("peaches", "peach"),
+    ("ablutions", "ablut"),
+    ("charities", "chariti"),
+    ("overlook", "overlook"),
+    ("reply", "repli"),
+    ("overcame", "overcam"),
+    ("lordships", "lordship"),
+    ("intriguing", "intrigu"),
+    ("banknotes", "banknot"),
+    ("needed", "ne"),
+    ("circumference", "circumfer"),
+    ("stout", "stout"),
+    ("whines", "whine"),
+    ("deferred", "defer"),
+    ("resembles", "resembl"),
+    ("mutilated", "mutil"),
+    ("framed", "frame"),
+    ("dispositions", "disposit"),
+    ("portsmouth", "portsmouth"),
+    ("willed", "will"),
+    ("macculloch", "macculloch"),
+    ("evasive", "evas"),
+    ("faithfuller", "faithful"),
+    ("wonder", "wonder"),
+    ("raises", "rais"),
+    ("dilapidation", "dilapidaz"),
+    ("aug", "aug"),
+    ("recouped", "recoup"),
+    ("obtuse", "obtus"),
+    ("complimenting", "compliment"),
+    ("stagnating", "stagnat"),
+    ("demder", "demder"),
+    ("repairing", "repair"),
+    ("debility", "debl"),
+    ("pleader", "pleader"),
+    ("cruet", "cruet"),
+    ("vindicate", "vindic"),
+    ("bottoms", "bottom"),
+    ("caldcleugh", "caldcleugh"),
+    ("fico", "fico"),
+    ("jan", "jan"),
+    ("exhausts", "exhaust"),
+    ("attire", "attir"),
+    ("albion", "albion"),
+    ("behring", "behr"),
+    ("pardonable", "pardon"),
+    ("forcing", "forc"),
+    ("revolutionist", "revolutionist"),
+    ("basis", "basi"),
+    ("irony", "ironi"),
+    ("destined", "destin"),
+    ("prominently", "promin"),
+    ("blacklead", "blacklead"),
+    ("sabre", "sabr"),
+    ("advice", "advic"),
+    ("barbarism", "barbar"),
+    ("showed", "show"),
+    ("disciplinary", "disciplinari"),
+    ("stockbroker", "stockbrok"),
+    ("infinitesimal", "infinitesim"),
+    ("pleases", "pleas"),
+    ("irrelevant", "irrelev"),
+    ("banker", "banker"),
+    ("ever", "ever"),
+    ("crease", "creas"),
+    ("whites", "white"),
+    ("gruff", "gruff"),
+    ("parlour", "parlour"),
+    ("distrustful", "distrust"),
+    ("rancorous", "rancor"),
+    ("zeal", "zeal"),
+    ("shorter", "shorter"),
+    ("dockyards", "dockyard"),
+    ("means", "mean"),
+    ("anglicanism", "anglican"),
+    ("ambassadors", "ambassador"),
+    ("suppositions", "supposit"),
+    ("tilda", "tilda"),
+    ("survey", "survei"),
+    ("begotten", "begotten"),
+    ("draining", "drain"),
+    ("hist", "hist"),
+    ("glimmerings", "glimmer"),
+    ("huantamo", "huantamo"),
+    ("manifold", "manifold"),
+    ("licentiousness", "licenti"),
+    ("tolerable", "toler"),
+    ("partaken", "partaken"),
+    ("treadin", "treadin"),
+    ("mindedness", "minded"),
+    ("loft", "loft"),
+    ("forbearing", "forbear"),
+    ("solicitude", "solicitud"),
+    ("bigotry", "bigotri"),
+    ("assorted", "assort"),
+    ("leisurely", "leisur"),
+    ("vegetabl", "vegetabl"),
+    ("gravesend", "gravesend"),
+    ("wresting", "wrest"),
+    ("ponders", "ponder"),
+    ("treasured", "treasur"),
+    ("entrap", "entrap"),
+    ("times", "time"),
+    ("fetlock", "fetlock"),
+    ("castles", "castl"),
+    ("ladder", "ladder"),
+    ("ole", "ol"),
+    ("toyed", "toi"),
+    ("dragging", "drag"),
+    ("dignities", "digniti"),
+    ("vindictive", "vindict"),
+    ("rummaged", "rummag"),
+    ("mockingly", "mockingli"),
+    ("blamable", "blamabl"),
+    ("foolishly", "foolishli"),
+    ("scavengers", "scaveng"),
+    ("pocketbook", "pocketbook"),
+    ("decrepit", "decrepit"),
+    ("plagues", "plagu"),
+    ("furs", "fur"),
+    ("destinction", "destinct"),
+    ("kindest", "kindest"),
+    ("corkscrewed", "corkscrew"),
+    ("induces", "induc"),
+    ("perplexed", "perplex"),
+    ("raffaelites", "raffaelit"),
+    ("forard", "forard"),
+    ("passionate", "passion"),
+    ("excites", "excit"),
+    ("bard", "bard"),
+    ("destroyed", "destroi"),
+    ("blamed", "blame"),
+    ("warrens", "warren"),
+    ("revealing", "reveal"),
+    ("earths", "earth"),
+    ("enforcing", "enforc"),
+    ("goatskin", "goatskin"),
+    ("tha", "tha"),
+    ("hysterics", "hyster"),
+    ("exceedingly", "exceedingli"),
+    ("flickering", "flicker"),
+    ("boudoir", "boudoir"),
+    ("intelligibly", "intelligibli"),
+    ("dismount", "dismount"),
+    ("lizaveta", "lizaveta"),
+    ("comb", "comb"),
+    ("undesigning", "undesign"),
+    ("specialist", "specialist"),
+    ("slopes", "slope"),
+    ("heartsease", "heartseas"),
+    ("snow", "snow"),
+    ("encounters", "encount"),
+    ("jade", "jade"),
+    ("ribbons", "ribbon"),
+    ("folly", "folli"),
+    ("regardful", "regard"),
+    ("inventing", "invent"),
+    ("chalr", "chalr"),
+    ("funk", "funk"),
+    ("flood", "flood"),
+    ("appea", "appea"),
+    ("scintillate", "scintil"),
+    ("way", "wai"),
+    ("corners", "corner"),
+    ("feline", "felin"),
+    ("geographica", "geographica"),
+    ("pastry", "pastri"),
+    ("theories", "theori"),
+    ("moulding", "mould"),
+    ("audaciously", "audaci"),
+    ("homewards", "homeward"),
+    ("fancies", "fanc"),
+    ("aloof", "aloof"),
+    ("praya", "praya"),
+    ("dibabs", "dibab"),
+    ("threaded", "thread"),
+    ("firmament", "firmament"),
+    ("sulks", "sulk"),
+    ("cowcumber", "cowcumb"),
+    ("bow", "bow"),
+    ("placed", "place"),
+    ("radack", "radack"),
+    ("strode", "strode"),
+    ("facial", "facial"),
+    ("hew", "hew"),
+    ("obscene", "obscen"),
+    ("overturn", "overturn"),
+    ("sole", "sole"),
+    ("stimulation", "stimulaz"),
+    ("displacement", "displac"),
+    ("accommodate", "accommod"),
+    ("akad", "akad"),
+    ("assents", "assent"),
+    ("heroes", "hero"),
+    ("turnings", "turn"),
+    ("feasts", "feast"),
+    ("initiative", "initi"),
+    ("rapid", "rapid"),
+    ("mechanically", "mechan"),
+    ("nervous", "nervou"),
+    ("smarting", "smart"),
+    ("funny", "funni"),
+    ("posta", "posta"),
+    ("sub", "sub"),
+    ("earlybird", "earlybird"),
+    ("hyde", "hyde"),
+    ("speculating", "specul"),
+    ("prized", "prize"),
+    ("roonaway", "roonawai"),
+    ("chelsea", "chelsea"),
+    ("psychology", "psychologi"),
+    ("debris", "debri"),
+    ("tenacious", "tenaci"),
+    ("sedateness", "sedat"),
+    ("torpid", "torpid"),
+    ("decrepitude", "decrepitud"),
+    ("adopted", "adopt"),
+    ("periagua", "periagua"),
+    ("feud", "feud"),
+    ("veo", "veo"),
+    ("colymbetes", "colymbet"),
+    ("mugeres", "muger"),
+    ("holyhead", "holyhead"),
+    ("palaeologos", "palaeologo"),
+    ("thicken", "thicken"),
+    ("hieroglyphics", "hieroglyph"),
+    ("grubble", "grubbl"),
+    ("smock", "smock"),
+    ("decoy", "decoi"),
+    ("adwiser", "adwis"),
+    ("escaped", "escap"),
+    ("vexation", "vexaz"),
+    ("sully", "sulli"),
+    ("bryanstone", "bryanston"),
+    ("ecstatic", "ecstat"),
+    ("narrowed", "narrow"),
+    ("justitia", "justitia"),
+    ("indica", "indica"),
+    ("snittle", "snittl"),
+    ("volcanoes", "volcano"),
+    ("divulge", "divulg"),
+    ("quizzing", "quizz"),
+    ("comprehensible", "comprehens"),
+    ("arnold", "arnold"),
+    ("tolling", "toll"),
+    ("denuding", "denud"),
+    ("emitted", "emit"),
+    ("wont", "wont"),
+    ("spacious", "spaciou"),
+    ("developing", "develop"),
+    ("spells", "spell"),
+    ("brims", "brim"),
+    ("breast", "breast"),
+    ("hair", "hair"),
+    ("abused", "abus"),
+    ("unconstrained", "unconstrain"),
+    ("passively", "passiv"),
+    ("unturned", "unturn"),
+    ("ascent", "ascent"),
+    ("junk", "junk"),
+    ("proposition", "proposit"),
+    ("surname", "surnam"),
+    ("steppe", "stepp"),
+    ("dreamer", "dreamer"),
+    ("affection", "affect"),
+    ("submitted", "submit"),
+    ("mouthed", "mouth"),
+    ("board", "board"),
+    ("carp", "carp"),
+    ("threat", "threat"),
+    ("phraseology", "phraseologi"),
+    ("merely", "mere"),
+    ("hurry", "hurri"),
+    ("endroits", "endroit"),
+    ("despondence", "despond"),
+    ("mistiness", "misti"),
+    ("inferiority", "inferior"),
+    ("kotzebue", "kotzebu"),
+    ("possessing", "possess"),
+    ("lopez", "lopez"),
+    ("aged", "ag"),
+    ("wines", "wine"),
+    ("limnaea", "limnaea"),
+    ("swig", "swig"),
+    ("trumps", "trump"),
+    ("wheeling", "wheel"),
+    ("venting", "vent"),
+    ("underwent", "underw"),
+    ("idling", "idl"),
+    ("yagouaroundi", "yagouaroundi"),
+    ("jerusalem", "jerusalem"),
+    ("excepted", "except"),
+    ("imposter", "impost"),
+    ("existent", "exist"),
+    ("cote", "cote"),
+    ("manufacturers", "manufactur"),
+    ("iniquities", "iniqu"),
+    ("edifices", "edific"),
+    ("scorn", "scorn"),
+    ("proposed", "propos"),
+    ("unenviable", "unenvi"),
+    ("jacob", "jacob"),
+    ("effigy", "effigi"),
+    ("tuning", "tune"),
+    ("various", "variou"),
+    ("pokers", "poker"),
+    ("brigands", "brigand"),
+    ("arabian", "arabian"),
+    ("york", "york"),
+    ("ranked", "rank"),
+    ("smallclothes", "smallcloth"),
+    ("wayward", "wayward"),
+    ("feign", "feign"),
+    ("incongruity", "incongru"),
+    ("intelligence", "intellig"),
+    ("melchisedech", "melchisedech"),
+    ("goatsucker", "goatsuck"),
+    ("aired", "air"),
+    ("murdered", "murder"),
+    ("lawns", "lawn"),
+    ("bonds", "bond"),
+    ("starvin", "starvin"),
+    ("foundling", "foundl"),
+    ("greenleaf", "greenleaf"),
+    ("nonchalant", "nonchal"),
+    ("francisco", "francisco"),
+    ("saved", "save"),
+    ("canoes", "cano"),
+    ("shibboleth", "shibboleth"),
+    ("lyres", "lyre"),
+    ("blossoming", "blossom"),
+    ("devouring", "devour"),
+    ("miscalled", "miscal"),
+    ("humoured", "humour"),
+    ("accosted", "accost"),
+    ("calculus", "calculu"),
+    ("consummation", "consummaz"),
+    ("palms", "palm"),
+    ("nerves", "nerv"),
+    ("unprevaricating", "unprevar"),
+    ("varieties", "varieti"),
+    ("authorities", "author"),
+    ("homeward", "homeward"),
+    ("inaugurate", "inaugur"),
+    ("closest", "closest"),
+    ("furze", "furz"),
+    ("durer", "durer"),
+    ("exhorts", "exhort"),
+    ("surgeons", "surgeon"),
+    ("progressives", "progress"),
+    ("incursion", "incurs"),
+    ("risible", "risibl"),
+    ("establish", "establish"),
+    ("sanguinary", "sanguinari"),
+    ("brazils", "brazil"),
+    ("devilish", "devilish"),
+    ("straggled", "straggl"),
+    ("started", "start"),
+    ("outerest", "outerest"),
+    ("barks", "bark"),
+    ("toad", "toad"),
+    ("equity", "equiti"),
+    ("precedents", "preced"),
+    ("religiously", "religi"),
+    ("tormenting", "torment"),
+    ("sleepless", "sleepless"),
+    ("oop", "oop"),
+    ("chapters", "chapter"),
+    ("stores", "store"),
+    ("torpor", "torpor"),
+    ("relaxing", "relax"),
+    ("unawares", "unawar"),
+    ("familiars", "familiar"),
+    ("epeira", "epeira"),
+    ("hesitated", "hesit"),
+    ("invented", "invent"),
+    ("exportation", "exportaz"),
+    ("theridion", "theridion"),
+    ("unimagined", "unimagin"),
+    ("tam", "tam"),
+    ("too", "too"),
+    ("tolled", "toll"),
+    ("tigerish", "tigerish"),
+    ("scribble", "scribbl"),
+    ("miranda", "miranda"),
+    ("clerks", "clerk"),
+    ("noun", "noun"),
+    ("noddy", "noddi"),
+    ("bedaubed", "bedaub"),
+    ("scentless", "scentless"),
+    ("plait", "plait"),
+    ("hart", "hart"),
+    ("avestruz", "avestruz"),
+    ("classed", "class"),
+    ("lettuce", "lettuc"),
+    ("prays", "prai"),
+    ("trophy", "trophi"),
+    ("dunghill", "dunghil"),
+    ("except", "except"),
+    ("staircase", "staircas"),
+    ("lengthen", "lengthen"),
+    ("ghosts", "ghost"),
+    ("skirts", "skirt"),
+    ("common", "common"),
+    ("charade", "charad"),
+    ("heartlessly", "heartlessli"),
+    ("imparted", "impart"),
+    ("grin", "grin"),
+    ("berlin", "berlin"),
+    ("representations", "representaz"),
+    ("whipcord", "whipcord"),
+    ("banked", "bank"),
+    ("alford", "alford"),
+    ("notions", "notion"),
+    ("rylstone", "rylston"),
+    ("teller", "teller"),
+    ("unblemished", "unblemish"),
+    ("tabby", "tabbi"),
+    ("streamer", "streamer"),
+    ("cristal", "cristal"),
+    ("sense", "sens"),
+    ("deity", "deiti"),
+    ("branch", "branch"),
+    ("trade", "trade"),
+    ("name", "name"),
+    ("dagestan", "dagestan"),
+    ("enfolded", "enfold"),
+    ("nokolay", "nokolai"),
+    ("robbery", "robberi"),
+    ("holies", "holi"),
+    ("admit", "admit"),
+    ("whistle", "whistl"),
+    ("warded", "ward"),
+    ("equidistant", "equidist"),
+    ("accomplishments", "accomplish"),
+    ("inch", "inch"),
+    ("million", "million"),
+    ("backwards", "backward"),
+    ("exploding", "explod"),
+    ("jelly", "jelli"),
+    ("tion", "tion"),
+    ("parabola", "parabola"),
+    ("buil", "buil"),
+    ("skurry", "skurri"),
+    ("palace", "palac"),
+    ("cassio", "cassio"),
+    ("belle", "bell"),
+    ("peas", "pea"),
+    ("peopled", "peopl"),
+    ("disregarded", "disregard"),
+    ("chronicled", "chronicl"),
+    ("trial", "trial"),
+    ("disgustingly", "disgustingli"),
+    ("uproot", "uproot"),
+    ("luggage", "luggag"),
+    ("babel", "babel"),
+    ("fitz", "fitz"),
+    ("treble", "trebl"),
+    ("houses", "hous"),
+    ("lvii", "lvii"),
+    ("certain", "certain"),
+    ("flocked", "flock"),
+    ("guttered", "gutter"),
+    ("unsettling", "unsettl"),
+    ("tunes", "tune"),
+    ("broad", "broad"),
+    ("crouching", "crouch"),
+    ("persistent", "persist"),
+    ("tooth", "tooth"),
+    ("crags", "crag"),
+    ("proclaim", "proclaim"),
+    ("cartridges", "cartridg"),
+    ("skip", "skip"),
+    ("levelle", "levell"),
+    ("surprising", "surpris"),
+    ("tasted", "tast"),
+    ("teneriffe", "teneriff"),
+    ("verify", "verifi"),
+    ("sterility", "steril"),
+    ("vagabondizing", "vagabond"),
+    ("journeying", "journei"),
+    ("stubbornly", "stubbornli"),
+    ("draperies", "draperi"),
+    ("imitate", "imit"),
+    ("orsono", "orsono"),
+    ("cannibals", "cannib"),
+    ("aunt", "aunt"),
+    ("etymology", "etymologi"),
+    ("steepest", "steepest"),
+    ("porpoises", "porpois"),
+    ("dick", "dick"),
+    ("fathomless", "fathomless"),
+    ("grasshoppers", "grasshopp"),
+    ("ingenuity", "ingenu"),
+    ("malignantly", "malignantli"),
+    ("brunswick", "brunswick"),
+    ("crustacea", "crustacea"),
+    ("wiolinceller", "wiolincel"),
+    ("rate", "rate"),
+    ("magnate", "magnat"),
+    ("borreria", "borreria"),
+    ("levities", "leviti"),
+    ("capacious", "capaci"),
+    ("stupider", "stupid"),
+    ("meet", "meet"),
+    ("claimed", "claim"),
+    ("tallow", "tallow"),
+    ("amiably", "amiabl"),
+    ("landlord", "landlord"),
+    ("hobgoblin", "hobgoblin"),
+    ("wasp", "wasp"),
+    ("emphasise", "emphasis"),
+    ("shooters", "shooter"),
+    ("rolled", "roll"),
+    ("crossing", "cross"),
+    ("outraging", "outrag"),
+    ("steering", "steer"),
+    ("supernatural", "supernatur"),
+    ("upturned", "upturn"),
+    ("music", "music"),
+    ("lazoed", "lazo"),
+    ("fridays", "fridai"),
+    ("fencing", "fenc"),
+    ("undoubted", "undoubt"),
+    ("coleridge", "coleridg"),
+    ("attaching", "attach"),
+    ("uncompromising", "uncompromis"),
+    ("epsom", "epsom"),
+    ("thorny", "thorni"),
+    ("betrayed", "betrai"),
+    ("possible", "possibl"),
+    ("worded", "word"),
+    ("sequestered", "sequest"),
+    ("propitiated", "propiti"),
+    ("specific", "specif"),
+    ("insinuated", "insinu"),
+    ("foulest", "foulest"),
+    ("transported", "transport"),
+    ("nestor", "nestor"),
+    ("trowel", "trowel"),
+    ("raffaelitism", "raffaelit"),
+    ("compatriots", "compatriot"),
+    ("immeasurable", "immeasur"),
+    ("case", "case"),
+    ("inebriety", "inebrieti"),
+    ("moodily", "moodili"),
+    ("lancashire", "lancashir"),
+    ("clashing", "clash"),
+    ("xxxix", "xxxix"),
+    ("compromised", "compromis"),
+    ("taps", "tap"),
+    ("afresh", "afresh"),
+    ("sewing", "sew"),
+    ("strolled", "stroll"),
+    ("steadfast", "steadfast"),
+    ("unchecked", "uncheck"),
+    ("pure", "pure"),
+    ("interminable", "intermin"),
+    ("hemisphere", "hemispher"),
+    ("commences", "commenc"),
+    ("coarsest", "coarsest"),
+    ("releases", "releas"),
+    ("sealed", "seal"),
+    ("egress", "egress"),
+    ("reciprocate", "reciproc"),
+    ("crackle", "crackl"),
+    ("burst", "burst"),
+    ("starte", "start"),
+    ("balalaika", "balalaika"),
+    ("ladyship", "ladyship"),
+    ("banged", "bang"),
+    ("cutter", "cutter"),
+    ("releasing", "releas"),
+    ("befitting", "befit"),
+    ("shirk", "shirk"),
+    ("mischance", "mischanc"),
+    ("boomerang", "boomerang"),
+    ("presidents", "presid"),
+    ("pickpocket", "pickpocket"),
+    ("sighed", "sigh"),
+    ("pani", "pani"),
+    ("singularly", "singularli"),
+    ("furrowed", "furrow"),
+    ("purer", "purer"),
+    ("admirable", "admir"),
+    ("depositing", "deposit"),
+    ("antlers", "antler"),
+    ("incomplete", "incomplet"),
+    ("accelerated", "acceler"),
+    ("particle", "particl"),
+    ("ups", "up"),
+    ("spake", "spake"),
+    ("warrants", "warrant"),
+    ("breached", "breach"),
+    ("reproach", "reproach"),
+    ("grease", "greas"),
+    ("aggeravating", "aggerav"),
+    ("measurement", "measur"),
+    ("lagoons", "lagoon"),
+    ("disengage", "disengag"),
+    ("invencion", "invencion"),
+    ("unpractised", "unpractis"),
+    ("converts", "convert"),
+    ("gets", "get"),
+    ("assurance", "assur"),
+    ("nodded", "nod"),
+    ("insecurity", "insecur"),
+    ("numskull", "numskul"),
+    ("dispersed", "dispers"),
+    ("magically", "magic"),
+    ("adducing", "adduc"),
+    ("upbearing", "upbear"),
+    ("stamford", "stamford"),
+    ("campos", "campo"),
+    ("soda", "soda"),
+    ("parishioner", "parishion"),
+    ("ores", "or"),
+    ("attach", "attach"),
+    ("inflamed", "inflam"),
+    ("ri", "ri"),
+    ("deadliest", "deadliest"),
+    ("chut", "chut"),
+    ("cetera", "cetera"),
+    ("coverture", "covertur"),
+    ("fanaticism", "fanatic"),
+    ("revolutionized", "revolution"),
+    ("tribulation", "tribulaz"),
+    ("teach", "teach"),
+    ("debauch", "debauch"),
+    ("respected", "respect"),
+    ("pores", "pore"),
+    ("phlegmatic", "phlegmat"),
+    ("heartache", "heartach"),
+    ("leap", "leap"),
+    ("jingle", "jingl"),
+    ("piling", "pile"),
+    ("contrary", "contrari"),
+    ("fatherly", "fatherli"),
+    ("poured", "pour"),
+    ("pulperia", "pulperia"),
+    ("irritably", "irrit"),
+    ("equipage", "equipag"),
+    ("sordid", "sordid"),
+    ("entertaining", "entertain"),
+    ("volatilized", "volatil"),
+    ("outsides", "outsid"),
+    ("hundredweight", "hundredweight"),
+    ("confuses", "confus"),
+    ("archipelago", "archipelago"),
+    ("snoring", "snore"),
+    ("stream", "stream"),
+    ("dotard", "dotard"),
+    ("modest", "modest"),
+    ("superiority", "superior"),
+    ("ursula", "ursula"),
+    ("himsel", "himsel"),
+    ("tripping", "trip"),
+    ("subduer", "subduer"),
+    ("kolya", "kolya"),
+    ("lavishly", "lavishli"),
+    ("erroneous", "erron"),
+    ("paleness", "pale"),
+    ("mass", "mass"),
+    ("reconnoitre", "reconnoitr"),
+    ("rise", "rise"),
+    ("streamlets", "streamlet"),
+    ("misanthropical", "misanthrop"),
+    ("cephalopoda", "cephalopoda"),
+    ("shoalness", "shoal"),
+    ("resemble", "resembl"),
+    ("don", "don"),
+    ("evergreens", "evergreen"),
+    ("amber", "amber"),
+    ("thrusting", "thrust"),
+    ("honours", "honour"),
+    ("stars", "star"),
+    ("civilized", "civil"),
+    ("swellings", "swell"),
+    ("deputed", "deput"),
+    ("implacably", "implac"),
+    ("loggerheads", "loggerhead"),
+    ("infamy", "infami"),
+    ("aesthetic", "aesthet"),
+    ("rudest", "rudest"),
+    ("stertorous", "stertor"),
+    ("govern", "govern"),
+    ("mystery", "mysteri"),
+    ("footing", "foot"),
+    ("mate", "mate"),
+    ("remitting", "remit"),
+    ("pedlar", "pedlar"),
+    ("constituted", "constitut"),
+    ("transportal", "transport"),
+    ("operations", "operaz"),
+    ("unpunctual", "unpunctu"),
+    ("widow", "widow"),
+    ("somnolent", "somnol"),
+    ("gonzales", "gonzal"),
+    ("elicits", "elicit"),
+    ("hinds", "hind"),
+    ("petitioners", "petition"),
+    ("hotels", "hotel"),
+    ("shouts", "shout"),
+    ("dispassionate", "dispassion"),
+    ("entanglement", "entangl"),
+    ("colonnades", "colonnad"),
+    ("cleavage", "cleavag"),
+    ("heraldic", "herald"),
+    ("merriest", "merriest"),
+    ("condolence", "condol"),
+    ("impromptu", "impromptu"),
+    ("decreased", "decreas"),
+    ("warmer", "warmer"),
+    ("michaelmas", "michaelma"),
+    ("adieu", "adieu"),
+    ("banner", "banner"),
+    ("multiplication", "multiplicaz"),
+    ("coextensive", "coextens"),
+    ("stole", "stole"),
+    ("nag", "nag"),
+    ("soon", "soon"),
+    ("boredom", "boredom"),
+    ("ledgers", "ledger"),
+    ("ferreted", "ferret"),
+    ("disinterested", "disinterest"),
+    ("shut", "shut"),
+    ("bail", "bail"),
+    ("furniture", "furnitur"),
+    ("follerers", "foller"),
+    ("spitefulness", "spite"),
+    ("expatiated", "expati"),
+    ("smacked", "smack"),
+    ("speaketh", "speaketh"),
+    ("emigrated", "emigr"),
+    ("horridly", "horridli"),
+    ("tenacity", "tenac"),
+    ("overlooked", "overlook"),
+    ("snarled", "snarl"),
+    ("protracted", "protract"),
+    ("pencil", "pencil"),
+    ("sewerage", "sewerag"),
+    ("stormed", "storm"),
+    ("foresees", "forese"),
+    ("becalmed", "becalm"),
+    ("neaming", "neam"),
+    ("clang", "clang"),
+    ("disclose", "disclos"),
+    ("ross", "ross"),
+    ("concave", "concav"),
+    ("xvii", "xvii"),
+    ("unquiet", "unquiet"),
+    ("sparring", "spar"),
+    ("tingling", "tingl"),
+    ("combusted", "combust"),
+    ("bestowing", "bestow"),
+    ("scowls", "scowl"),
+    ("lived", "live"),
+    ("albuminous", "albumin"),
+    ("diego", "diego"),
+    ("avaunt", "avaunt"),
+    ("bystanders", "bystand"),
+    ("peron", "peron"),
+    ("proposal", "propos"),
+    ("inhospitable", "inhospit"),
+    ("unloosed", "unloos"),
+    ("occupancy", "occup"),
+    ("lingerings", "linger"),
+    ("russian", "russian"),
+    ("becoming", "becom"),
+    ("disapprove", "disapprov"),
+    ("unsafe", "unsaf"),
+    ("violin", "violin"),
+    ("crawls", "crawl"),
+    ("deposit", "deposit"),
+    ("inferiorities", "inferior"),
+    ("pas", "pa"),
+    ("spittoons", "spittoon"),
+    ("baring", "bare"),
+    ("coiners", "coiner"),
+    ("treatment", "treatment"),
+    ("forefinger", "forefing"),
+    ("whimsically", "whimsic"),
+    ("osseous", "osseou"),
+    ("yawl", "yawl"),
+    ("relaxations", "relaxaz"),
+    ("doth", "doth"),
+    ("drinkings", "drink"),
+    ("omniscience", "omnisci"),
+    ("unredeemed", "unredeem"),
+    ("rana", "rana"),
+    ("islanders", "island"),
+    ("goldsmith", "goldsmith"),
+    ("triturated", "tritur"),
+    ("reduced", "reduc"),
+    ("monosyllables", "monosyl"),
+    ("apparel", "apparel"),
+    ("dwelling", "dwell"),
+    ("spelling", "spell"),
+    ("moods", "mood"),
+    ("ruler", "ruler"),
+    ("temperature", "temperatur"),
+    ("deviated", "deviat"),
+    ("eyesore", "eyesor"),
+    ("propagates", "propag"),
+    ("lxiii", "lxiii"),
+    ("lair", "lair"),
+    ("ruining", "ruin"),
+    ("greatly", "greatli"),
+    ("quadrupeds", "quadrup"),
+    ("game", "game"),
+    ("christen", "christen"),
+    ("visit", "visit"),
+    ("goad", "goad"),
+    ("election", "elect"),
+    ("edifice", "edific"),
+    ("sculptured", "sculptur"),
+    ("unmeaning", "unmean"),
+    ("elaboration", "elaboraz"),
+    ("extending", "extend"),
+    ("wisitations", "wisitaz"),
+    ("requite", "requit"),
+    ("discoloured", "discolour"),
+    ("shoon", "shoon"),
+    ("lute", "lute"),
+    ("convert", "convert"),
+    ("rebellious", "rebelli"),
+    ("bait", "bait"),
+    ("officiates", "offici"),
+    ("consist", "consist"),
+    ("losers", "loser"),
+    ("compete", "compet"),
+    ("polit", "polit"),
+    ("compositae", "composita"),
+    ("great", "great"),
+    ("individualism", "individu"),
+    ("ninepence", "ninep"),
+    ("tilted", "tilt"),
+    ("tent", "tent"),
+    ("loud", "loud"),
+    ("hopeless", "hopeless"),
+    ("rehearse", "rehears"),
+    ("confines", "confin"),
+    ("tearfully", "tearfulli"),
+    ("fortifications", "fortificaz"),
+    ("noodle", "noodl"),
+    ("aplysia", "aplysia"),
+    ("cufre", "cufr"),
+    ("hey", "hei"),
+    ("gamekeepers", "gamekeep"),
+    ("rapidity", "rapid"),
+    ("statue", "statu"),
+    ("genie", "geni"),
+    ("annuity", "annuiti"),
+    ("wrapping", "wrap"),
+    ("hiss", "hiss"),
+    ("tilled", "till"),
+    ("dances", "danc"),
+    ("observance", "observ"),
+    ("oo", "oo"),
+    ("subdues", "subdu"),
+    ("sheds", "shed"),
+    ("framing", "frame"),
+    ("pyrocephalus", "pyrocephalu"),
+    ("bitterness", "bitter"),
+    ("disapprobation", "disapprobaz"),
+    ("berries", "berri"),
+    ("enjoying", "enjoi"),
+    ("holden", "holden"),
+    ("understrapper", "understrapp"),
+    ("transgress", "transgress"),
+    ("repetitions", "repetit"),
+    ("friendliness", "friendli"),
+    ("dream", "dream"),
+    ("sugarscraps", "sugarscrap"),
+    ("finishing", "finish"),
+    ("antelopes", "antelop"),
+    ("limpid", "limpid"),
+    ("therein", "therein"),
+    ("upward", "upward"),
+    ("vater", "vater"),
+    ("tours", "tour"),
+    ("sentry", "sentri"),
+    ("breathings", "breath"),
+    ("curragh", "curragh"),
+    ("ont", "ont"),
+    ("leguminosae", "leguminosa"),
+    ("although", "although"),
+    ("unheard", "unheard"),
+    ("prionotus", "prionotu"),
+    ("decompose", "decompos"),
+    ("eternally", "etern"),
+    ("inculcate", "inculc"),
+    ("unfriendly", "unfriendli"),
+    ("ministership", "ministership"),
+    ("given", "given"),
+    ("sweetest", "sweetest"),
+    ("hops", "hop"),
+    ("wos", "wo"),
+    ("aura", "aura"),
+    ("landmark", "landmark"),
+    ("sedes", "sede"),
+    ("irascibly", "irascibli"),
+    ("reviling", "revil"),
+    ("multitude", "multitud"),
+    ("bushel", "bushel"),
+    ("resided", "resid"),
+    ("cuming", "cume"),
+    ("adduced", "adduc"),
+    ("tattoo", "tattoo"),
+    ("donnez", "donnez"),
+    ("banished", "banish"),
+    ("develops", "develop"),
+    ("morocco", "morocco"),
+    ("furtively", "furtiv"),
+    ("governessing", "gover"),
+    ("teachings", "teach"),
+    ("easily", "easili"),
+    ("seawards", "seaward"),
+    ("america", "america"),
+    ("idiosyncrasy", "idiosyncrasi"),
+    ("beliefs", "belief"),
+    ("vigorous", "vigor"),
+    ("plucks", "pluck"),
+    ("journalistic", "journalist"),
+    ("exalt", "exalt"),
+    ("upas", "upa"),
+    ("brilliance", "brillianc"),
+    ("unclouded", "uncloud"),
+    ("affectation", "affectaz"),
+    ("olfersia", "olfersia"),
+    ("plaintiveness", "plaintiv"),
+    ("chere", "chere"),
+    ("howeve", "howev"),
+    ("conjunction", "conjunct"),
+    ("swordsmanship", "swordsmanship"),
+    ("unparalleled", "unparallel"),
+    ("coolness", "cool"),
+    ("doctor", "doctor"),
+    ("pease", "peas"),
+    ("abjure", "abjur"),
+    ("brought", "brought"),
+    ("eradicated", "erad"),
+    ("bench", "bench"),
+    ("degenerate", "degener"),
+    ("sticky", "sticki"),
+    ("compter", "compter"),
+    ("transgressing", "transgress"),
+    ("necessity", "necess"),
+    ("unmitigated", "unmitig"),
+    ("thereabout", "thereabout"),
+    ("circling", "circl"),
+    ("pollutions", "pollut"),
+    ("defeated", "defeat"),
+    ("packer", "packer"),
+    ("hove", "hove"),
+    ("wearing", "wear"),
+    ("speediest", "speediest"),
+    ("frisking", "frisk"),
+    ("access", "access"),
+    ("her", "her"),
+    ("grind", "grind"),
+    ("republican", "republican"),
+    ("honds", "hond"),
+    ("richness", "rich"),
+    ("tributary", "tributari"),
+    ("waxed", "wax"),
+    ("knowest", "knowest"),
+    ("graciously", "gracious"),
+    ("eagle", "eagl"),
+    ("spoonful", "spoon"),
+    ("portentous", "portent"),
+    ("soizable", "soizabl"),
+    ("lazarus", "lazaru"),
+    ("friendship", "friendship"),
+    ("designation", "designaz"),
+    ("centred", "centr"),
+    ("yesday", "yesdai"),
+    ("inspirit", "inspirit"),
+    ("visions", "vision"),
+    ("diddler", "diddler"),
+    ("hansome", "hansom"),
+    ("trifle", "trifl"),
+    ("abandoning", "abandon"),
+    ("padlocking", "padlock"),
+    ("brazen", "brazen"),
+    ("passive", "passiv"),
+    ("talked", "talk"),
+    ("veteran", "veteran"),
+    ("unpleasantness", "unpleas"),
+    ("convinces", "convinc"),
+    ("iguana", "iguana"),
+    ("vied", "vi"),
+    ("passerby", "passerbi"),
+    ("imperceptibly", "imperceptibli"),
+    ("certifying", "certifi"),
+    ("affects", "affect"),
+    ("admiringly", "admiringli"),
+    ("proportions", "proport"),
+    ("sweeper", "sweeper"),
+    ("window", "window"),
+    ("fishermen", "fishermen"),
+    ("patrimony", "patrimoni"),
+    ("lolling", "loll"),
+    ("lofty", "lofti"),
+    ("stabling", "stabl"),
+    ("bays", "bai"),
+    ("converged", "converg"),
+    ("gratification", "gratificaz"),
+    ("coddling", "coddl"),
+    ("pampaean", "pampaean"),
+    ("boxes", "box"),
+    ("poster", "poster"),
+    ("introductions", "introduct"),
+    ("flew", "flew"),
+    ("phanaeus", "phanaeu"),
+    ("embargo", "embargo"),
+    ("beauty", "beauti"),
+    ("radiated", "radiat"),
+    ("pyke", "pyke"),
+    ("piping", "pipe"),
+    ("epistle", "epistl"),
+    ("humboldt", "humboldt"),
+    ("shoulders", "shoulder"),
+    ("corfield", "corfield"),
+    ("zigzag", "zigzag"),
+    ("eulogistic", "eulogist"),
+    ("dispossess", "dispossess"),
+    ("dispute", "disput"),
+    ("marylebone", "marylebon"),
+    ("joyousness", "joyous"),
+    ("patient", "patient"),
+    ("vast", "vast"),
+    ("carrancha", "carrancha"),
+    ("felled", "fell"),
+    ("hubbard", "hubbard"),
+    ("thrush", "thrush"),
+    ("unaccountably", "unaccount"),
+    ("roarer", "roarer"),
+    ("resumed", "resum"),
+    ("herself", "herself"),
+    ("othered", "other"),
+    ("expire", "expir"),
+    ("eloquent", "eloqu"),
+    ("expanses", "expans"),
+    ("seychelles", "seychell"),
+    ("schemer", "schemer"),
+    ("eliminate", "elimin"),
+    ("dazzler", "dazzler"),
+    ("space", "space"),
+    ("friths", "frith"),
+    ("eclipse", "eclips"),
+    ("roadside", "roadsid"),
+    ("staved", "stave"),
+    ("meetings", "meet"),
+    ("ventana", "ventana"),
+    ("interrogatories", "interrogatori"),
+    ("phrase", "phrase"),
+    ("inflammable", "inflamm"),
+    ("casuchas", "casucha"),
+    ("trickery", "trickeri"),
+    ("hinder", "hinder"),
+    ("elapsed", "elaps"),
+    ("obleege", "obleeg"),
+    ("orth", "orth"),
+    ("alleging", "alleg"),
+    ("properly", "properli"),
+    ("facilities", "facil"),
+    ("solstice", "solstic"),
+    ("screwing", "screw"),
+    ("theeself", "theeself"),
+    ("succeeding", "succeed"),
+    ("relieving", "reliev"),
+    ("paid", "paid"),
+    ("inwardly", "inwardli"),
+    ("ack", "ack"),
+    ("retrievable", "retriev"),
+    ("ambient", "ambient"),
+    ("imitative", "imit"),
+    ("procrastinates", "procrastin"),
+    ("interfered", "interf"),
+    ("rudimentary", "rudimentari"),
+    ("campo", "campo"),
+    ("fictitious", "fictiti"),
+    ("cashup", "cashup"),
+    ("riggers", "rigger"),
+    ("transverse", "transvers"),
+    ("islets", "islet"),
+    ("scantier", "scantier"),
+    ("obliterating", "obliter"),
+    ("municipalities", "municip"),
+    ("affinity", "affin"),
+    ("youthfully", "youthfulli"),
+    ("unembarrassed", "unembarrass"),
+    ("saturnine", "saturnin"),
+    ("melanops", "melanop"),
+    ("tiv", "tiv"),
+    ("scout", "scout"),
+    ("impassioned", "impass"),
+    ("perversion", "pervers"),
+    ("glowing", "glow"),
+    ("shamelessly", "shamelessli"),
+    ("deodara", "deodara"),
+    ("immortal", "immort"),
+    ("design", "design"),
+    ("unmistakable", "unmistak"),
+    ("hostility", "hostil"),
+    ("d", "d"),
+    ("plaintiff", "plaintiff"),
+    ("accedes", "acced"),
+    ("incommoded", "incommod"),
+    ("intractable", "intract"),
+    ("swindled", "swindl"),
+    ("specks", "speck"),
+    ("polecat", "polecat"),
+    ("stares", "stare"),
+    ("lyell", "lyell"),
+    ("cheer", "cheer"),
+    ("levelled", "level"),
+    ("rancho", "rancho"),
+    ("apprehension", "apprehens"),
+    ("promoter", "promot"),
+    ("hewers", "hewer"),
+    ("travel", "travel"),
+    ("acquittal", "acquitt"),
+    ("abel", "abel"),
+    ("weaving", "weav"),
+    ("mistrusted", "mistrust"),
+    ("greatl", "greatl"),
+    ("patelliform", "patelliform"),
+    ("familiarity", "familiar"),
+    ("race", "race"),
+    ("hummingbirds", "hummingbird"),
+    ("smothers", "smother"),
+    ("chanuncillo", "chanuncillo"),
+    ("contributes", "contribut"),
+    ("imitations", "imitaz"),
+    ("ies", "i"),
+    ("invitations", "invitaz"),
+    ("physiognomist", "physiognomist"),
+    ("flippant", "flippant"),
+    ("savage", "savag"),
+    ("salts", "salt"),
+    ("bob", "bob"),
+    ("reaches", "reach"),
+    ("reappear", "reappear"),
+    ("cardinal", "cardin"),
+    ("manufactories", "manufactori"),
+    ("tuberculata", "tuberculata"),
+    ("gold", "gold"),
+    ("frigate", "frigat"),
+    ("occurred", "occur"),
+    ("startling", "startl"),
+    ("dispatching", "dispatch"),
+    ("wreck", "wreck"),
+    ("curbed", "curb"),
+    ("average", "averag"),
+    ("ladders", "ladder"),
+    ("losing", "lose"),
+    ("performance", "perform"),
+    ("form", "form"),
+    ("instantaneous", "instantan"),
+    ("amphitheatre", "amphitheatr"),
+    ("osorno", "osorno"),
+    ("duly", "duli"),
+    ("donny", "donni"),
+    ("chaps", "chap"),
+    ("gentlefolk", "gentlefolk"),
+    ("object", "object"),
+    ("number", "number"),
+    ("pelting", "pelt"),
+    ("forlornly", "forlornli"),
+    ("smoking", "smoke"),
+    ("five", "five"),
+    ("carolling", "carol"),
+    ("goings", "go"),
+    ("inquests", "inquest"),
+    ("slumber", "slumber"),
+    ("frightens", "frighten"),
+    ("matted", "mat"),
+    ("patriotism", "patriot"),
+    ("solace", "solac"),
+    ("haberdasher", "haberdash"),
+    ("tortured", "tortur"),
+    ("emboldened", "embolden"),
+    ("stipulations", "stipulaz"),
+    ("chose", "chose"),
+    ("broodingly", "broodingli"),
+    ("condole", "condol"),
+    ("mond", "mond"),
+    ("dose", "dose"),
+    ("embroidered", "embroid"),
+    ("defining", "defin"),
+    ("blackheath", "blackheath"),
+    ("sightless", "sightless"),
+    ("dishes", "dish"),
+    ("lighting", "light"),
+    ("passers", "passer"),
+    ("archipelagoes", "archipelago"),
+    ("accumulated", "accumul"),
+    ("freemason", "freemason"),
+    ("eudromia", "eudromia"),
+    ("martyr", "martyr"),
+    ("posthouse", "posthous"),
+    ("dangerously", "danger"),
+    ("hayes", "haye"),
+    ("islands", "island"),
+    ("interchange", "interchang"),
+    ("boulong", "boulong"),
+    ("gulf", "gulf"),
+    ("promises", "promis"),
+    ("ovules", "ovul"),
+    ("crotchets", "crotchet"),
+    ("cadesses", "cadess"),
+    ("actual", "actual"),
+    ("feels", "feel"),
+    ("pleasantry", "pleasantri"),
+    ("aux", "aux"),
+    ("annelidous", "annelid"),
+    ("stamens", "stamen"),
+    ("squalls", "squall"),
+    ("headed", "head"),
+    ("foreshortened", "foreshorten"),
+    ("alluring", "allur"),
+    ("kingston", "kingston"),
+    ("rapture", "raptur"),
+    ("scuffling", "scuffl"),
+    ("gentility", "gentil"),
+    ("oughts", "ought"),
+    ("undetermined", "undetermin"),
+    ("swerve", "swerv"),
+    ("goldfinches", "goldfinch"),
+    ("tellee", "telle"),
+    ("revisit", "revisit"),
+    ("maturely", "matur"),
+    ("defect", "defect"),
+    ("worlds", "world"),
+    ("drowsily", "drowsili"),
+    ("lulls", "lull"),
+    ("vitals", "vital"),
+    ("vigilantly", "vigilantli"),
+    ("donkey", "donkei"),
+    ("materials", "materi"),
+    ("irishman", "irishman"),
+    ("milkman", "milkman"),
+    ("recado", "recado"),
+    ("darken", "darken"),
+    ("squeedged", "squeedg"),
+    ("healthier", "healthier"),
+    ("unpitying", "unpiti"),
+    ("without", "without"),
+    ("aeschylus", "aeschylu"),
+    ("condemn", "condemn"),
+    ("untaxed", "untax"),
+    ("paint", "paint"),
+    ("birch", "birch"),
+    ("nervousness", "nervous"),
+    ("bassoon", "bassoon"),
+    ("knife", "knife"),
+    ("she", "she"),
+    ("suck", "suck"),
+    ("matrimony", "matrimoni"),
+    ("gaze", "gaze"),
+    ("rushed", "rush"),
+    ("inculcating", "inculc"),
+    ("chariots", "chariot"),
+    ("parti", "parti"),
+    ("cophias", "cophia"),
+    ("investigate", "investig"),
+    ("charts", "chart"),
+    ("disheartened", "dishearten"),
+    ("ancestry", "ancestri"),
+    ("revered", "rever"),
+    ("unmercifully", "unmercifulli"),
+    ("fishing", "fish"),
+    ("lamentation", "lamentaz"),
+    ("reformation", "reformaz"),
+    ("boxing", "box"),
+    ("sedate", "sedat"),
+    ("blockhead", "blockhead"),
+    ("inclosure", "inclosur"),
+    ("t", "t"),
+    ("unwittingly", "unwittingli"),
+    ("delusion", "delus"),
+    ("semyonovitch", "semyonovitch"),
+    ("attractive", "attract"),
+    ("imitated", "imit"),
+    ("plebeian", "plebeian"),
+    ("previously", "previous"),
+    ("doubled", "doubl"),
+    ("raptures", "raptur"),
+    ("publisher", "publish"),
+    ("steadier", "steadier"),
+    ("volute", "volut"),
+    ("ruffled", "ruffl"),
+    ("killed", "kill"),
+    ("nidification", "nidificaz"),
+    ("fondness", "fond"),
+    ("yore", "yor"),
+    ("discourses", "discours"),
+    ("peacefulness", "peac"),
+    ("turnout", "turnout"),
+    ("chionis", "chioni"),
+    ("regarded", "regard"),
+    ("dumpling", "dumpl"),
+    ("forelock", "forelock"),
+    ("defection", "defect"),
+    ("shedding", "shed"),
+    ("sauntered", "saunter"),
+    ("liquorish", "liquorish"),
+    ("hovel", "hovel"),
+    ("piles", "pile"),
+    ("unceremoniously", "unceremoni"),
+    ("blur", "blur"),
+    ("trusses", "truss"),
+    ("smallest", "smallest"),
+    ("nicholas", "nichola"),
+    ("pyramid", "pyramid"),
+    ("exposed", "expos"),
+    ("protract", "protract"),
+    ("illustrates", "illustr"),
+    ("cray", "crai"),
+    ("obliged", "oblig"),
+    ("rivalled", "rival"),
+    ("strickland", "strickland"),
+    ("subduing", "subdu"),
+    ("darkening", "darken"),
+    ("ceryle", "ceryl"),
+    ("barber", "barber"),
+    ("plaid", "plaid"),
+    ("avocations", "avocaz"),
+    ("featherbed", "featherb"),
+    ("elsewhere", "elsewher"),
+    ("agreed", "agre"),
+    ("ohnglaube", "ohnglaub"),
+    ("jobs", "job"),
+    ("imploring", "implor"),
+    ("gyrations", "gyraz"),
+    ("articulating", "articul"),
+    ("bower", "bower"),
+    ("knowd", "knowd"),
+    ("reliques", "reliqu"),
+    ("wishing", "wish"),
+    ("abridged", "abridg"),
+    ("gags", "gag"),
+    ("lupton", "lupton"),
+    ("retrenchment", "retrench"),
+    ("lax", "lax"),
+    ("misbehave", "misbehav"),
+    ("coupling", "coupl"),
+    ("silverbridge", "silverbridg"),
+    ("cymindis", "cymindi"),
+    ("niger", "niger"),
+    ("approached", "approach"),
+    ("icebergs", "iceberg"),
+    ("laboriously", "labori"),
+    ("codicil", "codicil"),
+    ("liveth", "liveth"),
+    ("adopt", "adopt"),
+    ("grudged", "grudg"),
+    ("thorns", "thorn"),
+    ("ten", "ten"),
+    ("again", "again"),
+    ("masts", "mast"),
+    ("barrack", "barrack"),
+    ("scrutinising", "scrutinis"),
+    ("remplir", "remplir"),
+    ("amorous", "amor"),
+    ("voluble", "volubl"),
+    ("surrendering", "surrend"),
+    ("endangered", "endang"),
+    ("forrard", "forrard"),
+    ("doctrine", "doctrin"),
+    ("fairish", "fairish"),
+    ("pattens", "patten"),
+    ("archbishop", "archbishop"),
+    ("gravestones", "graveston"),
+    ("lighten", "lighten"),
+    ("sift", "sift"),
+    ("antagonistic", "antagonist"),
+    ("stamp", "stamp"),
+    ("arethusa", "arethusa"),
+    ("quietest", "quietest"),
+    ("knockers", "knocker"),
+    ("peep", "peep"),
+    ("advises", "advis"),
+    ("yellowish", "yellowish"),
+    ("quoth", "quoth"),
+    ("guarded", "guard"),
+    ("rapacious", "rapaci"),
+    ("coatings", "coat"),
+    ("fatigue", "fatigu"),
+    ("hipped", "hip"),
+    ("giv", "giv"),
+    ("insomuch", "insomuch"),
+    ("irksome", "irksom"),
+    ("physically", "physic"),
+    ("flock", "flock"),
+    ("craters", "crater"),
+    ("semyon", "semyon"),
+    ("lengthened", "lengthen"),
+    ("rustic", "rustic"),
+    ("acquaintances", "acquaint"),
+    ("listen", "listen"),
+    ("hustling", "hustl"),
+    ("preoccupied", "preoccupi"),
+    ("greed", "greed"),
+    ("quicken", "quicken"),
+    ("define", "defin"),
+    ("corroded", "corrod"),
+    ("knowledge", "knowledg"),
+    ("castigate", "castig"),
+    ("laches", "lach"),
+    ("heavy", "heavi"),
+    ("empty", "empti"),
+    ("returns", "return"),
+    ("dainty", "dainti"),
+    ("faction", "faction"),
+    ("games", "game"),
+    ("nutcrackers", "nutcrack"),
+    ("dover", "dover"),
+    ("incredulity", "incredul"),
+    ("irrespective", "irrespect"),
+    ("flatters", "flatter"),
+    ("acacias", "acacia"),
+    ("exhortations", "exhortaz"),
+    ("pulpy", "pulpi"),
+    ("whitewashed", "whitewash"),
+    ("exposing", "expos"),
+    ("fattish", "fattish"),
+    ("conservatory", "conservatori"),
+    ("tailors", "tailor"),
+    ("contented", "content"),
+    ("cherrybles", "cherrybl"),
+    ("tanned", "tan"),
+    ("intelligent", "intellig"),
+    ("lack", "lack"),
+    ("uncompanionable", "uncompanion"),
+    ("grate", "grate"),
+    ("diamonds", "diamond"),
+    ("expelled", "expel"),
+    ("gesticulating", "gesticul"),
+    ("sip", "sip"),
+    ("beat", "beat"),
+    ("instantly", "instantli"),
+    ("spitzbergen", "spitzbergen"),
+    ("reasoning", "reason"),
+    ("misgiving", "misgiv"),
+    ("danger", "danger"),
+    ("delivers", "deliv"),
+    ("prostrated", "prostrat"),
+    ("tropical", "tropic"),
+    ("combatted", "combat"),
+    ("welfare", "welfar"),
+    ("impurity", "impur"),
+    ("coeval", "coeval"),
+    ("appropriated", "appropri"),
+    ("affecting", "affect"),
+    ("rumours", "rumour"),
+    ("encumbering", "encumb"),
+    ("martyrdom", "martyrdom"),
+    ("stablewards", "stableward"),
+    ("ridg", "ridg"),
+    ("clever", "clever"),
+    ("pulled", "pull"),
+    ("grandees", "grande"),
+    ("sharpshooters", "sharpshoot"),
+    ("sitting", "sit"),
+    ("preferring", "prefer"),
+    ("fits", "fit"),
+    ("imputed", "imput"),
+    ("harassed", "harass"),
+    ("affidavit", "affidavit"),
+    ("invites", "invit"),
+    ("thieves", "thiev"),
+    ("threw", "threw"),
+    ("thoughts", "thought"),
+    ("anchor", "anchor"),
+    ("ounces", "ounc"),
+    ("breads", "bread"),
+    ("confirm", "confirm"),
+    ("caledonian", "caledonian"),
+    ("ladle", "ladl"),
+    ("presume", "presum"),
+    ("flirting", "flirt"),
+    ("soizes", "soiz"),
+    ("suppressing", "suppress"),
+    ("ptarmigans", "ptarmigan"),
+    ("enlightenment", "enlighten"),
+    ("arms", "arm"),
+    ("doves", "dove"),
+    ("christianity", "christian"),
+    ("amazed", "amaz"),
+    ("hawfinch", "hawfinch"),
+    ("fringing", "fringing"),
+    ("sever", "sever"),
+    ("petises", "petis"),
+    ("plotted", "plot"),
+    ("vestigia", "vestigia"),
+    ("figures", "figur"),
+    ("retrograding", "retrograd"),
+    ("breaks", "break"),
+    ("cul", "cul"),
+    ("exquisites", "exquisit"),
+    ("keeps", "keep"),
+    ("petticoats", "petticoat"),
+    ("landmarks", "landmark"),
+    ("accommodations", "accommodaz"),
+    ("pollys", "polli"),
+    ("fester", "fester"),
+    ("pusillanimity", "pusillanim"),
+    ("definitely", "definit"),
+    ("inauspicious", "inauspici"),
+    ("frequentl", "frequentl"),
+    ("concubine", "concubin"),
+    ("myrtle", "myrtl"),
+    ("emblems", "emblem"),
+    ("robes", "robe"),
+    ("hereafter", "hereaft"),
+    ("vigil", "vigil"),
+    ("foul", "foul"),
+    ("weakness", "weak"),
+    ("watering", "water"),
+    ("whitsuntide", "whitsuntid"),
+    ("egyptians", "egyptian"),
+    ("aggravations", "aggravaz"),
+    ("unction", "unction"),
+    ("glass", "glass"),
+    ("kindle", "kindl"),
+    ("flurried", "flurri"),
+    ("chowser", "chowser"),
+    ("britannia", "britannia"),
+    ("landings", "land"),
+    ("liberated", "liber"),
+    ("beseech", "beseech"),
+    ("spider", "spider"),
+    ("reddish", "reddish"),
+    ("suggesting", "suggest"),
+    ("herbarium", "herbarium"),
+    ("secretes", "secret"),
+    ("copse", "cops"),
+    ("lodging", "lodg"),
+    ("cables", "cabl"),
+    ("niggering", "nigger"),
+    ("demigods", "demigod"),
+    ("hospitility", "hospitil"),
+    ("impregnated", "impregn"),
+    ("slandering", "slander"),
+    ("uninvited", "uninvit"),
+    ("consequently", "consequ"),
+    ("accumulation", "accumulaz"),
+    ("glide", "glide"),
+    ("inked", "ink"),
+    ("wigs", "wig"),
+    ("intemperance", "intemper"),
+    ("makes", "make"),
+    ("fulfil", "fulfil"),
+    ("seamen", "seamen"),
+    ("superciliousness", "supercili"),
+    ("barton", "barton"),
+    ("affable", "affabl"),
+    ("innocence", "innoc"),
+    ("manageable", "manag"),
+    ("nailed", "nail"),
+    ("clerical", "cleric"),
+    ("spinach", "spinach"),
+    ("montes", "mont"),
+    ("bragging", "brag"),
+    ("melteth", "melteth"),
+    ("parallel", "parallel"),
+    ("transportation", "transportaz"),
+    ("niobe", "niob"),
+    ("overhead", "overhead"),
+    ("explanatory", "explanatori"),
+    ("disappearing", "disappear"),
+    ("stalactitic", "stalactit"),
+    ("plumed", "plume"),
+    ("commencement", "commenc"),
+    ("menacing", "menac"),
+    ("irresponsive", "irrespons"),
+    ("gala", "gala"),
+    ("preaching", "preach"),
+    ("ride", "ride"),
+    ("intercalated", "intercal"),
+    ("influences", "influenc"),
+    ("ungenerous", "ungener"),
+    ("convulsive", "convuls"),
+    ("satisfactorily", "satisfactorili"),
+    ("begging", "beg"),
+    ("apropos", "apropo"),
+    ("bounden", "bounden"),
+    ("patience", "patienc"),
+    ("cord", "cord"),
+    ("acquitted", "acquit"),
+    ("scattered", "scatter"),
+    ("rumicivorus", "rumicivoru"),
+    ("meekness", "meek"),
+    ("hoofs", "hoof"),
+    ("quito", "quito"),
+    ("externally", "extern"),
+    ("ineradicable", "ineradic"),
+    ("executive", "execut"),
+    ("undrained", "undrain"),
+    ("lacerate", "lacer"),
+    ("territory", "territori"),
+    ("beudant", "beudant"),
+    ("peeping", "peep"),
+    ("meant", "meant"),
+    ("audience", "audienc"),
+    ("rodion", "rodion"),
+    ("representing", "repres"),
+    ("clerkship", "clerkship"),
+    ("received", "receiv"),
+    ("thoroughbred", "thoroughbr"),
+    ("combativeness", "comb"),
+    ("constituency", "constitu"),
+    ("embellishment", "embellish"),
+    ("oftenest", "oftenest"),
+    ("thorax", "thorax"),
+    ("visage", "visag"),
+    ("virulence", "virul"),
+    ("eloquence", "eloqu"),
+    ("fourteenpenny", "fourteenpenni"),
+    ("ari", "ari"),
+    ("brim", "brim"),
+    ("orderly", "orderli"),
+    ("artifices", "artific"),
+    ("written", "written"),
+    ("vainer", "vainer"),
+    ("coated", "coat"),
+    ("resident", "resid"),
+    ("dang", "dang"),
+    ("attorneys", "attornei"),
+    ("wan", "wan"),
+    ("prophetic", "prophet"),
+    ("barely", "bare"),
+    ("whirlwind", "whirlwind"),
+    ("symmetry", "symmetri"),
+    ("inhabitants", "inhabit"),
+    ("profited", "profit"),
+    ("churchgoing", "churchgo"),
+    ("straws", "straw"),
+    ("rabbits", "rabbit"),
+    ("duets", "duet"),
+    ("steeply", "steepli"),
+    ("breezy", "breezi"),
+    ("chlorides", "chlorid"),
+    ("erupted", "erupt"),
+    ("trusting", "trust"),
+    ("feythers", "feyther"),
+    ("softer", "softer"),
+    ("unattempted", "unattempt"),
+    ("geologist", "geologist"),
+    ("survivor", "survivor"),
+    ("unrequited", "unrequit"),
+    ("sharks", "shark"),
+    ("stan", "stan"),
+    ("hartshorn", "hartshorn"),
+    ("baronets", "baronet"),
+    ("reductions", "reduct"),
+    ("fire", "fire"),
+    ("chock", "chock"),
+    ("pinnace", "pinnac"),
+    ("bloodshot", "bloodshot"),
+    ("meals", "meal"),
+    ("hated", "hate"),
+    ("irregularity", "irregular"),
+    ("nulla", "nulla"),
+    ("flinch", "flinch"),
+    ("genelmen", "genelmen"),
+    ("ebbing", "eb"),
+    ("pickings", "pick"),
+    ("command", "command"),
+    ("unload", "unload"),
+    ("extend", "extend"),
+    ("tickling", "tickl"),
+    ("welcomer", "welcom"),
+    ("matron", "matron"),
+    ("crazed", "craze"),
+    ("crushed", "crush"),
+    ("pluies", "plui"),
+    ("favourably", "favour"),
+    ("tastefully", "tastefulli"),
+    ("panted", "pant"),
+    ("tediousness", "tedious"),
+    ("flavor", "flavor"),
+    ("seductively", "seduct"),
+    ("parapet", "parapet"),
+    ("hurried", "hurri"),
+    ("banisters", "banist"),
+    ("perfumery", "perfumeri"),
+    ("beaches", "beach"),
+    ("dome", "dome"),
+    ("impertinent", "impertin"),
+    ("muffling", "muffl"),
+    ("contact", "contact"),
+    ("horsewhipped", "horsewhip"),
+    ("freedom", "freedom"),
+    ("lamplight", "lamplight"),
+    ("housemaid", "housemaid"),
+    ("freight", "freight"),
+    ("wigmaker", "wigmak"),
+    ("falkland", "falkland"),
+    ("twitch", "twitch"),
+    ("metaphysical", "metaphys"),
+    ("bladder", "bladder"),
+    ("xxxi", "xxxi"),
+    ("outrage", "outrag"),
+    ("shutting", "shut"),
+    ("ape", "ap"),
+    ("itself", "itself"),
+    ("shrubs", "shrub"),
+    ("bushels", "bushel"),
+    ("wholesale", "wholesal"),
+    ("blocking", "block"),
+    ("lustre", "lustr"),
+    ("contended", "contend"),
+    ("tumult", "tumult"),
+    ("lightness", "light"),
+    ("benignant", "benign"),
+    ("majesty", "majesti"),
+    ("nothink", "nothink"),
+    ("namesake", "namesak"),
+    ("adapted", "adapt"),
+    ("harbinger", "harbing"),
+    ("cannon", "cannon"),
+    ("haggle", "haggl"),
+    ("bachapins", "bachapin"),
+    ("communicate", "commun"),
+    ("deny", "deni"),
+    ("playthings", "playth"),
+    ("corrals", "corral"),
+    ("conventionality", "convention"),
+    ("muniz", "muniz"),
+    ("disproving", "disprov"),
+    ("breeding", "breed"),
+    ("miscalculation", "miscalculaz"),
+    ("prelude", "prelud"),
+    ("occasional", "occasion"),
+    ("efficiency", "effici"),
+    ("pavement", "pavement"),
+    ("moustached", "moustach"),
+    ("widower", "widow"),
+    ("seductive", "seduct"),
+    ("importation", "importaz"),
+    ("scotland", "scotland"),
+    ("madly", "madli"),
+    ("adulation", "adulaz"),
+    ("plutonic", "pluton"),
+    ("chat", "chat"),
+    ("pushkin", "pushkin"),
+    ("direct", "direct"),
+    ("naively", "naiv"),
+    ("scuttling", "scuttl"),
+    ("martial", "martial"),
+    ("gravest", "gravest"),
+    ("pettifogging", "pettifog"),
+    ("extensile", "extensil"),
+    ("smeared", "smear"),
+    ("broker", "broker"),
+    ("gallery", "galleri"),
+    ("hulloa", "hulloa"),
+    ("troubled", "troubl"),
+    ("unsuspicious", "unsuspici"),
+    ("illustrating", "illustr"),
+    ("arriero", "arriero"),
+    ("lod", "lod"),
+    ("republicans", "republican"),
+    ("manipulated", "manipul"),
+    ("created", "creat"),
+    ("dictator", "dictat"),
+    ("comedies", "comedi"),
+    ("displays", "displai"),
+    ("exhibiting", "exhibit"),
+    ("hawks", "hawk"),
+    ("plashing", "plash"),
+    ("contentious", "contenti"),
+    ("infectious", "infecti"),
+    ("lustier", "lustier"),
+    ("disappointments", "disappoint"),
+    ("hamlet", "hamlet"),
+    ("quails", "quail"),
+    ("decreasing", "decreas"),
+    ("ova", "ova"),
+    ("insufferable", "insuffer"),
+    ("hybernating", "hybern"),
+    ("haunts", "haunt"),
+    ("peak", "peak"),
+    ("naturedly", "naturedli"),
+    ("detestation", "detestaz"),
+    ("ceases", "ceas"),
+    ("preying", "prei"),
+    ("akimbo", "akimbo"),
+    ("guasco", "guasco"),
+    ("cloak", "cloak"),
+    ("hardy", "hardi"),
+    ("meaning", "mean"),
+    ("anythink", "anythink"),
+    ("inhumanly", "inhumanli"),
+    ("chair", "chair"),
+    ("glories", "glori"),
+    ("exhaust", "exhaust"),
+    ("prayers", "prayer"),
+    ("opiate", "opiat"),
+    ("scrubbing", "scrub"),
+    ("goatherd", "goatherd"),
+    ("ionic", "ionic"),
+    ("parted", "part"),
+    ("ennobled", "ennobl"),
+    ("workmen", "workmen"),
+    ("elasticity", "elast"),
+    ("salver", "salver"),
+    ("publishing", "publish"),
+    ("hurrahed", "hurrah"),
+    ("connected", "connect"),
+    ("stubb", "stubb"),
+    ("comfort", "comfort"),
+    ("outrigeously", "outrig"),
+    ("athletic", "athlet"),
+    ("epilepsy", "epilepsi"),
+    ("superb", "superb"),
+    ("upstairs", "upstair"),
+    ("wiglomeration", "wiglomeraz"),
+    ("executioner", "execution"),
+    ("whatever", "whatev"),
+    ("gunnners", "gunnner"),
+    ("rancorously", "rancor"),
+    ("horseman", "horseman"),
+    ("taunted", "taunt"),
+    ("antennae", "antenna"),
+    ("occupied", "occupi"),
+    ("inexpiable", "inexpi"),
+    ("cavillers", "cavil"),
+    ("wat", "wat"),
+    ("filamentous", "filament"),
+    ("chops", "chop"),
+    ("diviner", "divin"),
+    ("scanty", "scanti"),
+    ("mezzotinto", "mezzotinto"),
+    ("carlton", "carlton"),
+    ("shellfish", "shellfish"),
+    ("telegraphed", "telegraph"),
+    ("frequenfly", "frequenfli"),
+    ("elegance", "eleg"),
+    ("blues", "blue"),
+    ("notable", "notabl"),
+    ("eked", "ek"),
+    ("binding", "bind"),
+    ("halls", "hall"),
+    ("bennet", "bennet"),
+    ("remonstrative", "remonstr"),
+    ("support", "support"),
+    ("winters", "winter"),
+    ("sinai", "sinai"),
+    ("headland", "headland"),
+    ("secretly", "secretli"),
+    ("trammels", "trammel"),
+    ("calmly", "calmli"),
+    ("acqulred", "acqulr"),
+    ("maidens", "maiden"),
+    ("theft", "theft"),
+    ("sant", "sant"),
+    ("stages", "stage"),
+    ("compensate", "compens"),
+    ("singing", "singing"),
+    ("ascribable", "ascrib"),
+    ("himself", "himself"),
+    ("equals", "equal"),
+    ("frouzy", "frouzi"),
+    ("orb", "orb"),
+    ("maim", "maim"),
+    ("braids", "braid"),
+    ("divorced", "divorc"),
+    ("tantamount", "tantamount"),
+    ("timidity", "timid"),
+    ("reaping", "reap"),
+    ("sophistry", "sophistri"),
+    ("articulately", "articul"),
+    ("cocked", "cock"),
+    ("seame", "seam"),
+    ("wick", "wick"),
+    ("constructions", "construct"),
+    ("penny", "penni"),
+    ("used", "us"),
+    ("peerless", "peerless"),
+    ("plain", "plain"),
+    ("beak", "beak"),
+    ("reprobate", "reprob"),
+    ("forego", "forego"),
+    ("strack", "strack"),
+    ("sympathize", "sympath"),
+    ("llth", "llth"),
+    ("thicker", "thicker"),
+    ("withdrawn", "withdrawn"),
+    ("this", "thi"),
+    ("augen", "augen"),
+    ("wade", "wade"),
+    ("sloven", "sloven"),
+    ("capitalists", "capitalist"),
+    ("infected", "infect"),
+    ("rodent", "rodent"),
+    ("heal", "heal"),
+    ("hercules", "hercul"),
+    ("strangely", "strang"),
+    ("conjugal", "conjug"),
+    ("adventuresses", "adventuress"),
+    ("draughtsman", "draughtsman"),
+    ("persuadable", "persuad"),
+    ("comeliness", "comeli"),
+    ("birds", "bird"),
+    ("blinder", "blinder"),
+    ("naturalness", "natur"),
+    ("urinary", "urinari"),
+    ("bled", "bled"),
+    ("favoured", "favour"),
+    ("rigid", "rigid"),
+    ("fothergill", "fothergil"),
+    ("grip", "grip"),
+    ("jocundity", "jocund"),
+    ("favourable", "favour"),
+    ("shaking", "shake"),
+    ("tarnii", "tarnii"),
+    ("injuring", "injur"),
+    ("joys", "joi"),
+    ("notion", "notion"),
+    ("waggons", "waggon"),
+    ("roughening", "roughen"),
+    ("moonlighted", "moonlight"),
+    ("heir", "heir"),
+    ("shoot", "shoot"),
+    ("ships", "ship"),
+    ("exists", "exist"),
+    ("piloted", "pilot"),
+    ("prim", "prim"),
+    ("mirth", "mirth"),
+    ("discordantly", "discordantli"),
+    ("yearly", "yearli"),
+    ("riveted", "rivet"),
+    ("frolics", "frolic"),
+    ("bandying", "bandi"),
+    ("divining", "divin"),
+    ("notices", "notic"),
+    ("commodity", "commod"),
+    ("experience", "experi"),
+    ("shrubbery", "shrubberi"),
+    ("freshening", "freshen"),
+    ("crowin", "crowin"),
+    ("curtsy", "curtsi"),
+    ("shakspeare", "shakspear"),
+    ("beats", "beat"),
+    ("coavinses", "coavins"),
+    ("speculate", "specul"),
+    ("rheumatics", "rheumat"),
+    ("unmake", "unmak"),
+    ("yields", "yield"),
+    ("roaming", "roam"),
+    ("routine", "routin"),
+    ("poetry", "poetri"),
+    ("interpose", "interpos"),
+    ("retracted", "retract"),
+    ("determining", "determin"),
+    ("metamorphosed", "metamorphos"),
+    ("blacker", "blacker"),
+    ("surgery", "surgeri"),
+    ("nomadically", "nomad"),
+    ("apples", "appl"),
+    ("leadership", "leadership"),
+    ("fluid", "fluid"),
+    ("tankard", "tankard"),
+    ("sepulchre", "sepulchr"),
+    ("pervade", "pervad"),
+    ("ditties", "ditti"),
+    ("novelty", "novelti"),
+    ("waisted", "waist"),
+    ("presumptuous", "presumptu"),
+    ("causeless", "causeless"),
+    ("clause", "claus"),
+    ("inexpressively", "inexpress"),
+    ("guantajaya", "guantajaya"),
+    ("foo", "foo"),
+    ("commerce", "commerc"),
+    ("possessin", "possessin"),
+    ("sprugeon", "sprugeon"),
+    ("hectoring", "hector"),
+    ("agitate", "agit"),
+    ("famines", "famin"),
+    ("needlework", "needlework"),
+    ("irrevocably", "irrevoc"),
+    ("aggregate", "aggreg"),
+    ("invoking", "invok"),
+    ("temporal", "tempor"),
+    ("cenotherae", "cenothera"),
+    ("erratic", "errat"),
+    ("assign", "assign"),
+    ("prudish", "prudish"),
+    ("which", "which"),
+    ("acted", "act"),
+    ("prepossessing", "prepossess"),
+    ("razsudkin", "razsudkin"),
+    ("bubblin", "bubblin"),
+    ("longitude", "longitud"),
+    ("heightening", "heighten"),
+    ("weather", "weather"),
+    ("emblazoned", "emblazon"),
+    ("fifthly", "fifthli"),
+    ("declines", "declin"),
+    ("armchair", "armchair"),
+    ("gourmand", "gourmand"),
+    ("wreaked", "wreak"),
+    ("crossly", "crossli"),
+    ("beaux", "beaux"),
+    ("debacle", "debacl"),
+    ("trumpets", "trumpet"),
+    ("possesse", "possess"),
+    ("exhort", "exhort"),
+    ("arm", "arm"),
+    ("arrangements", "arrang"),
+    ("guardsmen", "guardsmen"),
+    ("worried", "worri"),
+    ("destroy", "destroi"),
+    ("responsive", "respons"),
+    ("fleck", "fleck"),
+    ("pleasuring", "pleasur"),
+    ("errors", "error"),
+    ("domo", "domo"),
+    ("destruction", "destruct"),
+    ("agonised", "agonis"),
+    ("irrefutable", "irrefut"),
+    ("frequented", "frequent"),
+    ("pacified", "pacifi"),
+    ("slush", "slush"),
+    ("xxxiii", "xxxiii"),
+    ("aches", "ach"),
+    ("clean", "clean"),
+    ("vigilance", "vigil"),
+    ("portraits", "portrait"),
+    ("airlessness", "airless"),
+    ("mercers", "mercer"),
+    ("disclosed", "disclos"),
+    ("forts", "fort"),
+    ("picked", "pick"),
+    ("tides", "tide"),
+    ("departures", "departur"),
+    ("ascension", "ascens"),
+    ("skeletons", "skeleton"),
+    ("laughter", "laughter"),
+    ("hornpipe", "hornpip"),
+    ("gratified", "gratifi"),
+    ("soothe", "sooth"),
+    ("laurance", "lauranc"),
+    ("completion", "complet"),
+    ("ice", "ic"),
+    ("having", "have"),
+    ("reverts", "revert"),
+    ("embark", "embark"),
+    ("finished", "finish"),
+    ("unobtrusive", "unobtrus"),
+    ("shrank", "shrank"),
+    ("walled", "wall"),
+    ("artful", "art"),
+    ("jogg", "jogg"),
+    ("wistful", "wist"),
+    ("stakes", "stake"),
+    ("cir", "cir"),
+    ("babe", "babe"),
+    ("definite", "definit"),
+    ("turpin", "turpin"),
+    ("difference", "differ"),
+    ("spasms", "spasm"),
+    ("rhetoric", "rhetor"),
+    ("braces", "brace"),
+    ("zoophytes", "zoophyt"),
+    ("sabine", "sabin"),
+    ("scandalizing", "scandal"),
+    ("mechanism", "mechan"),
+    ("clenching", "clench"),
+    ("dutifully", "dutifulli"),
+    ("flushed", "flush"),
+    ("steeples", "steepl"),
+    ("loathsome", "loathsom"),
+    ("ony", "oni"),
+    ("responding", "respond"),
+    ("flashed", "flash"),
+    ("heartfelt", "heartfelt"),
+    ("spikes", "spike"),
+    ("sprott", "sprott"),
+    ("oblige", "oblig"),
+    ("florian", "florian"),
+    ("precociously", "precoci"),
+    ("ocean", "ocean"),
+    ("concerted", "concert"),
+    ("progenitors", "progenitor"),
+    ("threes", "three"),
+    ("affirmed", "affirm"),
+    ("intimating", "intim"),
+    ("allays", "allai"),
+    ("earls", "earl"),
+    ("forwardness", "forward"),
+    ("productiveness", "product"),
+    ("permanence", "perman"),
+    ("picaninnies", "picaninni"),
+    ("goddess", "goddess"),
+    ("tarry", "tarri"),
+    ("intense", "intens"),
+    ("unexpressed", "unexpress"),
+    ("shyly", "shyli"),
+    ("numerous", "numer"),
+    ("flora", "flora"),
+    ("overcrowed", "overcrow"),
+    ("stuff", "stuff"),
+    ("beauteous", "beauteou"),
+    ("varie", "vari"),
+    ("malcontents", "malcont"),
+    ("scrubbs", "scrubb"),
+    ("enjoyment", "enjoyment"),
+    ("find", "find"),
+    ("impetuous", "impetu"),
+    ("foregoing", "forego"),
+    ("turmoils", "turmoil"),
+    ("footpad", "footpad"),
+    ("dyes", "dye"),
+    ("logically", "logic"),
+    ("tul", "tul"),
+    ("juxtaposition", "juxtaposit"),
+    ("rubicund", "rubicund"),
+    ("sorrowful", "sorrow"),
+    ("bugs", "bug"),
+    ("stunted", "stunt"),
+    ("transfer", "transfer"),
+    ("profusely", "profus"),
+    ("drownded", "drownd"),
+    ("underhand", "underhand"),
+    ("memoranda", "memoranda"),
+    ("districts", "district"),
+    ("cafe", "cafe"),
+    ("trigger", "trigger"),
+    ("perfume", "perfum"),
+    ("mug", "mug"),
+    ("deweloping", "dewelop"),
+    ("importunate", "importun"),
+    ("edusa", "edusa"),
+    ("occurs", "occur"),
+    ("famished", "famish"),
+    ("admittance", "admitt"),
+    ("karros", "karro"),
+    ("stirs", "stir"),
+    ("marched", "march"),
+    ("differences", "differ"),
+    ("twigs", "twig"),
+    ("warming", "warm"),
+    ("hopeful", "hope"),
+    ("guaso", "guaso"),
+    ("inveterate", "inveter"),
+    ("spiny", "spini"),
+    ("frequenting", "frequent"),
+    ("hypocritically", "hypocrit"),
+    ("forasmuch", "forasmuch"),
+    ("inconsiderateness", "inconsider"),
+    ("lounging", "loung"),
+    ("obedience", "obedi"),
+    ("lumley", "lumlei"),
+    ("holiday", "holidai"),
+    ("prokofitch", "prokofitch"),
+    ("dowager", "dowag"),
+    ("canada", "canada"),
+    ("poaching", "poach"),
+    ("plunder", "plunder"),
+    ("distortion", "distort"),
+    ("annuelle", "annuell"),
+    ("brout", "brout"),
+    ("vilely", "vile"),
+    ("tax", "tax"),
+    ("denying", "deni"),
+    ("plates", "plate"),
+    ("vholes", "vhole"),
+    ("cocoon", "cocoon"),
+    ("didelphis", "didelphi"),
+    ("showered", "shower"),
+    ("simultaneously", "simultan"),
+    ("blacken", "blacken"),
+    ("unloaded", "unload"),
+    ("cheers", "cheer"),
+    ("centres", "centr"),
+    ("complement", "complement"),
+    ("uncivil", "uncivil"),
+    ("ideals", "ideal"),
+    ("needless", "needless"),
+    ("lavender", "lavend"),
+    ("autobiographies", "autobiographi"),
+    ("savouries", "savouri"),
+    ("borrow", "borrow"),
+    ("increase", "increas"),
+    ("lieu", "lieu"),
+    ("navy", "navi"),
+    ("inter", "inter"),
+    ("wadded", "wad"),
+    ("harass", "harass"),
+    ("infidels", "infidel"),
+    ("forards", "forard"),
+    ("perfection", "perfect"),
+    ("hundredth", "hundredth"),
+    ("splashin", "splashin"),
+    ("heretic", "heret"),
+    ("fuego", "fuego"),
+    ("demonstrate", "demonstr"),
+    ("outsiders", "outsid"),
+    ("admission", "admiss"),
+    ("indications", "indicaz"),
+    ("creeks", "creek"),
+    ("conclude", "conclud"),
+    ("cathedral", "cathedr"),
+    ("stipulate", "stipul"),
+    ("chuzos", "chuzo"),
+    ("vilest", "vilest"),
+    ("shiver", "shiver"),
+    ("migrate", "migrat"),
+    ("dimly", "dimli"),
+    ("respiration", "respiraz"),
+    ("monks", "monk"),
+    ("suitor", "suitor"),
+    ("crouches", "crouch"),
+    ("sombrero", "sombrero"),
+    ("blessing", "bless"),
+    ("sweethearts", "sweetheart"),
+    ("alms", "alm"),
+    ("nam", "nam"),
+    ("staves", "stave"),
+    ("derangement", "derang"),
+    ("await", "await"),
+    ("obeisance", "obeis"),
+    ("vilna", "vilna"),
+    ("englishwoman", "englishwoman"),
+    ("edifying", "edifi"),
+    ("reverberating", "reverber"),
+    ("fleshy", "fleshi"),
+    ("zu", "zu"),
+    ("throngs", "throng"),
+    ("unsavoury", "unsavouri"),
+    ("mashes", "mash"),
+    ("curtains", "curtain"),
+    ("pettish", "pettish"),
+    ("oiliness", "oili"),
+    ("garden", "garden"),
+    ("outshine", "outshin"),
+    ("spokesman", "spokesman"),
+    ("depicts", "depict"),
+    ("ague", "agu"),
+    ("salesman", "salesman"),
+    ("ight", "ight"),
+    ("rescue", "rescu"),
+    ("strix", "strix"),
+    ("killing", "kill"),
+    ("corrects", "correct"),
+    ("hardly", "hardli"),
+    ("mack", "mack"),
+    ("aisle", "aisl"),
+    ("groundless", "groundless"),
+    ("encountering", "encount"),
+    ("zorillos", "zorillo"),
+    ("procurable", "procur"),
+    ("continual", "continu"),
+    ("brushwood", "brushwood"),
+    ("heedless", "heedless"),
+    ("husbands", "husband"),
+    ("kamtschatka", "kamtschatka"),
+    ("modified", "modifi"),
+    ("flings", "fling"),
+    ("guildford", "guildford"),
+    ("outpouring", "outpour"),
+    ("beehive", "beehiv"),
+    ("designer", "design"),
+    ("vanikoro", "vanikoro"),
+    ("quilmes", "quilm"),
+    ("student", "student"),
+    ("colleague", "colleagu"),
+    ("moind", "moind"),
+    ("repine", "repin"),
+    ("valve", "valv"),
+    ("contemptuous", "contemptu"),
+    ("lashed", "lash"),
+    ("questions", "question"),
+    ("cent", "cent"),
+    ("chord", "chord"),
+    ("stepped", "step"),
+    ("couched", "couch"),
+    ("ruin", "ruin"),
+    ("drift", "drift"),
+    ("proprietorship", "proprietorship"),
+    ("susceptible", "suscept"),
+    ("grain", "grain"),
+    ("wittles", "wittl"),
+    ("billiards", "billiard"),
+    ("tramped", "tramp"),
+    ("g", "g"),
+    ("aristides", "aristid"),
+    ("capture", "captur"),
+    ("discovering", "discov"),
+    ("curtain", "curtain"),
+    ("cleaner", "cleaner"),
+    ("at", "at"),
+    ("economists", "economist"),
+    ("shoes", "shoe"),
+    ("montagne", "montagn"),
+    ("abstruse", "abstrus"),
+    ("harping", "harp"),
+    ("silicified", "silicifi"),
+    ("malays", "malai"),
+    ("transport", "transport"),
+    ("highlands", "highland"),
+    ("defiantly", "defiantli"),
+    ("freed", "freed"),
+    ("weazen", "weazen"),
+    ("relieves", "reliev"),
+    ("bites", "bite"),
+    ("coeur", "coeur"),
+    ("vociferous", "vocifer"),
+    ("thei", "thei"),
+    ("decides", "decid"),
+    ("halcyon", "halcyon"),
+    ("harmattan", "harmattan"),
+    ("unassuming", "unassum"),
+    ("wisitin", "wisitin"),
+    ("regrets", "regret"),
+    ("interstices", "interstic"),
+    ("fern", "fern"),
+    ("burdens", "burden"),
+    ("romp", "romp"),
+    ("powerless", "powerless"),
+    ("legion", "legion"),
+    ("fatigued", "fatigu"),
+    ("parkers", "parker"),
+    ("predominate", "predomin"),
+    ("heavv", "heavv"),
+    ("sergeant", "sergeant"),
+    ("emphasising", "emphasis"),
+    ("austerity", "auster"),
+    ("porphyries", "porphyri"),
+    ("exasperation", "exasperaz"),
+    ("restaurants", "restaur"),
+    ("nocturnal", "nocturn"),
+    ("askant", "askant"),
+    ("guffawed", "guffaw"),
+    ("mischievous", "mischiev"),
+    ("seclusion", "seclus"),
+    ("scamander", "scamand"),
+    ("vomit", "vomit"),
+    ("regeneration", "regeneraz"),
+    ("retiring", "retir"),
+    ("rubies", "rubi"),
+    ("sparks", "spark"),
+    ("retard", "retard"),
+    ("succinea", "succinea"),
+    ("eighths", "eighth"),
+    ("combinations", "combinaz"),
+    ("scarum", "scarum"),
+    ("absent", "absent"),
+    ("fantastical", "fantast"),
+    ("rekindled", "rekindl"),
+    ("academy", "academi"),
+    ("view", "view"),
+    ("phases", "phase"),
+    ("phosphate", "phosphat"),
+    ("extemporaneously", "extemporan"),
+    ("town", "town"),
+    ("lopezites", "lopezit"),
+    ("flits", "flit"),
+    ("resplendently", "resplend"),
+    ("exclusive", "exclus"),
+    ("allowances", "allow"),
+    ("reduce", "reduc"),
+    ("paying", "pai"),
+    ("meanings", "mean"),
+    ("cape", "cape"),
+    ("bribes", "bribe"),
+    ("timbers", "timber"),
+    ("embarrassing", "embarrass"),
+    ("wainscotting", "wainscot"),
+    ("pawn", "pawn"),
+    ("suicidal", "suicid"),
+    ("deified", "deifi"),
+    ("procured", "procur"),
+    ("turned", "turn"),
+    ("laughable", "laughabl"),
+    ("renewals", "renew"),
+    ("poses", "pose"),
+    ("mataco", "mataco"),
+    ("enlist", "enlist"),
+    ("paul", "paul"),
+    ("overreached", "overreach"),
+    ("certainties", "certainti"),
+    ("organized", "organ"),
+    ("creature", "creatur"),
+    ("claims", "claim"),
+    ("ravings", "rave"),
+    ("gunwales", "gunwal"),
+    ("forbids", "forbid"),
+    ("lingered", "linger"),
+    ("utilitarianism", "utilitarian"),
+    ("fawned", "fawn"),
+    ("hippah", "hippah"),
+    ("embankments", "embank"),
+    ("mazy", "mazi"),
+    ("startles", "startl"),
+    ("tern", "tern"),
+    ("mars", "mar"),
+    ("verdict", "verdict"),
+    ("casts", "cast"),
+    ("physician", "physician"),
+    ("eternelle", "eternell"),
+    ("dusky", "duski"),
+    ("crumber", "crumber"),
+    ("borum", "borum"),
+    ("perquisition", "perquisit"),
+    ("resigns", "resign"),
+    ("refund", "refund"),
+    ("iii", "iii"),
+    ("entrapped", "entrap"),
+    ("digestion", "digest"),
+    ("intends", "intend"),
+    ("frights", "fright"),
+    ("frio", "frio"),
+    ("preparatory", "preparatori"),
+    ("forgets", "forget"),
+    ("dcar", "dcar"),
+    ("tranquil", "tranquil"),
+    ("bottled", "bottl"),
+    ("string", "string"),
+    ("repast", "repast"),
+    ("demmit", "demmit"),
+    ("disturbed", "disturb"),
+    ("tea", "tea"),
+    ("weren", "weren"),
+    ("omen", "omen"),
+    ("conjecture", "conjectur"),
+    ("ranging", "rang"),
+    ("conduits", "conduit"),
+    ("aspiration", "aspiraz"),
+    ("splashing", "splash"),
+    ("pt", "pt"),
+    ("wheelbarrowful", "wheelbarrow"),
+    ("parker", "parker"),
+    ("grown", "grown"),
+    ("sumatra", "sumatra"),
+    ("invitingly", "invitingli"),
+    ("enunciated", "enunci"),
+    ("style", "style"),
+    ("solidness", "solid"),
+    ("tradition", "tradit"),
+    ("apostrophised", "apostrophis"),
+    ("daily", "daili"),
+    ("zealous", "zealou"),
+    ("november", "novemb"),
+    ("knitting", "knit"),
+    ("hospitalities", "hospit"),
+    ("authentically", "authent"),
+    ("gallops", "gallop"),
+    ("crudely", "crude"),
+    ("conveying", "convei"),
+    ("observations", "observaz"),
+    ("recollections", "recollect"),
+    ("pamphlet", "pamphlet"),
+    ("contemporaneously", "contemporan"),
+    ("dustn", "dustn"),
+    ("thinks", "think"),
+    ("engender", "engend"),
+    ("reassuring", "reassur"),
+    ("shipton", "shipton"),
+    ("pasturage", "pasturag"),
+    ("canting", "cant"),
+    ("shrill", "shrill"),
+    ("soared", "soar"),
+    ("lying", "li"),
+    ("beings", "be"),
+    ("cursed", "curs"),
+    ("grandeur", "grandeur"),
+    ("lowness", "low"),
+    ("geniuses", "genius"),
+    ("introduce", "introduc"),
+    ("cries", "cri"),
+    ("infusoria", "infusoria"),
+    ("subsistence", "subsist"),
+    ("terminal", "termin"),
+    ("embowed", "embow"),
+    ("vire", "vire"),
+    ("tres", "tre"),
+    ("wrenched", "wrench"),
+    ("ehrenberg", "ehrenberg"),
+    ("du", "du"),
+    ("genteelly", "genteelli"),
+    ("ancestor", "ancestor"),
+    ("continuance", "continu"),
+    ("signifying", "signifi"),
+    ("sinew", "sinew"),
+    ("charging", "charg"),
+    ("overthrow", "overthrow"),
+    ("forces", "forc"),
+    ("daughters", "daughter"),
+    ("mausoleums", "mausoleum"),
+    ("occupiers", "occupi"),
+    ("altars", "altar"),
+    ("gunner", "gunner"),
+    ("exemplified", "exemplifi"),
+    ("waylay", "waylai"),
+    ("mercies", "merci"),
+    ("crust", "crust"),
+    ("xxxviii", "xxxviii"),
+    ("debar", "debar"),
+    ("methods", "method"),
+    ("impracticality", "impract"),
+    ("tickled", "tickl"),
+    ("grammatical", "grammat"),
+    ("ignore", "ignor"),
+    ("eagerness", "eager"),
+    ("pelham", "pelham"),
+    ("extinguishes", "extinguish"),
+    ("dingy", "dingi"),
+    ("reprove", "reprov"),
+    ("horrified", "horrifi"),
+    ("mistress", "mistress"),
+    ("polypi", "polypi"),
+    ("telescopic", "telescop"),
+    ("puna", "puna"),
+    ("enlarged", "enlarg"),
+    ("reminder", "remind"),
+    ("patches", "patch"),
+    ("unhappiness", "unhappi"),
+    ("clasping", "clasp"),
+    ("theirselves", "theirselv"),
+    ("briskly", "briskli"),
+    ("flung", "flung"),
+    ("occupations", "occupaz"),
+    ("tew", "tew"),
+    ("covered", "cover"),
+    ("answers", "answer"),
+    ("behaviour", "behaviour"),
+    ("ned", "ned"),
+    ("passionless", "passionless"),
+    ("remindingmanfact", "remindingmanfact"),
+    ("protected", "protect"),
+    ("tasting", "tast"),
+    ("assayer", "assayer"),
+    ("attracts", "attract"),
+    ("bladed", "blade"),
+    ("sterile", "steril"),
+    ("mortals", "mortal"),
+    ("brochure", "brochur"),
+    ("ringlets", "ringlet"),
+    ("murderer", "murder"),
+    ("plumpest", "plumpest"),
+    ("hd", "hd"),
+    ("crash", "crash"),
+    ("reeght", "reeght"),
+    ("ees", "ee"),
+    ("furnished", "furnish"),
+    ("cling", "cling"),
+    ("nea", "nea"),
+    ("ulloa", "ulloa"),
+    ("unlearnt", "unlearnt"),
+    ("brightest", "brightest"),
+    ("neeburs", "neebur"),
+    ("cowardice", "cowardic"),
+    ("declaim", "declaim"),
+    ("outside", "outsid"),
+    ("idea", "idea"),
+    ("review", "review"),
+    ("hut", "hut"),
+    ("seduced", "seduc"),
+    ("xliv", "xliv"),
+    ("laval", "laval"),
+    ("bloomsbury", "bloomsburi"),
+    ("politics", "polit"),
+    ("helm", "helm"),
+    ("free", "free"),
+    ("untwisting", "untwist"),
+    ("swarming", "swarm"),
+    ("buenos", "bueno"),
+    ("hampered", "hamper"),
+    ("scorpions", "scorpion"),
+    ("encroached", "encroach"),
+    ("geologists", "geologist"),
+    ("lacks", "lack"),
+    ("muffy", "muffi"),
+    ("myriads", "myriad"),
+    ("knits", "knit"),
+    ("manifestly", "manifestli"),
+    ("whiff", "whiff"),
+    ("subtracted", "subtract"),
+    ("rarest", "rarest"),
+    ("employs", "emploi"),
+    ("anguish", "anguish"),
+    ("conductress", "conductress"),
+    ("quarrel", "quarrel"),
+    ("tuck", "tuck"),
+    ("intents", "intent"),
+    ("sup", "sup"),
+    ("crest", "crest"),
+    ("memorable", "memor"),
+    ("bantam", "bantam"),
+    ("dispatch", "dispatch"),
+    ("bets", "bet"),
+    ("administering", "administ"),
+    ("woebegone", "woebegon"),
+    ("allude", "allud"),
+    ("cheerfully", "cheerfulli"),
+    ("encomium", "encomium"),
+    ("buccaneers", "buccan"),
+    ("exhausted", "exhaust"),
+    ("lichens", "lichen"),
+    ("subjects", "subject"),
+    ("heaviness", "heavi"),
+    ("oversleep", "oversleep"),
+    ("turbo", "turbo"),
+    ("litany", "litani"),
+    ("dilemma", "dilemma"),
+    ("hoonger", "hoonger"),
+    ("unifor", "unifor"),
+    ("directly", "directli"),
+    ("abrupt", "abrupt"),
+    ("monsters", "monster"),
+    ("lateral", "later"),
+    ("macrauchenia", "macrauchenia"),
+    ("thorn", "thorn"),
+    ("reluctance", "reluct"),
+    ("absurd", "absurd"),
+    ("spasmodic", "spasmod"),
+    ("ruins", "ruin"),
+    ("unfitted", "unfit"),
+    ("amarga", "amarga"),
+    ("appetite", "appetit"),
+    ("insulting", "insult"),
+    ("neath", "neath"),
+    ("pauline", "paulin"),
+    ("repulsive", "repuls"),
+    ("mirthfulness", "mirth"),
+    ("indifference", "indiffer"),
+    ("speaking", "speak"),
+    ("drovers", "drover"),
+    ("indecorously", "indecor"),
+    ("condescended", "condescend"),
+    ("plucking", "pluck"),
+    ("providence", "provid"),
+    ("bequest", "bequest"),
+    ("derided", "derid"),
+    ("rustle", "rustl"),
+    ("where", "where"),
+    ("stab", "stab"),
+    ("rock", "rock"),
+    ("shin", "shin"),
+    ("solecisms", "solec"),
+    ("wigwam", "wigwam"),
+    ("hymenophallus", "hymenophallu"),
+    ("reckoners", "reckon"),
+    ("slate", "slate"),
+    ("roe", "roe"),
+    ("cease", "ceas"),
+    ("entire", "entir"),
+    ("vertical", "vertic"),
+    ("stond", "stond"),
+    ("retaliation", "retaliaz"),
+    ("elders", "elder"),
+    ("recognizing", "recogn"),
+    ("ached", "ach"),
+    ("clove", "clove"),
+    ("bunch", "bunch"),
+    ("lazy", "lazi"),
+    ("implicit", "implicit"),
+    ("exults", "exult"),
+    ("scape", "scape"),
+    ("fractious", "fractiou"),
+    ("particularised", "particularis"),
+    ("abruptness", "abrupt"),
+    ("morsels", "morsel"),
+    ("pedestrian", "pedestrian"),
+    ("treads", "tread"),
+    ("turtledoves", "turtledov"),
+    ("teasing", "teas"),
+    ("keeling", "keel"),
+    ("superabundant", "superabund"),
+    ("wolsey", "wolsei"),
+    ("disrespect", "disrespect"),
+    ("dismay", "dismai"),
+    ("deciding", "decid"),
+    ("ighway", "ighwai"),
+    ("satisfaction", "satisfact"),
+    ("humble", "humbl"),
+    ("entombment", "entomb"),
+    ("laderas", "ladera"),
+    ("characterized", "character"),
+    ("chiel", "chiel"),
+    ("milestone", "mileston"),
+    ("core", "core"),
+    ("robustus", "robustu"),
+    ("involuntarily", "involuntarili"),
+    ("herrings", "her"),
+    ("displeasure", "displeasur"),
+    ("gigas", "giga"),
+    ("hostilities", "hostil"),
+    ("cherishes", "cherish"),
+    ("mamma", "mamma"),
+    ("demon", "demon"),
+    ("unsteadier", "unsteadi"),
+    ("vanishing", "vanish"),
+    ("rims", "rim"),
+    ("tan", "tan"),
+    ("smothered", "smother"),
+    ("gelatinous", "gelatin"),
+    ("mortgage", "mortgag"),
+    ("reported", "report"),
+    ("risked", "risk"),
+    ("ducal", "ducal"),
+    ("discouragement", "discourag"),
+    ("privilege", "privileg"),
+    ("horseback", "horseback"),
+    ("geometrical", "geometr"),
+    ("casually", "casual"),
+    ("buttonhole", "buttonhol"),
+    ("uruguay", "uruguai"),
+    ("prodigy", "prodigi"),
+    ("emboldening", "embolden"),
+    ("dotes", "dote"),
+    ("cramming", "cram"),
+    ("nodules", "nodul"),
+    ("publicity", "public"),
+    ("campestris", "campestri"),
+    ("excesses", "excess"),
+    ("volunteering", "volunt"),
+    ("reticule", "reticul"),
+    ("twas", "twa"),
+    ("vagrants", "vagrant"),
+    ("navigating", "navig"),
+    ("vinegar", "vinegar"),
+    ("autres", "autr"),
+    ("reel", "reel"),
+    ("prefacing", "prefac"),
+    ("admirers", "admir"),
+    ("smash", "smash"),
+    ("scented", "scent"),
+    ("skinny", "skinni"),
+    ("barefooted", "barefoot"),
+    ("descending", "descend"),
+    ("brains", "brain"),
+    ("counted", "count"),
+    ("snake", "snake"),
+    ("agouti", "agouti"),
+    ("captives", "captiv"),
+    ("lumpy", "lumpi"),
+    ("jokers", "joker"),
+    ("divorce", "divorc"),
+    ("smutty", "smutti"),
+    ("vacancies", "vacanc"),
+    ("narratives", "narr"),
+    ("obeying", "obei"),
+    ("discharges", "discharg"),
+    ("intervals", "interv"),
+    ("caustic", "caustic"),
+    ("intuition", "intuit"),
+    ("changed", "chang"),
+    ("operate", "oper"),
+    ("skulks", "skulk"),
+    ("lands", "land"),
+    ("fervency", "fervenc"),
+    ("realty", "realti"),
+    ("resfless", "resfless"),
+    ("pausilippo", "pausilippo"),
+    ("kinder", "kinder"),
+    ("in", "in"),
+    ("unwearying", "unweari"),
+    ("treasures", "treasur"),
+    ("madness", "mad"),
+    ("wheeled", "wheel"),
+    ("neighing", "neigh"),
+    ("onus", "onu"),
+    ("novae", "nova"),
+    ("comprehended", "comprehend"),
+    ("did", "did"),
+    ("singer", "singer"),
+    ("fuel", "fuel"),
+    ("possessor", "possessor"),
+    ("pleasanter", "pleasant"),
+    ("loving", "love"),
+    ("hornos", "horno"),
+    ("unoffending", "unoffend"),
+    ("sisterly", "sisterli"),
+    ("reflector", "reflector"),
+    ("typhus", "typhu"),
+    ("amicably", "amic"),
+    ("halfpennyworth", "halfpennyworth"),
+    ("weapons", "weapon"),
+    ("soot", "soot"),
+    ("coxswain", "coxswain"),
+    ("angelic", "angel"),
+    ("remainder", "remaind"),
+    ("jointly", "jointli"),
+    ("hilaire", "hilair"),
+    ("bribe", "bribe"),
+    ("interrogating", "interrog"),
+    ("abounding", "abound"),
+    ("prospective", "prospect"),
+    ("mouchoir", "mouchoir"),
+    ("descrip", "descrip"),
+    ("demanded", "demand"),
+    ("strains", "strain"),
+    ("extremum", "extremum"),
+    ("islet", "islet"),
+    ("imbecility", "imbecil"),
+    ("armourer", "armour"),
+    ("bygone", "bygon"),
+    ("alderman", "alderman"),
+    ("contrairy", "contrairi"),
+    ("psha", "psha"),
+    ("basaltic", "basalt"),
+    ("folded", "fold"),
+    ("neight", "neight"),
+    ("thier", "thier"),
+    ("artfully", "artfulli"),
+    ("tolstyakov", "tolstyakov"),
+    ("aud", "aud"),
+    ("soun", "soun"),
+    ("snappishly", "snappishli"),
+    ("twang", "twang"),
+    ("inferring", "infer"),
+    ("disorderly", "disorderli"),
+    ("shopkeeper", "shopkeep"),
+    ("haze", "haze"),
+    ("fury", "furi"),
+    ("power", "power"),
+    ("neuralgia", "neuralgia"),
+    ("pools", "pool"),
+    ("mandate", "mandat"),
+    ("paltry", "paltri"),
+    ("vol", "vol"),
+    ("fittest", "fittest"),
+    ("betokening", "betoken"),
+    ("foxes", "fox"),
+    ("bedchamber", "bedchamb"),
+    ("mounted", "mount"),
+    ("remit", "remit"),
+    ("howl", "howl"),
+    ("berrin", "berrin"),
+    ("query", "queri"),
+    ("shil", "shil"),
+    ("compelled", "compel"),
+    ("luxuriously", "luxuri"),
+    ("legally", "legal"),
+    ("stationary", "stationari"),
+    ("produces", "produc"),
+    ("composition", "composit"),
+    ("locomotives", "locomot"),
+    ("crusader", "crusad"),
+    ("crier", "crier"),
+    ("genuinely", "genuin"),
+    ("imeantersay", "imeantersai"),
+    ("agriculturists", "agriculturist"),
+    ("de", "de"),
+    ("heartless", "heartless"),
+    ("bare", "bare"),
+    ("regain", "regain"),
+    ("placards", "placard"),
+    ("shameless", "shameless"),
+    ("eared", "ear"),
+    ("meanness", "mean"),
+    ("improbably", "improb"),
+    ("tidings", "tide"),
+    ("tattered", "tatter"),
+    ("peruse", "perus"),
+    ("circuitous", "circuit"),
+    ("filtered", "filter"),
+    ("ministered", "minist"),
+    ("hurrahs", "hurrah"),
+    ("comrades", "comrad"),
+    ("anat", "anat"),
+    ("baptism", "baptism"),
+    ("witted", "wit"),
+    ("fretted", "fret"),
+    ("asiat", "asiat"),
+    ("enable", "enabl"),
+    ("friendliest", "friendliest"),
+    ("bloodlessness", "bloodless"),
+    ("chill", "chill"),
+    ("waterloo", "waterloo"),
+    ("heeds", "heed"),
+    ("moveable", "moveabl"),
+    ("plight", "plight"),
+    ("inconveniences", "inconveni"),
+    ("multiplying", "multipli"),
+    ("conflicting", "conflict"),
+    ("cholera", "cholera"),
+    ("discompose", "discompos"),
+    ("contrive", "contriv"),
+    ("overleap", "overleap"),
+    ("pistoling", "pistol"),
+    ("f", "f"),
+    ("prospect", "prospect"),
+    ("underlip", "underlip"),
+    ("lain", "lain"),
+    ("vessel", "vessel"),
+    ("disgraced", "disgrac"),
+    ("endurable", "endur"),
+    ("katharina", "katharina"),
+    ("flute", "flute"),
+    ("mitchell", "mitchel"),
+    ("cheshires", "cheshir"),
+    ("negus", "negu"),
+    ("coast", "coast"),
+    ("wery", "weri"),
+    ("toils", "toil"),
+    ("anniversaries", "anniversari"),
+    ("jupiter", "jupit"),
+    ("explored", "explor"),
+    ("sweep", "sweep"),
+    ("drowsiness", "drowsi"),
+    ("sock", "sock"),
+    ("cerro", "cerro"),
+    ("scrolls", "scroll"),
+    ("mediator", "mediat"),
+    ("gentle", "gentl"),
+    ("blow", "blow"),
+    ("begat", "begat"),
+    ("slackened", "slacken"),
+    ("monotony", "monotoni"),
+    ("pondering", "ponder"),
+    ("straining", "strain"),
+    ("waltzes", "waltz"),
+    ("stopper", "stopper"),
+    ("beaufort", "beaufort"),
+    ("banishe", "banish"),
+    ("thunderstorm", "thunderstorm"),
+    ("projected", "project"),
+    ("palpitation", "palpitaz"),
+    ("runaways", "runawai"),
+    ("blockade", "blockad"),
+    ("hairy", "hairi"),
+    ("forbore", "forbor"),
+    ("badinage", "badinag"),
+    ("thoughtfulness", "thought"),
+    ("replied", "repli"),
+    ("commencing", "commenc"),
+    ("stew", "stew"),
+    ("boles", "bole"),
+    ("climbing", "climb"),
+    ("almighty", "almighti"),
+    ("parched", "parch"),
+    ("poast", "poast"),
+    ("insubordination", "insubordinaz"),
+    ("stubbs", "stubb"),
+    ("loves", "love"),
+    ("believer", "believ"),
+    ("wonders", "wonder"),
+    ("metalliferous", "metallifer"),
+    ("duodenum", "duodenum"),
+    ("adoption", "adopt"),
+    ("court", "court"),
+    ("churlishness", "churlish"),
+    ("cooks", "cook"),
+    ("tissue", "tissu"),
+    ("visionary", "visionari"),
+    ("avenue", "avenu"),
+    ("alehouses", "alehous"),
+    ("tolerably", "toler"),
+    ("fretfully", "fretfulli"),
+    ("porters", "porter"),
+    ("leicestershire", "leicestershir"),
+    ("shrewdness", "shrewd"),
+    ("misdemeanours", "misdemeanour"),
+    ("filed", "file"),
+    ("forlorn", "forlorn"),
+    ("european", "european"),
+    ("curb", "curb"),
+    ("potanchikov", "potanchikov"),
+    ("forgiveness", "forgiv"),
+    ("series", "seri"),
+    ("unappeased", "unappeas"),
+    ("precipitated", "precipit"),
+    ("hush", "hush"),
+    ("experimented", "experi"),
+    ("worshipped", "worship"),
+    ("grieving", "griev"),
+    ("asks", "ask"),
+    ("apex", "apex"),
+    ("complied", "compli"),
+    ("edental", "edent"),
+    ("executors", "executor"),
+    ("worthy", "worthi"),
+    ("expressly", "expressli"),
+    ("programme", "programm"),
+    ("trenham", "trenham"),
+    ("israelites", "israelit"),
+    ("likelier", "likeli"),
+    ("hampshire", "hampshir"),
+    ("intrusted", "intrust"),
+    ("lopped", "lop"),
+    ("thankee", "thanke"),
+    ("rustily", "rustili"),
+    ("flys", "fli"),
+    ("cruenta", "cruenta"),
+    ("autre", "autr"),
+    ("disused", "disus"),
+    ("unknowing", "unknow"),
+    ("lizards", "lizard"),
+    ("repugnance", "repugn"),
+    ("profanely", "profan"),
+    ("onward", "onward"),
+    ("stager", "stager"),
+    ("wary", "wari"),
+    ("greater", "greater"),
+    ("officered", "offic"),
+    ("broke", "broke"),
+    ("astonish", "astonish"),
+    ("furiously", "furious"),
+    ("isobel", "isobel"),
+    ("opens", "open"),
+    ("wrecked", "wreck"),
+    ("astir", "astir"),
+    ("him", "him"),
+    ("shelley", "shellei"),
+    ("soldiers", "soldier"),
+    ("nearer", "nearer"),
+    ("rodeo", "rodeo"),
+    ("boar", "boar"),
+    ("patrician", "patrician"),
+    ("reservoirs", "reservoir"),
+    ("resolve", "resolv"),
+    ("vapoury", "vapouri"),
+    ("crevez", "crevez"),
+    ("sympathetically", "sympathet"),
+    ("assured", "assur"),
+    ("pelacanoides", "pelacanoid"),
+    ("thatched", "thatch"),
+    ("fiancee", "fiance"),
+    ("toughness", "tough"),
+    ("decorative", "decor"),
+    ("preponderates", "preponder"),
+    ("brambles", "brambl"),
+    ("fenced", "fenc"),
+    ("depart", "depart"),
+    ("discount", "discount"),
+    ("mirror", "mirror"),
+    ("taunton", "taunton"),
+    ("williams", "william"),
+    ("measthers", "measther"),
+    ("defiances", "defianc"),
+    ("inlaid", "inlaid"),
+    ("caledonia", "caledonia"),
+    ("gauze", "gauz"),
+    ("lull", "lull"),
+    ("distinctions", "distinct"),
+    ("tracks", "track"),
+    ("storeys", "storei"),
+    ("alienated", "alien"),
+    ("ludicrous", "ludicr"),
+    ("grouping", "group"),
+    ("deception", "decept"),
+    ("juices", "juic"),
+    ("boarders", "boarder"),
+    ("lik", "lik"),
+    ("subgroups", "subgroup"),
+    ("democracy", "democraci"),
+    ("sabbath", "sabbath"),
+    ("business", "busi"),
+    ("protrudes", "protrud"),
+    ("obtrusive", "obtrus"),
+    ("bookworms", "bookworm"),
+    ("recreative", "recr"),
+    ("caressed", "caress"),
+    ("roams", "roam"),
+    ("fame", "fame"),
+    ("wanst", "wanst"),
+    ("demoralisation", "demoralisaz"),
+    ("ran", "ran"),
+    ("blindfolding", "blindfold"),
+    ("pressed", "press"),
+    ("magazine", "magazin"),
+    ("burly", "burli"),
+    ("sich", "sich"),
+    ("glares", "glare"),
+    ("bauble", "baubl"),
+    ("unpolite", "unpolit"),
+    ("unhealed", "unheal"),
+    ("necessitate", "necessit"),
+    ("friendships", "friendship"),
+    ("conquerable", "conquer"),
+    ("evasiveness", "evas"),
+    ("stoop", "stoop"),
+    ("pheasants", "pheasant"),
+    ("unselfish", "unselfish"),
+    ("gory", "gori"),
+    ("knightly", "knightli"),
+    ("smokes", "smoke"),
+    ("metallic", "metal"),
+    ("telling", "tell"),
+    ("drafts", "draft"),
+    ("owns", "own"),
+    ("navigation", "navigaz"),
+    ("triumphant", "triumphant"),
+    ("thinkable", "thinkabl"),
+    ("gov", "gov"),
+    ("negro", "negro"),
+    ("retaliated", "retali"),
+    ("surweys", "surwei"),
+    ("eatables", "eatabl"),
+    ("embellishments", "embellish"),
+    ("kingdom", "kingdom"),
+    ("clothing", "cloth"),
+    ("redder", "redder"),
+    ("tyler", "tyler"),
+    ("axe", "ax"),
+    ("united", "unit"),
+    ("widder", "widder"),
+    ("sasiated", "sasiat"),
+    ("tremors", "tremor"),
+    ("mayhap", "mayhap"),
+    ("gratifies", "gratifi"),
+    ("unrest", "unrest"),
+    ("touching", "touch"),
+    ("histoire", "histoir"),
+    ("plaisir", "plaisir"),
+    ("searching", "search"),
+    ("quagmire", "quagmir"),
+    ("thankless", "thankless"),
+    ("undressed", "undress"),
+    ("dewdrop", "dewdrop"),
+    ("taker", "taker"),
+    ("smartly", "smartli"),
+    ("donkeys", "donkei"),
+    ("unfortunate", "unfortun"),
+    ("minas", "mina"),
+    ("lectures", "lectur"),
+    ("overslept", "overslept"),
+    ("mackenzie", "mackenzi"),
+    ("syrphus", "syrphu"),
+    ("betraying", "betrai"),
+    ("republic", "republ"),
+    ("prentices", "prentic"),
+    ("confervae", "conferva"),
+    ("conception", "concept"),
+    ("connection", "connect"),
+    ("solicitor", "solicitor"),
+    ("partisans", "partisan"),
+    ("corrected", "correct"),
+    ("horders", "horder"),
+    ("sententiously", "sententi"),
+    ("none", "none"),
+    ("cayenne", "cayenn"),
+    ("covert", "covert"),
+    ("resigned", "resign"),
+    ("fooleries", "fooleri"),
+    ("leaf", "leaf"),
+    ("writes", "write"),
+    ("moliere", "molier"),
+    ("handcuffed", "handcuf"),
+    ("solemnities", "solemn"),
+    ("jogged", "jog"),
+    ("gorging", "gorg"),
+    ("livers", "liver"),
+    ("gravely", "grave"),
+    ("paragraphs", "paragraph"),
+    ("fitter", "fitter"),
+    ("dines", "dine"),
+    ("unbidden", "unbidden"),
+    ("variance", "varianc"),
+    ("occurring", "occur"),
+    ("valley", "vallei"),
+    ("repellent", "repel"),
+    ("mishap", "mishap"),
+    ("overwrought", "overwrought"),
+    ("vera", "vera"),
+    ("rigidly", "rigidli"),
+    ("heartrending", "heartrend"),
+    ("greeted", "greet"),
+    ("coach", "coach"),
+    ("whimpers", "whimper"),
+    ("maritime", "maritim"),
+    ("fugitives", "fugit"),
+    ("leered", "leer"),
+    ("imputing", "imput"),
+    ("principles", "principl"),
+    ("ringleaders", "ringlead"),
+    ("babes", "babe"),
+    ("appendages", "appendag"),
+    ("prolific", "prolif"),
+    ("plotters", "plotter"),
+    ("tails", "tail"),
+    ("charitably", "charit"),
+    ("swaller", "swaller"),
+    ("lanterns", "lantern"),
+    ("der", "der"),
+    ("impatient", "impati"),
+    ("unpractical", "unpract"),
+    ("toast", "toast"),
+    ("aeqam", "aeqam"),
+    ("rivet", "rivet"),
+    ("crawled", "crawl"),
+    ("convalescence", "convalesc"),
+    ("proportionably", "proportion"),
+    ("devolved", "devolv"),
+    ("mean", "mean"),
+    ("corroboratory", "corroboratori"),
+    ("nimrod", "nimrod"),
+    ("serf", "serf"),
+    ("trellised", "trellis"),
+    ("particularity", "particular"),
+    ("dexterity", "dexter"),
+    ("soaring", "soar"),
+    ("diversity", "divers"),
+    ("anxieties", "anxieti"),
+    ("eden", "eden"),
+    ("throws", "throw"),
+    ("donation", "donaz"),
+    ("derived", "deriv"),
+    ("backsheesh", "backsheesh"),
+    ("perspective", "perspect"),
+    ("confound", "confound"),
+    ("sway", "swai"),
+    ("pillow", "pillow"),
+    ("gunless", "gunless"),
+    ("wakes", "wake"),
+    ("chess", "chess"),
+    ("hostlers", "hostler"),
+    ("supplementary", "supplementari"),
+    ("awl", "awl"),
+    ("latest", "latest"),
+    ("reappeared", "reappear"),
+    ("physic", "physic"),
+    ("ravishing", "ravish"),
+    ("moralizing", "moral"),
+    ("polynesia", "polynesia"),
+    ("insuperable", "insuper"),
+    ("detail", "detail"),
+    ("soused", "sous"),
+    ("reassurance", "reassur"),
+    ("dense", "dens"),
+    ("unborn", "unborn"),
+    ("grating", "grate"),
+    ("browsed", "brows"),
+    ("flower", "flower"),
+    ("hiccuped", "hiccup"),
+    ("wending", "wend"),
+    ("exceptions", "except"),
+    ("esther", "esther"),
+    ("has", "ha"),
+    ("shar", "shar"),
+    ("warriors", "warrior"),
+    ("latter", "latter"),
+    ("entomology", "entomologi"),
+    ("sobered", "sober"),
+    ("nor", "nor"),
+    ("clamorous", "clamor"),
+    ("original", "origin"),
+    ("engagmg", "engagmg"),
+    ("extinguished", "extinguish"),
+    ("appreciate", "appreci"),
+    ("clapping", "clap"),
+    ("narrates", "narrat"),
+    ("channels", "channel"),
+    ("consumers", "consum"),
+    ("solution", "solut"),
+    ("occurrences", "occurr"),
+    ("cesspool", "cesspool"),
+    ("parsonage", "parsonag"),
+    ("boans", "boan"),
+    ("contractors", "contractor"),
+    ("jet", "jet"),
+    ("mon", "mon"),
+    ("propitiatory", "propitiatori"),
+    ("beverage", "beverag"),
+    ("fallen", "fallen"),
+    ("percival", "perciv"),
+    ("delighting", "delight"),
+    ("corynetes", "corynet"),
+    ("intruder", "intrud"),
+    ("flycatchers", "flycatch"),
+    ("injunctions", "injunct"),
+    ("samovar", "samovar"),
+    ("fens", "fen"),
+    ("woes", "woe"),
+    ("preservation", "preservaz"),
+    ("stoves", "stove"),
+    ("disjoin", "disjoin"),
+    ("dismissal", "dismiss"),
+    ("nowt", "nowt"),
+    ("grated", "grate"),
+    ("wrens", "wren"),
+    ("mature", "matur"),
+    ("organization", "organ"),
+    ("unit", "unit"),
+    ("freestone", "freeston"),
+    ("reperusal", "reperus"),
+    ("whol", "whol"),
+    ("polly", "polli"),
+    ("manuscript", "manuscript"),
+    ("quizzical", "quizzic"),
+    ("ploughshares", "ploughshar"),
+    ("yo", "yo"),
+    ("minuteness", "minut"),
+    ("complications", "complicaz"),
+    ("dissatisfaction", "dissatisfact"),
+    ("itching", "itch"),
+    ("reminding", "remind"),
+    ("remand", "remand"),
+    ("vilipilli", "vilipilli"),
+    ("damnably", "damnabl"),
+    ("cunicularius", "cuniculariu"),
+    ("unsaddle", "unsaddl"),
+    ("finsbury", "finsburi"),
+    ("enjoyed", "enjoi"),
+    ("deduction", "deduct"),
+    ("gardners", "gardner"),
+    ("fuffy", "fuffi"),
+    ("requisites", "requisit"),
+    ("broune", "broun"),
+    ("puny", "puni"),
+    ("squiress", "squiress"),
+    ("sometimes", "sometim"),
+    ("sancho", "sancho"),
+    ("genera", "genera"),
+    ("diet", "diet"),
+    ("mausoleum", "mausoleum"),
+    ("tall", "tall"),
+    ("flushing", "flush"),
+    ("tahiti", "tahiti"),
+    ("overjoyed", "overjoi"),
+    ("baboon", "baboon"),
+    ("damaging", "damag"),
+    ("seeme", "seem"),
+    ("skimpole", "skimpol"),
+    ("arrival", "arriv"),
+    ("reiterates", "reiter"),
+    ("saintly", "saintli"),
+    ("solemnly", "solemnli"),
+    ("leader", "leader"),
+    ("contracting", "contract"),
+    ("allowance", "allow"),
+    ("girth", "girth"),
+    ("quarrelled", "quarrel"),
+    ("irreproachable", "irreproach"),
+    ("snatching", "snatch"),
+    ("mullins", "mullin"),
+    ("weakened", "weaken"),
+    ("reconcilements", "reconcil"),
+    ("truth", "truth"),
+    ("grimy", "grimi"),
+    ("rapping", "rap"),
+    ("cruises", "cruis"),
+    ("terms", "term"),
+    ("penniless", "penniless"),
+    ("jealousies", "jealousi"),
+    ("prance", "pranc"),
+    ("grandmothers", "grandmoth"),
+    ("burrows", "burrow"),
+    ("caracter", "caract"),
+    ("resembling", "resembl"),
+    ("grows", "grow"),
+    ("nearest", "nearest"),
+    ("securely", "secur"),
+    ("reactionaries", "reactionari"),
+    ("bashful", "bash"),
+    ("levees", "leve"),
+    ("parable", "parabl"),
+    ("smallweed", "smallwe"),
+    ("prowled", "prowl"),
+    ("fixedly", "fixedli"),
+    ("dampness", "damp"),
+    ("piecemeal", "piecem"),
+    ("nummularia", "nummularia"),
+    ("cudgels", "cudgel"),
+    ("chacao", "chacao"),
+    ("delaval", "delav"),
+    ("pariah", "pariah"),
+    ("humbug", "humbug"),
+    ("obscures", "obscur"),
+    ("exclaim", "exclaim"),
+    ("ejected", "eject"),
+    ("amused", "amus"),
+    ("animalcule", "animalcul"),
+    ("immutable", "immut"),
+    ("elephants", "eleph"),
+    ("italian", "italian"),
+    ("cicadas", "cicada"),
+    ("thank", "thank"),
+    ("entwine", "entwin"),
+    ("littoral", "littor"),
+    ("jove", "jove"),
+    ("harkee", "harke"),
+    ("apologised", "apologis"),
+    ("needs", "need"),
+    ("nostril", "nostril"),
+    ("amusement", "amus"),
+    ("moved", "move"),
+    ("marmeladov", "marmeladov"),
+    ("bolts", "bolt"),
+    ("irradiated", "irradi"),
+    ("dursn", "dursn"),
+    ("pilgrimage", "pilgrimag"),
+    ("grateful", "grate"),
+    ("merest", "merest"),
+    ("reversed", "revers"),
+    ("airnest", "airnest"),
+    ("scaffolding", "scaffold"),
+    ("whitsunday", "whitsundai"),
+    ("specialty", "specialti"),
+    ("miscalculated", "miscalcul"),
+    ("devils", "devil"),
+    ("unplaced", "unplac"),
+    ("obtaining", "obtain"),
+    ("proximity", "proxim"),
+    ("cantal", "cantal"),
+    ("enlightened", "enlighten"),
+    ("ogre", "ogr"),
+    ("conquered", "conquer"),
+    ("heaps", "heap"),
+    ("items", "item"),
+    ("waterside", "watersid"),
+    ("moderately", "moder"),
+    ("landseer", "landseer"),
+    ("regulating", "regul"),
+    ("summat", "summat"),
+    ("intently", "intent"),
+    ("resuming", "resum"),
+    ("untainted", "untaint"),
+    ("shuffled", "shuffl"),
+    ("heretics", "heret"),
+    ("imputation", "imputaz"),
+    ("begrudge", "begrudg"),
+    ("proceeded", "proce"),
+    ("poising", "pois"),
+    ("unkind", "unkind"),
+    ("regulars", "regular"),
+    ("imprudence", "imprud"),
+    ("divan", "divan"),
+    ("internal", "intern"),
+    ("whimpered", "whimper"),
+    ("glistened", "glisten"),
+    ("dated", "date"),
+    ("gamesters", "gamest"),
+    ("taylor", "taylor"),
+    ("monarchs", "monarch"),
+    ("depends", "depend"),
+    ("rakishness", "rakish"),
+    ("exacted", "exact"),
+    ("kindnesses", "kind"),
+    ("sixteenth", "sixteenth"),
+    ("cradles", "cradl"),
+    ("parchment", "parchment"),
+    ("voter", "voter"),
+    ("railings", "rail"),
+    ("nursed", "nurs"),
+    ("avenges", "aveng"),
+    ("honeymoon", "honeymoon"),
+    ("craning", "crane"),
+    ("curtsey", "curtsei"),
+    ("tata", "tata"),
+    ("callings", "call"),
+    ("tired", "tire"),
+    ("shouldest", "shouldest"),
+    ("aniska", "aniska"),
+    ("read", "read"),
+    ("astringent", "astring"),
+    ("whiteness", "white"),
+    ("hochbeseeltes", "hochbeseelt"),
+    ("denoted", "denot"),
+    ("confederates", "confeder"),
+    ("verge", "verg"),
+    ("gloomier", "gloomier"),
+    ("scrubby", "scrubbi"),
+    ("firebrand", "firebrand"),
+    ("neckkerchief", "neckkerchief"),
+    ("siphon", "siphon"),
+    ("ascribed", "ascrib"),
+    ("represented", "repres"),
+    ("admiral", "admir"),
+    ("pedro", "pedro"),
+    ("prowling", "prowl"),
+    ("circle", "circl"),
+    ("meditated", "medit"),
+    ("sand", "sand"),
+    ("hovered", "hover"),
+    ("consequent", "consequ"),
+    ("credulous", "credul"),
+    ("bliss", "bliss"),
+    ("diameter", "diamet"),
+    ("aspirated", "aspir"),
+    ("menage", "menag"),
+    ("poles", "pole"),
+    ("chinas", "china"),
+    ("patrick", "patrick"),
+    ("misuses", "misus"),
+    ("silex", "silex"),
+    ("windings", "wind"),
+    ("epilogue", "epilogu"),
+    ("metamorphic", "metamorph"),
+    ("canno", "canno"),
+    ("scrupulous", "scrupul"),
+    ("hindered", "hinder"),
+    ("nose", "nose"),
+    ("counter", "counter"),
+    ("roost", "roost"),
+    ("digestio", "digestio"),
+    ("walter", "walter"),
+    ("syenite", "syenit"),
+    ("compressed", "compress"),
+    ("juggle", "juggl"),
+    ("guardian", "guardian"),
+    ("conten", "conten"),
+    ("fashionahle", "fashionahl"),
+    ("blameable", "blameabl"),
+    ("ninnies", "ninni"),
+    ("minimum", "minimum"),
+    ("fable", "fabl"),
+    ("distributing", "distribut"),
+    ("lacing", "lace"),
+    ("gregsbury", "gregsburi"),
+    ("enhance", "enhanc"),
+    ("mirthful", "mirth"),
+    ("fervent", "fervent"),
+    ("professionally", "profession"),
+    ("enormous", "enorm"),
+    ("stratford", "stratford"),
+    ("lodge", "lodg"),
+    ("x", "x"),
+    ("magnetic", "magnet"),
+    ("grieved", "griev"),
+    ("census", "censu"),
+    ("fyodorovna", "fyodorovna"),
+    ("commencements", "commenc"),
+    ("narrowing", "narrow"),
+    ("expeditiously", "expediti"),
+    ("sins", "sin"),
+    ("parasol", "parasol"),
+    ("araucarian", "araucarian"),
+    ("maledictions", "maledict"),
+    ("saltenfiord", "saltenfiord"),
+    ("insight", "insight"),
+    ("pug", "pug"),
+    ("fathom", "fathom"),
+    ("pressingly", "pressingli"),
+    ("meanest", "meanest"),
+    ("loaf", "loaf"),
+    ("infallibility", "infal"),
+    ("boytborn", "boytborn"),
+    ("ellore", "ellor"),
+    ("shrivel", "shrivel"),
+    ("nobbiest", "nobbiest"),
+    ("groan", "groan"),
+    ("appearing", "appear"),
+    ("responsible", "respons"),
+    ("griffith", "griffith"),
+    ("knowledg", "knowledg"),
+    ("injured", "injur"),
+    ("kensington", "kensington"),
+    ("briny", "brini"),
+    ("necktie", "neckti"),
+    ("inhaling", "inhal"),
+    ("fife", "fife"),
+    ("java", "java"),
+    ("delights", "delight"),
+    ("knuckles", "knuckl"),
+    ("madden", "madden"),
+    ("whipper", "whipper"),
+    ("politic", "polit"),
+    ("avowed", "avow"),
+    ("maimed", "maim"),
+    ("offense", "offens"),
+    ("its", "it"),
+    ("mud", "mud"),
+    ("reined", "rein"),
+    ("debase", "debas"),
+    ("swan", "swan"),
+    ("contortions", "contort"),
+    ("unschooled", "unschool"),
+    ("union", "union"),
+    ("imperils", "imperil"),
+    ("anyone", "anyon"),
+    ("perturbation", "perturbaz"),
+    ("unhandsome", "unhandsom"),
+    ("eschara", "eschara"),
+    ("shell", "shell"),
+    ("summoning", "summon"),
+    ("dropped", "drop"),
+    ("unimproved", "unimprov"),
+    ("mexican", "mexican"),
+    ("crude", "crude"),
+    ("guffawing", "guffaw"),
+    ("bothered", "bother"),
+    ("conquerors", "conqueror"),
+    ("squint", "squint"),
+    ("pedigree", "pedigre"),
+    ("japan", "japan"),
+    ("perfidy", "perfidi"),
+    ("unities", "uniti"),
+    ("dissimulation", "dissimulaz"),
+    ("sisterhood", "sisterhood"),
+    ("misapprehend", "misapprehend"),
+    ("noticeable", "notic"),
+    ("lemons", "lemon"),
+    ("transition", "transit"),
+    ("tureen", "tureen"),
+    ("lobbies", "lobbi"),
+    ("civilization", "civil"),
+    ("ridges", "ridg"),
+    ("verse", "vers"),
+    ("dabber", "dabber"),
+    ("lorenzo", "lorenzo"),
+    ("stomach", "stomach"),
+    ("lintels", "lintel"),
+    ("cavernous", "cavern"),
+    ("spongey", "spongei"),
+    ("dashed", "dash"),
+    ("on", "on"),
+    ("races", "race"),
+    ("hovels", "hovel"),
+    ("pfoo", "pfoo"),
+    ("orations", "oraz"),
+    ("sharking", "shark"),
+    ("shriek", "shriek"),
+    ("growth", "growth"),
+    ("punt", "punt"),
+    ("furlongs", "furlong"),
+    ("nib", "nib"),
+    ("notebooks", "notebook"),
+    ("c", "c"),
+    ("delavals", "delav"),
+    ("pooh", "pooh"),
+    ("amassing", "amass"),
+    ("peacefully", "peacefulli"),
+    ("trimly", "trimli"),
+    ("climbers", "climber"),
+    ("heartiest", "heartiest"),
+    ("bony", "boni"),
+    ("lusts", "lust"),
+    ("supplications", "supplicaz"),
+    ("king", "king"),
+    ("safer", "safer"),
+    ("chief", "chief"),
+    ("deciphering", "deciph"),
+    ("whirlpool", "whirlpool"),
+    ("geneva", "geneva"),
+    ("knights", "knight"),
+    ("bereaved", "bereav"),
+    ("unobservant", "unobserv"),
+    ("punches", "punch"),
+    ("pepsis", "pepsi"),
+    ("chatham", "chatham"),
+    ("wrists", "wrist"),
+    ("enough", "enough"),
+    ("juillet", "juillet"),
+    ("inquisitively", "inquisit"),
+    ("indulged", "indulg"),
+    ("instruct", "instruct"),
+    ("atra", "atra"),
+    ("tuition", "tuition"),
+    ("treacle", "treacl"),
+    ("expletives", "explet"),
+    ("babushkin", "babushkin"),
+    ("supplies", "suppli"),
+    ("flooding", "flood"),
+    ("galaxy", "galaxi"),
+    ("testifying", "testifi"),
+    ("ocular", "ocular"),
+    ("tweak", "tweak"),
+    ("phenomenally", "phenomen"),
+    ("beseeches", "beseech"),
+    ("cliffs", "cliff"),
+    ("judy", "judi"),
+    ("yawning", "yawn"),
+    ("lambs", "lamb"),
+    ("complacently", "complac"),
+    ("chiefs", "chief"),
+    ("neither", "neither"),
+    ("rowling", "rowl"),
+    ("hustings", "hust"),
+    ("boddy", "boddi"),
+    ("importer", "import"),
+    ("ruminates", "rumin"),
+    ("anyway", "anywai"),
+    ("roar", "roar"),
+    ("entirety", "entireti"),
+    ("tapn", "tapn"),
+    ("porridge", "porridg"),
+    ("boer", "boer"),
+    ("burnous", "burnou"),
+    ("blackens", "blacken"),
+    ("robert", "robert"),
+    ("mayo", "mayo"),
+    ("piercingly", "piercingli"),
+    ("treatise", "treatis"),
+    ("tremor", "tremor"),
+    ("equitably", "equit"),
+    ("urgent", "urgent"),
+    ("abide", "abid"),
+    ("modifications", "modificaz"),
+    ("communities", "commun"),
+    ("tasselled", "tassel"),
+    ("semblance", "semblanc"),
+    ("savoir", "savoir"),
+    ("earning", "earn"),
+    ("carbine", "carbin"),
+    ("fared", "fare"),
+    ("consent", "consent"),
+    ("quivering", "quiver"),
+    ("mantling", "mantl"),
+    ("commendations", "commendaz"),
+    ("spruce", "spruce"),
+    ("counsellor", "counsellor"),
+    ("begrudged", "begrudg"),
+    ("tinder", "tinder"),
+    ("villechardouin", "villechardouin"),
+    ("patchwork", "patchwork"),
+    ("ev", "ev"),
+    ("beaters", "beater"),
+    ("aptitude", "aptitud"),
+    ("achieve", "achiev"),
+    ("proprieties", "proprieti"),
+    ("longwood", "longwood"),
+    ("afraid", "afraid"),
+    ("clank", "clank"),
+    ("severa", "severa"),
+    ("intersect", "intersect"),
+    ("harpy", "harpi"),
+    ("pomposities", "pompos"),
+    ("muffled", "muffl"),
+    ("hitchcock", "hitchcock"),
+    ("steered", "steer"),
+    ("vestige", "vestig"),
+    ("gizzard", "gizzard"),
+    ("observer", "observ"),
+    ("ropemakers", "ropemak"),
+    ("starvation", "starvaz"),
+    ("contrite", "contrit"),
+    ("alive", "aliv"),
+    ("kitty", "kitti"),
+    ("forms", "form"),
+    ("amputated", "amput"),
+    ("theodora", "theodora"),
+    ("enrolled", "enrol"),
+    ("unbounded", "unbound"),
+    ("afrique", "afriqu"),
+    ("succulent", "succul"),
+    ("piers", "pier"),
+    ("reap", "reap"),
+    ("neeght", "neeght"),
+    ("digester", "digest"),
+    ("landscape", "landscap"),
+    ("quiescent", "quiescent"),
+    ("thanked", "thank"),
+    ("statement", "statement"),
+    ("cried", "cri"),
+    ("encouragements", "encourag"),
+    ("risers", "riser"),
+    ("mobile", "mobil"),
+    ("followers", "follow"),
+    ("socialist", "socialist"),
+    ("duratio", "duratio"),
+    ("regret", "regret"),
+    ("plac", "plac"),
+    ("tightened", "tighten"),
+    ("impels", "impel"),
+    ("vulgarise", "vulgaris"),
+    ("lunacy", "lunaci"),
+    ("burchell", "burchel"),
+    ("redistribution", "redistribut"),
+    ("converted", "convert"),
+    ("slapping", "slap"),
+    ("economizing", "econom"),
+    ("murder", "murder"),
+    ("ee", "ee"),
+    ("australey", "australei"),
+    ("brokers", "broker"),
+    ("then", "then"),
+    ("portend", "portend"),
+    ("genteelest", "genteelest"),
+    ("capitally", "capit"),
+    ("aldershot", "aldershot"),
+    ("uncouth", "uncouth"),
+    ("general", "gener"),
+    ("composes", "compos"),
+    ("presents", "present"),
+    ("carriage", "carriag"),
+    ("prizes", "prize"),
+    ("coseguina", "coseguina"),
+    ("antarcticus", "antarcticu"),
+    ("lordship", "lordship"),
+    ("intoxicating", "intox"),
+    ("consented", "consent"),
+    ("servitor", "servitor"),
+    ("revolutionary", "revolutionari"),
+    ("hitching", "hitch"),
+    ("exercised", "exercis"),
+    ("backer", "backer"),
+    ("fledged", "fledg"),
+    ("everyone", "everyon"),
+    ("tones", "tone"),
+    ("migrating", "migrat"),
+    ("milkpot", "milkpot"),
+    ("bruising", "bruis"),
+    ("potboy", "potboi"),
+    ("rolls", "roll"),
+    ("spence", "spenc"),
+    ("suggestions", "suggest"),
+    ("wounding", "wound"),
+    ("avenge", "aveng"),
+    ("population", "populaz"),
+    ("resentful", "resent"),
+    ("progressed", "progress"),
+    ("santa", "santa"),
+    ("doubtful", "doubt"),
+    ("pasty", "pasti"),
+    ("thrilling", "thrill"),
+    ("embellish", "embellish"),
+    ("drizzly", "drizzli"),
+    ("justice", "justic"),
+    ("gaped", "gape"),
+    ("reckonings", "reckon"),
+    ("phrenological", "phrenolog"),
+    ("examine", "examin"),
+    ("gartered", "garter"),
+    ("uncovered", "uncov"),
+    ("subjection", "subject"),
+    ("kindled", "kindl"),
+    ("blubbered", "blubber"),
+    ("magnificently", "magnific"),
+    ("unfulfilled", "unfulfil"),
+    ("lamented", "lament"),
+    ("brother", "brother"),
+    ("discarded", "discard"),
+    ("nowadays", "nowadai"),
+    ("nettle", "nettl"),
+    ("counsellors", "counsellor"),
+    ("prurient", "prurient"),
+    ("defined", "defin"),
+    ("amuses", "amus"),
+    ("salon", "salon"),
+    ("grenadier", "grenadi"),
+    ("grazier", "grazier"),
+    ("arequipa", "arequipa"),
+    ("entrails", "entrail"),
+    ("slat", "slat"),
+    ("prunings", "prune"),
+    ("knelt", "knelt"),
+    ("minster", "minster"),
+    ("leather", "leather"),
+    ("commissioners", "commission"),
+    ("shylock", "shylock"),
+    ("vagaries", "vagari"),
+    ("disproportionate", "disproportion"),
+    ("advert", "advert"),
+    ("foretold", "foretold"),
+    ("gentile", "gentil"),
+    ("shines", "shine"),
+    ("hedgehog", "hedgehog"),
+    ("farmers", "farmer"),
+    ("foolscap", "foolscap"),
+    ("garbs", "garb"),
+    ("mehr", "mehr"),
+    ("jenny", "jenni"),
+    ("burglars", "burglar"),
+    ("imagined", "imagin"),
+    ("mallowford", "mallowford"),
+    ("buyings", "bui"),
+    ("cheap", "cheap"),
+    ("slip", "slip"),
+    ("devotions", "devot"),
+    ("impudence", "impud"),
+    ("thoroughly", "thoroughli"),
+    ("adherents", "adher"),
+    ("intensifies", "intensifi"),
+    ("espied", "espi"),
+    ("hauling", "haul"),
+    ("absorption", "absorpt"),
+    ("arrange", "arrang"),
+    ("lodged", "lodg"),
+    ("prefixed", "prefix"),
+    ("astonishes", "astonish"),
+    ("councils", "council"),
+    ("bluster", "bluster"),
+    ("tinged", "ting"),
+    ("torchlight", "torchlight"),
+    ("evincing", "evinc"),
+    ("oratory", "oratori"),
+    ("inopportune", "inopportun"),
+    ("tossed", "toss"),
+    ("sustain", "sustain"),
+    ("joyed", "joi"),
+    ("nutriment", "nutriment"),
+    ("chills", "chill"),
+    ("obeisances", "obeis"),
+    ("acknowledgement", "acknowledg"),
+    ("ditty", "ditti"),
+    ("unfair", "unfair"),
+    ("menfioning", "menfion"),
+    ("thoroughfare", "thoroughfar"),
+    ("bracing", "brace"),
+    ("stench", "stench"),
+    ("selling", "sell"),
+    ("togezzer", "togezz"),
+    ("orlandos", "orlando"),
+    ("encountered", "encount"),
+    ("blab", "blab"),
+    ("brokenly", "brokenli"),
+    ("ava", "ava"),
+    ("acquiescent", "acquiesc"),
+    ("guardsman", "guardsman"),
+    ("deal", "deal"),
+    ("consequential", "consequenti"),
+    ("petals", "petal"),
+    ("lviii", "lviii"),
+    ("entre", "entr"),
+    ("swaddling", "swaddl"),
+    ("droite", "droit"),
+    ("advanced", "advanc"),
+    ("yelling", "yell"),
+    ("wull", "wull"),
+    ("contributed", "contribut"),
+    ("lightly", "lightli"),
+    ("doubtfully", "doubtfulli"),
+    ("jury", "juri"),
+    ("manuscripts", "manuscript"),
+    ("rod", "rod"),
+    ("dais", "dai"),
+    ("sweeping", "sweep"),
+    ("palpitate", "palpit"),
+    ("blowers", "blower"),
+    ("profiting", "profit"),
+    ("doting", "dote"),
+    ("fatly", "fatli"),
+    ("am", "am"),
+    ("dun", "dun"),
+    ("playfellows", "playfellow"),
+    ("outlying", "outli"),
+    ("secure", "secur"),
+    ("penton", "penton"),
+    ("voluptuous", "voluptu"),
+    ("friction", "friction"),
+    ("riches", "rich"),
+    ("doos", "doo"),
+    ("foreseeing", "forese"),
+    ("soldierly", "soldierli"),
+    ("ow", "ow"),
+    ("clambered", "clamber"),
+    ("stitch", "stitch"),
+    ("glissez", "glissez"),
+    ("roamed", "roam"),
+    ("dispelled", "dispel"),
+    ("actually", "actual"),
+    ("impressions", "impress"),
+    ("subserviences", "subservi"),
+    ("sociality", "social"),
+    ("lycosa", "lycosa"),
+    ("jarndyces", "jarndyc"),
+    ("trinity", "triniti"),
+    ("dandy", "dandi"),
+    ("goads", "goad"),
+    ("discard", "discard"),
+    ("colder", "colder"),
+    ("extricated", "extric"),
+    ("discourtesy", "discourtesi"),
+    ("informing", "inform"),
+    ("jawlly", "jawlli"),
+    ("thistle", "thistl"),
+    ("kimiri", "kimiri"),
+    ("signally", "signal"),
+    ("comprises", "compris"),
+    ("vaunt", "vaunt"),
+    ("shelves", "shelv"),
+    ("weakly", "weakli"),
+    ("padres", "padr"),
+    ("certhia", "certhia"),
+    ("light", "light"),
+    ("magnum", "magnum"),
+    ("exports", "export"),
+    ("grasshopper", "grasshopp"),
+    ("critics", "critic"),
+    ("relic", "relic"),
+    ("four", "four"),
+    ("inducement", "induc"),
+    ("jurors", "juror"),
+    ("bark", "bark"),
+    ("malacca", "malacca"),
+    ("bodilessness", "bodiless"),
+    ("multiply", "multipli"),
+    ("daubney", "daubnei"),
+    ("athenian", "athenian"),
+    ("premise", "premis"),
+    ("xlvii", "xlvii"),
+    ("marquess", "marquess"),
+    ("taste", "tast"),
+    ("embankment", "embank"),
+    ("rabbit", "rabbit"),
+    ("sharer", "sharer"),
+    ("pampered", "pamper"),
+    ("paypote", "paypot"),
+    ("recognition", "recognit"),
+    ("pegs", "peg"),
+    ("gallantries", "gallantri"),
+    ("heartedness", "hearted"),
+    ("cardunculus", "cardunculu"),
+    ("snapper", "snapper"),
+    ("dripping", "drip"),
+    ("proteges", "proteg"),
+    ("secret", "secret"),
+    ("maces", "mace"),
+    ("substracted", "substract"),
+    ("apostle", "apostl"),
+    ("outskirting", "outskirt"),
+    ("anon", "anon"),
+    ("choristers", "chorist"),
+    ("crests", "crest"),
+    ("reflecting", "reflect"),
+    ("prouder", "prouder"),
+    ("savoury", "savouri"),
+    ("kerguelen", "kerguelen"),
+    ("contemns", "contemn"),
+    ("dispense", "dispens"),
+    ("silliness", "silli"),
+    ("quenched", "quench"),
+    ("fidgett", "fidgett"),
+    ("polemical", "polem"),
+    ("meagre", "meagr"),
+    ("cristiandad", "cristiandad"),
+    ("tragedy", "tragedi"),
+    ("prove", "prove"),
+    ("liking", "like"),
+    ("quartermaster", "quartermast"),
+    ("dans", "dan"),
+    ("sores", "sore"),
+    ("barmherzige", "barmherzig"),
+    ("inmate", "inmat"),
+    ("vocalists", "vocalist"),
+    ("phantom", "phantom"),
+    ("those", "those"),
+    ("slags", "slag"),
+    ("deprivation", "deprivaz"),
+    ("seller", "seller"),
+    ("feeding", "feed"),
+    ("tourists", "tourist"),
+    ("pachydermous", "pachyderm"),
+    ("traversing", "travers"),
+    ("hears", "hear"),
+    ("marshal", "marshal"),
+    ("styles", "style"),
+    ("troughs", "trough"),
+    ("vote", "vote"),
+    ("undefaced", "undefac"),
+    ("cajoling", "cajol"),
+    ("gnashed", "gnash"),
+    ("turgid", "turgid"),
+    ("omission", "omiss"),
+    ("quondam", "quondam"),
+    ("resuit", "resuit"),
+    ("vowing", "vow"),
+    ("garments", "garment"),
+    ("even", "even"),
+    ("vital", "vital"),
+    ("sipped", "sip"),
+    ("branched", "branch"),
+    ("capped", "cap"),
+    ("postures", "postur"),
+    ("lieutenants", "lieuten"),
+    ("bronchial", "bronchial"),
+    ("crinoline", "crinolin"),
+    ("frankfort", "frankfort"),
+    ("chatting", "chat"),
+    ("excess", "excess"),
+    ("berry", "berri"),
+    ("bohemianism", "bohemian"),
+    ("kennels", "kennel"),
+    ("presiding", "presid"),
+    ("hertfordshire", "hertfordshir"),
+    ("ire", "ir"),
+    ("scapegrace", "scapegrac"),
+    ("semicircle", "semicircl"),
+    ("exclaiming", "exclaim"),
+    ("grumblers", "grumbler"),
+    ("bankrupt", "bankrupt"),
+    ("sunburnt", "sunburnt"),
+    ("wondered", "wonder"),
+    ("snort", "snort"),
+    ("phytolitharia", "phytolitharia"),
+    ("seven", "seven"),
+    ("grumble", "grumbl"),
+    ("insurmountable", "insurmount"),
+    ("sloths", "sloth"),
+    ("patienter", "patient"),
+    ("rexes", "rex"),
+    ("downhearted", "downheart"),
+    ("tying", "ti"),
+    ("promptitude", "promptitud"),
+    ("momentous", "moment"),
+    ("portfolio", "portfolio"),
+    ("crockery", "crockeri"),
+    ("plied", "pli"),
+    ("papiete", "papiet"),
+    ("expanding", "expand"),
+    ("adas", "ada"),
+    ("sobbing", "sob"),
+    ("godchild", "godchild"),
+    ("despondingly", "despondingli"),
+    ("il", "il"),
+    ("hazarding", "hazard"),
+    ("edged", "edg"),
+    ("haughtiness", "haughti"),
+    ("unstable", "unstabl"),
+    ("prodigiously", "prodigi"),
+    ("prescribed", "prescrib"),
+    ("schooldays", "schooldai"),
+    ("approvingly", "approvingli"),
+    ("calais", "calai"),
+    ("knees", "knee"),
+    ("outhouse", "outhous"),
+    ("exhibition", "exhibit"),
+    ("garnet", "garnet"),
+    ("inventory", "inventori"),
+    ("mahdoo", "mahdoo"),
+    ("fluids", "fluid"),
+    ("sclater", "sclater"),
+    ("councillors", "councillor"),
+    ("tapering", "taper"),
+    ("curls", "curl"),
+    ("thy", "thi"),
+    ("album", "album"),
+    ("expansion", "expans"),
+    ("impose", "impos"),
+    ("vexations", "vexaz"),
+    ("wanders", "wander"),
+    ("pipelight", "pipelight"),
+    ("demnition", "demnit"),
+    ("stained", "stain"),
+    ("grips", "grip"),
+    ("oother", "oother"),
+    ("workaday", "workadai"),
+    ("esplanades", "esplanad"),
+    ("salvador", "salvador"),
+    ("questioned", "question"),
+    ("disparagement", "disparag"),
+    ("their", "their"),
+    ("picnic", "picnic"),
+    ("unimprovable", "unimprov"),
+    ("objectionable", "objection"),
+    ("gardens", "garden"),
+    ("bestir", "bestir"),
+    ("annihilation", "annihilaz"),
+    ("humane", "human"),
+    ("trailed", "trail"),
+    ("distinguished", "distinguish"),
+    ("foreboding", "forebod"),
+    ("trichodactylus", "trichodactylu"),
+    ("topsy", "topsi"),
+    ("supplanted", "supplant"),
+    ("abridge", "abridg"),
+    ("consolingly", "consolingli"),
+    ("postmen", "postmen"),
+    ("intercepting", "intercept"),
+    ("roundest", "roundest"),
+    ("coiled", "coil"),
+    ("speculation", "speculaz"),
+    ("bestow", "bestow"),
+    ("violently", "violent"),
+    ("forwarded", "forward"),
+    ("expatriation", "expatriaz"),
+    ("patronesses", "patro"),
+    ("emporium", "emporium"),
+    ("accountable", "account"),
+    ("challenging", "challeng"),
+    ("unfeignedly", "unfeignedli"),
+    ("standstill", "standstil"),
+    ("balconies", "balconi"),
+    ("vaguely", "vagu"),
+    ("happen", "happen"),
+    ("gulliver", "gulliv"),
+    ("sounder", "sounder"),
+    ("pierced", "pierc"),
+    ("moscow", "moscow"),
+    ("snewkes", "snewk"),
+    ("spread", "spread"),
+    ("affectedly", "affectedli"),
+    ("attacked", "attack"),
+    ("subscribing", "subscrib"),
+    ("repeal", "repeal"),
+    ("sladdery", "sladderi"),
+    ("bedsteads", "bedstead"),
+    ("porch", "porch"),
+    ("par", "par"),
+    ("laudable", "laudabl"),
+    ("perspiration", "perspiraz"),
+    ("talented", "talent"),
+    ("pursues", "pursu"),
+    ("terebyeva", "terebyeva"),
+    ("gateways", "gatewai"),
+    ("citizen", "citizen"),
+    ("personality", "person"),
+    ("forge", "forg"),
+    ("recomposed", "recompos"),
+    ("gossiping", "gossip"),
+    ("trifasciatus", "trifasciatu"),
+    ("observe", "observ"),
+    ("ope", "op"),
+    ("aptenodytes", "aptenodyt"),
+    ("bordering", "border"),
+    ("pairing", "pair"),
+    ("sheame", "sheam"),
+    ("snuff", "snuff"),
+    ("commoner", "common"),
+    ("hangman", "hangman"),
+    ("descend", "descend"),
+    ("cortez", "cortez"),
+    ("lodger", "lodger"),
+    ("gem", "gem"),
+    ("sin", "sin"),
+    ("inadvertently", "inadvert"),
+    ("unambitious", "unambiti"),
+    ("indulge", "indulg"),
+    ("ranged", "rang"),
+    ("collapses", "collaps"),
+    ("peaceably", "peaceabl"),
+    ("drummond", "drummond"),
+    ("matther", "matther"),
+    ("fascinator", "fascin"),
+    ("inroads", "inroad"),
+    ("crouched", "crouch"),
+    ("rhubarb", "rhubarb"),
+    ("vassal", "vassal"),
+    ("suckle", "suckl"),
+    ("yoongster", "yoongster"),
+    ("not", "not"),
+    ("burnings", "burn"),
+    ("puddick", "puddick"),
+    ("inky", "inki"),
+    ("reform", "reform"),
+    ("auditor", "auditor"),
+    ("highroad", "highroad"),
+    ("coinstantaneous", "coinstantan"),
+    ("brickmakers", "brickmak"),
+    ("muse", "muse"),
+    ("negligence", "neglig"),
+    ("browne", "brown"),
+    ("raff", "raff"),
+    ("woice", "woic"),
+    ("paternal", "patern"),
+    ("basque", "basqu"),
+    ("awed", "aw"),
+    ("blindnesses", "blind"),
+    ("debts", "debt"),
+    ("incorrectly", "incorrectli"),
+    ("dig", "dig"),
+    ("spitefully", "spitefulli"),
+    ("tristan", "tristan"),
+    ("collectors", "collector"),
+    ("trace", "trace"),
+    ("storehouse", "storehous"),
+    ("leicester", "leicest"),
+    ("complaining", "complain"),
+    ("bantering", "banter"),
+    ("toads", "toad"),
+    ("semicircular", "semicircular"),
+    ("boffinites", "boffinit"),
+    ("sinbad", "sinbad"),
+    ("excremens", "excremen"),
+    ("stork", "stork"),
+    ("quidnuncs", "quidnunc"),
+    ("havoc", "havoc"),
+    ("multiplied", "multipli"),
+    ("unstudied", "unstudi"),
+    ("sympathy", "sympathi"),
+    ("rhododendrons", "rhododendron"),
+    ("grandmother", "grandmoth"),
+    ("patagonian", "patagonian"),
+    ("secured", "secur"),
+    ("cometh", "cometh"),
+    ("unfolding", "unfold"),
+    ("connexions", "connexion"),
+    ("thoughtful", "thought"),
+    ("hippahs", "hippah"),
+    ("woody", "woodi"),
+    ("hotter", "hotter"),
+    ("despised", "despis"),
+    ("branded", "brand"),
+    ("talkin", "talkin"),
+    ("if", "if"),
+    ("gully", "gulli"),
+    ("milky", "milki"),
+    ("blocks", "block"),
+    ("cherished", "cherish"),
+    ("oddest", "oddest"),
+    ("incisive", "incis"),
+    ("skims", "skim"),
+    ("legality", "legal"),
+    ("lath", "lath"),
+    ("crowned", "crown"),
+    ("yellower", "yellow"),
+    ("mantle", "mantl"),
+    ("distraction", "distract"),
+    ("sceptical", "sceptic"),
+    ("found", "found"),
+    ("welcomes", "welcom"),
+    ("nap", "nap"),
+    ("incapable", "incap"),
+    ("veer", "veer"),
+    ("chatter", "chatter"),
+    ("amounted", "amount"),
+    ("fever", "fever"),
+    ("contradicted", "contradict"),
+    ("jajuel", "jajuel"),
+    ("while", "while"),
+    ("weddin", "weddin"),
+    ("sighted", "sight"),
+    ("cook", "cook"),
+    ("stay", "stai"),
+    ("months", "month"),
+    ("biddy", "biddi"),
+    ("decanter", "decant"),
+    ("clifford", "clifford"),
+    ("moraine", "morain"),
+    ("plausible", "plausibl"),
+    ("avdotya", "avdotya"),
+    ("taxes", "tax"),
+    ("scraped", "scrape"),
+    ("obligingness", "obliging"),
+    ("precept", "precept"),
+    ("illuminated", "illumin"),
+    ("wincing", "winc"),
+    ("forecoming", "forecom"),
+    ("notre", "notr"),
+    ("rut", "rut"),
+    ("admixture", "admixtur"),
+    ("bac", "bac"),
+    ("allows", "allow"),
+    ("facts", "fact"),
+    ("bygones", "bygon"),
+    ("solidified", "solidifi"),
+    ("cargo", "cargo"),
+    ("revolt", "revolt"),
+    ("blandishments", "blandish"),
+    ("palais", "palai"),
+    ("despondently", "despond"),
+    ("vindictiveness", "vindict"),
+    ("parson", "parson"),
+    ("scarcity", "scarciti"),
+    ("propriety", "proprieti"),
+    ("vagrant", "vagrant"),
+    ("jewelled", "jewel"),
+    ("niece", "niec"),
+    ("distinguishable", "distinguish"),
+    ("illimitable", "illimit"),
+    ("implication", "implicaz"),
+    ("bogsby", "bogsbi"),
+    ("astonishment", "astonish"),
+    ("crestfallen", "crestfallen"),
+    ("jeered", "jeer"),
+    ("aren", "aren"),
+    ("shoe", "shoe"),
+    ("roundness", "round"),
+    ("seized", "seiz"),
+    ("elevations", "elevaz"),
+    ("prevent", "prevent"),
+    ("successor", "successor"),
+    ("berkeley", "berkelei"),
+    ("decomposition", "decomposit"),
+    ("chimes", "chime"),
+    ("unsparing", "unspar"),
+    ("overcome", "overcom"),
+    ("booths", "booth"),
+    ("minerva", "minerva"),
+    ("rising", "rise"),
+    ("reddening", "redden"),
+    ("frever", "frever"),
+    ("unnecessary", "unnecessari"),
+    ("misunderstood", "misunderstood"),
+    ("musical", "music"),
+    ("regulate", "regul"),
+    ("danse", "dans"),
+    ("dewiness", "dewi"),
+    ("brutish", "brutish"),
+    ("frequently", "frequent"),
+    ("ordinary", "ordinari"),
+    ("scornfully", "scornfulli"),
+    ("grappled", "grappl"),
+    ("intended", "intend"),
+    ("trays", "trai"),
+    ("destinies", "destini"),
+    ("bouquet", "bouquet"),
+    ("provinces", "provinc"),
+    ("jeeringly", "jeeringli"),
+    ("mor", "mor"),
+    ("pr", "pr"),
+    ("execrating", "execr"),
+    ("inscrutable", "inscrut"),
+    ("verdure", "verdur"),
+    ("fogies", "fogi"),
+    ("keen", "keen"),
+    ("bottin", "bottin"),
+    ("underserving", "underserv"),
+    ("zealander", "zealand"),
+    ("umbrellas", "umbrella"),
+    ("spreads", "spread"),
+    ("plantagenet", "plantagenet"),
+    ("imagination", "imaginaz"),
+    ("interior", "interior"),
+    ("squirt", "squirt"),
+    ("bandied", "bandi"),
+    ("fleet", "fleet"),
+    ("glances", "glanc"),
+    ("unaffectedly", "unaffectedli"),
+    ("adventures", "adventur"),
+    ("attractions", "attract"),
+    ("deeds", "deed"),
+    ("controverted", "controvert"),
+    ("sacking", "sack"),
+    ("heaping", "heap"),
+    ("belgravia", "belgravia"),
+    ("wharf", "wharf"),
+    ("evidently", "evid"),
+    ("pear", "pear"),
+    ("leoneros", "leonero"),
+    ("digby", "digbi"),
+    ("entreating", "entreat"),
+    ("indelibly", "indelibli"),
+    ("enraptured", "enraptur"),
+    ("muttered", "mutter"),
+    ("inequality", "inequ"),
+    ("locomotive", "locomot"),
+    ("involved", "involv"),
+    ("wide", "wide"),
+    ("tupinieri", "tupinieri"),
+    ("thumbed", "thumb"),
+    ("cooking", "cook"),
+    ("aestivation", "aestivaz"),
+    ("hilarity", "hilar"),
+    ("irruption", "irrupt"),
+    ("radical", "radic"),
+    ("sectio", "sectio"),
+    ("bezants", "bezant"),
+    ("fraction", "fraction"),
+    ("studious", "studiou"),
+    ("recur", "recur"),
+    ("engendered", "engend"),
+    ("cousins", "cousin"),
+    ("perceived", "perceiv"),
+    ("smallweeds", "smallwe"),
+    ("robber", "robber"),
+    ("proverb", "proverb"),
+    ("conduce", "conduc"),
+    ("towers", "tower"),
+    ("jewish", "jewish"),
+    ("division", "divis"),
+    ("dealers", "dealer"),
+    ("warbling", "warbl"),
+    ("receive", "receiv"),
+    ("steak", "steak"),
+    ("mellowed", "mellow"),
+    ("lightest", "lightest"),
+    ("wakeful", "wake"),
+    ("twin", "twin"),
+    ("fantasy", "fantasi"),
+    ("manure", "manur"),
+    ("hip", "hip"),
+    ("percentage", "percentag"),
+    ("attentively", "attent"),
+    ("elegans", "elegan"),
+    ("caverns", "cavern"),
+    ("posterior", "posterior"),
+    ("backs", "back"),
+    ("rector", "rector"),
+    ("inverted", "invert"),
+    ("recruited", "recruit"),
+    ("acclamations", "acclamaz"),
+    ("firmness", "firm"),
+    ("witnessed", "wit"),
+    ("incoherence", "incoher"),
+    ("potteries", "potteri"),
+    ("spree", "spree"),
+    ("fibres", "fibr"),
+    ("tacking", "tack"),
+    ("laying", "lai"),
+    ("fiery", "fieri"),
+    ("thrash", "thrash"),
+    ("comestibles", "comest"),
+    ("promising", "promis"),
+    ("requiem", "requiem"),
+    ("aslant", "aslant"),
+    ("muddle", "muddl"),
+    ("thoroughfares", "thoroughfar"),
+    ("horn", "horn"),
+    ("tormentors", "tormentor"),
+    ("gown", "gown"),
+    ("tripods", "tripod"),
+    ("veil", "veil"),
+    ("us", "u"),
+    ("mitka", "mitka"),
+    ("investment", "invest"),
+    ("syriac", "syriac"),
+    ("very", "veri"),
+    ("tidal", "tidal"),
+    ("descriptions", "descript"),
+    ("bushby", "bushbi"),
+    ("paled", "pale"),
+    ("noronha", "noronha"),
+    ("convicts", "convict"),
+    ("ended", "end"),
+    ("goodness", "good"),
+    ("statute", "statut"),
+    ("darkly", "darkli"),
+    ("crumble", "crumbl"),
+    ("expelling", "expel"),
+    ("dysentery", "dysenteri"),
+    ("greenly", "greenli"),
+    ("invalidate", "invalid"),
+    ("churls", "churl"),
+    ("growing", "grow"),
+    ("mice", "mice"),
+    ("reasons", "reason"),
+    ("spoke", "spoke"),
+    ("coup", "coup"),
+    ("annoyance", "annoyanc"),
+    ("orlando", "orlando"),
+    ("uncongenial", "uncongeni"),
+    ("volunteered", "volunt"),
+    ("musket", "musket"),
+    ("cheltenham", "cheltenham"),
+    ("brittle", "brittl"),
+    ("undressing", "undress"),
+    ("rude", "rude"),
+    ("reservations", "reservaz"),
+    ("remuneration", "remuneraz"),
+    ("heavings", "heav"),
+    ("departure", "departur"),
+    ("bizcachas", "bizcacha"),
+    ("cool", "cool"),
+    ("return", "return"),
+    ("places", "place"),
+    ("headache", "headach"),
+    ("resplendent", "resplend"),
+    ("consenting", "consent"),
+    ("blasted", "blast"),
+    ("hesitating", "hesit"),
+    ("despise", "despis"),
+    ("code", "code"),
+    ("irritation", "irritaz"),
+    ("dishonesty", "dishonesti"),
+    ("folding", "fold"),
+    ("considered", "consid"),
+    ("crested", "crest"),
+    ("still", "still"),
+    ("skill", "skill"),
+    ("measurer", "measur"),
+    ("consummate", "consumm"),
+    ("blink", "blink"),
+    ("dying", "di"),
+    ("dogs", "dog"),
+    ("ande", "and"),
+    ("mightily", "mightili"),
+    ("substances", "substanc"),
+    ("perceive", "perceiv"),
+    ("unnat", "unnat"),
+    ("characteristically", "characterist"),
+    ("oswald", "oswald"),
+    ("mercy", "merci"),
+    ("misconception", "misconcept"),
+    ("valdivia", "valdivia"),
+    ("tranquilly", "tranquilli"),
+    ("gusty", "gusti"),
+    ("expired", "expir"),
+    ("predestined", "predestin"),
+    ("shops", "shop"),
+    ("inconsiderable", "inconsider"),
+    ("mistoo", "mistoo"),
+    ("struggler", "struggler"),
+    ("selfish", "selfish"),
+    ("trifled", "trifl"),
+    ("basin", "basin"),
+    ("tomahawk", "tomahawk"),
+    ("thicket", "thicket"),
+    ("hackney", "hacknei"),
+    ("bespeckled", "bespeckl"),
+    ("rio", "rio"),
+    ("partakes", "partak"),
+    ("uncleared", "unclear"),
+    ("blackest", "blackest"),
+    ("startled", "startl"),
+    ("brussels", "brussel"),
+    ("direction", "direct"),
+    ("macbeth", "macbeth"),
+    ("sewerely", "sewer"),
+    ("restrains", "restrain"),
+    ("nods", "nod"),
+    ("demerits", "demerit"),
+    ("corpse", "corps"),
+    ("epithets", "epithet"),
+    ("davies", "davi"),
+    ("offers", "offer"),
+    ("eghert", "eghert"),
+    ("lives", "live"),
+    ("cervus", "cervu"),
+    ("beatson", "beatson"),
+    ("disposal", "dispos"),
+    ("pinked", "pink"),
+    ("delaying", "delai"),
+    ("evading", "evad"),
+    ("nestling", "nestl"),
+    ("irretrievable", "irretriev"),
+    ("christendom", "christendom"),
+    ("pannikins", "pannikin"),
+    ("twenties", "twenti"),
+    ("mummy", "mummi"),
+    ("proffer", "proffer"),
+    ("intend", "intend"),
+    ("premiership", "premiership"),
+    ("pasture", "pastur"),
+    ("irishwoman", "irishwoman"),
+    ("lame", "lame"),
+    ("yegorovna", "yegorovna"),
+    ("accusing", "accus"),
+    ("chaperon", "chaperon"),
+    ("disinterestedness", "disinterested"),
+    ("fitted", "fit"),
+    ("lightens", "lighten"),
+    ("rivetted", "rivet"),
+    ("glutton", "glutton"),
+    ("store", "store"),
+    ("numberless", "numberless"),
+    ("fallacy", "fallaci"),
+    ("bible", "bibl"),
+    ("fancier", "fancier"),
+    ("agrees", "agre"),
+    ("unanswered", "unansw"),
+    ("engaging", "engag"),
+    ("warmth", "warmth"),
+    ("geologicas", "geologica"),
+    ("convulsion", "convuls"),
+    ("doctrines", "doctrin"),
+    ("tomorrow", "tomorrow"),
+    ("thigh", "thigh"),
+    ("trouble", "troubl"),
+    ("deplore", "deplor"),
+    ("shorten", "shorten"),
+    ("wales", "wale"),
+    ("fronte", "front"),
+    ("rapine", "rapin"),
+    ("fust", "fust"),
+    ("haste", "hast"),
+    ("performs", "perform"),
+    ("brawl", "brawl"),
+    ("thi", "thi"),
+    ("ad", "ad"),
+    ("resonant", "reson"),
+    ("sorted", "sort"),
+    ("freak", "freak"),
+    ("scandal", "scandal"),
+    ("percentages", "percentag"),
+    ("bind", "bind"),
+    ("mood", "mood"),
+    ("hankers", "hanker"),
+    ("smiting", "smite"),
+    ("unties", "unti"),
+    ("godfather", "godfath"),
+    ("reals", "real"),
+    ("fixing", "fix"),
+    ("distinguishing", "distinguish"),
+    ("saucepan", "saucepan"),
+    ("mighty", "mighti"),
+    ("mayst", "mayst"),
+    ("pomaded", "pomad"),
+    ("sling", "sling"),
+    ("warmhearted", "warmheart"),
+    ("illumined", "illumin"),
+    ("cannot", "cannot"),
+    ("reimbursed", "reimburs"),
+    ("directing", "direct"),
+    ("immoral", "immor"),
+    ("reforms", "reform"),
+    ("recumbent", "recumb"),
+    ("another", "anoth"),
+    ("welcome", "welcom"),
+    ("comfortabler", "comfortabl"),
+    ("finding", "find"),
+    ("streaming", "stream"),
+    ("ostriches", "ostrich"),
+    ("moralisings", "moralis"),
+    ("reg", "reg"),
+    ("rigging", "rig"),
+    ("hewer", "hewer"),
+    ("fluffles", "fluffl"),
+    ("emerging", "emerg"),
+    ("complain", "complain"),
+    ("smell", "smell"),
+    ("palpably", "palpabl"),
+    ("superadding", "superad"),
+    ("squeezing", "squeez"),
+    ("swindling", "swindl"),
+    ("jackson", "jackson"),
+    ("ascetic", "ascet"),
+    ("beseems", "beseem"),
+    ("tinge", "ting"),
+    ("gauch", "gauch"),
+    ("quakes", "quak"),
+    ("invisible", "invis"),
+    ("botanizing", "botan"),
+    ("reflectin", "reflectin"),
+    ("catalogued", "catalogu"),
+    ("qu", "qu"),
+    ("firstborn", "firstborn"),
+    ("campana", "campana"),
+    ("gaul", "gaul"),
+    ("inhaled", "inhal"),
+    ("encroachments", "encroach"),
+    ("dublin", "dublin"),
+    ("damnatory", "damnatori"),
+    ("droppe", "dropp"),
+    ("tassels", "tassel"),
+    ("expectant", "expect"),
+    ("discomposure", "discomposur"),
+    ("disarray", "disarrai"),
+    ("marvel", "marvel"),
+    ("moves", "move"),
+    ("glimpse", "glimps"),
+    ("timothy", "timothi"),
+    ("longitudinal", "longitudin"),
+    ("cited", "cite"),
+    ("hopping", "hop"),
+    ("shovel", "shovel"),
+    ("laughs", "laugh"),
+    ("commissioned", "commiss"),
+    ("snapped", "snap"),
+    ("railroads", "railroad"),
+    ("constructing", "construct"),
+    ("fashioned", "fashion"),
+    ("missy", "missi"),
+    ("dials", "dial"),
+    ("lisp", "lisp"),
+    ("humouredly", "humouredli"),
+    ("transmitted", "transmit"),
+    ("flite", "flite"),
+    ("daft", "daft"),
+    ("masses", "mass"),
+    ("aptness", "apt"),
+    ("everywheres", "everywher"),
+    ("gooseberries", "gooseberri"),
+    ("hisself", "hisself"),
+    ("profound", "profound"),
+    ("testament", "testament"),
+    ("moulded", "mould"),
+    ("round", "round"),
+    ("nixon", "nixon"),
+    ("bamboos", "bamboo"),
+    ("eatable", "eatabl"),
+    ("forte", "fort"),
+    ("tenderer", "tender"),
+    ("homelessness", "homeless"),
+    ("braved", "brave"),
+    ("micaceous", "micac"),
+    ("hugging", "hug"),
+    ("berried", "berri"),
+    ("disown", "disown"),
+    ("song", "song"),
+    ("inspecting", "inspect"),
+    ("temperate", "temper"),
+    ("burying", "buri"),
+    ("deadwood", "deadwood"),
+    ("fifer", "fifer"),
+    ("usurers", "usur"),
+    ("architectural", "architectur"),
+    ("composer", "compos"),
+    ("blowing", "blow"),
+    ("fellows", "fellow"),
+    ("claw", "claw"),
+    ("splenetic", "splenet"),
+    ("furnishing", "furnish"),
+    ("linear", "linear"),
+    ("calamities", "calam"),
+    ("woodbine", "woodbin"),
+    ("lett", "lett"),
+    ("archness", "arch"),
+    ("chariot", "chariot"),
+    ("doesn", "doesn"),
+    ("discourse", "discours"),
+    ("descent", "descent"),
+    ("dreams", "dream"),
+    ("elmo", "elmo"),
+    ("compelling", "compel"),
+    ("implicitly", "implicitli"),
+    ("knotted", "knot"),
+    ("scoriaceous", "scoriac"),
+    ("benito", "benito"),
+    ("founding", "found"),
+    ("praskovya", "praskovya"),
+    ("gains", "gain"),
+    ("pounced", "pounc"),
+    ("flapped", "flap"),
+    ("deprecating", "deprec"),
+    ("smoothings", "smooth"),
+    ("incredible", "incred"),
+    ("passionately", "passion"),
+    ("clad", "clad"),
+    ("nastasya", "nastasya"),
+    ("gumwood", "gumwood"),
+    ("stee", "stee"),
+    ("repayment", "repayment"),
+    ("urge", "urg"),
+    ("mps", "mp"),
+    ("judicial", "judici"),
+    ("imprecations", "imprecaz"),
+    ("importunity", "importun"),
+    ("sedulous", "sedul"),
+    ("brotherhood", "brotherhood"),
+    ("jealous", "jealou"),
+    ("overtures", "overtur"),
+    ("contradicts", "contradict"),
+    ("endeavours", "endeavour"),
+    ("trebled", "trebl"),
+    ("communication", "communicaz"),
+    ("deathbed", "deathb"),
+    ("dreamy", "dreami"),
+    ("wer", "wer"),
+    ("using", "us"),
+    ("subordination", "subordinaz"),
+    ("despising", "despis"),
+    ("ceremonies", "ceremoni"),
+    ("glisten", "glisten"),
+    ("midsummer", "midsumm"),
+    ("singly", "singli"),
+    ("protest", "protest"),
+    ("democratic", "democrat"),
+    ("rubinstein", "rubinstein"),
+    ("unsupported", "unsupport"),
+    ("contorting", "contort"),
+    ("behoof", "behoof"),
+    ("progression", "progress"),
+    ("magnanimously", "magnanim"),
+    ("whethe", "wheth"),
+    ("sailing", "sail"),
+    ("circumlocution", "circumlocut"),
+    ("hatched", "hatch"),
+    ("inadequate", "inadequ"),
+    ("catched", "catch"),
+    ("turtle", "turtl"),
+    ("tricksters", "trickster"),
+    ("pseudo", "pseudo"),
+    ("habeas", "habea"),
+    ("fly", "fli"),
+    ("thing", "thing"),
+    ("word", "word"),
+    ("refuge", "refug"),
+    ("hill", "hill"),
+    ("abysses", "abyss"),
+    ("pundit", "pundit"),
+    ("annales", "annal"),
+    ("ingenious", "ingeni"),
+    ("curiously", "curious"),
+    ("lyra", "lyra"),
+    ("teems", "teem"),
+    ("ignominy", "ignomini"),
+    ("coventry", "coventri"),
+    ("heresy", "heresi"),
+    ("exactly", "exactli"),
+    ("arago", "arago"),
+    ("umsonst", "umsonst"),
+    ("correctness", "correct"),
+    ("congruous", "congruou"),
+    ("gato", "gato"),
+    ("stolid", "stolid"),
+    ("godmother", "godmoth"),
+    ("carry", "carri"),
+    ("silken", "silken"),
+    ("accidentally", "accident"),
+    ("shelter", "shelter"),
+    ("lease", "leas"),
+    ("flagstaff", "flagstaff"),
+    ("calosoma", "calosoma"),
+    ("pectoral", "pector"),
+    ("impulsive", "impuls"),
+    ("grim", "grim"),
+    ("unavailingly", "unavailingli"),
+    ("chimneys", "chimnei"),
+    ("advisedly", "advisedli"),
+    ("conveyance", "conveyanc"),
+    ("quince", "quinc"),
+    ("violates", "violat"),
+    ("hairless", "hairless"),
+    ("melancholy", "melancholi"),
+    ("realizes", "real"),
+    ("gatherers", "gather"),
+    ("gapes", "gape"),
+    ("witch", "witch"),
+    ("enchanter", "enchant"),
+    ("exclaims", "exclaim"),
+    ("turco", "turco"),
+    ("euphorbia", "euphorbia"),
+    ("pistol", "pistol"),
+    ("roof", "roof"),
+    ("fertility", "fertil"),
+    ("republicanism", "republican"),
+    ("muriates", "muriat"),
+    ("unattainable", "unattain"),
+    ("platters", "platter"),
+    ("fractures", "fractur"),
+    ("larcenerer", "larcener"),
+    ("radicals", "radic"),
+    ("overshadowed", "overshadow"),
+    ("useless", "useless"),
+    ("acuteness", "acut"),
+    ("resign", "resign"),
+    ("posing", "pose"),
+    ("salutes", "salut"),
+    ("furniter", "furnit"),
+    ("eventful", "event"),
+    ("relinquishing", "relinquish"),
+    ("rugs", "rug"),
+    ("forgive", "forgiv"),
+    ("reposing", "repos"),
+    ("polybori", "polybori"),
+    ("oracle", "oracl"),
+    ("brilliancy", "brillianc"),
+    ("fervid", "fervid"),
+    ("transplanted", "transplant"),
+    ("wardrobes", "wardrob"),
+    ("inexpressible", "inexpress"),
+    ("clare", "clare"),
+    ("ulterior", "ulterior"),
+    ("concourse", "concours"),
+    ("desponded", "despond"),
+    ("villosus", "villosu"),
+    ("bored", "bore"),
+    ("drama", "drama"),
+    ("bluntly", "bluntli"),
+    ("war", "war"),
+    ("rakish", "rakish"),
+    ("intestate", "intest"),
+    ("plunge", "plung"),
+    ("suggests", "suggest"),
+    ("perforate", "perfor"),
+    ("hysterical", "hyster"),
+    ("natur", "natur"),
+    ("cuts", "cut"),
+    ("ii", "ii"),
+    ("coves", "cove"),
+    ("queen", "queen"),
+    ("moustachios", "moustachio"),
+    ("immensity", "immens"),
+    ("cooler", "cooler"),
+    ("broomstick", "broomstick"),
+    ("poking", "poke"),
+    ("sov", "sov"),
+    ("trochilus", "trochilu"),
+    ("faded", "fade"),
+    ("cleverest", "cleverest"),
+    ("shrieking", "shriek"),
+    ("resumption", "resumpt"),
+    ("derisive", "deris"),
+    ("brat", "brat"),
+    ("atoll", "atol"),
+    ("island", "island"),
+    ("shifting", "shift"),
+    ("gossips", "gossip"),
+    ("sequence", "sequenc"),
+    ("compounded", "compound"),
+    ("contemplating", "contempl"),
+    ("cylindrical", "cylindr"),
+    ("incarnation", "incarnaz"),
+    ("responsibilities", "respons"),
+    ("sandwich", "sandwich"),
+    ("alliances", "allianc"),
+    ("petrovna", "petrovna"),
+    ("vehicles", "vehicl"),
+    ("tussocks", "tussock"),
+    ("st", "st"),
+    ("animation", "animaz"),
+    ("upside", "upsid"),
+    ("docile", "docil"),
+    ("gulping", "gulp"),
+    ("ganz", "ganz"),
+    ("grey", "grei"),
+    ("despises", "despis"),
+    ("production", "product"),
+    ("dieman", "dieman"),
+    ("paws", "paw"),
+    ("consciences", "conscienc"),
+    ("surging", "surg"),
+    ("bungling", "bungl"),
+    ("repelled", "repel"),
+    ("divines", "divin"),
+    ("tempted", "tempt"),
+    ("unreason", "unreason"),
+    ("deadened", "deaden"),
+    ("amazement", "amaz"),
+    ("latinity", "latin"),
+    ("shape", "shape"),
+    ("graduated", "graduat"),
+    ("lodgings", "lodg"),
+    ("demonstrative", "demonstr"),
+    ("conference", "confer"),
+    ("flier", "flier"),
+    ("messages", "messag"),
+    ("cloudless", "cloudless"),
+    ("compassionating", "compassion"),
+    ("scheme", "scheme"),
+    ("enhanced", "enhanc"),
+    ("worry", "worri"),
+    ("widowed", "widow"),
+    ("ebullitions", "ebullit"),
+    ("bruised", "bruis"),
+    ("requisite", "requisit"),
+    ("bucketful", "bucket"),
+    ("rathe", "rath"),
+    ("outlaws", "outlaw"),
+    ("widespread", "widespread"),
+    ("grandly", "grandli"),
+    ("correcting", "correct"),
+    ("lurked", "lurk"),
+    ("declaring", "declar"),
+    ("insect", "insect"),
+    ("variable", "variabl"),
+    ("talkers", "talker"),
+    ("bynoe", "byno"),
+    ("pirouette", "pirouett"),
+    ("snooks", "snook"),
+    ("curling", "curl"),
+    ("recognised", "recognis"),
+    ("unpack", "unpack"),
+    ("persuaded", "persuad"),
+    ("clinging", "clinging"),
+    ("readjourned", "readjourn"),
+    ("substitute", "substitut"),
+    ("turf", "turf"),
+    ("calculated", "calcul"),
+    ("soonest", "soonest"),
+    ("possibility", "possibl"),
+    ("imps", "imp"),
+    ("unavoidable", "unavoid"),
+    ("missions", "mission"),
+    ("advantage", "advantag"),
+    ("drest", "drest"),
+    ("bivouac", "bivouac"),
+    ("trait", "trait"),
+    ("pum", "pum"),
+    ("elateridae", "elaterida"),
+    ("wilfulness", "wil"),
+    ("molested", "molest"),
+    ("judgement", "judgement"),
+    ("digger", "digger"),
+    ("encumber", "encumb"),
+    ("slipped", "slip"),
+    ("provoke", "provok"),
+    ("damper", "damper"),
+    ("comfortablest", "comfortablest"),
+    ("drinks", "drink"),
+    ("interlude", "interlud"),
+    ("blast", "blast"),
+    ("gambles", "gambl"),
+    ("traducers", "traduc"),
+    ("intentionally", "intention"),
+    ("delivered", "deliv"),
+    ("dear", "dear"),
+    ("fruits", "fruit"),
+    ("crumbling", "crumbl"),
+    ("encomiums", "encomium"),
+    ("loll", "loll"),
+    ("john", "john"),
+    ("gilds", "gild"),
+    ("efficient", "effici"),
+    ("turkey", "turkei"),
+    ("worthwhile", "worthwhil"),
+    ("armado", "armado"),
+    ("gentry", "gentri"),
+    ("mactrae", "mactra"),
+    ("commonplaces", "commonplac"),
+    ("pallid", "pallid"),
+    ("conditional", "condit"),
+    ("consumedly", "consumedli"),
+    ("therewith", "therewith"),
+    ("planter", "planter"),
+    ("each", "each"),
+    ("lamps", "lamp"),
+    ("playfully", "playfulli"),
+    ("amalgamated", "amalgam"),
+    ("modelled", "model"),
+    ("rafael", "rafael"),
+    ("horsehair", "horsehair"),
+    ("desire", "desir"),
+    ("confine", "confin"),
+    ("quaintness", "quaint"),
+    ("heartiness", "hearti"),
+    ("intention", "intent"),
+    ("birthright", "birthright"),
+    ("wires", "wire"),
+    ("orange", "orang"),
+    ("dispersing", "dispers"),
+    ("resulting", "result"),
+    ("incited", "incit"),
+    ("grouse", "grous"),
+    ("squared", "squar"),
+    ("attachment", "attach"),
+    ("bas", "ba"),
+    ("antecedents", "anteced"),
+    ("jawed", "jaw"),
+    ("ascertained", "ascertain"),
+    ("gatherum", "gatherum"),
+    ("nutritious", "nutriti"),
+    ("lusty", "lusti"),
+    ("redress", "redress"),
+    ("revived", "reviv"),
+    ("cycles", "cycl"),
+    ("giles", "gile"),
+    ("wrung", "wrung"),
+    ("lippevechsel", "lippevechsel"),
+    ("handmaid", "handmaid"),
+    ("knocks", "knock"),
+    ("requested", "request"),
+    ("nights", "night"),
+    ("overblown", "overblown"),
+    ("boot", "boot"),
+    ("tyrannus", "tyrannu"),
+    ("jockey", "jockei"),
+    ("steeplechase", "steeplechas"),
+    ("herds", "herd"),
+    ("pencils", "pencil"),
+    ("burlinghammer", "burlinghamm"),
+    ("crown", "crown"),
+    ("learns", "learn"),
+    ("flies", "fli"),
+    ("offended", "offend"),
+    ("encouragement", "encourag"),
+    ("depress", "depress"),
+    ("relics", "relic"),
+    ("unexampled", "unexampl"),
+    ("stroked", "stroke"),
+    ("sturdy", "sturdi"),
+    ("girt", "girt"),
+    ("others", "other"),
+    ("insensibly", "insensibli"),
+    ("melts", "melt"),
+    ("innkeepers", "innkeep"),
+    ("bree", "bree"),
+    ("some", "some"),
+    ("crystial", "crystial"),
+    ("asylum", "asylum"),
+    ("immolate", "immol"),
+    ("simper", "simper"),
+    ("depths", "depth"),
+    ("recovered", "recov"),
+    ("leer", "leer"),
+    ("belongs", "belong"),
+    ("doorkeeper", "doorkeep"),
+    ("tottenham", "tottenham"),
+    ("punishment", "punish"),
+    ("sorcery", "sorceri"),
+    ("abjectness", "abject"),
+    ("thrives", "thrive"),
+    ("flycatcher", "flycatch"),
+    ("liberals", "liber"),
+    ("smiled", "smile"),
+    ("kind", "kind"),
+    ("politeness", "polit"),
+    ("solidities", "solid"),
+    ("hottest", "hottest"),
+    ("boyhood", "boyhood"),
+    ("latent", "latent"),
+    ("ix", "ix"),
+    ("invitation", "invitaz"),
+    ("irrepressible", "irrepress"),
+    ("grief", "grief"),
+    ("u", "u"),
+    ("pise", "pise"),
+    ("bend", "bend"),
+    ("speedily", "speedili"),
+    ("fraudulently", "fraudul"),
+    ("novitiate", "noviti"),
+    ("cataleptic", "catalept"),
+    ("spire", "spire"),
+    ("dimpled", "dimpl"),
+    ("parliament", "parliament"),
+    ("fondest", "fondest"),
+    ("rescuers", "rescuer"),
+    ("valiantly", "valiantli"),
+    ("desolateness", "desol"),
+    ("rated", "rate"),
+    ("blitheness", "blith"),
+    ("lawful", "law"),
+    ("capitol", "capitol"),
+    ("paddock", "paddock"),
+    ("fascination", "fascinaz"),
+    ("huacas", "huaca"),
+    ("mai", "mai"),
+    ("boldest", "boldest"),
+    ("shirt", "shirt"),
+    ("clung", "clung"),
+    ("accepting", "accept"),
+    ("skylark", "skylark"),
+    ("murmurs", "murmur"),
+    ("conviction", "convict"),
+    ("ache", "ach"),
+    ("pashenka", "pashenka"),
+    ("smooths", "smooth"),
+    ("choice", "choic"),
+    ("angular", "angular"),
+    ("consolidating", "consolid"),
+    ("choir", "choir"),
+    ("cigaritos", "cigarito"),
+    ("smattering", "smatter"),
+    ("crystallization", "crystal"),
+    ("woul", "woul"),
+    ("fanshawe", "fanshaw"),
+    ("slavery", "slaveri"),
+    ("clearance", "clearanc"),
+    ("images", "imag"),
+    ("omitted", "omit"),
+    ("deeming", "deem"),
+    ("sarcophagus", "sarcophagu"),
+    ("begins", "begin"),
+    ("spilling", "spill"),
+    ("instability", "instabl"),
+    ("broadly", "broadli"),
+    ("dilatory", "dilatori"),
+    ("lizzie", "lizzi"),
+    ("guard", "guard"),
+    ("pronounces", "pronounc"),
+    ("twelvemonth", "twelvemonth"),
+    ("tunnel", "tunnel"),
+    ("gl", "gl"),
+    ("hearthstone", "hearthston"),
+    ("braid", "braid"),
+    ("square", "squar"),
+    ("bottomed", "bottom"),
+    ("allegory", "allegori"),
+    ("daunt", "daunt"),
+    ("retarded", "retard"),
+    ("primitive", "primit"),
+    ("inclines", "inclin"),
+    ("members", "member"),
+    ("sentimental", "sentiment"),
+    ("azara", "azara"),
+    ("unshaken", "unshaken"),
+    ("slapped", "slap"),
+    ("geranium", "geranium"),
+    ("intercepted", "intercept"),
+    ("worker", "worker"),
+    ("smoothing", "smooth"),
+    ("ultimately", "ultim"),
+    ("unhesitatingly", "unhesitatingli"),
+    ("parasha", "parasha"),
+    ("schools", "school"),
+    ("indignant", "indign"),
+    ("joyously", "joyous"),
+    ("hisses", "hiss"),
+    ("dividend", "dividend"),
+    ("knocked", "knock"),
+    ("whoam", "whoam"),
+    ("split", "split"),
+    ("strips", "strip"),
+    ("progressing", "progress"),
+    ("imploringly", "imploringli"),
+    ("oddly", "oddli"),
+    ("alone", "alon"),
+    ("wharves", "wharv"),
+    ("outermost", "outermost"),
+    ("entrenched", "entrench"),
+    ("partaker", "partak"),
+    ("latched", "latch"),
+    ("tunnels", "tunnel"),
+    ("unspoilt", "unspoilt"),
+    ("invaluable", "invalu"),
+    ("reside", "resid"),
+    ("magician", "magician"),
+    ("volcanic", "volcan"),
+    ("llandaff", "llandaff"),
+    ("befriending", "befriend"),
+    ("pollis", "polli"),
+    ("mushroom", "mushroom"),
+    ("roller", "roller"),
+    ("leaders", "leader"),
+    ("kin", "kin"),
+    ("jolted", "jolt"),
+    ("thickness", "thick"),
+    ("brickwork", "brickwork"),
+    ("spit", "spit"),
+    ("magnanimous", "magnanim"),
+    ("declares", "declar"),
+    ("adventurous", "adventur"),
+    ("alcide", "alcid"),
+    ("rhymes", "rhyme"),
+    ("spend", "spend"),
+    ("downfall", "downfal"),
+    ("dotted", "dot"),
+    ("superintendent", "superintend"),
+    ("understrappers", "understrapp"),
+    ("trample", "trampl"),
+    ("merchandise", "merchandis"),
+    ("oysters", "oyster"),
+    ("surfac", "surfac"),
+    ("worthbourne", "worthbourn"),
+    ("smears", "smear"),
+    ("article", "articl"),
+    ("reddest", "reddest"),
+    ("chivalrously", "chivalr"),
+    ("assets", "asset"),
+    ("diversion", "divers"),
+    ("stormy", "stormi"),
+    ("astonishingly", "astonishingli"),
+    ("segments", "segment"),
+    ("gauntlet", "gauntlet"),
+    ("bade", "bade"),
+    ("anticipated", "anticip"),
+    ("convicted", "convict"),
+    ("chaunt", "chaunt"),
+    ("plainly", "plainli"),
+    ("mien", "mien"),
+    ("keeper", "keeper"),
+    ("devote", "devot"),
+    ("bemoaned", "bemoan"),
+    ("lapel", "lapel"),
+    ("crucified", "crucifi"),
+    ("exquisite", "exquisit"),
+    ("proves", "prove"),
+    ("bagnet", "bagnet"),
+    ("apparitions", "apparit"),
+    ("repute", "reput"),
+    ("depended", "depend"),
+    ("illegitimate", "illegitim"),
+    ("geologically", "geolog"),
+    ("sustaining", "sustain"),
+    ("needle", "needl"),
+    ("whitewash", "whitewash"),
+    ("fruitlessly", "fruitlessli"),
+    ("wearisome", "wearisom"),
+    ("concession", "concess"),
+    ("warehouses", "warehous"),
+    ("hollow", "hollow"),
+    ("rebounding", "rebound"),
+    ("afloat", "afloat"),
+    ("traducer", "traduc"),
+    ("exped", "exp"),
+    ("picaninny", "picaninni"),
+    ("hornpipes", "hornpip"),
+    ("cheerily", "cheerili"),
+    ("compilers", "compil"),
+    ("feronia", "feronia"),
+    ("sonnets", "sonnet"),
+    ("baffled", "baffl"),
+    ("aspen", "aspen"),
+    ("exhortation", "exhortaz"),
+    ("wizened", "wizen"),
+    ("neighbouring", "neighbour"),
+    ("replaced", "replac"),
+    ("affront", "affront"),
+    ("headless", "headless"),
+    ("millinery", "millineri"),
+    ("lattice", "lattic"),
+    ("beguiled", "beguil"),
+    ("babies", "babi"),
+    ("trudges", "trudg"),
+    ("disembarrassed", "disembarrass"),
+    ("inarticulate", "inarticul"),
+    ("plucked", "pluck"),
+    ("laurels", "laurel"),
+    ("speculators", "specul"),
+    ("atwater", "atwat"),
+    ("insignificance", "insignific"),
+    ("twould", "twould"),
+    ("flrst", "flrst"),
+    ("woodcut", "woodcut"),
+    ("mining", "mine"),
+    ("mervyn", "mervyn"),
+    ("leaked", "leak"),
+    ("desperate", "desper"),
+    ("wrangling", "wrangl"),
+    ("planned", "plan"),
+    ("chai", "chai"),
+    ("villa", "villa"),
+    ("doing", "do"),
+    ("dease", "deas"),
+    ("grooms", "groom"),
+    ("translated", "translat"),
+    ("decaying", "decai"),
+    ("habitations", "habitaz"),
+    ("marmeladovs", "marmeladov"),
+    ("wickham", "wickham"),
+    ("glossary", "glossari"),
+    ("rhythmically", "rhythmic"),
+    ("erred", "er"),
+    ("extended", "extend"),
+    ("manufacture", "manufactur"),
+    ("poorly", "poorli"),
+    ("adulatory", "adulatori"),
+    ("ditch", "ditch"),
+    ("flaring", "flare"),
+    ("thatch", "thatch"),
+    ("visibly", "visibli"),
+    ("pebbles", "pebbl"),
+    ("avatars", "avatar"),
+    ("ils", "il"),
+    ("unguarded", "unguard"),
+    ("stilt", "stilt"),
+    ("hearne", "hearn"),
+    ("renegade", "renegad"),
+    ("servitude", "servitud"),
+    ("holder", "holder"),
+    ("sworn", "sworn"),
+    ("valid", "valid"),
+    ("patrons", "patron"),
+    ("intersected", "intersect"),
+    ("malcolmson", "malcolmson"),
+    ("biscuit", "biscuit"),
+    ("loam", "loam"),
+    ("boozed", "booz"),
+    ("elongation", "elongaz"),
+    ("rebel", "rebel"),
+    ("yearned", "yearn"),
+    ("ticklish", "ticklish"),
+    ("reckon", "reckon"),
+    ("scratched", "scratch"),
+    ("pleaders", "pleader"),
+    ("skaters", "skater"),
+    ("commoners", "common"),
+    ("chesney", "chesnei"),
+    ("kept", "kept"),
+    ("staid", "staid"),
+    ("puddle", "puddl"),
+    ("corner", "corner"),
+    ("industry", "industri"),
+    ("proud", "proud"),
+    ("arrogant", "arrog"),
+    ("associate", "associ"),
+    ("point", "point"),
+    ("paramatta", "paramatta"),
+    ("charles", "charl"),
+    ("banc", "banc"),
+    ("praise", "prais"),
+    ("sandaled", "sandal"),
+    ("corresponding", "correspond"),
+    ("stipulation", "stipulaz"),
+    ("captains", "captain"),
+    ("ya", "ya"),
+    ("capitalist", "capitalist"),
+    ("possess", "possess"),
+    ("inevitably", "inevit"),
+    ("ferret", "ferret"),
+    ("boulogne", "boulogn"),
+    ("cluster", "cluster"),
+    ("m", "m"),
+    ("panelling", "panel"),
+    ("benefited", "benefit"),
+    ("wiped", "wipe"),
+    ("heritage", "heritag"),
+    ("joyous", "joyou"),
+    ("run", "run"),
+    ("discontent", "discont"),
+    ("allegations", "allegaz"),
+    ("taunting", "taunt"),
+    ("pitcairn", "pitcairn"),
+    ("nether", "nether"),
+    ("future", "futur"),
+    ("rejoining", "rejoin"),
+    ("commendable", "commend"),
+    ("pouncing", "pounc"),
+    ("waned", "wane"),
+    ("setting", "set"),
+    ("piqued", "piqu"),
+    ("stammers", "stammer"),
+    ("orchideae", "orchidea"),
+    ("uncertain", "uncertain"),
+    ("punctually", "punctual"),
+    ("perkins", "perkin"),
+    ("relaxes", "relax"),
+    ("fragrant", "fragrant"),
+    ("half", "half"),
+    ("documents", "document"),
+    ("adamant", "adam"),
+    ("befriend", "befriend"),
+    ("independeuce", "independeuc"),
+    ("foinds", "foind"),
+    ("patrimonial", "patrimoni"),
+    ("thu", "thu"),
+    ("argyroneta", "argyroneta"),
+    ("transposing", "transpos"),
+    ("assortment", "assort"),
+    ("otus", "otu"),
+    ("horns", "horn"),
+    ("unceasing", "unceas"),
+    ("grazing", "graze"),
+    ("noblesse", "nobless"),
+    ("interrupt", "interrupt"),
+    ("juster", "juster"),
+    ("petitions", "petit"),
+    ("decapitated", "decapit"),
+    ("ails", "ail"),
+    ("muddocks", "muddock"),
+    ("geously", "geous"),
+    ("raking", "rake"),
+    ("laps", "lap"),
+    ("healthiness", "healthi"),
+    ("originally", "origin"),
+    ("wickedness", "wicked"),
+    ("facade", "facad"),
+    ("renewable", "renew"),
+    ("hungering", "hunger"),
+    ("smuggle", "smuggl"),
+    ("mist", "mist"),
+    ("auckland", "auckland"),
+    ("officials", "offici"),
+    ("thereof", "thereof"),
+    ("thin", "thin"),
+    ("madeline", "madelin"),
+    ("chronicles", "chronicl"),
+    ("sheltering", "shelter"),
+    ("society", "societi"),
+    ("endowments", "endow"),
+    ("tread", "tread"),
+    ("lamed", "lame"),
+    ("teaching", "teach"),
+    ("chaffinch", "chaffinch"),
+    ("patriarchal", "patriarch"),
+    ("unflinchingly", "unflinchingli"),
+    ("matched", "match"),
+    ("weeding", "weed"),
+    ("norman", "norman"),
+    ("ploughshare", "ploughshar"),
+    ("execrate", "execr"),
+    ("gnashing", "gnash"),
+    ("tiles", "tile"),
+    ("cheerfulness", "cheer"),
+    ("sunset", "sunset"),
+    ("repays", "repai"),
+    ("fainter", "fainter"),
+    ("meats", "meat"),
+    ("honestest", "honestest"),
+    ("fulness", ""),
+    ("handsomer", "handsom"),
+    ("gossiper", "gossip"),
+    ("poodle", "poodl"),
+    ("sanctum", "sanctum"),
+    ("burgomaster", "burgomast"),
+    ("di", "di"),
+    ("tractable", "tractabl"),
+    ("sprang", "sprang"),
+    ("couthouy", "couthoui"),
+    ("descended", "descend"),
+    ("tidied", "tidi"),
+    ("ye", "ye"),
+    ("live", "live"),
+    ("baseness", "base"),
+    ("overwhelms", "overwhelm"),
+    ("dialogues", "dialogu"),
+    ("rig", "rig"),
+    ("pervades", "pervad"),
+    ("embers", "ember"),
+    ("hurled", "hurl"),
+    ("belonged", "belong"),
+    ("definition", "definit"),
+    ("hedge", "hedg"),
+    ("rational", "rate"),
+    ("devout", "devout"),
+    ("provoking", "provok"),
+    ("vulgarest", "vulgarest"),
+    ("pile", "pile"),
+    ("tightly", "tightli"),
+    ("diappointments", "diappoint"),
+    ("squealing", "squeal"),
+    ("grassy", "grassi"),
+    ("footprint", "footprint"),
+    ("drizzle", "drizzl"),
+    ("undertaker", "undertak"),
+    ("pail", "pail"),
+    ("eprise", "epris"),
+    ("premised", "premis"),
+    ("ejaculations", "ejaculaz"),
+    ("sakes", "sake"),
+    ("caterpillar", "caterpillar"),
+    ("spaletro", "spaletro"),
+    ("always", "alwai"),
+    ("racing", "race"),
+    ("brief", "brief"),
+    ("continents", "contin"),
+    ("service", "servic"),
+    ("fasten", "fasten"),
+    ("felo", "felo"),
+    ("muses", "muse"),
+    ("sixth", "sixth"),
+    ("administer", "administ"),
+    ("refreshingly", "refreshingli"),
+    ("foliaceous", "foliac"),
+    ("shrub", "shrub"),
+    ("shrouded", "shroud"),
+    ("tranquillized", "tranquil"),
+    ("letters", "letter"),
+    ("butter", "butter"),
+    ("scar", "scar"),
+    ("storied", "stori"),
+    ("tree", "tree"),
+    ("puffed", "puf"),
+    ("meanly", "meanli"),
+    ("maddened", "madden"),
+    ("pearls", "pearl"),
+    ("solitaries", "solitari"),
+    ("tidy", "tidi"),
+    ("necessaries", "necessari"),
+    ("overturns", "overturn"),
+    ("cora", "cora"),
+    ("afeared", "afear"),
+    ("ridinghood", "ridinghood"),
+    ("gormandize", "gormand"),
+    ("hugged", "hug"),
+    ("stimulates", "stimul"),
+    ("philosophies", "philosophi"),
+    ("fibbing", "fib"),
+    ("ligament", "ligament"),
+    ("puzzling", "puzzl"),
+    ("discussing", "discuss"),
+    ("entomostracous", "entomostrac"),
+    ("fiercely", "fierc"),
+    ("nickelby", "nickelbi"),
+    ("rivalship", "rivalship"),
+    ("butting", "but"),
+    ("yard", "yard"),
+    ("detected", "detect"),
+    ("listed", "list"),
+    ("bridesmaid", "bridesmaid"),
+    ("gallows", "gallow"),
+    ("synonymous", "synonym"),
+    ("almac", "almac"),
+    ("flagging", "flag"),
+    ("trusts", "trust"),
+    ("import", "import"),
+    ("tho", "tho"),
+    ("doubtless", "doubtless"),
+    ("browsing", "brows"),
+    ("today", "todai"),
+    ("insipidity", "insipid"),
+    ("spileing", "spile"),
+    ("weakest", "weakest"),
+    ("pun", "pun"),
+    ("disastrous", "disastr"),
+    ("dispensing", "dispens"),
+    ("longest", "longest"),
+    ("lxii", "lxii"),
+    ("rangees", "range"),
+    ("make", "make"),
+    ("nigricans", "nigrican"),
+    ("xxix", "xxix"),
+    ("severest", "severest"),
+    ("commenting", "comment"),
+    ("drawer", "drawer"),
+    ("duped", "dupe"),
+    ("breakfasted", "breakfast"),
+    ("hunter", "hunter"),
+    ("adores", "ador"),
+    ("resuscitated", "resuscit"),
+    ("cancan", "cancan"),
+    ("overstated", "overst"),
+    ("melt", "melt"),
+    ("razors", "razor"),
+    ("pountney", "pountnei"),
+    ("fraud", "fraud"),
+    ("hyperbolical", "hyperbol"),
+    ("unholy", "unholi"),
+    ("maliciously", "malici"),
+    ("temptations", "temptaz"),
+    ("madras", "madra"),
+    ("captivator", "captiv"),
+    ("humbler", "humbler"),
+    ("gale", "gale"),
+    ("unsullied", "unsulli"),
+    ("ties", "ti"),
+    ("background", "background"),
+    ("crockford", "crockford"),
+    ("descendin", "descendin"),
+    ("salta", "salta"),
+    ("robin", "robin"),
+    ("suspended", "suspend"),
+    ("sombre", "sombr"),
+    ("debating", "debat"),
+    ("mont", "mont"),
+    ("dutch", "dutch"),
+    ("mutton", "mutton"),
+    ("reclaimed", "reclaim"),
+    ("zag", "zag"),
+    ("slaughter", "slaughter"),
+    ("threadbare", "threadbar"),
+    ("collected", "collect"),
+    ("stupids", "stupid"),
+    ("waat", "waat"),
+    ("embossed", "emboss"),
+    ("ribeira", "ribeira"),
+    ("forbade", "forbad"),
+    ("opened", "open"),
+    ("collect", "collect"),
+    ("consanguinity", "consanguin"),
+    ("lot", "lot"),
+    ("dennison", "dennison"),
+    ("tommy", "tommi"),
+    ("brimless", "brimless"),
+    ("jovial", "jovial"),
+    ("apteryz", "apteryz"),
+    ("senators", "senat"),
+    ("deducting", "deduct"),
+    ("warped", "warp"),
+    ("dependant", "depend"),
+    ("clifton", "clifton"),
+    ("unloved", "unlov"),
+    ("slenderer", "slender"),
+    ("sulphates", "sulphat"),
+    ("thot", "thot"),
+    ("tim", "tim"),
+    ("quarterly", "quarterli"),
+    ("everbrowns", "everbrown"),
+    ("wheelwright", "wheelwright"),
+    ("muttering", "mutter"),
+    ("bounde", "bound"),
+    ("endeavoured", "endeavour"),
+    ("yielded", "yield"),
+    ("inquest", "inquest"),
+    ("murmur", "murmur"),
+    ("navigator", "navig"),
+    ("feed", "feed"),
+    ("nocturna", "nocturna"),
+    ("liebig", "liebig"),
+    ("peal", "peal"),
+    ("ght", "ght"),
+    ("curving", "curv"),
+    ("louisa", "louisa"),
+    ("upper", "upper"),
+    ("missile", "missil"),
+    ("oppression", "oppress"),
+    ("pit", "pit"),
+    ("fated", "fate"),
+    ("strolls", "stroll"),
+    ("craw", "craw"),
+    ("similes", "simil"),
+    ("auspicious", "auspici"),
+    ("pith", "pith"),
+    ("bright", "bright"),
+    ("apprenticed", "apprent"),
+    ("hardness", "hard"),
+    ("engagements", "engag"),
+    ("tribute", "tribut"),
+    ("rung", "rung"),
+    ("tiptop", "tiptop"),
+    ("tit", "tit"),
+    ("pursue", "pursu"),
+    ("thankfully", "thankfulli"),
+    ("fattened", "fatten"),
+    ("cad", "cad"),
+    ("grecian", "grecian"),
+    ("mangling", "mangl"),
+    ("treacherous", "treacher"),
+    ("countenanced", "countenanc"),
+    ("ninth", "ninth"),
+    ("imaginings", "imagin"),
+    ("accusation", "accusaz"),
+    ("drifted", "drift"),
+    ("stabbed", "stab"),
+    ("freyrina", "freyrina"),
+    ("numbers", "number"),
+    ("garment", "garment"),
+    ("pretend", "pretend"),
+    ("diffidence", "diffid"),
+    ("whiter", "whiter"),
+    ("follows", "follow"),
+    ("veneration", "veneraz"),
+    ("meekest", "meekest"),
+    ("passwords", "password"),
+    ("reproof", "reproof"),
+    ("gallivanting", "galliv"),
+    ("displacing", "displac"),
+    ("powers", "power"),
+    ("acumen", "acumen"),
+    ("mortification", "mortificaz"),
+    ("ambitious", "ambiti"),
+    ("floated", "float"),
+    ("caucahue", "caucahu"),
+    ("herd", "herd"),
+    ("drown", "drown"),
+    ("revenged", "reveng"),
+    ("cover", "cover"),
+    ("behoves", "behov"),
+    ("root", "root"),
+    ("suffocated", "suffoc"),
+    ("varying", "vari"),
+    ("reporting", "report"),
+    ("voir", "voir"),
+    ("boreali", "boreali"),
+    ("bobs", "bob"),
+    ("lxv", "lxv"),
+    ("instructor", "instructor"),
+    ("kettles", "kettl"),
+    ("scantiest", "scantiest"),
+    ("crystals", "crystal"),
+    ("dressing", "dress"),
+    ("lalla", "lalla"),
+    ("sumptuous", "sumptuou"),
+    ("lesser", "lesser"),
+    ("vex", "vex"),
+    ("harlamov", "harlamov"),
+    ("crusading", "crusad"),
+    ("bushy", "bushi"),
+    ("unconvinced", "unconvinc"),
+    ("internally", "intern"),
+    ("adored", "ador"),
+    ("studied", "studi"),
+    ("operculum", "operculum"),
+    ("monitions", "monit"),
+    ("inlet", "inlet"),
+    ("tiresome", "tiresom"),
+    ("stately", "state"),
+    ("germ", "germ"),
+    ("konig", "konig"),
+    ("pinching", "pinch"),
+    ("naturalest", "naturalest"),
+    ("grotesquely", "grotesqu"),
+    ("misther", "misther"),
+    ("importing", "import"),
+    ("walking", "walk"),
+    ("overbalancing", "overbalanc"),
+    ("guessing", "guess"),
+    ("shoals", "shoal"),
+    ("congratulations", "congratulaz"),
+    ("mouthing", "mouth"),
+    ("mollusca", "mollusca"),
+    ("deficient", "defici"),
+    ("pyotr", "pyotr"),
+    ("conceal", "conceal"),
+    ("yawns", "yawn"),
+    ("likened", "liken"),
+    ("chafes", "chafe"),
+    ("starry", "starri"),
+    ("flickers", "flicker"),
+    ("pokorev", "pokorev"),
+    ("hulk", "hulk"),
+    ("coincide", "coincid"),
+    ("cloisterly", "cloisterli"),
+    ("lucrative", "lucr"),
+    ("it", "it"),
+    ("pedestal", "pedest"),
+    ("use", "us"),
+    ("reticles", "reticl"),
+    ("calcareo", "calcareo"),
+    ("consequences", "consequ"),
+    ("berquelo", "berquelo"),
+    ("watched", "watch"),
+    ("reign", "reign"),
+    ("income", "incom"),
+    ("rinsing", "rins"),
+    ("sculptor", "sculptor"),
+    ("gorgeous", "gorgeou"),
+    ("testifled", "testifl"),
+    ("rejects", "reject"),
+    ("refusal", "refus"),
+    ("reproaches", "reproach"),
+    ("morose", "moros"),
+    ("stray", "strai"),
+    ("nobodies", "nobodi"),
+    ("capabilities", "capabl"),
+    ("unmarried", "unmarri"),
+    ("calm", "calm"),
+    ("sultan", "sultan"),
+    ("crows", "crow"),
+    ("caroline", "carolin"),
+    ("pooder", "pooder"),
+    ("urged", "urg"),
+    ("float", "float"),
+    ("recklessness", "reckless"),
+    ("wrist", "wrist"),
+    ("broo", "broo"),
+    ("forward", "forward"),
+    ("gallanbile", "gallanbil"),
+    ("weakening", "weaken"),
+    ("nicher", "nicher"),
+    ("intellectually", "intellectu"),
+    ("parcels", "parcel"),
+    ("tradesfolk", "tradesfolk"),
+    ("jocularity", "jocular"),
+    ("sure", "sure"),
+    ("harden", "harden"),
+    ("device", "devic"),
+    ("monosyllable", "monosyl"),
+    ("apace", "apac"),
+    ("affability", "affabl"),
+    ("must", "must"),
+    ("deriving", "deriv"),
+    ("inspector", "inspector"),
+    ("secretary", "secretari"),
+    ("scriptural", "scriptur"),
+    ("idiotcy", "idiotci"),
+    ("excavations", "excavaz"),
+    ("polenka", "polenka"),
+    ("mammillated", "mammil"),
+    ("bullocks", "bullock"),
+    ("lists", "list"),
+    ("princely", "princ"),
+    ("statecraft", "statecraft"),
+    ("laity", "laiti"),
+    ("insulated", "insul"),
+    ("occupying", "occupi"),
+    ("bot", "bot"),
+    ("blight", "blight"),
+    ("saracens", "saracen"),
+    ("confirmation", "confirmaz"),
+    ("helvellyn", "helvellyn"),
+    ("theoretically", "theoret"),
+    ("fah", "fah"),
+    ("perlen", "perlen"),
+    ("ensure", "ensur"),
+    ("mew", "mew"),
+    ("candlesticks", "candlestick"),
+    ("grandeurs", "grandeur"),
+    ("implying", "impli"),
+    ("underhanded", "underhand"),
+    ("bespeaks", "bespeak"),
+    ("fool", "fool"),
+    ("foragers", "forag"),
+    ("boastfulness", "boast"),
+    ("frigid", "frigid"),
+    ("array", "arrai"),
+    ("blemish", "blemish"),
+    ("necessary", "necessari"),
+    ("copper", "copper"),
+    ("tenants", "tenant"),
+    ("guanaco", "guanaco"),
+    ("dodges", "dodg"),
+    ("jocular", "jocular"),
+    ("frequenters", "frequent"),
+    ("reproached", "reproach"),
+    ("enfolding", "enfold"),
+    ("retrograde", "retrograd"),
+    ("smith", "smith"),
+    ("accordingly", "accordingli"),
+    ("boulevard", "boulevard"),
+    ("untrue", "untru"),
+    ("coldly", "coldli"),
+    ("inquisitiveness", "inquisit"),
+    ("evils", "evil"),
+    ("inaccurately", "inaccur"),
+    ("lance", "lanc"),
+    ("asserted", "assert"),
+    ("buildings", "build"),
+    ("displeases", "displeas"),
+    ("admired", "admir"),
+    ("delicately", "delic"),
+    ("disheartening", "dishearten"),
+    ("feyther", "feyther"),
+    ("snug", "snug"),
+    ("accoun", "accoun"),
+    ("harrow", "harrow"),
+    ("brighton", "brighton"),
+    ("coward", "coward"),
+    ("meditate", "medit"),
+    ("carpet", "carpet"),
+    ("document", "document"),
+    ("sounds", "sound"),
+    ("parasites", "parasit"),
+    ("trumpery", "trumperi"),
+    ("funebre", "funebr"),
+    ("yourself", "yourself"),
+    ("chama", "chama"),
+    ("dooms", "doom"),
+    ("condescending", "condescend"),
+    ("restraint", "restraint"),
+    ("curtsied", "curtsi"),
+    ("quench", "quench"),
+    ("lift", "lift"),
+    ("leafy", "leafi"),
+    ("flogging", "flog"),
+    ("philanthropic", "philanthrop"),
+    ("staffs", "staff"),
+    ("countless", "countless"),
+    ("ivanovna", "ivanovna"),
+    ("mungo", "mungo"),
+    ("meditations", "meditaz"),
+    ("cetrer", "cetrer"),
+    ("treachery", "treacheri"),
+    ("judgments", "judgment"),
+    ("arming", "arm"),
+    ("precincts", "precinct"),
+    ("tricked", "trick"),
+    ("shipwrecked", "shipwreck"),
+    ("epaulets", "epaulet"),
+    ("savours", "savour"),
+    ("occasions", "occas"),
+    ("monster", "monster"),
+    ("minor", "minor"),
+    ("chastened", "chasten"),
+    ("cacti", "cacti"),
+    ("cliquot", "cliquot"),
+    ("sundry", "sundri"),
+    ("unbear", "unbear"),
+    ("budget", "budget"),
+    ("crush", "crush"),
+    ("or", "or"),
+    ("pegasus", "pegasu"),
+    ("spared", "spare"),
+    ("luxurious", "luxuri"),
+    ("credence", "credenc"),
+    ("scaley", "scalei"),
+    ("recipro", "recipro"),
+    ("mortgages", "mortgag"),
+    ("hampers", "hamper"),
+    ("humanizing", "human"),
+    ("undisfigured", "undisfigur"),
+    ("bearings", "bear"),
+    ("handed", "hand"),
+    ("judah", "judah"),
+    ("reproachfully", "reproachfulli"),
+    ("efther", "efther"),
+    ("abominably", "abomin"),
+    ("betting", "bet"),
+    ("chenille", "chenill"),
+    ("genteel", "genteel"),
+    ("concurrent", "concurr"),
+    ("writhed", "writh"),
+    ("pawnbrokers", "pawnbrok"),
+    ("uncover", "uncov"),
+    ("scoresby", "scoresbi"),
+    ("circumstantial", "circumstanti"),
+    ("quantities", "quantiti"),
+    ("ability", "abl"),
+    ("horribly", "horribli"),
+    ("avenues", "avenu"),
+    ("loaded", "load"),
+    ("besought", "besought"),
+    ("probably", "probabl"),
+    ("confuting", "confut"),
+    ("commemorate", "commemor"),
+    ("holborn", "holborn"),
+    ("unusually", "unusu"),
+    ("whereve", "wherev"),
+    ("asunder", "asund"),
+    ("undesirable", "undesir"),
+    ("milk", "milk"),
+    ("linked", "link"),
+    ("witticisms", "wittic"),
+    ("exertions", "exert"),
+    ("depict", "depict"),
+    ("conspicuous", "conspicu"),
+    ("graspus", "graspu"),
+    ("ivanitch", "ivanitch"),
+    ("twined", "twine"),
+    ("alighting", "alight"),
+    ("embellishing", "embellish"),
+    ("distanc", "distanc"),
+    ("gushed", "gush"),
+    ("marquis", "marqui"),
+    ("rakes", "rake"),
+    ("allenby", "allenbi"),
+    ("rather", "rather"),
+    ("shafts", "shaft"),
+    ("removed", "remov"),
+    ("oaken", "oaken"),
+    ("sooper", "sooper"),
+    ("presumes", "presum"),
+    ("refreshed", "refresh"),
+    ("gallinazo", "gallinazo"),
+    ("hornet", "hornet"),
+    ("impartial", "imparti"),
+    ("disdainfully", "disdainfulli"),
+    ("softest", "softest"),
+    ("shared", "share"),
+    ("vholeses", "vholes"),
+    ("uprisings", "upris"),
+    ("decencies", "decenc"),
+    ("heirlooms", "heirloom"),
+    ("postpone", "postpon"),
+    ("eternity", "etern"),
+    ("beaver", "beaver"),
+    ("leaning", "lean"),
+    ("thout", "thout"),
+    ("cancelled", "cancel"),
+    ("reception", "recept"),
+    ("croesus", "croesu"),
+    ("lifting", "lift"),
+    ("mal", "mal"),
+    ("chartered", "charter"),
+    ("wretchedly", "wretchedli"),
+    ("intervened", "interven"),
+    ("blear", "blear"),
+    ("damn", "damn"),
+    ("spoiling", "spoil"),
+    ("innuendoes", "innuendo"),
+    ("innumerable", "innumer"),
+    ("inextinguishable", "inextinguish"),
+    ("heartbroken", "heartbroken"),
+    ("adjoined", "adjoin"),
+    ("opposites", "opposit"),
+    ("concertina", "concertina"),
+    ("alley", "allei"),
+    ("apoplectic", "apoplect"),
+    ("stifling", "stifl"),
+    ("whispered", "whisper"),
+    ("rebound", "rebound"),
+    ("observers", "observ"),
+    ("surged", "surg"),
+    ("shock", "shock"),
+    ("courageous", "courag"),
+    ("curse", "curs"),
+    ("movement", "movement"),
+    ("yell", "yell"),
+    ("philippe", "philipp"),
+    ("seychelle", "seychell"),
+    ("perseveringly", "perseveringli"),
+    ("impetuously", "impetu"),
+    ("terrestrial", "terrestri"),
+    ("diverged", "diverg"),
+    ("indeed", "inde"),
+    ("distresses", "distress"),
+    ("heretick", "heretick"),
+    ("parrot", "parrot"),
+    ("sat", "sat"),
+    ("lizzy", "lizzi"),
+    ("dependants", "depend"),
+    ("dolichonyx", "dolichonyx"),
+    ("humanising", "humanis"),
+    ("sensible", "sensibl"),
+    ("inaugurates", "inaugur"),
+    ("flown", "flown"),
+    ("equitem", "equitem"),
+    ("chilly", "chilli"),
+    ("ganglion", "ganglion"),
+    ("beech", "beech"),
+    ("marl", "marl"),
+    ("stopped", "stop"),
+    ("unbrushed", "unbrush"),
+    ("embarrassments", "embarrass"),
+    ("heights", "height"),
+    ("confiscate", "confisc"),
+    ("fierce", "fierc"),
+    ("governors", "governor"),
+    ("intruding", "intrud"),
+    ("lauded", "laud"),
+    ("quell", "quell"),
+    ("puzzles", "puzzl"),
+    ("squally", "squal"),
+    ("congratulate", "congratul"),
+    ("betrothed", "betroth"),
+    ("waggishly", "waggishli"),
+    ("cast", "cast"),
+    ("steadiness", "steadi"),
+    ("contents", "content"),
+    ("company", "compani"),
+    ("tenez", "tenez"),
+    ("appeal", "appeal"),
+    ("befits", "befit"),
+    ("elicit", "elicit"),
+    ("chamber", "chamber"),
+    ("anonymous", "anonym"),
+    ("gift", "gift"),
+    ("fact", "fact"),
+    ("bribery", "briberi"),
+    ("terraces", "terrac"),
+    ("bless", "bless"),
+    ("realities", "real"),
+    ("disinterestedly", "disinterestedli"),
+    ("proodest", "proodest"),
+    ("esteeming", "esteem"),
+    ("resettles", "resettl"),
+    ("creepers", "creeper"),
+    ("porches", "porch"),
+    ("antonio", "antonio"),
+    ("sensibility", "sensibl"),
+    ("annoyances", "annoyanc"),
+    ("moroseness", "moros"),
+    ("agitators", "agit"),
+    ("senile", "senil"),
+    ("regularly", "regularli"),
+    ("hachette", "hachett"),
+    ("slides", "slide"),
+    ("hopelessness", "hopeless"),
+    ("tinted", "tint"),
+    ("blinded", "blind"),
+    ("demurred", "demur"),
+    ("respectably", "respect"),
+    ("testamentary", "testamentari"),
+    ("carve", "carv"),
+    ("bobbing", "bob"),
+    ("wheresoever", "wheresoev"),
+    ("budded", "bud"),
+    ("soomat", "soomat"),
+    ("candlelight", "candlelight"),
+    ("unity", "uniti"),
+    ("scrape", "scrape"),
+    ("coil", "coil"),
+    ("instrument", "instrument"),
+    ("usher", "usher"),
+    ("artistic", "artist"),
+    ("sketches", "sketch"),
+    ("locomotion", "locomot"),
+    ("vrazumihin", "vrazumihin"),
+    ("guiding", "guid"),
+    ("visaged", "visag"),
+    ("steps", "step"),
+    ("inanity", "inan"),
+    ("morbidly", "morbidli"),
+    ("albicollis", "albicolli"),
+    ("brood", "brood"),
+    ("musician", "musician"),
+    ("scalded", "scald"),
+    ("misbehaving", "misbehav"),
+    ("generously", "gener"),
+    ("everybody", "everybodi"),
+    ("adversity", "advers"),
+    ("aprons", "apron"),
+    ("warfare", "warfar"),
+    ("ate", "at"),
+    ("lone", "lone"),
+    ("justification", "justificaz"),
+    ("deplorable", "deplor"),
+    ("lodgers", "lodger"),
+    ("lampyris", "lampyri"),
+    ("alphabets", "alphabet"),
+    ("lost", "lost"),
+    ("export", "export"),
+    ("stools", "stool"),
+    ("cynucus", "cynucu"),
+    ("omitting", "omit"),
+    ("augusta", "augusta"),
+    ("pent", "pent"),
+    ("capping", "cap"),
+    ("assiduity", "assidu"),
+    ("rowland", "rowland"),
+    ("cheated", "cheat"),
+    ("samaritan", "samaritan"),
+    ("wail", "wail"),
+    ("imperilled", "imperil"),
+    ("sixthly", "sixthli"),
+    ("devastate", "devast"),
+    ("harps", "harp"),
+    ("degrading", "degrad"),
+    ("cambric", "cambric"),
+    ("submits", "submit"),
+    ("reckless", "reckless"),
+    ("netted", "net"),
+    ("melody", "melodi"),
+    ("protectors", "protector"),
+    ("sympathising", "sympathis"),
+    ("eldest", "eldest"),
+    ("cost", "cost"),
+    ("withheld", "withheld"),
+    ("tentative", "tent"),
+    ("zenith", "zenith"),
+    ("demd", "demd"),
+    ("birdcage", "birdcag"),
+    ("terseness", "ters"),
+    ("violate", "violat"),
+    ("date", "date"),
+    ("infusing", "infus"),
+    ("claying", "clai"),
+    ("coom", "coom"),
+    ("temperament", "tempera"),
+    ("tips", "tip"),
+    ("smacks", "smack"),
+    ("ugly", "ugli"),
+    ("ascertaining", "ascertain"),
+    ("obviating", "obviat"),
+    ("scienc", "scienc"),
+    ("flurries", "flurri"),
+    ("downing", "down"),
+    ("blurting", "blurt"),
+    ("porth", "porth"),
+    ("penal", "penal"),
+    ("attract", "attract"),
+    ("streetdoor", "streetdoor"),
+    ("pupils", "pupil"),
+    ("sallied", "sal"),
+    ("understand", "understand"),
+    ("pacify", "pacifi"),
+    ("hammered", "hammer"),
+    ("jolly", "jolli"),
+    ("miracle", "miracl"),
+    ("vayli", "vayli"),
+    ("croaking", "croak"),
+    ("inherited", "inherit"),
+    ("volubly", "volubli"),
+    ("detention", "detent"),
+    ("tuming", "tume"),
+    ("stamping", "stamp"),
+    ("unreclaimed", "unreclaim"),
+    ("liberty", "liberti"),
+    ("fucus", "fucu"),
+    ("boody", "boodi"),
+    ("equalled", "equal"),
+    ("sticking", "stick"),
+    ("sandwiches", "sandwich"),
+    ("sheep", "sheep"),
+    ("ascendant", "ascend"),
+    ("beginning", "begin"),
+    ("built", "built"),
+    ("hoar", "hoar"),
+    ("defenders", "defend"),
+    ("aeronautics", "aeronaut"),
+    ("reclining", "reclin"),
+    ("hunting", "hunt"),
+    ("slaked", "slake"),
+    ("gritting", "grit"),
+    ("affirms", "affirm"),
+    ("counterbalance", "counterbal"),
+    ("annoyed", "annoi"),
+    ("edinburgh", "edinburgh"),
+    ("sorely", "sore"),
+    ("potatoes", "potato"),
+    ("moorland", "moorland"),
+    ("chaste", "chast"),
+    ("succession", "success"),
+    ("contribution", "contribut"),
+    ("reforming", "reform"),
+    ("sharper", "sharper"),
+    ("overlooking", "overlook"),
+    ("comme", "comm"),
+    ("deed", "deed"),
+    ("mary", "mari"),
+    ("hawkinses", "hawkins"),
+    ("bee", "bee"),
+    ("launch", "launch"),
+    ("selected", "select"),
+    ("locksmiths", "locksmith"),
+    ("flashy", "flashi"),
+    ("component", "compon"),
+    ("pencilled", "pencil"),
+    ("mixed", "mix"),
+    ("suiting", "suit"),
+    ("lukewarm", "lukewarm"),
+    ("puppyism", "puppyism"),
+    ("undercliff", "undercliff"),
+    ("experienced", "experienc"),
+    ("vortex", "vortex"),
+    ("butterman", "butterman"),
+    ("horticulture", "horticultur"),
+    ("lvi", "lvi"),
+    ("classicality", "classic"),
+    ("entail", "entail"),
+    ("beastliness", "beastli"),
+    ("normally", "normal"),
+    ("lukin", "lukin"),
+    ("nominal", "nomin"),
+    ("evelyns", "evelyn"),
+    ("wretched", "wretch"),
+    ("inmost", "inmost"),
+    ("oraison", "oraison"),
+    ("effect", "effect"),
+    ("derives", "deriv"),
+    ("improving", "improv"),
+    ("trail", "trail"),
+    ("finery", "fineri"),
+    ("terminates", "termin"),
+    ("hop", "hop"),
+    ("unanue", "unanu"),
+    ("undivulged", "undivulg"),
+    ("lace", "lace"),
+    ("coke", "coke"),
+    ("mossy", "mossi"),
+    ("density", "densiti"),
+    ("boas", "boa"),
+    ("secluded", "seclud"),
+    ("propped", "prop"),
+    ("afflictions", "afflict"),
+    ("tormentor", "tormentor"),
+    ("overbearing", "overbear"),
+    ("clusters", "cluster"),
+    ("lurch", "lurch"),
+    ("queerest", "queerest"),
+    ("licentious", "licenti"),
+    ("stepson", "stepson"),
+    ("brigade", "brigad"),
+    ("illnesses", "il"),
+    ("band", "band"),
+    ("unpleasant", "unpleas"),
+    ("geolog", "geolog"),
+    ("contradiction", "contradict"),
+    ("improvisatrice", "improvisatric"),
+    ("shortcoming", "shortcom"),
+    ("own", "own"),
+    ("stealthy", "stealthi"),
+    ("erle", "erl"),
+    ("outgoing", "outgo"),
+    ("symptom", "symptom"),
+    ("affairs", "affair"),
+    ("conwenient", "conweni"),
+    ("exceeded", "exc"),
+    ("ungratefully", "ungratefulli"),
+    ("passin", "passin"),
+    ("dummy", "dummi"),
+    ("fortnight", "fortnight"),
+    ("circuit", "circuit"),
+    ("between", "between"),
+    ("match", "match"),
+    ("unscrews", "unscrew"),
+    ("katerina", "katerina"),
+    ("royalists", "royalist"),
+    ("custody", "custodi"),
+    ("backers", "backer"),
+    ("words", "word"),
+    ("stripes", "stripe"),
+    ("stoops", "stoop"),
+    ("bumped", "bump"),
+    ("grappling", "grappl"),
+    ("frosted", "frost"),
+    ("maids", "maid"),
+    ("ovarium", "ovarium"),
+    ("northerly", "northerli"),
+    ("scours", "scour"),
+    ("categories", "categori"),
+    ("vitiated", "vitiat"),
+    ("james", "jame"),
+    ("them", "them"),
+    ("hospitably", "hospit"),
+    ("declaimed", "declaim"),
+    ("depairture", "depairtur"),
+    ("husbandman", "husbandman"),
+    ("sills", "sill"),
+    ("drizzling", "drizzl"),
+    ("fonder", "fonder"),
+    ("unconquerable", "unconquer"),
+    ("forget", "forget"),
+    ("overwhelmingly", "overwhelmingli"),
+    ("gallantly", "gallantli"),
+    ("verbose", "verbos"),
+    ("wilheim", "wilheim"),
+    ("misanthrope", "misanthrop"),
+    ("lays", "lai"),
+    ("giggling", "giggl"),
+    ("tons", "ton"),
+    ("hay", "hai"),
+    ("defiance", "defianc"),
+    ("racked", "rack"),
+    ("organisation", "organisaz"),
+    ("pulse", "puls"),
+    ("andrews", "andrew"),
+    ("putrid", "putrid"),
+    ("announcement", "announc"),
+    ("spec", "spec"),
+    ("ormolu", "ormolu"),
+    ("ellis", "elli"),
+    ("cooms", "coom"),
+    ("trackless", "trackless"),
+    ("ich", "ich"),
+    ("tat", "tat"),
+    ("buoy", "buoi"),
+    ("extravagance", "extravag"),
+    ("creevy", "creevi"),
+    ("wands", "wand"),
+    ("visitor", "visitor"),
+    ("combining", "combin"),
+    ("lockouts", "lockout"),
+    ("jesus", "jesu"),
+    ("endear", "endear"),
+    ("correction", "correct"),
+    ("soliciting", "solicit"),
+    ("philosophy", "philosophi"),
+    ("excitableness", "excit"),
+    ("decease", "deceas"),
+    ("copiously", "copious"),
+    ("rose", "rose"),
+    ("whirls", "whirl"),
+    ("screeches", "screech"),
+    ("mind", "mind"),
+    ("perplexing", "perplex"),
+    ("unions", "union"),
+    ("hissing", "hiss"),
+    ("transitory", "transitori"),
+    ("afrosinya", "afrosinya"),
+    ("baptismal", "baptism"),
+    ("heedlessly", "heedlessli"),
+    ("retches", "retch"),
+    ("became", "becam"),
+    ("new", "new"),
+    ("lemuy", "lemui"),
+    ("frontispiece", "frontispiec"),
+    ("abbey", "abbei"),
+    ("practised", "practis"),
+    ("overlaid", "overlaid"),
+    ("dilettanti", "dilettanti"),
+    ("doted", "dote"),
+    ("kneading", "knead"),
+    ("improper", "improp"),
+    ("bulk", "bulk"),
+    ("purchaser", "purchas"),
+    ("trimmers", "trimmer"),
+    ("sehr", "sehr"),
+    ("remote", "remot"),
+    ("excellent", "excel"),
+    ("requirements", "requir"),
+    ("diverted", "divert"),
+    ("thousandths", "thousandth"),
+    ("reiterated", "reiter"),
+    ("able", "abl"),
+    ("winder", "winder"),
+    ("restrained", "restrain"),
+    ("sated", "sate"),
+    ("moderate", "moder"),
+    ("corpses", "corps"),
+    ("achievements", "achiev"),
+    ("bishopgate", "bishopg"),
+    ("fastened", "fasten"),
+    ("sweetened", "sweeten"),
+    ("capita", "capita"),
+    ("libertines", "libertin"),
+    ("airy", "airi"),
+    ("smallweedy", "smallweedi"),
+    ("wantonness", "wanton"),
+    ("instructions", "instruct"),
+    ("attributes", "attribut"),
+    ("manoeuvre", "manoeuvr"),
+    ("p", "p"),
+    ("ballad", "ballad"),
+    ("commodore", "commodor"),
+    ("enamoured", "enamour"),
+    ("highgate", "highgat"),
+    ("englishmen", "englishmen"),
+    ("moat", "moat"),
+    ("surprises", "surpris"),
+    ("feloniously", "feloni"),
+    ("county", "counti"),
+    ("prophesied", "prophesi"),
+    ("je", "je"),
+    ("battery", "batteri"),
+    ("stipulated", "stipul"),
+    ("broom", "broom"),
+    ("plagued", "plagu"),
+    ("slongs", "slong"),
+    ("jestingly", "jestingli"),
+    ("disk", "disk"),
+    ("adequate", "adequ"),
+    ("establishes", "establish"),
+    ("unfeigned", "unfeign"),
+    ("thanyou", "thanyou"),
+    ("terrific", "terrif"),
+    ("exaltation", "exaltaz"),
+    ("footsore", "footsor"),
+    ("umbrella", "umbrella"),
+    ("repentant", "repent"),
+    ("negotiations", "negotiaz"),
+    ("supported", "support"),
+    ("wooded", "wood"),
+    ("tol", "tol"),
+    ("extremity", "extrem"),
+    ("symbolism", "symbol"),
+    ("porphyrio", "porphyrio"),
+    ("sprucely", "spruce"),
+    ("carried", "carri"),
+    ("agonisingly", "agonisingli"),
+    ("votes", "vote"),
+    ("morrow", "morrow"),
+    ("frenzy", "frenzi"),
+    ("exordium", "exordium"),
+    ("protecting", "protect"),
+    ("shirked", "shirk"),
+    ("ogreish", "ogreish"),
+    ("mover", "mover"),
+    ("consoled", "consol"),
+    ("relative", "rel"),
+    ("knacks", "knack"),
+    ("bane", "bane"),
+    ("flouncing", "flounc"),
+    ("sadness", "sad"),
+    ("universally", "univers"),
+    ("vehemently", "vehement"),
+    ("emigrate", "emigr"),
+    ("wander", "wander"),
+    ("profession", "profess"),
+    ("estuaries", "estuari"),
+    ("dislike", "dislik"),
+    ("exercising", "exercis"),
+    ("cornice", "cornic"),
+    ("subdue", "subdu"),
+    ("chattering", "chatter"),
+    ("olive", "oliv"),
+    ("disillusion", "disillus"),
+    ("unique", "uniqu"),
+    ("leading", "lead"),
+    ("patted", "pat"),
+    ("overturning", "overturn"),
+    ("dimity", "dimiti"),
+    ("profuse", "profus"),
+    ("suborning", "suborn"),
+    ("consistency", "consist"),
+    ("decayed", "decai"),
+    ("considering", "consid"),
+    ("existing", "exist"),
+    ("cloths", "cloth"),
+    ("flunkey", "flunkei"),
+    ("lucidly", "lucidli"),
+    ("commonl", "commonl"),
+    ("arrangement", "arrang"),
+    ("cable", "cabl"),
+    ("dim", "dim"),
+    ("halloa", "halloa"),
+    ("whereof", "whereof"),
+    ("kissed", "kiss"),
+    ("minx", "minx"),
+    ("squat", "squat"),
+    ("warmest", "warmest"),
+    ("apostrophized", "apostroph"),
+    ("shapeless", "shapeless"),
+    ("inscriptions", "inscript"),
+    ("economic", "econom"),
+    ("twinkle", "twinkl"),
+    ("harold", "harold"),
+    ("treadmills", "treadmil"),
+    ("magnetized", "magnet"),
+    ("peepy", "peepi"),
+    ("worship", "worship"),
+    ("appalled", "appal"),
+    ("sagacious", "sagaci"),
+    ("henry", "henri"),
+    ("unreasonableness", "unreason"),
+    ("enlisting", "enlist"),
+    ("unfinished", "unfinish"),
+    ("personated", "person"),
+    ("tamper", "tamper"),
+    ("goats", "goat"),
+    ("hydrophobia", "hydrophobia"),
+    ("perianth", "perianth"),
+    ("station", "staze"),
+    ("fired", "fire"),
+    ("irredeemable", "irredeem"),
+    ("dependencies", "depend"),
+    ("strait", "strait"),
+    ("commissariat", "commissariat"),
+    ("abroad", "abroad"),
+    ("dissection", "dissect"),
+    ("seamed", "seam"),
+    ("novels", "novel"),
+    ("villainous", "villain"),
+    ("conspirators", "conspir"),
+    ("illusion", "illus"),
+    ("overdid", "overdid"),
+    ("riff", "riff"),
+    ("serpents", "serpent"),
+    ("quiver", "quiver"),
+    ("vehicle", "vehicl"),
+    ("revile", "revil"),
+    ("boffin", "boffin"),
+    ("intrigues", "intrigu"),
+    ("insense", "insens"),
+    ("louse", "lous"),
+    ("infusorial", "infusori"),
+    ("militia", "militia"),
+    ("authoritative", "authorit"),
+    ("institution", "institut"),
+    ("bring", "bring"),
+    ("nearly", "nearli"),
+    ("proclamation", "proclamaz"),
+    ("herons", "heron"),
+    ("richer", "richer"),
+    ("chairman", "chairman"),
+    ("janeiro", "janeiro"),
+    ("promissory", "promissori"),
+    ("spurs", "spur"),
+    ("quietude", "quietud"),
+    ("serviceably", "servic"),
+    ("poet", "poet"),
+    ("knob", "knob"),
+    ("qualifications", "qualificaz"),
+    ("centaurs", "centaur"),
+    ("offensively", "offens"),
+    ("wept", "wept"),
+    ("recommend", "recommend"),
+    ("oppressed", "oppress"),
+    ("heed", "heed"),
+    ("described", "describ"),
+    ("distortions", "distort"),
+    ("presumably", "presum"),
+    ("plausibly", "plausibli"),
+    ("hunts", "hunt"),
+    ("cunning", "cun"),
+    ("ruby", "rubi"),
+    ("ingratitude", "ingratitud"),
+    ("fear", "fear"),
+    ("jerk", "jerk"),
+    ("generally", "gener"),
+    ("quicker", "quicker"),
+    ("secures", "secur"),
+    ("eel", "eel"),
+    ("bronzed", "bronz"),
+    ("trudged", "trudg"),
+    ("appointed", "appoint"),
+    ("guns", "gun"),
+    ("conjuring", "conjur"),
+    ("longing", "long"),
+    ("corcovado", "corcovado"),
+    ("cleanses", "cleans"),
+    ("linguist", "linguist"),
+    ("hereditary", "hereditari"),
+    ("fennel", "fennel"),
+    ("scarlet", "scarlet"),
+    ("difficulty", "difficulti"),
+    ("skulking", "skulk"),
+    ("adios", "adio"),
+    ("borrowed", "borrow"),
+    ("lowliest", "lowliest"),
+    ("smoke", "smoke"),
+    ("fluttering", "flutter"),
+    ("prolixities", "prolix"),
+    ("indefinitely", "indefinit"),
+    ("differing", "differ"),
+    ("aquatic", "aquat"),
+    ("impregnable", "impregn"),
+    ("discerning", "discern"),
+    ("guano", "guano"),
+    ("castrated", "castrat"),
+    ("enriching", "enrich"),
+    ("appallingly", "appallingli"),
+    ("animosity", "animos"),
+    ("fashionable", "fashion"),
+    ("sensibly", "sensibli"),
+    ("insist", "insist"),
+    ("exponent", "expon"),
+    ("archly", "archli"),
+    ("are", "ar"),
+    ("keyholes", "keyhol"),
+    ("zooks", "zook"),
+    ("littered", "litter"),
+    ("unriddling", "unriddl"),
+    ("laterally", "later"),
+    ("connexion", "connexion"),
+    ("airily", "airili"),
+    ("fatigues", "fatigu"),
+    ("supports", "support"),
+    ("dispenses", "dispens"),
+    ("fragile", "fragil"),
+    ("scornful", "scorn"),
+    ("benchers", "bencher"),
+    ("growlings", "growl"),
+    ("dreaming", "dream"),
+    ("cushions", "cushion"),
+    ("impugned", "impugn"),
+    ("vista", "vista"),
+    ("gloomy", "gloomi"),
+    ("butterflies", "butterfli"),
+    ("reverend", "reverend"),
+    ("celery", "celeri"),
+    ("fetched", "fetch"),
+    ("irrigated", "irrig"),
+    ("loopholes", "loophol"),
+    ("imagine", "imagin"),
+    ("bales", "bale"),
+    ("recited", "recit"),
+    ("ford", "ford"),
+    ("curl", "curl"),
+    ("unfailing", "unfail"),
+    ("ferruginous", "ferrugin"),
+    ("introduction", "introduct"),
+    ("flounce", "flounc"),
+    ("arragonite", "arragonit"),
+    ("drying", "dri"),
+    ("key", "kei"),
+    ("primera", "primera"),
+    ("ailments", "ailment"),
+    ("heav", "heav"),
+    ("capricious", "caprici"),
+    ("yonge", "yong"),
+    ("generality", "gener"),
+    ("entrances", "entranc"),
+    ("arts", "art"),
+    ("fuse", "fuse"),
+    ("wrangle", "wrangl"),
+    ("toulon", "toulon"),
+    ("grasping", "grasp"),
+    ("francais", "francai"),
+    ("attache", "attach"),
+    ("anaemic", "anaem"),
+    ("reverting", "revert"),
+    ("cradle", "cradl"),
+    ("portfolios", "portfolio"),
+    ("jocosely", "jocos"),
+    ("solaces", "solac"),
+    ("vagabond", "vagabond"),
+    ("serpentine", "serpentin"),
+    ("afternoons", "afternoon"),
+    ("summon", "summon"),
+    ("sparingly", "sparingli"),
+    ("tix", "tix"),
+    ("posterity", "poster"),
+    ("opponent", "oppon"),
+    ("pities", "piti"),
+    ("wound", "wound"),
+    ("pandemoniac", "pandemoniac"),
+    ("speculative", "specul"),
+    ("silliest", "silliest"),
+    ("plump", "plump"),
+    ("circular", "circular"),
+    ("myiobius", "myiobiu"),
+    ("bray", "brai"),
+    ("blinding", "blind"),
+    ("convertion", "convert"),
+    ("degradation", "degradaz"),
+    ("plundered", "plunder"),
+    ("clergyman", "clergyman"),
+    ("condescendingly", "condescendingli"),
+    ("cherishing", "cherish"),
+    ("sufficient", "suffici"),
+    ("but", "but"),
+    ("ref", "ref"),
+    ("petowker", "petowk"),
+    ("muchisimas", "muchisima"),
+    ("regard", "regard"),
+    ("tassel", "tassel"),
+    ("proscribed", "proscrib"),
+    ("psychologist", "psychologist"),
+    ("addition", "addit"),
+    ("potchinkov", "potchinkov"),
+    ("disappoints", "disappoint"),
+    ("recrossed", "recross"),
+    ("tosses", "toss"),
+    ("natural", "natur"),
+    ("cogitating", "cogit"),
+    ("serviceable", "servic"),
+    ("lifetime", "lifetim"),
+    ("caldeleugh", "caldeleugh"),
+    ("hareskin", "hareskin"),
+    ("matrimonially", "matrimoni"),
+    ("cruise", "cruis"),
+    ("persecuting", "persecut"),
+    ("warbler", "warbler"),
+    ("finches", "finch"),
+    ("empress", "empress"),
+    ("expiation", "expiaz"),
+    ("perennial", "perenni"),
+    ("juryman", "juryman"),
+    ("embarked", "embark"),
+    ("andes", "and"),
+    ("hoity", "hoiti"),
+    ("transfixed", "transfix"),
+    ("renews", "renew"),
+    ("evidences", "evid"),
+    ("repaying", "repai"),
+    ("both", "both"),
+    ("blurted", "blurt"),
+    ("uncontradicted", "uncontradict"),
+    ("conceptions", "concept"),
+    ("hips", "hip"),
+    ("damme", "damm"),
+    ("tripped", "trip"),
+    ("glancing", "glanc"),
+    ("taming", "tame"),
+    ("tardily", "tardili"),
+    ("reappears", "reappear"),
+    ("vertically", "vertic"),
+    ("naturally", "natur"),
+    ("consumptive", "consumpt"),
+    ("tillage", "tillag"),
+    ("elect", "elect"),
+    ("fullest", "fullest"),
+    ("royalty", "royalti"),
+    ("seconded", "second"),
+    ("jist", "jist"),
+    ("insulted", "insult"),
+    ("disobedient", "disobedi"),
+    ("beautiful", "beauti"),
+    ("slovenly", "slovenli"),
+    ("shepherdesses", "shepherdess"),
+    ("proposes", "propos"),
+    ("improperly", "improperli"),
+    ("geography", "geographi"),
+    ("damnable", "damnabl"),
+    ("surge", "surg"),
+    ("plummets", "plummet"),
+    ("foals", "foal"),
+    ("sunflowers", "sunflow"),
+    ("bus", "bu"),
+    ("refine", "refin"),
+    ("executing", "execut"),
+    ("keepsakes", "keepsak"),
+    ("carse", "cars"),
+    ("fairest", "fairest"),
+    ("viewing", "view"),
+    ("antechambers", "antechamb"),
+    ("balandra", "balandra"),
+    ("whoo", "whoo"),
+    ("bungays", "bungai"),
+    ("commissions", "commiss"),
+    ("backyard", "backyard"),
+    ("ardness", "ard"),
+    ("ay", "ai"),
+    ("degage", "degag"),
+    ("eulogium", "eulogium"),
+    ("parrots", "parrot"),
+    ("crevice", "crevic"),
+    ("included", "includ"),
+    ("relatively", "rel"),
+    ("roguery", "rogueri"),
+    ("indenting", "indent"),
+    ("actresses", "actress"),
+    ("accepted", "accept"),
+    ("elsdale", "elsdal"),
+    ("outstripping", "outstrip"),
+    ("council", "council"),
+    ("exemplify", "exemplifi"),
+    ("judiciously", "judici"),
+    ("buzzed", "buzz"),
+    ("dianaea", "dianaea"),
+    ("pleasantly", "pleasantli"),
+    ("summits", "summit"),
+    ("examination", "examinaz"),
+    ("recrimination", "recriminaz"),
+    ("kinds", "kind"),
+    ("deepen", "deepen"),
+    ("thumb", "thumb"),
+    ("knave", "knave"),
+    ("spinnies", "spinni"),
+    ("region", "region"),
+    ("captivating", "captiv"),
+    ("adhere", "adher"),
+    ("alps", "alp"),
+    ("sulkiness", "sulki"),
+    ("city", "citi"),
+    ("absorbs", "absorb"),
+    ("upholsterer", "upholster"),
+    ("intersecting", "intersect"),
+    ("vibrating", "vibrat"),
+    ("footstep", "footstep"),
+    ("conjoint", "conjoint"),
+    ("cloister", "cloister"),
+    ("retainer", "retain"),
+    ("entrance", "entranc"),
+    ("observed", "observ"),
+    ("landing", "land"),
+    ("confessor", "confessor"),
+    ("sharpest", "sharpest"),
+    ("worm", "worm"),
+    ("tempting", "tempt"),
+    ("sacrificing", "sacrif"),
+    ("gracechurch", "gracechurch"),
+    ("sniffing", "snif"),
+    ("advertising", "advertis"),
+    ("newman", "newman"),
+    ("lucanee", "lucane"),
+    ("whate", "whate"),
+    ("intersection", "intersect"),
+    ("collectedly", "collectedli"),
+    ("marries", "marri"),
+    ("sentimentally", "sentiment"),
+    ("blooming", "bloom"),
+    ("hushed", "hush"),
+    ("cavities", "caviti"),
+    ("whensoever", "whensoev"),
+    ("carpeting", "carpet"),
+    ("stone", "stone"),
+    ("fashions", "fashion"),
+    ("professors", "professor"),
+    ("utmost", "utmost"),
+    ("timbrel", "timbrel"),
+    ("brasses", "brass"),
+    ("thickets", "thicket"),
+    ("exaggerate", "exagger"),
+    ("invalid", "invalid"),
+    ("bilious", "biliou"),
+    ("pursued", "pursu"),
+    ("outlets", "outlet"),
+    ("felicitously", "felicit"),
+    ("linger", "linger"),
+    ("dragoon", "dragoon"),
+    ("blowed", "blow"),
+    ("dacia", "dacia"),
+    ("terres", "terr"),
+    ("claim", "claim"),
+    ("devonshire", "devonshir"),
+    ("explode", "explod"),
+    ("route", "rout"),
+    ("blunt", "blunt"),
+    ("justifications", "justificaz"),
+    ("glacier", "glacier"),
+    ("debtor", "debtor"),
+    ("tone", "tone"),
+    ("mammalia", "mammalia"),
+    ("repeating", "repeat"),
+    ("identical", "ident"),
+    ("flabby", "flabbi"),
+    ("passing", "pass"),
+    ("negotiating", "negoti"),
+    ("innocents", "innoc"),
+    ("borrows", "borrow"),
+    ("lunch", "lunch"),
+    ("tearing", "tear"),
+    ("hydrophilidae", "hydrophilida"),
+    ("coavins", "coavin"),
+    ("infernal", "infern"),
+    ("acknowledgments", "acknowledg"),
+    ("slandered", "slander"),
+    ("pastoral", "pastor"),
+    ("congo", "congo"),
+    ("lecture", "lectur"),
+    ("inexpressibly", "inexpressibli"),
+    ("pyrophorus", "pyrophoru"),
+    ("planted", "plant"),
+    ("grinding", "grind"),
+    ("parlours", "parlour"),
+    ("peewits", "peewit"),
+    ("faculties", "faculti"),
+    ("milleypoise", "milleypois"),
+    ("rallied", "ral"),
+    ("twistings", "twist"),
+    ("offerings", "offer"),
+    ("zossimov", "zossimov"),
+    ("damask", "damask"),
+    ("falsehoods", "falsehood"),
+    ("resented", "resent"),
+    ("concert", "concert"),
+    ("orders", "order"),
+    ("piece", "piec"),
+    ("groves", "grove"),
+    ("checking", "check"),
+    ("fingerends", "fingerend"),
+    ("representative", "repres"),
+    ("concerning", "concern"),
+    ("velocity", "veloc"),
+    ("philanthropy", "philanthropi"),
+    ("loosened", "loosen"),
+    ("exhibitions", "exhibit"),
+    ("coronal", "coron"),
+    ("overshadows", "overshadow"),
+    ("arcade", "arcad"),
+    ("virginian", "virginian"),
+    ("skulk", "skulk"),
+    ("brand", "brand"),
+    ("establishment", "establish"),
+    ("philippines", "philippin"),
+    ("schedule", "schedul"),
+    ("torture", "tortur"),
+    ("miscarried", "miscarri"),
+    ("sentries", "sentri"),
+    ("paired", "pair"),
+    ("opposing", "oppos"),
+    ("germs", "germ"),
+    ("reliable", "reliabl"),
+    ("occupies", "occupi"),
+    ("obtains", "obtain"),
+    ("echoing", "echo"),
+    ("athos", "atho"),
+    ("sit", "sit"),
+    ("benignantly", "benignantli"),
+    ("balcony", "balconi"),
+    ("oily", "oili"),
+    ("repacked", "repack"),
+    ("eased", "eas"),
+    ("waants", "waant"),
+    ("abed", "ab"),
+    ("shallows", "shallow"),
+    ("obtrusively", "obtrus"),
+    ("scullions", "scullion"),
+    ("nuptial", "nuptial"),
+    ("retored", "retor"),
+    ("tortershell", "tortershel"),
+    ("ideality", "ideal"),
+    ("workmanlike", "workmanlik"),
+    ("boots", "boot"),
+    ("bloodthirsty", "bloodthirsti"),
+    ("fairness", "fair"),
+    ("begin", "begin"),
+    ("precipitous", "precipit"),
+    ("chatters", "chatter"),
+    ("asperity", "asper"),
+    ("dote", "dote"),
+    ("shed", "shed"),
+    ("hunters", "hunter"),
+    ("characterizes", "character"),
+    ("emotional", "emot"),
+    ("accurate", "accur"),
+    ("hatchings", "hatch"),
+    ("contingency", "conting"),
+    ("impecunious", "impecuni"),
+    ("whose", "whose"),
+    ("censuring", "censur"),
+    ("luciano", "luciano"),
+    ("heaves", "heav"),
+    ("conventionalities", "convention"),
+    ("strangeness", "strang"),
+    ("outgrown", "outgrown"),
+    ("marstone", "marston"),
+    ("skuttle", "skuttl"),
+    ("microscopic", "microscop"),
+    ("muskets", "musket"),
+    ("professing", "profess"),
+    ("secretaries", "secretari"),
+    ("armorial", "armori"),
+    ("note", "note"),
+    ("farming", "farm"),
+    ("spreading", "spread"),
+    ("rosy", "rosi"),
+    ("preliminary", "preliminari"),
+    ("creditable", "credit"),
+    ("neve", "neve"),
+    ("explorer", "explor"),
+    ("lxi", "lxi"),
+    ("impediments", "impedi"),
+    ("investigating", "investig"),
+    ("flourished", "flourish"),
+    ("absurdities", "absurd"),
+    ("protegee", "protege"),
+    ("enjoyingly", "enjoyingli"),
+    ("inappeasable", "inappeas"),
+    ("plus", "plu"),
+    ("tresses", "tress"),
+    ("mellows", "mellow"),
+    ("purified", "purifi"),
+    ("prilukov", "prilukov"),
+    ("partners", "partner"),
+    ("priests", "priest"),
+    ("forensic", "forens"),
+    ("unconcerned", "unconcern"),
+    ("tense", "tens"),
+    ("ostentatious", "ostentati"),
+    ("thronged", "throng"),
+    ("toilette", "toilett"),
+    ("artless", "artless"),
+    ("ledges", "ledg"),
+    ("certainl", "certainl"),
+    ("assembling", "assembl"),
+    ("bitterer", "bitter"),
+    ("harkye", "harky"),
+    ("managed", "manag"),
+    ("distrust", "distrust"),
+    ("stupidly", "stupidli"),
+    ("skye", "skye"),
+    ("slumbers", "slumber"),
+    ("toxodon", "toxodon"),
+    ("physalia", "physalia"),
+    ("asthma", "asthma"),
+    ("dreamed", "dream"),
+    ("disarrangement", "disarrang"),
+    ("migrates", "migrat"),
+    ("exceed", "exce"),
+    ("score", "score"),
+    ("borneo", "borneo"),
+    ("sparkling", "sparkl"),
+    ("shearing", "shear"),
+    ("challenged", "challeng"),
+    ("defies", "defi"),
+    ("advantageously", "advantag"),
+    ("peasants", "peasant"),
+    ("gunther", "gunther"),
+    ("exclusively", "exclus"),
+    ("jolting", "jolt"),
+    ("belief", "belief"),
+    ("niche", "nich"),
+    ("much", "much"),
+    ("slept", "slept"),
+    ("muffles", "muffl"),
+    ("picter", "picter"),
+    ("scrawl", "scrawl"),
+    ("fulvipes", "fulvip"),
+    ("outlive", "outliv"),
+    ("planty", "planti"),
+    ("disarming", "disarm"),
+    ("steppes", "stepp"),
+    ("corrupted", "corrupt"),
+    ("embraces", "embrac"),
+    ("investigation", "investigaz"),
+    ("madman", "madman"),
+    ("disguised", "disguis"),
+    ("cruelties", "cruelti"),
+    ("clayver", "clayver"),
+    ("onwards", "onward"),
+    ("spotty", "spotti"),
+    ("guppy", "guppi"),
+    ("deport", "deport"),
+    ("heliotrope", "heliotrop"),
+    ("clanking", "clank"),
+    ("quills", "quill"),
+    ("diseased", "diseas"),
+    ("profoundly", "profoundli"),
+    ("thriftless", "thriftless"),
+    ("desserts", "dessert"),
+    ("encouraged", "encourag"),
+    ("bottinney", "bottinnei"),
+    ("insufficiently", "insuffici"),
+    ("unheeded", "unh"),
+    ("manes", "mane"),
+    ("employers", "employer"),
+    ("discernment", "discern"),
+    ("overstepped", "overstep"),
+    ("elles", "ell"),
+    ("corrugated", "corrug"),
+    ("studies", "studi"),
+    ("impanelled", "impanel"),
+    ("circumstanced", "circumstanc"),
+    ("oppressive", "oppress"),
+    ("ezactly", "ezactli"),
+    ("percy", "perci"),
+    ("incurred", "incur"),
+    ("holds", "hold"),
+    ("ist", "ist"),
+    ("discursive", "discurs"),
+    ("raved", "rave"),
+    ("ranche", "ranch"),
+    ("harpalidae", "harpalida"),
+    ("expanded", "expand"),
+    ("undertake", "undertak"),
+    ("mum", "mum"),
+    ("regarding", "regard"),
+    ("demonstrated", "demonstr"),
+    ("inconsolable", "inconsol"),
+    ("randall", "randal"),
+    ("east", "east"),
+    ("outrageous", "outrag"),
+    ("foretell", "foretel"),
+    ("flaying", "flai"),
+    ("chins", "chin"),
+    ("antiquity", "antiqu"),
+    ("impassive", "impass"),
+    ("dooced", "dooc"),
+    ("proceeding", "proceed"),
+    ("jewellery", "jewelleri"),
+    ("diminishes", "diminish"),
+    ("patronised", "patronis"),
+    ("terror", "terror"),
+    ("dazzling", "dazzl"),
+    ("debasement", "debas"),
+    ("algarroba", "algarroba"),
+    ("desirable", "desir"),
+    ("ignited", "ignit"),
+    ("georgia", "georgia"),
+    ("blame", "blame"),
+    ("longed", "long"),
+    ("reprint", "reprint"),
+    ("angela", "angela"),
+    ("sturdily", "sturdili"),
+    ("vom", "vom"),
+    ("defiled", "defil"),
+    ("speech", "speech"),
+    ("shifts", "shift"),
+    ("discontinuance", "discontinu"),
+    ("zoodle", "zoodl"),
+    ("attentions", "attent"),
+    ("listeners", "listen"),
+    ("skull", "skull"),
+    ("deceived", "deceiv"),
+    ("aesthetics", "aesthet"),
+    ("shores", "shore"),
+    ("aglow", "aglow"),
+    ("dependent", "depend"),
+    ("prototype", "prototyp"),
+    ("cowley", "cowlei"),
+    ("correct", "correct"),
+    ("finnish", "finnish"),
+    ("whipt", "whipt"),
+    ("chestnut", "chestnut"),
+    ("shouldering", "shoulder"),
+    ("doom", "doom"),
+    ("pair", "pair"),
+    ("whisperers", "whisper"),
+    ("outpourings", "outpour"),
+    ("decimals", "decim"),
+    ("ship", "ship"),
+    ("shorts", "short"),
+    ("worth", "worth"),
+    ("glared", "glare"),
+    ("pant", "pant"),
+    ("fotheringham", "fotheringham"),
+    ("inquires", "inquir"),
+    ("bellmen", "bellmen"),
+    ("sheets", "sheet"),
+    ("intrigue", "intrigu"),
+    ("speedy", "speedi"),
+    ("reproduced", "reproduc"),
+    ("maldiva", "maldiva"),
+    ("griefs", "grief"),
+    ("famoso", "famoso"),
+    ("holland", "holland"),
+    ("promotes", "promot"),
+    ("fuddled", "fuddl"),
+    ("set", "set"),
+    ("contests", "contest"),
+    ("francis", "franc"),
+    ("intonations", "intonaz"),
+    ("lisbon", "lisbon"),
+    ("tamer", "tamer"),
+    ("unchangeably", "unchang"),
+    ("patients", "patient"),
+    ("amongst", "amongst"),
+    ("peeps", "peep"),
+    ("betwixt", "betwixt"),
+    ("blends", "blend"),
+    ("beckoning", "beckon"),
+    ("nudging", "nudg"),
+    ("accompaniment", "accompani"),
+    ("see", "see"),
+    ("roving", "rove"),
+    ("avenger", "aveng"),
+    ("whish", "whish"),
+    ("lear", "lear"),
+    ("snubs", "snub"),
+    ("mosses", "moss"),
+    ("stylish", "stylish"),
+    ("cork", "cork"),
+    ("trenchant", "trenchant"),
+    ("cockaded", "cockad"),
+    ("adversaries", "adversari"),
+    ("abstract", "abstract"),
+    ("nesbit", "nesbit"),
+    ("proudly", "proudli"),
+    ("speciality", "special"),
+    ("tapster", "tapster"),
+    ("gently", "gent"),
+    ("accountability", "account"),
+    ("default", "default"),
+    ("sapped", "sap"),
+    ("burrow", "burrow"),
+    ("avers", "aver"),
+    ("chased", "chase"),
+    ("nikolay", "nikolai"),
+    ("flat", "flat"),
+    ("honester", "honest"),
+    ("laborious", "labori"),
+    ("melasoma", "melasoma"),
+    ("sleeping", "sleep"),
+    ("bands", "band"),
+    ("cracked", "crack"),
+    ("partridges", "partridg"),
+    ("densely", "dens"),
+    ("dips", "dip"),
+    ("fetches", "fetch"),
+    ("envies", "envi"),
+    ("casarita", "casarita"),
+    ("gateway", "gatewai"),
+    ("padded", "pad"),
+    ("crustaceous", "crustac"),
+    ("erec", "erec"),
+    ("parochial", "parochi"),
+    ("daresay", "daresai"),
+    ("bores", "bore"),
+    ("strain", "strain"),
+    ("sparing", "spare"),
+    ("postern", "postern"),
+    ("presentiment", "presenti"),
+    ("latro", "latro"),
+    ("berteroii", "berteroii"),
+    ("slave", "slave"),
+    ("benchuca", "benchuca"),
+    ("snubbed", "snub"),
+    ("raw", "raw"),
+    ("runaway", "runawai"),
+    ("licenses", "licens"),
+    ("discountenanced", "discountenanc"),
+    ("go", "go"),
+    ("guardianship", "guardianship"),
+    ("unalluring", "unallur"),
+    ("tyrannized", "tyrann"),
+    ("puts", "put"),
+    ("corporation", "corporaz"),
+    ("dissuaded", "dissuad"),
+    ("kinkajou", "kinkaj"),
+    ("virtually", "virtual"),
+    ("ministers", "minist"),
+    ("obtruding", "obtrud"),
+    ("shalt", "shalt"),
+    ("sittin", "sittin"),
+    ("collision", "collis"),
+    ("duffer", "duffer"),
+    ("aft", "aft"),
+    ("encroachment", "encroach"),
+    ("seriously", "serious"),
+    ("bloodhound", "bloodhound"),
+    ("home", "home"),
+    ("tabanus", "tabanu"),
+    ("glaze", "glaze"),
+    ("spak", "spak"),
+    ("xi", "xi"),
+    ("pals", "pal"),
+    ("encrusted", "encrust"),
+    ("blackguardly", "blackguardli"),
+    ("poetizing", "poetiz"),
+    ("wealthy", "wealthi"),
+    ("mocked", "mock"),
+    ("causes", "caus"),
+    ("upshot", "upshot"),
+    ("horizontally", "horizont"),
+    ("ample", "ampl"),
+    ("crashed", "crash"),
+    ("creeping", "creep"),
+    ("presumption", "presumpt"),
+    ("rinse", "rins"),
+    ("abasement", "abas"),
+    ("analogous", "analog"),
+    ("cock", "cock"),
+    ("alyoshka", "alyoshka"),
+    ("shunning", "shun"),
+    ("labyrinths", "labyrinth"),
+    ("botanist", "botanist"),
+    ("altercation", "altercaz"),
+    ("jerked", "jerk"),
+    ("fearfully", "fearfulli"),
+    ("shrieked", "shriek"),
+    ("saturated", "satur"),
+    ("anciently", "ancient"),
+    ("pounder", "pounder"),
+    ("relieve", "reliev"),
+    ("thursdays", "thursdai"),
+    ("transform", "transform"),
+    ("wings", "wing"),
+    ("harlequin", "harlequin"),
+    ("proving", "prove"),
+    ("chaperone", "chaperon"),
+    ("chacun", "chacun"),
+    ("cooling", "cool"),
+    ("finances", "financ"),
+    ("verdant", "verdant"),
+    ("raa", "raa"),
+    ("labourious", "labouri"),
+    ("inarticulately", "inarticul"),
+    ("endowed", "endow"),
+    ("clippers", "clipper"),
+    ("unconscious", "unconsci"),
+    ("agonies", "agoni"),
+    ("functionary", "functionari"),
+    ("opining", "opin"),
+    ("dragooning", "dragoon"),
+    ("sovereignty", "sovereignti"),
+    ("cobbler", "cobbler"),
+    ("garde", "gard"),
+    ("patterns", "pattern"),
+    ("citizens", "citizen"),
+    ("sect", "sect"),
+    ("anoother", "anooth"),
+    ("imperfect", "imperfect"),
+    ("costumes", "costum"),
+    ("associates", "associ"),
+    ("extricating", "extric"),
+    ("harbours", "harbour"),
+    ("mournfulness", "mourn"),
+    ("justifies", "justifi"),
+    ("mysteries", "mysteri"),
+    ("terse", "ters"),
+    ("playsure", "playsur"),
+    ("canister", "canist"),
+    ("utilized", "util"),
+    ("disregarding", "disregard"),
+    ("think", "think"),
+    ("lamp", "lamp"),
+    ("whim", "whim"),
+    ("roby", "robi"),
+    ("venda", "venda"),
+    ("volney", "volnei"),
+    ("uncontrolled", "uncontrol"),
+    ("superciliously", "supercili"),
+    ("bookish", "bookish"),
+    ("stupidity", "stupid"),
+    ("prosperous", "prosper"),
+    ("concedes", "conced"),
+    ("coercive", "coerciv"),
+    ("forgery", "forgeri"),
+    ("entreat", "entreat"),
+    ("fill", "fill"),
+    ("adjutant", "adjut"),
+    ("pertinacity", "pertinac"),
+    ("infinite", "infinit"),
+    ("belgrave", "belgrav"),
+    ("latel", "latel"),
+    ("dale", "dale"),
+    ("eleven", "eleven"),
+    ("troop", "troop"),
+    ("severn", "severn"),
+    ("insisting", "insist"),
+    ("braxon", "braxon"),
+    ("owdacious", "owdaci"),
+    ("overcoming", "overcom"),
+    ("brisker", "brisker"),
+    ("imposed", "impos"),
+    ("vilified", "vilifi"),
+    ("knot", "knot"),
+    ("spiders", "spider"),
+    ("hydrochaerus", "hydrochaeru"),
+    ("satellite", "satellit"),
+    ("hums", "hum"),
+    ("owing", "ow"),
+    ("cupid", "cupid"),
+    ("bona", "bona"),
+    ("profundity", "profund"),
+    ("mouthpiece", "mouthpiec"),
+    ("profits", "profit"),
+    ("seas", "sea"),
+    ("prompting", "prompt"),
+    ("ney", "nei"),
+    ("misjudges", "misjudg"),
+    ("keepers", "keeper"),
+    ("defects", "defect"),
+    ("scream", "scream"),
+    ("morass", "morass"),
+    ("bott", "bott"),
+    ("malleable", "malleabl"),
+    ("planks", "plank"),
+    ("let", "let"),
+    ("bass", "bass"),
+    ("camberwell", "camberwel"),
+    ("accompanying", "accompani"),
+    ("opportune", "opportun"),
+    ("creditors", "creditor"),
+    ("peach", "peach"),
+    ("sevenpence", "sevenp"),
+    ("sanction", "sanction"),
+    ("criminals", "crimin"),
+    ("unmolested", "unmolest"),
+    ("depute", "deput"),
+    ("sackcloths", "sackcloth"),
+    ("buried", "buri"),
+    ("estate", "estat"),
+    ("wineglassful", "wineglass"),
+    ("manganese", "manganes"),
+    ("wellington", "wellington"),
+    ("christopher", "christoph"),
+    ("wrap", "wrap"),
+    ("falser", "falser"),
+    ("wager", "wager"),
+    ("whole", "whole"),
+    ("leggings", "leg"),
+    ("topic", "topic"),
+    ("scuffle", "scuffl"),
+    ("lingering", "linger"),
+    ("emma", "emma"),
+    ("smartnesses", "smart"),
+    ("lighted", "light"),
+    ("unimpaired", "unimpair"),
+    ("uproarious", "uproari"),
+    ("black", "black"),
+    ("heigho", "heigho"),
+    ("hypochondria", "hypochondria"),
+    ("indignantly", "indignantli"),
+    ("bother", "bother"),
+    ("tablecloth", "tablecloth"),
+    ("manifestation", "manifestaz"),
+    ("target", "target"),
+    ("proudhon", "proudhon"),
+    ("conscientiously", "conscienti"),
+    ("invigoration", "invigoraz"),
+    ("obliging", "oblig"),
+    ("clubs", "club"),
+    ("fernando", "fernando"),
+    ("squeak", "squeak"),
+    ("totum", "totum"),
+    ("trembles", "trembl"),
+    ("enclosure", "enclosur"),
+    ("strong", "strong"),
+    ("curses", "curs"),
+    ("solves", "solv"),
+    ("walckanaer", "walckana"),
+    ("overstep", "overstep"),
+    ("handel", "handel"),
+    ("assailant", "assail"),
+    ("preponderated", "preponder"),
+    ("wasteful", "wast"),
+    ("methodically", "method"),
+    ("disappointed", "disappoint"),
+    ("deciduous", "decidu"),
+    ("parchmentses", "parchments"),
+    ("hideously", "hideous"),
+    ("grapes", "grape"),
+    ("saws", "saw"),
+    ("fables", "fabl"),
+    ("remorse", "remors"),
+    ("trustful", "trust"),
+    ("redoubling", "redoubl"),
+    ("pier", "pier"),
+    ("contradistinction", "contradistinct"),
+    ("secondly", "secondli"),
+    ("whitened", "whiten"),
+    ("langwedge", "langwedg"),
+    ("lessening", "lessen"),
+    ("din", "din"),
+    ("might", "might"),
+    ("adages", "adag"),
+    ("spoon", "spoon"),
+    ("recess", "recess"),
+    ("inflation", "inflaz"),
+    ("paragon", "paragon"),
+    ("oh", "oh"),
+    ("cleanse", "cleans"),
+    ("unfortunates", "unfortun"),
+    ("nigricollis", "nigricolli"),
+    ("lucy", "luci"),
+    ("proclaimed", "proclaim"),
+    ("bitten", "bitten"),
+    ("featured", "featur"),
+    ("scant", "scant"),
+    ("classes", "class"),
+    ("shufflers", "shuffler"),
+    ("harmlessly", "harmlessli"),
+    ("fervour", "fervour"),
+    ("owen", "owen"),
+    ("blackberry", "blackberri"),
+    ("heteromera", "heteromera"),
+    ("anecdotes", "anecdot"),
+    ("pundits", "pundit"),
+    ("emptying", "empti"),
+    ("drained", "drain"),
+    ("stigmatize", "stigmat"),
+    ("comer", "comer"),
+    ("blowpipe", "blowpip"),
+    ("credibility", "credibl"),
+    ("precedence", "preced"),
+    ("avoid", "avoid"),
+    ("youn", "youn"),
+    ("sillies", "silli"),
+    ("awhiles", "awhil"),
+    ("unclose", "unclos"),
+    ("rocket", "rocket"),
+    ("commixta", "commixta"),
+    ("boroughs", "borough"),
+    ("learn", "learn"),
+    ("suspension", "suspens"),
+    ("twain", "twain"),
+    ("guitar", "guitar"),
+    ("cookites", "cookit"),
+    ("contain", "contain"),
+    ("browns", "brown"),
+    ("waltzing", "waltz"),
+    ("cleansing", "cleans"),
+    ("deuced", "deuc"),
+    ("chivied", "chivi"),
+    ("shambling", "shambl"),
+    ("jets", "jet"),
+    ("sketch", "sketch"),
+    ("cheque", "chequ"),
+    ("consoling", "consol"),
+    ("honouring", "honour"),
+    ("damnation", "damnaz"),
+    ("thirsting", "thirst"),
+    ("doubts", "doubt"),
+    ("informed", "inform"),
+    ("roulette", "roulett"),
+    ("seacoast", "seacoast"),
+    ("wild", "wild"),
+    ("scare", "scare"),
+    ("cattle", "cattl"),
+    ("seemed", "seem"),
+    ("pride", "pride"),
+    ("references", "refer"),
+    ("spearing", "spear"),
+    ("chaise", "chais"),
+    ("sinned", "sin"),
+    ("idlers", "idler"),
+    ("imminent", "immin"),
+    ("slighter", "slighter"),
+    ("warhorse", "warhors"),
+    ("ancestress", "ancestress"),
+    ("gainer", "gainer"),
+    ("befit", "befit"),
+    ("positions", "posit"),
+    ("hatter", "hatter"),
+    ("flirtations", "flirtaz"),
+    ("tenway", "tenwai"),
+    ("overthrown", "overthrown"),
+    ("hilts", "hilt"),
+    ("snipping", "snip"),
+    ("condolences", "condol"),
+    ("candidates", "candid"),
+    ("perfecfly", "perfecfli"),
+    ("pillows", "pillow"),
+    ("garland", "garland"),
+    ("unqualified", "unqualifi"),
+    ("smashed", "smash"),
+    ("zametov", "zametov"),
+    ("grand", "grand"),
+    ("gradation", "gradaz"),
+    ("tallest", "tallest"),
+    ("allotting", "allot"),
+    ("whiles", "while"),
+    ("anomaly", "anomali"),
+    ("journals", "journal"),
+    ("contentedly", "contentedli"),
+    ("impressionable", "impression"),
+    ("betuloides", "betuloid"),
+    ("revelry", "revelri"),
+    ("benefactors", "benefactor"),
+    ("sampler", "sampler"),
+    ("elizabeth", "elizabeth"),
+    ("north", "north"),
+    ("preface", "prefac"),
+    ("caffres", "caffr"),
+    ("stuck", "stuck"),
+    ("vacantly", "vacantli"),
+    ("wanted", "want"),
+    ("nogg", "nogg"),
+    ("derivable", "deriv"),
+    ("man", "man"),
+    ("duty", "duti"),
+    ("unconnected", "unconnect"),
+    ("indirectly", "indirectli"),
+    ("flap", "flap"),
+    ("victim", "victim"),
+    ("privileges", "privileg"),
+    ("masterpieces", "masterpiec"),
+    ("produced", "produc"),
+    ("dollars", "dollar"),
+    ("slack", "slack"),
+    ("remensher", "remensh"),
+    ("flustraceae", "flustracea"),
+    ("moan", "moan"),
+    ("sick", "sick"),
+    ("waxing", "wax"),
+    ("regretted", "regret"),
+    ("lin", "lin"),
+    ("voted", "vote"),
+    ("watchful", "watch"),
+    ("byways", "bywai"),
+    ("playmates", "playmat"),
+    ("fighter", "fighter"),
+    ("happily", "happili"),
+    ("feaced", "feac"),
+    ("acknowledgment", "acknowledg"),
+    ("gables", "gabl"),
+    ("eas", "ea"),
+    ("signification", "significaz"),
+    ("luxan", "luxan"),
+    ("sleet", "sleet"),
+    ("web", "web"),
+    ("footer", "footer"),
+    ("eggs", "egg"),
+    ("boys", "boi"),
+    ("unchain", "unchain"),
+    ("fearlessly", "fearlessli"),
+    ("tortuous", "tortuou"),
+    ("wounds", "wound"),
+    ("sweetie", "sweeti"),
+    ("intensity", "intens"),
+    ("nayver", "nayver"),
+    ("grater", "grater"),
+    ("chaotic", "chaotic"),
+    ("estanciero", "estanciero"),
+    ("inconvenienced", "inconvenienc"),
+    ("blissful", "bliss"),
+    ("compacts", "compact"),
+    ("parallelism", "parallel"),
+    ("sixteen", "sixteen"),
+    ("extras", "extra"),
+    ("lion", "lion"),
+    ("trustees", "truste"),
+    ("watch", "watch"),
+    ("uproariously", "uproari"),
+    ("unclean", "unclean"),
+    ("ewent", "ewent"),
+    ("meddle", "meddl"),
+    ("ruder", "ruder"),
+    ("graced", "grace"),
+    ("dresser", "dresser"),
+    ("executor", "executor"),
+    ("ideas", "idea"),
+    ("bedding", "bed"),
+    ("mystic", "mystic"),
+    ("kelp", "kelp"),
+    ("blushed", "blush"),
+    ("dearer", "dearer"),
+    ("bashfulness", "bash"),
+    ("geologizing", "geolog"),
+    ("vilinco", "vilinco"),
+    ("scowled", "scowl"),
+    ("injected", "inject"),
+    ("dole", "dole"),
+    ("grooves", "groov"),
+    ("tibiae", "tibia"),
+    ("animated", "anim"),
+    ("ploughman", "ploughman"),
+    ("highways", "highwai"),
+    ("douglas", "dougla"),
+    ("tightness", "tight"),
+    ("nat", "nat"),
+    ("doorways", "doorwai"),
+    ("margins", "margin"),
+    ("attack", "attack"),
+    ("ana", "ana"),
+    ("catches", "catch"),
+    ("foal", "foal"),
+    ("australian", "australian"),
+    ("nation", "naze"),
+    ("appealing", "appeal"),
+    ("weeks", "week"),
+    ("affright", "affright"),
+    ("xvi", "xvi"),
+    ("offe", "off"),
+    ("mainly", "mainli"),
+    ("butchered", "butcher"),
+    ("improbable", "improb"),
+    ("connect", "connect"),
+    ("shongi", "shongi"),
+    ("virtuously", "virtuous"),
+    ("sticks", "stick"),
+    ("expiating", "expiat"),
+    ("principals", "princip"),
+    ("humility", "humil"),
+    ("fails", "fail"),
+    ("wheelbarrow", "wheelbarrow"),
+    ("breathe", "breath"),
+    ("hazy", "hazi"),
+    ("husky", "huski"),
+    ("travertin", "travertin"),
+    ("noting", "note"),
+    ("tribes", "tribe"),
+    ("shh", "shh"),
+    ("cigars", "cigar"),
+    ("tiger", "tiger"),
+    ("choiseul", "choiseul"),
+    ("occupy", "occupi"),
+    ("factory", "factori"),
+    ("avert", "avert"),
+    ("faugh", "faugh"),
+    ("columbus", "columbu"),
+    ("adapts", "adapt"),
+    ("actuate", "actuat"),
+    ("frenchwomen", "frenchwomen"),
+    ("sur", "sur"),
+    ("now", "now"),
+    ("prompter", "prompter"),
+    ("nero", "nero"),
+    ("fruitless", "fruitless"),
+    ("mignonette", "mignonett"),
+    ("lilacs", "lilac"),
+    ("gleamed", "gleam"),
+    ("decorates", "decor"),
+    ("perversely", "pervers"),
+    ("swerved", "swerv"),
+    ("archducal", "archduc"),
+    ("elgble", "elgbl"),
+    ("pompey", "pompei"),
+    ("manful", "man"),
+    ("recipe", "recip"),
+    ("paposo", "paposo"),
+    ("taunts", "taunt"),
+    ("memorials", "memori"),
+    ("huggins", "huggin"),
+    ("beforehand", "beforehand"),
+    ("obstacle", "obstacl"),
+    ("uncles", "uncl"),
+    ("chivalrous", "chivalr"),
+    ("considerably", "consider"),
+    ("drunkard", "drunkard"),
+    ("blunder", "blunder"),
+    ("whereabouts", "whereabout"),
+    ("hag", "hag"),
+    ("incentive", "incent"),
+    ("treat", "treat"),
+    ("sac", "sac"),
+    ("winced", "winc"),
+    ("assembled", "assembl"),
+    ("remonstrated", "remonstr"),
+    ("partially", "partial"),
+    ("brachyptera", "brachyptera"),
+    ("obispo", "obispo"),
+    ("removing", "remov"),
+    ("booby", "boobi"),
+    ("shoved", "shove"),
+    ("hymenoptera", "hymenoptera"),
+    ("frantically", "frantic"),
+    ("harmonious", "harmoni"),
+    ("statues", "statu"),
+    ("blew", "blew"),
+    ("perished", "perish"),
+    ("unsteadily", "unsteadili"),
+    ("calle", "call"),
+    ("bearer", "bearer"),
+    ("versatile", "versatil"),
+    ("quite", "quit"),
+    ("solemnity", "solemn"),
+    ("politest", "politest"),
+    ("solitudes", "solitud"),
+    ("cutlass", "cutlass"),
+    ("hollies", "holli"),
+    ("spaniards", "spaniard"),
+    ("embracing", "embrac"),
+    ("usual", "usual"),
+    ("historical", "histor"),
+    ("cereal", "cereal"),
+    ("wrongdoing", "wrongdo"),
+    ("flit", "flit"),
+    ("steeds", "steed"),
+    ("lowest", "lowest"),
+    ("bestest", "bestest"),
+    ("likenesses", "like"),
+    ("oppress", "oppress"),
+    ("resolving", "resolv"),
+    ("livest", "livest"),
+    ("flinder", "flinder"),
+    ("unfort", "unfort"),
+    ("horsewhip", "horsewhip"),
+    ("acrid", "acrid"),
+    ("outrages", "outrag"),
+    ("sacrilege", "sacrileg"),
+    ("interference", "interfer"),
+    ("wonderingly", "wonderingli"),
+    ("spontaneous", "spontan"),
+    ("volubility", "volubl"),
+    ("dazzled", "dazzl"),
+    ("master", "master"),
+    ("jump", "jump"),
+    ("prevents", "prevent"),
+    ("mewlinnwillinwodd", "mewlinnwillinwodd"),
+    ("tags", "tag"),
+    ("stratum", "stratum"),
+    ("magnates", "magnat"),
+    ("stipendiaries", "stipendiari"),
+    ("aching", "ach"),
+    ("submit", "submit"),
+    ("brush", "brush"),
+    ("relays", "relai"),
+    ("marchantiae", "marchantia"),
+    ("we", "we"),
+    ("wititterly", "wititterli"),
+    ("taciturnity", "taciturn"),
+    ("pique", "piqu"),
+    ("yarn", "yarn"),
+    ("lordlings", "lordl"),
+    ("banter", "banter"),
+    ("insolence", "insol"),
+    ("serenely", "seren"),
+    ("admonishing", "admonish"),
+    ("sacrament", "sacrament"),
+    ("pored", "pore"),
+    ("greets", "greet"),
+    ("consecrating", "consecr"),
+    ("buns", "bun"),
+    ("swooped", "swoop"),
+    ("apprenticeship", "apprenticeship"),
+    ("barren", "barren"),
+    ("hearse", "hears"),
+    ("omnium", "omnium"),
+    ("fondle", "fondl"),
+    ("white", "white"),
+    ("impeachment", "impeach"),
+    ("servants", "servant"),
+    ("inform", "inform"),
+    ("unencumbered", "unencumb"),
+    ("areas", "area"),
+    ("narrative", "narr"),
+    ("tittered", "titter"),
+    ("hopefulness", "hope"),
+    ("rousseau", "rousseau"),
+    ("yokes", "yok"),
+    ("yellow", "yellow"),
+    ("entrust", "entrust"),
+    ("association", "associaz"),
+    ("majestic", "majest"),
+    ("coherence", "coher"),
+    ("dadass", "dadass"),
+    ("attic", "attic"),
+    ("bachelor", "bachelor"),
+    ("anterior", "anterior"),
+    ("convent", "convent"),
+    ("taciturn", "taciturn"),
+    ("stylifer", "stylif"),
+    ("acids", "acid"),
+    ("though", "though"),
+    ("quizzings", "quizz"),
+    ("earring", "ear"),
+    ("huachos", "huacho"),
+    ("ironed", "iron"),
+    ("chronic", "chronic"),
+    ("perish", "perish"),
+    ("dodge", "dodg"),
+    ("aught", "aught"),
+    ("sincerest", "sincerest"),
+    ("r", "r"),
+    ("accrue", "accru"),
+    ("grammars", "grammar"),
+    ("effective", "effect"),
+    ("seco", "seco"),
+    ("nikolaevsky", "nikolaevski"),
+    ("flowering", "flower"),
+    ("warehousemen", "warehousemen"),
+    ("insuring", "insur"),
+    ("bowling", "bowl"),
+    ("residue", "residu"),
+    ("destiny", "destini"),
+    ("tyrolese", "tyroles"),
+    ("why", "whi"),
+    ("pterophorus", "pterophoru"),
+    ("wrappings", "wrap"),
+    ("petticoat", "petticoat"),
+    ("contributions", "contribut"),
+    ("mortality", "mortal"),
+    ("leeuwin", "leeuwin"),
+    ("arithmetical", "arithmet"),
+    ("fainting", "faint"),
+    ("abodes", "abod"),
+    ("tak", "tak"),
+    ("hend", "hend"),
+    ("brutes", "brute"),
+    ("notoriety", "notorieti"),
+    ("entombed", "entomb"),
+    ("foetid", "foetid"),
+    ("travelled", "travel"),
+    ("valise", "valis"),
+    ("chest", "chest"),
+    ("dicks", "dick"),
+    ("phosphorescence", "phosphoresc"),
+    ("nobleness", "nobl"),
+    ("sparklers", "sparkler"),
+    ("mustn", "mustn"),
+    ("broadsides", "broadsid"),
+    ("revolting", "revolt"),
+    ("ibis", "ibi"),
+    ("curlew", "curlew"),
+    ("scarf", "scarf"),
+    ("deyvle", "deyvl"),
+    ("gymnastic", "gymnast"),
+    ("extortions", "extort"),
+    ("upholding", "uphold"),
+    ("methoozellers", "methoozel"),
+    ("disarm", "disarm"),
+    ("play", "plai"),
+    ("venerable", "vener"),
+    ("prostrate", "prostrat"),
+    ("steel", "steel"),
+    ("forster", "forster"),
+    ("parental", "parent"),
+    ("assort", "assort"),
+    ("alternating", "altern"),
+    ("dressmaking", "dressmak"),
+    ("doses", "dose"),
+    ("commits", "commit"),
+    ("pilger", "pilger"),
+    ("persevered", "persev"),
+    ("greens", "green"),
+    ("ministrations", "ministraz"),
+    ("curious", "curiou"),
+    ("stealing", "steal"),
+    ("unwhitewashed", "unwhitewash"),
+    ("acres", "acr"),
+    ("healed", "heal"),
+    ("birgos", "birgo"),
+    ("inspect", "inspect"),
+    ("curtness", "curt"),
+    ("predicted", "predict"),
+    ("edwin", "edwin"),
+    ("russell", "russel"),
+    ("preachers", "preacher"),
+    ("introduced", "introduc"),
+    ("worst", "worst"),
+    ("duenna", "duenna"),
+    ("snowstorms", "snowstorm"),
+    ("meritorious", "meritori"),
+    ("lawgiver", "lawgiv"),
+    ("reviving", "reviv"),
+    ("odours", "odour"),
+    ("examples", "exampl"),
+    ("transporting", "transport"),
+    ("gaunt", "gaunt"),
+    ("basalt", "basalt"),
+    ("dining", "dine"),
+    ("wiping", "wipe"),
+    ("wilt", "wilt"),
+    ("agglomeration", "agglomeraz"),
+    ("legislator", "legisl"),
+    ("unstratified", "unstratifi"),
+    ("ambox", "ambox"),
+    ("drain", "drain"),
+    ("habitually", "habitu"),
+    ("eyebrows", "eyebrow"),
+    ("penalties", "penalti"),
+    ("explanations", "explanaz"),
+    ("covering", "cover"),
+    ("hoodwinked", "hoodwink"),
+    ("ornaments", "ornament"),
+    ("attorney", "attornei"),
+    ("jeremy", "jeremi"),
+    ("first", "first"),
+    ("beadle", "beadl"),
+    ("suitability", "suitabl"),
+    ("lit", "lit"),
+    ("quixotic", "quixot"),
+    ("obeyed", "obei"),
+    ("endow", "endow"),
+    ("turban", "turban"),
+    ("prae", "prae"),
+    ("achievement", "achiev"),
+    ("bearded", "beard"),
+    ("punctiliousness", "punctili"),
+    ("deflecting", "deflect"),
+    ("patch", "patch"),
+    ("eau", "eau"),
+    ("appropriation", "appropriaz"),
+    ("matlock", "matlock"),
+    ("pounded", "pound"),
+    ("guilt", "guilt"),
+    ("conwulsions", "conwuls"),
+    ("economize", "econom"),
+    ("unchangeable", "unchang"),
+    ("ginger", "ginger"),
+    ("cabinet", "cabinet"),
+    ("aggravate", "aggrav"),
+    ("afferdavid", "afferdavid"),
+    ("flutterers", "flutter"),
+    ("keenest", "keenest"),
+    ("monday", "mondai"),
+    ("ripped", "rip"),
+    ("chafing", "chafe"),
+    ("drab", "drab"),
+    ("climb", "climb"),
+    ("conflict", "conflict"),
+    ("ensue", "ensu"),
+    ("latthers", "latther"),
+    ("proscenium", "proscenium"),
+    ("spouse", "spous"),
+    ("character", "charact"),
+    ("contrasted", "contrast"),
+    ("whipping", "whip"),
+    ("tail", "tail"),
+    ("unchild", "unchild"),
+    ("lethargy", "lethargi"),
+    ("brutal", "brutal"),
+    ("hitting", "hit"),
+    ("poets", "poet"),
+    ("fur", "fur"),
+    ("purechurch", "purechurch"),
+    ("rouged", "roug"),
+    ("surry", "surri"),
+    ("pecuniarily", "pecuniarili"),
+    ("bits", "bit"),
+    ("contributing", "contribut"),
+    ("news", "new"),
+    ("freshly", "freshli"),
+    ("pill", "pill"),
+    ("invention", "invent"),
+    ("cottages", "cottag"),
+    ("origin", "origin"),
+    ("remains", "remain"),
+    ("eart", "eart"),
+    ("insensible", "insens"),
+    ("predecessors", "predecessor"),
+    ("cone", "cone"),
+    ("unforeseen", "unforeseen"),
+    ("hamlets", "hamlet"),
+    ("limiting", "limit"),
+    ("crowd", "crowd"),
+    ("burton", "burton"),
+    ("relinquish", "relinquish"),
+    ("lemonade", "lemonad"),
+    ("suffolk", "suffolk"),
+    ("failings", "fail"),
+    ("sane", "sane"),
+    ("brooses", "broos"),
+    ("doggies", "doggi"),
+    ("strikingly", "strikingli"),
+    ("surveyed", "survei"),
+    ("bewailed", "bewail"),
+    ("appeared", "appear"),
+    ("calves", "calv"),
+    ("stud", "stud"),
+    ("demdest", "demdest"),
+    ("olympus", "olympu"),
+    ("audacious", "audaci"),
+    ("analogy", "analogi"),
+    ("sincerity", "sincer"),
+    ("pious", "piou"),
+    ("scribbled", "scribbl"),
+    ("fostered", "foster"),
+    ("disposed", "dispos"),
+    ("naked", "nake"),
+    ("singularity", "singular"),
+    ("quarter", "quarter"),
+    ("soliloquized", "soliloqu"),
+    ("downfallings", "downfal"),
+    ("nurture", "nurtur"),
+    ("inculcates", "inculc"),
+    ("boundary", "boundari"),
+    ("foolery", "fooleri"),
+    ("hm", "hm"),
+    ("uncomprehended", "uncomprehend"),
+    ("bowing", "bow"),
+    ("wafted", "waft"),
+    ("research", "research"),
+    ("goes", "goe"),
+    ("mushrooms", "mushroom"),
+    ("laylec", "laylec"),
+    ("buckskins", "buckskin"),
+    ("sidings", "side"),
+    ("scooping", "scoop"),
+    ("personally", "person"),
+    ("desolately", "desol"),
+    ("soured", "sour"),
+    ("monotonous", "monoton"),
+    ("cells", "cell"),
+    ("rookh", "rookh"),
+    ("claimant", "claimant"),
+    ("undaunted", "undaunt"),
+    ("gude", "gude"),
+    ("via", "via"),
+    ("departed", "depart"),
+    ("worming", "worm"),
+    ("currant", "currant"),
+    ("gallegos", "gallego"),
+    ("slaps", "slap"),
+    ("successors", "successor"),
+    ("yea", "yea"),
+    ("unfolded", "unfold"),
+    ("canter", "canter"),
+    ("be", "be"),
+    ("refugees", "refuge"),
+    ("spouting", "spout"),
+    ("yammerschoonering", "yammerschoon"),
+    ("loiterer", "loiter"),
+    ("inherit", "inherit"),
+    ("chiltern", "chiltern"),
+    ("prevailed", "prevail"),
+    ("inspection", "inspect"),
+    ("resist", "resist"),
+    ("jocose", "jocos"),
+    ("cleans", "clean"),
+    ("escarpments", "escarp"),
+    ("rejoined", "rejoin"),
+    ("womanly", "womanli"),
+    ("piano", "piano"),
+    ("diversified", "diversifi"),
+    ("dykes", "dyke"),
+    ("handmaiden", "handmaiden"),
+    ("mathematical", "mathemat"),
+    ("sermonizing", "sermon"),
+    ("corroborative", "corrobor"),
+    ("sisters", "sister"),
+    ("characterizing", "character"),
+    ("gloom", "gloom"),
+    ("personification", "personificaz"),
+    ("refulgent", "refulg"),
+    ("advised", "advis"),
+    ("entertain", "entertain"),
+    ("mercifully", "mercifulli"),
+    ("injuries", "injuri"),
+    ("principal", "princip"),
+    ("zenaida", "zenaida"),
+    ("loathed", "loath"),
+    ("cemented", "cement"),
+    ("exaggerates", "exagger"),
+    ("confidently", "confid"),
+    ("maintained", "maintain"),
+    ("canopy", "canopi"),
+    ("stretching", "stretch"),
+    ("dressed", "dress"),
+    ("come", "come"),
+    ("nauseous", "nauseou"),
+    ("cursitor", "cursitor"),
+    ("themselves", "themselv"),
+    ("singling", "singl"),
+    ("imprudently", "imprud"),
+    ("injections", "inject"),
+    ("newcomers", "newcom"),
+    ("oppose", "oppos"),
+    ("thickening", "thicken"),
+    ("mastodon", "mastodon"),
+    ("denounced", "denounc"),
+    ("comrade", "comrad"),
+    ("spoil", "spoil"),
+    ("ithacaia", "ithacaia"),
+    ("commercial", "commerci"),
+    ("collnett", "collnett"),
+    ("uplifting", "uplift"),
+    ("prolixity", "prolix"),
+    ("cordiality", "cordial"),
+    ("salute", "salut"),
+    ("grogzwig", "grogzwig"),
+    ("assembles", "assembl"),
+    ("turbans", "turban"),
+    ("madhouse", "madhous"),
+    ("jostled", "jostl"),
+    ("extortion", "extort"),
+    ("payment", "payment"),
+    ("revolutions", "revolut"),
+    ("narrowly", "narrowli"),
+    ("thump", "thump"),
+    ("dumb", "dumb"),
+    ("rusting", "rust"),
+    ("coping", "cope"),
+    ("manifesting", "manifest"),
+    ("roughs", "rough"),
+    ("notice", "notic"),
+    ("viciously", "vicious"),
+    ("applauding", "applaud"),
+    ("shone", "shone"),
+    ("mica", "mica"),
+    ("goodnaturedly", "goodnaturedli"),
+    ("unwary", "unwari"),
+    ("mesalliance", "mesalli"),
+    ("farmed", "farm"),
+    ("inasmuch", "inasmuch"),
+    ("boils", "boil"),
+    ("exceptional", "except"),
+    ("guascos", "guasco"),
+    ("nothura", "nothura"),
+    ("deliverer", "deliver"),
+    ("outspoken", "outspoken"),
+    ("sanity", "saniti"),
+    ("safes", "safe"),
+    ("blossom", "blossom"),
+    ("ceasing", "ceas"),
+    ("rattle", "rattl"),
+    ("fatima", "fatima"),
+    ("mentioned", "mention"),
+    ("grounded", "ground"),
+    ("december", "decemb"),
+    ("commodious", "commodi"),
+    ("crumb", "crumb"),
+    ("wronged", "wrong"),
+    ("survive", "surviv"),
+    ("basest", "basest"),
+    ("commemoration", "commemoraz"),
+    ("drawn", "drawn"),
+    ("coloured", "colour"),
+    ("omniscient", "omnisci"),
+    ("accumulations", "accumulaz"),
+    ("magistrates", "magistr"),
+    ("lalegraicavalca", "lalegraicavalca"),
+    ("authors", "author"),
+    ("scrubs", "scrub"),
+    ("seminal", "semin"),
+    ("retrieved", "retriev"),
+    ("confess", "confess"),
+    ("garters", "garter"),
+    ("climbed", "climb"),
+    ("facetiousness", "faceti"),
+    ("tsar", "tsar"),
+    ("sloppy", "sloppi"),
+    ("rebelled", "rebel"),
+    ("coleman", "coleman"),
+    ("christened", "christen"),
+    ("maker", "maker"),
+    ("watchfulness", "watch"),
+    ("decently", "decent"),
+    ("coarsely", "coars"),
+    ("matronly", "matronli"),
+    ("malty", "malti"),
+    ("phenomenon", "phenomenon"),
+    ("commanding", "command"),
+    ("circumjacent", "circumjac"),
+    ("narrower", "narrow"),
+    ("village", "villag"),
+    ("ootside", "ootsid"),
+    ("desperately", "desper"),
+    ("fabricius", "fabriciu"),
+    ("saddle", "saddl"),
+    ("dodged", "dodg"),
+    ("hairs", "hair"),
+    ("felipe", "felip"),
+    ("burning", "burn"),
+    ("deploring", "deplor"),
+    ("exhale", "exhal"),
+    ("forgetfulness", "forget"),
+    ("motives", "motiv"),
+    ("vigorously", "vigor"),
+    ("arter", "arter"),
+    ("catamaran", "catamaran"),
+    ("telegraph", "telegraph"),
+    ("hoste", "host"),
+    ("faintings", "faint"),
+    ("startings", "start"),
+    ("amen", "amen"),
+    ("grandchild", "grandchild"),
+    ("trooped", "troop"),
+    ("misfortune", "misfortun"),
+    ("ponderous", "ponder"),
+    ("accoutrements", "accoutr"),
+    ("motioned", "motion"),
+    ("jewel", "jewel"),
+    ("enjoys", "enjoi"),
+    ("sealers", "sealer"),
+    ("menace", "menac"),
+    ("laughed", "laugh"),
+    ("multimaculatus", "multimaculatu"),
+    ("biscuits", "biscuit"),
+    ("skin", "skin"),
+    ("abusing", "abus"),
+    ("dobrizhoffen", "dobrizhoffen"),
+    ("catchers", "catcher"),
+    ("personified", "personifi"),
+    ("caspian", "caspian"),
+    ("foiled", "foil"),
+    ("rookery", "rookeri"),
+    ("herschel", "herschel"),
+    ("fares", "fare"),
+    ("gradually", "gradual"),
+    ("cantrip", "cantrip"),
+    ("voyagers", "voyager"),
+    ("extinguishing", "extinguish"),
+    ("transcendent", "transcend"),
+    ("averted", "avert"),
+    ("fair", "fair"),
+    ("clown", "clown"),
+    ("barbare", "barbar"),
+    ("likings", "like"),
+    ("obscure", "obscur"),
+    ("maidish", "maidish"),
+    ("slimy", "slimi"),
+    ("peaked", "peak"),
+    ("impatiently", "impati"),
+    ("bridal", "bridal"),
+    ("torrid", "torrid"),
+    ("inexpedient", "inexpedi"),
+    ("chants", "chant"),
+    ("cheek", "cheek"),
+    ("boa", "boa"),
+    ("pickwick", "pickwick"),
+    ("scapegoat", "scapegoat"),
+    ("promisin", "promisin"),
+    ("mundane", "mundan"),
+    ("bandbox", "bandbox"),
+    ("welcoming", "welcom"),
+    ("contemplates", "contempl"),
+    ("pincers", "pincer"),
+    ("boned", "bone"),
+    ("horsewhipping", "horsewhip"),
+    ("shillings", "shill"),
+    ("vibrations", "vibraz"),
+    ("intestines", "intestin"),
+    ("gazes", "gaze"),
+    ("witchcraft", "witchcraft"),
+    ("going", "go"),
+    ("baize", "baiz"),
+    ("passion", "passion"),
+    ("pound", "pound"),
+    ("mutilates", "mutil"),
+    ("writin", "writin"),
+    ("threads", "thread"),
+    ("widows", "widow"),
+    ("frost", "frost"),
+    ("efflorescence", "effloresc"),
+    ("bubbling", "bubbl"),
+    ("jokes", "joke"),
+    ("swooned", "swoon"),
+    ("gaucho", "gaucho"),
+    ("shout", "shout"),
+    ("records", "record"),
+    ("troubles", "troubl"),
+    ("unspeakable", "unspeak"),
+    ("agreeably", "agreeabl"),
+    ("errands", "errand"),
+    ("mulberry", "mulberri"),
+    ("deceitful", "deceit"),
+    ("saltpeter", "saltpet"),
+    ("elm", "elm"),
+    ("unbecoming", "unbecom"),
+    ("remarks", "remark"),
+    ("allowed", "allow"),
+    ("reluctantly", "reluctantli"),
+    ("nightly", "nightli"),
+    ("emus", "emu"),
+    ("angerless", "angerless"),
+    ("naturalists", "naturalist"),
+    ("hoch", "hoch"),
+    ("recognising", "recognis"),
+    ("simpletons", "simpleton"),
+    ("disarranged", "disarrang"),
+    ("monopoly", "monopoli"),
+    ("vouchsafe", "vouchsaf"),
+    ("unintentionally", "unintention"),
+    ("lothbury", "lothburi"),
+    ("usefulness", "us"),
+    ("breathes", "breath"),
+    ("polyborus", "polyboru"),
+    ("dimmed", "dim"),
+    ("vastness", "vast"),
+    ("brusque", "brusqu"),
+    ("chateau", "chateau"),
+    ("hampdens", "hampden"),
+    ("betwee", "betwe"),
+    ("steadfastness", "steadfast"),
+    ("enthusiastically", "enthusiast"),
+    ("remained", "remain"),
+    ("countree", "countre"),
+    ("minorities", "minor"),
+    ("english", "english"),
+    ("microscope", "microscop"),
+    ("child", "child"),
+    ("maire", "mair"),
+    ("brigs", "brig"),
+    ("relished", "relish"),
+    ("lifted", "lift"),
+    ("independent", "independ"),
+    ("clearer", "clearer"),
+    ("swinishness", "swinish"),
+    ("yeoman", "yeoman"),
+    ("combs", "comb"),
+    ("engines", "engin"),
+    ("louvain", "louvain"),
+    ("dropsy", "dropsi"),
+    ("confide", "confid"),
+    ("arrngd", "arrngd"),
+    ("gestures", "gestur"),
+    ("interposed", "interpos"),
+    ("flourishing", "flourish"),
+    ("unexceptionable", "unexception"),
+    ("impassiveness", "impass"),
+    ("feared", "fear"),
+    ("mapped", "map"),
+    ("slippery", "slipperi"),
+    ("windmills", "windmil"),
+    ("pecker", "pecker"),
+    ("ruffians", "ruffian"),
+    ("vm", "vm"),
+    ("inkling", "inkl"),
+    ("description", "descript"),
+    ("caned", "cane"),
+    ("annal", "annal"),
+    ("wheedled", "wheedl"),
+    ("gainsaying", "gainsai"),
+    ("fazenda", "fazenda"),
+    ("oftener", "often"),
+    ("uncomfortable", "uncomfort"),
+    ("muscular", "muscular"),
+    ("responds", "respond"),
+    ("holloway", "hollowai"),
+    ("wreaths", "wreath"),
+    ("bashfully", "bashfulli"),
+    ("waked", "wake"),
+    ("rules", "rule"),
+    ("steamboat", "steamboat"),
+    ("barnacles", "barnacl"),
+    ("codlings", "codl"),
+    ("inducing", "induc"),
+    ("turreted", "turret"),
+    ("prudent", "prudent"),
+    ("plainness", "plain"),
+    ("uncarried", "uncarri"),
+    ("nobly", "nobli"),
+    ("partisanship", "partisanship"),
+    ("oaths", "oath"),
+    ("expecting", "expect"),
+    ("convents", "convent"),
+    ("allus", "allu"),
+    ("peruvians", "peruvian"),
+    ("hogoleu", "hogoleu"),
+    ("hoardings", "hoard"),
+    ("maddest", "maddest"),
+    ("shrinking", "shrink"),
+    ("animalized", "anim"),
+    ("shrewd", "shrewd"),
+    ("sky", "ski"),
+    ("china", "china"),
+    ("regains", "regain"),
+    ("stolidly", "stolidli"),
+    ("ballenar", "ballenar"),
+    ("details", "detail"),
+    ("silkworm", "silkworm"),
+    ("lacquered", "lacquer"),
+    ("discovered", "discov"),
+    ("eruptive", "erupt"),
+    ("palladium", "palladium"),
+    ("darby", "darbi"),
+    ("farinha", "farinha"),
+    ("rhinoceros", "rhinocero"),
+    ("mortimer", "mortim"),
+    ("bills", "bill"),
+    ("pick", "pick"),
+    ("acquires", "acquir"),
+    ("veals", "veal"),
+    ("beside", "besid"),
+    ("nae", "nae"),
+    ("lassoit", "lassoit"),
+    ("river", "river"),
+    ("to", "to"),
+    ("yinder", "yinder"),
+    ("they", "thei"),
+    ("marvelled", "marvel"),
+    ("keener", "keener"),
+    ("stertorously", "stertor"),
+    ("immoderate", "immoder"),
+    ("fewer", "fewer"),
+    ("jeweller", "jewel"),
+    ("botofogo", "botofogo"),
+    ("lamplighter", "lamplight"),
+    ("snores", "snore"),
+    ("banditti", "banditti"),
+    ("displeasing", "displeas"),
+    ("numbered", "number"),
+    ("says", "sai"),
+    ("longs", "long"),
+    ("infants", "infant"),
+    ("contains", "contain"),
+    ("wor", "wor"),
+    ("priority", "prioriti"),
+    ("mistrust", "mistrust"),
+    ("comical", "comic"),
+    ("raspberries", "raspberri"),
+    ("believeth", "believeth"),
+    ("drowsy", "drowsi"),
+    ("skilled", "skill"),
+    ("thorough", "thorough"),
+    ("neckett", "neckett"),
+    ("royal", "royal"),
+    ("tamely", "tame"),
+    ("parley", "parlei"),
+    ("devoid", "devoid"),
+    ("looks", "look"),
+    ("inflicted", "inflict"),
+    ("interruptions", "interrupt"),
+    ("diatribes", "diatrib"),
+    ("sapient", "sapient"),
+    ("cowslip", "cowslip"),
+    ("bakers", "baker"),
+    ("writhe", "writh"),
+    ("artificial", "artifici"),
+    ("wishermaydie", "wishermaydi"),
+    ("diurnal", "diurnal"),
+    ("barbs", "barb"),
+    ("cows", "cow"),
+    ("horner", "horner"),
+    ("politically", "polit"),
+    ("deliverance", "deliver"),
+    ("proved", "prove"),
+    ("gaudiest", "gaudiest"),
+    ("sailed", "sail"),
+    ("eyeing", "eye"),
+    ("noose", "noos"),
+    ("carnegie", "carnegi"),
+    ("samson", "samson"),
+    ("glavormelly", "glavormelli"),
+    ("hugh", "hugh"),
+    ("flatter", "flatter"),
+    ("roused", "rous"),
+    ("reclined", "reclin"),
+    ("somebody", "somebodi"),
+    ("tracking", "track"),
+    ("sneaking", "sneak"),
+    ("ignorantly", "ignorantli"),
+    ("hawthorns", "hawthorn"),
+    ("terrace", "terrac"),
+    ("imbedded", "imbed"),
+    ("bloometh", "bloometh"),
+    ("arranging", "arrang"),
+    ("eryngium", "eryngium"),
+    ("experiment", "experi"),
+    ("appease", "appeas"),
+    ("breaches", "breach"),
+    ("lovers", "lover"),
+    ("sudden", "sudden"),
+    ("whiskered", "whisker"),
+    ("beneficent", "benefic"),
+    ("depreciate", "depreci"),
+    ("carrion", "carrion"),
+    ("festoons", "festoon"),
+    ("begrimed", "begrim"),
+    ("ossemens", "ossemen"),
+    ("galvanism", "galvan"),
+    ("excursion", "excurs"),
+    ("batrachian", "batrachian"),
+    ("chiloe", "chilo"),
+    ("zealand", "zealand"),
+    ("fide", "fide"),
+    ("gorda", "gorda"),
+    ("fealty", "fealti"),
+    ("lagostomus", "lagostomu"),
+    ("unmixed", "unmix"),
+    ("legitimate", "legitim"),
+    ("maun", "maun"),
+    ("stiffness", "stiff"),
+    ("envelope", "envelop"),
+    ("fixes", "fix"),
+    ("gratulation", "gratulaz"),
+    ("feeders", "feeder"),
+    ("completing", "complet"),
+    ("incidentally", "incident"),
+    ("helpful", "help"),
+    ("beard", "beard"),
+    ("devoured", "devour"),
+    ("gentlema", "gentlema"),
+    ("ramble", "rambl"),
+    ("march", "march"),
+    ("whitest", "whitest"),
+    ("compunction", "compunct"),
+    ("receiving", "receiv"),
+    ("xx", "xx"),
+    ("darning", "darn"),
+    ("abuts", "abut"),
+    ("scratch", "scratch"),
+    ("apologies", "apologi"),
+    ("scraper", "scraper"),
+    ("structure", "structur"),
+    ("plateau", "plateau"),
+    ("desisted", "desist"),
+    ("necklaces", "necklac"),
+    ("changing", "chang"),
+    ("famous", "famou"),
+    ("restorative", "restor"),
+    ("peasant", "peasant"),
+    ("meddled", "meddl"),
+    ("calomel", "calomel"),
+    ("instigation", "instigaz"),
+    ("transparency", "transpar"),
+    ("surroundings", "surround"),
+    ("merit", "merit"),
+    ("smallness", "smal"),
+    ("fel", "fel"),
+    ("snub", "snub"),
+    ("daark", "daark"),
+    ("whistles", "whistl"),
+    ("invade", "invad"),
+    ("tapir", "tapir"),
+    ("nevew", "nevew"),
+    ("comprehen", "comprehen"),
+    ("decked", "deck"),
+    ("youths", "youth"),
+    ("sabres", "sabr"),
+    ("billed", "bill"),
+    ("made", "made"),
+    ("convertible", "convert"),
+    ("diminish", "diminish"),
+    ("major", "major"),
+    ("cleveland", "cleveland"),
+    ("attrition", "attrit"),
+    ("complicated", "complic"),
+    ("kororadika", "kororadika"),
+    ("flannel", "flannel"),
+    ("gender", "gender"),
+    ("sips", "sip"),
+    ("unrivalled", "unrival"),
+    ("stall", "stall"),
+    ("copeck", "copeck"),
+    ("kiss", "kiss"),
+    ("heartily", "heartili"),
+    ("complication", "complicaz"),
+    ("commuted", "commut"),
+    ("propaganda", "propaganda"),
+    ("bidder", "bidder"),
+    ("bah", "bah"),
+    ("theatricals", "theatric"),
+    ("chances", "chanc"),
+    ("grinning", "grin"),
+    ("sculptures", "sculptur"),
+    ("concentration", "concentraz"),
+    ("sidled", "sidl"),
+    ("erected", "erect"),
+    ("boiling", "boil"),
+    ("scum", "scum"),
+    ("canterbury", "canterburi"),
+    ("resolution", "resolut"),
+    ("perfumes", "perfum"),
+    ("tamed", "tame"),
+    ("deptford", "deptford"),
+    ("shellfuls", "shel"),
+    ("country", "countri"),
+    ("ated", "at"),
+    ("shawl", "shawl"),
+    ("fidget", "fidget"),
+    ("tear", "tear"),
+    ("imperfectly", "imperfectli"),
+    ("rounde", "round"),
+    ("newcastle", "newcastl"),
+    ("procrastination", "procrastinaz"),
+    ("immense", "immens"),
+    ("hearted", "heart"),
+    ("nursery", "nurseri"),
+    ("ledger", "ledger"),
+    ("legislators", "legisl"),
+    ("wiser", "wiser"),
+    ("expects", "expect"),
+    ("rains", "rain"),
+    ("hostesses", "hostess"),
+    ("putting", "put"),
+    ("forerunner", "forerunn"),
+    ("edgeware", "edgewar"),
+    ("worsted", "worst"),
+    ("exterminating", "extermin"),
+    ("ventnor", "ventnor"),
+    ("abstracted", "abstract"),
+    ("sprugeons", "sprugeon"),
+    ("whaler", "whaler"),
+    ("aversions", "avers"),
+    ("essentially", "essenti"),
+    ("pollewt", "pollewt"),
+    ("garb", "garb"),
+    ("beggared", "beggar"),
+    ("eyelids", "eyelid"),
+    ("slavishly", "slavishli"),
+    ("pricked", "prick"),
+    ("merveille", "merveill"),
+    ("tylerish", "tylerish"),
+    ("didst", "didst"),
+    ("constitutes", "constitut"),
+    ("study", "studi"),
+    ("maps", "map"),
+    ("flirt", "flirt"),
+    ("guides", "guid"),
+    ("spring", "spring"),
+    ("valuation", "valuaz"),
+    ("sympathizers", "sympath"),
+    ("perform", "perform"),
+    ("liquid", "liquid"),
+    ("settlers", "settler"),
+    ("deposed", "depos"),
+    ("multiplier", "multipli"),
+    ("incalculably", "incalcul"),
+    ("gurgling", "gurgl"),
+    ("deputy", "deputi"),
+    ("antony", "antoni"),
+    ("disfigurements", "disfigur"),
+    ("pensiveness", "pensiv"),
+    ("overawe", "overaw"),
+    ("alexandrovna", "alexandrovna"),
+    ("deprived", "depriv"),
+    ("aspire", "aspir"),
+    ("geraldine", "geraldin"),
+    ("smoothingly", "smoothingli"),
+    ("aboard", "aboard"),
+    ("scolds", "scold"),
+    ("aforementioned", "aforement"),
+    ("punchinello", "punchinello"),
+    ("rigidity", "rigid"),
+    ("cocoa", "cocoa"),
+    ("analysing", "analys"),
+    ("conscientious", "conscienti"),
+    ("meditative", "medit"),
+    ("services", "servic"),
+    ("reached", "reach"),
+    ("certificates", "certif"),
+    ("benedict", "benedict"),
+    ("piebalds", "piebald"),
+    ("dragoons", "dragoon"),
+    ("heaviest", "heaviest"),
+    ("hignominiousness", "hignomini"),
+    ("utilize", "util"),
+    ("lav", "lav"),
+    ("productions", "product"),
+    ("pared", "pare"),
+    ("startle", "startl"),
+    ("refreshments", "refresh"),
+    ("pints", "pint"),
+    ("churches", "church"),
+    ("beach", "beach"),
+    ("saddled", "saddl"),
+    ("moneys", "monei"),
+    ("lincoln", "lincoln"),
+    ("gaimard", "gaimard"),
+    ("wonderful", "wonder"),
+    ("resistance", "resist"),
+    ("helping", "help"),
+    ("terrors", "terror"),
+    ("renounced", "renounc"),
+    ("sprawls", "sprawl"),
+    ("ingenio", "ingenio"),
+    ("hah", "hah"),
+    ("flattery", "flatteri"),
+    ("miniature", "miniatur"),
+    ("disband", "disband"),
+    ("alehouse", "alehous"),
+    ("spar", "spar"),
+    ("hell", "hell"),
+    ("deprecation", "deprecaz"),
+    ("cutaneous", "cutan"),
+    ("selection", "select"),
+    ("rakishly", "rakishli"),
+    ("incumbrance", "incumbr"),
+    ("hark", "hark"),
+    ("muscles", "muscl"),
+    ("phonolite", "phonolit"),
+    ("colonel", "colonel"),
+    ("banners", "banner"),
+    ("oarsman", "oarsman"),
+    ("blackened", "blacken"),
+    ("shakes", "shake"),
+    ("appoint", "appoint"),
+    ("civilians", "civilian"),
+    ("citation", "citaz"),
+    ("stuttered", "stutter"),
+    ("duchess", "duchess"),
+    ("straying", "strai"),
+    ("stuffs", "stuff"),
+    ("undermine", "undermin"),
+    ("highland", "highland"),
+    ("stands", "stand"),
+    ("splashed", "splash"),
+    ("baudi", "baudi"),
+    ("intendeds", "intend"),
+    ("drawed", "draw"),
+    ("curtsying", "curtsi"),
+    ("geologize", "geolog"),
+    ("expansive", "expans"),
+    ("tumblers", "tumbler"),
+    ("solomon", "solomon"),
+    ("goeree", "goere"),
+    ("senhor", "senhor"),
+    ("tinkle", "tinkl"),
+    ("grandmamma", "grandmamma"),
+    ("speckled", "speckl"),
+    ("rancagua", "rancagua"),
+    ("lade", "lade"),
+    ("corrales", "corral"),
+    ("skein", "skein"),
+    ("crowl", "crowl"),
+    ("abut", "abut"),
+    ("vestiges", "vestig"),
+    ("were", "were"),
+    ("mystify", "mystifi"),
+    ("diverging", "diverg"),
+    ("relinquishment", "relinquish"),
+    ("stature", "statur"),
+    ("pictured", "pictur"),
+    ("authorized", "author"),
+    ("wexed", "wex"),
+    ("clemency", "clemenc"),
+    ("egbert", "egbert"),
+    ("pushes", "push"),
+    ("chintz", "chintz"),
+    ("embarrass", "embarrass"),
+    ("superfluity", "superflu"),
+    ("fires", "fire"),
+    ("obstinately", "obstin"),
+    ("weaknesses", "weak"),
+    ("napoleons", "napoleon"),
+    ("steams", "steam"),
+    ("inappropriate", "inappropri"),
+    ("nest", "nest"),
+    ("fiendish", "fiendish"),
+    ("groom", "groom"),
+    ("tartary", "tartari"),
+    ("overflowing", "overflow"),
+    ("contemptuously", "contemptu"),
+    ("psammophis", "psammophi"),
+    ("curdle", "curdl"),
+    ("conducted", "conduct"),
+    ("howling", "howl"),
+    ("damping", "damp"),
+    ("scruples", "scrupl"),
+    ("situation", "situaz"),
+    ("forefathers", "forefath"),
+    ("aeronaut", "aeronaut"),
+    ("manifest", "manifest"),
+    ("bids", "bid"),
+    ("oftentimes", "oftentim"),
+    ("costliest", "costliest"),
+    ("effected", "effect"),
+    ("fascinating", "fascin"),
+    ("larger", "larger"),
+    ("unveiling", "unveil"),
+    ("continuous", "continu"),
+    ("woven", "woven"),
+    ("surely", "sure"),
+    ("disgorging", "disgorg"),
+    ("velvets", "velvet"),
+    ("sufficiently", "suffici"),
+    ("cathedrals", "cathedr"),
+    ("bolivian", "bolivian"),
+    ("nape", "nape"),
+    ("shaped", "shape"),
+    ("mineralogical", "mineralog"),
+    ("hubbub", "hubbub"),
+    ("windmill", "windmil"),
+    ("circulation", "circulaz"),
+    ("lily", "lili"),
+    ("cinder", "cinder"),
+    ("maid", "maid"),
+    ("acknowledged", "acknowledg"),
+    ("pembroke", "pembrok"),
+    ("lajdak", "lajdak"),
+    ("benevolence", "benevol"),
+    ("behavior", "behavior"),
+    ("sarcastic", "sarcast"),
+    ("tarn", "tarn"),
+    ("predictions", "predict"),
+    ("opening", "open"),
+    ("swear", "swear"),
+    ("lineaments", "lineament"),
+    ("chopping", "chop"),
+    ("effusions", "effus"),
+    ("amend", "amend"),
+    ("dissected", "dissect"),
+    ("opulent", "opul"),
+    ("restless", "restless"),
+    ("enclosed", "enclos"),
+    ("cause", "caus"),
+    ("strangest", "strangest"),
+    ("mediate", "mediat"),
+    ("unrestrained", "unrestrain"),
+    ("lopezes", "lopez"),
+    ("porterage", "porterag"),
+    ("objectless", "objectless"),
+    ("exhibit", "exhibit"),
+    ("noabody", "noabodi"),
+    ("gentlemanlike", "gentlemanlik"),
+    ("seating", "seat"),
+    ("discreditable", "discredit"),
+    ("avenging", "aveng"),
+    ("xxviii", "xxviii"),
+    ("skipper", "skipper"),
+    ("headstrong", "headstrong"),
+    ("story", "stori"),
+    ("lmmediately", "lmmediat"),
+    ("incessantly", "incessantli"),
+    ("rods", "rod"),
+    ("monocotyledonous", "monocotyledon"),
+    ("quailing", "quail"),
+    ("conchalee", "conchale"),
+    ("debarred", "debar"),
+    ("jesuitical", "jesuit"),
+    ("blocked", "block"),
+    ("smear", "smear"),
+    ("roosher", "roosher"),
+    ("suburb", "suburb"),
+    ("gauntlets", "gauntlet"),
+    ("beardless", "beardless"),
+    ("uncontaminated", "uncontamin"),
+    ("apartment", "apart"),
+    ("stewardship", "stewardship"),
+    ("cogitated", "cogit"),
+    ("lynx", "lynx"),
+    ("pincheira", "pincheira"),
+    ("stationer", "station"),
+    ("thief", "thief"),
+    ("willin", "willin"),
+    ("checks", "check"),
+    ("assoiled", "assoil"),
+    ("wield", "wield"),
+    ("wiles", "wile"),
+    ("apart", "apart"),
+    ("witty", "witti"),
+    ("brougham", "brougham"),
+    ("jailer", "jailer"),
+    ("sauvages", "sauvag"),
+    ("heterogeneous", "heterogen"),
+    ("sobbings", "sob"),
+    ("repay", "repai"),
+    ("mayor", "mayor"),
+    ("streaked", "streak"),
+    ("napkin", "napkin"),
+    ("weaning", "wean"),
+    ("bloodshed", "bloodsh"),
+    ("paroxysms", "paroxysm"),
+    ("storks", "stork"),
+    ("puss", "puss"),
+    ("silvered", "silver"),
+    ("poetic", "poetic"),
+    ("turgenev", "turgenev"),
+    ("restores", "restor"),
+    ("attainable", "attain"),
+    ("necessitates", "necessit"),
+    ("tears", "tear"),
+    ("shading", "shade"),
+    ("rashly", "rashli"),
+    ("musty", "musti"),
+    ("conspicuously", "conspicu"),
+    ("eloquently", "eloqu"),
+    ("baronet", "baronet"),
+    ("floored", "floor"),
+    ("gower", "gower"),
+    ("atratus", "atratu"),
+    ("clavipes", "clavip"),
+    ("cozily", "cozili"),
+    ("servile", "servil"),
+    ("repartee", "reparte"),
+    ("distribute", "distribut"),
+    ("mischief", "mischief"),
+    ("horrors", "horror"),
+    ("mockery", "mockeri"),
+    ("noses", "nose"),
+    ("sponsor", "sponsor"),
+    ("productive", "product"),
+    ("parting", "part"),
+    ("can", "can"),
+    ("cheery", "cheeri"),
+    ("bookstall", "bookstal"),
+    ("sorrowfully", "sorrowfulli"),
+    ("involving", "involv"),
+    ("clerkly", "clerkli"),
+    ("impaling", "impal"),
+    ("geometry", "geometri"),
+    ("triumph", "triumph"),
+    ("fidgeting", "fidget"),
+    ("tranquillize", "tranquil"),
+    ("aggravation", "aggravaz"),
+    ("passed", "pass"),
+    ("abolished", "abolish"),
+    ("somehow", "somehow"),
+    ("exaggerating", "exagger"),
+    ("lay", "lai"),
+    ("abominable", "abomin"),
+    ("skeleto", "skeleto"),
+    ("gunnera", "gunnera"),
+    ("rifling", "rifl"),
+    ("scoops", "scoop"),
+    ("celebrate", "celebr"),
+    ("theodolite", "theodolit"),
+    ("achieving", "achiev"),
+    ("postmaster", "postmast"),
+    ("tub", "tub"),
+    ("polish", "polish"),
+    ("ungentlemanly", "ungentlemanli"),
+    ("toughy", "toughi"),
+    ("outshone", "outshon"),
+    ("unsheltered", "unshelt"),
+    ("wallflowers", "wallflow"),
+    ("shrikes", "shrike"),
+    ("strike", "strike"),
+    ("emperor", "emperor"),
+    ("instinct", "instinct"),
+    ("exceptionalness", "exception"),
+    ("models", "model"),
+    ("midday", "middai"),
+    ("carnation", "carnaz"),
+    ("cushioning", "cushion"),
+    ("gush", "gush"),
+    ("alternative", "altern"),
+    ("o", "o"),
+    ("despondency", "despond"),
+    ("wherefore", "wherefor"),
+    ("bluid", "bluid"),
+    ("compass", "compass"),
+    ("callao", "callao"),
+    ("dulwich", "dulwich"),
+    ("tanqui", "tanqui"),
+    ("desk", "desk"),
+    ("pianos", "piano"),
+    ("telegrams", "telegram"),
+    ("weapon", "weapon"),
+    ("unmentionable", "unmention"),
+    ("drips", "drip"),
+    ("scores", "score"),
+    ("ones", "on"),
+    ("executions", "execut"),
+    ("fret", "fret"),
+    ("yours", "your"),
+    ("balking", "balk"),
+    ("blushing", "blush"),
+    ("opetiorhynchi", "opetiorhynchi"),
+    ("bat", "bat"),
+    ("stored", "store"),
+    ("upliftings", "uplift"),
+    ("howell", "howel"),
+    ("uncultivated", "uncultiv"),
+    ("shares", "share"),
+    ("intellectual", "intellectu"),
+    ("dementyev", "dementyev"),
+    ("chafed", "chafe"),
+    ("piquant", "piquant"),
+    ("armed", "arm"),
+    ("proceeds", "proce"),
+    ("le", "le"),
+    ("adn", "adn"),
+    ("zealanders", "zealand"),
+    ("uncrossing", "uncross"),
+    ("jaded", "jade"),
+    ("redecorated", "redecor"),
+    ("dwell", "dwell"),
+    ("hairbrush", "hairbrush"),
+    ("deliquescent", "deliquesc"),
+    ("unfold", "unfold"),
+    ("added", "ad"),
+    ("prettiest", "prettiest"),
+    ("maniacs", "maniac"),
+    ("bulkeley", "bulkelei"),
+    ("droops", "droop"),
+    ("official", "offici"),
+    ("mythische", "mythisch"),
+    ("depravity", "deprav"),
+    ("correndera", "correndera"),
+    ("accord", "accord"),
+    ("discrimination", "discriminaz"),
+    ("kernel", "kernel"),
+    ("denoting", "denot"),
+    ("underneath", "underneath"),
+    ("enders", "ender"),
+    ("pigsties", "pigsti"),
+    ("grazed", "graze"),
+    ("unopened", "unopen"),
+    ("bolt", "bolt"),
+    ("lettres", "lettr"),
+    ("rhine", "rhine"),
+    ("tumultuously", "tumultu"),
+    ("days", "dai"),
+    ("condensed", "condens"),
+    ("sphere", "sphere"),
+    ("our", "our"),
+    ("riotous", "riotou"),
+    ("crediting", "credit"),
+    ("entitling", "entitl"),
+    ("giddily", "giddili"),
+    ("pierres", "pierr"),
+    ("officier", "offici"),
+    ("concealment", "conceal"),
+    ("types", "type"),
+    ("despite", "despit"),
+    ("moonshine", "moonshin"),
+    ("godsend", "godsend"),
+    ("unsaddled", "unsaddl"),
+    ("worse", "wors"),
+    ("fostering", "foster"),
+    ("tacna", "tacna"),
+    ("blasphemy", "blasphemi"),
+    ("rounded", "round"),
+    ("yet", "yet"),
+    ("pickled", "pickl"),
+    ("bile", "bile"),
+    ("luxuriate", "luxuri"),
+    ("copiapo", "copiapo"),
+    ("goa", "goa"),
+    ("abiding", "abid"),
+    ("hurting", "hurt"),
+    ("arson", "arson"),
+    ("lozenge", "lozeng"),
+    ("hereford", "hereford"),
+    ("greenland", "greenland"),
+    ("secular", "secular"),
+    ("scagliola", "scagliola"),
+    ("stewart", "stewart"),
+    ("constitutional", "constitut"),
+    ("ring", "ring"),
+    ("shaken", "shaken"),
+    ("funniest", "funniest"),
+    ("cedars", "cedar"),
+    ("bid", "bid"),
+    ("wider", "wider"),
+    ("investigations", "investigaz"),
+    ("vassilitch", "vassilitch"),
+    ("reall", "reall"),
+    ("amicable", "amic"),
+    ("cementing", "cement"),
+    ("cuentas", "cuenta"),
+    ("cousinship", "cousinship"),
+    ("bu", "bu"),
+    ("magnify", "magnifi"),
+    ("timed", "time"),
+    ("morals", "moral"),
+    ("bacchus", "bacchu"),
+    ("trifles", "trifl"),
+    ("sidelong", "sidelong"),
+    ("nought", "nought"),
+    ("jewellers", "jewel"),
+    ("wearying", "weari"),
+    ("decorations", "decoraz"),
+    ("deserted", "desert"),
+    ("wither", "wither"),
+    ("crusts", "crust"),
+    ("droves", "drove"),
+    ("commit", "commit"),
+    ("conventions", "convent"),
+    ("signified", "signifi"),
+    ("pension", "pension"),
+    ("anatolia", "anatolia"),
+    ("humaine", "humain"),
+    ("comforters", "comfort"),
+    ("geographer", "geograph"),
+    ("seashells", "seashel"),
+    ("befillaire", "befillair"),
+    ("pantomime", "pantomim"),
+    ("lama", "lama"),
+    ("sportiveness", "sportiv"),
+    ("shelopaev", "shelopaev"),
+    ("incumbent", "incumb"),
+    ("awakens", "awaken"),
+    ("parlay", "parlai"),
+    ("saucer", "saucer"),
+    ("eject", "eject"),
+    ("limache", "limach"),
+    ("discoverer", "discover"),
+    ("ragged", "rag"),
+    ("unintentional", "unintent"),
+    ("impracticable", "impractic"),
+    ("thirtieth", "thirtieth"),
+    ("foun", "foun"),
+    ("realm", "realm"),
+    ("courted", "court"),
+    ("attrapped", "attrap"),
+    ("clouded", "cloud"),
+    ("quoted", "quot"),
+    ("declare", "declar"),
+    ("andle", "andl"),
+    ("crumpets", "crumpet"),
+    ("niggers", "nigger"),
+    ("encased", "encas"),
+    ("brabantio", "brabantio"),
+    ("differ", "differ"),
+    ("fernery", "ferneri"),
+    ("dyer", "dyer"),
+    ("sneaked", "sneak"),
+    ("sneered", "sneer"),
+    ("pretending", "pretend"),
+    ("slaughtered", "slaughter"),
+    ("patellae", "patella"),
+    ("guiltiness", "guilti"),
+    ("subjected", "subject"),
+    ("confidingly", "confidingli"),
+    ("rejected", "reject"),
+    ("arraying", "arrai"),
+    ("partiality", "partial"),
+    ("goesler", "goesler"),
+    ("chilenos", "chileno"),
+    ("housekeepers", "housekeep"),
+    ("watchmen", "watchmen"),
+    ("equilibrium", "equilibrium"),
+    ("negative", "neg"),
+    ("tarred", "tar"),
+    ("someone", "someon"),
+    ("outward", "outward"),
+    ("troublous", "troublou"),
+    ("excellence", "excel"),
+    ("brokken", "brokken"),
+    ("moonlight", "moonlight"),
+    ("literal", "liter"),
+    ("cowshed", "cowsh"),
+    ("exile", "exil"),
+    ("ojos", "ojo"),
+    ("cockatoo", "cockatoo"),
+    ("driving", "drive"),
+    ("amazes", "amaz"),
+    ("perspires", "perspir"),
+    ("across", "across"),
+    ("roomed", "room"),
+    ("familiarities", "familiar"),
+    ("influenced", "influenc"),
+    ("oaf", "oaf"),
+    ("faltering", "falter"),
+    ("fellow", "fellow"),
+    ("purity", "puriti"),
+    ("repose", "repos"),
+    ("commend", "commend"),
+    ("straitened", "straiten"),
+    ("excitedly", "excitedli"),
+    ("niente", "nient"),
+    ("chevaux", "chevaux"),
+    ("purchased", "purchas"),
+    ("greece", "greec"),
+    ("baronial", "baroni"),
+    ("enumerated", "enumer"),
+    ("stones", "stone"),
+    ("stockings", "stock"),
+    ("belated", "belat"),
+    ("accoutred", "accoutr"),
+    ("hilda", "hilda"),
+    ("ineptitude", "ineptitud"),
+    ("yeso", "yeso"),
+    ("frankly", "frankli"),
+    ("jackals", "jackal"),
+    ("blackstone", "blackston"),
+    ("cruelty", "cruelti"),
+    ("strongly", "strongli"),
+    ("prolonging", "prolong"),
+    ("electing", "elect"),
+    ("gratefully", "gratefulli"),
+    ("correctly", "correctli"),
+    ("starwation", "starwaz"),
+    ("harder", "harder"),
+    ("turnstile", "turnstil"),
+    ("cricket", "cricket"),
+    ("careworn", "careworn"),
+    ("polysyllable", "polysyl"),
+    ("consultation", "consultaz"),
+    ("chewed", "chew"),
+    ("pendant", "pendant"),
+    ("poorness", "poor"),
+    ("averring", "aver"),
+    ("comments", "comment"),
+    ("maitre", "maitr"),
+    ("independence", "independ"),
+    ("adverting", "advert"),
+    ("pray", "prai"),
+    ("thong", "thong"),
+    ("severally", "sever"),
+    ("scallops", "scallop"),
+    ("hever", "hever"),
+    ("morsel", "morsel"),
+    ("ods", "od"),
+    ("willingness", "willing"),
+    ("stoned", "stone"),
+    ("caldrons", "caldron"),
+    ("detested", "detest"),
+    ("schoolmeasther", "schoolmeasth"),
+    ("persecution", "persecut"),
+    ("provider", "provid"),
+    ("abolition", "abolit"),
+    ("retreats", "retreat"),
+    ("conglomeration", "conglomeraz"),
+    ("codger", "codger"),
+    ("ac", "ac"),
+    ("amazing", "amaz"),
+    ("cudgelled", "cudgel"),
+    ("touch", "touch"),
+    ("meteor", "meteor"),
+    ("philanthropical", "philanthrop"),
+    ("tinting", "tint"),
+    ("admitting", "admit"),
+    ("cart", "cart"),
+    ("inebriated", "inebri"),
+    ("hedging", "hedg"),
+    ("oaks", "oak"),
+    ("rasher", "rasher"),
+    ("normal", "normal"),
+    ("pigtails", "pigtail"),
+    ("prospered", "prosper"),
+    ("tinker", "tinker"),
+    ("successes", "success"),
+    ("tortures", "tortur"),
+    ("betaken", "betaken"),
+    ("eyeball", "eyebal"),
+    ("cringingly", "cringingli"),
+    ("practically", "practic"),
+    ("skilly", "skilli"),
+    ("wakken", "wakken"),
+    ("hecla", "hecla"),
+    ("corporations", "corporaz"),
+    ("averaging", "averag"),
+    ("psalms", "psalm"),
+    ("larg", "larg"),
+    ("creetur", "creetur"),
+    ("nuss", "nuss"),
+    ("ellesmere", "ellesmer"),
+    ("digestive", "digest"),
+    ("ushering", "usher"),
+    ("encouragingly", "encouragingli"),
+    ("reminiscence", "reminisc"),
+    ("revilings", "revil"),
+    ("tortoise", "tortois"),
+    ("motion", "motion"),
+    ("southfront", "southfront"),
+    ("tial", "tial"),
+    ("ostlers", "ostler"),
+    ("crumpet", "crumpet"),
+    ("quiet", "quiet"),
+    ("jenkins", "jenkin"),
+    ("armour", "armour"),
+    ("kingdoms", "kingdom"),
+    ("slain", "slain"),
+    ("eyesight", "eyesight"),
+    ("spirituous", "spiritu"),
+    ("squabble", "squabbl"),
+    ("dungeon", "dungeon"),
+    ("incautious", "incauti"),
+    ("crosse", "cross"),
+    ("specious", "speciou"),
+    ("townsman", "townsman"),
+    ("soliloquy", "soliloqui"),
+    ("ve", "ve"),
+    ("dragon", "dragon"),
+    ("grew", "grew"),
+    ("shrine", "shrine"),
+    ("cursory", "cursori"),
+    ("untold", "untold"),
+    ("deigned", "deign"),
+    ("calamitous", "calamit"),
+    ("thuds", "thud"),
+    ("arenales", "arenal"),
+    ("feverish", "feverish"),
+    ("punctuation", "punctuaz"),
+    ("knowing", "know"),
+    ("powdered", "powder"),
+    ("marseilles", "marseill"),
+    ("periodicals", "period"),
+    ("juffy", "juffi"),
+    ("fragility", "fragil"),
+    ("prayin", "prayin"),
+    ("aha", "aha"),
+    ("hoarse", "hoars"),
+    ("that", "that"),
+    ("maria", "maria"),
+    ("estimation", "estimaz"),
+    ("critical", "critic"),
+    ("versed", "vers"),
+    ("welsh", "welsh"),
+    ("vagary", "vagari"),
+    ("brimmed", "brim"),
+    ("wanness", "wan"),
+    ("wayside", "waysid"),
+    ("broth", "broth"),
+    ("magic", "magic"),
+    ("conclusions", "conclus"),
+    ("wanderings", "wander"),
+    ("paragraph", "paragraph"),
+    ("cyclopean", "cyclopean"),
+    ("transact", "transact"),
+    ("freer", "freer"),
+    ("mechanic", "mechan"),
+    ("iquique", "iquiqu"),
+    ("crowing", "crow"),
+    ("emanated", "eman"),
+    ("iota", "iota"),
+    ("deems", "deem"),
+    ("graveyard", "graveyard"),
+    ("shuflle", "shufll"),
+    ("impulses", "impuls"),
+    ("foggy", "foggi"),
+    ("wards", "ward"),
+    ("caught", "caught"),
+    ("grave", "grave"),
+    ("combined", "combin"),
+    ("decent", "decent"),
+    ("emu", "emu"),
+    ("abdomen", "abdomen"),
+    ("scope", "scope"),
+    ("grove", "grove"),
+    ("prescribe", "prescrib"),
+    ("shamefully", "shamefulli"),
+    ("presently", "present"),
+    ("ganges", "gang"),
+    ("pies", "pi"),
+    ("respond", "respond"),
+    ("moldavia", "moldavia"),
+    ("tune", "tune"),
+    ("suspicions", "suspicion"),
+    ("buds", "bud"),
+    ("field", "field"),
+    ("ead", "ead"),
+    ("tendering", "tender"),
+    ("tattooed", "tattoo"),
+    ("congratulation", "congratulaz"),
+    ("rejoicings", "rejoic"),
+    ("salvator", "salvat"),
+    ("strokings", "stroke"),
+    ("whinstone", "whinston"),
+    ("misconstruing", "misconstru"),
+    ("jog", "jog"),
+    ("wouldest", "wouldest"),
+    ("watchers", "watcher"),
+    ("hindering", "hinder"),
+    ("whither", "whither"),
+    ("tanks", "tank"),
+    ("stacks", "stack"),
+    ("ballot", "ballot"),
+    ("transplanting", "transplant"),
+    ("attitudes", "attitud"),
+    ("dst", "dst"),
+    ("preening", "preen"),
+    ("littlest", "littlest"),
+    ("depressed", "depress"),
+    ("convex", "convex"),
+    ("sojourners", "sojourn"),
+    ("glib", "glib"),
+    ("fascinated", "fascin"),
+    ("cuero", "cuero"),
+    ("gangs", "gang"),
+    ("grantham", "grantham"),
+    ("bearing", "bear"),
+    ("prompters", "prompter"),
+    ("sept", "sept"),
+    ("sepulchral", "sepulchr"),
+    ("apennines", "apennin"),
+    ("filling", "fill"),
+    ("religious", "religi"),
+    ("pitmen", "pitmen"),
+    ("cynical", "cynic"),
+    ("possessions", "possess"),
+    ("enchanted", "enchant"),
+    ("gervais", "gervai"),
+    ("against", "against"),
+    ("unwonted", "unwont"),
+    ("slyboots", "slyboot"),
+    ("quote", "quot"),
+    ("pressure", "pressur"),
+    ("acrydium", "acrydium"),
+    ("recently", "recent"),
+    ("clearness", "clear"),
+    ("graveclothes", "gravecloth"),
+    ("condescent", "condesc"),
+    ("preach", "preach"),
+    ("surnames", "surnam"),
+    ("puff", "puff"),
+    ("subcostal", "subcost"),
+    ("eddication", "eddicaz"),
+    ("risk", "risk"),
+    ("censorship", "censorship"),
+    ("tolerated", "toler"),
+    ("filthily", "filthili"),
+    ("shallow", "shallow"),
+    ("rich", "rich"),
+    ("wreathes", "wreath"),
+    ("lords", "lord"),
+    ("precocious", "precoci"),
+    ("abandoned", "abandon"),
+    ("europeans", "european"),
+    ("patriot", "patriot"),
+    ("bellowing", "bellow"),
+    ("say", "sai"),
+    ("bouquets", "bouquet"),
+    ("relaxed", "relax"),
+    ("classify", "classifi"),
+    ("reigned", "reign"),
+    ("swab", "swab"),
+    ("belauded", "belaud"),
+    ("maniac", "maniac"),
+    ("supersede", "supersed"),
+    ("dostoevsky", "dostoevski"),
+    ("idolatrous", "idolatr"),
+    ("essays", "essai"),
+    ("evenings", "even"),
+    ("impartially", "imparti"),
+    ("decorate", "decor"),
+    ("seniors", "senior"),
+    ("reach", "reach"),
+    ("hiccup", "hiccup"),
+    ("tulkinghorn", "tulkinghorn"),
+    ("foretaste", "foretast"),
+    ("virtues", "virtu"),
+    ("contemplation", "contemplaz"),
+    ("notification", "notificaz"),
+    ("praiseworthy", "praiseworthi"),
+    ("foresaw", "foresaw"),
+    ("precarious", "precari"),
+    ("woods", "wood"),
+    ("miniatures", "miniatur"),
+    ("bazaar", "bazaar"),
+    ("cigarette", "cigarett"),
+    ("prepares", "prepar"),
+    ("maiden", "maiden"),
+    ("kingfisher", "kingfish"),
+    ("helplessness", "helpless"),
+    ("abundantly", "abundantli"),
+    ("papin", "papin"),
+    ("congregate", "congreg"),
+    ("pulpit", "pulpit"),
+    ("doubly", "doubli"),
+    ("sausage", "sausag"),
+    ("mt", "mt"),
+    ("etiquette", "etiquett"),
+    ("fieldfare", "fieldfar"),
+    ("saturdays", "saturdai"),
+    ("swipes", "swipe"),
+    ("rendus", "rendu"),
+    ("crushing", "crush"),
+    ("gloucester", "gloucest"),
+    ("risks", "risk"),
+    ("fall", "fall"),
+    ("waistcoat", "waistcoat"),
+    ("regulation", "regulaz"),
+    ("wondrous", "wondrou"),
+    ("realizing", "real"),
+    ("swoon", "swoon"),
+    ("successive", "success"),
+    ("rally", "ral"),
+    ("lincolnshire", "lincolnshir"),
+    ("mercedes", "merced"),
+    ("pointless", "pointless"),
+    ("morgenfruh", "morgenfruh"),
+    ("credit", "credit"),
+    ("toying", "toi"),
+    ("surrounded", "surround"),
+    ("hassan", "hassan"),
+    ("bomb", "bomb"),
+    ("moderation", "moderaz"),
+    ("humming", "hum"),
+    ("electioneering", "election"),
+    ("sedentary", "sedentari"),
+    ("refraction", "refract"),
+    ("anger", "anger"),
+    ("vividly", "vividli"),
+    ("squarely", "squar"),
+    ("recommenced", "recommenc"),
+    ("impeach", "impeach"),
+    ("intrust", "intrust"),
+    ("burrowing", "burrow"),
+    ("god", "god"),
+    ("rumbled", "rumbl"),
+    ("banishes", "banish"),
+    ("panza", "panza"),
+    ("deploy", "deploi"),
+    ("kneeled", "kneel"),
+    ("changeable", "changeabl"),
+    ("notched", "notch"),
+    ("deluge", "delug"),
+    ("gettings", "get"),
+    ("sew", "sew"),
+    ("radiantly", "radiantli"),
+    ("gallants", "gallant"),
+    ("althoug", "althoug"),
+    ("engagement", "engag"),
+    ("currents", "current"),
+    ("novelist", "novelist"),
+    ("colloquial", "colloqui"),
+    ("squall", "squall"),
+    ("plumage", "plumag"),
+    ("headgear", "headgear"),
+    ("merchants", "merchant"),
+    ("drawls", "drawl"),
+    ("whereabout", "whereabout"),
+    ("argue", "argu"),
+    ("mademoiselle", "mademoisell"),
+    ("loveliest", "loveliest"),
+    ("aggression", "aggress"),
+    ("claps", "clap"),
+    ("mentioning", "mention"),
+    ("dramatically", "dramat"),
+    ("industrie", "industri"),
+    ("hav", "hav"),
+    ("sloane", "sloan"),
+    ("presided", "presid"),
+    ("strew", "strew"),
+    ("hybrid", "hybrid"),
+    ("returning", "return"),
+    ("mimics", "mimic"),
+    ("undulatory", "undulatori"),
+    ("chandeliers", "chandeli"),
+    ("merged", "merg"),
+    ("distort", "distort"),
+    ("criticise", "criticis"),
+    ("curs", "cur"),
+    ("cutters", "cutter"),
+    ("relish", "relish"),
+    ("compactness", "compact"),
+    ("verona", "verona"),
+    ("gilding", "gild"),
+    ("fresco", "fresco"),
+    ("nosegay", "nosegai"),
+    ("comprising", "compris"),
+    ("bracelets", "bracelet"),
+    ("fog", "fog"),
+    ("bullion", "bullion"),
+    ("fountain", "fountain"),
+    ("concerned", "concern"),
+    ("faced", "face"),
+    ("plurality", "plural"),
+    ("superstition", "superstit"),
+    ("lieut", "lieut"),
+    ("edified", "edifi"),
+    ("irresistibly", "irresistibli"),
+    ("rosewood", "rosewood"),
+    ("rosina", "rosina"),
+    ("press", "press"),
+    ("augmentation", "augmentaz"),
+    ("profile", "profil"),
+    ("confounded", "confound"),
+    ("acquired", "acquir"),
+    ("autour", "autour"),
+    ("artichoke", "artichok"),
+    ("dormouse", "dormous"),
+    ("bugle", "bugl"),
+    ("cottons", "cotton"),
+    ("block", "block"),
+    ("spectre", "spectr"),
+    ("nightingale", "nightingal"),
+    ("encerrado", "encerrado"),
+    ("pommel", "pommel"),
+    ("larking", "lark"),
+    ("neatly", "neatli"),
+    ("hoary", "hoari"),
+    ("starving", "starv"),
+    ("junction", "junction"),
+    ("mountain", "mountain"),
+    ("weaker", "weaker"),
+    ("impolitic", "impolit"),
+    ("gothic", "gothic"),
+    ("orchestra", "orchestra"),
+    ("deserved", "deserv"),
+    ("twinklings", "twinkl"),
+    ("fountains", "fountain"),
+    ("characteristic", "characterist"),
+    ("lifts", "lift"),
+    ("idiotic", "idiot"),
+    ("foam", "foam"),
+    ("downright", "downright"),
+    ("ridden", "ridden"),
+    ("oceanic", "ocean"),
+    ("dispassionately", "dispassion"),
+    ("forfeited", "forfeit"),
+    ("unbearable", "unbear"),
+    ("brittann", "brittann"),
+    ("surmising", "surmis"),
+    ("selves", "selv"),
+    ("favour", "favour"),
+    ("watchfully", "watchfulli"),
+    ("muffin", "muffin"),
+    ("authoritatively", "authorit"),
+    ("journeys", "journei"),
+    ("prisoner", "prison"),
+    ("splash", "splash"),
+    ("neighbours", "neighbour"),
+    ("spell", "spell"),
+    ("inappreciable", "inappreci"),
+    ("marge", "marg"),
+    ("fissurella", "fissurella"),
+    ("immemorial", "immemori"),
+    ("lepidoptera", "lepidoptera"),
+    ("aristocrats", "aristocrat"),
+    ("loaves", "loav"),
+    ("owls", "owl"),
+    ("fumbling", "fumbl"),
+    ("plaintiffs", "plaintiff"),
+    ("regimen", "regimen"),
+    ("jack", "jack"),
+    ("xxx", "xxx"),
+    ("fifteen", "fifteen"),
+    ("devastation", "devastaz"),
+    ("gallinaceous", "gallinac"),
+    ("divine", "divin"),
+    ("tunbridge", "tunbridg"),
+    ("fotheringhams", "fotheringham"),
+    ("camp", "camp"),
+    ("stroking", "stroke"),
+    ("glowworm", "glowworm"),
+    ("publication", "publicaz"),
+    ("bawls", "bawl"),
+    ("noticeably", "notic"),
+    ("pm", "pm"),
+    ("launching", "launch"),
+    ("mammifers", "mammif"),
+    ("clutch", "clutch"),
+    ("waither", "waither"),
+    ("gin", "gin"),
+    ("notaphus", "notaphu"),
+    ("drones", "drone"),
+    ("somewhere", "somewher"),
+    ("unpaved", "unpav"),
+    ("mats", "mat"),
+    ("patronage", "patronag"),
+    ("curve", "curv"),
+    ("jaundice", "jaundic"),
+    ("pre", "pre"),
+    ("rhea", "rhea"),
+    ("stops", "stop"),
+    ("imperturbability", "imperturb"),
+    ("hawk", "hawk"),
+    ("blending", "blend"),
+    ("ellen", "ellen"),
+    ("barriers", "barrier"),
+    ("waltzed", "waltz"),
+    ("doubtingly", "doubtingli"),
+    ("interview", "interview"),
+    ("princesses", "princess"),
+    ("moths", "moth"),
+    ("staring", "stare"),
+    ("powerfully", "powerfulli"),
+    ("hum", "hum"),
+    ("unjustly", "unjustli"),
+    ("authorship", "authorship"),
+    ("privacy", "privaci"),
+    ("urbane", "urban"),
+    ("murderers", "murder"),
+    ("pumps", "pump"),
+    ("webs", "web"),
+    ("proportionally", "proportion"),
+    ("precipitate", "precipit"),
+    ("chunk", "chunk"),
+    ("canvassed", "canvass"),
+    ("detainer", "detain"),
+    ("worships", "worship"),
+    ("contracted", "contract"),
+    ("sinuous", "sinuou"),
+    ("chambre", "chambr"),
+    ("apprehending", "apprehend"),
+    ("dosed", "dose"),
+    ("exercises", "exercis"),
+    ("rotten", "rotten"),
+    ("commanded", "command"),
+    ("pancake", "pancak"),
+    ("avons", "avon"),
+    ("revolver", "revolv"),
+    ("whirled", "whirl"),
+    ("bravest", "bravest"),
+    ("anglican", "anglican"),
+    ("irregularities", "irregular"),
+    ("antagonist", "antagonist"),
+    ("preliminaries", "preliminari"),
+    ("perversity", "pervers"),
+    ("safely", "safe"),
+    ("unabated", "unab"),
+    ("blades", "blade"),
+    ("undeceive", "undec"),
+    ("prayed", "prai"),
+    ("hung", "hung"),
+    ("madmen", "madmen"),
+    ("gratuitously", "gratuit"),
+    ("curing", "cure"),
+    ("covet", "covet"),
+    ("lor", "lor"),
+    ("stony", "stoni"),
+    ("clocks", "clock"),
+    ("highest", "highest"),
+    ("acquisition", "acquisit"),
+    ("bottom", "bottom"),
+    ("worrying", "worri"),
+    ("gulfs", "gulf"),
+    ("spy", "spi"),
+    ("wrested", "wrest"),
+    ("envelopes", "envelop"),
+    ("vileness", "vile"),
+    ("jackas", "jacka"),
+    ("enrage", "enrag"),
+    ("explosions", "explos"),
+    ("draggled", "draggl"),
+    ("shots", "shot"),
+    ("western", "western"),
+    ("ryazan", "ryazan"),
+    ("uninteresting", "uninterest"),
+    ("tremenjous", "tremenj"),
+    ("wonderfully", "wonderfulli"),
+    ("birthdays", "birthdai"),
+    ("confessedly", "confessedli"),
+    ("inactive", "inact"),
+    ("reaching", "reach"),
+    ("puffy", "puffi"),
+    ("layers", "layer"),
+    ("pacifying", "pacifi"),
+    ("harnessed", "har"),
+    ("tambourine", "tambourin"),
+    ("multiplicity", "multipl"),
+    ("oniscia", "oniscia"),
+    ("heyday", "heydai"),
+    ("refute", "refut"),
+    ("com", "com"),
+    ("ballet", "ballet"),
+    ("sources", "sourc"),
+    ("unreal", "unreal"),
+    ("gracing", "grace"),
+    ("chrysopa", "chrysopa"),
+    ("however", "howev"),
+    ("frippery", "fripperi"),
+    ("asphalax", "asphalax"),
+    ("demurely", "demur"),
+    ("judgment", "judgment"),
+    ("unfitness", "unfit"),
+    ("dobrizhoffer", "dobrizhoff"),
+    ("holstein", "holstein"),
+    ("satins", "satin"),
+    ("grecians", "grecian"),
+    ("benignity", "benign"),
+    ("footstool", "footstool"),
+    ("consisting", "consist"),
+    ("unendurable", "unendur"),
+    ("moins", "moin"),
+    ("hedges", "hedg"),
+    ("hunky", "hunki"),
+    ("thinness", "thin"),
+    ("suborned", "suborn"),
+    ("faltered", "falter"),
+    ("pimple", "pimpl"),
+    ("carboy", "carboi"),
+    ("postponement", "postpon"),
+    ("shipping", "ship"),
+    ("ventures", "ventur"),
+    ("joinin", "joinin"),
+    ("funeral", "funer"),
+    ("print", "print"),
+    ("perfumed", "perfum"),
+    ("locked", "lock"),
+    ("obtained", "obtain"),
+    ("defalcation", "defalcaz"),
+    ("singers", "singer"),
+    ("cautioning", "caution"),
+    ("ordering", "order"),
+    ("halfpenny", "halfpenni"),
+    ("wash", "wash"),
+    ("paradise", "paradis"),
+    ("blinking", "blink"),
+    ("comforts", "comfort"),
+    ("impalpably", "impalp"),
+    ("ech", "ech"),
+    ("obleeging", "obleeg"),
+    ("dictation", "dictaz"),
+    ("successfully", "successfulli"),
+    ("synod", "synod"),
+    ("crime", "crime"),
+    ("sneezed", "sneez"),
+    ("frequents", "frequent"),
+    ("slumbering", "slumber"),
+    ("failure", "failur"),
+    ("austere", "auster"),
+    ("contusion", "contus"),
+    ("jar", "jar"),
+    ("indulgences", "indulg"),
+    ("bowls", "bowl"),
+    ("depose", "depos"),
+    ("chamois", "chamoi"),
+    ("tour", "tour"),
+    ("intermingling", "intermingl"),
+    ("thinking", "think"),
+    ("missed", "miss"),
+    ("awakening", "awaken"),
+    ("erosio", "erosio"),
+    ("soiled", "soil"),
+    ("fro", "fro"),
+    ("fortuitous", "fortuit"),
+    ("dieffenbach", "dieffenbach"),
+    ("halves", "halv"),
+    ("cassada", "cassada"),
+    ("everbody", "everbodi"),
+    ("behest", "behest"),
+    ("terrified", "terrifi"),
+    ("money", "monei"),
+    ("sceptics", "sceptic"),
+    ("whereas", "wherea"),
+    ("toll", "toll"),
+    ("paradoxical", "paradox"),
+    ("buff", "buff"),
+    ("tsubmit", "tsubmit"),
+    ("murderous", "murder"),
+    ("tongs", "tong"),
+    ("buildin", "buildin"),
+    ("limestone", "limeston"),
+    ("awake", "awak"),
+    ("woke", "woke"),
+    ("countryman", "countryman"),
+    ("sidle", "sidl"),
+    ("diffused", "diffus"),
+    ("hob", "hob"),
+    ("concentrating", "concentr"),
+    ("nearness", "near"),
+    ("unhewn", "unhewn"),
+    ("idealism", "ideal"),
+    ("nominated", "nomin"),
+    ("coffins", "coffin"),
+    ("unblushingly", "unblushingli"),
+    ("schooled", "school"),
+    ("verbally", "verbal"),
+    ("borrioboola", "borrioboola"),
+    ("planed", "plane"),
+    ("merging", "merg"),
+    ("schoolmaster", "schoolmast"),
+    ("pell", "pell"),
+    ("fibre", "fibr"),
+    ("thrashed", "thrash"),
+    ("nations", "naze"),
+    ("aspirant", "aspir"),
+    ("relation", "relaz"),
+    ("offence", "offenc"),
+    ("upholstered", "upholst"),
+    ("lulled", "lull"),
+    ("dusting", "dust"),
+    ("palaeotherium", "palaeotherium"),
+    ("sorrow", "sorrow"),
+    ("usage", "usag"),
+    ("tambillos", "tambillo"),
+    ("tearless", "tearless"),
+    ("hopefully", "hopefulli"),
+    ("magical", "magic"),
+    ("drawl", "drawl"),
+    ("shoreham", "shoreham"),
+    ("fawning", "fawn"),
+    ("profligates", "proflig"),
+    ("submission", "submiss"),
+    ("spurn", "spurn"),
+    ("diligently", "dilig"),
+    ("fretting", "fret"),
+    ("ferdy", "ferdi"),
+    ("sows", "sow"),
+    ("securing", "secur"),
+    ("herbert", "herbert"),
+    ("coinstantaneously", "coinstantan"),
+    ("git", "git"),
+    ("floats", "float"),
+    ("colours", "colour"),
+    ("disinherit", "disinherit"),
+    ("twofold", "twofold"),
+    ("anew", "anew"),
+    ("inhabiting", "inhabit"),
+    ("feelings", "feel"),
+    ("permitting", "permit"),
+    ("fomentation", "fomentaz"),
+    ("avaricious", "avarici"),
+    ("brandons", "brandon"),
+    ("barrel", "barrel"),
+    ("slung", "slung"),
+    ("calculating", "calcul"),
+    ("gondola", "gondola"),
+    ("far", "far"),
+    ("girdled", "girdl"),
+    ("littering", "litter"),
+    ("weighs", "weigh"),
+    ("advantageous", "advantag"),
+    ("contamination", "contaminaz"),
+    ("firma", "firma"),
+    ("redemption", "redempt"),
+    ("loone", "loon"),
+    ("fringed", "fring"),
+    ("borrioboolan", "borrioboolan"),
+    ("calms", "calm"),
+    ("gaping", "gape"),
+    ("viceroys", "viceroi"),
+    ("evolution", "evolut"),
+    ("scarred", "scar"),
+    ("meat", "meat"),
+    ("conspired", "conspir"),
+    ("instructed", "instruct"),
+    ("equus", "equu"),
+    ("alleged", "alleg"),
+    ("reconciliation", "reconciliaz"),
+    ("blanched", "blanch"),
+    ("gathered", "gather"),
+    ("dancer", "dancer"),
+    ("corrupt", "corrupt"),
+    ("wrathful", "wrath"),
+    ("lettuces", "lettuc"),
+    ("microscopical", "microscop"),
+    ("parenthetically", "parenthet"),
+    ("life", "life"),
+    ("supposing", "suppos"),
+    ("misled", "misl"),
+    ("earn", "earn"),
+    ("versification", "versificaz"),
+    ("fetching", "fetch"),
+    ("dies", "di"),
+    ("scared", "scare"),
+    ("conica", "conica"),
+    ("fletchers", "fletcher"),
+    ("enabled", "enabl"),
+    ("cosgrave", "cosgrav"),
+    ("harm", "harm"),
+    ("quotation", "quotaz"),
+    ("balmy", "balmi"),
+    ("fragrance", "fragranc"),
+    ("annette", "annett"),
+    ("development", "develop"),
+    ("thanksgiving", "thanksgiv"),
+    ("american", "american"),
+    ("progenitive", "progenit"),
+    ("terrifying", "terrifi"),
+    ("tampers", "tamper"),
+    ("ascal", "ascal"),
+    ("quarrelling", "quarrel"),
+    ("derwent", "derwent"),
+    ("ll", "ll"),
+    ("fringes", "fring"),
+    ("vibrate", "vibrat"),
+    ("regards", "regard"),
+    ("takken", "takken"),
+    ("laura", "laura"),
+    ("shelters", "shelter"),
+    ("record", "record"),
+    ("avarice", "avaric"),
+    ("eucalyptus", "eucalyptu"),
+    ("fabricated", "fabric"),
+    ("restful", "rest"),
+    ("silky", "silki"),
+    ("ding", "ding"),
+    ("monkey", "monkei"),
+    ("being", "be"),
+    ("spends", "spend"),
+    ("inheritance", "inherit"),
+    ("moneyed", "monei"),
+    ("dandyism", "dandyism"),
+    ("passport", "passport"),
+    ("threatened", "threaten"),
+    ("inflict", "inflict"),
+    ("currently", "current"),
+    ("highness", "high"),
+    ("signed", "sign"),
+    ("curvature", "curvatur"),
+    ("penalty", "penalti"),
+    ("monsieur", "monsieur"),
+    ("consolatory", "consolatori"),
+    ("brightness", "bright"),
+    ("tinkled", "tinkl"),
+    ("exceeds", "exce"),
+    ("bruise", "bruis"),
+    ("protococcus", "protococcu"),
+    ("colouring", "colour"),
+    ("foreigner", "foreign"),
+    ("accountant", "account"),
+    ("staidness", "staid"),
+    ("apperton", "apperton"),
+    ("sewed", "sew"),
+    ("bothering", "bother"),
+    ("gasping", "gasp"),
+    ("echoes", "echo"),
+    ("thankfulness", "thank"),
+    ("sandal", "sandal"),
+    ("lii", "lii"),
+    ("mottles", "mottl"),
+    ("vegetation", "vegetaz"),
+    ("gen", "gen"),
+    ("borne", "born"),
+    ("rotatory", "rotatori"),
+    ("adieux", "adieux"),
+    ("tilting", "tilt"),
+    ("engraving", "engrav"),
+    ("unexpected", "unexpect"),
+    ("encounter", "encount"),
+    ("upwards", "upward"),
+    ("identically", "ident"),
+    ("talking", "talk"),
+    ("fours", "four"),
+    ("hairdresser", "hairdress"),
+    ("bonnets", "bonnet"),
+    ("fortress", "fortress"),
+    ("tyranny", "tyranni"),
+    ("listlessly", "listlessli"),
+    ("disturbing", "disturb"),
+    ("landsman", "landsman"),
+    ("bachman", "bachman"),
+    ("deposits", "deposit"),
+    ("incidents", "incid"),
+    ("signifieth", "signifieth"),
+    ("seaport", "seaport"),
+    ("sweetheart", "sweetheart"),
+    ("bileing", "bile"),
+    ("overdone", "overdon"),
+    ("conserved", "conserv"),
+    ("whomsoever", "whomsoev"),
+    ("thanksgivings", "thanksgiv"),
+    ("immediately", "immedi"),
+    ("une", "un"),
+    ("boasting", "boast"),
+    ("divided", "divid"),
+    ("pruning", "prune"),
+    ("undraws", "undraw"),
+    ("approbation", "approbaz"),
+    ("mould", "mould"),
+    ("derogatory", "derogatori"),
+    ("unobjectionable", "unobjection"),
+    ("scrope", "scrope"),
+    ("upheld", "upheld"),
+    ("readings", "read"),
+    ("unpromising", "unpromis"),
+    ("replete", "replet"),
+    ("fuegian", "fuegian"),
+    ("quits", "quit"),
+    ("servant", "servant"),
+    ("gout", "gout"),
+    ("enveloped", "envelop"),
+    ("pardiggle", "pardiggl"),
+    ("verging", "verg"),
+    ("lacking", "lack"),
+    ("afflicted", "afflict"),
+    ("starlight", "starlight"),
+    ("uprising", "upris"),
+    ("pins", "pin"),
+    ("strove", "strove"),
+    ("rendered", "render"),
+    ("luminosus", "luminosu"),
+    ("anthus", "anthu"),
+    ("bronchitis", "bronchiti"),
+    ("melodiously", "melodi"),
+    ("domineer", "domin"),
+    ("crotchet", "crotchet"),
+    ("smifligate", "smiflig"),
+    ("drought", "drought"),
+    ("bigamy", "bigami"),
+    ("ouly", "ouli"),
+    ("obits", "obit"),
+    ("wallflower", "wallflow"),
+    ("deein", "deein"),
+    ("clark", "clark"),
+    ("nymph", "nymph"),
+    ("jenyns", "jenyn"),
+    ("watercourse", "watercours"),
+    ("attenuated", "attenu"),
+    ("disappoint", "disappoint"),
+    ("collar", "collar"),
+    ("expectants", "expect"),
+    ("heaver", "heaver"),
+    ("furious", "furiou"),
+    ("razumihin", "razumihin"),
+    ("announces", "announc"),
+    ("undertone", "underton"),
+    ("measures", "measur"),
+    ("feverishness", "feverish"),
+    ("fuller", "fuller"),
+    ("resounding", "resound"),
+    ("circulating", "circul"),
+    ("chanted", "chant"),
+    ("wristbands", "wristband"),
+    ("bland", "bland"),
+    ("plantation", "plantaz"),
+    ("matricide", "matricid"),
+    ("procession", "process"),
+    ("unsatisfied", "unsatisfi"),
+    ("sent", "sent"),
+    ("wrongs", "wrong"),
+    ("retreated", "retreat"),
+    ("waylaid", "waylaid"),
+    ("tie", "tie"),
+    ("stowed", "stow"),
+    ("scouts", "scout"),
+    ("worldliness", "worldli"),
+    ("fille", "fill"),
+    ("piteous", "piteou"),
+    ("quake", "quak"),
+    ("color", "color"),
+    ("venturesome", "venturesom"),
+    ("sloth", "sloth"),
+    ("shockingly", "shockingli"),
+    ("superior", "superior"),
+    ("cosmopolitan", "cosmopolitan"),
+    ("duping", "dupe"),
+    ("sped", "sped"),
+    ("display", "displai"),
+    ("regiment", "regiment"),
+    ("ruddy", "ruddi"),
+    ("protestation", "protestaz"),
+    ("shabby", "shabbi"),
+    ("wedged", "wedg"),
+    ("trains", "train"),
+    ("tranquillity", "tranquil"),
+    ("breed", "breed"),
+    ("sulphate", "sulphat"),
+    ("peel", "peel"),
+    ("behold", "behold"),
+    ("fuegians", "fuegian"),
+    ("buries", "buri"),
+    ("garrison", "garrison"),
+    ("stationers", "station"),
+    ("feathered", "feather"),
+    ("sparking", "spark"),
+    ("affair", "affair"),
+    ("apollinaris", "apollinari"),
+    ("unbelieving", "unbeliev"),
+    ("odd", "odd"),
+    ("spoliation", "spoliaz"),
+    ("brats", "brat"),
+    ("overspreading", "overspread"),
+    ("phib", "phib"),
+    ("weed", "weed"),
+    ("griffiths", "griffith"),
+    ("debated", "debat"),
+    ("lumbey", "lumbei"),
+    ("chinks", "chink"),
+    ("mat", "mat"),
+    ("nines", "nine"),
+    ("wordy", "wordi"),
+    ("defiant", "defiant"),
+    ("consciousness", "conscious"),
+    ("examines", "examin"),
+    ("reputations", "reputaz"),
+    ("patagones", "patagon"),
+    ("strugglers", "struggler"),
+    ("massively", "massiv"),
+    ("kilns", "kiln"),
+    ("chatty", "chatti"),
+    ("disparaging", "disparag"),
+    ("swaggered", "swagger"),
+    ("sung", "sung"),
+    ("nicolas", "nicola"),
+    ("trousers", "trouser"),
+    ("abashed", "abash"),
+    ("sleek", "sleek"),
+    ("admirer", "admir"),
+    ("harbour", "harbour"),
+    ("geology", "geologi"),
+    ("sighing", "sigh"),
+    ("wagged", "wag"),
+    ("sponge", "spong"),
+    ("displeased", "displeas"),
+    ("comptes", "compt"),
+    ("tooting", "toot"),
+    ("singula", "singula"),
+    ("views", "view"),
+    ("obstruction", "obstruct"),
+    ("quicksilver", "quicksilv"),
+    ("falklands", "falkland"),
+    ("assignable", "assign"),
+    ("message", "messag"),
+    ("lebanon", "lebanon"),
+    ("eldon", "eldon"),
+    ("parades", "parad"),
+    ("conjectures", "conjectur"),
+    ("squeals", "squeal"),
+    ("papering", "paper"),
+    ("underlying", "underli"),
+    ("policeman", "policeman"),
+    ("popularity", "popular"),
+    ("coburg", "coburg"),
+    ("habits", "habit"),
+    ("vide", "vide"),
+    ("unpoliteness", "unpolit"),
+    ("lombardic", "lombard"),
+    ("lymph", "lymph"),
+    ("dispossessed", "dispossess"),
+    ("soever", "soever"),
+    ("doozen", "doozen"),
+    ("milch", "milch"),
+    ("magnificence", "magnific"),
+    ("senior", "senior"),
+    ("vouchsafed", "vouchsaf"),
+    ("flawed", "flaw"),
+    ("quadra", "quadra"),
+    ("fortitude", "fortitud"),
+    ("thereafter", "thereaft"),
+    ("neuter", "neuter"),
+    ("simply", "simpli"),
+    ("inveigle", "inveigl"),
+    ("gregory", "gregori"),
+    ("reporters", "report"),
+    ("pack", "pack"),
+    ("ologies", "ologi"),
+    ("tremulously", "tremul"),
+    ("plaits", "plait"),
+    ("brandy", "brandi"),
+    ("pompously", "pompous"),
+    ("dy", "di"),
+    ("impressive", "impress"),
+    ("chaneral", "chaner"),
+    ("blacking", "black"),
+    ("ink", "ink"),
+    ("lasts", "last"),
+    ("mongering", "monger"),
+    ("conveyances", "conveyanc"),
+    ("mount", "mount"),
+    ("weeps", "weep"),
+    ("hl", "hl"),
+    ("atmospheric", "atmospher"),
+    ("encroaches", "encroach"),
+    ("peevishness", "peevish"),
+    ("glimmer", "glimmer"),
+    ("distilled", "distil"),
+    ("araucanians", "araucanian"),
+    ("liberal", "liber"),
+    ("uncorked", "uncork"),
+    ("renewed", "renew"),
+    ("languish", "languish"),
+    ("fooled", "fool"),
+    ("fling", "fling"),
+    ("offering", "offer"),
+    ("skies", "ski"),
+    ("prosecution", "prosecut"),
+    ("drummer", "drummer"),
+    ("reconciled", "reconcil"),
+    ("arrow", "arrow"),
+    ("tiff", "tiff"),
+    ("innings", "in"),
+    ("confidential", "confidenti"),
+    ("nurtured", "nurtur"),
+    ("strawberries", "strawberri"),
+    ("jacket", "jacket"),
+    ("kampfes", "kampf"),
+    ("reddened", "redden"),
+    ("punctual", "punctual"),
+    ("full", "full"),
+    ("wet", "wet"),
+    ("guy", "gui"),
+    ("smoother", "smoother"),
+    ("exciting", "excit"),
+    ("cymbal", "cymbal"),
+    ("alcicornis", "alcicorni"),
+    ("chilling", "chill"),
+    ("frail", "frail"),
+    ("columnar", "columnar"),
+    ("sorrowing", "sorrow"),
+    ("banquetings", "banquet"),
+    ("rite", "rite"),
+    ("wedge", "wedg"),
+    ("intentions", "intent"),
+    ("marriage", "marriag"),
+    ("unless", "unless"),
+    ("voce", "voce"),
+    ("protesting", "protest"),
+    ("compressible", "compress"),
+    ("peru", "peru"),
+    ("pinkish", "pinkish"),
+    ("deluges", "delug"),
+    ("vaporous", "vapor"),
+    ("sleeper", "sleeper"),
+    ("salutary", "salutari"),
+    ("apron", "apron"),
+    ("joyfully", "joyfulli"),
+    ("excitement", "excit"),
+    ("raspberry", "raspberri"),
+    ("beholder", "behold"),
+    ("gladness", "glad"),
+    ("transferred", "transfer"),
+    ("winning", "win"),
+    ("molasses", "molass"),
+    ("usefully", "usefulli"),
+    ("breakwaters", "breakwat"),
+    ("surrey", "surrei"),
+    ("sunsets", "sunset"),
+    ("geoffroi", "geoffroi"),
+    ("cynicism", "cynic"),
+    ("beacon", "beacon"),
+    ("brightens", "brighten"),
+    ("devotedness", "devoted"),
+    ("exaggerated", "exagger"),
+    ("jugular", "jugular"),
+    ("assenting", "assent"),
+    ("short", "short"),
+    ("bending", "bend"),
+    ("bogg", "bogg"),
+    ("hooking", "hook"),
+    ("greatcoat", "greatcoat"),
+    ("blubber", "blubber"),
+    ("discontented", "discont"),
+    ("traces", "trace"),
+    ("pace", "pace"),
+    ("repented", "repent"),
+    ("allow", "allow"),
+    ("comfortable", "comfort"),
+    ("lurid", "lurid"),
+    ("reek", "reek"),
+    ("goldfish", "goldfish"),
+    ("otters", "otter"),
+    ("lathered", "lather"),
+    ("sheepskin", "sheepskin"),
+    ("sivatherium", "sivatherium"),
+    ("careful", "care"),
+    ("boulders", "boulder"),
+    ("purify", "purifi"),
+    ("firelight", "firelight"),
+    ("swearing", "swear"),
+    ("restoration", "restoraz"),
+    ("tramps", "tramp"),
+    ("sawyer", "sawyer"),
+    ("examined", "examin"),
+    ("drawbridge", "drawbridg"),
+    ("essential", "essenti"),
+    ("blurt", "blurt"),
+    ("darkness", "dark"),
+    ("disgusted", "disgust"),
+    ("extensively", "extens"),
+    ("retort", "retort"),
+    ("egregiously", "egregi"),
+    ("spies", "spi"),
+    ("conventual", "conventu"),
+    ("australes", "austral"),
+    ("townsmen", "townsmen"),
+    ("scalping", "scalp"),
+    ("blunted", "blunt"),
+    ("trustworthy", "trustworthi"),
+    ("easy", "easi"),
+    ("firm", "firm"),
+    ("cadogan", "cadogan"),
+    ("buoyant", "buoyant"),
+    ("deposer", "depos"),
+    ("thirsty", "thirsti"),
+    ("millions", "million"),
+    ("pinker", "pinker"),
+    ("lip", "lip"),
+    ("maze", "maze"),
+    ("communism", "commun"),
+    ("octopus", "octopu"),
+    ("alternatives", "altern"),
+    ("slings", "sling"),
+    ("liesk", "liesk"),
+    ("intermission", "intermiss"),
+    ("smithers", "smither"),
+    ("inundations", "inundaz"),
+    ("gambler", "gambler"),
+    ("harding", "hard"),
+    ("fringe", "fring"),
+    ("giving", "give"),
+    ("forebodings", "forebod"),
+    ("imperial", "imperi"),
+    ("express", "express"),
+    ("finn", "finn"),
+    ("rebellion", "rebellion"),
+    ("succeed", "succe"),
+    ("luzhin", "luzhin"),
+    ("unclaimed", "unclaim"),
+    ("settles", "settl"),
+    ("beef", "beef"),
+    ("separating", "separ"),
+    ("adjournment", "adjourn"),
+    ("panke", "pank"),
+    ("geist", "geist"),
+    ("popular", "popular"),
+    ("haggardly", "haggardli"),
+    ("optical", "optic"),
+    ("northampton", "northampton"),
+    ("disillusioned", "disillus"),
+    ("roi", "roi"),
+    ("enumerate", "enumer"),
+    ("lapse", "laps"),
+    ("twittering", "twitter"),
+    ("nowhere", "nowher"),
+    ("legends", "legend"),
+    ("ascidiae", "ascidia"),
+    ("russians", "russian"),
+    ("fooling", "fool"),
+    ("planing", "plane"),
+    ("jumbling", "jumbl"),
+    ("deformities", "deform"),
+    ("pianoforte", "pianofort"),
+    ("unseasonable", "unseason"),
+    ("goodle", "goodl"),
+    ("accuses", "accus"),
+    ("homely", "home"),
+    ("chariey", "chariei"),
+    ("critic", "critic"),
+    ("sidewise", "sidewis"),
+    ("pantry", "pantri"),
+    ("walks", "walk"),
+    ("reciprocal", "reciproc"),
+    ("contemporaries", "contemporari"),
+    ("restoring", "restor"),
+    ("halloo", "halloo"),
+    ("describe", "describ"),
+    ("debate", "debat"),
+    ("hushing", "hush"),
+    ("refrigerator", "refriger"),
+    ("praises", "prais"),
+    ("alternate", "altern"),
+    ("auction", "auction"),
+    ("dinner", "dinner"),
+    ("tenderest", "tenderest"),
+    ("frees", "free"),
+    ("keenness", "keen"),
+    ("camlet", "camlet"),
+    ("stark", "stark"),
+    ("spiteful", "spite"),
+    ("flutes", "flute"),
+    ("righteous", "righteou"),
+    ("owners", "owner"),
+    ("shetland", "shetland"),
+    ("kicks", "kick"),
+    ("sings", "sing"),
+    ("frayed", "frai"),
+    ("chadbands", "chadband"),
+    ("debtors", "debtor"),
+    ("conveys", "convei"),
+    ("backed", "back"),
+    ("bailed", "bail"),
+    ("depending", "depend"),
+    ("theoretical", "theoret"),
+    ("cleverer", "clever"),
+    ("dullness", "dul"),
+    ("peuquenes", "peuquen"),
+    ("trifling", "trifl"),
+    ("seldom", "seldom"),
+    ("rusticities", "rustic"),
+    ("scratching", "scratch"),
+    ("vegetate", "veget"),
+    ("boon", "boon"),
+    ("hombre", "hombr"),
+    ("suppliant", "suppliant"),
+    ("seraphically", "seraph"),
+    ("nice", "nice"),
+    ("othe", "oth"),
+    ("juries", "juri"),
+    ("wha", "wha"),
+    ("crater", "crater"),
+    ("nunnery", "nunneri"),
+    ("environne", "environn"),
+    ("convivial", "convivi"),
+    ("baited", "bait"),
+    ("gulped", "gulp"),
+    ("repressed", "repress"),
+    ("ramsden", "ramsden"),
+    ("illegible", "illeg"),
+    ("untie", "unti"),
+    ("bagatelle", "bagatell"),
+    ("englishman", "englishman"),
+    ("basa", "basa"),
+    ("reconsideration", "reconsideraz"),
+    ("atonement", "aton"),
+    ("unrecognised", "unrecognis"),
+    ("wastes", "wast"),
+    ("kitten", "kitten"),
+    ("sneer", "sneer"),
+    ("erect", "erect"),
+    ("svidrigailov", "svidrigailov"),
+    ("admire", "admir"),
+    ("dairy", "dairi"),
+    ("byron", "byron"),
+    ("operas", "opera"),
+    ("subscribed", "subscrib"),
+    ("marvellously", "marvel"),
+    ("colonized", "colon"),
+    ("perusals", "perus"),
+    ("manifested", "manifest"),
+    ("stung", "stung"),
+    ("armaments", "armament"),
+    ("tickings", "tick"),
+    ("chilled", "chill"),
+    ("moment", "moment"),
+    ("grade", "grade"),
+    ("shanties", "shanti"),
+    ("reasonable", "reason"),
+    ("luring", "lure"),
+    ("piquancy", "piquanc"),
+    ("hillsides", "hillsid"),
+    ("tragedies", "tragedi"),
+    ("unformed", "unform"),
+    ("beset", "beset"),
+    ("comparison", "comparison"),
+    ("permission", "permiss"),
+    ("threatening", "threaten"),
+    ("wye", "wye"),
+    ("slights", "slight"),
+    ("hospitable", "hospit"),
+    ("saurophagus", "saurophagu"),
+    ("sitter", "sitter"),
+    ("exhilarated", "exhilar"),
+    ("galapagoensis", "galapagoensi"),
+    ("thou", "thou"),
+    ("sickliness", "sickli"),
+    ("staylace", "staylac"),
+    ("families", "famili"),
+    ("dipped", "dip"),
+    ("real", "real"),
+    ("recognise", "recognis"),
+    ("purposes", "purpos"),
+    ("supper", "supper"),
+    ("stateliness", "stateli"),
+    ("speck", "speck"),
+    ("petulantly", "petulantli"),
+    ("involve", "involv"),
+    ("reproofs", "reproof"),
+    ("unwarily", "unwarili"),
+    ("etiquettes", "etiquett"),
+    ("cures", "cure"),
+    ("impunity", "impun"),
+    ("blesses", "bless"),
+    ("vincent", "vincent"),
+    ("umbrageous", "umbrag"),
+    ("advancement", "advanc"),
+    ("augean", "augean"),
+    ("persuasion", "persuas"),
+    ("pountneys", "pountnei"),
+    ("precipitately", "precipit"),
+    ("signifies", "signifi"),
+    ("primarily", "primarili"),
+    ("whined", "whine"),
+    ("beseechingly", "beseechingli"),
+    ("nebulous", "nebul"),
+    ("dearth", "dearth"),
+    ("laudation", "laudaz"),
+    ("beamingly", "beamingli"),
+    ("contraction", "contract"),
+    ("watcher", "watcher"),
+    ("undutiful", "unduti"),
+    ("asheamed", "asheam"),
+    ("hues", "hue"),
+    ("accursed", "accurs"),
+    ("auditors", "auditor"),
+    ("nelly", "nelli"),
+    ("sore", "sore"),
+    ("enjoyable", "enjoyabl"),
+    ("outlived", "outliv"),
+    ("miscellaneous", "miscellan"),
+    ("plated", "plate"),
+    ("esse", "ess"),
+    ("pyrard", "pyrard"),
+    ("miller", "miller"),
+    ("thrill", "thrill"),
+    ("his", "hi"),
+    ("pieces", "piec"),
+    ("reactionary", "reactionari"),
+    ("bale", "bale"),
+    ("scooped", "scoop"),
+    ("halts", "halt"),
+    ("people", "peopl"),
+    ("accosting", "accost"),
+    ("vahrushin", "vahrushin"),
+    ("observant", "observ"),
+    ("hoping", "hope"),
+    ("stumbles", "stumbl"),
+    ("calmer", "calmer"),
+    ("rambles", "rambl"),
+    ("petrovsky", "petrovski"),
+    ("other", "other"),
+    ("kindliest", "kindliest"),
+    ("notoriously", "notori"),
+    ("durstn", "durstn"),
+    ("no", "no"),
+    ("penguin", "penguin"),
+    ("scald", "scald"),
+    ("highfaluting", "highfalut"),
+    ("desmodus", "desmodu"),
+    ("shopkeepers", "shopkeep"),
+    ("cite", "cite"),
+    ("brag", "brag"),
+    ("vacation", "vacaz"),
+    ("unowned", "unown"),
+    ("regretfully", "regretfulli"),
+    ("around", "around"),
+    ("cognate", "cognat"),
+    ("airs", "air"),
+    ("unrepining", "unrepin"),
+    ("exposition", "exposit"),
+    ("washings", "wash"),
+    ("mortify", "mortifi"),
+    ("sketchily", "sketchili"),
+    ("charmers", "charmer"),
+    ("boodle", "boodl"),
+    ("rhynchops", "rhynchop"),
+    ("sash", "sash"),
+    ("coiner", "coiner"),
+    ("pagan", "pagan"),
+    ("england", "england"),
+    ("hopes", "hope"),
+    ("occasioning", "occas"),
+    ("otto", "otto"),
+    ("lemuel", "lemuel"),
+    ("sums", "sum"),
+    ("sink", "sink"),
+    ("cherish", "cherish"),
+    ("haughtily", "haughtili"),
+    ("vishera", "vishera"),
+    ("ludwigovna", "ludwigovna"),
+    ("venus", "venu"),
+    ("browning", "brown"),
+    ("skeen", "skeen"),
+    ("jag", "jag"),
+    ("colored", "color"),
+    ("blas", "bla"),
+    ("speaks", "speak"),
+    ("pigmies", "pigmi"),
+    ("moody", "moodi"),
+    ("proudest", "proudest"),
+    ("pussy", "pussi"),
+    ("sallys", "sal"),
+    ("stockade", "stockad"),
+    ("whalery", "whaleri"),
+    ("tastes", "tast"),
+    ("missive", "missiv"),
+    ("deprives", "depriv"),
+    ("backbone", "backbon"),
+    ("ingenuously", "ingenu"),
+    ("flares", "flare"),
+    ("sad", "sad"),
+    ("derive", "deriv"),
+    ("thousing", "thous"),
+    ("domidor", "domidor"),
+    ("mincefenille", "mincefenill"),
+    ("starn", "starn"),
+    ("handy", "handi"),
+    ("brawny", "brawni"),
+    ("ambiguous", "ambigu"),
+    ("oration", "oraz"),
+    ("quixotism", "quixot"),
+    ("coalesced", "coalesc"),
+    ("tobacconist", "tobacconist"),
+    ("prisons", "prison"),
+    ("enables", "enabl"),
+    ("permissible", "permiss"),
+    ("aberration", "aberraz"),
+    ("exhorting", "exhort"),
+    ("mayn", "mayn"),
+    ("neane", "nean"),
+    ("cessation", "cessaz"),
+    ("bespoke", "bespok"),
+    ("obliges", "oblig"),
+    ("cataract", "cataract"),
+    ("sulkily", "sulkili"),
+    ("hobby", "hobbi"),
+    ("awful", "aw"),
+    ("kimpelled", "kimpel"),
+    ("impossible", "imposs"),
+    ("early", "earli"),
+    ("most", "most"),
+    ("estimable", "estim"),
+    ("arrayed", "arrai"),
+    ("clapped", "clap"),
+    ("felix", "felix"),
+    ("inches", "inch"),
+    ("tendencies", "tendenc"),
+    ("discretion", "discret"),
+    ("measther", "measther"),
+    ("anson", "anson"),
+    ("costlier", "costlier"),
+    ("deinornis", "deinorni"),
+    ("women", "women"),
+    ("occupant", "occup"),
+    ("alleviated", "allevi"),
+    ("comin", "comin"),
+    ("cawa", "cawa"),
+    ("unknowingly", "unknowingli"),
+    ("stupendous", "stupend"),
+    ("cheval", "cheval"),
+    ("police", "polic"),
+    ("corruption", "corrupt"),
+    ("pilgrim", "pilgrim"),
+    ("penetrable", "penetr"),
+    ("unlocked", "unlock"),
+    ("marshy", "marshi"),
+    ("adaptability", "adapt"),
+    ("because", "becaus"),
+    ("unsolicited", "unsolicit"),
+    ("florence", "florenc"),
+    ("moistened", "moisten"),
+    ("flaw", "flaw"),
+    ("robbing", "rob"),
+    ("prescription", "prescript"),
+    ("fluttered", "flutter"),
+    ("unhorsed", "unhors"),
+    ("trouve", "trouv"),
+    ("truthlike", "truthlik"),
+    ("midway", "midwai"),
+    ("trotting", "trot"),
+    ("hangings", "hang"),
+    ("applications", "applicaz"),
+    ("anteater", "anteat"),
+    ("mares", "mare"),
+    ("manie", "mani"),
+    ("genial", "genial"),
+    ("crusade", "crusad"),
+    ("hereupon", "hereupon"),
+    ("candid", "candid"),
+    ("stripling", "stripl"),
+    ("lxvii", "lxvii"),
+    ("ar", "ar"),
+    ("recommended", "recommend"),
+    ("counterplot", "counterplot"),
+    ("couches", "couch"),
+    ("invincible", "invinc"),
+    ("announcing", "announc"),
+    ("burlesque", "burlesqu"),
+    ("clerk", "clerk"),
+    ("rads", "rad"),
+    ("throat", "throat"),
+    ("grudges", "grudg"),
+    ("drawers", "drawer"),
+    ("waddling", "waddl"),
+    ("timely", "time"),
+    ("snatches", "snatch"),
+    ("ami", "ami"),
+    ("suspicious", "suspici"),
+    ("ripening", "ripen"),
+    ("footways", "footwai"),
+    ("dusted", "dust"),
+    ("echo", "echo"),
+    ("terminate", "termin"),
+    ("eyelid", "eyelid"),
+    ("scaffold", "scaffold"),
+    ("clip", "clip"),
+    ("earthen", "earthen"),
+    ("desert", "desert"),
+    ("affably", "affabl"),
+    ("retrace", "retrac"),
+    ("fail", "fail"),
+    ("palatable", "palat"),
+    ("sliderskew", "sliderskew"),
+    ("potent", "potent"),
+    ("intimate", "intim"),
+    ("battled", "battl"),
+    ("devolves", "devolv"),
+    ("inattentive", "inattent"),
+    ("mends", "mend"),
+    ("oak", "oak"),
+    ("longevity", "longev"),
+    ("these", "these"),
+    ("pensioning", "pension"),
+    ("tampered", "tamper"),
+    ("collection", "collect"),
+    ("nate", "nate"),
+    ("bedroom", "bedroom"),
+    ("expert", "expert"),
+    ("smokings", "smoke"),
+    ("despotic", "despot"),
+    ("azucar", "azucar"),
+    ("uglier", "uglier"),
+    ("sydney", "sydnei"),
+    ("fans", "fan"),
+    ("dates", "date"),
+    ("rations", "raze"),
+    ("keeping", "keep"),
+    ("reigning", "reign"),
+    ("coughs", "cough"),
+    ("augur", "augur"),
+    ("mournful", "mourn"),
+    ("norway", "norwai"),
+    ("flexure", "flexur"),
+    ("gaieties", "gaieti"),
+    ("intercourse", "intercours"),
+    ("lilliput", "lilliput"),
+    ("parlance", "parlanc"),
+    ("thrushes", "thrush"),
+    ("mists", "mist"),
+    ("fatherland", "fatherland"),
+    ("astounding", "astound"),
+    ("felons", "felon"),
+    ("awkwardness", "awkward"),
+    ("hard", "hard"),
+    ("poker", "poker"),
+    ("implacable", "implac"),
+    ("editors", "editor"),
+    ("howard", "howard"),
+    ("gall", "gall"),
+    ("procedure", "procedur"),
+    ("provide", "provid"),
+    ("gewgaws", "gewgaw"),
+    ("kong", "kong"),
+    ("disengaging", "disengag"),
+    ("stags", "stag"),
+    ("symptoms", "symptom"),
+    ("supposed", "suppos"),
+    ("timorously", "timor"),
+    ("preferer", "prefer"),
+    ("powerful", "power"),
+    ("rolor", "rolor"),
+    ("render", "render"),
+    ("deliberately", "deliber"),
+    ("covent", "covent"),
+    ("brash", "brash"),
+    ("engraved", "engrav"),
+    ("whistled", "whistl"),
+    ("drear", "drear"),
+    ("adorns", "adorn"),
+    ("peer", "peer"),
+    ("menfion", "menfion"),
+    ("la", "la"),
+    ("informs", "inform"),
+    ("inermis", "inermi"),
+    ("fernandez", "fernandez"),
+    ("italy", "itali"),
+    ("harshly", "harshli"),
+    ("pursuits", "pursuit"),
+    ("stripped", "strip"),
+    ("overgrown", "overgrown"),
+    ("confidence", "confid"),
+    ("beggarly", "beggarli"),
+    ("instances", "instanc"),
+    ("gentler", "gentler"),
+    ("noisome", "noisom"),
+    ("sentinel", "sentinel"),
+    ("mania", "mania"),
+    ("depressions", "depress"),
+    ("bungay", "bungai"),
+    ("eaten", "eaten"),
+    ("clinched", "clinch"),
+    ("dire", "dire"),
+    ("plaint", "plaint"),
+    ("clanks", "clank"),
+    ("slavish", "slavish"),
+    ("labouring", "labour"),
+    ("chemical", "chemic"),
+    ("dramatist", "dramatist"),
+    ("opp", "opp"),
+    ("library", "librari"),
+    ("auvergne", "auvergn"),
+    ("unacknowledged", "unacknowledg"),
+    ("secession", "secess"),
+    ("decorum", "decorum"),
+    ("brasiliensis", "brasiliensi"),
+    ("incognito", "incognito"),
+    ("ticketed", "ticket"),
+    ("poached", "poach"),
+    ("invaders", "invad"),
+    ("grandiloquent", "grandiloqu"),
+    ("tahitians", "tahitian"),
+    ("demolished", "demolish"),
+    ("transgression", "transgress"),
+    ("figment", "figment"),
+    ("arena", "arena"),
+    ("prophetically", "prophet"),
+    ("commands", "command"),
+    ("unmerciful", "unmerci"),
+    ("lovely", "love"),
+    ("acquainted", "acquaint"),
+    ("grandiflorus", "grandifloru"),
+    ("interrogatory", "interrogatori"),
+    ("tile", "tile"),
+    ("credulity", "credul"),
+    ("embracer", "embrac"),
+    ("sharpening", "sharpen"),
+    ("womanhood", "womanhood"),
+    ("magalonyx", "magalonyx"),
+    ("peasantry", "peasantri"),
+    ("dentistical", "dentist"),
+    ("unjustifiable", "unjustifi"),
+    ("rewarding", "reward"),
+    ("yorkshireman", "yorkshireman"),
+    ("painfully", "painfulli"),
+    ("drenched", "drench"),
+    ("vibration", "vibraz"),
+    ("undivided", "undivid"),
+    ("continuing", "continu"),
+    ("tremble", "trembl"),
+    ("betters", "better"),
+    ("overmuch", "overmuch"),
+    ("tortoises", "tortois"),
+    ("confronting", "confront"),
+    ("dramaticus", "dramaticu"),
+    ("california", "california"),
+    ("picks", "pick"),
+    ("induced", "induc"),
+    ("hobbles", "hobbl"),
+    ("commentary", "commentari"),
+    ("uncalculating", "uncalcul"),
+    ("fastenings", "fasten"),
+    ("coursers", "courser"),
+    ("coincided", "coincid"),
+    ("manuring", "manur"),
+    ("ventured", "ventur"),
+    ("confederate", "confeder"),
+    ("impassibility", "impass"),
+    ("ejaculating", "ejacul"),
+    ("rodya", "rodya"),
+    ("gigantic", "gigant"),
+    ("growlery", "growleri"),
+    ("chantant", "chantant"),
+    ("py", "pi"),
+    ("switches", "switch"),
+    ("renous", "renou"),
+    ("wherein", "wherein"),
+    ("vale", "vale"),
+    ("frightening", "frighten"),
+    ("rationally", "ration"),
+    ("constituent", "constitu"),
+    ("attainment", "attain"),
+    ("squatting", "squat"),
+    ("excite", "excit"),
+    ("sandstone", "sandston"),
+    ("proffered", "proffer"),
+    ("labours", "labour"),
+    ("exploits", "exploit"),
+    ("feature", "featur"),
+    ("difficult", "difficult"),
+    ("adaptable", "adapt"),
+    ("suppression", "suppress"),
+    ("ministries", "ministri"),
+    ("enterprising", "enterpris"),
+    ("mainstay", "mainstai"),
+    ("fauna", "fauna"),
+    ("vodka", "vodka"),
+    ("opposed", "oppos"),
+    ("wolf", "wolf"),
+    ("palpitated", "palpit"),
+    ("waving", "wave"),
+    ("irascibility", "irasc"),
+    ("chemise", "chemis"),
+    ("cheapest", "cheapest"),
+    ("iodic", "iodic"),
+    ("amphitheatrical", "amphitheatr"),
+    ("burrowed", "burrow"),
+    ("chagrin", "chagrin"),
+    ("masted", "mast"),
+    ("interlopers", "interlop"),
+    ("subsidized", "subsid"),
+    ("lapses", "laps"),
+    ("rotted", "rot"),
+    ("depresses", "depress"),
+    ("vexed", "vex"),
+    ("takes", "take"),
+    ("sturdiest", "sturdiest"),
+    ("intercede", "interced"),
+    ("give", "give"),
+    ("unfathomable", "unfathom"),
+    ("unwinding", "unwind"),
+    ("ecstatically", "ecstat"),
+    ("hardships", "hardship"),
+    ("orphan", "orphan"),
+    ("plane", "plane"),
+    ("contrast", "contrast"),
+    ("unexciting", "unexcit"),
+    ("bluffy", "bluffi"),
+    ("curly", "curli"),
+    ("ooman", "ooman"),
+    ("unearthly", "unearthli"),
+    ("transversely", "transvers"),
+    ("bedabbled", "bedabbl"),
+    ("reckoning", "reckon"),
+    ("hadst", "hadst"),
+    ("implied", "impli"),
+    ("expiring", "expir"),
+    ("coquette", "coquett"),
+    ("bullen", "bullen"),
+    ("daniel", "daniel"),
+    ("sentimentality", "sentiment"),
+    ("brindle", "brindl"),
+    ("hornsey", "hornsei"),
+    ("boythorn", "boythorn"),
+    ("chagos", "chago"),
+    ("panacea", "panacea"),
+    ("similarly", "similarli"),
+    ("forbidding", "forbid"),
+    ("curled", "curl"),
+    ("crazy", "crazi"),
+    ("borrowing", "borrow"),
+    ("formality", "formal"),
+    ("turmoil", "turmoil"),
+    ("material", "materi"),
+    ("reads", "read"),
+    ("outweighed", "outweigh"),
+    ("philosoph", "philosoph"),
+    ("mile", "mile"),
+    ("whatsoever", "whatsoev"),
+    ("annoy", "annoi"),
+    ("efface", "effac"),
+    ("frothed", "froth"),
+    ("nigh", "nigh"),
+    ("circles", "circl"),
+    ("youth", "youth"),
+    ("vizier", "vizier"),
+    ("inns", "inn"),
+    ("screaming", "scream"),
+    ("chaffers", "chaffer"),
+    ("fireside", "firesid"),
+    ("consumption", "consumpt"),
+    ("richardson", "richardson"),
+    ("organism", "organ"),
+    ("intrude", "intrud"),
+    ("flocks", "flock"),
+    ("cents", "cent"),
+    ("mistrusts", "mistrust"),
+    ("michell", "michel"),
+    ("ap", "ap"),
+    ("arbitrary", "arbitrari"),
+    ("decline", "declin"),
+    ("waxy", "waxi"),
+    ("masthead", "masthead"),
+    ("lauzun", "lauzun"),
+    ("diverting", "divert"),
+    ("wishful", "wish"),
+    ("tomkins", "tomkin"),
+    ("barrenness", "barren"),
+    ("blaze", "blaze"),
+    ("conjures", "conjur"),
+    ("inquiry", "inquiri"),
+    ("peremptorily", "peremptorili"),
+    ("clamouring", "clamour"),
+    ("smithson", "smithson"),
+    ("suing", "su"),
+    ("pester", "pester"),
+    ("dedlock", "dedlock"),
+    ("lv", "lv"),
+    ("crafts", "craft"),
+    ("commendation", "commendaz"),
+    ("oho", "oho"),
+    ("contralto", "contralto"),
+    ("restrictions", "restrict"),
+    ("attained", "attain"),
+    ("sofy", "sofi"),
+    ("brilliantly", "brilliantli"),
+    ("lassie", "lassi"),
+    ("doubling", "doubl"),
+    ("australians", "australian"),
+    ("towered", "tower"),
+    ("minded", "mind"),
+    ("extolled", "extol"),
+    ("dashes", "dash"),
+    ("composed", "compos"),
+    ("pa", "pa"),
+    ("limping", "limp"),
+    ("grander", "grander"),
+    ("flanked", "flank"),
+    ("barge", "barg"),
+    ("counterfeits", "counterfeit"),
+    ("removes", "remov"),
+    ("omnibuses", "omnibus"),
+    ("hates", "hate"),
+    ("chip", "chip"),
+    ("ruminations", "ruminaz"),
+    ("myself", "myself"),
+    ("woodlouse", "woodlous"),
+    ("swaggering", "swagger"),
+    ("dussauts", "dussaut"),
+    ("daybreak", "daybreak"),
+    ("kepler", "kepler"),
+    ("reviewal", "review"),
+    ("hastening", "hasten"),
+    ("knackeries", "knackeri"),
+    ("cue", "cue"),
+    ("silworth", "silworth"),
+    ("shun", "shun"),
+    ("ferule", "ferul"),
+    ("crossgrained", "crossgrain"),
+    ("disproportionately", "disproportion"),
+    ("famine", "famin"),
+    ("babble", "babbl"),
+    ("ottoman", "ottoman"),
+    ("nineteenth", "nineteenth"),
+    ("proportionately", "proportion"),
+    ("mankind", "mankind"),
+    ("scurvy", "scurvi"),
+    ("transmission", "transmiss"),
+    ("blushes", "blush"),
+    ("plotter", "plotter"),
+    ("stalactical", "stalact"),
+    ("devoutly", "devoutli"),
+    ("bibo", "bibo"),
+    ("minutus", "minutu"),
+    ("barbuda", "barbuda"),
+    ("revoked", "revok"),
+    ("unhooking", "unhook"),
+    ("higgins", "higgin"),
+    ("foller", "foller"),
+    ("granted", "grant"),
+    ("blister", "blister"),
+    ("fleetingly", "fleetingli"),
+    ("ransacked", "ransack"),
+    ("polynesian", "polynesian"),
+    ("offences", "offenc"),
+    ("bush", "bush"),
+    ("intertropical", "intertrop"),
+    ("glassy", "glassi"),
+    ("cummin", "cummin"),
+    ("broiled", "broil"),
+    ("recounted", "recount"),
+    ("titlark", "titlark"),
+    ("temminckii", "temminckii"),
+    ("blooded", "blood"),
+    ("dimple", "dimpl"),
+    ("orphaned", "orphan"),
+    ("hyperion", "hyperion"),
+    ("jarodyce", "jarodyc"),
+    ("crabbe", "crabb"),
+    ("hullo", "hullo"),
+    ("insanity", "insan"),
+    ("heretofore", "heretofor"),
+    ("joint", "joint"),
+    ("attagis", "attagi"),
+    ("nostrils", "nostril"),
+    ("tucutuco", "tucutuco"),
+    ("bursts", "burst"),
+    ("un", "un"),
+    ("conflagrations", "conflagraz"),
+    ("genlmn", "genlmn"),
+    ("helen", "helen"),
+    ("impressing", "impress"),
+    ("huts", "hut"),
+    ("modestly", "modestli"),
+    ("stool", "stool"),
+    ("remounted", "remount"),
+    ("sigh", "sigh"),
+    ("vehement", "vehement"),
+    ("selecting", "select"),
+    ("yoong", "yoong"),
+    ("coincidence", "coincid"),
+    ("frowsy", "frowsi"),
+    ("site", "site"),
+    ("fin", "fin"),
+    ("financial", "financi"),
+    ("loix", "loix"),
+    ("incongruous", "incongru"),
+    ("pouted", "pout"),
+    ("caricatures", "caricatur"),
+    ("outgoings", "outgo"),
+    ("rivers", "river"),
+    ("map", "map"),
+    ("tumbler", "tumbler"),
+    ("backwardness", "backward"),
+    ("wouldn", "wouldn"),
+    ("sodden", "sodden"),
+    ("blaming", "blame"),
+    ("fiscal", "fiscal"),
+    ("euston", "euston"),
+    ("implements", "implement"),
+    ("exterminations", "exterminaz"),
+    ("mylodon", "mylodon"),
+    ("superstitiously", "superstiti"),
+    ("operator", "oper"),
+    ("trunk", "trunk"),
+    ("luxury", "luxuri"),
+    ("solely", "sole"),
+    ("fetters", "fetter"),
+    ("have", "have"),
+    ("worldly", "worldli"),
+    ("feldspathic", "feldspath"),
+    ("timber", "timber"),
+    ("creditor", "creditor"),
+    ("clasps", "clasp"),
+    ("veins", "vein"),
+    ("discomfited", "discomfit"),
+    ("inwentory", "inwentori"),
+    ("selfishness", "selfish"),
+    ("dey", "dei"),
+    ("retaining", "retain"),
+    ("immeasurably", "immeasur"),
+    ("rashness", "rash"),
+    ("annoys", "annoi"),
+    ("goose", "goos"),
+    ("ray", "rai"),
+    ("offspring", "offspr"),
+    ("debater", "debat"),
+    ("albania", "albania"),
+    ("pitiful", "piti"),
+    ("quieter", "quieter"),
+    ("spanned", "span"),
+    ("ada", "ada"),
+    ("ceremonious", "ceremoni"),
+    ("grimace", "grimac"),
+    ("leases", "leas"),
+    ("forgave", "forgav"),
+    ("remarking", "remark"),
+    ("abbott", "abbott"),
+    ("revolves", "revolv"),
+    ("extract", "extract"),
+    ("modes", "mode"),
+    ("impiety", "impieti"),
+    ("sixty", "sixti"),
+    ("carrier", "carrier"),
+    ("pastures", "pastur"),
+    ("poins", "poin"),
+    ("withstand", "withstand"),
+    ("psalm", "psalm"),
+    ("stupefaction", "stupefact"),
+    ("gunners", "gunner"),
+    ("hapless", "hapless"),
+    ("outrun", "outrun"),
+    ("huff", "huff"),
+    ("approachin", "approachin"),
+    ("grosser", "grosser"),
+    ("dressmaker", "dressmak"),
+    ("glare", "glare"),
+    ("fork", "fork"),
+    ("burthen", "burthen"),
+    ("cross", "cross"),
+    ("detachment", "detach"),
+    ("southampton", "southampton"),
+    ("kapernaumovs", "kapernaumov"),
+    ("hydra", "hydra"),
+    ("detects", "detect"),
+    ("trip", "trip"),
+    ("muffins", "muffin"),
+    ("amount", "amount"),
+    ("withdraws", "withdraw"),
+    ("baskets", "basket"),
+    ("beseeltes", "beseelt"),
+    ("wrenching", "wrench"),
+    ("beaded", "bead"),
+    ("friend", "friend"),
+    ("resemblance", "resembl"),
+    ("nutshells", "nutshel"),
+    ("ineffectual", "ineffectu"),
+    ("brushed", "brush"),
+    ("sixpenny", "sixpenni"),
+    ("alert", "alert"),
+    ("fallible", "fallibl"),
+    ("mane", "mane"),
+    ("estates", "estat"),
+    ("happy", "happi"),
+    ("explosive", "explos"),
+    ("arrecife", "arrecif"),
+    ("noisily", "noisili"),
+    ("famously", "famous"),
+    ("clt", "clt"),
+    ("contentment", "content"),
+    ("met", "met"),
+    ("essayed", "essai"),
+    ("sainted", "saint"),
+    ("reeking", "reek"),
+    ("aspirants", "aspir"),
+    ("fetch", "fetch"),
+    ("julia", "julia"),
+    ("aie", "aie"),
+    ("pocketing", "pocket"),
+    ("paupers", "pauper"),
+    ("repulsion", "repuls"),
+    ("fossiliferous", "fossilifer"),
+    ("crescent", "crescent"),
+    ("shadowed", "shadow"),
+    ("hotel", "hotel"),
+    ("lewis", "lewi"),
+    ("suffocating", "suffoc"),
+    ("apricots", "apricot"),
+    ("weatherboard", "weatherboard"),
+    ("boundless", "boundless"),
+    ("hutton", "hutton"),
+    ("daintily", "daintili"),
+    ("inquisitive", "inquisit"),
+    ("draw", "draw"),
+    ("grinned", "grin"),
+    ("grazes", "graze"),
+    ("satisfied", "satisfi"),
+    ("eyed", "ei"),
+    ("jezebel", "jezebel"),
+    ("strictest", "strictest"),
+    ("receipt", "receipt"),
+    ("brew", "brew"),
+    ("talisman", "talisman"),
+    ("artfulness", "art"),
+    ("interpret", "interpret"),
+    ("demonstrates", "demonstr"),
+    ("expatiating", "expati"),
+    ("twinkling", "twinkl"),
+    ("appealed", "appeal"),
+    ("wrapt", "wrapt"),
+    ("leetle", "leetl"),
+    ("eddies", "eddi"),
+    ("spoils", "spoil"),
+    ("malouines", "malouin"),
+    ("breeze", "breez"),
+    ("basket", "basket"),
+    ("redeemer", "redeem"),
+    ("apire", "apir"),
+    ("outfit", "outfit"),
+    ("gatherings", "gather"),
+    ("propitious", "propiti"),
+    ("anything", "anyth"),
+    ("languishing", "languish"),
+    ("crab", "crab"),
+    ("notably", "notabl"),
+    ("breathing", "breath"),
+    ("stretch", "stretch"),
+    ("useful", "us"),
+    ("panes", "pane"),
+    ("assumed", "assum"),
+    ("tact", "tact"),
+    ("assistance", "assist"),
+    ("joins", "join"),
+    ("cherryble", "cherrybl"),
+    ("coalworth", "coalworth"),
+    ("charwoman", "charwoman"),
+    ("creases", "creas"),
+    ("spotted", "spot"),
+    ("pauses", "paus"),
+    ("squeamishness", "squeamish"),
+    ("pairs", "pair"),
+    ("mistrustfully", "mistrustfulli"),
+    ("harbouring", "harbour"),
+    ("groups", "group"),
+    ("abt", "abt"),
+    ("intellectuality", "intellectu"),
+    ("giggled", "giggl"),
+    ("handsomest", "handsomest"),
+    ("mounting", "mount"),
+    ("threshold", "threshold"),
+    ("abortive", "abort"),
+    ("termed", "term"),
+    ("ostrov", "ostrov"),
+    ("trusted", "trust"),
+    ("harris", "harri"),
+    ("te", "te"),
+    ("ruminated", "rumin"),
+    ("scrapes", "scrape"),
+    ("swordsman", "swordsman"),
+    ("preys", "prei"),
+    ("loitering", "loiter"),
+    ("washin", "washin"),
+    ("width", "width"),
+    ("traceable", "traceabl"),
+    ("stragglers", "straggler"),
+    ("tumbled", "tumbl"),
+    ("aided", "aid"),
+    ("bleed", "bleed"),
+    ("delirium", "delirium"),
+    ("privations", "privaz"),
+    ("censured", "censur"),
+    ("quiets", "quiet"),
+    ("compels", "compel"),
+    ("disappointment", "disappoint"),
+    ("proboscis", "probosci"),
+    ("noticed", "notic"),
+    ("naturalized", "natur"),
+    ("juggled", "juggl"),
+    ("fashionably", "fashion"),
+    ("pretext", "pretext"),
+    ("skeleton", "skeleton"),
+    ("maintain", "maintain"),
+    ("refrains", "refrain"),
+    ("cloe", "cloe"),
+    ("hof", "hof"),
+    ("reverenced", "reverenc"),
+    ("resolute", "resolut"),
+    ("dissertation", "dissertaz"),
+    ("deemed", "deem"),
+    ("agent", "agent"),
+    ("aflicto", "aflicto"),
+    ("previous", "previou"),
+    ("dishonourable", "dishonour"),
+    ("pausing", "paus"),
+    ("contingencies", "conting"),
+    ("hours", "hour"),
+    ("unyielding", "unyield"),
+    ("breeches", "breech"),
+    ("expectancy", "expect"),
+    ("boroughbridge", "boroughbridg"),
+    ("surrounding", "surround"),
+    ("follow", "follow"),
+    ("cum", "cum"),
+    ("superscription", "superscript"),
+    ("grasp", "grasp"),
+    ("inferred", "infer"),
+    ("hatching", "hatch"),
+    ("opportunity", "opportun"),
+    ("apothecary", "apothecari"),
+    ("substitutes", "substitut"),
+    ("cubes", "cube"),
+    ("pernety", "perneti"),
+    ("significant", "signific"),
+    ("unfamiliarity", "unfamiliar"),
+    ("apprentice", "apprentic"),
+    ("screens", "screen"),
+    ("polishes", "polish"),
+    ("scrawled", "scrawl"),
+    ("fiddle", "fiddl"),
+    ("communicative", "commun"),
+    ("quaver", "quaver"),
+    ("director", "director"),
+    ("skunks", "skunk"),
+    ("washery", "washeri"),
+    ("combustion", "combust"),
+    ("determined", "determin"),
+    ("caprices", "capric"),
+    ("suffers", "suffer"),
+    ("smarts", "smart"),
+    ("offing", "of"),
+    ("omnipotent", "omnipot"),
+    ("whimpering", "whimper"),
+    ("slanting", "slant"),
+    ("omnibus", "omnibu"),
+    ("coverley", "coverlei"),
+    ("sensational", "sensat"),
+    ("subsided", "subsid"),
+    ("gazed", "gaze"),
+    ("cheerful", "cheer"),
+    ("petition", "petit"),
+    ("nitrate", "nitrat"),
+    ("impertinently", "impertin"),
+    ("distressing", "distress"),
+    ("nothings", "noth"),
+    ("nourished", "nourish"),
+    ("post", "post"),
+    ("sniffs", "sniff"),
+    ("parisian", "parisian"),
+    ("tidily", "tidili"),
+    ("pats", "pat"),
+    ("aback", "aback"),
+    ("committed", "commit"),
+    ("propounding", "propound"),
+    ("currency", "currenc"),
+    ("shocked", "shock"),
+    ("ferry", "ferri"),
+    ("purchasing", "purchas"),
+    ("teacher", "teacher"),
+    ("primrose", "primros"),
+    ("darn", "darn"),
+    ("disguising", "disguis"),
+    ("market", "market"),
+    ("phenomenons", "phenomenon"),
+    ("quilt", "quilt"),
+    ("dapibus", "dapibu"),
+    ("oxen", "oxen"),
+    ("muzzle", "muzzl"),
+    ("awoided", "awoid"),
+    ("lake", "lake"),
+    ("impalpable", "impalp"),
+    ("sustenance", "susten"),
+    ("knives", "knive"),
+    ("sabe", "sabe"),
+    ("hummed", "hum"),
+    ("painful", "pain"),
+    ("hearer", "hearer"),
+    ("neutral", "neutral"),
+    ("quarrels", "quarrel"),
+    ("deferring", "defer"),
+    ("wessel", "wessel"),
+    ("oilskin", "oilskin"),
+    ("waterhouse", "waterhous"),
+    ("look", "look"),
+    ("islington", "islington"),
+    ("lures", "lure"),
+    ("firmest", "firmest"),
+    ("inclement", "inclement"),
+    ("phenomenes", "phenomen"),
+    ("ventilator", "ventil"),
+    ("bamford", "bamford"),
+    ("fourthly", "fourthli"),
+    ("turns", "turn"),
+    ("amuse", "amus"),
+    ("toy", "toi"),
+    ("composing", "compos"),
+    ("lignite", "lignit"),
+    ("growl", "growl"),
+    ("axles", "axl"),
+    ("rages", "rage"),
+    ("fledglings", "fledgl"),
+    ("trees", "tree"),
+    ("unravelling", "unravel"),
+    ("looder", "looder"),
+    ("undiminished", "undiminish"),
+    ("mace", "mace"),
+    ("unpolitely", "unpolit"),
+    ("elegancies", "eleg"),
+    ("lag", "lag"),
+    ("fogy", "fogi"),
+    ("bosh", "bosh"),
+    ("christmas", "christma"),
+    ("convulsions", "convuls"),
+    ("impend", "impend"),
+    ("inder", "inder"),
+    ("untied", "unti"),
+    ("enraged", "enrag"),
+    ("duties", "duti"),
+    ("finishes", "finish"),
+    ("boy", "boi"),
+    ("captivate", "captiv"),
+    ("impression", "impress"),
+    ("charcoaled", "charcoal"),
+    ("vulture", "vultur"),
+    ("smack", "smack"),
+    ("speculates", "specul"),
+    ("natives", "n"),
+    ("recompenses", "recompens"),
+    ("canelones", "canelon"),
+    ("wretchedest", "wretchedest"),
+    ("starve", "starv"),
+    ("locality", "local"),
+    ("ann", "ann"),
+    ("duclida", "duclida"),
+    ("completed", "complet"),
+    ("aristocracy", "aristocraci"),
+    ("handfuls", "hand"),
+    ("rainy", "raini"),
+    ("tercero", "tercero"),
+    ("poem", "poem"),
+    ("surpassing", "surpass"),
+    ("decrees", "decre"),
+    ("weepers", "weeper"),
+    ("sobbed", "sob"),
+    ("egrets", "egret"),
+    ("low", "low"),
+    ("diocese", "dioces"),
+    ("conform", "conform"),
+    ("nothin", "nothin"),
+    ("coals", "coal"),
+    ("counties", "counti"),
+    ("que", "que"),
+    ("beadles", "beadl"),
+    ("stated", "state"),
+    ("residence", "resid"),
+    ("sensation", "sensaz"),
+    ("mercie", "merci"),
+    ("gasped", "gasp"),
+    ("depraved", "deprav"),
+    ("spigwiffin", "spigwiffin"),
+    ("hearth", "hearth"),
+    ("learning", "learn"),
+    ("aggrieved", "aggriev"),
+    ("cochrane", "cochran"),
+    ("hy", "hi"),
+    ("residents", "resid"),
+    ("insects", "insect"),
+    ("ew", "ew"),
+    ("pursuers", "pursuer"),
+    ("edify", "edifi"),
+    ("exotic", "exot"),
+    ("godly", "godli"),
+    ("drooped", "droop"),
+    ("propositions", "proposit"),
+    ("jealously", "jealous"),
+    ("originated", "origin"),
+    ("disregard", "disregard"),
+    ("twenty", "twenti"),
+    ("complained", "complain"),
+    ("bonjour", "bonjour"),
+    ("shortens", "shorten"),
+    ("provides", "provid"),
+    ("trained", "train"),
+    ("retorted", "retort"),
+    ("carpenter", "carpent"),
+    ("ceaselessly", "ceaselessli"),
+    ("participate", "particip"),
+    ("infantry", "infantri"),
+    ("cos", "co"),
+    ("incense", "incens"),
+    ("deliberations", "deliberaz"),
+    ("discomposed", "discompos"),
+    ("sillier", "sillier"),
+    ("mare", "mare"),
+    ("crosses", "cross"),
+    ("juliet", "juliet"),
+    ("resisting", "resist"),
+    ("immigrants", "immigr"),
+    ("whisky", "whiski"),
+    ("splendour", "splendour"),
+    ("channel", "channel"),
+    ("stems", "stem"),
+    ("undoubtedly", "undoubtedli"),
+    ("subsidiary", "subsidiari"),
+    ("spraining", "sprain"),
+    ("tandeel", "tandeel"),
+    ("granddaughter", "granddaught"),
+    ("nightingales", "nightingal"),
+    ("preconceived", "preconceiv"),
+    ("impious", "impiou"),
+    ("itchen", "itchen"),
+    ("storybook", "storybook"),
+    ("overdoes", "overdo"),
+    ("scarecrow", "scarecrow"),
+    ("lender", "lender"),
+    ("victory", "victori"),
+    ("fastens", "fasten"),
+    ("flakes", "flake"),
+    ("gnawing", "gnaw"),
+    ("impulsiveness", "impuls"),
+    ("capybaras", "capybara"),
+    ("cowers", "cower"),
+    ("discreet", "discreet"),
+    ("ser", "ser"),
+    ("buffoonery", "buffooneri"),
+    ("malicious", "malici"),
+    ("sable", "sabl"),
+    ("whining", "whine"),
+    ("vous", "vou"),
+    ("talents", "talent"),
+    ("episode", "episod"),
+    ("shapes", "shape"),
+    ("badgered", "badger"),
+    ("thereunto", "thereunto"),
+    ("weavers", "weaver"),
+    ("escheated", "escheat"),
+    ("net", "net"),
+    ("particularly", "particularli"),
+    ("conversational", "convers"),
+    ("originates", "origin"),
+    ("opulence", "opul"),
+    ("jericho", "jericho"),
+    ("interleaved", "interleav"),
+    ("tipslark", "tipslark"),
+    ("sofas", "sofa"),
+    ("aside", "asid"),
+    ("joe", "joe"),
+    ("arrives", "arriv"),
+    ("evinced", "evinc"),
+    ("unconstitutional", "unconstitut"),
+    ("hammer", "hammer"),
+    ("grimacing", "grimac"),
+    ("seraph", "seraph"),
+    ("bombarding", "bombard"),
+    ("ultimate", "ultim"),
+    ("governments", "govern"),
+    ("benevolent", "benevol"),
+    ("veritably", "verit"),
+    ("prefer", "prefer"),
+    ("resorted", "resort"),
+    ("untroubled", "untroubl"),
+    ("pool", "pool"),
+    ("beer", "beer"),
+    ("lima", "lima"),
+    ("impressible", "impress"),
+    ("floundered", "flounder"),
+    ("altar", "altar"),
+    ("retreat", "retreat"),
+    ("smalls", "small"),
+    ("certifiket", "certifiket"),
+    ("follering", "foller"),
+    ("partings", "part"),
+    ("pacing", "pace"),
+    ("suggested", "suggest"),
+    ("holiness", "holi"),
+    ("motioning", "motion"),
+    ("invariably", "invari"),
+    ("whisperirig", "whisperirig"),
+    ("unlimited", "unlimit"),
+    ("cunicularia", "cunicularia"),
+    ("swing", "swing"),
+    ("factor", "factor"),
+    ("energy", "energi"),
+    ("paradoxus", "paradoxu"),
+    ("newest", "newest"),
+    ("unhand", "unhand"),
+    ("syllogism", "syllog"),
+    ("sportsmen", "sportsmen"),
+    ("admonitions", "admonit"),
+    ("alexander", "alexand"),
+    ("waft", "waft"),
+    ("ascendancy", "ascend"),
+    ("pipes", "pipe"),
+    ("middling", "middl"),
+    ("assisting", "assist"),
+    ("defending", "defend"),
+    ("stricter", "stricter"),
+    ("gar", "gar"),
+    ("projecting", "project"),
+    ("opined", "opin"),
+    ("blackleg", "blackleg"),
+    ("rickworth", "rickworth"),
+    ("coddle", "coddl"),
+    ("emulation", "emulaz"),
+    ("severer", "sever"),
+    ("trophies", "trophi"),
+    ("reasoned", "reason"),
+    ("snapping", "snap"),
+    ("boats", "boat"),
+    ("colonia", "colonia"),
+    ("comprehensive", "comprehens"),
+    ("mangy", "mangi"),
+    ("involvement", "involv"),
+    ("slackness", "slack"),
+    ("chattered", "chatter"),
+    ("somnum", "somnum"),
+    ("arose", "aros"),
+    ("floors", "floor"),
+    ("charmer", "charmer"),
+    ("companions", "companion"),
+    ("father", "father"),
+    ("plough", "plough"),
+    ("mammals", "mammal"),
+    ("oozes", "ooz"),
+    ("slow", "slow"),
+    ("quivered", "quiver"),
+    ("stabled", "stabl"),
+    ("bruises", "bruis"),
+    ("marketplace", "marketplac"),
+    ("censure", "censur"),
+    ("crusoe", "cruso"),
+    ("mistaken", "mistaken"),
+    ("distaste", "distast"),
+    ("patachonica", "patachonica"),
+    ("counterpoise", "counterpois"),
+    ("percolation", "percolaz"),
+    ("unwieldy", "unwieldi"),
+    ("foaming", "foam"),
+    ("leslie", "lesli"),
+    ("claws", "claw"),
+    ("alfalfa", "alfalfa"),
+    ("digression", "digress"),
+    ("enigma", "enigma"),
+    ("ironing", "iron"),
+    ("llanos", "llano"),
+    ("majorities", "major"),
+    ("sliding", "slide"),
+    ("completely", "complet"),
+    ("departs", "depart"),
+    ("move", "move"),
+    ("bobbish", "bobbish"),
+    ("tippy", "tippi"),
+    ("reasonably", "reason"),
+    ("cactus", "cactu"),
+    ("memory", "memori"),
+    ("innocently", "innoc"),
+    ("preparations", "preparaz"),
+    ("planet", "planet"),
+    ("galapagos", "galapago"),
+    ("tragical", "tragic"),
+    ("lass", "lass"),
+    ("busier", "busier"),
+    ("aesthetically", "aesthet"),
+    ("lovelier", "loveli"),
+    ("drake", "drake"),
+    ("scrapbook", "scrapbook"),
+    ("egad", "egad"),
+    ("pat", "pat"),
+    ("green", "green"),
+    ("sneezing", "sneez"),
+    ("braced", "brace"),
+    ("legislative", "legisl"),
+    ("stove", "stove"),
+    ("sinewy", "sinewi"),
+    ("inspires", "inspir"),
+    ("innkeeper", "innkeep"),
+    ("pudding", "pud"),
+    ("zaraisky", "zaraiski"),
+    ("diversions", "divers"),
+    ("papilio", "papilio"),
+    ("epicure", "epicur"),
+    ("snarl", "snarl"),
+    ("parentheses", "parenthes"),
+    ("rubber", "rubber"),
+    ("tweaking", "tweak"),
+    ("se", "se"),
+    ("bishops", "bishop"),
+    ("fogeys", "fogei"),
+    ("keine", "kein"),
+    ("jabbers", "jabber"),
+    ("saloon", "saloon"),
+    ("largest", "largest"),
+    ("execute", "execut"),
+    ("retire", "retir"),
+    ("pigeon", "pigeon"),
+    ("harse", "hars"),
+    ("hover", "hover"),
+    ("unavoidably", "unavoid"),
+    ("coalescing", "coalesc"),
+    ("wallowing", "wallow"),
+    ("as", "a"),
+    ("engrossed", "engross"),
+    ("blockheads", "blockhead"),
+    ("untranslatable", "untranslat"),
+    ("bewilderment", "bewilder"),
+    ("accompany", "accompani"),
+    ("egoist", "egoist"),
+    ("embodiment", "embodi"),
+    ("coldnesses", "cold"),
+    ("oft", "oft"),
+    ("pyre", "pyre"),
+    ("brawling", "brawl"),
+    ("tingle", "tingl"),
+    ("chases", "chase"),
+    ("revenging", "reveng"),
+    ("broader", "broader"),
+    ("speechlessly", "speechlessli"),
+    ("magellan", "magellan"),
+    ("indiscreetness", "indiscreet"),
+    ("biography", "biographi"),
+    ("caveat", "caveat"),
+    ("shuts", "shut"),
+    ("hides", "hide"),
+    ("maldonado", "maldonado"),
+    ("toothed", "tooth"),
+    ("chilipa", "chilipa"),
+    ("streamlet", "streamlet"),
+    ("indolence", "indol"),
+    ("irresolutely", "irresolut"),
+    ("milked", "milk"),
+    ("talent", "talent"),
+    ("prowl", "prowl"),
+    ("unrestrainedly", "unrestrainedli"),
+    ("comprehension", "comprehens"),
+    ("refer", "refer"),
+    ("acknowledging", "acknowledg"),
+    ("fellowship", "fellowship"),
+    ("xviii", "xviii"),
+    ("calico", "calico"),
+    ("draggletails", "draggletail"),
+    ("letting", "let"),
+    ("villainy", "villaini"),
+    ("marred", "mar"),
+    ("scoff", "scoff"),
+    ("confusing", "confus"),
+    ("ridiculous", "ridicul"),
+    ("tame", "tame"),
+    ("analysis", "analysi"),
+    ("thried", "thri"),
+    ("hoist", "hoist"),
+    ("sinews", "sinew"),
+    ("rob", "rob"),
+    ("offend", "offend"),
+    ("conditioned", "condit"),
+    ("marshalled", "marshal"),
+    ("incontestable", "incontest"),
+    ("spaniard", "spaniard"),
+    ("interrogate", "interrog"),
+    ("breaking", "break"),
+    ("regaining", "regain"),
+    ("pahia", "pahia"),
+    ("whist", "whist"),
+    ("constituting", "constitut"),
+    ("reunion", "reunion"),
+    ("trepanned", "trepan"),
+    ("shouted", "shout"),
+    ("viscid", "viscid"),
+    ("foxgloves", "foxglov"),
+    ("soldiering", "soldier"),
+    ("hand", "hand"),
+    ("dearest", "dearest"),
+    ("shies", "shi"),
+    ("waterfloods", "waterflood"),
+    ("console", "consol"),
+    ("tis", "ti"),
+    ("unlawfully", "unlawfulli"),
+    ("paradox", "paradox"),
+    ("lonely", "lone"),
+    ("lyre", "lyre"),
+    ("henrietta", "henrietta"),
+    ("abnormality", "abnorm"),
+    ("radiant", "radiant"),
+    ("patronizing", "patron"),
+    ("intuitive", "intuit"),
+    ("peter", "peter"),
+    ("sham", "sham"),
+    ("spirted", "spirt"),
+    ("diffidently", "diffid"),
+    ("wastefulness", "wast"),
+    ("handling", "handl"),
+    ("tarpaulins", "tarpaulin"),
+    ("writ", "writ"),
+    ("festoon", "festoon"),
+    ("curves", "curv"),
+    ("supposition", "supposit"),
+    ("intact", "intact"),
+    ("hens", "hen"),
+    ("strides", "stride"),
+    ("consuming", "consum"),
+    ("bequeathed", "bequeath"),
+    ("stigmatised", "stigmatis"),
+    ("linnean", "linnean"),
+    ("smaller", "smaller"),
+    ("orchideous", "orchid"),
+    ("creeps", "creep"),
+    ("burchess", "burchess"),
+    ("baptized", "baptiz"),
+    ("nolasko", "nolasko"),
+    ("problematical", "problemat"),
+    ("ceiling", "ceil"),
+    ("giddiness", "giddi"),
+    ("middlesex", "middlesex"),
+    ("cheucau", "cheucau"),
+    ("spinal", "spinal"),
+    ("honourahle", "honourahl"),
+    ("suitable", "suitabl"),
+    ("legislature", "legislatur"),
+    ("monomaniacs", "monomaniac"),
+    ("specifically", "specif"),
+    ("gather", "gather"),
+    ("presuming", "presum"),
+    ("jugglery", "juggleri"),
+    ("rice", "rice"),
+    ("mammon", "mammon"),
+    ("unnecessarily", "unnecessarili"),
+    ("prolix", "prolix"),
+    ("resslich", "resslich"),
+    ("exaggerations", "exaggeraz"),
+    ("ladyhood", "ladyhood"),
+    ("felspar", "felspar"),
+    ("appendix", "appendix"),
+    ("extravagances", "extravag"),
+    ("queens", "queen"),
+    ("villages", "villag"),
+    ("reward", "reward"),
+    ("never", "never"),
+    ("drive", "drive"),
+    ("mournfully", "mournfulli"),
+    ("cushioned", "cushion"),
+    ("iced", "ic"),
+    ("sleepy", "sleepi"),
+    ("harrowgate", "harrowg"),
+    ("equivocation", "equivocaz"),
+    ("respects", "respect"),
+    ("goggle", "goggl"),
+    ("noisier", "noisier"),
+    ("arise", "aris"),
+    ("globular", "globular"),
+    ("impudently", "impud"),
+    ("evidence", "evid"),
+    ("barbarous", "barbar"),
+    ("fiercest", "fiercest"),
+    ("illustration", "illustraz"),
+    ("beachheads", "beachhead"),
+    ("pallor", "pallor"),
+    ("thundering", "thunder"),
+    ("accruing", "accru"),
+    ("ornithologists", "ornithologist"),
+    ("consigning", "consign"),
+    ("tracked", "track"),
+    ("trim", "trim"),
+    ("solaced", "solac"),
+    ("undervalue", "undervalu"),
+    ("tucked", "tuck"),
+    ("ruddier", "ruddier"),
+    ("improvement", "improv"),
+    ("accounting", "account"),
+    ("diplomatical", "diplomat"),
+    ("treading", "tread"),
+    ("estrange", "estrang"),
+    ("riddance", "riddanc"),
+    ("relying", "re"),
+    ("oxford", "oxford"),
+    ("bega", "bega"),
+    ("unselfishness", "unselfish"),
+    ("newgate", "newgat"),
+    ("amazingly", "amazingli"),
+    ("instead", "instead"),
+    ("font", "font"),
+    ("bayham", "bayham"),
+    ("vouches", "vouch"),
+    ("missiones", "mission"),
+    ("keyne", "keyn"),
+    ("wanting", "want"),
+    ("alice", "alic"),
+    ("gallant", "gallant"),
+    ("thoughy", "thoughi"),
+    ("pugnacious", "pugnaci"),
+    ("threats", "threat"),
+    ("con", "con"),
+    ("begone", "begon"),
+    ("tongues", "tongu"),
+    ("descriptive", "descript"),
+    ("swell", "swell"),
+    ("lantern", "lantern"),
+    ("radii", "radii"),
+    ("mooth", "mooth"),
+    ("rattus", "rattu"),
+    ("systematic", "systemat"),
+    ("ruling", "rule"),
+    ("voices", "voic"),
+    ("marginal", "margin"),
+    ("helpers", "helper"),
+    ("al", "al"),
+    ("messenger", "messeng"),
+    ("auriferous", "aurifer"),
+    ("cajoled", "cajol"),
+    ("latte", "latt"),
+    ("loudest", "loudest"),
+    ("mismanagement", "mismanag"),
+    ("scarcer", "scarcer"),
+    ("novel", "novel"),
+    ("courageously", "courag"),
+    ("vitrified", "vitrifi"),
+    ("gainsaid", "gainsaid"),
+    ("fortune", "fortun"),
+    ("sided", "side"),
+    ("staff", "staff"),
+    ("furder", "furder"),
+    ("spot", "spot"),
+    ("mango", "mango"),
+    ("feeling", "feel"),
+    ("keyhole", "keyhol"),
+    ("robberies", "robberi"),
+    ("petruchio", "petruchio"),
+    ("demand", "demand"),
+    ("snevellicci", "snevellicci"),
+    ("safeguard", "safeguard"),
+    ("elater", "elat"),
+    ("expiate", "expiat"),
+    ("defendants", "defend"),
+    ("intemperate", "intemper"),
+    ("accomplishment", "accomplish"),
+    ("palpable", "palpabl"),
+    ("barcaroles", "barcarol"),
+    ("risin", "risin"),
+    ("partial", "partial"),
+    ("debilitated", "debilit"),
+    ("complanata", "complanata"),
+    ("kinsman", "kinsman"),
+    ("babylon", "babylon"),
+    ("immortality", "immort"),
+    ("intimacy", "intimaci"),
+    ("projector", "projector"),
+    ("strings", "string"),
+    ("reality", "real"),
+    ("cutlery", "cutleri"),
+    ("variously", "various"),
+    ("hounding", "hound"),
+    ("satire", "satir"),
+    ("silver", "silver"),
+    ("wednesday", "wednesdai"),
+    ("push", "push"),
+    ("loitered", "loiter"),
+    ("tire", "tire"),
+    ("fade", "fade"),
+    ("strzelecki", "strzelecki"),
+    ("clods", "clod"),
+    ("dounia", "dounia"),
+    ("arroyo", "arroyo"),
+    ("lamenting", "lament"),
+    ("seasoned", "season"),
+    ("realising", "realis"),
+    ("trap", "trap"),
+    ("braving", "brave"),
+    ("scoundrelly", "scoundrelli"),
+    ("potatoless", "potatoless"),
+    ("emigration", "emigraz"),
+    ("total", "total"),
+    ("reascended", "reascend"),
+    ("consoles", "consol"),
+    ("colonize", "colon"),
+    ("granitic", "granit"),
+    ("tiring", "tire"),
+    ("colds", "cold"),
+    ("trails", "trail"),
+    ("cadaverous", "cadaver"),
+    ("memoirs", "memoir"),
+    ("chirruping", "chirrup"),
+    ("deux", "deux"),
+    ("tero", "tero"),
+    ("folio", "folio"),
+    ("tendered", "tender"),
+    ("criticize", "critic"),
+    ("bounced", "bounc"),
+    ("withdrew", "withdrew"),
+    ("pyrenees", "pyrene"),
+    ("strip", "strip"),
+    ("bloated", "bloat"),
+    ("adoring", "ador"),
+    ("scattering", "scatter"),
+    ("curtseys", "curtsei"),
+    ("telegraphing", "telegraph"),
+    ("drinker", "drinker"),
+    ("railway", "railwai"),
+    ("gnaw", "gnaw"),
+    ("dug", "dug"),
+    ("sear", "sear"),
+    ("egypt", "egypt"),
+    ("flashes", "flash"),
+    ("robbed", "rob"),
+    ("grimaces", "grimac"),
+    ("doors", "door"),
+    ("tackle", "tackl"),
+    ("heaven", "heaven"),
+    ("horror", "horror"),
+    ("jute", "jute"),
+    ("unlovable", "unlov"),
+    ("prophet", "prophet"),
+    ("virgin", "virgin"),
+    ("vin", "vin"),
+    ("ploughing", "plough"),
+    ("incognita", "incognita"),
+    ("pincushions", "pincushion"),
+    ("wrathfully", "wrathfulli"),
+    ("defence", "defenc"),
+    ("chimbley", "chimblei"),
+    ("ardently", "ardent"),
+    ("warding", "ward"),
+    ("adjuration", "adjuraz"),
+    ("worthier", "worthier"),
+    ("extermination", "exterminaz"),
+    ("sadly", "sadli"),
+    ("kentish", "kentish"),
+    ("teens", "teen"),
+    ("judicious", "judici"),
+    ("beverley", "beverlei"),
+    ("benefit", "benefit"),
+    ("solved", "solv"),
+    ("unlooked", "unlook"),
+    ("forsaking", "forsak"),
+    ("contrivance", "contriv"),
+    ("umbilical", "umbil"),
+    ("lop", "lop"),
+    ("antics", "antic"),
+    ("poof", "poof"),
+    ("bag", "bag"),
+    ("propos", "propo"),
+    ("obvious", "obviou"),
+    ("empties", "empti"),
+    ("luxuriance", "luxuri"),
+    ("withhold", "withhold"),
+    ("e", "e"),
+    ("everett", "everett"),
+    ("witness", "wit"),
+    ("latin", "latin"),
+    ("verd", "verd"),
+    ("explosion", "explos"),
+    ("toutes", "tout"),
+    ("duster", "duster"),
+    ("summonsizzing", "summonsizz"),
+    ("larch", "larch"),
+    ("prettier", "prettier"),
+    ("pop", "pop"),
+    ("prodigieux", "prodigieux"),
+    ("from", "from"),
+    ("besmeared", "besmear"),
+    ("campany", "campani"),
+    ("illness", "il"),
+    ("fyodor", "fyodor"),
+    ("gesticulations", "gesticulaz"),
+    ("pig", "pig"),
+    ("sous", "sou"),
+    ("barefoot", "barefoot"),
+    ("bullied", "bulli"),
+    ("rheims", "rheim"),
+    ("clash", "clash"),
+    ("stability", "stabl"),
+    ("fierceness", "fierc"),
+    ("parchments", "parchment"),
+    ("enjoy", "enjoi"),
+    ("trice", "trice"),
+    ("pamper", "pamper"),
+    ("whom", "whom"),
+    ("compartments", "compart"),
+    ("rebuffed", "rebuf"),
+    ("dower", "dower"),
+    ("filthy", "filthi"),
+    ("illustrated", "illustr"),
+    ("redeemed", "redeem"),
+    ("spoiled", "spoil"),
+    ("pursuant", "pursuant"),
+    ("ud", "ud"),
+    ("twentieth", "twentieth"),
+    ("crystallized", "crystal"),
+    ("blanks", "blank"),
+    ("clayey", "clayei"),
+    ("promotion", "promot"),
+    ("sinner", "sinner"),
+    ("integrity", "integr"),
+    ("vellum", "vellum"),
+    ("malignant", "malign"),
+    ("huaraz", "huaraz"),
+    ("dissemination", "disseminaz"),
+    ("cowl", "cowl"),
+    ("barges", "barg"),
+    ("sanded", "sand"),
+    ("gavia", "gavia"),
+    ("final", "final"),
+    ("alfonso", "alfonso"),
+    ("crois", "croi"),
+    ("houri", "houri"),
+    ("beatten", "beatten"),
+    ("grudden", "grudden"),
+    ("gross", "gross"),
+    ("camarhynchus", "camarhynchu"),
+    ("antiques", "antiqu"),
+    ("providing", "provid"),
+    ("caln", "caln"),
+    ("owl", "owl"),
+    ("nudged", "nudg"),
+    ("sout", "sout"),
+    ("retrorsum", "retrorsum"),
+    ("obdurately", "obdur"),
+    ("behave", "behav"),
+    ("busybody", "busybodi"),
+    ("monceaux", "monceaux"),
+    ("silently", "silent"),
+    ("pistolling", "pistol"),
+    ("sympathies", "sympathi"),
+    ("lens", "len"),
+    ("noir", "noir"),
+    ("precepts", "precept"),
+    ("whalebones", "whalebon"),
+    ("nonsense", "nonsens"),
+    ("expect", "expect"),
+    ("tombstone", "tombston"),
+    ("tiddler", "tiddler"),
+    ("allusions", "allus"),
+    ("zarnitsyn", "zarnitsyn"),
+    ("institute", "institut"),
+    ("hea", "hea"),
+    ("clashes", "clash"),
+    ("shiny", "shini"),
+    ("witticism", "wittic"),
+    ("belongings", "belong"),
+    ("weaken", "weaken"),
+    ("dunlops", "dunlop"),
+    ("till", "till"),
+    ("multitudinous", "multitudin"),
+    ("bon", "bon"),
+    ("drivers", "driver"),
+    ("possessive", "possess"),
+    ("grinder", "grinder"),
+    ("projection", "project"),
+    ("botheration", "botheraz"),
+    ("liquidating", "liquid"),
+    ("repass", "repass"),
+    ("cane", "cane"),
+    ("expected", "expect"),
+    ("ferocity", "feroc"),
+    ("droop", "droop"),
+    ("conceited", "conceit"),
+    ("roomy", "roomi"),
+    ("willows", "willow"),
+    ("afar", "afar"),
+    ("corse", "cors"),
+    ("scent", "scent"),
+    ("babbled", "babbl"),
+    ("devil", "devil"),
+    ("romeo", "romeo"),
+    ("heiresses", "heiress"),
+    ("haired", "hair"),
+    ("arch", "arch"),
+    ("gnats", "gnat"),
+    ("squares", "squar"),
+    ("spirits", "spirit"),
+    ("forficatus", "forficatu"),
+    ("damned", "damn"),
+    ("renewing", "renew"),
+    ("sights", "sight"),
+    ("apprising", "appris"),
+    ("hostelry", "hostelri"),
+    ("educated", "educ"),
+    ("reviendra", "reviendra"),
+    ("equestrian", "equestrian"),
+    ("rectly", "rectli"),
+    ("untidy", "untidi"),
+    ("overtakes", "overtak"),
+    ("zest", "zest"),
+    ("doubt", "doubt"),
+    ("quadruple", "quadrupl"),
+    ("newark", "newark"),
+    ("shadows", "shadow"),
+    ("occidentalis", "occidentali"),
+    ("schirdel", "schirdel"),
+    ("wreathed", "wreath"),
+    ("squatters", "squatter"),
+    ("inutility", "inutil"),
+    ("probation", "probaz"),
+    ("unreasonably", "unreason"),
+    ("bit", "bit"),
+    ("operative", "oper"),
+    ("phrased", "phrase"),
+    ("abingdon", "abingdon"),
+    ("milksop", "milksop"),
+    ("saunters", "saunter"),
+    ("hereabouts", "hereabout"),
+    ("comparatively", "compar"),
+    ("interrogations", "interrogaz"),
+    ("papers", "paper"),
+    ("preordained", "preordain"),
+    ("secondary", "secondari"),
+    ("unproductive", "unproduct"),
+    ("reflected", "reflect"),
+    ("scorning", "scorn"),
+    ("caravanserai", "caravanserai"),
+    ("laxity", "laxiti"),
+    ("mistrustful", "mistrust"),
+    ("disentangles", "disentangl"),
+    ("foppish", "foppish"),
+    ("jorullo", "jorullo"),
+    ("consecutively", "consecut"),
+    ("unused", "unus"),
+    ("comfortably", "comfort"),
+    ("yearning", "yearn"),
+    ("hybernate", "hybern"),
+    ("worries", "worri"),
+    ("house", "hous"),
+    ("differently", "differ"),
+    ("upbraiding", "upbraid"),
+    ("held", "held"),
+    ("buttresses", "buttress"),
+    ("facing", "face"),
+    ("deathly", "deathli"),
+    ("david", "david"),
+    ("shreds", "shred"),
+    ("bengal", "bengal"),
+    ("consarn", "consarn"),
+    ("magazines", "magazin"),
+    ("promethean", "promethean"),
+    ("farthing", "farth"),
+    ("grammar", "grammar"),
+    ("ministerial", "ministeri"),
+    ("monomania", "monomania"),
+    ("spiked", "spike"),
+    ("flue", "flue"),
+    ("sinners", "sinner"),
+    ("dagger", "dagger"),
+    ("descendant", "descend"),
+    ("plundering", "plunder"),
+    ("children", "children"),
+    ("dirt", "dirt"),
+    ("gander", "gander"),
+    ("gladdened", "gladden"),
+    ("want", "want"),
+    ("ostentatiously", "ostentati"),
+    ("beaming", "beam"),
+    ("hastily", "hastili"),
+    ("suites", "suit"),
+    ("cot", "cot"),
+    ("oracularly", "oracularli"),
+    ("infrequent", "infrequ"),
+    ("wrongly", "wrongli"),
+    ("decision", "decis"),
+    ("mimicry", "mimicri"),
+    ("alphonse", "alphons"),
+    ("luffy", "luffi"),
+    ("tasso", "tasso"),
+    ("perverseness", "pervers"),
+    ("cordial", "cordial"),
+    ("interruption", "interrupt"),
+    ("chirruped", "chirrup"),
+    ("sparkles", "sparkl"),
+    ("mahogany", "mahogani"),
+    ("overborne", "overborn"),
+    ("dispirited", "dispirit"),
+    ("advancing", "advanc"),
+    ("koch", "koch"),
+    ("exchanged", "exchang"),
+    ("guided", "guid"),
+    ("aloe", "alo"),
+    ("jeer", "jeer"),
+    ("incensed", "incens"),
+    ("vouch", "vouch"),
+    ("buckets", "bucket"),
+    ("cumbrously", "cumbrous"),
+    ("bea", "bea"),
+    ("madam", "madam"),
+    ("placarded", "placard"),
+    ("females", "femal"),
+    ("xxiii", "xxiii"),
+    ("eager", "eager"),
+    ("thousand", "thousand"),
+    ("regal", "regal"),
+    ("obey", "obei"),
+    ("ponder", "ponder"),
+    ("panel", "panel"),
+    ("impetuosity", "impetuos"),
+    ("rollings", "roll"),
+    ("flutter", "flutter"),
+    ("scamp", "scamp"),
+    ("fruit", "fruit"),
+    ("honey", "honei"),
+    ("inaccessible", "inaccess"),
+    ("rust", "rust"),
+    ("intwining", "intwin"),
+    ("bisection", "bisect"),
+    ("easier", "easier"),
+    ("exert", "exert"),
+    ("coriaceous", "coriac"),
+    ("pastrycook", "pastrycook"),
+    ("missis", "missi"),
+    ("oesophagus", "oesophagu"),
+    ("opposition", "opposit"),
+    ("interesting", "interest"),
+    ("strained", "strain"),
+    ("miraculous", "miracul"),
+    ("readjust", "readjust"),
+    ("mince", "minc"),
+    ("midwives", "midwiv"),
+    ("inscription", "inscript"),
+    ("lentils", "lentil"),
+    ("luxuriantly", "luxuriantli"),
+    ("everlasting", "everlast"),
+    ("sawdust", "sawdust"),
+    ("blessed", "bless"),
+    ("molesting", "molest"),
+    ("scrip", "scrip"),
+    ("hypochondriacal", "hypochondriac"),
+    ("waists", "waist"),
+    ("crayons", "crayon"),
+    ("remonstrating", "remonstr"),
+    ("crossed", "cross"),
+    ("license", "licens"),
+    ("prince", "princ"),
+    ("resorts", "resort"),
+    ("shadowy", "shadowi"),
+    ("excavated", "excav"),
+    ("confusedly", "confusedli"),
+    ("peered", "peer"),
+    ("xliii", "xliii"),
+    ("trainer", "trainer"),
+    ("susan", "susan"),
+    ("amid", "amid"),
+    ("cheapside", "cheapsid"),
+    ("coddled", "coddl"),
+    ("browner", "browner"),
+    ("floor", "floor"),
+    ("noisy", "noisi"),
+    ("subdued", "subdu"),
+    ("undeviating", "undevi"),
+    ("tapis", "tapi"),
+    ("seek", "seek"),
+    ("salted", "salt"),
+    ("blundering", "blunder"),
+    ("rewashed", "rewash"),
+    ("jollity", "jolliti"),
+    ("checked", "check"),
+    ("keane", "kean"),
+    ("rebuilding", "rebuild"),
+    ("unconstitutionally", "unconstitution"),
+    ("reindeer", "reindeer"),
+    ("helmet", "helmet"),
+    ("eligible", "elig"),
+    ("twonty", "twonti"),
+    ("bethel", "bethel"),
+    ("flinching", "flinch"),
+    ("calming", "calm"),
+    ("iceland", "iceland"),
+    ("cedar", "cedar"),
+    ("ostler", "ostler"),
+    ("evaporation", "evaporaz"),
+    ("timberry", "timberri"),
+    ("leapt", "leapt"),
+    ("chastity", "chastiti"),
+    ("heathen", "heathen"),
+    ("equable", "equabl"),
+    ("dumbfoundered", "dumbfound"),
+    ("bore", "bore"),
+    ("flowers", "flower"),
+    ("samples", "sampl"),
+    ("lengthy", "lengthi"),
+    ("traitor", "traitor"),
+    ("refutation", "refutaz"),
+    ("closer", "closer"),
+    ("projects", "project"),
+    ("cockades", "cockad"),
+    ("staving", "stave"),
+    ("lambeth", "lambeth"),
+    ("attachments", "attach"),
+    ("mantelpiece", "mantelpiec"),
+    ("improve", "improv"),
+    ("taketh", "taketh"),
+    ("pentland", "pentland"),
+    ("mugs", "mug"),
+    ("gossip", "gossip"),
+    ("dictating", "dictat"),
+    ("smiles", "smile"),
+    ("prosing", "prose"),
+    ("granular", "granular"),
+    ("hillocks", "hillock"),
+    ("cant", "cant"),
+    ("barnevelts", "barnevelt"),
+    ("swindle", "swindl"),
+    ("intrusive", "intrus"),
+    ("comely", "come"),
+    ("conduct", "conduct"),
+    ("mislaid", "mislaid"),
+    ("otaheite", "otaheit"),
+    ("alike", "alik"),
+    ("botanic", "botan"),
+    ("pending", "pend"),
+    ("fence", "fenc"),
+    ("boguey", "boguei"),
+    ("cetaceous", "cetac"),
+    ("flights", "flight"),
+    ("soberly", "soberli"),
+    ("collier", "collier"),
+    ("engineering", "engin"),
+    ("caddy", "caddi"),
+    ("genius", "geniu"),
+    ("reserves", "reserv"),
+    ("influx", "influx"),
+    ("fricassee", "fricasse"),
+    ("rhododendron", "rhododendron"),
+    ("damped", "damp"),
+    ("potosi", "potosi"),
+    ("colts", "colt"),
+    ("fearful", "fear"),
+    ("divesting", "divest"),
+    ("wafers", "wafer"),
+    ("meritoriously", "meritori"),
+    ("consulted", "consult"),
+    ("scabs", "scab"),
+    ("fitzhugh", "fitzhugh"),
+    ("philos", "philo"),
+    ("piteously", "piteous"),
+    ("sensitiveness", "sensit"),
+    ("sonorous", "sonor"),
+    ("salitrales", "salitral"),
+    ("entrusted", "entrust"),
+    ("stationed", "staze"),
+    ("thighs", "thigh"),
+    ("tartar", "tartar"),
+    ("lawn", "lawn"),
+    ("nimbly", "nimbli"),
+    ("pensioner", "pension"),
+    ("healthy", "healthi"),
+    ("through", "through"),
+    ("grandfather", "grandfath"),
+    ("treats", "treat"),
+    ("defendant", "defend"),
+    ("reclaim", "reclaim"),
+    ("ordinances", "ordin"),
+    ("muslins", "muslin"),
+    ("respectively", "respect"),
+    ("interchangeable", "interchang"),
+    ("he", "he"),
+    ("deduct", "deduct"),
+    ("actuality", "actual"),
+    ("ruthlessly", "ruthlessli"),
+    ("defrayed", "defrai"),
+    ("unscratched", "unscratch"),
+    ("maddening", "madden"),
+    ("intent", "intent"),
+    ("westminster", "westminst"),
+    ("latther", "latther"),
+    ("moors", "moor"),
+    ("mercuries", "mercuri"),
+    ("barouche", "barouch"),
+    ("fireworks", "firework"),
+    ("senatorial", "senatori"),
+    ("brow", "brow"),
+    ("weave", "weav"),
+    ("counsel", "counsel"),
+    ("bishop", "bishop"),
+    ("product", "product"),
+    ("subtlety", "subtleti"),
+    ("surveying", "survei"),
+    ("graze", "graze"),
+    ("scelidotherium", "scelidotherium"),
+    ("watt", "watt"),
+    ("hobbling", "hobbl"),
+    ("bath", "bath"),
+    ("relapse", "relaps"),
+    ("unconcern", "unconcern"),
+    ("den", "den"),
+    ("hittites", "hittit"),
+    ("remedy", "remedi"),
+    ("pinning", "pin"),
+    ("penitent", "penit"),
+    ("balbi", "balbi"),
+    ("drifts", "drift"),
+    ("indolent", "indol"),
+    ("kater", "kater"),
+    ("mouths", "mouth"),
+    ("rocky", "rocki"),
+    ("pouring", "pour"),
+    ("elastic", "elast"),
+    ("immoveable", "immov"),
+    ("lounger", "lounger"),
+    ("rumble", "rumbl"),
+    ("berrid", "berrid"),
+    ("necessities", "necess"),
+    ("piper", "piper"),
+    ("respect", "respect"),
+    ("distant", "distant"),
+    ("mariano", "mariano"),
+    ("testified", "testifi"),
+    ("roubles", "roubl"),
+    ("everythink", "everythink"),
+    ("talk", "talk"),
+    ("insatiate", "insati"),
+    ("pleasures", "pleasur"),
+    ("benignly", "benignli"),
+    ("sponsors", "sponsor"),
+    ("family", "famili"),
+    ("inaudibly", "inaudibli"),
+    ("ungallant", "ungal"),
+    ("switch", "switch"),
+    ("respectful", "respect"),
+    ("molest", "molest"),
+    ("johnny", "johnni"),
+    ("hibiscus", "hibiscu"),
+    ("traveller", "travel"),
+    ("interval", "interv"),
+    ("indirect", "indirect"),
+    ("cognisant", "cognis"),
+    ("vocal", "vocal"),
+    ("cabins", "cabin"),
+    ("casements", "casement"),
+    ("reciprocating", "reciproc"),
+    ("insisted", "insist"),
+    ("ingratiate", "ingrati"),
+    ("swarm", "swarm"),
+    ("comparisons", "comparison"),
+    ("cities", "citi"),
+    ("reading", "read"),
+    ("clearly", "clearli"),
+    ("listlessness", "listless"),
+    ("degs", "deg"),
+    ("waggish", "waggish"),
+    ("shutters", "shutter"),
+    ("lancaster", "lancast"),
+    ("older", "older"),
+    ("valleys", "vallei"),
+    ("nasty", "nasti"),
+    ("infancy", "infanc"),
+    ("parlaying", "parlai"),
+    ("greyhound", "greyhound"),
+    ("hesitate", "hesit"),
+    ("warted", "wart"),
+    ("treating", "treat"),
+    ("hollo", "hollo"),
+    ("moreton", "moreton"),
+    ("coursed", "cours"),
+    ("barter", "barter"),
+    ("embroidery", "embroideri"),
+    ("gnawed", "gnaw"),
+    ("trousseau", "trousseau"),
+    ("insists", "insist"),
+    ("separation", "separaz"),
+    ("cadets", "cadet"),
+    ("wig", "wig"),
+    ("lakes", "lake"),
+    ("ivy", "ivi"),
+    ("terrier", "terrier"),
+    ("studio", "studio"),
+    ("quietness", "quiet"),
+    ("monkeys", "monkei"),
+    ("clasped", "clasp"),
+    ("buskin", "buskin"),
+    ("surface", "surfac"),
+    ("outr", "outr"),
+    ("nausea", "nausea"),
+    ("hasty", "hasti"),
+    ("injures", "injur"),
+    ("razor", "razor"),
+    ("uses", "us"),
+    ("wholesomer", "wholesom"),
+    ("terewth", "terewth"),
+    ("kenge", "keng"),
+    ("policemen", "policemen"),
+    ("deporting", "deport"),
+    ("cramped", "cramp"),
+    ("woeful", "woe"),
+    ("muddying", "muddi"),
+    ("vertebrae", "vertebra"),
+    ("guidance", "guidanc"),
+    ("unkempt", "unkempt"),
+    ("loveable", "loveabl"),
+    ("stethoscope", "stethoscop"),
+    ("inflicts", "inflict"),
+    ("sons", "son"),
+    ("loppings", "lop"),
+    ("coasts", "coast"),
+    ("downward", "downward"),
+    ("waistcoats", "waistcoat"),
+    ("blanc", "blanc"),
+    ("land", "land"),
+    ("gride", "gride"),
+    ("moist", "moist"),
+    ("drolly", "drolli"),
+    ("soundly", "soundli"),
+    ("paralysis", "paralysi"),
+    ("concoct", "concoct"),
+    ("triumvirate", "triumvir"),
+    ("blasphemer", "blasphem"),
+    ("indefinite", "indefinit"),
+    ("yachting", "yacht"),
+    ("prevail", "prevail"),
+    ("fanlight", "fanlight"),
+    ("bisecting", "bisect"),
+    ("draft", "draft"),
+    ("danced", "danc"),
+    ("freeing", "free"),
+    ("dank", "dank"),
+    ("retzch", "retzch"),
+    ("swaying", "swai"),
+    ("devise", "devis"),
+    ("heavily", "heavili"),
+    ("eyebrow", "eyebrow"),
+    ("superstitious", "superstiti"),
+    ("aspirations", "aspiraz"),
+    ("ammunition", "ammunit"),
+    ("maturer", "matur"),
+    ("mornmg", "mornmg"),
+    ("orchard", "orchard"),
+    ("rank", "rank"),
+    ("confirming", "confirm"),
+    ("shade", "shade"),
+    ("boiler", "boiler"),
+    ("calandria", "calandria"),
+    ("dra", "dra"),
+    ("spelt", "spelt"),
+    ("mariners", "marin"),
+    ("rat", "rat"),
+    ("repletion", "replet"),
+    ("grape", "grape"),
+    ("defray", "defrai"),
+    ("congealed", "congeal"),
+    ("hoots", "hoot"),
+    ("hothouse", "hothous"),
+    ("redly", "redli"),
+    ("andrey", "andrei"),
+    ("skirted", "skirt"),
+    ("developes", "develop"),
+    ("porphyry", "porphyri"),
+    ("quit", "quit"),
+    ("analogies", "analogi"),
+    ("texture", "textur"),
+    ("costly", "costli"),
+    ("dealings", "deal"),
+    ("dubious", "dubiou"),
+    ("products", "product"),
+    ("chandler", "chandler"),
+    ("helplessly", "helplessli"),
+    ("ecstasy", "ecstasi"),
+    ("incomparably", "incompar"),
+    ("countrymen", "countrymen"),
+    ("depicter", "depict"),
+    ("dash", "dash"),
+    ("xxxvi", "xxxvi"),
+    ("ruled", "rule"),
+    ("philo", "philo"),
+    ("liable", "liabl"),
+    ("gryllus", "gryllu"),
+    ("verbena", "verbena"),
+    ("unceasingly", "unceasingli"),
+    ("nobler", "nobler"),
+    ("compare", "compar"),
+    ("curtseyed", "curtsei"),
+    ("billet", "billet"),
+    ("marmalade", "marmalad"),
+    ("flitted", "flit"),
+    ("bodies", "bodi"),
+    ("conundrums", "conundrum"),
+    ("murky", "murki"),
+    ("shielding", "shield"),
+    ("disqualification", "disqualificaz"),
+    ("ikons", "ikon"),
+    ("apologize", "apolog"),
+    ("saturday", "saturdai"),
+    ("charm", "charm"),
+    ("gospel", "gospel"),
+    ("chesterfield", "chesterfield"),
+    ("halo", "halo"),
+    ("anticipating", "anticip"),
+    ("abstractedly", "abstractedli"),
+    ("wedded", "wed"),
+    ("bridling", "bridl"),
+    ("commonplace", "commonplac"),
+    ("sarcastically", "sarcast"),
+    ("discerned", "discern"),
+    ("physicking", "physick"),
+    ("legh", "legh"),
+    ("language", "languag"),
+    ("libertine", "libertin"),
+    ("outcries", "outcri"),
+    ("vines", "vine"),
+    ("exultation", "exultaz"),
+    ("cecilia", "cecilia"),
+    ("rums", "rum"),
+    ("kettle", "kettl"),
+    ("protests", "protest"),
+    ("prolonged", "prolong"),
+    ("flu", "flu"),
+    ("carpenters", "carpent"),
+    ("cab", "cab"),
+    ("dictated", "dictat"),
+    ("seul", "seul"),
+    ("colias", "colia"),
+    ("rushlight", "rushlight"),
+    ("misunderstanding", "misunderstand"),
+    ("slammons", "slammon"),
+    ("layton", "layton"),
+    ("crusades", "crusad"),
+    ("ministress", "ministress"),
+    ("risen", "risen"),
+    ("sludge", "sludg"),
+    ("passage", "passag"),
+    ("forbearance", "forbear"),
+    ("calls", "call"),
+    ("transports", "transport"),
+    ("claiming", "claim"),
+    ("several", "sever"),
+    ("ebbed", "eb"),
+    ("whispering", "whisper"),
+    ("ornamented", "ornament"),
+    ("angers", "anger"),
+    ("scheming", "scheme"),
+    ("ascend", "ascend"),
+    ("was", "wa"),
+    ("gloved", "glove"),
+    ("vestal", "vestal"),
+    ("rheumatism", "rheumat"),
+    ("predicament", "predica"),
+    ("trapped", "trap"),
+    ("rises", "rise"),
+    ("grimalkin", "grimalkin"),
+    ("mihailovsky", "mihailovski"),
+    ("polypus", "polypu"),
+    ("pavilion", "pavilion"),
+    ("charing", "chare"),
+    ("slut", "slut"),
+    ("craving", "crave"),
+    ("misers", "miser"),
+    ("hares", "hare"),
+    ("anecdote", "anecdot"),
+    ("educational", "educ"),
+    ("speculator", "specul"),
+    ("characteristics", "characterist"),
+    ("shield", "shield"),
+    ("heartlessness", "heartless"),
+    ("dregs", "dreg"),
+    ("mahlos", "mahlo"),
+    ("vicious", "viciou"),
+    ("alacrity", "alacr"),
+    ("frowned", "frown"),
+    ("nay", "nai"),
+    ("commander", "command"),
+    ("endemic", "endem"),
+    ("fount", "fount"),
+    ("banns", "bann"),
+    ("footlights", "footlight"),
+    ("rivalry", "rivalri"),
+    ("encouraging", "encourag"),
+    ("wholesomely", "wholesom"),
+    ("materially", "materi"),
+    ("uprightness", "upright"),
+    ("vultures", "vultur"),
+    ("remonstrate", "remonstr"),
+    ("degrees", "degre"),
+    ("tailor", "tailor"),
+    ("unavailing", "unavail"),
+    ("anthony", "anthoni"),
+    ("fronds", "frond"),
+    ("issued", "issu"),
+    ("titles", "titl"),
+    ("vacated", "vacat"),
+    ("nicklebys", "nicklebi"),
+    ("balustrade", "balustrad"),
+    ("notic", "notic"),
+    ("assert", "assert"),
+    ("shipbuilder", "shipbuild"),
+    ("guile", "guil"),
+    ("overrated", "overr"),
+    ("extremities", "extrem"),
+    ("articles", "articl"),
+    ("thanking", "thank"),
+    ("feebly", "feebli"),
+    ("recruit", "recruit"),
+    ("prime", "prime"),
+    ("herewith", "herewith"),
+    ("injure", "injur"),
+    ("sycophancy", "sycoph"),
+    ("yaquil", "yaquil"),
+    ("edgeways", "edgewai"),
+    ("ancestral", "ancestr"),
+    ("induce", "induc"),
+    ("initiation", "initiaz"),
+    ("gamut", "gamut"),
+    ("head", "head"),
+    ("spattered", "spatter"),
+    ("rents", "rent"),
+    ("princes", "princ"),
+    ("foolish", "foolish"),
+    ("cornered", "corner"),
+    ("qualities", "qual"),
+    ("bowled", "bowl"),
+    ("niatas", "niata"),
+    ("relationship", "relationship"),
+    ("concern", "concern"),
+    ("peevishly", "peevishli"),
+    ("insult", "insult"),
+    ("gnawer", "gnawer"),
+    ("consulting", "consult"),
+    ("exhilarating", "exhilar"),
+    ("accustoming", "accustom"),
+    ("booked", "book"),
+    ("li", "li"),
+    ("hemming", "hem"),
+    ("frenchman", "frenchman"),
+    ("reconciling", "reconcil"),
+    ("poorest", "poorest"),
+    ("truncheon", "truncheon"),
+    ("reconcile", "reconcil"),
+    ("nautical", "nautic"),
+    ("pathways", "pathwai"),
+    ("abreast", "abreast"),
+    ("maktng", "maktng"),
+    ("scarus", "scaru"),
+    ("acre", "acr"),
+    ("midshipmen", "midshipmen"),
+    ("quoy", "quoi"),
+    ("hire", "hire"),
+    ("brine", "brine"),
+    ("gneiss", "gneiss"),
+    ("administration", "administraz"),
+    ("flattened", "flatten"),
+    ("defying", "defi"),
+    ("americana", "americana"),
+    ("hidden", "hidden"),
+    ("baker", "baker"),
+    ("quillay", "quillai"),
+    ("rankness", "rank"),
+    ("scrivener", "scriven"),
+    ("pantomimist", "pantomimist"),
+    ("pecado", "pecado"),
+    ("next", "next"),
+    ("indistinctly", "indistinctli"),
+    ("desperation", "desperaz"),
+    ("irritate", "irrit"),
+    ("boarding", "board"),
+    ("moiler", "moiler"),
+    ("presupposes", "presuppos"),
+    ("abstraction", "abstract"),
+    ("resurrected", "resurrect"),
+    ("ministerialist", "ministerialist"),
+    ("billiard", "billiard"),
+    ("perfections", "perfect"),
+    ("pediment", "pediment"),
+    ("sterner", "sterner"),
+    ("extravagant", "extravag"),
+    ("sees", "see"),
+    ("es", "e"),
+    ("wakened", "waken"),
+    ("hath", "hath"),
+    ("ticking", "tick"),
+    ("unvaried", "unvari"),
+    ("romans", "roman"),
+    ("sundries", "sundri"),
+    ("sake", "sake"),
+    ("credibly", "credibli"),
+    ("patting", "pat"),
+    ("squeers", "squeer"),
+    ("beholders", "behold"),
+    ("padre", "padr"),
+    ("exeter", "exet"),
+    ("inducts", "induct"),
+    ("inertia", "inertia"),
+    ("detenined", "detenin"),
+    ("comprise", "compris"),
+    ("dangle", "dangl"),
+    ("socks", "sock"),
+    ("casara", "casara"),
+    ("distance", "distanc"),
+    ("fickleness", "fickl"),
+    ("cautions", "caution"),
+    ("needn", "needn"),
+    ("moons", "moon"),
+    ("smacking", "smack"),
+    ("carmichael", "carmichael"),
+    ("statesman", "statesman"),
+    ("leafless", "leafless"),
+    ("thenceforth", "thenceforth"),
+    ("carbonaceous", "carbonac"),
+    ("height", "height"),
+    ("credible", "credibl"),
+    ("curate", "curat"),
+    ("hanger", "hanger"),
+    ("thousandth", "thousandth"),
+    ("log", "log"),
+    ("helped", "help"),
+    ("mixtur", "mixtur"),
+    ("many", "mani"),
+    ("estancias", "estancia"),
+    ("dooble", "doobl"),
+    ("vices", "vice"),
+    ("petise", "petis"),
+    ("agave", "agav"),
+    ("decidedly", "decidedli"),
+    ("coining", "coin"),
+    ("gammoning", "gammon"),
+    ("dismal", "dismal"),
+    ("frowning", "frown"),
+    ("ivan", "ivan"),
+    ("cut", "cut"),
+    ("lends", "lend"),
+    ("earrings", "ear"),
+    ("placing", "place"),
+    ("up", "up"),
+    ("applicant", "applic"),
+    ("banana", "banana"),
+    ("vindication", "vindicaz"),
+    ("analogue", "analogu"),
+    ("steamboats", "steamboat"),
+    ("frivolous", "frivol"),
+    ("contemplate", "contempl"),
+    ("expounding", "expound"),
+    ("wormwood", "wormwood"),
+    ("construe", "constru"),
+    ("insured", "insur"),
+    ("mute", "mute"),
+    ("caresses", "caress"),
+    ("bullet", "bullet"),
+    ("ottomans", "ottoman"),
+    ("fertilizing", "fertil"),
+    ("froze", "froze"),
+    ("blighted", "blight"),
+    ("persecutors", "persecutor"),
+    ("answered", "answer"),
+    ("constituencies", "constitu"),
+    ("practitioner", "practition"),
+    ("chevy", "chevi"),
+    ("itinerant", "itiner"),
+    ("inexhaustible", "inexhaust"),
+    ("phallus", "phallu"),
+    ("inhospitality", "inhospit"),
+    ("lump", "lump"),
+    ("bewailing", "bewail"),
+    ("indicating", "indic"),
+    ("openly", "openli"),
+    ("pew", "pew"),
+    ("uncharitable", "uncharit"),
+    ("fences", "fenc"),
+    ("presenting", "present"),
+    ("superannuated", "superannu"),
+    ("hitch", "hitch"),
+    ("abdominal", "abdomin"),
+    ("appearances", "appear"),
+    ("adversary", "adversari"),
+    ("amphibious", "amphibi"),
+    ("meanwhile", "meanwhil"),
+    ("extreme", "extrem"),
+    ("curacy", "curaci"),
+    ("needful", "need"),
+    ("purest", "purest"),
+    ("rugged", "rug"),
+    ("furthest", "furthest"),
+    ("only", "onli"),
+    ("outwardly", "outwardli"),
+    ("eaters", "eater"),
+    ("mob", "mob"),
+    ("constrained", "constrain"),
+    ("contaminating", "contamin"),
+    ("hemmed", "hem"),
+    ("crummleses", "crummles"),
+    ("bear", "bear"),
+    ("joker", "joker"),
+    ("contra", "contra"),
+    ("alluding", "allud"),
+    ("overpowers", "overpow"),
+    ("esk", "esk"),
+    ("misdirections", "misdirect"),
+    ("nervure", "nervur"),
+    ("plaguing", "plagu"),
+    ("condemnation", "condemnaz"),
+    ("clytia", "clytia"),
+    ("vocalist", "vocalist"),
+    ("blanket", "blanket"),
+    ("shuddered", "shudder"),
+    ("whether", "whether"),
+    ("recipient", "recipi"),
+    ("handeder", "handed"),
+    ("affronted", "affront"),
+    ("watching", "watch"),
+    ("pitiably", "pitiabl"),
+    ("latterly", "latterli"),
+    ("strokes", "stroke"),
+    ("outstretched", "outstretch"),
+    ("theils", "theil"),
+    ("murmuringly", "murmuringli"),
+    ("bribed", "bribe"),
+    ("pedantry", "pedantri"),
+    ("sealer", "sealer"),
+    ("despairs", "despair"),
+    ("mantie", "manti"),
+    ("species", "speci"),
+    ("branchial", "branchial"),
+    ("retrospect", "retrospect"),
+    ("wisp", "wisp"),
+    ("bonne", "bonn"),
+    ("archer", "archer"),
+    ("handrail", "handrail"),
+    ("tumultuous", "tumultu"),
+    ("inadmissible", "inadmiss"),
+    ("feigning", "feign"),
+    ("undertakers", "undertak"),
+    ("hopelessly", "hopelessli"),
+    ("conglomerate", "conglomer"),
+    ("jolter", "jolter"),
+    ("stiffest", "stiffest"),
+    ("masse", "mass"),
+    ("pours", "pour"),
+    ("hiatus", "hiatu"),
+    ("twitter", "twitter"),
+    ("kimbo", "kimbo"),
+    ("dogged", "dog"),
+    ("arises", "aris"),
+    ("singed", "sing"),
+    ("maurice", "mauric"),
+    ("horny", "horni"),
+    ("disorders", "disord"),
+    ("harvest", "harvest"),
+    ("grande", "grand"),
+    ("diplomatic", "diplomat"),
+    ("screwed", "screw"),
+    ("toity", "toiti"),
+    ("faster", "faster"),
+    ("conciliation", "conciliaz"),
+    ("slipping", "slip"),
+    ("vivid", "vivid"),
+    ("unworthiness", "unworthi"),
+    ("almshouses", "almshous"),
+    ("revise", "revis"),
+    ("knag", "knag"),
+    ("persistence", "persist"),
+    ("godfeyther", "godfeyth"),
+    ("sightedness", "sighted"),
+    ("delightful", "delight"),
+    ("individual", "individu"),
+    ("mine", "mine"),
+    ("fishes", "fish"),
+    ("occupier", "occupi"),
+    ("turtles", "turtl"),
+    ("sending", "send"),
+    ("thither", "thither"),
+    ("antagonism", "antagon"),
+    ("swamp", "swamp"),
+    ("distressful", "distress"),
+    ("sing", "sing"),
+    ("fix", "fix"),
+    ("governing", "govern"),
+    ("wrongfully", "wrongfulli"),
+    ("prolongation", "prolongaz"),
+    ("chickens", "chicken"),
+    ("avoiding", "avoid"),
+    ("preference", "prefer"),
+    ("prefatory", "prefatori"),
+    ("bended", "bend"),
+    ("bathurst", "bathurst"),
+    ("berkele", "berkel"),
+    ("puffing", "puf"),
+    ("berzelius", "berzeliu"),
+    ("dunheved", "dunhev"),
+    ("knitted", "knit"),
+    ("gratful", "grat"),
+    ("equal", "equal"),
+    ("abbreviation", "abbreviaz"),
+    ("likelihood", "likelihood"),
+    ("ther", "ther"),
+    ("condense", "condens"),
+    ("wreaking", "wreak"),
+    ("boisterous", "boister"),
+    ("resoled", "resol"),
+    ("argus", "argu"),
+    ("thundery", "thunderi"),
+    ("lonesome", "lonesom"),
+    ("vultur", "vultur"),
+    ("griping", "gripe"),
+    ("osyth", "osyth"),
+    ("drugs", "drug"),
+    ("em", "em"),
+    ("piccadilly", "piccadilli"),
+    ("sternly", "sternli"),
+    ("alpine", "alpin"),
+    ("clipped", "clip"),
+    ("jungle", "jungl"),
+    ("enclosures", "enclosur"),
+    ("coarse", "coars"),
+    ("tekenika", "tekenika"),
+    ("guarantee", "guarante"),
+    ("marrying", "marri"),
+    ("arcades", "arcad"),
+    ("gove", "gove"),
+    ("impaired", "impair"),
+    ("venice", "venic"),
+    ("denunciation", "denunciaz"),
+    ("excruciatingly", "excruciatingli"),
+    ("disestablished", "disestablish"),
+    ("portico", "portico"),
+    ("savannahs", "savannah"),
+    ("footboy", "footboi"),
+    ("prisoners", "prison"),
+    ("deliberated", "deliber"),
+    ("buccaneering", "buccan"),
+    ("effrontery", "effronteri"),
+    ("playmate", "playmat"),
+    ("pattern", "pattern"),
+    ("clattered", "clatter"),
+    ("allah", "allah"),
+    ("rates", "rate"),
+    ("attested", "attest"),
+    ("proctotretus", "proctotretu"),
+    ("rheumatic", "rheumat"),
+    ("infanticide", "infanticid"),
+    ("ineffable", "ineff"),
+    ("mixture", "mixtur"),
+    ("belling", "bell"),
+    ("flourish", "flourish"),
+    ("restraints", "restraint"),
+    ("prominent", "promin"),
+    ("murderess", "murderess"),
+    ("sorts", "sort"),
+    ("earl", "earl"),
+    ("announce", "announc"),
+    ("tracing", "trace"),
+    ("brachelytra", "brachelytra"),
+    ("drivelling", "drivel"),
+    ("slicing", "slice"),
+    ("salaried", "salari"),
+    ("tight", "tight"),
+    ("fraudulent", "fraudul"),
+    ("old", "old"),
+    ("unforgiving", "unforgiv"),
+    ("disinclined", "disinclin"),
+    ("inner", "inner"),
+    ("detaches", "detach"),
+    ("daniell", "daniel"),
+    ("copying", "copi"),
+    ("cher", "cher"),
+    ("desolation", "desolaz"),
+    ("languid", "languid"),
+    ("discourage", "discourag"),
+    ("gnarled", "gnarl"),
+    ("leeward", "leeward"),
+    ("phoebe", "phoeb"),
+    ("capacities", "capac"),
+    ("augured", "augur"),
+    ("outbidden", "outbidden"),
+    ("cebrionidae", "cebrionida"),
+    ("gloss", "gloss"),
+    ("totanus", "totanu"),
+    ("threading", "thread"),
+    ("natures", "natur"),
+    ("unheeding", "unheed"),
+    ("subsiding", "subsid"),
+    ("hack", "hack"),
+    ("mm", "mm"),
+    ("unanimous", "unanim"),
+    ("pratty", "pratti"),
+    ("absolve", "absolv"),
+    ("valdes", "vald"),
+    ("ticks", "tick"),
+    ("impress", "impress"),
+    ("jest", "jest"),
+    ("elbowed", "elbow"),
+    ("lean", "lean"),
+    ("postponed", "postpon"),
+    ("linn", "linn"),
+    ("overrun", "overrun"),
+    ("remonstrance", "remonstr"),
+    ("evaporated", "evapor"),
+    ("conquering", "conquer"),
+    ("minority", "minor"),
+    ("blindly", "blindli"),
+    ("shark", "shark"),
+    ("agglutinated", "agglutin"),
+    ("tomahawked", "tomahawk"),
+    ("switzerland", "switzerland"),
+    ("orphans", "orphan"),
+    ("ingrained", "ingrain"),
+    ("apoplexy", "apoplexi"),
+    ("despond", "despond"),
+    ("incalculable", "incalcul"),
+    ("dee", "dee"),
+    ("victimized", "victim"),
+    ("casma", "casma"),
+    ("grins", "grin"),
+    ("unpleasingly", "unpleasingli"),
+    ("torments", "torment"),
+    ("salmon", "salmon"),
+    ("enticing", "entic"),
+    ("legatee", "legate"),
+    ("coodle", "coodl"),
+    ("residing", "resid"),
+    ("agin", "agin"),
+    ("vish", "vish"),
+    ("flashing", "flash"),
+    ("prognostications", "prognosticaz"),
+    ("paused", "paus"),
+    ("tender", "tender"),
+    ("misapprehended", "misapprehend"),
+    ("reinstating", "reinstat"),
+    ("augmenting", "augment"),
+    ("carpetless", "carpetless"),
+    ("drawled", "drawl"),
+    ("sitiwations", "sitiwaz"),
+    ("disparage", "disparag"),
+    ("disposition", "disposit"),
+    ("spearhead", "spearhead"),
+    ("leisure", "leisur"),
+    ("wot", "wot"),
+    ("mostly", "mostli"),
+    ("hitched", "hitch"),
+    ("cads", "cad"),
+    ("inveigled", "inveigl"),
+    ("kidnapped", "kidnap"),
+    ("predisposition", "predisposit"),
+    ("del", "del"),
+    ("knee", "knee"),
+    ("drains", "drain"),
+    ("maybe", "mayb"),
+    ("defers", "defer"),
+    ("pangs", "pang"),
+    ("spencer", "spencer"),
+    ("swallowing", "swallow"),
+    ("countrified", "countrifi"),
+    ("vesicles", "vesicl"),
+    ("northwards", "northward"),
+    ("cheaply", "cheapli"),
+    ("quedius", "quediu"),
+    ("any", "ani"),
+    ("delivering", "deliv"),
+    ("rascality", "rascal"),
+    ("deficiencies", "defici"),
+    ("tossing", "toss"),
+    ("flesh", "flesh"),
+    ("ealthiest", "ealthiest"),
+    ("overthrows", "overthrow"),
+    ("rouble", "roubl"),
+    ("gild", "gild"),
+    ("swiftest", "swiftest"),
+    ("robe", "robe"),
+    ("valour", "valour"),
+    ("rubecula", "rubecula"),
+    ("long", "long"),
+    ("hi", "hi"),
+    ("pines", "pine"),
+    ("diminished", "diminish"),
+    ("magnirostris", "magnirostri"),
+    ("lurking", "lurk"),
+    ("rummest", "rummest"),
+    ("appertained", "appertain"),
+    ("territorial", "territori"),
+    ("peat", "peat"),
+    ("walerawang", "walerawang"),
+    ("riding", "ride"),
+    ("entertains", "entertain"),
+    ("outlook", "outlook"),
+    ("fare", "fare"),
+    ("fib", "fib"),
+    ("paucity", "pauciti"),
+    ("firesides", "firesid"),
+    ("dastardly", "dastardli"),
+    ("pocket", "pocket"),
+    ("falmouth", "falmouth"),
+    ("summons", "summon"),
+    ("lilaceous", "lilac"),
+    ("ter", "ter"),
+    ("sighs", "sigh"),
+    ("muddled", "muddl"),
+    ("falling", "fall"),
+    ("brompton", "brompton"),
+    ("communion", "communion"),
+    ("foodle", "foodl"),
+    ("bedstead", "bedstead"),
+    ("scarecrows", "scarecrow"),
+    ("recognize", "recogn"),
+    ("knif", "knif"),
+    ("ungenteel", "ungenteel"),
+    ("evidenfly", "evidenfli"),
+    ("blackish", "blackish"),
+    ("conducting", "conduct"),
+    ("violets", "violet"),
+    ("blamelessness", "blameless"),
+    ("rome", "rome"),
+    ("later", "later"),
+    ("sonia", "sonia"),
+    ("despairing", "despair"),
+    ("companion", "companion"),
+    ("gammon", "gammon"),
+    ("untasted", "untast"),
+    ("girls", "girl"),
+    ("criticizing", "critic"),
+    ("feint", "feint"),
+    ("lida", "lida"),
+    ("armstrong", "armstrong"),
+    ("domiciled", "domicil"),
+    ("synetheres", "synether"),
+    ("archways", "archwai"),
+    ("vividness", "vivid"),
+    ("faithfulness", "faith"),
+    ("ensuing", "ensu"),
+    ("fortunatus", "fortunatu"),
+    ("axiom", "axiom"),
+    ("multitudes", "multitud"),
+    ("kill", "kill"),
+    ("soundness", "sound"),
+    ("sowed", "sow"),
+    ("valle", "vall"),
+    ("violated", "violat"),
+    ("doleful", "dole"),
+    ("during", "dure"),
+    ("tendinous", "tendin"),
+    ("occurrence", "occurr"),
+    ("african", "african"),
+    ("dives", "dive"),
+    ("speaker", "speaker"),
+    ("winders", "winder"),
+    ("beholds", "behold"),
+    ("proxy", "proxi"),
+    ("reverberate", "reverber"),
+    ("cox", "cox"),
+    ("hypothetical", "hypothet"),
+    ("debt", "debt"),
+    ("pritchard", "pritchard"),
+    ("ornithologist", "ornithologist"),
+    ("gatherlng", "gatherlng"),
+    ("hilariously", "hilari"),
+    ("gulled", "gull"),
+    ("pitchfork", "pitchfork"),
+    ("consistently", "consist"),
+    ("purring", "pur"),
+    ("contrarieties", "contrarieti"),
+    ("bord", "bord"),
+    ("hateful", "hate"),
+    ("amity", "amiti"),
+    ("deathless", "deathless"),
+    ("recoils", "recoil"),
+    ("destitute", "destitut"),
+    ("sleeved", "sleev"),
+    ("haymakers", "haymak"),
+    ("seafowl", "seafowl"),
+    ("iagoensis", "iagoensi"),
+    ("wingless", "wingless"),
+    ("regardless", "regardless"),
+    ("suddenness", "sudden"),
+    ("roomer", "roomer"),
+    ("edge", "edg"),
+    ("mimosa", "mimosa"),
+    ("detriment", "detriment"),
+    ("placard", "placard"),
+    ("pauper", "pauper"),
+    ("crumlinwallinwer", "crumlinwallinw"),
+    ("maccoorts", "maccoort"),
+    ("wilfully", "wilfulli"),
+    ("zelandiae", "zelandia"),
+    ("laissez", "laissez"),
+    ("curt", "curt"),
+    ("drinking", "drink"),
+    ("aboriginal", "aborigin"),
+    ("gig", "gig"),
+    ("gap", "gap"),
+    ("sunday", "sundai"),
+    ("blasts", "blast"),
+    ("recite", "recit"),
+    ("wicked", "wick"),
+    ("forgi", "forgi"),
+    ("oblivious", "oblivi"),
+    ("advertiser", "advertis"),
+    ("deformity", "deform"),
+    ("bill", "bill"),
+    ("intercession", "intercess"),
+    ("mememto", "mememto"),
+    ("fletcher", "fletcher"),
+    ("swaggerings", "swagger"),
+    ("signs", "sign"),
+    ("stumble", "stumbl"),
+    ("securities", "secur"),
+    ("lecturing", "lectur"),
+    ("dayvle", "dayvl"),
+    ("cabbages", "cabbag"),
+    ("baubles", "baubl"),
+    ("stitches", "stitch"),
+    ("unexplored", "unexplor"),
+    ("mendozinos", "mendozino"),
+    ("coins", "coin"),
+    ("fishmonger", "fishmong"),
+    ("disbelieving", "disbeliev"),
+    ("irrigation", "irrigaz"),
+    ("retouching", "retouch"),
+    ("vine", "vine"),
+    ("wasn", "wasn"),
+    ("threaten", "threaten"),
+    ("beck", "beck"),
+    ("lilies", "lili"),
+    ("ireland", "ireland"),
+    ("analyze", "analyz"),
+    ("ranks", "rank"),
+    ("headquarters", "headquart"),
+    ("discreetest", "discreetest"),
+    ("misdeeds", "misde"),
+    ("obduracy", "obduraci"),
+    ("sickening", "sicken"),
+    ("aright", "aright"),
+    ("familiarized", "familiar"),
+    ("mother", "mother"),
+    ("friendless", "friendless"),
+    ("scoundrel", "scoundrel"),
+    ("confuse", "confus"),
+    ("ove", "ov"),
+    ("medusae", "medusa"),
+    ("radishchev", "radishchev"),
+    ("beryl", "beryl"),
+    ("wordsworth", "wordsworth"),
+    ("faineant", "faineant"),
+    ("ferociously", "feroci"),
+    ("holloa", "holloa"),
+    ("harum", "harum"),
+    ("snuffling", "snuffl"),
+    ("circulars", "circular"),
+    ("sacrifices", "sacrific"),
+    ("crafty", "crafti"),
+    ("gamblers", "gambler"),
+    ("dazzle", "dazzl"),
+    ("tossings", "toss"),
+    ("lottery", "lotteri"),
+    ("gaps", "gap"),
+    ("jurymen", "jurymen"),
+    ("incomprehensible", "incomprehens"),
+    ("aerial", "aerial"),
+    ("confoundedly", "confoundedli"),
+    ("nankeen", "nankeen"),
+    ("beholden", "beholden"),
+    ("soho", "soho"),
+    ("despoblado", "despoblado"),
+    ("mound", "mound"),
+    ("dowdles", "dowdl"),
+    ("goitre", "goitr"),
+    ("thursday", "thursdai"),
+    ("clandestinely", "clandestin"),
+    ("jargonelle", "jargonell"),
+    ("flint", "flint"),
+    ("couch", "couch"),
+    ("exiled", "exil"),
+    ("hearing", "hear"),
+    ("distinguons", "distinguon"),
+    ("cumuli", "cumuli"),
+    ("similitude", "similitud"),
+    ("before", "befor"),
+    ("scripture", "scriptur"),
+    ("saving", "save"),
+    ("jellybys", "jellybi"),
+    ("flocking", "flock"),
+    ("confirmed", "confirm"),
+    ("alluvium", "alluvium"),
+    ("guests", "guest"),
+    ("johnson", "johnson"),
+    ("precipitation", "precipitaz"),
+    ("corroboration", "corroboraz"),
+    ("temporary", "temporari"),
+    ("crackling", "crackl"),
+    ("pah", "pah"),
+    ("duskier", "duskier"),
+    ("samovars", "samovar"),
+    ("immediate", "immedi"),
+    ("gravelled", "gravel"),
+    ("puppy", "puppi"),
+    ("shaved", "shave"),
+    ("questionings", "question"),
+    ("enacting", "enact"),
+    ("strange", "strang"),
+    ("instance", "instanc"),
+    ("mismanaged", "mismanag"),
+    ("translate", "translat"),
+    ("reascending", "reascend"),
+    ("throne", "throne"),
+    ("widest", "widest"),
+    ("bois", "boi"),
+    ("guava", "guava"),
+    ("reviews", "review"),
+    ("johnnie", "johnni"),
+    ("spine", "spine"),
+    ("physiol", "physiol"),
+    ("circus", "circu"),
+    ("bugged", "bug"),
+    ("gutter", "gutter"),
+    ("meridian", "meridian"),
+    ("hearts", "heart"),
+    ("redolent", "redol"),
+    ("vexing", "vex"),
+    ("zonotrichia", "zonotrichia"),
+    ("bequeath", "bequeath"),
+    ("finish", "finish"),
+    ("cheeked", "cheek"),
+    ("towel", "towel"),
+    ("gorgeousness", "gorgeous"),
+    ("oliva", "oliva"),
+    ("loans", "loan"),
+    ("infinitely", "infinit"),
+    ("yan", "yan"),
+    ("assuredly", "assuredli"),
+    ("subject", "subject"),
+    ("printers", "printer"),
+    ("rife", "rife"),
+    ("jumping", "jump"),
+    ("charcoal", "charcoal"),
+    ("unthinkable", "unthink"),
+    ("violoncello", "violoncello"),
+    ("novelties", "novelti"),
+    ("merchant", "merchant"),
+    ("thwart", "thwart"),
+    ("relishing", "relish"),
+    ("capstans", "capstan"),
+    ("raven", "raven"),
+    ("minutes", "minut"),
+    ("stabbing", "stab"),
+    ("crabbed", "crab"),
+    ("astley", "astlei"),
+    ("baby", "babi"),
+    ("pumiceous", "pumic"),
+    ("homes", "home"),
+    ("afflicting", "afflict"),
+    ("accused", "accus"),
+    ("shambled", "shambl"),
+    ("consign", "consign"),
+    ("grace", "grace"),
+    ("cabriolets", "cabriolet"),
+    ("dan", "dan"),
+    ("doodle", "doodl"),
+    ("alighted", "alight"),
+    ("aperient", "aperi"),
+    ("lund", "lund"),
+    ("segment", "segment"),
+    ("crackled", "crackl"),
+    ("thee", "thee"),
+    ("miserly", "miserli"),
+    ("stimulants", "stimul"),
+    ("hospital", "hospit"),
+    ("womanish", "womanish"),
+    ("eccentricity", "eccentr"),
+    ("snigger", "snigger"),
+    ("steer", "steer"),
+    ("transactions", "transact"),
+    ("unpardonably", "unpardon"),
+    ("fra", "fra"),
+    ("skirmishing", "skirmish"),
+    ("visible", "visibl"),
+    ("rooks", "rook"),
+    ("disdain", "disdain"),
+    ("scoriae", "scoria"),
+    ("feace", "feac"),
+    ("favourites", "favourit"),
+    ("unburdening", "unburden"),
+    ("animas", "anima"),
+    ("elucidate", "elucid"),
+    ("chestnuts", "chestnut"),
+    ("swinging", "swinging"),
+    ("victims", "victim"),
+    ("theatre", "theatr"),
+    ("whirl", "whirl"),
+    ("ruminants", "rumin"),
+    ("fower", "fower"),
+    ("filka", "filka"),
+    ("within", "within"),
+    ("disturbances", "disturb"),
+    ("nodding", "nod"),
+    ("cornfield", "cornfield"),
+    ("lassonthwaite", "lassonthwait"),
+    ("summertime", "summertim"),
+    ("disgust", "disgust"),
+    ("pigmy", "pigmi"),
+    ("pithy", "pithi"),
+    ("dullish", "dullish"),
+    ("humanity", "human"),
+    ("warts", "wart"),
+    ("impassable", "impass"),
+    ("lui", "lui"),
+    ("roger", "roger"),
+    ("fleeting", "fleet"),
+    ("diges", "dige"),
+    ("pieced", "piec"),
+    ("creeturs", "creetur"),
+    ("lineament", "lineament"),
+    ("dock", "dock"),
+    ("brogden", "brogden"),
+    ("donnys", "donni"),
+    ("jeweler", "jewel"),
+    ("wants", "want"),
+    ("confer", "confer"),
+    ("disbelieve", "disbeliev"),
+    ("prohibited", "prohibit"),
+    ("morn", "morn"),
+    ("crippled", "crippl"),
+    ("misdeed", "misde"),
+    ("yawn", "yawn"),
+    ("impatience", "impati"),
+    ("tenderness", "tender"),
+    ("specimen", "specimen"),
+    ("emerald", "emerald"),
+    ("fictions", "fiction"),
+    ("rote", "rote"),
+    ("grasps", "grasp"),
+    ("proportionate", "proportion"),
+    ("neckerchief", "neckerchief"),
+    ("webbed", "web"),
+    ("dimensions", "dimens"),
+    ("nod", "nod"),
+    ("miscreant", "miscreant"),
+    ("consideration", "consideraz"),
+    ("belied", "be"),
+    ("eine", "ein"),
+    ("link", "link"),
+    ("withered", "wither"),
+    ("bonney", "bonnei"),
+    ("expiration", "expiraz"),
+    ("cancelling", "cancel"),
+    ("unnatural", "unnatur"),
+    ("doe", "doe"),
+    ("piquet", "piquet"),
+    ("responded", "respond"),
+    ("offer", "offer"),
+    ("colorado", "colorado"),
+    ("wigmore", "wigmor"),
+    ("promoted", "promot"),
+    ("prematurely", "prematur"),
+    ("confounding", "confound"),
+    ("mannish", "mannish"),
+    ("espoused", "espous"),
+    ("servility", "servil"),
+    ("hovering", "hover"),
+    ("clothes", "cloth"),
+    ("relinquished", "relinquish"),
+    ("mastery", "masteri"),
+    ("vocation", "vocaz"),
+    ("adverted", "advert"),
+    ("cuchilla", "cuchilla"),
+    ("promontory", "promontori"),
+    ("souled", "soul"),
+    ("pears", "pear"),
+    ("climates", "climat"),
+    ("grinders", "grinder"),
+    ("fe", "fe"),
+    ("chemists", "chemist"),
+    ("forest", "forest"),
+    ("seventeen", "seventeen"),
+    ("tickets", "ticket"),
+    ("parliamentary", "parliamentari"),
+    ("nimble", "nimbl"),
+    ("proprietress", "proprietress"),
+    ("agonising", "agonis"),
+    ("effervescent", "effervesc"),
+    ("tee", "tee"),
+    ("deteriorating", "deterior"),
+    ("bends", "bend"),
+    ("afield", "afield"),
+    ("negroes", "negro"),
+    ("basked", "bask"),
+    ("urges", "urg"),
+    ("corroding", "corrod"),
+    ("devoir", "devoir"),
+    ("monumental", "monument"),
+    ("hootings", "hoot"),
+    ("dothebys", "dothebi"),
+    ("nosed", "nose"),
+    ("squallingest", "squallingest"),
+    ("blackberries", "blackberri"),
+    ("undermining", "undermin"),
+    ("essentials", "essenti"),
+    ("rachael", "rachael"),
+    ("pinafore", "pinafor"),
+    ("setten", "setten"),
+    ("tombs", "tomb"),
+    ("veterinary", "veterinari"),
+    ("schleswig", "schleswig"),
+    ("historian", "historian"),
+    ("etc", "etc"),
+    ("staircases", "staircas"),
+    ("condor", "condor"),
+    ("guessed", "guess"),
+    ("condors", "condor"),
+    ("assertion", "assert"),
+    ("nourish", "nourish"),
+    ("leafed", "leaf"),
+    ("lall", "lall"),
+    ("all", "all"),
+    ("despatch", "despatch"),
+    ("natalya", "natalya"),
+    ("seals", "seal"),
+    ("scantiness", "scanti"),
+    ("rounceweil", "rounceweil"),
+    ("founded", "found"),
+    ("bowery", "boweri"),
+    ("continuation", "continuaz"),
+    ("inhabit", "inhabit"),
+    ("lively", "live"),
+    ("heighten", "heighten"),
+    ("constable", "constabl"),
+    ("crabs", "crab"),
+    ("attracted", "attract"),
+    ("puffs", "puff"),
+    ("inconstant", "inconst"),
+    ("undertakings", "undertak"),
+    ("alleys", "allei"),
+    ("snarls", "snarl"),
+    ("standaloft", "standaloft"),
+    ("murrumbidgee", "murrumbidge"),
+    ("documentary", "documentari"),
+    ("traders", "trader"),
+    ("refractory", "refractori"),
+    ("conservatism", "conservat"),
+    ("millstone", "millston"),
+    ("barbarians", "barbarian"),
+    ("resentment", "resent"),
+    ("announcements", "announc"),
+    ("flop", "flop"),
+    ("gimlet", "gimlet"),
+    ("remind", "remind"),
+    ("sideways", "sidewai"),
+    ("tendency", "tendenc"),
+    ("figurative", "figur"),
+    ("testiness", "testi"),
+    ("enliven", "enliven"),
+    ("grieves", "griev"),
+    ("postscript", "postscript"),
+    ("lightsome", "lightsom"),
+    ("trustiest", "trustiest"),
+    ("silly", "silli"),
+    ("amost", "amost"),
+    ("fading", "fade"),
+    ("toilet", "toilet"),
+    ("lithographic", "lithograph"),
+    ("munching", "munch"),
+    ("codified", "codifi"),
+    ("castaway", "castawai"),
+    ("builder", "builder"),
+    ("imaginary", "imaginari"),
+    ("preconcerted", "preconcert"),
+    ("nosegays", "nosegai"),
+    ("son", "son"),
+    ("shabbiness", "shabbi"),
+    ("substantive", "substant"),
+    ("agricultural", "agricultur"),
+    ("stayed", "stai"),
+    ("pitchy", "pitchi"),
+    ("forthcoming", "forthcom"),
+    ("despoil", "despoil"),
+    ("et", "et"),
+    ("flattered", "flatter"),
+    ("tremblings", "trembl"),
+    ("armies", "armi"),
+    ("rotting", "rot"),
+    ("unchanging", "unchang"),
+    ("rain", "rain"),
+    ("whe", "whe"),
+    ("ripples", "rippl"),
+    ("woodland", "woodland"),
+    ("sickened", "sicken"),
+    ("deluding", "delud"),
+    ("aimlessly", "aimlessli"),
+    ("handcuff", "handcuff"),
+    ("muntlehiney", "muntlehinei"),
+    ("compose", "compos"),
+    ("waist", "waist"),
+    ("fortunately", "fortun"),
+    ("ironmasters", "ironmast"),
+    ("evokes", "evok"),
+    ("writer", "writer"),
+    ("symes", "syme"),
+    ("retention", "retent"),
+    ("prospects", "prospect"),
+    ("childhood", "childhood"),
+    ("guffaw", "guffaw"),
+    ("pitty", "pitti"),
+    ("huskily", "huskili"),
+    ("ruminant", "rumin"),
+    ("formed", "form"),
+    ("readied", "readi"),
+    ("invalids", "invalid"),
+    ("begged", "beg"),
+    ("folk", "folk"),
+    ("collation", "collaz"),
+    ("swallow", "swallow"),
+    ("fortifying", "fortifi"),
+    ("madame", "madam"),
+    ("saloons", "saloon"),
+    ("eluding", "elud"),
+    ("apprehensive", "apprehens"),
+    ("motto", "motto"),
+    ("confidantes", "confidant"),
+    ("aconcagua", "aconcagua"),
+    ("partition", "partit"),
+    ("portuguese", "portugues"),
+    ("debates", "debat"),
+    ("licensed", "licens"),
+    ("wilder", "wilder"),
+    ("phantoms", "phantom"),
+    ("obdurate", "obdur"),
+    ("disquietude", "disquietud"),
+    ("selections", "select"),
+    ("counterpart", "counterpart"),
+    ("grooving", "groov"),
+    ("poonded", "poond"),
+    ("atrocious", "atroci"),
+    ("seal", "seal"),
+    ("abound", "abound"),
+    ("help", "help"),
+    ("mercenary", "mercenari"),
+    ("slunk", "slunk"),
+    ("pieman", "pieman"),
+    ("obsolete", "obsolet"),
+    ("earwigs", "earwig"),
+    ("dandling", "dandl"),
+    ("lessened", "lessen"),
+    ("fireplace", "fireplac"),
+    ("suspiciousness", "suspici"),
+    ("scaling", "scale"),
+    ("variation", "variaz"),
+    ("unreasonable", "unreason"),
+    ("domingo", "domingo"),
+    ("teapot", "teapot"),
+    ("peccari", "peccari"),
+    ("casuistry", "casuistri"),
+    ("sacramiento", "sacramiento"),
+    ("willy", "willi"),
+    ("swainson", "swainson"),
+    ("quagmires", "quagmir"),
+    ("handled", "handl"),
+    ("attend", "attend"),
+    ("needing", "need"),
+    ("carved", "carv"),
+    ("impropriety", "improprieti"),
+    ("speculated", "specul"),
+    ("ecod", "ecod"),
+    ("walleechu", "walleechu"),
+    ("ercharged", "ercharg"),
+    ("informant", "inform"),
+    ("facilitate", "facilit"),
+    ("rocking", "rock"),
+    ("manhood", "manhood"),
+    ("towed", "tow"),
+    ("amended", "amend"),
+    ("contract", "contract"),
+    ("coquille", "coquill"),
+    ("invent", "invent"),
+    ("votaries", "votari"),
+    ("anarchy", "anarchi"),
+    ("mimicking", "mimick"),
+    ("dent", "dent"),
+    ("rests", "rest"),
+    ("wepping", "wep"),
+    ("kneels", "kneel"),
+    ("wale", "wale"),
+    ("ungracious", "ungraci"),
+    ("inconsistency", "inconsist"),
+    ("evil", "evil"),
+    ("doomsday", "doomsdai"),
+    ("deportation", "deportaz"),
+    ("hadn", "hadn"),
+    ("crupper", "crupper"),
+    ("vise", "vise"),
+    ("journey", "journei"),
+    ("disclosure", "disclosur"),
+    ("articulate", "articul"),
+    ("descanting", "descant"),
+    ("tint", "tint"),
+    ("dwells", "dwell"),
+    ("inserts", "insert"),
+    ("mourned", "mourn"),
+    ("noice", "noic"),
+    ("bead", "bead"),
+    ("gunpowder", "gunpowd"),
+    ("likes", "like"),
+    ("forbearingly", "forbearingli"),
+    ("eructans", "eructan"),
+    ("copies", "copi"),
+    ("hustled", "hustl"),
+    ("sleeve", "sleev"),
+    ("relating", "relat"),
+    ("succeeded", "succ"),
+    ("tract", "tract"),
+    ("causing", "caus"),
+    ("subtracting", "subtract"),
+    ("shielded", "shield"),
+    ("torrent", "torrent"),
+    ("sertularia", "sertularia"),
+    ("brimmy", "brimmi"),
+    ("pyrites", "pyrit"),
+    ("siliceous", "silic"),
+    ("antilles", "antill"),
+    ("aromatic", "aromat"),
+    ("squib", "squib"),
+    ("flocculent", "floccul"),
+    ("fingers", "finger"),
+    ("bargains", "bargain"),
+    ("merciful", "merci"),
+    ("inexplicable", "inexplic"),
+    ("longings", "long"),
+    ("burnished", "burnish"),
+    ("receding", "reced"),
+    ("postas", "posta"),
+    ("gloomily", "gloomili"),
+    ("stumbled", "stumbl"),
+    ("dumbly", "dumbli"),
+    ("lanky", "lanki"),
+    ("muntle", "muntl"),
+    ("pedlars", "pedlar"),
+    ("manufacturing", "manufactur"),
+    ("polluted", "pollut"),
+    ("disagreeing", "disagre"),
+    ("peculiar", "peculiar"),
+    ("ode", "od"),
+    ("extrication", "extricaz"),
+    ("telescope", "telescop"),
+    ("jostling", "jostl"),
+    ("dry", "dri"),
+    ("carrot", "carrot"),
+    ("rejoins", "rejoin"),
+    ("scholarly", "scholarli"),
+    ("refuse", "refus"),
+    ("acute", "acut"),
+    ("downstairs", "downstair"),
+    ("entertained", "entertain"),
+    ("smelted", "smelt"),
+    ("rail", "rail"),
+    ("pacific", "pacif"),
+    ("periodically", "period"),
+    ("extracting", "extract"),
+    ("button", "button"),
+    ("stratification", "stratificaz"),
+    ("transfers", "transfer"),
+    ("parliaments", "parliament"),
+    ("justifiable", "justifi"),
+    ("lacerta", "lacerta"),
+    ("manuals", "manual"),
+    ("allowable", "allow"),
+    ("emily", "emili"),
+    ("feel", "feel"),
+    ("catalogue", "catalogu"),
+    ("gazingi", "gazingi"),
+    ("ruined", "ruin"),
+    ("coop", "coop"),
+    ("globe", "globe"),
+    ("corded", "cord"),
+    ("reduction", "reduct"),
+    ("agricult", "agricult"),
+    ("ewe", "ew"),
+    ("understanding", "understand"),
+    ("exactions", "exact"),
+    ("daughter", "daughter"),
+    ("expatiate", "expati"),
+    ("borrower", "borrow"),
+    ("tolerate", "toler"),
+    ("pattered", "patter"),
+    ("messes", "mess"),
+    ("picnics", "picnic"),
+    ("costs", "cost"),
+    ("indisputably", "indisput"),
+    ("summerson", "summerson"),
+    ("garnished", "garnish"),
+    ("parthenon", "parthenon"),
+    ("packets", "packet"),
+    ("roots", "root"),
+    ("radiata", "radiata"),
+    ("buffy", "buffi"),
+    ("friendly", "friendli"),
+    ("carelessness", "careless"),
+    ("asleep", "asleep"),
+    ("necesidad", "necesidad"),
+    ("sacrificed", "sacrif"),
+    ("pardoning", "pardon"),
+    ("tougher", "tougher"),
+    ("slid", "slid"),
+    ("rejoinders", "rejoind"),
+    ("landlady", "landladi"),
+    ("flattish", "flattish"),
+    ("inventions", "invent"),
+    ("goldfinch", "goldfinch"),
+    ("prettiness", "pretti"),
+    ("fearing", "fear"),
+    ("reptile", "reptil"),
+    ("drums", "drum"),
+    ("heeled", "heel"),
+    ("pants", "pant"),
+    ("decomposing", "decompos"),
+    ("monologues", "monologu"),
+    ("belts", "belt"),
+    ("faults", "fault"),
+    ("pho", "pho"),
+    ("gog", "gog"),
+    ("empowered", "empow"),
+    ("correspondent", "correspond"),
+    ("racers", "racer"),
+    ("allegiance", "allegi"),
+    ("geese", "gees"),
+    ("theorize", "theoriz"),
+    ("apologizing", "apolog"),
+    ("whiteford", "whiteford"),
+    ("silent", "silent"),
+    ("frolic", "frolic"),
+    ("saturation", "saturaz"),
+    ("jail", "jail"),
+    ("athene", "athen"),
+    ("cesenate", "cesen"),
+    ("insinuations", "insinuaz"),
+    ("wen", "wen"),
+    ("pizzaro", "pizzaro"),
+    ("portion", "portion"),
+    ("amelioration", "amelioraz"),
+    ("barley", "barlei"),
+    ("organised", "organis"),
+    ("equalle", "equall"),
+    ("prey", "prei"),
+    ("humbugging", "humbug"),
+    ("gridiron", "gridiron"),
+    ("complacency", "complac"),
+    ("shrunk", "shrunk"),
+    ("ingenuous", "ingenu"),
+    ("dabbing", "dab"),
+    ("conjointly", "conjointli"),
+    ("indisposition", "indisposit"),
+    ("lenning", "len"),
+    ("strive", "strive"),
+    ("villarica", "villarica"),
+    ("mesh", "mesh"),
+    ("spilt", "spilt"),
+    ("congeners", "congen"),
+    ("glazed", "glaze"),
+    ("dowdy", "dowdi"),
+    ("authenticity", "authent"),
+    ("priscilla", "priscilla"),
+    ("fustian", "fustian"),
+    ("countenances", "counten"),
+    ("disconcerted", "disconcert"),
+    ("dryness", "dry"),
+    ("mikolka", "mikolka"),
+    ("peals", "peal"),
+    ("icterus", "icteru"),
+    ("strum", "strum"),
+    ("frittered", "fritter"),
+    ("wititterlys", "wititterli"),
+    ("adjuring", "adjur"),
+    ("weights", "weight"),
+    ("complying", "compli"),
+    ("outcasts", "outcast"),
+    ("steepness", "steep"),
+    ("untamed", "untam"),
+    ("palpitations", "palpitaz"),
+    ("unwillingly", "unwillingli"),
+    ("abstained", "abstain"),
+    ("dissecting", "dissect"),
+    ("greet", "greet"),
+    ("cranberry", "cranberri"),
+    ("moralised", "moralis"),
+    ("obstinacy", "obstinaci"),
+    ("bonn", "bonn"),
+    ("juicy", "juici"),
+    ("loathing", "loath"),
+    ("betimes", "betim"),
+    ("coaching", "coach"),
+    ("mint", "mint"),
+    ("sweetbread", "sweetbread"),
+    ("olfactory", "olfactori"),
+    ("dame", "dame"),
+    ("moi", "moi"),
+    ("botanical", "botan"),
+    ("dreadful", "dread"),
+    ("irtish", "irtish"),
+    ("alternations", "alternaz"),
+    ("intellects", "intellect"),
+    ("unmade", "unmad"),
+    ("blench", "blench"),
+    ("drap", "drap"),
+    ("poison", "poison"),
+    ("steepled", "steepl"),
+    ("cavalry", "cavalri"),
+    ("comfortingly", "comfortingli"),
+    ("training", "train"),
+    ("contentions", "content"),
+    ("capsule", "capsul"),
+    ("memorandum", "memorandum"),
+    ("collateral", "collater"),
+    ("indispensably", "indispens"),
+    ("partnerships", "partnership"),
+    ("fritter", "fritter"),
+    ("shower", "shower"),
+    ("cloud", "cloud"),
+    ("particles", "particl"),
+    ("plans", "plan"),
+    ("disease", "diseas"),
+    ("incur", "incur"),
+    ("goblet", "goblet"),
+    ("paunch", "paunch"),
+    ("droughts", "drought"),
+    ("publishers", "publish"),
+    ("prepense", "prepens"),
+    ("ctenomys", "ctenomi"),
+    ("incoherent", "incoher"),
+    ("waxen", "waxen"),
+    ("coarser", "coarser"),
+    ("discharge", "discharg"),
+    ("resorting", "resort"),
+    ("advances", "advanc"),
+    ("sweet", "sweet"),
+    ("foreign", "foreign"),
+    ("excused", "excus"),
+    ("utility", "util"),
+    ("egg", "egg"),
+    ("grant", "grant"),
+    ("ornamental", "ornament"),
+    ("posts", "post"),
+    ("matured", "matur"),
+    ("known", "known"),
+    ("conveyed", "convei"),
+    ("admiralty", "admiralti"),
+    ("mediterranean", "mediterranean"),
+    ("musk", "musk"),
+    ("psyche", "psych"),
+    ("unlawful", "unlaw"),
+    ("worrited", "worrit"),
+    ("shipwrecks", "shipwreck"),
+    ("flooring", "floor"),
+    ("moments", "moment"),
+    ("thoughtlessness", "thoughtless"),
+    ("peruvian", "peruvian"),
+    ("thereupon", "thereupon"),
+    ("wistfully", "wistfulli"),
+    ("exhaustless", "exhaustless"),
+    ("veracious", "veraci"),
+    ("neyver", "neyver"),
+    ("arranges", "arrang"),
+    ("mild", "mild"),
+    ("caged", "cage"),
+    ("magnitude", "magnitud"),
+    ("tucker", "tucker"),
+    ("vengeance", "vengeanc"),
+    ("volume", "volum"),
+    ("altisidora", "altisidora"),
+    ("vaulted", "vault"),
+    ("birthday", "birthdai"),
+    ("beamed", "beam"),
+    ("mangroves", "mangrov"),
+    ("albeit", "albeit"),
+    ("tumbrils", "tumbril"),
+    ("poetical", "poetic"),
+    ("parsley", "parslei"),
+    ("ballast", "ballast"),
+    ("dissatisfied", "dissatisfi"),
+    ("liberality", "liber"),
+    ("celebrated", "celebr"),
+    ("wandered", "wander"),
+    ("miserably", "miser"),
+    ("universal", "univers"),
+    ("address", "address"),
+    ("tensions", "tension"),
+    ("easter", "easter"),
+    ("callems", "callem"),
+    ("cloudy", "cloudi"),
+    ("shrinks", "shrink"),
+    ("blemishes", "blemish"),
+    ("charnel", "charnel"),
+    ("pinions", "pinion"),
+    ("evervwhere", "evervwher"),
+    ("sessions", "session"),
+    ("organ", "organ"),
+    ("perched", "perch"),
+    ("wildest", "wildest"),
+    ("ball", "ball"),
+    ("shirts", "shirt"),
+    ("assaulted", "assault"),
+    ("waggoner", "waggon"),
+    ("decency", "decenc"),
+    ("enviable", "enviabl"),
+    ("individuality", "individu"),
+    ("buey", "buei"),
+    ("labour", "labour"),
+    ("satisfies", "satisfi"),
+    ("step", "step"),
+    ("turk", "turk"),
+    ("unchildish", "unchildish"),
+    ("devising", "devis"),
+    ("craftiest", "craftiest"),
+    ("spectators", "spectat"),
+    ("fifty", "fifti"),
+    ("beg", "beg"),
+    ("rearrangements", "rearrang"),
+    ("slit", "slit"),
+    ("shorn", "shorn"),
+    ("bonitos", "bonito"),
+    ("tries", "tri"),
+    ("eccentric", "eccentr"),
+    ("best", "best"),
+    ("svirbey", "svirbei"),
+    ("intimation", "intimaz"),
+    ("siege", "sieg"),
+    ("xlii", "xlii"),
+    ("sufficiency", "suffici"),
+    ("vigour", "vigour"),
+    ("protection", "protect"),
+    ("festive", "festiv"),
+    ("delectable", "delect"),
+    ("enervated", "enerv"),
+    ("zoologically", "zoolog"),
+    ("salutation", "salutaz"),
+    ("flambeaux", "flambeaux"),
+    ("elapses", "elaps"),
+    ("buzz", "buzz"),
+    ("showy", "showi"),
+    ("abjured", "abjur"),
+    ("suns", "sun"),
+    ("adheres", "adher"),
+    ("heartsore", "heartsor"),
+    ("fitzroy", "fitzroi"),
+    ("frederick", "frederick"),
+    ("heath", "heath"),
+    ("trickles", "trickl"),
+    ("spears", "spear"),
+    ("worthlessness", "worthless"),
+    ("unexamined", "unexamin"),
+    ("millenniums", "millennium"),
+    ("emotions", "emot"),
+    ("grievances", "grievanc"),
+    ("sol", "sol"),
+    ("skittish", "skittish"),
+    ("brain", "brain"),
+    ("faithfully", "faithfulli"),
+    ("arising", "aris"),
+    ("vehemence", "vehem"),
+    ("interviews", "interview"),
+    ("appropriately", "appropri"),
+    ("dilates", "dilat"),
+    ("civility", "civil"),
+    ("overstepping", "overstep"),
+    ("lenville", "lenvill"),
+    ("mistaking", "mistak"),
+    ("turnbull", "turnbul"),
+    ("leathern", "leathern"),
+    ("repassed", "repass"),
+    ("wisely", "wise"),
+    ("congratulatory", "congratulatori"),
+    ("provokingly", "provokingli"),
+    ("newness", "new"),
+    ("imbue", "imbu"),
+    ("acut", "acut"),
+    ("captivated", "captiv"),
+    ("statuary", "statuari"),
+    ("prohibiting", "prohibit"),
+    ("mem", "mem"),
+    ("sal", "sal"),
+    ("guid", "guid"),
+    ("dispatched", "dispatch"),
+    ("skips", "skip"),
+    ("instantaneously", "instantan"),
+    ("galloping", "gallop"),
+    ("parasols", "parasol"),
+    ("pickle", "pickl"),
+    ("ou", "ou"),
+    ("pupker", "pupker"),
+    ("rows", "row"),
+    ("satirically", "satir"),
+    ("destroyers", "destroyer"),
+    ("resignations", "resignaz"),
+    ("conductor", "conductor"),
+    ("distribution", "distribut"),
+    ("wearily", "wearili"),
+    ("amang", "amang"),
+    ("cogitations", "cogitaz"),
+    ("leans", "lean"),
+    ("artillery", "artilleri"),
+    ("beautifully", "beautifulli"),
+    ("solicited", "solicit"),
+    ("label", "label"),
+    ("convention", "convent"),
+    ("dawn", "dawn"),
+    ("stooping", "stoop"),
+    ("valet", "valet"),
+    ("alerce", "alerc"),
+    ("constrain", "constrain"),
+    ("macquarie", "macquari"),
+    ("uncensorious", "uncensori"),
+    ("roseate", "roseat"),
+    ("spherical", "spheric"),
+    ("fretful", "fret"),
+    ("showery", "showeri"),
+    ("mercantile", "mercantil"),
+    ("howbeit", "howbeit"),
+    ("exchequer", "exchequ"),
+    ("stratified", "stratifi"),
+    ("dears", "dear"),
+    ("struggling", "struggl"),
+    ("commendingly", "commendingli"),
+    ("traitent", "traitent"),
+    ("laggard", "laggard"),
+    ("lieutenant", "lieuten"),
+    ("appropriateness", "appropri"),
+    ("corpulence", "corpul"),
+    ("teaspoons", "teaspoon"),
+    ("couple", "coupl"),
+    ("shyer", "shyer"),
+    ("florid", "florid"),
+    ("dislocation", "dislocaz"),
+    ("try", "tri"),
+    ("exhilaration", "exhilaraz"),
+    ("invariable", "invari"),
+    ("shrugs", "shrug"),
+    ("eighthly", "eighthli"),
+    ("muss", "muss"),
+    ("reasonableness", "reason"),
+    ("exterminated", "extermin"),
+    ("convincing", "convinc"),
+    ("riveting", "rivet"),
+    ("meteorological", "meteorolog"),
+    ("position", "posit"),
+    ("representation", "representaz"),
+    ("margate", "margat"),
+    ("perpendicularly", "perpendicularli"),
+    ("regimental", "regiment"),
+    ("sandalled", "sandal"),
+    ("feigns", "feign"),
+    ("raffaelite", "raffaelit"),
+    ("forgot", "forgot"),
+    ("faces", "face"),
+    ("answering", "answer"),
+    ("blank", "blank"),
+    ("beasts", "beast"),
+    ("capsicum", "capsicum"),
+    ("impotent", "impot"),
+    ("salt", "salt"),
+    ("quavering", "quaver"),
+    ("impure", "impur"),
+    ("hande", "hand"),
+    ("charmed", "charm"),
+    ("beards", "beard"),
+    ("kindles", "kindl"),
+    ("exchanging", "exchang"),
+    ("summary", "summari"),
+    ("inexcusable", "inexcus"),
+    ("bewildering", "bewild"),
+    ("artisan", "artisan"),
+    ("persuasively", "persuas"),
+    ("blistering", "blister"),
+    ("waiting", "wait"),
+    ("gridley", "gridlei"),
+    ("cavendish", "cavendish"),
+    ("flatterer", "flatter"),
+    ("currants", "currant"),
+    ("twelvemonths", "twelvemonth"),
+    ("entry", "entri"),
+    ("ro", "ro"),
+    ("debauchery", "debaucheri"),
+    ("athwart", "athwart"),
+    ("plagiarists", "plagiarist"),
+    ("winks", "wink"),
+    ("type", "type"),
+    ("deceased", "deceas"),
+    ("unspoken", "unspoken"),
+    ("testy", "testi"),
+    ("grigoryev", "grigoryev"),
+    ("pustules", "pustul"),
+    ("affrighted", "affright"),
+    ("boastful", "boast"),
+    ("ooze", "ooz"),
+    ("vanish", "vanish"),
+    ("horse", "hors"),
+    ("entomologist", "entomologist"),
+    ("elementary", "elementari"),
+    ("momentary", "momentari"),
+    ("pickers", "picker"),
+    ("blue", "blue"),
+    ("glen", "glen"),
+    ("cooled", "cool"),
+    ("chosen", "chosen"),
+    ("lines", "line"),
+    ("adds", "add"),
+    ("gaiety", "gaieti"),
+    ("stupefied", "stupefi"),
+    ("heirship", "heirship"),
+    ("pinch", "pinch"),
+    ("inimical", "inim"),
+    ("recourse", "recours"),
+    ("shopman", "shopman"),
+    ("unquestionably", "unquestion"),
+    ("scenes", "scene"),
+    ("expulsion", "expuls"),
+    ("foreseen", "foreseen"),
+    ("moisture", "moistur"),
+    ("septa", "septa"),
+    ("irritating", "irrit"),
+    ("eventually", "eventu"),
+    ("heaths", "heath"),
+    ("containing", "contain"),
+    ("scrambling", "scrambl"),
+    ("haggardness", "haggard"),
+    ("fronts", "front"),
+    ("irresolution", "irresolut"),
+    ("q", "q"),
+    ("approve", "approv"),
+    ("socialists", "socialist"),
+    ("sliced", "slice"),
+    ("considerations", "consideraz"),
+    ("wipe", "wipe"),
+    ("prevalent", "preval"),
+    ("moored", "moor"),
+    ("dentist", "dentist"),
+    ("hurtado", "hurtado"),
+    ("iles", "il"),
+    ("amounts", "amount"),
+    ("balloon", "balloon"),
+    ("avails", "avail"),
+    ("christian", "christian"),
+    ("scramble", "scrambl"),
+    ("sprung", "sprung"),
+    ("lordly", "lordli"),
+    ("downs", "down"),
+    ("bromelia", "bromelia"),
+    ("passable", "passabl"),
+    ("pyramids", "pyramid"),
+    ("disinclination", "disinclinaz"),
+    ("rejoice", "rejoic"),
+    ("caciques", "caciqu"),
+    ("migration", "migraz"),
+    ("fades", "fade"),
+    ("infirmities", "infirm"),
+    ("marrow", "marrow"),
+    ("illustrative", "illustr"),
+    ("milling", "mill"),
+    ("oath", "oath"),
+    ("concurs", "concur"),
+    ("lunches", "lunch"),
+    ("indifferent", "indiffer"),
+    ("sameness", "same"),
+    ("counting", "count"),
+    ("continental", "continent"),
+    ("guise", "guis"),
+    ("goloshes", "golosh"),
+    ("estimated", "estim"),
+    ("surprised", "surpris"),
+    ("hindoos", "hindoo"),
+    ("fler", "fler"),
+    ("indication", "indicaz"),
+    ("deputation", "deputaz"),
+    ("unwisely", "unwis"),
+    ("unshadowed", "unshadow"),
+    ("swosser", "swosser"),
+    ("disruption", "disrupt"),
+    ("turret", "turret"),
+    ("compliance", "complianc"),
+    ("encore", "encor"),
+    ("state", "state"),
+    ("posters", "poster"),
+    ("vocabulary", "vocabulari"),
+    ("petersburg", "petersburg"),
+    ("mustaches", "mustach"),
+    ("incline", "inclin"),
+    ("afterthought", "afterthought"),
+    ("reproaching", "reproach"),
+    ("betokened", "betoken"),
+    ("protestantism", "protestant"),
+    ("distressed", "distress"),
+    ("beauties", "beauti"),
+    ("honest", "honest"),
+    ("crimson", "crimson"),
+    ("sediments", "sediment"),
+    ("vino", "vino"),
+    ("gorges", "gorg"),
+    ("plaster", "plaster"),
+    ("arisen", "arisen"),
+    ("snoog", "snoog"),
+    ("forsaken", "forsaken"),
+    ("slaughterous", "slaughter"),
+    ("quadrupled", "quadrupl"),
+    ("legatees", "legate"),
+    ("goat", "goat"),
+    ("caprice", "capric"),
+    ("centuries", "centuri"),
+    ("aggregated", "aggreg"),
+    ("watkins", "watkin"),
+    ("harsher", "harsher"),
+    ("sinks", "sink"),
+    ("adhered", "adher"),
+    ("captured", "captur"),
+    ("smart", "smart"),
+    ("busied", "busi"),
+    ("inhabits", "inhabit"),
+    ("intensest", "intensest"),
+    ("solicitations", "solicitaz"),
+    ("hands", "hand"),
+    ("falteringly", "falteringli"),
+    ("bargain", "bargain"),
+    ("consolidate", "consolid"),
+    ("shrimps", "shrimp"),
+    ("dozing", "doze"),
+    ("mudie", "mudi"),
+    ("withi", "withi"),
+    ("inaptitude", "inaptitud"),
+    ("lampyridae", "lampyrida"),
+    ("profligacy", "profligaci"),
+    ("social", "social"),
+    ("send", "send"),
+    ("ensnared", "ensnar"),
+    ("lighthearted", "lightheart"),
+    ("sovereigns", "sovereign"),
+    ("purpose", "purpos"),
+    ("observaciones", "observacion"),
+    ("wooman", "wooman"),
+    ("discern", "discern"),
+    ("wadding", "wad"),
+    ("evenlng", "evenlng"),
+    ("incredulous", "incredul"),
+    ("thanks", "thank"),
+    ("oblivion", "oblivion"),
+    ("tubers", "tuber"),
+    ("harmful", "harm"),
+    ("nough", "nough"),
+    ("followed", "follow"),
+    ("exasperating", "exasper"),
+    ("sanctuary", "sanctuari"),
+    ("toppling", "toppl"),
+    ("thunderclap", "thunderclap"),
+    ("castle", "castl"),
+    ("twins", "twin"),
+    ("puma", "puma"),
+    ("subterranean", "subterranean"),
+    ("invigorated", "invigor"),
+    ("pestryakov", "pestryakov"),
+    ("pilot", "pilot"),
+    ("policies", "polici"),
+    ("misinformed", "misinform"),
+    ("ere", "er"),
+    ("matavai", "matavai"),
+    ("petted", "pet"),
+    ("simile", "simil"),
+    ("perfect", "perfect"),
+    ("carrie", "carri"),
+    ("moss", "moss"),
+    ("writhing", "writh"),
+    ("ayant", "ayant"),
+    ("hottentot", "hottentot"),
+    ("undersecretary", "undersecretari"),
+    ("barrington", "barrington"),
+    ("threadneedle", "threadneedl"),
+    ("punch", "punch"),
+    ("volte", "volt"),
+    ("sanger", "sanger"),
+    ("descends", "descend"),
+    ("apollo", "apollo"),
+    ("aim", "aim"),
+    ("breakers", "breaker"),
+    ("marsupial", "marsupi"),
+    ("collapsed", "collaps"),
+    ("irritability", "irrit"),
+    ("winchester", "winchest"),
+    ("vara", "vara"),
+    ("terns", "tern"),
+    ("ignoble", "ignobl"),
+    ("bridle", "bridl"),
+    ("slaved", "slave"),
+    ("kindliness", "kindli"),
+    ("widened", "widen"),
+    ("rider", "rider"),
+    ("dem", "dem"),
+    ("deeply", "deepli"),
+    ("evaporates", "evapor"),
+    ("drollery", "drolleri"),
+    ("jangling", "jangl"),
+    ("sunken", "sunken"),
+    ("discoveries", "discoveri"),
+    ("locock", "locock"),
+    ("disdained", "disdain"),
+    ("cristatus", "cristatu"),
+    ("inlets", "inlet"),
+    ("intervening", "interven"),
+    ("teeming", "teem"),
+    ("plural", "plural"),
+    ("prosecuted", "prosecut"),
+    ("cabs", "cab"),
+    ("adjustment", "adjust"),
+    ("bluntness", "blunt"),
+    ("nudge", "nudg"),
+    ("cactuses", "cactus"),
+    ("gazelles", "gazell"),
+    ("goar", "goar"),
+    ("conejos", "conejo"),
+    ("noon", "noon"),
+    ("confirmatory", "confirmatori"),
+    ("fluctuating", "fluctuat"),
+    ("extent", "extent"),
+    ("road", "road"),
+    ("silence", "silenc"),
+    ("waterfall", "waterfal"),
+    ("also", "also"),
+    ("authoress", "authoress"),
+    ("foundries", "foundri"),
+    ("repressing", "repress"),
+    ("brimming", "brim"),
+    ("bet", "bet"),
+    ("doings", "do"),
+    ("endured", "endur"),
+    ("sister", "sister"),
+    ("twist", "twist"),
+    ("dramatic", "dramat"),
+    ("extenuating", "extenu"),
+    ("perches", "perch"),
+    ("philip", "philip"),
+    ("inconveniency", "inconveni"),
+    ("comprehend", "comprehend"),
+    ("bristle", "bristl"),
+    ("serene", "seren"),
+    ("mr", "mr"),
+    ("mandioca", "mandioca"),
+    ("evade", "evad"),
+    ("turks", "turk"),
+    ("newspapers", "newspap"),
+    ("berardi", "berardi"),
+    ("gallantry", "gallantri"),
+    ("damsel", "damsel"),
+    ("bianchini", "bianchini"),
+    ("elevatory", "elevatori"),
+    ("discontended", "discontend"),
+    ("gluttons", "glutton"),
+    ("swifter", "swifter"),
+    ("withdrawal", "withdraw"),
+    ("splitting", "split"),
+    ("recuvver", "recuvv"),
+    ("confusion", "confus"),
+    ("spendthrift", "spendthrift"),
+    ("robs", "rob"),
+    ("indited", "indit"),
+    ("workmanship", "workmanship"),
+    ("stomacher", "stomach"),
+    ("practitioners", "practition"),
+    ("benumbed", "benumb"),
+    ("uncomplaining", "uncomplain"),
+    ("philosophically", "philosoph"),
+    ("pets", "pet"),
+    ("paper", "paper"),
+    ("adroit", "adroit"),
+    ("albemarle", "albemarl"),
+    ("hankering", "hanker"),
+    ("lowers", "lower"),
+    ("parasitical", "parasit"),
+    ("duchy", "duchi"),
+    ("ward", "ward"),
+    ("concord", "concord"),
+    ("filth", "filth"),
+    ("gradual", "gradual"),
+    ("custom", "custom"),
+    ("spectacles", "spectacl"),
+    ("repository", "repositori"),
+    ("tasman", "tasman"),
+    ("compassionately", "compassion"),
+    ("hymn", "hymn"),
+    ("cement", "cement"),
+    ("streams", "stream"),
+    ("byzantine", "byzantin"),
+    ("taken", "taken"),
+    ("whimsical", "whimsic"),
+    ("sympathised", "sympathis"),
+    ("exigencies", "exig"),
+    ("wounded", "wound"),
+    ("uncommon", "uncommon"),
+    ("pursuer", "pursuer"),
+    ("marketing", "market"),
+    ("fob", "fob"),
+    ("dully", "dulli"),
+    ("obtruded", "obtrud"),
+    ("sensual", "sensual"),
+    ("walker", "walker"),
+    ("anathematizing", "anathemat"),
+    ("strictly", "strictli"),
+    ("consequence", "consequ"),
+    ("resourcefulness", "resourc"),
+    ("mollify", "mollifi"),
+    ("denomination", "denominaz"),
+    ("pasted", "past"),
+    ("bougainville", "bougainvill"),
+    ("shocks", "shock"),
+    ("canvassing", "canvass"),
+    ("plums", "plum"),
+    ("swallower", "swallow"),
+    ("suckling", "suckl"),
+    ("sound", "sound"),
+    ("cleanliest", "cleanliest"),
+    ("ga", "ga"),
+    ("doin", "doin"),
+    ("ascribe", "ascrib"),
+    ("unlocks", "unlock"),
+    ("purporting", "purport"),
+    ("doubles", "doubl"),
+    ("dozens", "dozen"),
+    ("hatred", "hatr"),
+    ("ankle", "ankl"),
+    ("chameleon", "chameleon"),
+    ("links", "link"),
+    ("grosvenor", "grosvenor"),
+    ("praising", "prais"),
+    ("cell", "cell"),
+    ("visitation", "visitaz"),
+    ("el", "el"),
+    ("wakening", "waken"),
+    ("cimabue", "cimabu"),
+    ("reticules", "reticul"),
+    ("lowland", "lowland"),
+    ("conjuror", "conjuror"),
+    ("infatuated", "infatu"),
+    ("cousin", "cousin"),
+    ("implore", "implor"),
+    ("uphill", "uphil"),
+    ("satirical", "satir"),
+    ("ponies", "poni"),
+    ("magellanica", "magellanica"),
+    ("croak", "croak"),
+    ("balks", "balk"),
+    ("battlefield", "battlefield"),
+    ("cringe", "cring"),
+    ("delighted", "delight"),
+    ("subservience", "subservi"),
+    ("chubby", "chubbi"),
+    ("supervision", "supervis"),
+    ("schoolfellows", "schoolfellow"),
+    ("trading", "trade"),
+    ("rooms", "room"),
+    ("contrition", "contrit"),
+    ("fists", "fist"),
+    ("enlighten", "enlighten"),
+    ("substantiate", "substanti"),
+    ("bride", "bride"),
+    ("stipend", "stipend"),
+    ("ho", "ho"),
+    ("comic", "comic"),
+    ("exerting", "exert"),
+    ("happerton", "happerton"),
+    ("ineffectually", "ineffectu"),
+    ("headlong", "headlong"),
+    ("bond", "bond"),
+    ("narrow", "narrow"),
+    ("century", "centuri"),
+    ("reprobation", "reprobaz"),
+    ("undulations", "undulaz"),
+    ("unjoyous", "unjoyou"),
+    ("begludship", "begludship"),
+    ("nieces", "niec"),
+    ("conventional", "convent"),
+    ("looms", "loom"),
+    ("mouth", "mouth"),
+    ("lockyer", "lockyer"),
+    ("week", "week"),
+    ("nook", "nook"),
+    ("compulsory", "compulsori"),
+    ("chemins", "chemin"),
+    ("undimmed", "undim"),
+    ("fiver", "fiver"),
+    ("sinking", "sink"),
+    ("foreground", "foreground"),
+    ("mollified", "mollifi"),
+    ("millepora", "millepora"),
+    ("chirps", "chirp"),
+    ("exerts", "exert"),
+    ("hitches", "hitch"),
+    ("mihailovitch", "mihailovitch"),
+    ("rubbers", "rubber"),
+    ("solvent", "solvent"),
+    ("coomin", "coomin"),
+    ("naturalista", "naturalista"),
+    ("outerside", "outersid"),
+    ("maxillae", "maxilla"),
+    ("ashamed", "asham"),
+    ("hyacinths", "hyacinth"),
+    ("genus", "genu"),
+    ("asses", "ass"),
+    ("pimlico", "pimlico"),
+    ("cactornis", "cactorni"),
+    ("mahony", "mahoni"),
+    ("printing", "print"),
+    ("firs", "fir"),
+    ("rumpling", "rumpl"),
+    ("pretences", "pretenc"),
+    ("pervious", "perviou"),
+    ("important", "import"),
+    ("imitation", "imitaz"),
+    ("alleviate", "allevi"),
+    ("gratitude", "gratitud"),
+    ("inconvenient", "inconveni"),
+    ("obsession", "obsess"),
+    ("glanced", "glanc"),
+    ("misses", "miss"),
+    ("jaculation", "jaculaz"),
+    ("halter", "halter"),
+    ("dwellings", "dwell"),
+    ("ower", "ower"),
+    ("summing", "sum"),
+    ("passengers", "passeng"),
+    ("adventurously", "adventur"),
+    ("assay", "assai"),
+    ("caravansary", "caravansari"),
+    ("paw", "paw"),
+    ("bolter", "bolter"),
+    ("luckiest", "luckiest"),
+    ("haul", "haul"),
+    ("apologising", "apologis"),
+    ("expresses", "express"),
+    ("creating", "creat"),
+    ("wax", "wax"),
+    ("albans", "alban"),
+    ("fathoms", "fathom"),
+    ("busts", "bust"),
+    ("tropics", "tropic"),
+    ("states", "state"),
+    ("wearer", "wearer"),
+    ("celebrity", "celebr"),
+    ("outcast", "outcast"),
+    ("trust", "trust"),
+    ("comparable", "compar"),
+    ("stanch", "stanch"),
+    ("disposes", "dispos"),
+    ("tress", "tress"),
+    ("instant", "instant"),
+    ("submerged", "submerg"),
+    ("steward", "steward"),
+    ("combing", "comb"),
+    ("soften", "soften"),
+    ("bowels", "bowel"),
+    ("jeers", "jeer"),
+    ("incontestably", "incontest"),
+    ("dip", "dip"),
+    ("twopenny", "twopenni"),
+    ("wrestling", "wrestl"),
+    ("canning", "can"),
+    ("jingling", "jingl"),
+    ("spare", "spare"),
+    ("joke", "joke"),
+    ("royston", "royston"),
+    ("perceiving", "perceiv"),
+    ("rush", "rush"),
+    ("basalti", "basalti"),
+    ("weirs", "weir"),
+    ("storming", "storm"),
+    ("mope", "mope"),
+    ("jerking", "jerk"),
+    ("richard", "richard"),
+    ("haystacks", "haystack"),
+    ("plains", "plain"),
+    ("tigre", "tigr"),
+    ("gillingwater", "gillingwat"),
+    ("fights", "fight"),
+    ("gullies", "gulli"),
+    ("elapse", "elaps"),
+    ("sharing", "share"),
+    ("loveliness", "loveli"),
+    ("weekly", "weekli"),
+    ("millepois", "millepoi"),
+    ("woodwork", "woodwork"),
+    ("unbound", "unbound"),
+    ("unbearably", "unbear"),
+    ("opposite", "opposit"),
+    ("ignorance", "ignor"),
+    ("sympathizing", "sympath"),
+    ("buckingham", "buckingham"),
+    ("behoved", "behov"),
+    ("apostles", "apostl"),
+    ("interrogated", "interrog"),
+    ("scarce", "scarc"),
+    ("constance", "constanc"),
+    ("eruption", "erupt"),
+    ("simplon", "simplon"),
+    ("playbill", "playbil"),
+    ("voluminous", "volumin"),
+    ("moor", "moor"),
+    ("twirl", "twirl"),
+    ("plaything", "playth"),
+    ("ingredients", "ingredi"),
+    ("lookye", "looky"),
+    ("conceive", "conceiv"),
+    ("asparagus", "asparagu"),
+    ("deafen", "deafen"),
+    ("misdirecting", "misdirect"),
+    ("iceberg", "iceberg"),
+    ("robbers", "robber"),
+    ("nozzle", "nozzl"),
+    ("canaries", "canari"),
+    ("coolest", "coolest"),
+    ("premature", "prematur"),
+    ("slippers", "slipper"),
+    ("acceptable", "accept"),
+    ("cums", "cum"),
+    ("glowered", "glower"),
+    ("pardiggles", "pardiggl"),
+    ("saddling", "saddl"),
+    ("signify", "signifi"),
+    ("emilia", "emilia"),
+    ("fibs", "fib"),
+    ("primer", "primer"),
+    ("supplying", "suppli"),
+    ("inactivity", "inact"),
+    ("educate", "educ"),
+    ("thankful", "thank"),
+    ("shifted", "shift"),
+    ("striding", "stride"),
+    ("matrons", "matron"),
+    ("fomitch", "fomitch"),
+    ("fulfilling", "fulfil"),
+    ("undo", "undo"),
+    ("gulls", "gull"),
+    ("easiest", "easiest"),
+    ("mainspring", "mainspr"),
+    ("escort", "escort"),
+    ("headlands", "headland"),
+    ("skate", "skate"),
+    ("catalonian", "catalonian"),
+    ("ghostly", "ghostli"),
+    ("fangs", "fang"),
+    ("inaction", "inact"),
+    ("meditating", "medit"),
+    ("army", "armi"),
+    ("steeple", "steepl"),
+    ("evolutions", "evolut"),
+    ("tyrants", "tyrant"),
+    ("confidentially", "confidenti"),
+    ("geograph", "geograph"),
+    ("esquire", "esquir"),
+    ("accessible", "access"),
+    ("thirds", "third"),
+    ("repining", "repin"),
+    ("winner", "winner"),
+    ("boorly", "boorli"),
+    ("camphor", "camphor"),
+    ("chefs", "chef"),
+    ("nixt", "nixt"),
+    ("felspathic", "felspath"),
+    ("crow", "crow"),
+    ("stalks", "stalk"),
+    ("thumbs", "thumb"),
+    ("pulls", "pull"),
+    ("matilda", "matilda"),
+    ("slope", "slope"),
+    ("bosomer", "bosom"),
+    ("scars", "scar"),
+    ("effaceable", "effac"),
+    ("musingly", "musingli"),
+    ("sizes", "size"),
+    ("overstrained", "overstrain"),
+    ("squatted", "squat"),
+    ("arauco", "arauco"),
+    ("amusing", "amus"),
+    ("got", "got"),
+    ("national", "nate"),
+    ("persuade", "persuad"),
+    ("penetrating", "penetr"),
+    ("irons", "iron"),
+    ("holidays", "holidai"),
+    ("glistens", "glisten"),
+    ("bailey", "bailei"),
+    ("recitals", "recit"),
+    ("diabolical", "diabol"),
+    ("aery", "aeri"),
+    ("screwdriver", "screwdriv"),
+    ("giraffes", "giraff"),
+    ("gracefully", "gracefulli"),
+    ("cobbey", "cobbei"),
+    ("ceilings", "ceil"),
+    ("adornment", "adorn"),
+    ("sleight", "sleight"),
+    ("wily", "wili"),
+    ("thavies", "thavi"),
+    ("mode", "mode"),
+    ("ticked", "tick"),
+    ("tottered", "totter"),
+    ("drakes", "drake"),
+    ("plunging", "plung"),
+    ("methodical", "method"),
+    ("unuttered", "unutt"),
+    ("pavements", "pavement"),
+    ("patagonica", "patagonica"),
+    ("lavas", "lava"),
+    ("conservative", "conserv"),
+    ("film", "film"),
+    ("satisfactory", "satisfactori"),
+    ("would", "would"),
+    ("forbears", "forbear"),
+    ("evans", "evan"),
+    ("devious", "deviou"),
+    ("retires", "retir"),
+    ("consistent", "consist"),
+    ("wavering", "waver"),
+    ("brigand", "brigand"),
+    ("tickles", "tickl"),
+    ("methuselah", "methuselah"),
+    ("starred", "star"),
+    ("conferences", "confer"),
+    ("unlike", "unlik"),
+    ("tipsily", "tipsili"),
+    ("blights", "blight"),
+    ("involutions", "involut"),
+    ("cackle", "cackl"),
+    ("groaned", "groan"),
+    ("banishment", "banish"),
+    ("garish", "garish"),
+    ("fresh", "fresh"),
+    ("liverpool", "liverpool"),
+    ("unperturbable", "unperturb"),
+    ("signing", "sign"),
+    ("boundaries", "boundari"),
+    ("kittlitz", "kittlitz"),
+    ("overhearing", "overhear"),
+    ("winding", "wind"),
+    ("something", "someth"),
+    ("ravages", "ravag"),
+    ("bludgeons", "bludgeon"),
+    ("clenched", "clench"),
+    ("raisins", "raisin"),
+    ("steeped", "steep"),
+    ("relapsing", "relaps"),
+    ("alloyed", "alloi"),
+    ("whisker", "whisker"),
+    ("dialect", "dialect"),
+    ("system", "system"),
+    ("discussions", "discuss"),
+    ("atrocities", "atroc"),
+    ("gave", "gave"),
+    ("sucker", "sucker"),
+    ("card", "card"),
+    ("domestic", "domest"),
+    ("bases", "base"),
+    ("cribs", "crib"),
+    ("kicked", "kick"),
+    ("ejecting", "eject"),
+    ("whipster", "whipster"),
+    ("encourager", "encourag"),
+    ("betook", "betook"),
+    ("rejoices", "rejoic"),
+    ("impostures", "impostur"),
+    ("bug", "bug"),
+    ("deos", "deo"),
+    ("apate", "apat"),
+    ("mechanics", "mechan"),
+    ("ploughboy", "ploughboi"),
+    ("bundled", "bundl"),
+    ("cousinly", "cousinli"),
+    ("clothe", "cloth"),
+    ("cheerybles", "cheerybl"),
+    ("successions", "success"),
+    ("unworthy", "unworthi"),
+    ("creak", "creak"),
+    ("agitated", "agit"),
+    ("emollient", "emolli"),
+    ("unclasping", "unclasp"),
+    ("negligent", "neglig"),
+    ("tenements", "tenement"),
+    ("zoolog", "zoolog"),
+    ("smelling", "smell"),
+    ("drat", "drat"),
+    ("science", "scienc"),
+    ("edges", "edg"),
+    ("directory", "directori"),
+    ("looking", "look"),
+    ("bellow", "bellow"),
+    ("vapid", "vapid"),
+    ("embryo", "embryo"),
+    ("unequivocal", "unequivoc"),
+    ("bottles", "bottl"),
+    ("practice", "practic"),
+    ("melted", "melt"),
+    ("acting", "act"),
+    ("dismayed", "dismai"),
+    ("retract", "retract"),
+    ("felt", "felt"),
+    ("fan", "fan"),
+    ("unto", "unto"),
+    ("duplicates", "duplic"),
+    ("tory", "tori"),
+    ("flavour", "flavour"),
+    ("translucency", "transluc"),
+    ("brushes", "brush"),
+    ("holders", "holder"),
+    ("blundered", "blunder"),
+    ("scytalopus", "scytalopu"),
+    ("fattening", "fatten"),
+    ("bory", "bori"),
+    ("durst", "durst"),
+    ("gouty", "gouti"),
+    ("swarmed", "swarm"),
+    ("better", "better"),
+    ("retainers", "retain"),
+    ("shaving", "shave"),
+    ("travels", "travel"),
+    ("elegantly", "elegantli"),
+    ("planting", "plant"),
+    ("sexton", "sexton"),
+    ("ribbed", "rib"),
+    ("impoverished", "impoverish"),
+    ("repudiating", "repudi"),
+    ("irascible", "irasc"),
+    ("astonished", "astonish"),
+    ("lustreless", "lustreless"),
+    ("gaudy", "gaudi"),
+    ("cowslips", "cowslip"),
+    ("ascending", "ascend"),
+    ("attraction", "attract"),
+    ("perpetuity", "perpetu"),
+    ("aroused", "arous"),
+    ("rippled", "rippl"),
+    ("pips", "pip"),
+    ("gull", "gull"),
+    ("external", "extern"),
+    ("unprincipled", "unprincipl"),
+    ("indiaman", "indiaman"),
+    ("tights", "tight"),
+    ("furnace", "furnac"),
+    ("solicits", "solicit"),
+    ("highway", "highwai"),
+    ("symond", "symond"),
+    ("ripens", "ripen"),
+    ("entitled", "entitl"),
+    ("earnings", "earn"),
+    ("epithet", "epithet"),
+    ("awaken", "awaken"),
+    ("minding", "mind"),
+    ("diversifying", "diversifi"),
+    ("vermin", "vermin"),
+    ("pimply", "pimpli"),
+    ("hunt", "hunt"),
+    ("deter", "deter"),
+    ("forefingers", "forefing"),
+    ("advance", "advanc"),
+    ("unwell", "unwel"),
+    ("manatee", "manate"),
+    ("journ", "journ"),
+    ("lied", "li"),
+    ("settler", "settler"),
+    ("disapproving", "disapprov"),
+    ("looked", "look"),
+    ("daubeny", "daubeni"),
+    ("chuck", "chuck"),
+    ("unlocking", "unlock"),
+    ("unwound", "unwound"),
+    ("legendary", "legendari"),
+    ("loike", "loik"),
+    ("theorie", "theori"),
+    ("dirtier", "dirtier"),
+    ("guardedness", "guarded"),
+    ("reformer", "reform"),
+    ("dating", "date"),
+    ("continuously", "continu"),
+    ("ennobles", "ennobl"),
+    ("herculean", "herculean"),
+    ("incident", "incid"),
+    ("pineapple", "pineappl"),
+    ("restoratives", "restor"),
+    ("hacienda", "hacienda"),
+    ("rhyme", "rhyme"),
+    ("serpent", "serpent"),
+    ("avidity", "avid"),
+    ("badger", "badger"),
+    ("morgan", "morgan"),
+    ("cawing", "caw"),
+    ("propitiate", "propiti"),
+    ("expatriated", "expatri"),
+    ("touts", "tout"),
+    ("humdrum", "humdrum"),
+    ("whaling", "whale"),
+    ("ancient", "ancient"),
+    ("distrustfully", "distrustfulli"),
+    ("jell", "jell"),
+    ("whigs", "whig"),
+    ("community", "commun"),
+    ("revellers", "revel"),
+    ("toldos", "toldo"),
+    ("assuring", "assur"),
+    ("infamies", "infami"),
+    ("va", "va"),
+    ("divinities", "divin"),
+    ("quart", "quart"),
+    ("juggler", "juggler"),
+    ("tuesday", "tuesdai"),
+    ("tallied", "tal"),
+    ("gamester", "gamest"),
+    ("bureau", "bureau"),
+    ("eyelashes", "eyelash"),
+    ("outweigh", "outweigh"),
+    ("darkened", "darken"),
+    ("constant", "constant"),
+    ("warned", "warn"),
+    ("mantel", "mantel"),
+    ("gangway", "gangwai"),
+    ("gingerbread", "gingerbread"),
+    ("smoldering", "smolder"),
+    ("pondered", "ponder"),
+    ("attendances", "attend"),
+    ("deceive", "deceiv"),
+    ("inert", "inert"),
+    ("invisibly", "invisibli"),
+    ("venturing", "ventur"),
+    ("gingery", "gingeri"),
+    ("expound", "expound"),
+    ("posuit", "posuit"),
+    ("goldingsby", "goldingsbi"),
+    ("journeyings", "journei"),
+    ("commuuity", "commuuiti"),
+    ("frequent", "frequent"),
+    ("circuits", "circuit"),
+    ("floral", "floral"),
+    ("drudgery", "drudgeri"),
+    ("confidante", "confidant"),
+    ("scudded", "scud"),
+    ("perceptions", "percept"),
+    ("conciliate", "concili"),
+    ("waterfalls", "waterfal"),
+    ("elder", "elder"),
+    ("forked", "fork"),
+    ("appear", "appear"),
+    ("starting", "start"),
+    ("winter", "winter"),
+    ("domain", "domain"),
+    ("futile", "futil"),
+    ("ecstasies", "ecstasi"),
+    ("falconer", "falcon"),
+    ("brackish", "brackish"),
+    ("delirious", "deliri"),
+    ("hear", "hear"),
+    ("calmness", "calm"),
+    ("pumas", "puma"),
+    ("licked", "lick"),
+    ("throats", "throat"),
+    ("mortal", "mortal"),
+    ("oens", "oen"),
+    ("anathematising", "anathematis"),
+    ("straggle", "straggl"),
+    ("jolts", "jolt"),
+    ("hanged", "hang"),
+    ("alexandr", "alexandr"),
+    ("desponding", "despond"),
+    ("conveyancer", "conveyanc"),
+    ("snagsby", "snagsbi"),
+    ("stammered", "stammer"),
+    ("parried", "parri"),
+    ("repossess", "repossess"),
+    ("masculine", "masculin"),
+    ("francia", "francia"),
+    ("inanimate", "inanim"),
+    ("inferiors", "inferior"),
+    ("undergoing", "undergo"),
+    ("rigueur", "rigueur"),
+    ("cases", "case"),
+    ("fulils", "fulil"),
+    ("wholesome", "wholesom"),
+    ("effects", "effect"),
+    ("morosely", "moros"),
+    ("spaniels", "spaniel"),
+    ("dab", "dab"),
+    ("confession", "confess"),
+    ("plainest", "plainest"),
+    ("barn", "barn"),
+    ("turveydrop", "turveydrop"),
+    ("vassily", "vassili"),
+    ("impracticability", "impractic"),
+    ("herod", "herod"),
+    ("environment", "environ"),
+    ("inconceivable", "inconceiv"),
+    ("foibles", "foibl"),
+    ("fantastic", "fantast"),
+    ("strayed", "strai"),
+    ("phillippensis", "phillippensi"),
+    ("lamb", "lamb"),
+    ("refines", "refin"),
+    ("lightening", "lighten"),
+    ("sported", "sport"),
+    ("fifths", "fifth"),
+    ("reeked", "reek"),
+    ("henriette", "henriett"),
+    ("inviting", "invit"),
+    ("theirs", "their"),
+    ("w", "w"),
+    ("planariae", "planaria"),
+    ("horticultural", "horticultur"),
+    ("cabinets", "cabinet"),
+    ("patternless", "patternless"),
+    ("detour", "detour"),
+    ("positively", "posit"),
+    ("neglectful", "neglect"),
+    ("liveried", "liveri"),
+    ("bridegroom", "bridegroom"),
+    ("retirement", "retir"),
+    ("blinks", "blink"),
+    ("overnight", "overnight"),
+    ("memories", "memori"),
+    ("availing", "avail"),
+    ("bargaining", "bargain"),
+    ("incapacitated", "incapacit"),
+    ("unsuccessfully", "unsuccessfulli"),
+    ("expenses", "expens"),
+    ("accustom", "accustom"),
+    ("hailstones", "hailston"),
+    ("sire", "sire"),
+    ("scrubbed", "scrub"),
+    ("courtyard", "courtyard"),
+    ("stillest", "stillest"),
+    ("abettor", "abettor"),
+    ("native", "n"),
+    ("untimely", "untim"),
+    ("festival", "festiv"),
+    ("eighteenpenny", "eighteenpenni"),
+    ("barnard", "barnard"),
+    ("custodian", "custodian"),
+    ("nutmeg", "nutmeg"),
+    ("dizzier", "dizzier"),
+    ("wizard", "wizard"),
+    ("university", "univers"),
+    ("professed", "profess"),
+    ("refolding", "refold"),
+    ("scribe", "scribe"),
+    ("know", "know"),
+    ("hazardous", "hazard"),
+    ("notorious", "notori"),
+    ("stifle", "stifl"),
+    ("garlands", "garland"),
+    ("reflect", "reflect"),
+    ("charlie", "charli"),
+    ("milton", "milton"),
+    ("fortunes", "fortun"),
+    ("chatterer", "chatter"),
+    ("jackets", "jacket"),
+    ("horizonta", "horizonta"),
+    ("quieted", "quiet"),
+    ("exhorted", "exhort"),
+    ("wriggling", "wriggl"),
+    ("proxying", "proxi"),
+    ("certified", "certifi"),
+    ("arum", "arum"),
+    ("hero", "hero"),
+    ("costal", "costal"),
+    ("harrison", "harrison"),
+    ("condescension", "condescens"),
+    ("dots", "dot"),
+    ("affidavits", "affidavit"),
+    ("onions", "onion"),
+    ("orthodox", "orthodox"),
+    ("boding", "bode"),
+    ("storm", "storm"),
+    ("fortis", "forti"),
+    ("ark", "ark"),
+    ("superannuating", "superannu"),
+    ("reversion", "revers"),
+    ("befriended", "befriend"),
+    ("ruat", "ruat"),
+    ("clarionet", "clarionet"),
+    ("excited", "excit"),
+    ("berth", "berth"),
+    ("aimed", "aim"),
+    ("cellarage", "cellarag"),
+    ("terriers", "terrier"),
+    ("songs", "song"),
+    ("glory", "glori"),
+    ("unloading", "unload"),
+    ("conning", "con"),
+    ("ton", "ton"),
+    ("assent", "assent"),
+    ("tubercles", "tubercl"),
+    ("adams", "adam"),
+    ("underclothes", "undercloth"),
+    ("slider", "slider"),
+    ("pantomimes", "pantomim"),
+    ("wasche", "wasch"),
+    ("squarer", "squarer"),
+    ("gomez", "gomez"),
+    ("overawed", "overaw"),
+    ("stared", "stare"),
+    ("syenitic", "syenit"),
+    ("fantastically", "fantast"),
+    ("perusal", "perus"),
+    ("prompt", "prompt"),
+    ("kate", "kate"),
+    ("foregathered", "foregath"),
+    ("modesty", "modesti"),
+    ("rulers", "ruler"),
+    ("cobwebs", "cobweb"),
+    ("resenting", "resent"),
+    ("trance", "tranc"),
+    ("plaintive", "plaintiv"),
+    ("deepening", "deepen"),
+    ("hall", "hall"),
+    ("dismally", "dismal"),
+    ("pyrosma", "pyrosma"),
+    ("plague", "plagu"),
+    ("tedium", "tedium"),
+    ("yushin", "yushin"),
+    ("saddening", "sadden"),
+    ("highlanders", "highland"),
+    ("vremya", "vremya"),
+    ("deducible", "deduc"),
+    ("leaden", "leaden"),
+    ("restored", "restor"),
+    ("vision", "vision"),
+    ("animals", "anim"),
+    ("cel", "cel"),
+    ("prejudicial", "prejudici"),
+    ("groaning", "groan"),
+    ("supply", "suppli"),
+    ("milkmaid", "milkmaid"),
+    ("custodians", "custodian"),
+    ("certify", "certifi"),
+    ("ledge", "ledg"),
+    ("files", "file"),
+    ("autumn", "autumn"),
+    ("may", "mai"),
+    ("politicians", "politician"),
+    ("obelisk", "obelisk"),
+    ("penitents", "penit"),
+    ("arbutus", "arbutu"),
+    ("disavowal", "disavow"),
+    ("slightly", "slightli"),
+    ("superinduced", "superinduc"),
+    ("monstrous", "monstrou"),
+    ("believe", "believ"),
+    ("situate", "situat"),
+    ("chicken", "chicken"),
+    ("tigress", "tigress"),
+    ("hazard", "hazard"),
+    ("trampling", "trampl"),
+    ("meets", "meet"),
+    ("colourless", "colourless"),
+    ("ounce", "ounc"),
+    ("throw", "throw"),
+    ("barred", "bar"),
+    ("result", "result"),
+    ("trichodesmium", "trichodesmium"),
+    ("chilicauquen", "chilicauquen"),
+    ("prohibition", "prohibit"),
+    ("conquer", "conquer"),
+    ("opinlon", "opinlon"),
+    ("rios", "rio"),
+    ("reducidos", "reducido"),
+    ("terminated", "termin"),
+    ("woolsack", "woolsack"),
+    ("liveliness", "liveli"),
+    ("elephantine", "elephantin"),
+    ("dotage", "dotag"),
+    ("sort", "sort"),
+    ("eminence", "emin"),
+    ("identified", "identifi"),
+    ("atolls", "atol"),
+    ("portrayed", "portrai"),
+    ("attributable", "attribut"),
+    ("torturer", "tortur"),
+    ("abruptly", "abruptli"),
+    ("humorous", "humor"),
+    ("unaltered", "unalt"),
+    ("namely", "name"),
+    ("shrieks", "shriek"),
+    ("pygmy", "pygmi"),
+    ("chain", "chain"),
+    ("radiance", "radianc"),
+    ("hit", "hit"),
+    ("equipment", "equip"),
+    ("relates", "relat"),
+    ("repeat", "repeat"),
+    ("cincinnatuses", "cincinnatus"),
+    ("yorkshire", "yorkshir"),
+    ("croaked", "croak"),
+    ("spirited", "spirit"),
+    ("hardening", "harden"),
+    ("freemasonry", "freemasonri"),
+    ("bristles", "bristl"),
+    ("obscuring", "obscur"),
+    ("disenchanted", "disench"),
+    ("indolently", "indol"),
+    ("willing", "will"),
+    ("parish", "parish"),
+    ("dixon", "dixon"),
+    ("twitted", "twit"),
+    ("almanacs", "almanac"),
+    ("peltirogus", "peltirogu"),
+    ("facetiously", "faceti"),
+    ("briefly", "briefli"),
+    ("forsake", "forsak"),
+    ("pause", "paus"),
+    ("such", "such"),
+    ("unfaithfulness", "unfaith"),
+    ("please", "pleas"),
+    ("clinking", "clink"),
+    ("nuisance", "nuisanc"),
+    ("deleterious", "deleteri"),
+    ("endeared", "endear"),
+    ("acquaints", "acquaint"),
+    ("sheer", "sheer"),
+    ("allegories", "allegori"),
+    ("straight", "straight"),
+    ("persisted", "persist"),
+    ("hide", "hide"),
+    ("suavity", "suaviti"),
+    ("fermenting", "ferment"),
+    ("hymns", "hymn"),
+    ("oscillates", "oscil"),
+    ("manifestations", "manifestaz"),
+    ("longitudinally", "longitudin"),
+    ("swine", "swine"),
+    ("freezes", "freez"),
+    ("elber", "elber"),
+    ("cristianos", "cristiano"),
+    ("timour", "timour"),
+    ("staggered", "stagger"),
+    ("epileptic", "epilept"),
+    ("appiness", "appi"),
+    ("clotted", "clot"),
+    ("retraction", "retract"),
+    ("fills", "fill"),
+    ("garnish", "garnish"),
+    ("quale", "qual"),
+    ("snares", "snare"),
+    ("predict", "predict"),
+    ("clumps", "clump"),
+    ("cumber", "cumber"),
+    ("breakin", "breakin"),
+    ("veiled", "veil"),
+    ("separates", "separ"),
+    ("pestered", "pester"),
+    ("spoonbill", "spoonbil"),
+    ("rancour", "rancour"),
+    ("artilleryman", "artilleryman"),
+    ("docks", "dock"),
+    ("sightseer", "sightseer"),
+    ("ephemeral", "ephemer"),
+    ("hostess", "hostess"),
+    ("chop", "chop"),
+    ("elope", "elop"),
+    ("pinnacles", "pinnacl"),
+    ("ago", "ago"),
+    ("gothlands", "gothland"),
+    ("skittles", "skittl"),
+    ("dro", "dro"),
+    ("confided", "confid"),
+    ("butcher", "butcher"),
+    ("gemmules", "gemmul"),
+    ("whic", "whic"),
+    ("tyrant", "tyrant"),
+    ("pearly", "pearli"),
+    ("acknowledge", "acknowledg"),
+    ("rode", "rode"),
+    ("prestige", "prestig"),
+    ("revelations", "revelaz"),
+    ("is", "i"),
+    ("inflexible", "inflex"),
+    ("sucked", "suck"),
+    ("liv", "liv"),
+    ("angler", "angler"),
+    ("messiah", "messiah"),
+    ("destroying", "destroi"),
+    ("saviour", "saviour"),
+    ("considerable", "consider"),
+    ("vapidity", "vapid"),
+    ("married", "marri"),
+    ("yam", "yam"),
+    ("westwood", "westwood"),
+    ("patronized", "patron"),
+    ("toasting", "toast"),
+    ("urn", "urn"),
+    ("chairing", "chair"),
+    ("sleep", "sleep"),
+    ("rim", "rim"),
+    ("sounded", "sound"),
+    ("largely", "larg"),
+    ("removals", "remov"),
+    ("fasting", "fast"),
+    ("pummelling", "pummel"),
+    ("gripped", "grip"),
+    ("unnoticed", "unnot"),
+    ("september", "septemb"),
+    ("refreshes", "refresh"),
+    ("trenched", "trench"),
+    ("assimilation", "assimilaz"),
+    ("overhung", "overhung"),
+    ("evaporate", "evapor"),
+    ("elewated", "elew"),
+    ("caw", "caw"),
+    ("fused", "fuse"),
+    ("renovation", "renovaz"),
+    ("homily", "homili"),
+    ("forfeits", "forfeit"),
+    ("granite", "granit"),
+    ("betake", "betak"),
+    ("showers", "shower"),
+    ("faintness", "faint"),
+    ("faint", "faint"),
+    ("statements", "statement"),
+    ("devised", "devis"),
+    ("circumnavigate", "circumnavig"),
+    ("suggestion", "suggest"),
+    ("nick", "nick"),
+    ("linings", "line"),
+    ("algebra", "algebra"),
+    ("truest", "truest"),
+    ("combine", "combin"),
+    ("ceremoniously", "ceremoni"),
+    ("assistant", "assist"),
+    ("neighborhood", "neighborhood"),
+    ("sharpen", "sharpen"),
+    ("silvery", "silveri"),
+    ("platforna", "platforna"),
+    ("livelier", "liveli"),
+    ("godalming", "godalm"),
+    ("saunter", "saunter"),
+    ("health", "health"),
+    ("estranged", "estrang"),
+    ("smal", "smal"),
+    ("inflicting", "inflict"),
+    ("ruefully", "ruefulli"),
+    ("cream", "cream"),
+    ("gresham", "gresham"),
+    ("hamilton", "hamilton"),
+    ("remarkable", "remark"),
+    ("bivalves", "bivalv"),
+    ("coverlet", "coverlet"),
+    ("senoritas", "senorita"),
+    ("rifles", "rifl"),
+    ("colnett", "colnett"),
+    ("addicted", "addict"),
+    ("persistently", "persist"),
+    ("angrily", "angrili"),
+    ("ass", "ass"),
+    ("wreathing", "wreath"),
+    ("prebendary", "prebendari"),
+    ("fish", "fish"),
+    ("prudery", "pruderi"),
+    ("damaged", "damag"),
+    ("littl", "littl"),
+    ("dawning", "dawn"),
+    ("hind", "hind"),
+    ("conclusive", "conclus"),
+    ("controlling", "control"),
+    ("tested", "test"),
+    ("discusses", "discuss"),
+    ("oot", "oot"),
+    ("scandals", "scandal"),
+    ("poop", "poop"),
+    ("experiments", "experi"),
+    ("actively", "activ"),
+    ("penetrated", "penetr"),
+    ("comply", "compli"),
+    ("doveton", "doveton"),
+    ("heart", "heart"),
+    ("identifying", "identifi"),
+    ("nore", "nore"),
+    ("overhanging", "overhang"),
+    ("treaces", "treac"),
+    ("auguste", "august"),
+    ("excessively", "excess"),
+    ("gypsy", "gypsi"),
+    ("ards", "ard"),
+    ("delicacy", "delicaci"),
+    ("desirous", "desir"),
+    ("tristram", "tristram"),
+    ("toys", "toi"),
+    ("listened", "listen"),
+    ("pounds", "pound"),
+    ("protector", "protector"),
+    ("chamisso", "chamisso"),
+    ("trying", "tri"),
+    ("submergence", "submerg"),
+    ("mites", "mite"),
+    ("nightcaps", "nightcap"),
+    ("saniem", "saniem"),
+    ("affirm", "affirm"),
+    ("blossomed", "blossom"),
+    ("toasted", "toast"),
+    ("disregardful", "disregard"),
+    ("archaeologists", "archaeologist"),
+    ("row", "row"),
+    ("saint", "saint"),
+    ("day", "dai"),
+    ("adherent", "adher"),
+    ("spirit", "spirit"),
+    ("malevolent", "malevol"),
+    ("heiress", "heiress"),
+    ("papillae", "papilla"),
+    ("flag", "flag"),
+    ("commodities", "commod"),
+    ("pillar", "pillar"),
+    ("mortally", "mortal"),
+    ("dryer", "dryer"),
+    ("plover", "plover"),
+    ("b", "b"),
+    ("gadzooks", "gadzook"),
+    ("skirmish", "skirmish"),
+    ("perpetrator", "perpetr"),
+    ("tradesman", "tradesman"),
+    ("refit", "refit"),
+    ("bahama", "bahama"),
+    ("pretty", "pretti"),
+    ("inhuman", "inhuman"),
+    ("prattlings", "prattl"),
+    ("nd", "nd"),
+    ("vacancy", "vacanc"),
+    ("chepones", "chepon"),
+    ("poisoned", "poison"),
+    ("arithmetic", "arithmet"),
+    ("composite", "composit"),
+    ("catgut", "catgut"),
+    ("uncandid", "uncandid"),
+    ("unpopular", "unpopular"),
+    ("prosperously", "prosper"),
+    ("clump", "clump"),
+    ("spoken", "spoken"),
+    ("coaxingly", "coaxingli"),
+    ("knuckle", "knuckl"),
+    ("rendering", "render"),
+    ("overruled", "overrul"),
+    ("reverentially", "reverenti"),
+    ("lous", "lou"),
+    ("workshop", "workshop"),
+    ("inappropriately", "inappropri"),
+    ("evergreen", "evergreen"),
+    ("moistening", "moisten"),
+    ("contend", "contend"),
+    ("flustered", "fluster"),
+    ("ignominious", "ignomini"),
+    ("greyhounds", "greyhound"),
+    ("pachydermatous", "pachydermat"),
+    ("estuary", "estuari"),
+    ("benson", "benson"),
+    ("stammering", "stammer"),
+    ("caressing", "caress"),
+    ("stooped", "stoop"),
+    ("dictionary", "dictionari"),
+    ("contained", "contain"),
+    ("recoil", "recoil"),
+    ("toight", "toight"),
+    ("au", "au"),
+    ("everyways", "everywai"),
+    ("versions", "version"),
+    ("bound", "bound"),
+    ("holy", "holi"),
+    ("disciplining", "disciplin"),
+    ("rouse", "rous"),
+    ("proficiency", "profici"),
+    ("detestable", "detest"),
+    ("degraded", "degrad"),
+    ("tempestuous", "tempestu"),
+    ("thermometer", "thermomet"),
+    ("dive", "dive"),
+    ("tockahoopo", "tockahoopo"),
+    ("commune", "commun"),
+    ("monotonously", "monoton"),
+    ("crape", "crape"),
+    ("pamphlets", "pamphlet"),
+    ("darted", "dart"),
+    ("amiss", "amiss"),
+    ("thousands", "thousand"),
+    ("fisherman", "fisherman"),
+    ("koodle", "koodl"),
+    ("gauntly", "gauntli"),
+    ("modern", "modern"),
+    ("implies", "impli"),
+    ("smooth", "smooth"),
+    ("interestin", "interestin"),
+    ("chipping", "chip"),
+    ("personage", "personag"),
+    ("shuddering", "shudder"),
+    ("preferable", "prefer"),
+    ("hooting", "hoot"),
+    ("intrinsic", "intrins"),
+    ("cherry", "cherri"),
+    ("furnaces", "furnac"),
+    ("mightier", "mightier"),
+    ("palmy", "palmi"),
+    ("vapours", "vapour"),
+    ("desultory", "desultori"),
+    ("bifurcating", "bifurc"),
+    ("sinful", "sin"),
+    ("helps", "help"),
+    ("reeled", "reel"),
+    ("estimate", "estim"),
+    ("populous", "popul"),
+    ("vindictively", "vindict"),
+    ("horsemen", "horsemen"),
+    ("lonelier", "loneli"),
+    ("prone", "prone"),
+    ("video", "video"),
+    ("womankind", "womankind"),
+    ("portly", "portli"),
+    ("trot", "trot"),
+    ("craved", "crave"),
+    ("saucers", "saucer"),
+    ("parole", "parol"),
+    ("afanasy", "afanasi"),
+    ("lid", "lid"),
+    ("appanage", "appanag"),
+    ("untidily", "untidili"),
+    ("severely", "sever"),
+    ("exposure", "exposur"),
+    ("hod", "hod"),
+    ("hot", "hot"),
+    ("ornament", "ornament"),
+    ("baronetcy", "baronetci"),
+    ("closeness", "close"),
+    ("private", "privat"),
+    ("kidnapping", "kidnap"),
+    ("happertons", "happerton"),
+    ("buffet", "buffet"),
+    ("pluck", "pluck"),
+    ("exchange", "exchang"),
+    ("girdle", "girdl"),
+    ("hoarsely", "hoars"),
+    ("mastiff", "mastiff"),
+    ("sneak", "sneak"),
+    ("diamanten", "diamanten"),
+    ("wharton", "wharton"),
+    ("ground", "ground"),
+    ("retained", "retain"),
+    ("mongrober", "mongrob"),
+    ("mecum", "mecum"),
+    ("fluctuate", "fluctuat"),
+    ("antiquaries", "antiquari"),
+    ("right", "right"),
+    ("stipulating", "stipul"),
+    ("repentance", "repent"),
+    ("disagreed", "disagre"),
+    ("counsels", "counsel"),
+    ("evening", "even"),
+    ("manoeuvres", "manoeuvr"),
+    ("polking", "polk"),
+    ("uttered", "utter"),
+    ("wrinkles", "wrinkl"),
+    ("bos", "bo"),
+    ("wallow", "wallow"),
+    ("sojourn", "sojourn"),
+    ("extirpating", "extirp"),
+    ("wayfarers", "wayfar"),
+    ("wherever", "wherev"),
+    ("hovers", "hover"),
+    ("cluck", "cluck"),
+    ("striven", "striven"),
+    ("pickaxe", "pickax"),
+    ("millennium", "millennium"),
+    ("unworldly", "unworldli"),
+    ("coffee", "coffe"),
+    ("wane", "wane"),
+    ("figuring", "figur"),
+    ("worked", "work"),
+    ("peerages", "peerag"),
+    ("capers", "caper"),
+    ("murmuring", "murmur"),
+    ("superintend", "superintend"),
+    ("advisability", "advis"),
+    ("handkerchiefs", "handkerchief"),
+    ("aider", "aider"),
+    ("bishopsgate", "bishopsg"),
+    ("geospiza", "geospiza"),
+    ("invasion", "invas"),
+    ("rusted", "rust"),
+    ("pikestaff", "pikestaff"),
+    ("groundwork", "groundwork"),
+    ("prospering", "prosper"),
+    ("there", "there"),
+    ("genfleman", "genfleman"),
+    ("giggles", "giggl"),
+    ("hawdon", "hawdon"),
+    ("powder", "powder"),
+    ("hierarchy", "hierarchi"),
+    ("platitudes", "platitud"),
+    ("trials", "trial"),
+    ("kalydor", "kalydor"),
+    ("engraver", "engrav"),
+    ("masquerading", "masquerad"),
+    ("construct", "construct"),
+    ("scandalous", "scandal"),
+    ("apt", "apt"),
+    ("newspaper", "newspap"),
+    ("wait", "wait"),
+    ("peroration", "peroraz"),
+    ("accurately", "accur"),
+    ("consolidated", "consolid"),
+    ("hem", "hem"),
+    ("invited", "invit"),
+    ("briers", "brier"),
+    ("rounder", "rounder"),
+    ("sobering", "sober"),
+    ("railways", "railwai"),
+    ("noises", "nois"),
+    ("rebelling", "rebel"),
+    ("aiding", "aid"),
+    ("descry", "descri"),
+    ("evoked", "evok"),
+    ("riviere", "rivier"),
+    ("nervously", "nervous"),
+    ("dozenth", "dozenth"),
+    ("unveil", "unveil"),
+    ("adapter", "adapt"),
+    ("adjoining", "adjoin"),
+    ("peace", "peac"),
+    ("fiddlesticks", "fiddlestick"),
+    ("sneering", "sneer"),
+    ("unaffected", "unaffect"),
+    ("oun", "oun"),
+    ("elan", "elan"),
+    ("recurs", "recur"),
+    ("pampean", "pampean"),
+    ("carizal", "cariz"),
+    ("delving", "delv"),
+    ("puddles", "puddl"),
+    ("tibby", "tibbi"),
+    ("corps", "corp"),
+    ("roofed", "roof"),
+    ("misbehaved", "misbehav"),
+    ("expensive", "expens"),
+    ("retraced", "retrac"),
+    ("continually", "continu"),
+    ("sedge", "sedg"),
+    ("blistered", "blister"),
+    ("introductory", "introductori"),
+    ("unbolted", "unbolt"),
+    ("natured", "natur"),
+    ("surveyors", "surveyor"),
+    ("spits", "spit"),
+    ("deceptions", "decept"),
+    ("raillery", "railleri"),
+    ("customs", "custom"),
+    ("actors", "actor"),
+    ("nomination", "nominaz"),
+    ("arduous", "arduou"),
+    ("unbiassed", "unbiass"),
+    ("conducts", "conduct"),
+    ("magnanimity", "magnanim"),
+    ("problems", "problem"),
+    ("retrospective", "retrospect"),
+    ("eastern", "eastern"),
+    ("concentrated", "concentr"),
+    ("moresby", "moresbi"),
+    ("nettled", "nettl"),
+    ("prepossessed", "prepossess"),
+    ("wicket", "wicket"),
+    ("disobeyed", "disobei"),
+    ("dante", "dant"),
+    ("disdainful", "disdain"),
+    ("creative", "cre"),
+    ("get", "get"),
+    ("nurses", "nurs"),
+    ("top", "top"),
+    ("lemen", "lemen"),
+    ("sop", "sop"),
+    ("dexterously", "dexter"),
+    ("genlmen", "genlmen"),
+    ("lagged", "lag"),
+    ("genealogist", "genealogist"),
+    ("bouncing", "bounc"),
+    ("assistants", "assist"),
+    ("answerable", "answer"),
+    ("helpless", "helpless"),
+    ("laboured", "labour"),
+    ("jumps", "jump"),
+    ("peoples", "peopl"),
+    ("loight", "loight"),
+    ("revolted", "revolt"),
+    ("escaping", "escap"),
+    ("paths", "path"),
+    ("apparition", "apparit"),
+    ("insinuate", "insinu"),
+    ("exclamation", "exclamaz"),
+    ("maydickle", "maydickl"),
+    ("extraordinarily", "extraordinarili"),
+    ("poll", "poll"),
+    ("precautions", "precaut"),
+    ("didn", "didn"),
+    ("priory", "priori"),
+    ("disapproved", "disapprov"),
+    ("massacres", "massacr"),
+    ("vase", "vase"),
+    ("fag", "fag"),
+    ("insufferably", "insuffer"),
+    ("whisking", "whisk"),
+    ("interlacings", "interlac"),
+    ("budgets", "budget"),
+    ("advocacy", "advocaci"),
+    ("holes", "hole"),
+    ("offensive", "offens"),
+    ("bluebells", "bluebel"),
+    ("tripod", "tripod"),
+    ("volleys", "vollei"),
+    ("cyrus", "cyru"),
+    ("additionally", "addition"),
+    ("attest", "attest"),
+    ("kendall", "kendal"),
+    ("replies", "repli"),
+    ("inability", "inabl"),
+    ("rosebud", "rosebud"),
+    ("by", "bi"),
+    ("plumpness", "plump"),
+    ("heroism", "heroism"),
+    ("sport", "sport"),
+    ("plant", "plant"),
+    ("dancings", "danc"),
+    ("foolishness", "foolish"),
+    ("contending", "contend"),
+    ("afoot", "afoot"),
+    ("bethought", "bethought"),
+    ("dens", "den"),
+    ("farm", "farm"),
+    ("claret", "claret"),
+    ("unlikely", "unlik"),
+    ("indignity", "indign"),
+    ("nursemaid", "nursemaid"),
+    ("humanly", "humanli"),
+    ("pining", "pine"),
+    ("vivoras", "vivora"),
+    ("idly", "idli"),
+    ("uncommonly", "uncommonli"),
+    ("control", "control"),
+    ("advertisements", "advertis"),
+    ("woices", "woic"),
+    ("thinker", "thinker"),
+    ("plastered", "plaster"),
+    ("werry", "werri"),
+    ("remain", "remain"),
+    ("pp", "pp"),
+    ("certainly", "certainli"),
+    ("savana", "savana"),
+    ("shapely", "shape"),
+    ("murmurings", "murmur"),
+    ("julian", "julian"),
+    ("heels", "heel"),
+    ("mangering", "manger"),
+    ("facile", "facil"),
+    ("americans", "american"),
+    ("party", "parti"),
+    ("fiord", "fiord"),
+    ("wool", "wool"),
+    ("tuckered", "tucker"),
+    ("drawing", "draw"),
+    ("rocked", "rock"),
+    ("discreetly", "discreetli"),
+    ("hong", "hong"),
+    ("portrait", "portrait"),
+    ("palates", "palat"),
+    ("perilous", "peril"),
+    ("coughing", "cough"),
+    ("frown", "frown"),
+    ("magistrate", "magistr"),
+    ("epicurean", "epicurean"),
+    ("pretentious", "pretenti"),
+    ("attacks", "attack"),
+    ("tchebarov", "tchebarov"),
+    ("imitating", "imit"),
+    ("dissensions", "dissens"),
+    ("elegant", "eleg"),
+    ("necessarily", "necessarili"),
+    ("verbal", "verbal"),
+    ("stack", "stack"),
+    ("notified", "notifi"),
+    ("relentless", "relentless"),
+    ("lucky", "lucki"),
+    ("proclaiming", "proclaim"),
+    ("fadedly", "fadedli"),
+    ("frolicsome", "frolicsom"),
+    ("tokens", "token"),
+    ("fundamentally", "fundament"),
+    ("oryzivorus", "oryzivoru"),
+    ("disconcert", "disconcert"),
+    ("untoward", "untoward"),
+    ("primogeniture", "primogenitur"),
+    ("carefulness", "care"),
+    ("vapouring", "vapour"),
+    ("indented", "indent"),
+    ("doers", "doer"),
+    ("changes", "chang"),
+    ("suspicion", "suspicion"),
+    ("mata", "mata"),
+    ("futures", "futur"),
+    ("equivalent", "equival"),
+    ("searches", "search"),
+    ("redeem", "redeem"),
+    ("santiago", "santiago"),
+    ("abode", "abod"),
+    ("jerky", "jerki"),
+    ("melanotis", "melanoti"),
+    ("ostrich", "ostrich"),
+    ("billing", "bill"),
+    ("dreamily", "dreamili"),
+    ("plodding", "plod"),
+    ("dined", "dine"),
+    ("decamp", "decamp"),
+    ("hug", "hug"),
+    ("dull", "dull"),
+    ("sorrows", "sorrow"),
+    ("bewildered", "bewild"),
+    ("pistil", "pistil"),
+    ("event", "event"),
+    ("thirdly", "thirdli"),
+    ("disrespectfully", "disrespectfulli"),
+    ("cakes", "cake"),
+    ("routed", "rout"),
+    ("rochester", "rochest"),
+    ("piecework", "piecework"),
+    ("spat", "spat"),
+    ("chec", "chec"),
+    ("webster", "webster"),
+    ("cackled", "cackl"),
+    ("arnong", "arnong"),
+    ("names", "name"),
+    ("compromising", "compromis"),
+    ("xxii", "xxii"),
+    ("fractured", "fractur"),
+    ("obliterated", "obliter"),
+    ("virgularia", "virgularia"),
+    ("yon", "yon"),
+    ("monsther", "monsther"),
+    ("dammed", "dam"),
+    ("slumbered", "slumber"),
+    ("prairies", "prairi"),
+    ("repent", "repent"),
+    ("roasting", "roast"),
+    ("epaulette", "epaulett"),
+    ("velvet", "velvet"),
+    ("lions", "lion"),
+    ("transfigured", "transfigur"),
+    ("bitch", "bitch"),
+    ("further", "further"),
+    ("sufferance", "suffer"),
+    ("scarabaeus", "scarabaeu"),
+    ("surround", "surround"),
+    ("arthurs", "arthur"),
+    ("shortening", "shorten"),
+    ("skirmished", "skirmish"),
+    ("phryniscus", "phryniscu"),
+    ("whichever", "whichev"),
+    ("indenture", "indentur"),
+    ("thyself", "thyself"),
+    ("delude", "delud"),
+    ("considers", "consid"),
+    ("propounds", "propound"),
+    ("flowed", "flow"),
+    ("staunch", "staunch"),
+    ("canal", "canal"),
+    ("roofs", "roof"),
+    ("broadwise", "broadwis"),
+    ("deportment", "deport"),
+    ("job", "job"),
+    ("driver", "driver"),
+    ("approximate", "approxim"),
+    ("disavow", "disavow"),
+    ("unfeelingly", "unfeelingli"),
+    ("trod", "trod"),
+    ("coachmen", "coachmen"),
+    ("litter", "litter"),
+    ("scholastic", "scholast"),
+    ("wringing", "wringing"),
+    ("mobbs", "mobb"),
+    ("destroyer", "destroyer"),
+    ("faultless", "faultless"),
+    ("y", "i"),
+    ("creek", "creek"),
+    ("deliriously", "deliri"),
+    ("frenchwoman", "frenchwoman"),
+    ("ultra", "ultra"),
+    ("mercury", "mercuri"),
+    ("caskets", "casket"),
+    ("translating", "translat"),
+    ("xlix", "xlix"),
+    ("invents", "invent"),
+    ("entries", "entri"),
+    ("basements", "basement"),
+    ("mustered", "muster"),
+    ("friday", "fridai"),
+    ("gentlewoman", "gentlewoman"),
+    ("jobling", "jobl"),
+    ("panelled", "panel"),
+    ("auspices", "auspic"),
+    ("firmer", "firmer"),
+    ("simplest", "simplest"),
+    ("ministry", "ministri"),
+    ("irritable", "irrit"),
+    ("invective", "invect"),
+    ("butchers", "butcher"),
+    ("liberalism", "liber"),
+    ("medicine", "medicin"),
+    ("breath", "breath"),
+    ("sweden", "sweden"),
+    ("investigated", "investig"),
+    ("ostensibly", "ostensibli"),
+    ("drabs", "drab"),
+    ("diverge", "diverg"),
+    ("megatheroid", "megatheroid"),
+    ("cavern", "cavern"),
+    ("feebleness", "feebl"),
+    ("purpura", "purpura"),
+    ("potash", "potash"),
+    ("assigned", "assign"),
+    ("persists", "persist"),
+    ("childish", "childish"),
+    ("thrveydrop", "thrveydrop"),
+    ("maurua", "maurua"),
+    ("glowworms", "glowworm"),
+    ("scenery", "sceneri"),
+    ("cultivating", "cultiv"),
+    ("saltire", "saltir"),
+    ("nails", "nail"),
+    ("ambush", "ambush"),
+    ("coffeehouse", "coffeehous"),
+    ("organise", "organis"),
+    ("niceness", "nice"),
+    ("staphylinidae", "staphylinida"),
+    ("severity", "sever"),
+    ("deem", "deem"),
+    ("mandibl", "mandibl"),
+    ("prudence", "prudenc"),
+    ("drawling", "drawl"),
+    ("etes", "et"),
+    ("pestilential", "pestilenti"),
+    ("dingleby", "dinglebi"),
+    ("agitation", "agitaz"),
+    ("disturb", "disturb"),
+    ("epitome", "epitom"),
+    ("chewing", "chew"),
+    ("suffused", "suffus"),
+    ("molten", "molten"),
+    ("energies", "energi"),
+    ("perception", "percept"),
+    ("recording", "record"),
+    ("allured", "allur"),
+    ("indicate", "indic"),
+    ("tents", "tent"),
+    ("ado", "ado"),
+    ("pine", "pine"),
+    ("humbleness", "humbl"),
+    ("put", "put"),
+    ("overweening", "overween"),
+    ("strictness", "strict"),
+    ("mingle", "mingl"),
+    ("sarandis", "sarandi"),
+    ("parana", "parana"),
+    ("extracts", "extract"),
+    ("trades", "trade"),
+    ("occur", "occur"),
+    ("squod", "squod"),
+    ("resort", "resort"),
+    ("coaxin", "coaxin"),
+    ("animalcula", "animalcula"),
+    ("pancakes", "pancak"),
+    ("hydrogen", "hydrogen"),
+    ("oscillation", "oscillaz"),
+    ("divest", "divest"),
+    ("twolve", "twolv"),
+    ("beggar", "beggar"),
+    ("territories", "territori"),
+    ("carcase", "carcas"),
+    ("drooping", "droop"),
+    ("recital", "recit"),
+    ("dissuade", "dissuad"),
+    ("tom", "tom"),
+    ("defy", "defi"),
+    ("plaza", "plaza"),
+    ("loosing", "loos"),
+    ("perilled", "peril"),
+    ("cupids", "cupid"),
+    ("clogged", "clog"),
+    ("leoville", "leovill"),
+    ("chancellor", "chancellor"),
+    ("lxvi", "lxvi"),
+    ("correspondents", "correspond"),
+    ("suffocation", "suffocaz"),
+    ("blacksmiths", "blacksmith"),
+    ("skinning", "skin"),
+    ("liveries", "liveri"),
+    ("secreted", "secret"),
+    ("irksomeness", "irksom"),
+    ("lenou", "lenou"),
+    ("random", "random"),
+    ("blotted", "blot"),
+    ("visitations", "visitaz"),
+    ("averse", "avers"),
+    ("simpleton", "simpleton"),
+    ("xlvi", "xlvi"),
+    ("praetorian", "praetorian"),
+    ("rewards", "reward"),
+    ("marbl", "marbl"),
+    ("drawback", "drawback"),
+    ("molar", "molar"),
+    ("problem", "problem"),
+    ("afanasyvitch", "afanasyvitch"),
+    ("chunks", "chunk"),
+    ("tagua", "tagua"),
+    ("scatter", "scatter"),
+    ("occasioned", "occas"),
+    ("loved", "love"),
+    ("excusing", "excus"),
+    ("saucepans", "saucepan"),
+    ("illustrations", "illustraz"),
+    ("knock", "knock"),
+    ("testify", "testifi"),
+    ("entertainments", "entertain"),
+    ("porous", "porou"),
+    ("comprised", "compris"),
+    ("overspreads", "overspread"),
+    ("surpass", "surpass"),
+    ("accession", "access"),
+    ("mp", "mp"),
+    ("fragment", "fragment"),
+    ("stemming", "stem"),
+    ("correspondence", "correspond"),
+    ("beau", "beau"),
+    ("hail", "hail"),
+    ("unnamed", "unnam"),
+    ("darlings", "darl"),
+    ("posture", "postur"),
+    ("deluded", "delud"),
+    ("practicable", "practic"),
+    ("postman", "postman"),
+    ("onthophagus", "onthophagu"),
+    ("approved", "approv"),
+    ("serving", "serv"),
+    ("forlornest", "forlornest"),
+    ("brotherly", "brotherli"),
+    ("tasmania", "tasmania"),
+    ("declamation", "declamaz"),
+    ("suadiva", "suadiva"),
+    ("eliciting", "elicit"),
+    ("atollons", "atollon"),
+    ("larry", "larri"),
+    ("rattler", "rattler"),
+    ("haunches", "haunch"),
+    ("packing", "pack"),
+    ("learners", "learner"),
+    ("workman", "workman"),
+    ("indefatigably", "indefatig"),
+    ("paving", "pave"),
+    ("posession", "posess"),
+    ("sha", "sha"),
+    ("mantles", "mantl"),
+    ("homage", "homag"),
+    ("minister", "minist"),
+    ("housewifery", "housewiferi"),
+    ("orpheus", "orpheu"),
+    ("och", "och"),
+    ("chit", "chit"),
+    ("colonies", "coloni"),
+    ("doll", "doll"),
+    ("conceived", "conceiv"),
+    ("submissiveness", "submiss"),
+    ("wring", "wring"),
+    ("wake", "wake"),
+    ("palliated", "palliat"),
+    ("consigned", "consign"),
+    ("oil", "oil"),
+    ("fluggers", "flugger"),
+    ("sausages", "sausag"),
+    ("sporting", "sport"),
+    ("second", "second"),
+    ("owed", "ow"),
+    ("bust", "bust"),
+    ("ailing", "ail"),
+    ("culture", "cultur"),
+    ("endless", "endless"),
+    ("somewhat", "somewhat"),
+    ("extorted", "extort"),
+    ("deceiving", "deceiv"),
+    ("sapling", "sapl"),
+    ("overlying", "overli"),
+    ("millionth", "millionth"),
+    ("concocted", "concoct"),
+    ("particular", "particular"),
+    ("advocated", "advoc"),
+    ("harkov", "harkov"),
+    ("nephew", "nephew"),
+    ("picturing", "pictur"),
+    ("moanings", "moan"),
+    ("accidental", "accident"),
+    ("granulo", "granulo"),
+    ("gums", "gum"),
+    ("tulip", "tulip"),
+    ("paralyse", "paralys"),
+    ("fry", "fri"),
+    ("arcadia", "arcadia"),
+    ("grieve", "griev"),
+    ("bonny", "bonni"),
+    ("rapacity", "rapac"),
+    ("biting", "bite"),
+    ("cacique", "caciqu"),
+    ("accomplice", "accomplic"),
+    ("frozen", "frozen"),
+    ("fermentable", "ferment"),
+    ("cudgel", "cudgel"),
+    ("prose", "prose"),
+    ("recurring", "recur"),
+    ("subsidence", "subsid"),
+    ("repealed", "repeal"),
+    ("regretting", "regret"),
+    ("bosses", "boss"),
+    ("despot", "despot"),
+    ("mark", "mark"),
+    ("narborough", "narborough"),
+    ("unsympathetic", "unsympathet"),
+    ("oblique", "obliqu"),
+    ("spontaneously", "spontan"),
+    ("kit", "kit"),
+    ("palliation", "palliaz"),
+    ("lo", "lo"),
+    ("narr", "narr"),
+    ("knuckleboy", "knuckleboi"),
+    ("showering", "shower"),
+    ("rosinas", "rosina"),
+    ("managing", "manag"),
+    ("blacksmith", "blacksmith"),
+    ("diabolicus", "diabolicu"),
+    ("unkindness", "unkind"),
+    ("murmured", "murmur"),
+    ("blazing", "blaze"),
+    ("viler", "viler"),
+    ("measles", "measl"),
+    ("entitle", "entitl"),
+    ("renowned", "renown"),
+    ("nemo", "nemo"),
+    ("deferentially", "deferenti"),
+    ("decisively", "decis"),
+    ("ahead", "ahead"),
+    ("calculates", "calcul"),
+    ("accomplished", "accomplish"),
+    ("interposing", "interpos"),
+    ("aspiring", "aspir"),
+    ("emits", "emit"),
+    ("stupor", "stupor"),
+    ("invigorate", "invigor"),
+    ("forty", "forti"),
+    ("girths", "girth"),
+    ("expande", "expand"),
+    ("saliva", "saliva"),
+    ("offending", "offend"),
+    ("plank", "plank"),
+    ("heightened", "heighten"),
+    ("laundry", "laundri"),
+    ("brink", "brink"),
+    ("seems", "seem"),
+    ("thriving", "thrive"),
+    ("inconstancy", "inconst"),
+    ("appertaining", "appertain"),
+    ("recuperative", "recup"),
+    ("circumscribed", "circumscrib"),
+    ("strap", "strap"),
+    ("summer", "summer"),
+    ("historically", "histor"),
+    ("rope", "rope"),
+    ("vicariously", "vicari"),
+    ("classic", "classic"),
+    ("disorder", "disord"),
+    ("sweltering", "swelter"),
+    ("goo", "goo"),
+    ("wigwams", "wigwam"),
+    ("isolation", "isolaz"),
+    ("fiat", "fiat"),
+    ("brocade", "brocad"),
+    ("stagger", "stagger"),
+    ("platina", "platina"),
+    ("distasteful", "distast"),
+    ("sam", "sam"),
+    ("shortly", "shortli"),
+    ("dislikes", "dislik"),
+    ("blunderbore", "blunderbor"),
+    ("eighty", "eighti"),
+    ("gill", "gill"),
+    ("dividing", "divid"),
+    ("expletive", "explet"),
+    ("rack", "rack"),
+    ("lambert", "lambert"),
+    ("sharmer", "sharmer"),
+    ("unattached", "unattach"),
+    ("framework", "framework"),
+    ("gravity", "graviti"),
+    ("nonchalance", "nonchal"),
+    ("extort", "extort"),
+    ("taints", "taint"),
+    ("liked", "like"),
+    ("gun", "gun"),
+    ("hats", "hat"),
+    ("branching", "branch"),
+    ("flagrantly", "flagrantli"),
+    ("forgetful", "forget"),
+    ("mutually", "mutual"),
+    ("dresses", "dress"),
+    ("divined", "divin"),
+    ("leighton", "leighton"),
+    ("shoemakers", "shoemak"),
+    ("cold", "cold"),
+    ("impede", "imped"),
+    ("scandalized", "scandal"),
+    ("managers", "manag"),
+    ("liberally", "liber"),
+    ("magnificent", "magnific"),
+    ("outright", "outright"),
+    ("finely", "fine"),
+    ("trowels", "trowel"),
+    ("enhancing", "enhanc"),
+    ("devonport", "devonport"),
+    ("honour", "honour"),
+    ("blended", "blend"),
+    ("flotilla", "flotilla"),
+    ("cracks", "crack"),
+    ("customarily", "customarili"),
+    ("imprecation", "imprecaz"),
+    ("wheels", "wheel"),
+    ("infantine", "infantin"),
+    ("baggage", "baggag"),
+    ("savages", "savag"),
+    ("goodwood", "goodwood"),
+    ("thunderstorms", "thunderstorm"),
+    ("destitution", "destitut"),
+    ("phineas", "phinea"),
+    ("benefits", "benefit"),
+    ("journalist", "journalist"),
+    ("dullest", "dullest"),
+    ("octavo", "octavo"),
+    ("pageants", "pageant"),
+    ("mellifluousness", "melliflu"),
+    ("ne", "ne"),
+    ("relate", "relat"),
+    ("granaries", "granari"),
+    ("inwards", "inward"),
+    ("confessed", "confess"),
+    ("tongued", "tongu"),
+    ("witchery", "witcheri"),
+    ("shepherd", "shepherd"),
+    ("difficulties", "difficulti"),
+    ("calf", "calf"),
+    ("altering", "alter"),
+    ("sickly", "sickli"),
+    ("drilled", "drill"),
+    ("dishonour", "dishonour"),
+    ("hunchback", "hunchback"),
+    ("alligators", "allig"),
+    ("ferment", "ferment"),
+    ("varnish", "varnish"),
+    ("refined", "refin"),
+    ("browdies", "browdi"),
+    ("sifter", "sifter"),
+    ("fatiguing", "fatigu"),
+    ("prejudices", "prejudic"),
+    ("bite", "bite"),
+    ("jennings", "jen"),
+    ("deaf", "deaf"),
+    ("semyonovsky", "semyonovski"),
+    ("dared", "dare"),
+    ("dedicated", "dedic"),
+    ("blazoned", "blazon"),
+    ("comforting", "comfort"),
+    ("protestant", "protest"),
+    ("apologists", "apologist"),
+    ("digging", "dig"),
+    ("fourier", "fourier"),
+    ("striped", "stripe"),
+    ("descanted", "descant"),
+    ("ridge", "ridg"),
+    ("africa", "africa"),
+    ("underwood", "underwood"),
+    ("overburdened", "overburden"),
+    ("shoed", "sho"),
+    ("convey", "convei"),
+    ("disappeared", "disappear"),
+    ("palmer", "palmer"),
+    ("you", "you"),
+    ("cabals", "cabal"),
+    ("volcanos", "volcano"),
+    ("elysium", "elysium"),
+    ("caricature", "caricatur"),
+    ("propitiation", "propitiaz"),
+    ("jago", "jago"),
+    ("simplified", "simplifi"),
+    ("running", "run"),
+    ("solitude", "solitud"),
+    ("pettiness", "petti"),
+    ("unpretendingly", "unpretendingli"),
+    ("blade", "blade"),
+    ("ingratiated", "ingrati"),
+    ("appurtenance", "appurten"),
+    ("wilful", "wil"),
+    ("batter", "batter"),
+    ("drapery", "draperi"),
+    ("tedious", "tediou"),
+    ("joshua", "joshua"),
+    ("gamble", "gambl"),
+    ("solicitously", "solicit"),
+    ("officer", "offic"),
+    ("legged", "leg"),
+    ("emptied", "empti"),
+    ("stimulated", "stimul"),
+    ("nephews", "nephew"),
+    ("boating", "boat"),
+    ("layer", "layer"),
+    ("moaning", "moan"),
+    ("fitfully", "fitfulli"),
+    ("determine", "determin"),
+    ("unripe", "unrip"),
+    ("forgetting", "forget"),
+    ("ludgate", "ludgat"),
+    ("trimmings", "trim"),
+    ("sold", "sold"),
+    ("necklaced", "necklac"),
+    ("literature", "literatur"),
+    ("hartlepool", "hartlepool"),
+    ("anatomy", "anatomi"),
+    ("nominate", "nomin"),
+    ("frequency", "frequenc"),
+    ("zoophyt", "zoophyt"),
+    ("hoards", "hoard"),
+    ("supernumeraries", "supernumerari"),
+    ("steeper", "steeper"),
+    ("eagles", "eagl"),
+    ("solemnest", "solemnest"),
+    ("abstinence", "abstin"),
+    ("curtsys", "curtsi"),
+    ("circumspect", "circumspect"),
+    ("stationering", "station"),
+    ("favourite", "favourit"),
+    ("notopods", "notopod"),
+    ("hilt", "hilt"),
+    ("flask", "flask"),
+    ("attributed", "attribut"),
+    ("quadrangle", "quadrangl"),
+    ("jests", "jest"),
+    ("cove", "cove"),
+    ("membrane", "membran"),
+    ("prehensilis", "prehensili"),
+    ("presentable", "present"),
+    ("enamelled", "enamel"),
+    ("enjoined", "enjoin"),
+    ("esculentus", "esculentu"),
+    ("professional", "profession"),
+    ("heat", "heat"),
+    ("branchiae", "branchia"),
+    ("obedt", "obedt"),
+    ("rejoinder", "rejoind"),
+    ("annually", "annual"),
+    ("strongholds", "stronghold"),
+    ("waggon", "waggon"),
+    ("navigate", "navig"),
+    ("blower", "blower"),
+    ("outstep", "outstep"),
+    ("acerbity", "acerb"),
+    ("whenever", "whenev"),
+    ("apparatus", "apparatu"),
+    ("tarnished", "tarnish"),
+    ("entangle", "entangl"),
+    ("suburbs", "suburb"),
+    ("grossest", "grossest"),
+    ("ra", "ra"),
+    ("main", "main"),
+    ("self", "self"),
+    ("stirrup", "stirrup"),
+    ("xiv", "xiv"),
+    ("hesitates", "hesit"),
+    ("slurring", "slur"),
+    ("pokes", "poke"),
+    ("tiniest", "tiniest"),
+    ("misrepresent", "misrepres"),
+    ("shrewdly", "shrewdli"),
+    ("increased", "increas"),
+    ("squandering", "squander"),
+    ("wrote", "wrote"),
+    ("leapings", "leap"),
+    ("grafted", "graft"),
+    ("striving", "strive"),
+    ("fates", "fate"),
+    ("unladylike", "unladylik"),
+    ("traced", "trace"),
+    ("sinlessly", "sinlessli"),
+    ("barre", "barr"),
+    ("graver", "graver"),
+    ("relent", "relent"),
+    ("disagree", "disagre"),
+    ("cheese", "chees"),
+    ("scorch", "scorch"),
+    ("pawnbroker", "pawnbrok"),
+    ("troublesome", "troublesom"),
+    ("tenantry", "tenantri"),
+    ("necks", "neck"),
+    ("whosoever", "whosoev"),
+    ("ungraciousness", "ungraci"),
+    ("intermeddling", "intermeddl"),
+    ("counterbalanced", "counterbalanc"),
+    ("dungeons", "dungeon"),
+    ("longer", "longer"),
+    ("sloped", "slope"),
+    ("talbot", "talbot"),
+    ("brooker", "brooker"),
+    ("governs", "govern"),
+    ("supporter", "support"),
+    ("rouncewell", "rouncewel"),
+    ("semi", "semi"),
+    ("shall", "shall"),
+    ("straightly", "straightli"),
+    ("cursing", "curs"),
+    ("sends", "send"),
+    ("cyttaria", "cyttaria"),
+    ("simplex", "simplex"),
+    ("whitechapel", "whitechapel"),
+    ("reprieve", "repriev"),
+    ("deus", "deu"),
+    ("thunderstruck", "thunderstruck"),
+    ("freezing", "freez"),
+    ("bump", "bump"),
+    ("atlantic", "atlant"),
+    ("method", "method"),
+    ("policy", "polici"),
+    ("boozums", "boozum"),
+    ("mortified", "mortifi"),
+    ("mangostin", "mangostin"),
+    ("unbent", "unbent"),
+    ("azores", "azor"),
+    ("ralph", "ralph"),
+    ("trigonomics", "trigonom"),
+    ("prioress", "prioress"),
+    ("rearrange", "rearrang"),
+    ("tendon", "tendon"),
+    ("churchyard", "churchyard"),
+    ("mytilus", "mytilu"),
+    ("attended", "attend"),
+    ("downwards", "downward"),
+    ("concatenation", "concatenaz"),
+    ("acknowledges", "acknowledg"),
+    ("different", "differ"),
+    ("beloved", "belov"),
+    ("physicians", "physician"),
+    ("domesticated", "domest"),
+    ("competent", "compet"),
+    ("ducks", "duck"),
+    ("aptly", "aptli"),
+    ("toothpicks", "toothpick"),
+    ("lanes", "lane"),
+    ("soliloquised", "soliloquis"),
+    ("simul", "simul"),
+    ("properties", "properti"),
+    ("whew", "whew"),
+    ("hobart", "hobart"),
+    ("earthly", "earthli"),
+    ("buck", "buck"),
+    ("pond", "pond"),
+    ("moreover", "moreov"),
+    ("enigmatically", "enigmat"),
+    ("flabella", "flabella"),
+    ("myopotamus", "myopotamu"),
+    ("cavies", "cavi"),
+    ("racketing", "racket"),
+    ("lovingly", "lovingli"),
+    ("limited", "limit"),
+    ("sedative", "sed"),
+    ("xxxv", "xxxv"),
+    ("eve", "ev"),
+    ("recapitulation", "recapitulaz"),
+    ("foster", "foster"),
+    ("scorches", "scorch"),
+    ("earnest", "earnest"),
+    ("appreciating", "appreci"),
+    ("abundant", "abund"),
+    ("arrant", "arrant"),
+    ("jam", "jam"),
+    ("huffy", "huffi"),
+    ("brutality", "brutal"),
+    ("loodle", "loodl"),
+    ("lazo", "lazo"),
+    ("rebuked", "rebuk"),
+    ("greasy", "greasi"),
+    ("mornings", "morn"),
+    ("coils", "coil"),
+    ("adventurer", "adventur"),
+    ("pathetic", "pathet"),
+    ("suppressed", "suppress"),
+    ("exclude", "exclud"),
+    ("deadly", "deadli"),
+    ("brock", "brock"),
+    ("straightening", "straighten"),
+    ("sojourning", "sojourn"),
+    ("jackdaw", "jackdaw"),
+    ("austrian", "austrian"),
+    ("rebels", "rebel"),
+    ("published", "publish"),
+    ("systems", "system"),
+    ("fume", "fume"),
+    ("ceremonial", "ceremoni"),
+    ("worldlings", "worldl"),
+    ("upsetting", "upset"),
+    ("reaumur", "reaumur"),
+    ("tip", "tip"),
+    ("flutters", "flutter"),
+    ("moans", "moan"),
+    ("establishments", "establish"),
+    ("yearnings", "yearn"),
+    ("aqui", "aqui"),
+    ("commending", "commend"),
+    ("barbauld", "barbauld"),
+    ("nameless", "nameless"),
+    ("barker", "barker"),
+    ("endowing", "endow"),
+    ("contradictory", "contradictori"),
+    ("marks", "mark"),
+    ("told", "told"),
+    ("hebrew", "hebrew"),
+    ("june", "june"),
+    ("rooted", "root"),
+    ("utters", "utter"),
+    ("astonishinent", "astonishin"),
+    ("sweetly", "sweetli"),
+    ("beget", "beget"),
+    ("portland", "portland"),
+    ("retributive", "retribut"),
+    ("dealer", "dealer"),
+    ("clother", "clother"),
+    ("decree", "decre"),
+    ("tumley", "tumlei"),
+    ("hydraulic", "hydraul"),
+    ("subscribe", "subscrib"),
+    ("interpretations", "interpretaz"),
+    ("squires", "squir"),
+    ("ebb", "ebb"),
+    ("augment", "augment"),
+    ("toddy", "toddi"),
+    ("sweeter", "sweeter"),
+    ("stronger", "stronger"),
+    ("crumbled", "crumbl"),
+    ("agreeing", "agre"),
+    ("sandstones", "sandston"),
+    ("canis", "cani"),
+    ("attainments", "attain"),
+    ("exceeding", "exceed"),
+    ("domestication", "domesticaz"),
+    ("varnished", "varnish"),
+    ("sieve", "siev"),
+    ("boast", "boast"),
+    ("lazily", "lazili"),
+    ("below", "below"),
+    ("lobsters", "lobster"),
+    ("mouse", "mous"),
+    ("wavy", "wavi"),
+    ("estrangement", "estrang"),
+    ("elbers", "elber"),
+    ("assurances", "assur"),
+    ("wittier", "wittier"),
+    ("mindful", "mind"),
+    ("upon", "upon"),
+    ("elf", "elf"),
+    ("habit", "habit"),
+    ("sacks", "sack"),
+    ("flounced", "flounc"),
+    ("pestle", "pestl"),
+    ("forfeiting", "forfeit"),
+    ("abomination", "abominaz"),
+    ("stave", "stave"),
+    ("pleasant", "pleasant"),
+    ("serio", "serio"),
+    ("stepmother", "stepmoth"),
+    ("extinguish", "extinguish"),
+    ("tires", "tire"),
+    ("customer", "custom"),
+    ("speeches", "speech"),
+    ("parents", "parent"),
+    ("vorticose", "vorticos"),
+    ("beaten", "beaten"),
+    ("fevers", "fever"),
+    ("hacks", "hack"),
+    ("survivors", "survivor"),
+    ("limpet", "limpet"),
+    ("machina", "machina"),
+    ("objection", "object"),
+    ("forewarnings", "forewarn"),
+    ("widths", "width"),
+    ("deformed", "deform"),
+    ("crumpy", "crumpi"),
+    ("safeguards", "safeguard"),
+    ("powderin", "powderin"),
+    ("luckless", "luckless"),
+    ("bar", "bar"),
+    ("upset", "upset"),
+    ("disputes", "disput"),
+    ("sternness", "stern"),
+    ("philanthropist", "philanthropist"),
+    ("serrated", "serrat"),
+    ("empetrum", "empetrum"),
+    ("andled", "andl"),
+    ("crumpled", "crumpl"),
+    ("lent", "lent"),
+    ("disappears", "disappear"),
+    ("brewer", "brewer"),
+    ("betther", "betther"),
+    ("lullaby", "lullabi"),
+    ("ending", "end"),
+    ("degrade", "degrad"),
+    ("ransack", "ransack"),
+    ("wealth", "wealth"),
+    ("artist", "artist"),
+    ("win", "win"),
+    ("pitched", "pitch"),
+    ("lessen", "lessen"),
+    ("drink", "drink"),
+    ("registries", "registri"),
+    ("refinements", "refin"),
+    ("indecent", "indec"),
+    ("demonstration", "demonstraz"),
+    ("downy", "downi"),
+    ("housemaids", "housemaid"),
+    ("howsoever", "howsoev"),
+    ("workers", "worker"),
+    ("meditates", "medit"),
+    ("moon", "moon"),
+    ("medley", "medlei"),
+    ("grizzled", "grizzl"),
+    ("ungratified", "ungratifi"),
+    ("implanted", "implant"),
+    ("arrears", "arrear"),
+    ("humiliating", "humili"),
+    ("entomostraca", "entomostraca"),
+    ("rendezvous", "rendezv"),
+    ("worthily", "worthili"),
+    ("scour", "scour"),
+    ("accustomed", "accustom"),
+    ("instalment", "instal"),
+    ("pearl", "pearl"),
+    ("taverns", "tavern"),
+    ("bulky", "bulki"),
+    ("instincts", "instinct"),
+    ("ropes", "rope"),
+    ("misrepresenting", "misrepres"),
+    ("fascinate", "fascin"),
+    ("fondles", "fondl"),
+    ("requesting", "request"),
+    ("rats", "rat"),
+    ("greatness", "great"),
+    ("logic", "logic"),
+    ("petioles", "petiol"),
+    ("coal", "coal"),
+    ("owning", "own"),
+    ("caps", "cap"),
+    ("skilfulness", "skil"),
+    ("standing", "stand"),
+    ("oats", "oat"),
+    ("practises", "practis"),
+    ("skul", "skul"),
+    ("verds", "verd"),
+    ("abounds", "abound"),
+    ("reverie", "reveri"),
+    ("tiptoe", "tipto"),
+    ("shelving", "shelv"),
+    ("fat", "fat"),
+    ("missionaries", "missionari"),
+    ("illapel", "illapel"),
+    ("chargeable", "chargeabl"),
+    ("evasions", "evas"),
+    ("colonnade", "colonnad"),
+    ("dios", "dio"),
+    ("laments", "lament"),
+    ("rea", "rea"),
+    ("festivity", "festiv"),
+    ("fallin", "fallin"),
+    ("mitigation", "mitigaz"),
+    ("professor", "professor"),
+    ("purposeless", "purposeless"),
+    ("distinctness", "distinct"),
+    ("wolgan", "wolgan"),
+    ("assisted", "assist"),
+    ("wheat", "wheat"),
+    ("exorbitant", "exorbit"),
+    ("sa", "sa"),
+    ("permissable", "permiss"),
+    ("excoriate", "excori"),
+    ("villas", "villa"),
+    ("windlass", "windlass"),
+    ("intricate", "intric"),
+    ("bankruptcies", "bankruptci"),
+    ("stirred", "stir"),
+    ("institut", "institut"),
+    ("intrudes", "intrud"),
+    ("tied", "ti"),
+    ("shan", "shan"),
+    ("awaits", "await"),
+    ("gore", "gore"),
+    ("hallo", "hallo"),
+    ("grig", "grig"),
+    ("minutest", "minutest"),
+    ("angle", "angl"),
+    ("monstrously", "monstrous"),
+    ("ironmonger", "ironmong"),
+    ("nobody", "nobodi"),
+    ("linking", "link"),
+    ("confinement", "confin"),
+    ("sluggish", "sluggish"),
+    ("convinced", "convinc"),
+    ("hiding", "hide"),
+    ("slyly", "slyli"),
+    ("violins", "violin"),
+    ("outdone", "outdon"),
+    ("volutas", "voluta"),
+    ("ain", "ain"),
+    ("unsightly", "unsightli"),
+    ("skulls", "skull"),
+    ("sets", "set"),
+    ("hid", "hid"),
+    ("emptor", "emptor"),
+    ("hooted", "hoot"),
+    ("amaze", "amaz"),
+    ("bravely", "brave"),
+    ("brightened", "brighten"),
+    ("superbly", "superbli"),
+    ("xxi", "xxi"),
+    ("pursed", "purs"),
+    ("somethink", "somethink"),
+    ("cumfbler", "cumfbler"),
+    ("vegetables", "veget"),
+    ("moralise", "moralis"),
+    ("looser", "looser"),
+    ("nightcap", "nightcap"),
+    ("sovereign", "sovereign"),
+    ("exulted", "exult"),
+    ("aggravates", "aggrav"),
+    ("chroniclers", "chronicl"),
+    ("converse", "convers"),
+    ("provided", "provid"),
+    ("staying", "stai"),
+    ("tactics", "tactic"),
+    ("nests", "nest"),
+    ("trenching", "trench"),
+    ("delusive", "delus"),
+    ("inquisition", "inquisit"),
+    ("cumberland", "cumberland"),
+    ("unpersuadable", "unpersuad"),
+    ("office", "offic"),
+    ("dialogue", "dialogu"),
+    ("quadruped", "quadrup"),
+    ("manage", "manag"),
+    ("burn", "burn"),
+    ("quiriquina", "quiriquina"),
+    ("bathe", "bath"),
+    ("quatre", "quatr"),
+    ("generations", "generaz"),
+    ("anchoring", "anchor"),
+    ("soil", "soil"),
+    ("neckcloth", "neckcloth"),
+    ("cannibal", "cannib"),
+    ("crisp", "crisp"),
+    ("unprecedented", "unpreced"),
+    ("chorus", "choru"),
+    ("shortened", "shorten"),
+    ("groped", "grope"),
+    ("advent", "advent"),
+    ("model", "model"),
+    ("discordant", "discord"),
+    ("besetting", "beset"),
+    ("tuft", "tuft"),
+    ("ratio", "ratio"),
+    ("springs", "spring"),
+    ("irreverently", "irrever"),
+    ("depriving", "depriv"),
+    ("like", "like"),
+    ("camel", "camel"),
+    ("dormitory", "dormitori"),
+    ("dedlocks", "dedlock"),
+    ("instructs", "instruct"),
+    ("wronging", "wrong"),
+    ("recluse", "reclus"),
+    ("sign", "sign"),
+    ("alter", "alter"),
+    ("indicative", "indic"),
+    ("niebla", "niebla"),
+    ("therefrom", "therefrom"),
+    ("giants", "giant"),
+    ("alarm", "alarm"),
+    ("dues", "due"),
+    ("applying", "appli"),
+    ("strengthened", "strengthen"),
+    ("dwindling", "dwindl"),
+    ("meads", "mead"),
+    ("back", "back"),
+    ("florula", "florula"),
+    ("tacks", "tack"),
+    ("anna", "anna"),
+    ("vere", "vere"),
+    ("spangled", "spangl"),
+    ("ca", "ca"),
+    ("intimidation", "intimidaz"),
+    ("evelyn", "evelyn"),
+    ("underground", "underground"),
+    ("readiness", "readi"),
+    ("spheres", "sphere"),
+    ("corks", "cork"),
+    ("pollard", "pollard"),
+    ("oriental", "orient"),
+    ("sorting", "sort"),
+    ("befell", "befel"),
+    ("strewn", "strewn"),
+    ("laud", "laud"),
+    ("oruro", "oruro"),
+    ("coaxing", "coax"),
+    ("grades", "grade"),
+    ("unjust", "unjust"),
+    ("delay", "delai"),
+    ("regale", "regal"),
+    ("players", "player"),
+    ("repossession", "repossess"),
+    ("dejected", "deject"),
+    ("appropriate", "appropri"),
+    ("noa", "noa"),
+    ("sketching", "sketch"),
+    ("sugar", "sugar"),
+    ("ses", "se"),
+    ("air", "air"),
+    ("thieved", "thiev"),
+    ("counts", "count"),
+    ("pampas", "pampa"),
+    ("clerkenwell", "clerkenwel"),
+    ("delegates", "deleg"),
+    ("estranging", "estrang"),
+    ("nut", "nut"),
+    ("acclivity", "accliv"),
+    ("opener", "open"),
+    ("twisted", "twist"),
+    ("stitched", "stitch"),
+    ("opera", "opera"),
+    ("squeery", "squeeri"),
+    ("unflinching", "unflinch"),
+    ("reithrodon", "reithrodon"),
+    ("hammond", "hammond"),
+    ("daylight", "daylight"),
+    ("swarms", "swarm"),
+    ("fireflies", "firefli"),
+    ("boasted", "boast"),
+    ("cordials", "cordial"),
+    ("unpardonable", "unpardon"),
+    ("daverous", "daver"),
+    ("unblotted", "unblot"),
+    ("attending", "attend"),
+    ("commended", "commend"),
+    ("spawn", "spawn"),
+    ("shrew", "shrew"),
+    ("caravels", "caravel"),
+    ("coughed", "cough"),
+    ("nuptials", "nuptial"),
+    ("amends", "amend"),
+    ("evins", "evin"),
+    ("flank", "flank"),
+    ("earth", "earth"),
+    ("saunterer", "saunter"),
+    ("consumed", "consum"),
+    ("displaying", "displai"),
+    ("lemon", "lemon"),
+    ("incubation", "incubaz"),
+    ("unsymmetrical", "unsymmetr"),
+    ("dung", "dung"),
+    ("grow", "grow"),
+    ("truncated", "truncat"),
+    ("stolbuns", "stolbun"),
+    ("fabled", "fabl"),
+    ("privately", "privat"),
+    ("lighthouse", "lighthous"),
+    ("untended", "untend"),
+    ("cured", "cure"),
+    ("ferguson", "ferguson"),
+    ("roads", "road"),
+    ("ungovernable", "ungovern"),
+    ("assume", "assum"),
+    ("cards", "card"),
+    ("enshrined", "enshrin"),
+    ("resolutely", "resolut"),
+    ("obsequiousness", "obsequi"),
+    ("chuzo", "chuzo"),
+    ("delays", "delai"),
+    ("maidenly", "maidenli"),
+    ("audacity", "audac"),
+    ("infidelities", "infidel"),
+    ("leaving", "leav"),
+    ("reporter", "report"),
+    ("mines", "mine"),
+    ("cripple", "crippl"),
+    ("musgrave", "musgrav"),
+    ("slinking", "slink"),
+    ("floriated", "floriat"),
+    ("peril", "peril"),
+    ("grandpapa", "grandpapa"),
+    ("bracelet", "bracelet"),
+    ("accuser", "accus"),
+    ("passees", "passe"),
+    ("cuckoo", "cuckoo"),
+    ("lord", "lord"),
+    ("olinda", "olinda"),
+    ("openings", "open"),
+    ("refreshing", "refresh"),
+    ("bespeak", "bespeak"),
+    ("misused", "misus"),
+    ("sprouter", "sprouter"),
+    ("hatchet", "hatchet"),
+    ("innocent", "innoc"),
+    ("highl", "highl"),
+    ("factors", "factor"),
+    ("deliberation", "deliberaz"),
+    ("former", "former"),
+    ("subsoil", "subsoil"),
+    ("bedecked", "bedeck"),
+    ("insensibility", "insens"),
+    ("reigns", "reign"),
+    ("street", "street"),
+    ("reverends", "reverend"),
+    ("property", "properti"),
+    ("separated", "separ"),
+    ("worritted", "worrit"),
+    ("shrug", "shrug"),
+    ("misty", "misti"),
+    ("missionary", "missionari"),
+    ("chiefly", "chiefli"),
+    ("gypsies", "gypsi"),
+    ("madrina", "madrina"),
+    ("levels", "level"),
+    ("tops", "top"),
+    ("grasped", "grasp"),
+    ("lap", "lap"),
+    ("divested", "divest"),
+    ("honesty", "honesti"),
+    ("reins", "rein"),
+    ("whereby", "wherebi"),
+    ("violence", "violenc"),
+    ("mountains", "mountain"),
+    ("condescends", "condescend"),
+    ("unsolved", "unsolv"),
+    ("pricking", "prick"),
+    ("carries", "carri"),
+    ("cudico", "cudico"),
+    ("rears", "rear"),
+    ("mooted", "moot"),
+    ("macgregor", "macgregor"),
+    ("peeped", "peep"),
+    ("buffets", "buffet"),
+    ("wheel", "wheel"),
+    ("sender", "sender"),
+    ("recurrence", "recurr"),
+    ("helix", "helix"),
+    ("trenches", "trench"),
+    ("tempest", "tempest"),
+    ("angels", "angel"),
+    ("waterproof", "waterproof"),
+    ("books", "book"),
+    ("mews", "mew"),
+    ("constitootion", "constitoot"),
+    ("smirk", "smirk"),
+    ("tangible", "tangibl"),
+    ("idiosyncrasies", "idiosyncrasi"),
+    ("booksellers", "booksel"),
+    ("absolutely", "absolut"),
+    ("reflective", "reflect"),
+    ("askmg", "askmg"),
+    ("vineyards", "vineyard"),
+    ("mentally", "mental"),
+    ("catching", "catch"),
+    ("pour", "pour"),
+    ("nikodim", "nikodim"),
+    ("chuckled", "chuckl"),
+    ("erring", "er"),
+    ("mourning", "mourn"),
+    ("adherence", "adher"),
+    ("secrets", "secret"),
+    ("cutthroat", "cutthroat"),
+    ("creasing", "creas"),
+    ("isabel", "isabel"),
+    ("professions", "profess"),
+    ("convened", "conven"),
+    ("alma", "alma"),
+    ("quivers", "quiver"),
+    ("pensioners", "pension"),
+    ("bevan", "bevan"),
+    ("avowing", "avow"),
+    ("loftily", "loftili"),
+    ("siberian", "siberian"),
+    ("account", "account"),
+    ("achieved", "achiev"),
+    ("enthusiastic", "enthusiast"),
+    ("gilt", "gilt"),
+    ("chemistry", "chemistri"),
+    ("blush", "blush"),
+    ("lamentations", "lamentaz"),
+    ("riders", "rider"),
+    ("buffetings", "buffet"),
+    ("penitence", "penit"),
+    ("lamellicorn", "lamellicorn"),
+    ("complimented", "compliment"),
+    ("lava", "lava"),
+    ("advising", "advis"),
+    ("odour", "odour"),
+    ("waitress", "waitress"),
+    ("gunsmith", "gunsmith"),
+    ("mangles", "mangl"),
+    ("scotched", "scotch"),
+    ("feeder", "feeder"),
+    ("explicit", "explicit"),
+    ("demeaning", "demean"),
+    ("polished", "polish"),
+    ("reader", "reader"),
+    ("differed", "differ"),
+    ("awakes", "awak"),
+    ("wednesdays", "wednesdai"),
+    ("ninetta", "ninetta"),
+    ("dived", "dive"),
+    ("curlings", "curl"),
+    ("polygastrica", "polygastrica"),
+    ("rascal", "rascal"),
+    ("heeding", "heed"),
+    ("unaccountable", "unaccount"),
+    ("groweth", "groweth"),
+    ("neighbourhood", "neighbourhood"),
+    ("weise", "weis"),
+    ("restaurant", "restaur"),
+    ("polishing", "polish"),
+    ("himantopus", "himantopu"),
+    ("moral", "moral"),
+    ("wilmot", "wilmot"),
+    ("cranes", "crane"),
+    ("intiv", "intiv"),
+    ("ourangoutang", "ourangoutang"),
+    ("swillenhausen", "swillenhausen"),
+    ("tentatively", "tent"),
+    ("cumin", "cumin"),
+    ("juniors", "junior"),
+    ("conversationally", "conversation"),
+    ("crashing", "crash"),
+    ("sections", "section"),
+    ("fiction", "fiction"),
+    ("joful", "jo"),
+    ("harlot", "harlot"),
+    ("gazing", "gaze"),
+    ("sulivan", "sulivan"),
+    ("equalling", "equal"),
+    ("luck", "luck"),
+    ("lameness", "lame"),
+    ("fourpence", "fourpenc"),
+    ("fabulous", "fabul"),
+    ("informer", "inform"),
+    ("paternally", "patern"),
+    ("pugnacity", "pugnac"),
+    ("sofa", "sofa"),
+    ("saying", "sai"),
+    ("coincidences", "coincid"),
+    ("palely", "pale"),
+    ("pet", "pet"),
+    ("unwillingness", "unwilling"),
+    ("launched", "launch"),
+    ("earliest", "earliest"),
+    ("vestry", "vestri"),
+    ("directions", "direct"),
+    ("incrustations", "incrustaz"),
+    ("sumhoo", "sumhoo"),
+    ("otherwise", "otherwis"),
+    ("bedevilment", "bedevil"),
+    ("burgomeister", "burgomeist"),
+    ("mazes", "maze"),
+    ("wainscot", "wainscot"),
+    ("grimed", "grime"),
+    ("joyful", "joy"),
+    ("des", "de"),
+    ("cabooses", "caboos"),
+    ("brunt", "brunt"),
+    ("deliberating", "deliber"),
+    ("ignored", "ignor"),
+    ("bindings", "bind"),
+    ("guiltily", "guiltili"),
+    ("disarmed", "disarm"),
+    ("godson", "godson"),
+    ("unexpectedly", "unexpectedli"),
+    ("distract", "distract"),
+    ("poignant", "poignant"),
+    ("cauldron", "cauldron"),
+    ("touchwood", "touchwood"),
+    ("alarming", "alarm"),
+    ("grossly", "grossli"),
+    ("mischances", "mischanc"),
+    ("blatta", "blatta"),
+    ("bird", "bird"),
+    ("townspeople", "townspeopl"),
+    ("spo", "spo"),
+    ("renown", "renown"),
+    ("speechless", "speechless"),
+    ("validity", "valid"),
+    ("recross", "recross"),
+    ("antipodean", "antipodean"),
+    ("suspected", "suspect"),
+    ("jumbled", "jumbl"),
+    ("omnipotence", "omnipot"),
+    ("team", "team"),
+    ("rumbling", "rumbl"),
+    ("martens", "marten"),
+    ("johann", "johann"),
+    ("sportive", "sportiv"),
+    ("canes", "cane"),
+    ("sealing", "seal"),
+    ("jane", "jane"),
+    ("undying", "undi"),
+    ("darkens", "darken"),
+    ("surmounted", "surmount"),
+    ("quakings", "quak"),
+    ("expedience", "expedi"),
+    ("chase", "chase"),
+    ("outstanding", "outstand"),
+    ("quaking", "quak"),
+    ("wrong", "wrong"),
+    ("peccet", "peccet"),
+    ("thunderings", "thunder"),
+    ("hoop", "hoop"),
+    ("conjuration", "conjuraz"),
+    ("mooney", "moonei"),
+    ("titular", "titular"),
+    ("saxon", "saxon"),
+    ("ariel", "ariel"),
+    ("overhear", "overhear"),
+    ("malice", "malic"),
+    ("powell", "powel"),
+    ("imself", "imself"),
+    ("unapproachable", "unapproach"),
+    ("conspirator", "conspir"),
+    ("dramatised", "dramatis"),
+    ("critique", "critiqu"),
+    ("swampy", "swampi"),
+    ("began", "began"),
+    ("superficially", "superfici"),
+    ("persevere", "persever"),
+    ("brooch", "brooch"),
+    ("pert", "pert"),
+    ("munich", "munich"),
+    ("municipal", "municip"),
+    ("speculations", "speculaz"),
+    ("companies", "compani"),
+    ("reid", "reid"),
+    ("pathway", "pathwai"),
+    ("trout", "trout"),
+    ("dismiss", "dismiss"),
+    ("idle", "idl"),
+    ("coxcombs", "coxcomb"),
+    ("neigh", "neigh"),
+    ("banging", "bang"),
+    ("foretelling", "foretel"),
+    ("falsetto", "falsetto"),
+    ("bibron", "bibron"),
+    ("alured", "alur"),
+    ("pitcher", "pitcher"),
+    ("cynic", "cynic"),
+    ("armadilloes", "armadillo"),
+    ("lest", "lest"),
+    ("hurly", "hurli"),
+    ("opinionative", "opinion"),
+    ("barnton", "barnton"),
+    ("premium", "premium"),
+    ("sketched", "sketch"),
+    ("cues", "cue"),
+    ("wather", "wather"),
+    ("wickedest", "wickedest"),
+    ("triumphed", "triumph"),
+    ("studded", "stud"),
+    ("audibly", "audibli"),
+    ("seize", "seiz"),
+    ("effets", "effet"),
+    ("jolquera", "jolquera"),
+    ("heated", "heat"),
+    ("unsubstantial", "unsubstanti"),
+    ("cependent", "cepend"),
+    ("besprinkled", "besprinkl"),
+    ("pledging", "pledg"),
+    ("inheriting", "inherit"),
+    ("borrowers", "borrow"),
+    ("apportioned", "apport"),
+    ("kenwigs", "kenwig"),
+    ("dance", "danc"),
+    ("repudiate", "repudi"),
+    ("spain", "spain"),
+    ("branches", "branch"),
+    ("tertiary", "tertiari"),
+    ("saluting", "salut"),
+    ("conical", "conic"),
+    ("girlhood", "girlhood"),
+    ("glassful", "glass"),
+    ("farmhouses", "farmhous"),
+    ("biped", "bipe"),
+    ("deplorably", "deplor"),
+    ("bounty", "bounti"),
+    ("astelia", "astelia"),
+    ("replace", "replac"),
+    ("furtive", "furtiv"),
+    ("conjure", "conjur"),
+    ("recounting", "recount"),
+    ("pest", "pest"),
+    ("valuables", "valuabl"),
+    ("choiceness", "choic"),
+    ("propound", "propound"),
+    ("pernambuco", "pernambuco"),
+    ("rue", "rue"),
+    ("assez", "assez"),
+    ("undergoes", "undergo"),
+    ("inured", "inur"),
+    ("isolated", "isol"),
+    ("sarah", "sarah"),
+    ("scissor", "scissor"),
+    ("meadows", "meadow"),
+    ("neglected", "neglect"),
+    ("fold", "fold"),
+    ("unspeakably", "unspeak"),
+    ("hou", "hou"),
+    ("hardworking", "hardwork"),
+    ("irresolute", "irresolut"),
+    ("few", "few"),
+    ("gatherer", "gather"),
+    ("death", "death"),
+    ("offal", "offal"),
+    ("transacted", "transact"),
+    ("caress", "caress"),
+    ("bellies", "belli"),
+    ("heap", "heap"),
+    ("landowners", "landown"),
+    ("quilimari", "quilimari"),
+    ("clients", "client"),
+    ("beefsteak", "beefsteak"),
+    ("bravo", "bravo"),
+    ("crocodiles", "crocodil"),
+    ("unfitting", "unfit"),
+    ("solacing", "solac"),
+    ("singularities", "singular"),
+    ("finds", "find"),
+    ("suffrages", "suffrag"),
+    ("fouque", "fouqu"),
+    ("inference", "infer"),
+    ("acquiesced", "acquiesc"),
+    ("naturelles", "naturell"),
+    ("taller", "taller"),
+    ("ticket", "ticket"),
+    ("automaton", "automaton"),
+    ("side", "side"),
+    ("circumstances", "circumst"),
+    ("romantically", "romant"),
+    ("ostend", "ostend"),
+    ("female", "femal"),
+    ("prig", "prig"),
+    ("playwright", "playwright"),
+    ("marbles", "marbl"),
+    ("had", "had"),
+    ("ruff", "ruff"),
+    ("collieries", "collieri"),
+    ("subsequently", "subsequ"),
+    ("derision", "deris"),
+    ("afeard", "afeard"),
+    ("refuses", "refus"),
+    ("rill", "rill"),
+    ("appellation", "appellaz"),
+    ("prosper", "prosper"),
+    ("unsuited", "unsuit"),
+    ("stern", "stern"),
+    ("expletus", "expletu"),
+    ("suicides", "suicid"),
+    ("skylight", "skylight"),
+    ("weathered", "weather"),
+    ("hammersmith", "hammersmith"),
+    ("fazendas", "fazenda"),
+    ("uppermost", "uppermost"),
+    ("weddings", "wed"),
+    ("ambuscade", "ambuscad"),
+    ("pealed", "peal"),
+    ("ninety", "nineti"),
+    ("wrings", "wring"),
+    ("grotesque", "grotesqu"),
+    ("parvulus", "parvulu"),
+    ("convenience", "conveni"),
+    ("dresden", "dresden"),
+    ("phalanstery", "phalansteri"),
+    ("forgivingly", "forgivingli"),
+    ("frightfully", "frightfulli"),
+    ("fichy", "fichi"),
+    ("deane", "dean"),
+    ("xxv", "xxv"),
+    ("refused", "refus"),
+    ("keepsake", "keepsak"),
+    ("buys", "bui"),
+    ("contumely", "contum"),
+    ("moonths", "moonth"),
+    ("infallibly", "infallibli"),
+    ("rowed", "row"),
+    ("staled", "stale"),
+    ("concise", "concis"),
+    ("finch", "finch"),
+    ("volunteer", "volunt"),
+    ("persistency", "persist"),
+    ("deposition", "deposit"),
+    ("surplus", "surplu"),
+    ("subsist", "subsist"),
+    ("hoongry", "hoongri"),
+    ("fireman", "fireman"),
+    ("streaks", "streak"),
+    ("gleefully", "gleefulli"),
+    ("constitute", "constitut"),
+    ("treated", "treat"),
+    ("loom", "loom"),
+    ("bodied", "bodi"),
+    ("appointments", "appoint"),
+    ("pye", "pye"),
+    ("embodied", "embodi"),
+    ("conducive", "conduc"),
+    ("exhibits", "exhibit"),
+    ("aboveboard", "aboveboard"),
+    ("dulcet", "dulcet"),
+    ("recount", "recount"),
+    ("imposing", "impos"),
+    ("locations", "locaz"),
+    ("fuega", "fuega"),
+    ("lachrymose", "lachrymos"),
+    ("maligned", "malign"),
+    ("struggle", "struggl"),
+    ("choose", "choos"),
+    ("upheaval", "upheav"),
+    ("impresses", "impress"),
+    ("mire", "mire"),
+    ("earnestly", "earnestli"),
+    ("tenderly", "tenderli"),
+    ("petitioned", "petit"),
+    ("remarkedly", "remarkedli"),
+    ("after", "after"),
+    ("chirping", "chirp"),
+    ("gra", "gra"),
+    ("ballenagh", "ballenagh"),
+    ("lillyvick", "lillyvick"),
+    ("adore", "ador"),
+    ("thawed", "thaw"),
+    ("paints", "paint"),
+    ("scorns", "scorn"),
+    ("kennington", "kennington"),
+    ("scraps", "scrap"),
+    ("outraged", "outrag"),
+    ("sufflciently", "sufflcient"),
+    ("sandpiper", "sandpip"),
+    ("unusual", "unusu"),
+    ("stagecoach", "stagecoach"),
+    ("sword", "sword"),
+    ("robys", "robi"),
+    ("ramsgate", "ramsgat"),
+    ("soldier", "soldier"),
+    ("bronze", "bronz"),
+    ("penguins", "penguin"),
+    ("glencora", "glencora"),
+    ("ty", "ti"),
+    ("usnera", "usnera"),
+    ("treasure", "treasur"),
+    ("seaside", "seasid"),
+    ("cuffs", "cuff"),
+    ("unpacked", "unpack"),
+    ("involves", "involv"),
+    ("horses", "hors"),
+    ("tinkling", "tinkl"),
+    ("charity", "chariti"),
+    ("inhumanity", "inhuman"),
+    ("sentence", "sentenc"),
+    ("unobserved", "unobserv"),
+    ("squabbles", "squabbl"),
+    ("obedient", "obedi"),
+    ("calve", "calv"),
+    ("profaning", "profan"),
+    ("habiliments", "habili"),
+    ("chartism", "chartism"),
+    ("hat", "hat"),
+    ("steep", "steep"),
+    ("leaped", "leap"),
+    ("twickenham", "twickenham"),
+    ("injudiciously", "injudici"),
+    ("sallies", "sal"),
+    ("liability", "liabl"),
+    ("points", "point"),
+    ("eddying", "eddi"),
+    ("flustra", "flustra"),
+    ("stokes", "stoke"),
+    ("drowned", "drown"),
+    ("como", "como"),
+    ("sweetness", "sweet"),
+    ("rescued", "rescu"),
+    ("nooses", "noos"),
+    ("surmises", "surmis"),
+    ("reverberates", "reverber"),
+    ("playfellow", "playfellow"),
+    ("diverse", "divers"),
+    ("retorts", "retort"),
+    ("kangaroo", "kangaroo"),
+    ("quartering", "quarter"),
+    ("goethe", "goeth"),
+    ("verified", "verifi"),
+    ("realize", "real"),
+    ("splendidly", "splendidli"),
+    ("horizon", "horizon"),
+    ("behaving", "behav"),
+    ("slay", "slai"),
+    ("zoo", "zoo"),
+    ("australia", "australia"),
+    ("waywardness", "wayward"),
+    ("touchingly", "touchingli"),
+    ("hang", "hang"),
+    ("intermittent", "intermitt"),
+    ("accusers", "accus"),
+    ("annie", "anni"),
+    ("scruple", "scrupl"),
+    ("lebeziatnikov", "lebeziatnikov"),
+    ("balsam", "balsam"),
+    ("pain", "pain"),
+    ("congregation", "congregaz"),
+    ("wonderment", "wonder"),
+    ("banqueting", "banquet"),
+    ("pamplemousses", "pamplemouss"),
+    ("anchorage", "anchorag"),
+    ("gong", "gong"),
+    ("drew", "drew"),
+    ("narrated", "narrat"),
+    ("interwoven", "interwoven"),
+    ("orchis", "orchi"),
+    ("wiled", "wile"),
+    ("figuireda", "figuireda"),
+    ("chimney", "chimnei"),
+    ("shattered", "shatter"),
+    ("sunny", "sunni"),
+    ("flatness", "flat"),
+    ("whirling", "whirl"),
+    ("subjecting", "subject"),
+    ("irresponsible", "irrespons"),
+    ("galleries", "galleri"),
+    ("tableau", "tableau"),
+    ("concepcion", "concepcion"),
+    ("envelops", "envelop"),
+    ("excuse", "excus"),
+    ("summoned", "summon"),
+    ("stickler", "stickler"),
+    ("reputable", "reput"),
+    ("advisable", "advis"),
+    ("tipsy", "tipsi"),
+    ("dweller", "dweller"),
+    ("pitchers", "pitcher"),
+    ("pen", "pen"),
+    ("lavished", "lavish"),
+    ("topsel", "topsel"),
+    ("reverence", "rever"),
+    ("eighteen", "eighteen"),
+    ("variations", "variaz"),
+    ("sonorously", "sonor"),
+    ("geoffroy", "geoffroi"),
+    ("lawver", "lawver"),
+    ("condeseending", "condeseend"),
+    ("turning", "turn"),
+    ("decreed", "decre"),
+    ("frock", "frock"),
+    ("abjectly", "abjectli"),
+    ("housekeeping", "housekeep"),
+    ("reminders", "remind"),
+    ("sap", "sap"),
+    ("diligence", "dilig"),
+    ("advertise", "advertis"),
+    ("nassa", "nassa"),
+    ("transgressed", "transgress"),
+    ("porter", "porter"),
+    ("countrywomen", "countrywomen"),
+    ("unruly", "unruli"),
+    ("undermined", "undermin"),
+    ("about", "about"),
+    ("aforesaid", "aforesaid"),
+    ("confining", "confin"),
+    ("mannered", "manner"),
+    ("rubrum", "rubrum"),
+    ("especial", "especi"),
+    ("adorning", "adorn"),
+    ("incredibly", "incredibli"),
+    ("jogging", "jog"),
+    ("repulsed", "repuls"),
+    ("possessors", "possessor"),
+    ("customers", "custom"),
+    ("complexion", "complexion"),
+    ("expectation", "expectaz"),
+    ("swim", "swim"),
+    ("twitching", "twitch"),
+    ("pavlovitch", "pavlovitch"),
+    ("jarring", "jar"),
+    ("founds", "found"),
+    ("reputation", "reputaz"),
+    ("abandonment", "abandon"),
+    ("vulgar", "vulgar"),
+    ("skampling", "skampl"),
+    ("oryctes", "oryct"),
+    ("morbury", "morburi"),
+    ("irrigating", "irrig"),
+    ("flounces", "flounc"),
+    ("thomas", "thoma"),
+    ("status", "statu"),
+    ("estimates", "estim"),
+    ("your", "your"),
+    ("wrinkling", "wrinkl"),
+    ("kazan", "kazan"),
+    ("prepossession", "prepossess"),
+    ("intoxication", "intoxicaz"),
+    ("ways", "wai"),
+    ("six", "six"),
+    ("taxed", "tax"),
+    ("monetary", "monetari"),
+    ("roy", "roi"),
+    ("mail", "mail"),
+    ("hindrance", "hindranc"),
+    ("ruinous", "ruinou"),
+    ("unpremeditated", "unpremedit"),
+    ("public", "public"),
+    ("forewarning", "forewarn"),
+    ("tle", "tle"),
+    ("grunt", "grunt"),
+    ("handle", "handl"),
+    ("thickly", "thickli"),
+    ("packhorse", "packhors"),
+    ("amalia", "amalia"),
+    ("sailor", "sailor"),
+    ("discoursing", "discours"),
+    ("cigarettes", "cigarett"),
+    ("punished", "punish"),
+    ("based", "base"),
+    ("naughtiest", "naughtiest"),
+    ("hosts", "host"),
+    ("clink", "clink"),
+    ("fatal", "fatal"),
+    ("denominations", "denominaz"),
+    ("signals", "signal"),
+    ("cauliflowers", "cauliflow"),
+    ("coaches", "coach"),
+    ("hired", "hire"),
+    ("streamed", "stream"),
+    ("janes", "jane"),
+    ("milking", "milk"),
+    ("betray", "betrai"),
+    ("stephenson", "stephenson"),
+    ("whereupon", "whereupon"),
+    ("las", "la"),
+    ("centre", "centr"),
+    ("jaguars", "jaguar"),
+    ("parties", "parti"),
+    ("exalting", "exalt"),
+    ("dews", "dew"),
+    ("gypsum", "gypsum"),
+    ("pecoris", "pecori"),
+    ("append", "append"),
+    ("sickness", "sick"),
+    ("settling", "settl"),
+    ("peaceable", "peaceabl"),
+    ("petrified", "petrifi"),
+    ("stigmatizing", "stigmat"),
+    ("whitefriars", "whitefriar"),
+    ("infrequency", "infrequ"),
+    ("azure", "azur"),
+    ("fateful", "fate"),
+    ("steamed", "steam"),
+    ("willage", "willag"),
+    ("norval", "norval"),
+    ("purely", "pure"),
+    ("lar", "lar"),
+    ("immutability", "immut"),
+    ("contradictions", "contradict"),
+    ("gloried", "glori"),
+    ("seen", "seen"),
+    ("v", "v"),
+    ("educator", "educ"),
+    ("sweetener", "sweeten"),
+    ("silkiness", "silki"),
+    ("operation", "operaz"),
+    ("remotest", "remotest"),
+    ("birth", "birth"),
+    ("countryrmen", "countryrmen"),
+    ("obliteration", "obliteraz"),
+    ("prodigal", "prodig"),
+    ("belled", "bell"),
+    ("underrated", "underr"),
+    ("organs", "organ"),
+    ("monneyment", "monneyment"),
+    ("grossness", "gross"),
+    ("navies", "navi"),
+    ("sauntering", "saunter"),
+    ("equitable", "equit"),
+    ("encircle", "encircl"),
+    ("clks", "clk"),
+    ("alluded", "allud"),
+    ("domains", "domain"),
+    ("superlatives", "superl"),
+    ("embroil", "embroil"),
+    ("twilight", "twilight"),
+    ("extinguisher", "extinguish"),
+    ("biffin", "biffin"),
+    ("radiation", "radiaz"),
+    ("constructed", "construct"),
+    ("conquest", "conquest"),
+    ("including", "includ"),
+    ("splashes", "splash"),
+    ("hillock", "hillock"),
+    ("jeopardy", "jeopardi"),
+    ("rece", "rece"),
+    ("fitnesses", "fit"),
+    ("exulting", "exult"),
+    ("mules", "mule"),
+    ("putrefaction", "putrefact"),
+    ("gases", "gase"),
+    ("sowerby", "sowerbi"),
+    ("bricks", "brick"),
+    ("boan", "boan"),
+    ("disclosing", "disclos"),
+    ("warrn", "warrn"),
+    ("needy", "needi"),
+    ("madonna", "madonna"),
+    ("rosa", "rosa"),
+    ("lt", "lt"),
+    ("robust", "robust"),
+    ("efforts", "effort"),
+    ("buttoning", "button"),
+    ("bristol", "bristol"),
+    ("chinking", "chink"),
+    ("closing", "close"),
+    ("doant", "doant"),
+    ("infidelity", "infidel"),
+    ("deceit", "deceit"),
+    ("malignance", "malign"),
+    ("monastery", "monasteri"),
+    ("fathers", "father"),
+    ("galapageian", "galapageian"),
+    ("reverential", "reverenti"),
+    ("thereon", "thereon"),
+    ("abscess", "abscess"),
+    ("adjusted", "adjust"),
+    ("faith", "faith"),
+    ("viciousness", "vicious"),
+    ("middl", "middl"),
+    ("resentfully", "resentfulli"),
+    ("disgraceful", "disgrac"),
+    ("bushe", "bush"),
+    ("appeals", "appeal"),
+    ("tainted", "taint"),
+    ("rest", "rest"),
+    ("crammed", "cram"),
+    ("humanely", "human"),
+    ("stimulating", "stimul"),
+    ("decanters", "decant"),
+    ("copious", "copiou"),
+    ("onwholesome", "onwholesom"),
+    ("embarassed", "embarass"),
+    ("advise", "advis"),
+    ("mansion", "mansion"),
+    ("wis", "wi"),
+    ("blo", "blo"),
+    ("london", "london"),
+    ("puncture", "punctur"),
+    ("commented", "comment"),
+    ("dreamings", "dream"),
+    ("mixing", "mix"),
+    ("emanation", "emanaz"),
+    ("discussed", "discuss"),
+    ("adrianople", "adrianopl"),
+    ("ornithorhynchus", "ornithorhynchu"),
+    ("pries", "pri"),
+    ("crying", "cri"),
+    ("same", "same"),
+    ("oppressor", "oppressor"),
+    ("shanks", "shank"),
+    ("ideal", "ideal"),
+    ("departing", "depart"),
+    ("oriels", "oriel"),
+    ("reverted", "revert"),
+    ("cypress", "cypress"),
+    ("syria", "syria"),
+    ("lineage", "lineag"),
+    ("bars", "bar"),
+    ("commttted", "commtt"),
+    ("communicates", "commun"),
+    ("inexpressibles", "inexpress"),
+    ("irreconcilable", "irreconcil"),
+    ("gossamer", "gossam"),
+    ("bigoted", "bigot"),
+    ("joan", "joan"),
+    ("embittered", "embitt"),
+    ("lighthouses", "lighthous"),
+    ("sought", "sought"),
+    ("reverberated", "reverber"),
+    ("heirs", "heir"),
+    ("shod", "shod"),
+    ("bewilder", "bewild"),
+    ("trois", "troi"),
+    ("littlenesses", "littl"),
+    ("rout", "rout"),
+    ("for", "for"),
+    ("farms", "farm"),
+    ("possibly", "possibli"),
+    ("cordially", "cordial"),
+    ("sapphire", "sapphir"),
+    ("execrable", "execr"),
+    ("dawdling", "dawdl"),
+    ("travellers", "travel"),
+    ("abandon", "abandon"),
+    ("identification", "identificaz"),
+    ("wonld", "wonld"),
+    ("harmonic", "harmon"),
+    ("roue", "roue"),
+    ("exclamations", "exclamaz"),
+    ("februa", "februa"),
+    ("analyzed", "analyz"),
+    ("soup", "soup"),
+    ("incased", "incas"),
+    ("plaudits", "plaudit"),
+    ("foh", "foh"),
+    ("popolorum", "popolorum"),
+    ("casualties", "casualti"),
+    ("prismatic", "prismat"),
+    ("budge", "budg"),
+    ("cancellaria", "cancellaria"),
+    ("mawkish", "mawkish"),
+    ("mattress", "mattress"),
+    ("unvarying", "unvari"),
+    ("ligh", "ligh"),
+    ("musn", "musn"),
+    ("pumila", "pumila"),
+    ("antediluvian", "antediluvian"),
+    ("dora", "dora"),
+    ("perused", "perus"),
+    ("defrauded", "defraud"),
+    ("volumnias", "volumnia"),
+    ("whalers", "whaler"),
+    ("meshes", "mesh"),
+    ("solon", "solon"),
+    ("untrustworthy", "untrustworthi"),
+    ("cholechel", "cholechel"),
+    ("oughtn", "oughtn"),
+    ("ethereal", "ether"),
+    ("fretfulness", "fret"),
+    ("instructive", "instruct"),
+    ("whereon", "whereon"),
+    ("caused", "caus"),
+    ("fluent", "fluent"),
+    ("ownership", "ownership"),
+    ("quixote", "quixot"),
+    ("corresponded", "correspond"),
+    ("savoured", "savour"),
+    ("perjured", "perjur"),
+    ("medallions", "medallion"),
+    ("outre", "outr"),
+    ("filial", "filial"),
+    ("kingly", "kingli"),
+    ("pestilence", "pestil"),
+    ("starch", "starch"),
+    ("pea", "pea"),
+    ("cottage", "cottag"),
+    ("cisterns", "cistern"),
+    ("whoever", "whoever"),
+    ("inspire", "inspir"),
+    ("soreness", "sore"),
+    ("klopstock", "klopstock"),
+    ("brakes", "brake"),
+    ("crichton", "crichton"),
+    ("untarnished", "untarnish"),
+    ("irrational", "irrat"),
+    ("hypocrite", "hypocrit"),
+    ("fairer", "fairer"),
+    ("exposes", "expos"),
+    ("lazos", "lazo"),
+    ("accept", "accept"),
+    ("anxious", "anxiou"),
+    ("commends", "commend"),
+    ("monied", "moni"),
+    ("reed", "reed"),
+    ("entertainer", "entertain"),
+    ("vassilyevsky", "vassilyevski"),
+    ("sight", "sight"),
+    ("mystified", "mystifi"),
+    ("distressfully", "distressfulli"),
+    ("carbonate", "carbon"),
+    ("surly", "surli"),
+    ("unfashionable", "unfashion"),
+    ("overhaul", "overhaul"),
+    ("beneficial", "benefici"),
+    ("excelled", "excel"),
+    ("butler", "butler"),
+    ("elements", "element"),
+    ("highwayman", "highwayman"),
+    ("grimbles", "grimbl"),
+    ("rolle", "roll"),
+    ("receptions", "recept"),
+    ("fussy", "fussi"),
+    ("bone", "bone"),
+    ("asserting", "assert"),
+    ("jingled", "jingl"),
+    ("assertions", "assert"),
+    ("vitality", "vital"),
+    ("almos", "almo"),
+    ("kozel", "kozel"),
+    ("glided", "glide"),
+    ("stanza", "stanza"),
+    ("mercilessly", "mercilessli"),
+    ("cangrejales", "cangrejal"),
+    ("earings", "ear"),
+    ("optic", "optic"),
+    ("disturbs", "disturb"),
+    ("utter", "utter"),
+    ("swift", "swift"),
+    ("proclaims", "proclaim"),
+    ("indignities", "indign"),
+    ("consorting", "consort"),
+    ("unskilful", "unskil"),
+    ("n", "n"),
+    ("world", "world"),
+    ("coating", "coat"),
+    ("divisions", "divis"),
+    ("unlock", "unlock"),
+    ("slenderly", "slenderli"),
+    ("frill", "frill"),
+    ("albicores", "albicor"),
+    ("twos", "two"),
+    ("rogers", "roger"),
+    ("arbitrarily", "arbitrarili"),
+    ("observes", "observ"),
+    ("producible", "produc"),
+    ("spying", "spi"),
+    ("actor", "actor"),
+    ("collectively", "collect"),
+    ("kerr", "kerr"),
+    ("crusaders", "crusad"),
+    ("chalk", "chalk"),
+    ("duffy", "duffi"),
+    ("anybody", "anybodi"),
+    ("elderly", "elderli"),
+    ("picture", "pictur"),
+    ("change", "chang"),
+    ("discomfiture", "discomfitur"),
+    ("veils", "veil"),
+    ("primary", "primari"),
+    ("wore", "wore"),
+    ("articulated", "articul"),
+    ("figure", "figur"),
+    ("fumbled", "fumbl"),
+    ("disappearance", "disappear"),
+    ("unburied", "unburi"),
+    ("plotting", "plot"),
+    ("insubordinate", "insubordin"),
+    ("fule", "fule"),
+    ("covers", "cover"),
+    ("bedclothes", "bedcloth"),
+    ("admitted", "admit"),
+    ("commons", "common"),
+    ("walpole", "walpol"),
+    ("abhorred", "abhor"),
+    ("yielding", "yield"),
+    ("agueros", "aguero"),
+    ("beds", "bed"),
+    ("indefinable", "indefin"),
+    ("loosen", "loosen"),
+    ("augments", "augment"),
+    ("jesuit", "jesuit"),
+    ("riddles", "riddl"),
+    ("encampment", "encamp"),
+    ("marching", "march"),
+    ("nihil", "nihil"),
+    ("sally", "sal"),
+    ("cockney", "cocknei"),
+    ("shook", "shook"),
+    ("deftly", "deftli"),
+    ("expend", "expend"),
+    ("macpherson", "macpherson"),
+    ("flogged", "flog"),
+    ("distinct", "distinct"),
+    ("rarefied", "rarefi"),
+    ("blackbird", "blackbird"),
+    ("restive", "restiv"),
+    ("yolk", "yolk"),
+    ("mistakenly", "mistakenli"),
+    ("treason", "treason"),
+    ("strop", "strop"),
+    ("coat", "coat"),
+    ("parchappe", "parchapp"),
+    ("fie", "fie"),
+    ("someday", "somedai"),
+    ("tut", "tut"),
+    ("laz", "laz"),
+    ("plaire", "plair"),
+    ("session", "session"),
+    ("shrillness", "shril"),
+    ("knots", "knot"),
+    ("ironical", "iron"),
+    ("bajada", "bajada"),
+    ("yonder", "yonder"),
+    ("raskolnikov", "raskolnikov"),
+    ("punctatissima", "punctatissima"),
+    ("evasively", "evas"),
+    ("feverishly", "feverishli"),
+    ("shouldn", "shouldn"),
+    ("rub", "rub"),
+    ("bog", "bog"),
+    ("buckram", "buckram"),
+    ("farewells", "farewel"),
+    ("savings", "save"),
+    ("veered", "veer"),
+    ("instea", "instea"),
+    ("seniority", "senior"),
+    ("pollute", "pollut"),
+    ("diseases", "diseas"),
+    ("overboard", "overboard"),
+    ("apertures", "apertur"),
+    ("disfigured", "disfigur"),
+    ("fifth", "fifth"),
+    ("shame", "shame"),
+    ("vanities", "vaniti"),
+    ("myrtus", "myrtu"),
+    ("spiritual", "spiritu"),
+    ("chitons", "chiton"),
+    ("haply", "hapli"),
+    ("amble", "ambl"),
+    ("correspond", "correspond"),
+    ("fulfilment", "fulfil"),
+    ("commission", "commiss"),
+    ("depicted", "depict"),
+    ("interchanged", "interchang"),
+    ("mowed", "mow"),
+    ("eh", "eh"),
+    ("pierce", "pierc"),
+    ("perplex", "perplex"),
+    ("exploit", "exploit"),
+    ("vie", "vie"),
+    ("warrior", "warrior"),
+    ("dullards", "dullard"),
+    ("discourages", "discourag"),
+    ("coppice", "coppic"),
+    ("sweetmeat", "sweetmeat"),
+    ("archest", "archest"),
+    ("indicates", "indic"),
+    ("wishes", "wish"),
+    ("reeds", "reed"),
+    ("vamp", "vamp"),
+    ("rogues", "rogu"),
+    ("altitude", "altitud"),
+    ("monge", "mong"),
+    ("pugilistic", "pugilist"),
+    ("champions", "champion"),
+    ("irresistible", "irresist"),
+    ("effecting", "effect"),
+    ("effort", "effort"),
+    ("drowning", "drown"),
+    ("askew", "askew"),
+    ("chonchi", "chonchi"),
+    ("triumphantly", "triumphantli"),
+    ("scoop", "scoop"),
+    ("debased", "debas"),
+    ("whisked", "whisk"),
+    ("qualifying", "qualifi"),
+    ("albertine", "albertin"),
+    ("superhuman", "superhuman"),
+    ("instruments", "instrument"),
+    ("sala", "sala"),
+    ("workroom", "workroom"),
+    ("parent", "parent"),
+    ("restrain", "restrain"),
+    ("resolves", "resolv"),
+    ("antennatus", "antennatu"),
+    ("determines", "determin"),
+    ("soothingly", "soothingli"),
+    ("chafe", "chafe"),
+    ("abhorring", "abhor"),
+    ("plentiful", "plenti"),
+    ("felony", "feloni"),
+    ("cabbage", "cabbag"),
+    ("unsuspecting", "unsuspect"),
+    ("ministration", "ministraz"),
+    ("xxxvii", "xxxvii"),
+    ("sagacity", "sagac"),
+    ("authenticated", "authent"),
+    ("agrarian", "agrarian"),
+    ("rebuke", "rebuk"),
+    ("coquimbo", "coquimbo"),
+    ("well", "well"),
+    ("squeerses", "squeers"),
+    ("procure", "procur"),
+    ("substantials", "substanti"),
+    ("unwilling", "unwil"),
+    ("duplicate", "duplic"),
+    ("inhabited", "inhabit"),
+    ("civilly", "civilli"),
+    ("telescopes", "telescop"),
+    ("irregular", "irregular"),
+    ("skylights", "skylight"),
+    ("hesitation", "hesitaz"),
+    ("breathless", "breathless"),
+    ("false", "fals"),
+    ("hartlepod", "hartlepod"),
+    ("consignment", "consign"),
+    ("frugality", "frugal"),
+    ("communicant", "communic"),
+    ("revision", "revis"),
+    ("apprentices", "apprentic"),
+    ("fast", "fast"),
+    ("toe", "toe"),
+    ("magpies", "magpi"),
+    ("upbraid", "upbraid"),
+    ("sarmiento", "sarmiento"),
+    ("noted", "note"),
+    ("inorganic", "inorgan"),
+    ("mission", "mission"),
+    ("germinating", "germin"),
+    ("kilt", "kilt"),
+    ("corridor", "corridor"),
+    ("doubted", "doubt"),
+    ("narration", "narraz"),
+    ("uncompressed", "uncompress"),
+    ("cavil", "cavil"),
+    ("hollower", "hollow"),
+    ("cuckoos", "cuckoo"),
+    ("entertainment", "entertain"),
+    ("dissipating", "dissip"),
+    ("lenient", "lenient"),
+    ("bowered", "bower"),
+    ("doubting", "doubt"),
+    ("amendment", "amend"),
+    ("swamps", "swamp"),
+    ("appealingly", "appealingli"),
+    ("erst", "erst"),
+    ("rounding", "round"),
+    ("fort", "fort"),
+    ("hamstrings", "hamstr"),
+    ("reptiles", "reptil"),
+    ("steddy", "steddi"),
+    ("hen", "hen"),
+    ("imprinted", "imprint"),
+    ("pierces", "pierc"),
+    ("upstart", "upstart"),
+    ("thwarting", "thwart"),
+    ("stamps", "stamp"),
+    ("basks", "bask"),
+    ("steadfastly", "steadfastli"),
+    ("plea", "plea"),
+    ("marquises", "marquis"),
+    ("handful", "hand"),
+    ("ridendus", "ridendu"),
+    ("furnishes", "furnish"),
+    ("chiselled", "chisel"),
+    ("apathy", "apathi"),
+    ("sob", "sob"),
+    ("newer", "newer"),
+    ("reappearance", "reappear"),
+    ("recomposing", "recompos"),
+    ("thread", "thread"),
+    ("yankee", "yanke"),
+    ("opossum", "opossum"),
+    ("central", "central"),
+    ("generic", "gener"),
+    ("weigh", "weigh"),
+    ("refurnish", "refurnish"),
+    ("bole", "bole"),
+    ("bellavista", "bellavista"),
+    ("benefiting", "benefit"),
+    ("wife", "wife"),
+    ("fidgety", "fidgeti"),
+    ("negotiate", "negoti"),
+    ("surgeon", "surgeon"),
+    ("flash", "flash"),
+    ("expectations", "expectaz"),
+    ("likewise", "likewis"),
+    ("ponds", "pond"),
+    ("prickly", "prickli"),
+    ("driblets", "driblet"),
+    ("sandals", "sandal"),
+    ("coot", "coot"),
+    ("sylph", "sylph"),
+    ("concealments", "conceal"),
+    ("discontentedly", "discontentedli"),
+    ("buttered", "butter"),
+    ("carmine", "carmin"),
+    ("tomb", "tomb"),
+    ("tyrone", "tyron"),
+    ("host", "host"),
+    ("oranges", "orang"),
+    ("cheeriness", "cheeri"),
+    ("oldest", "oldest"),
+    ("sixieme", "sixiem"),
+    ("beetles", "beetl"),
+    ("chopkins", "chopkin"),
+    ("marriages", "marriag"),
+    ("copied", "copi"),
+    ("quickly", "quickli"),
+    ("sackcloth", "sackcloth"),
+    ("sunk", "sunk"),
+    ("feast", "feast"),
+    ("bye", "bye"),
+    ("fifteenth", "fifteenth"),
+    ("credited", "credit"),
+    ("consciously", "conscious"),
+    ("throbbed", "throb"),
+    ("realised", "realis"),
+    ("whisper", "whisper"),
+    ("dozed", "doze"),
+    ("limbs", "limb"),
+    ("revert", "revert"),
+    ("olives", "oliv"),
+    ("reams", "ream"),
+    ("est", "est"),
+    ("large", "larg"),
+    ("crevices", "crevic"),
+    ("victorious", "victori"),
+    ("unassisted", "unassist"),
+    ("captious", "captiou"),
+    ("kerchief", "kerchief"),
+    ("breccia", "breccia"),
+    ("von", "von"),
+    ("entwined", "entwin"),
+    ("teatime", "teatim"),
+    ("encroach", "encroach"),
+    ("feariocious", "fearioci"),
+    ("lured", "lure"),
+    ("glad", "glad"),
+    ("poin", "poin"),
+    ("patagonicus", "patagonicu"),
+    ("veering", "veer"),
+    ("widowhood", "widowhood"),
+    ("surrender", "surrend"),
+    ("coroner", "coron"),
+    ("healing", "heal"),
+    ("awoke", "awok"),
+    ("nestled", "nestl"),
+    ("brisk", "brisk"),
+    ("disavowals", "disavow"),
+    ("coppery", "copperi"),
+    ("beheaded", "behead"),
+    ("peevish", "peevish"),
+    ("pernicious", "pernici"),
+    ("bagnets", "bagnet"),
+    ("behind", "behind"),
+    ("prudently", "prudent"),
+    ("fawn", "fawn"),
+    ("indo", "indo"),
+    ("leastways", "leastwai"),
+    ("issuing", "issu"),
+    ("infant", "infant"),
+    ("libraries", "librari"),
+    ("thoughtless", "thoughtless"),
+    ("whey", "whei"),
+    ("undid", "undid"),
+    ("trivets", "trivet"),
+    ("beholding", "behold"),
+    ("ces", "ce"),
+    ("pichy", "pichi"),
+    ("hallucination", "hallucinaz"),
+    ("inefficacy", "inefficaci"),
+    ("snowy", "snowi"),
+    ("indoor", "indoor"),
+    ("swindlers", "swindler"),
+    ("morpheus", "morpheu"),
+    ("muslin", "muslin"),
+    ("spleen", "spleen"),
+    ("trigonometry", "trigonometri"),
+    ("plunges", "plung"),
+    ("endanger", "endang"),
+    ("primeval", "primev"),
+    ("visiting", "visit"),
+    ("peculiarities", "peculiar"),
+    ("luxuriant", "luxuri"),
+    ("orangeman", "orangeman"),
+    ("ladies", "ladi"),
+    ("suggest", "suggest"),
+    ("dreadfully", "dreadfulli"),
+    ("pox", "pox"),
+    ("punctilious", "punctili"),
+    ("puckered", "pucker"),
+    ("reprinted", "reprint"),
+    ("duller", "duller"),
+    ("forbear", "forbear"),
+    ("misinterpreted", "misinterpret"),
+    ("uninterested", "uninterest"),
+    ("pardon", "pardon"),
+    ("bent", "bent"),
+    ("weir", "weir"),
+    ("solander", "soland"),
+    ("slatternly", "slatternli"),
+    ("craws", "craw"),
+    ("skilfully", "skilfulli"),
+    ("aid", "aid"),
+    ("wheedlin", "wheedlin"),
+    ("repress", "repress"),
+    ("avoidable", "avoid"),
+    ("belgravian", "belgravian"),
+    ("mumbled", "mumbl"),
+    ("motherly", "motherli"),
+    ("appetites", "appetit"),
+    ("reassuringly", "reassuringli"),
+    ("slouching", "slouch"),
+    ("dustman", "dustman"),
+    ("emerges", "emerg"),
+    ("consents", "consent"),
+    ("pity", "piti"),
+    ("councillor", "councillor"),
+    ("jobbed", "job"),
+    ("inquirers", "inquir"),
+    ("roosting", "roost"),
+    ("smoothed", "smooth"),
+    ("dabbling", "dabbl"),
+    ("westerly", "westerli"),
+    ("waterwheel", "waterwheel"),
+    ("lawyers", "lawyer"),
+    ("rolling", "roll"),
+    ("tardiness", "tardi"),
+    ("incessant", "incess"),
+    ("glorying", "glori"),
+    ("precluded", "preclud"),
+    ("greenish", "greenish"),
+    ("upjohn", "upjohn"),
+    ("bodkins", "bodkin"),
+    ("equatorial", "equatori"),
+    ("dainties", "dainti"),
+    ("buttoned", "button"),
+    ("preventing", "prevent"),
+    ("scrutiny", "scrutini"),
+    ("missing", "miss"),
+    ("sentinels", "sentinel"),
+    ("distracts", "distract"),
+    ("ingleses", "ingles"),
+    ("unthankfulness", "unthank"),
+    ("pavlovna", "pavlovna"),
+    ("ominous", "omin"),
+    ("som", "som"),
+    ("gucho", "gucho"),
+    ("stammer", "stammer"),
+    ("placidly", "placidli"),
+    ("addresses", "address"),
+    ("heightens", "heighten"),
+    ("sideboard", "sideboard"),
+    ("plan", "plan"),
+    ("livingstone", "livingston"),
+    ("mourners", "mourner"),
+    ("artistical", "artist"),
+    ("ascends", "ascend"),
+    ("dustier", "dustier"),
+    ("dozen", "dozen"),
+    ("mulcted", "mulct"),
+    ("bewitched", "bewitch"),
+    ("boars", "boar"),
+    ("indescribable", "indescrib"),
+    ("gambolling", "gambol"),
+    ("balancing", "balanc"),
+    ("balanced", "balanc"),
+    ("backward", "backward"),
+    ("idiocy", "idioci"),
+    ("genuine", "genuin"),
+    ("petulant", "petul"),
+    ("summers", "summer"),
+    ("clappings", "clap"),
+    ("ba", "ba"),
+    ("protested", "protest"),
+    ("rang", "rang"),
+    ("jea", "jea"),
+    ("unutterable", "unutter"),
+    ("widely", "wide"),
+    ("akin", "akin"),
+    ("continue", "continu"),
+    ("thick", "thick"),
+    ("dumbfounded", "dumbfound"),
+    ("titmouse", "titmous"),
+    ("housemaker", "housemak"),
+    ("glittered", "glitter"),
+    ("kenwigses", "kenwigs"),
+    ("dampier", "dampier"),
+    ("smells", "smell"),
+    ("unfit", "unfit"),
+    ("hardened", "harden"),
+    ("madwoman", "madwoman"),
+    ("alterations", "alteraz"),
+    ("buy", "bui"),
+    ("lowering", "lower"),
+    ("leadenhall", "leadenhal"),
+    ("jura", "jura"),
+    ("haphazard", "haphazard"),
+    ("floods", "flood"),
+    ("player", "player"),
+    ("uniform", "uniform"),
+    ("fourths", "fourth"),
+    ("lacked", "lack"),
+    ("auxiliaries", "auxiliari"),
+    ("demeanour", "demeanour"),
+    ("questioning", "question"),
+    ("adjurations", "adjuraz"),
+    ("sniggering", "snigger"),
+    ("eglantine", "eglantin"),
+    ("nonsensical", "nonsens"),
+    ("softly", "softli"),
+    ("loudness", "loud"),
+    ("ferns", "fern"),
+    ("harshest", "harshest"),
+    ("colony", "coloni"),
+    ("libel", "libel"),
+    ("waltz", "waltz"),
+    ("overtaking", "overtak"),
+    ("affords", "afford"),
+    ("voluntarily", "voluntarili"),
+    ("trudging", "trudg"),
+    ("uncleanness", "unclean"),
+    ("undress", "undress"),
+    ("unappeasable", "unappeas"),
+    ("ludicrously", "ludicr"),
+    ("marie", "mari"),
+    ("spiritless", "spiritless"),
+    ("serfs", "serf"),
+    ("mattresses", "mattress"),
+    ("rincon", "rincon"),
+    ("shak", "shak"),
+    ("flowing", "flow"),
+    ("gasps", "gasp"),
+    ("monkeyish", "monkeyish"),
+    ("zig", "zig"),
+    ("castigation", "castigaz"),
+    ("intreat", "intreat"),
+    ("baden", "baden"),
+    ("ducklings", "duckl"),
+    ("greengrocer", "greengroc"),
+    ("reread", "reread"),
+    ("preserves", "preserv"),
+    ("rough", "rough"),
+    ("committee", "committe"),
+    ("feebler", "feebler"),
+    ("uncherished", "uncherish"),
+    ("vary", "vari"),
+    ("emphatically", "emphat"),
+    ("aquilines", "aquilin"),
+    ("corn", "corn"),
+    ("eastwards", "eastward"),
+    ("feat", "feat"),
+    ("casuarina", "casuarina"),
+    ("ironmaster", "ironmast"),
+    ("arrivals", "arriv"),
+    ("moth", "moth"),
+    ("ravine", "ravin"),
+    ("heptarchy", "heptarchi"),
+    ("maggots", "maggot"),
+    ("belonging", "belong"),
+    ("lifeless", "lifeless"),
+    ("sexes", "sex"),
+    ("accounted", "account"),
+    ("ashes", "ash"),
+    ("orinoco", "orinoco"),
+    ("telegram", "telegram"),
+    ("preached", "preach"),
+    ("clashed", "clash"),
+    ("cow", "cow"),
+    ("slanderer", "slander"),
+    ("golgotha", "golgotha"),
+    ("demi", "demi"),
+    ("force", "forc"),
+    ("compact", "compact"),
+    ("harsh", "harsh"),
+    ("uncompleted", "uncomplet"),
+    ("dominions", "dominion"),
+    ("heerd", "heerd"),
+    ("cavity", "caviti"),
+    ("apprise", "appris"),
+    ("spirt", "spirt"),
+    ("fund", "fund"),
+    ("footman", "footman"),
+    ("adjusts", "adjust"),
+    ("inserted", "insert"),
+    ("thrust", "thrust"),
+    ("patriarch", "patriarch"),
+    ("deyvlish", "deyvlish"),
+    ("mischeevious", "mischeevi"),
+    ("coodleites", "coodleit"),
+    ("luise", "luis"),
+    ("odium", "odium"),
+    ("gambled", "gambl"),
+    ("revels", "revel"),
+    ("frank", "frank"),
+    ("playing", "plai"),
+    ("events", "event"),
+    ("mix", "mix"),
+    ("donatia", "donatia"),
+    ("bramador", "bramador"),
+    ("freshened", "freshen"),
+    ("bespattered", "bespatt"),
+    ("singapore", "singapor"),
+    ("cropley", "croplei"),
+    ("steamers", "steamer"),
+    ("specified", "specifi"),
+    ("papa", "papa"),
+    ("leering", "leer"),
+    ("fogs", "fog"),
+    ("glove", "glove"),
+    ("condemning", "condemn"),
+    ("balci", "balci"),
+    ("littleness", "littl"),
+    ("tipperary", "tipperari"),
+    ("racehorses", "racehors"),
+    ("distinguishes", "distinguish"),
+    ("banda", "banda"),
+    ("languished", "languish"),
+    ("personages", "personag"),
+    ("human", "human"),
+    ("delusions", "delus"),
+    ("scrupulously", "scrupul"),
+    ("freeholders", "freehold"),
+    ("mansos", "manso"),
+    ("dinners", "dinner"),
+    ("capt", "capt"),
+    ("bedfellows", "bedfellow"),
+    ("phosphoric", "phosphor"),
+    ("shivers", "shiver"),
+    ("unknown", "unknown"),
+    ("gradations", "gradaz"),
+    ("finest", "finest"),
+    ("buckles", "buckl"),
+    ("innombrable", "innombr"),
+    ("rake", "rake"),
+    ("angriest", "angriest"),
+    ("vows", "vow"),
+    ("devoting", "devot"),
+    ("rapturous", "raptur"),
+    ("bloods", "blood"),
+    ("garthers", "garther"),
+    ("antiquarian", "antiquarian"),
+    ("act", "act"),
+    ("genially", "genial"),
+    ("jostle", "jostl"),
+    ("vanellus", "vanellu"),
+    ("feller", "feller"),
+    ("fertile", "fertil"),
+    ("lover", "lover"),
+    ("smartness", "smart"),
+    ("longbarns", "longbarn"),
+    ("turkish", "turkish"),
+    ("complaint", "complaint"),
+    ("undisguisable", "undisguis"),
+    ("designates", "design"),
+    ("familiar", "familiar"),
+    ("atheists", "atheist"),
+    ("planaria", "planaria"),
+    ("juice", "juic"),
+    ("charge", "charg"),
+    ("liveliest", "liveliest"),
+    ("uneasy", "uneasi"),
+    ("satin", "satin"),
+    ("squeaked", "squeak"),
+    ("autographs", "autograph"),
+    ("deserves", "deserv"),
+    ("associations", "associaz"),
+    ("stuffing", "stuf"),
+    ("daunted", "daunt"),
+    ("humbly", "humbli"),
+    ("strife", "strife"),
+    ("occupation", "occupaz"),
+    ("beginnings", "begin"),
+    ("brawler", "brawler"),
+    ("zebras", "zebra"),
+    ("disbelieved", "disbeliev"),
+    ("peculiarity", "peculiar"),
+    ("dog", "dog"),
+    ("roman", "roman"),
+    ("gott", "gott"),
+    ("azalea", "azalea"),
+    ("nuts", "nut"),
+    ("derisively", "deris"),
+    ("claspknife", "claspknif"),
+    ("egotistically", "egotist"),
+    ("shift", "shift"),
+    ("bethlehem", "bethlehem"),
+    ("anatomists", "anatomist"),
+    ("tumbling", "tumbl"),
+    ("cruz", "cruz"),
+    ("barrels", "barrel"),
+    ("pushed", "push"),
+    ("infinity", "infin"),
+    ("saith", "saith"),
+    ("badly", "badli"),
+    ("unnerved", "unnerv"),
+    ("results", "result"),
+    ("verite", "verit"),
+    ("demonstrations", "demonstraz"),
+    ("discomfort", "discomfort"),
+    ("sill", "sill"),
+    ("naval", "naval"),
+    ("cashmere", "cashmer"),
+    ("sledge", "sledg"),
+    ("bate", "bate"),
+    ("intimates", "intim"),
+    ("registrar", "registrar"),
+    ("attendant", "attend"),
+    ("myrmidons", "myrmidon"),
+    ("dust", "dust"),
+    ("stillness", "stil"),
+    ("effluvia", "effluvia"),
+    ("rudely", "rude"),
+    ("complex", "complex"),
+    ("giraffe", "giraff"),
+    ("thinning", "thin"),
+    ("contest", "contest"),
+    ("single", "singl"),
+    ("recent", "recent"),
+    ("flagrant", "flagrant"),
+    ("won", "won"),
+    ("exotics", "exot"),
+    ("amblyrhynchus", "amblyrhynchu"),
+    ("elevates", "elev"),
+    ("defrauding", "defraud"),
+    ("deductions", "deduct"),
+    ("drip", "drip"),
+    ("warnings", "warn"),
+    ("admissions", "admiss"),
+    ("musketry", "musketri"),
+    ("beatings", "beat"),
+    ("novos", "novo"),
+    ("one", "on"),
+    ("hundreds", "hundr"),
+    ("overtasked", "overtask"),
+    ("three", "three"),
+    ("gist", "gist"),
+    ("stair", "stair"),
+    ("wurd", "wurd"),
+    ("magnified", "magnifi"),
+    ("facility", "facil"),
+    ("roles", "role"),
+    ("gha", "gha"),
+    ("imperiously", "imperi"),
+    ("schooling", "school"),
+    ("unmarked", "unmark"),
+    ("snuffed", "snuf"),
+    ("decorously", "decor"),
+    ("befallen", "befallen"),
+    ("blusterers", "bluster"),
+    ("laudatory", "laudatori"),
+    ("apprised", "appris"),
+    ("accrued", "accru"),
+    ("bourbon", "bourbon"),
+    ("consorted", "consort"),
+    ("hyseters", "hyset"),
+    ("bankers", "banker"),
+    ("introducing", "introduc"),
+    ("persecute", "persecut"),
+    ("loyal", "loyal"),
+    ("republished", "republish"),
+    ("luxuries", "luxuri"),
+    ("protruded", "protrud"),
+    ("inefficacious", "inefficaci"),
+    ("confiscation", "confiscaz"),
+    ("clicking", "click"),
+    ("hon", "hon"),
+    ("ach", "ach"),
+    ("napoleon", "napoleon"),
+    ("divisio", "divisio"),
+    ("gauchos", "gaucho"),
+    ("clarke", "clark"),
+    ("distracted", "distract"),
+    ("retreating", "retreat"),
+    ("rachel", "rachel"),
+    ("save", "save"),
+    ("chancelor", "chancelor"),
+    ("undergone", "undergon"),
+    ("acceptances", "accept"),
+    ("controls", "control"),
+    ("murray", "murrai"),
+    ("kidney", "kidnei"),
+    ("refrain", "refrain"),
+    ("tapped", "tap"),
+    ("loosed", "loos"),
+    ("assiduously", "assidu"),
+    ("dmitri", "dmitri"),
+    ("pistols", "pistol"),
+    ("strolling", "stroll"),
+    ("leg", "leg"),
+    ("speak", "speak"),
+    ("defended", "defend"),
+    ("resembled", "resembl"),
+    ("punctuality", "punctual"),
+    ("guide", "guid"),
+    ("waved", "wave"),
+    ("discovery", "discoveri"),
+    ("saints", "saint"),
+    ("auld", "auld"),
+    ("capacity", "capac"),
+    ("complainings", "complain"),
+    ("sum", "sum"),
+    ("signalised", "signalis"),
+    ("cobblesborough", "cobblesborough"),
+    ("abraham", "abraham"),
+    ("volatile", "volatil"),
+    ("detesting", "detest"),
+    ("tetes", "tete"),
+    ("eneaf", "eneaf"),
+    ("nezhin", "nezhin"),
+    ("gould", "gould"),
+    ("quien", "quien"),
+    ("apostrophizing", "apostroph"),
+    ("garbled", "garbl"),
+    ("rooteth", "rooteth"),
+    ("apiece", "apiec"),
+    ("normously", "normous"),
+    ("creeper", "creeper"),
+    ("desires", "desir"),
+    ("basketful", "basket"),
+    ("recall", "recal"),
+    ("vituperative", "vitup"),
+    ("girl", "girl"),
+    ("questionable", "question"),
+    ("meadow", "meadow"),
+    ("sufferings", "suffer"),
+    ("regular", "regular"),
+    ("adder", "adder"),
+    ("courts", "court"),
+    ("throttled", "throttl"),
+    ("wins", "win"),
+    ("floodgates", "floodgat"),
+    ("reserve", "reserv"),
+    ("rectified", "rectifi"),
+    ("voyager", "voyager"),
+    ("courage", "courag"),
+    ("cranks", "crank"),
+    ("herbaceous", "herbac"),
+    ("temp", "temp"),
+    ("ai", "ai"),
+    ("liz", "liz"),
+    ("endlessly", "endlessli"),
+    ("carte", "cart"),
+    ("astronomical", "astronom"),
+    ("flemish", "flemish"),
+    ("clumsiness", "clumsi"),
+    ("discounters", "discount"),
+    ("vociferates", "vocifer"),
+    ("enterprise", "enterpris"),
+    ("hooks", "hook"),
+    ("gratify", "gratifi"),
+    ("conspiring", "conspir"),
+    ("piderit", "piderit"),
+    ("fate", "fate"),
+    ("storr", "storr"),
+    ("bears", "bear"),
+    ("carcasses", "carcass"),
+    ("saw", "saw"),
+    ("captain", "captain"),
+    ("repeatedly", "repeatedli"),
+    ("undone", "undon"),
+    ("sniff", "sniff"),
+    ("magnifies", "magnifi"),
+    ("etna", "etna"),
+    ("births", "birth"),
+    ("hamper", "hamper"),
+    ("oceans", "ocean"),
+    ("digitated", "digit"),
+    ("palings", "pale"),
+    ("buffoons", "buffoon"),
+    ("egyptian", "egyptian"),
+    ("blanca", "blanca"),
+    ("twopenn", "twopenn"),
+    ("feats", "feat"),
+    ("everlastingly", "everlastingli"),
+    ("wagner", "wagner"),
+    ("browdie", "browdi"),
+    ("bustled", "bustl"),
+    ("cataracts", "cataract"),
+    ("celebration", "celebraz"),
+    ("awakened", "awaken"),
+    ("die", "die"),
+    ("proportional", "proport"),
+    ("hospitality", "hospit"),
+    ("hectic", "hectic"),
+    ("pursuance", "pursuanc"),
+    ("gab", "gab"),
+    ("perfected", "perfect"),
+    ("consternation", "consternaz"),
+    ("increasingly", "increasingli"),
+    ("glittering", "glitter"),
+    ("commissioner", "commission"),
+    ("nun", "nun"),
+    ("quebec", "quebec"),
+    ("basins", "basin"),
+    ("shaver", "shaver"),
+    ("stride", "stride"),
+    ("descried", "descri"),
+    ("weep", "weep"),
+    ("hashed", "hash"),
+    ("insolently", "insol"),
+    ("motionles", "motionl"),
+    ("sachet", "sachet"),
+    ("prosy", "prosi"),
+    ("knocking", "knock"),
+    ("imbecile", "imbecil"),
+    ("splinter", "splinter"),
+    ("stepping", "step"),
+    ("resulted", "result"),
+    ("congratulating", "congratul"),
+    ("grimly", "grimli"),
+    ("contradicting", "contradict"),
+    ("wuns", "wun"),
+    ("scholarship", "scholarship"),
+    ("tenure", "tenur"),
+    ("swillenhausens", "swillenhausen"),
+    ("uninjured", "uninjur"),
+    ("gales", "gale"),
+    ("toss", "toss"),
+    ("imp", "imp"),
+    ("expressions", "express"),
+    ("manual", "manual"),
+    ("exultingly", "exultingli"),
+    ("workhouse", "workhous"),
+    ("ken", "ken"),
+    ("upsallata", "upsallata"),
+    ("finlshed", "finlsh"),
+    ("chancery", "chanceri"),
+    ("question", "question"),
+    ("group", "group"),
+    ("stump", "stump"),
+    ("odious", "odiou"),
+    ("roughness", "rough"),
+    ("bountiful", "bounti"),
+    ("front", "front"),
+    ("mingled", "mingl"),
+    ("ignoring", "ignor"),
+    ("therefore", "therefor"),
+    ("shooting", "shoot"),
+    ("precise", "precis"),
+    ("fives", "five"),
+    ("conformity", "conform"),
+    ("gayest", "gayest"),
+    ("vinoque", "vinoqu"),
+    ("pretends", "pretend"),
+    ("tarts", "tart"),
+    ("arqueros", "arquero"),
+    ("swelled", "swell"),
+    ("nomads", "nomad"),
+    ("abated", "abat"),
+    ("captive", "captiv"),
+    ("avicularia", "avicularia"),
+    ("monsoon", "monsoon"),
+    ("cinders", "cinder"),
+    ("quest", "quest"),
+    ("wall", "wall"),
+    ("painters", "painter"),
+    ("divers", "diver"),
+    ("chin", "chin"),
+    ("procellaria", "procellaria"),
+    ("muleteer", "mulet"),
+    ("refers", "refer"),
+    ("crave", "crave"),
+    ("palliate", "palliat"),
+    ("horsepittle", "horsepittl"),
+    ("aghast", "aghast"),
+    ("orbignyi", "orbignyi"),
+    ("pleasure", "pleasur"),
+    ("labyrinth", "labyrinth"),
+    ("concluded", "conclud"),
+    ("kindlier", "kindlier"),
+    ("petulance", "petul"),
+    ("month", "month"),
+    ("gloating", "gloat"),
+    ("divulges", "divulg"),
+    ("camels", "camel"),
+    ("excellently", "excel"),
+    ("nigger", "nigger"),
+    ("monomaniac", "monomaniac"),
+    ("jaws", "jaw"),
+    ("response", "respons"),
+    ("emancipation", "emancipaz"),
+    ("cincinnatus", "cincinnatu"),
+    ("unintellectual", "unintellectu"),
+    ("nakedness", "naked"),
+    ("murinus", "murinu"),
+    ("toned", "tone"),
+    ("seriatim", "seriatim"),
+    ("vague", "vagu"),
+    ("matches", "match"),
+    ("screeched", "screech"),
+    ("retail", "retail"),
+    ("perfectly", "perfectli"),
+    ("suction", "suction"),
+    ("exclusions", "exclus"),
+    ("cheat", "cheat"),
+    ("wyelands", "wyeland"),
+    ("cob", "cob"),
+    ("pansies", "pansi"),
+    ("taint", "taint"),
+    ("asked", "ask"),
+    ("carstone", "carston"),
+    ("liquor", "liquor"),
+    ("dolorous", "dolor"),
+    ("stays", "stai"),
+    ("destination", "destinaz"),
+    ("das", "da"),
+    ("unsustainable", "unsustain"),
+    ("immensely", "immens"),
+    ("preponderating", "preponder"),
+    ("staringly", "staringli"),
+    ("will", "will"),
+    ("identity", "ident"),
+    ("sheet", "sheet"),
+    ("report", "report"),
+    ("truer", "truer"),
+    ("tapes", "tape"),
+    ("incompatible", "incompat"),
+    ("fairly", "fairli"),
+    ("remunerate", "remuner"),
+    ("initiated", "initi"),
+    ("fleshing", "flesh"),
+    ("cure", "cure"),
+    ("heralded", "herald"),
+    ("letter", "letter"),
+    ("shiftings", "shift"),
+    ("guyaquil", "guyaquil"),
+    ("remembrances", "remembr"),
+    ("beast", "beast"),
+    ("care", "care"),
+    ("stagnation", "stagnaz"),
+    ("predecessor", "predecessor"),
+    ("gentlemen", "gentlemen"),
+    ("mask", "mask"),
+    ("perpetually", "perpetu"),
+    ("unmatched", "unmatch"),
+    ("compression", "compress"),
+    ("joy", "joi"),
+    ("somers", "somer"),
+    ("unpainted", "unpaint"),
+    ("slam", "slam"),
+    ("grope", "grope"),
+    ("inapplicable", "inapplic"),
+    ("least", "least"),
+    ("hutch", "hutch"),
+    ("injunction", "injunct"),
+    ("swears", "swear"),
+    ("maccoort", "maccoort"),
+    ("infirm", "infirm"),
+    ("into", "into"),
+    ("promoting", "promot"),
+    ("wisest", "wisest"),
+    ("criminality", "crimin"),
+    ("scold", "scold"),
+    ("pitilessly", "pitilessli"),
+    ("cemetery", "cemeteri"),
+    ("hypotheses", "hypothes"),
+    ("lon", "lon"),
+    ("covertly", "covertli"),
+    ("regaled", "regal"),
+    ("pad", "pad"),
+    ("evince", "evinc"),
+    ("kindred", "kindr"),
+    ("salinas", "salina"),
+    ("secretion", "secret"),
+    ("cocos", "coco"),
+    ("dissipate", "dissip"),
+    ("jolt", "jolt"),
+    ("relents", "relent"),
+    ("iron", "iron"),
+    ("prevost", "prevost"),
+    ("neuroptera", "neuroptera"),
+    ("geological", "geolog"),
+    ("assembly", "assembli"),
+    ("yield", "yield"),
+    ("macfuzlem", "macfuzlem"),
+    ("gooseberry", "gooseberri"),
+    ("feeble", "feebl"),
+    ("pulling", "pull"),
+    ("nothing", "noth"),
+    ("utamme", "utamm"),
+    ("populated", "popul"),
+    ("mayfair", "mayfair"),
+    ("ry", "ri"),
+    ("misunderstand", "misunderstand"),
+    ("colonist", "colonist"),
+    ("presumed", "presum"),
+    ("agers", "ager"),
+    ("groundlessness", "groundless"),
+    ("jaw", "jaw"),
+    ("unroofed", "unroof"),
+    ("arabic", "arab"),
+    ("cleaned", "clean"),
+    ("body", "bodi"),
+    ("brushing", "brush"),
+    ("recognizes", "recogn"),
+    ("carnivorous", "carnivor"),
+    ("wrench", "wrench"),
+    ("scott", "scott"),
+    ("profusion", "profus"),
+    ("rural", "rural"),
+    ("soporific", "soporif"),
+    ("juan", "juan"),
+    ("unliquidated", "unliquid"),
+    ("bindloes", "bindlo"),
+    ("assoc", "assoc"),
+    ("creatures", "creatur"),
+    ("bellower", "bellow"),
+    ("gives", "give"),
+    ("unresisting", "unresist"),
+    ("illustrious", "illustri"),
+    ("extolling", "extol"),
+    ("sinfulness", "sin"),
+    ("harmony", "harmoni"),
+    ("secrecy", "secreci"),
+    ("vice", "vice"),
+    ("clench", "clench"),
+    ("hating", "hate"),
+    ("tenant", "tenant"),
+    ("despotism", "despot"),
+    ("tugged", "tug"),
+    ("filing", "file"),
+    ("forfeit", "forfeit"),
+    ("tucutucos", "tucutuco"),
+    ("despicably", "despic"),
+    ("peninsular", "peninsular"),
+    ("ices", "ic"),
+    ("wand", "wand"),
+    ("nulliporae", "nullipora"),
+    ("quartered", "quarter"),
+    ("writings", "write"),
+    ("elephant", "eleph"),
+    ("morleena", "morleena"),
+    ("maunder", "maunder"),
+    ("dimension", "dimens"),
+    ("durham", "durham"),
+    ("unassailable", "unassail"),
+    ("encircles", "encircl"),
+    ("ghost", "ghost"),
+    ("charmingly", "charmingli"),
+    ("poorer", "poorer"),
+    ("brown", "brown"),
+    ("housed", "hous"),
+    ("contempt", "contempt"),
+    ("haltica", "haltica"),
+    ("diopaea", "diopaea"),
+    ("approach", "approach"),
+    ("condemned", "condemn"),
+    ("grasses", "grass"),
+    ("violet", "violet"),
+    ("asiatiques", "asiatiqu"),
+    ("forged", "forg"),
+    ("stunning", "stun"),
+    ("semyonova", "semyonova"),
+    ("offends", "offend"),
+    ("engraven", "engraven"),
+    ("envied", "envi"),
+    ("necklace", "necklac"),
+    ("anymore", "anymor"),
+    ("twists", "twist"),
+    ("poltroon", "poltroon"),
+    ("supervene", "superven"),
+    ("unmans", "unman"),
+    ("alway", "alwai"),
+    ("category", "categori"),
+    ("dreariness", "dreari"),
+    ("spithead", "spithead"),
+    ("suppurated", "suppur"),
+    ("fetid", "fetid"),
+    ("unscrupulously", "unscrupul"),
+    ("subversive", "subvers"),
+    ("aviary", "aviari"),
+    ("pardoned", "pardon"),
+    ("specimens", "specimen"),
+    ("haughtiest", "haughtiest"),
+    ("delightfully", "delightfulli"),
+    ("dastard", "dastard"),
+    ("belly", "belli"),
+    ("horseflesh", "horseflesh"),
+    ("hooker", "hooker"),
+    ("wicker", "wicker"),
+    ("lamentably", "lament"),
+    ("living", "live"),
+    ("uselessness", "useless"),
+    ("philosophical", "philosoph"),
+    ("emancipist", "emancipist"),
+    ("snakes", "snake"),
+    ("reformed", "reform"),
+    ("hearths", "hearth"),
+    ("apothecaries", "apothecari"),
+    ("anyvays", "anyvai"),
+    ("accomplishes", "accomplish"),
+    ("scampered", "scamper"),
+    ("gasp", "gasp"),
+    ("dish", "dish"),
+    ("grates", "grate"),
+    ("anomalous", "anomal"),
+    ("rejoiced", "rejoic"),
+    ("bonnet", "bonnet"),
+    ("winged", "wing"),
+    ("tucuman", "tucuman"),
+    ("shrimp", "shrimp"),
+    ("realized", "real"),
+    ("messrs", "messr"),
+    ("close", "close"),
+    ("ledrook", "ledrook"),
+    ("brandished", "brandish"),
+    ("hounds", "hound"),
+    ("courtier", "courtier"),
+    ("axes", "ax"),
+    ("coy", "coi"),
+    ("sockets", "socket"),
+    ("trodden", "trodden"),
+    ("pandanus", "pandanu"),
+    ("wed", "wed"),
+    ("generals", "gener"),
+    ("stuffed", "stuf"),
+    ("fissure", "fissur"),
+    ("gilded", "gild"),
+    ("history", "histori"),
+    ("girlishly", "girlishli"),
+    ("whisk", "whisk"),
+    ("imitator", "imit"),
+    ("swordsmen", "swordsmen"),
+    ("detached", "detach"),
+    ("bitterest", "bitterest"),
+    ("mauritius", "mauritiu"),
+    ("re", "re"),
+    ("navedad", "navedad"),
+    ("fowler", "fowler"),
+    ("teardrop", "teardrop"),
+    ("gravelly", "gravelli"),
+    ("croup", "croup"),
+    ("paralysed", "paralys"),
+    ("knit", "knit"),
+    ("trumpeters", "trumpet"),
+    ("hazards", "hazard"),
+    ("counselled", "counsel"),
+    ("knew", "knew"),
+    ("begrimes", "begrim"),
+    ("adventure", "adventur"),
+    ("edin", "edin"),
+    ("sale", "sale"),
+    ("ramification", "ramificaz"),
+    ("canvass", "canvass"),
+    ("outlaw", "outlaw"),
+    ("botanists", "botanist"),
+    ("affected", "affect"),
+    ("term", "term"),
+    ("indestructible", "indestruct"),
+    ("nekrassov", "nekrassov"),
+    ("matthews", "matthew"),
+    ("comings", "come"),
+    ("abstain", "abstain"),
+    ("linendraper", "linendrap"),
+    ("bolted", "bolt"),
+    ("weary", "weari"),
+    ("tenor", "tenor"),
+    ("lichtenstein", "lichtenstein"),
+    ("cherries", "cherri"),
+    ("onerous", "oner"),
+    ("expressed", "express"),
+    ("roughly", "roughli"),
+    ("undertaking", "undertak"),
+    ("capella", "capella"),
+    ("footprints", "footprint"),
+    ("profligately", "proflig"),
+    ("resolutions", "resolut"),
+    ("intercept", "intercept"),
+    ("anatomical", "anatom"),
+    ("entreats", "entreat"),
+    ("dissenter", "dissent"),
+    ("emerge", "emerg"),
+    ("dibabses", "dibabs"),
+    ("slep", "slep"),
+    ("saluted", "salut"),
+    ("northern", "northern"),
+    ("chartist", "chartist"),
+    ("criminate", "crimin"),
+    ("discoverers", "discover"),
+    ("blustering", "bluster"),
+    ("clima", "clima"),
+    ("faithful", "faith"),
+    ("hybernation", "hybernaz"),
+    ("engrossing", "engross"),
+    ("premeditation", "premeditaz"),
+    ("webb", "webb"),
+    ("iv", "iv"),
+    ("talks", "talk"),
+    ("impassible", "impass"),
+    ("blackamoor", "blackamoor"),
+    ("flirted", "flirt"),
+    ("knobs", "knob"),
+    ("peck", "peck"),
+    ("controvert", "controvert"),
+    ("displaces", "displac"),
+    ("straggling", "straggl"),
+    ("forsook", "forsook"),
+    ("flagellator", "flagel"),
+    ("hundred", "hundr"),
+    ("chapels", "chapel"),
+    ("anniversary", "anniversari"),
+    ("constituents", "constitu"),
+    ("smoky", "smoki"),
+    ("ribbentrop", "ribbentrop"),
+    ("impossibilities", "imposs"),
+    ("argued", "argu"),
+    ("themes", "theme"),
+    ("chinese", "chines"),
+    ("judith", "judith"),
+    ("corporeal", "corpor"),
+    ("reproducing", "reproduc"),
+    ("pinnacled", "pinnacl"),
+    ("vein", "vein"),
+    ("remnant", "remnant"),
+    ("pretence", "pretenc"),
+    ("blandest", "blandest"),
+    ("housekeeper", "housekeep"),
+    ("remembers", "rememb"),
+    ("explanation", "explanaz"),
+    ("reflections", "reflect"),
+    ("excusable", "excus"),
+    ("symbol", "symbol"),
+    ("gaining", "gain"),
+    ("arrowing", "arrow"),
+    ("neva", "neva"),
+    ("spite", "spite"),
+    ("peltiroguses", "peltirogus"),
+    ("crowded", "crowd"),
+    ("scolding", "scold"),
+    ("smifser", "smifser"),
+    ("rephed", "reph"),
+    ("badge", "badg"),
+    ("agaves", "agav"),
+    ("oven", "oven"),
+    ("sued", "su"),
+    ("erased", "eras"),
+    ("cardoon", "cardoon"),
+    ("uncertainties", "uncertainti"),
+    ("inbred", "inbr"),
+    ("rejection", "reject"),
+    ("ore", "or"),
+    ("ploughs", "plough"),
+    ("pitying", "piti"),
+    ("bowl", "bowl"),
+    ("fastidiousness", "fastidi"),
+    ("buoyantly", "buoyantli"),
+    ("orator", "orat"),
+    ("wisitation", "wisitaz"),
+    ("flutteringiy", "flutteringii"),
+    ("contends", "contend"),
+    ("accumulating", "accumul"),
+    ("rokeby", "rokebi"),
+    ("cornerstone", "cornerston"),
+    ("transmits", "transmit"),
+    ("slashing", "slash"),
+    ("ake", "ak"),
+    ("mermaid", "mermaid"),
+    ("gabbled", "gabbl"),
+    ("constraining", "constrain"),
+    ("endeavour", "endeavour"),
+    ("grovel", "grovel"),
+    ("until", "until"),
+    ("abyss", "abyss"),
+    ("dependents", "depend"),
+    ("middleton", "middleton"),
+    ("dessert", "dessert"),
+    ("dwindled", "dwindl"),
+    ("woodchips", "woodchip"),
+    ("libation", "libaz"),
+    ("sacked", "sack"),
+    ("diffusion", "diffus"),
+    ("colour", "colour"),
+    ("subaqueous", "subaqu"),
+    ("melodrama", "melodrama"),
+    ("crop", "crop"),
+    ("testudo", "testudo"),
+    ("salubrity", "salubr"),
+    ("gibing", "gibe"),
+    ("neatness", "neat"),
+    ("bold", "bold"),
+    ("cree", "cree"),
+    ("rushing", "rush"),
+    ("distending", "distend"),
+    ("person", "person"),
+    ("turbulence", "turbul"),
+    ("dowry", "dowri"),
+    ("misgave", "misgav"),
+    ("cotton", "cotton"),
+    ("truths", "truth"),
+    ("falsity", "falsiti"),
+    ("respectfully", "respectfulli"),
+    ("diminishing", "diminish"),
+    ("aware", "awar"),
+    ("disobey", "disobei"),
+    ("afterwards", "afterward"),
+    ("cros", "cro"),
+    ("ornithology", "ornithologi"),
+    ("vulgarity", "vulgar"),
+    ("gambling", "gambl"),
+    ("kent", "kent"),
+    ("versailles", "versaill"),
+    ("corpus", "corpu"),
+    ("tells", "tell"),
+    ("observa", "observa"),
+    ("devotes", "devot"),
+    ("capability", "capabl"),
+    ("bumping", "bump"),
+    ("frond", "frond"),
+    ("levanted", "levant"),
+    ("hesitatingly", "hesitatingli"),
+    ("matter", "matter"),
+    ("antechamber", "antechamb"),
+    ("paddles", "paddl"),
+    ("baptistery", "baptisteri"),
+    ("edging", "edg"),
+    ("naming", "name"),
+    ("bobbed", "bob"),
+    ("malediction", "maledict"),
+    ("droll", "droll"),
+    ("paced", "pace"),
+    ("qulte", "qult"),
+    ("unprotected", "unprotect"),
+    ("fitness", "fit"),
+    ("xxxii", "xxxii"),
+    ("barest", "barest"),
+    ("emphatic", "emphat"),
+    ("buts", "but"),
+    ("foreigners", "foreign"),
+    ("evasion", "evas"),
+    ("overwhelming", "overwhelm"),
+    ("hearken", "hearken"),
+    ("immovability", "immov"),
+    ("so", "so"),
+    ("colt", "colt"),
+    ("confessing", "confess"),
+    ("van", "van"),
+    ("valueless", "valueless"),
+    ("dismantle", "dismantl"),
+    ("telephoridae", "telephorida"),
+    ("orbingy", "orbingi"),
+    ("october", "octob"),
+    ("take", "take"),
+    ("ins", "in"),
+    ("culpability", "culpabl"),
+    ("remarkably", "remark"),
+    ("favours", "favour"),
+    ("nateral", "nater"),
+    ("ah", "ah"),
+    ("zample", "zampl"),
+    ("truly", "truli"),
+    ("askance", "askanc"),
+    ("towns", "town"),
+    ("handwriting", "handwrit"),
+    ("marking", "mark"),
+    ("cheaper", "cheaper"),
+    ("trumpet", "trumpet"),
+    ("simple", "simpl"),
+    ("competency", "compet"),
+    ("marble", "marbl"),
+    ("khan", "khan"),
+    ("cubs", "cub"),
+    ("collector", "collector"),
+    ("apprehended", "apprehend"),
+    ("mauvais", "mauvai"),
+    ("snarling", "snarl"),
+    ("humbling", "humbl"),
+    ("waiomio", "waiomio"),
+    ("coffers", "coffer"),
+    ("befogged", "befog"),
+    ("vainest", "vainest"),
+    ("ensnare", "ensnar"),
+    ("twisting", "twist"),
+    ("detect", "detect"),
+    ("tragedian", "tragedian"),
+    ("provocative", "provoc"),
+    ("deum", "deum"),
+    ("pecuniary", "pecuniari"),
+    ("forgotten", "forgotten"),
+    ("allegorical", "allegor"),
+    ("uspallata", "uspallata"),
+    ("seventeenth", "seventeenth"),
+    ("complexions", "complexion"),
+    ("snare", "snare"),
+    ("principally", "princip"),
+    ("scullery", "sculleri"),
+    ("harmless", "harmless"),
+    ("undisposed", "undispos"),
+    ("welcomed", "welcom"),
+    ("converses", "convers"),
+    ("burdened", "burden"),
+    ("woolly", "woolli"),
+    ("restlessness", "restless"),
+    ("intensely", "intens"),
+    ("dolls", "doll"),
+    ("retrieving", "retriev"),
+    ("respecting", "respect"),
+    ("snuffy", "snuffi"),
+    ("damage", "damag"),
+    ("theatrical", "theatric"),
+    ("remarked", "remark"),
+    ("knack", "knack"),
+    ("esteems", "esteem"),
+    ("uncomplimentary", "uncomplimentari"),
+    ("gladden", "gladden"),
+    ("candle", "candl"),
+    ("casement", "casement"),
+    ("interspersing", "interspers"),
+    ("swallowed", "swallow"),
+    ("emasculate", "emascul"),
+    ("commotion", "commot"),
+    ("nasturtions", "nasturt"),
+    ("superseded", "supers"),
+    ("secretaryship", "secretaryship"),
+    ("supremest", "supremest"),
+    ("shown", "shown"),
+    ("horned", "horn"),
+    ("parsimonious", "parsimoni"),
+    ("distrusted", "distrust"),
+    ("alight", "alight"),
+    ("solo", "solo"),
+    ("forgives", "forgiv"),
+    ("marly", "marli"),
+    ("hood", "hood"),
+    ("walk", "walk"),
+    ("adapting", "adapt"),
+    ("untenanted", "unten"),
+    ("colla", "colla"),
+    ("hangers", "hanger"),
+    ("frightful", "fright"),
+    ("couldn", "couldn"),
+    ("proposing", "propos"),
+    ("weedy", "weedi"),
+    ("approval", "approv"),
+    ("durden", "durden"),
+    ("inexperience", "inexperi"),
+    ("discords", "discord"),
+    ("ear", "ear"),
+    ("heaving", "heav"),
+    ("swindler", "swindler"),
+    ("since", "sinc"),
+    ("gambier", "gambier"),
+    ("preceding", "preced"),
+    ("drinkers", "drinker"),
+    ("headdress", "headdress"),
+    ("revenges", "reveng"),
+    ("continued", "continu"),
+    ("niches", "nich"),
+    ("henceforward", "henceforward"),
+    ("dudgeon", "dudgeon"),
+    ("burdening", "burden"),
+    ("fairy", "fairi"),
+    ("impudent", "impud"),
+    ("yit", "yit"),
+    ("bequeathing", "bequeath"),
+    ("abhorrence", "abhorr"),
+    ("marines", "marin"),
+    ("knotty", "knotti"),
+    ("quarry", "quarri"),
+    ("haymaking", "haymak"),
+    ("jackdaws", "jackdaw"),
+    ("affectionately", "affection"),
+    ("devour", "devour"),
+    ("constancy", "constanc"),
+    ("unaccustomed", "unaccustom"),
+    ("jauntily", "jauntili"),
+    ("generalship", "generalship"),
+    ("swoop", "swoop"),
+    ("daubed", "daub"),
+    ("shoohoo", "shoohoo"),
+    ("butther", "butther"),
+    ("workbox", "workbox"),
+    ("lead", "lead"),
+    ("throb", "throb"),
+    ("frivolity", "frivol"),
+    ("bandit", "bandit"),
+    ("canoe", "cano"),
+    ("imply", "impli"),
+    ("tumble", "tumbl"),
+    ("papered", "paper"),
+    ("connecting", "connect"),
+    ("uniformity", "uniform"),
+    ("suspending", "suspend"),
+    ("hideousness", "hideous"),
+    ("pardons", "pardon"),
+    ("supporters", "support"),
+    ("rastro", "rastro"),
+    ("wrymug", "wrymug"),
+    ("unacquainted", "unacquaint"),
+    ("travelling", "travel"),
+    ("unfastened", "unfasten"),
+    ("approaching", "approach"),
+    ("deserting", "desert"),
+    ("gathering", "gather"),
+    ("throwing", "throw"),
+    ("aimless", "aimless"),
+    ("stallions", "stallion"),
+    ("vicencio", "vicencio"),
+    ("terebra", "terebra"),
+    ("apple", "appl"),
+    ("trollope", "trollop"),
+    ("illusions", "illus"),
+    ("brace", "brace"),
+    ("mellow", "mellow"),
+    ("resin", "resin"),
+    ("antarctic", "antarct"),
+    ("floating", "float"),
+    ("opuntia", "opuntia"),
+    ("fibrous", "fibrou"),
+    ("foreshadow", "foreshadow"),
+    ("wares", "ware"),
+    ("subjugating", "subjug"),
+    ("baneful", "bane"),
+    ("impossibility", "imposs"),
+    ("fragments", "fragment"),
+    ("tufaceous", "tufac"),
+    ("firmly", "firmli"),
+    ("tempter", "tempter"),
+    ("depressing", "depress"),
+    ("merited", "merit"),
+    ("lightning", "lightn"),
+    ("ordeal", "ordeal"),
+    ("eat", "eat"),
+    ("cod", "cod"),
+    ("patrolling", "patrol"),
+    ("halt", "halt"),
+    ("intolerable", "intoler"),
+    ("employed", "emploi"),
+    ("herbivorous", "herbivor"),
+    ("untrammelled", "untrammel"),
+    ("winnings", "win"),
+    ("together", "togeth"),
+    ("spectacle", "spectacl"),
+    ("hydrobius", "hydrobiu"),
+    ("sopped", "sop"),
+    ("telegraphic", "telegraph"),
+    ("decoration", "decoraz"),
+    ("morbid", "morbid"),
+    ("gird", "gird"),
+    ("markets", "market"),
+    ("oddities", "odditi"),
+    ("jacuitque", "jacuitqu"),
+    ("denser", "denser"),
+    ("galleys", "gallei"),
+    ("develop", "develop"),
+    ("simpered", "simper"),
+    ("demanding", "demand"),
+    ("breathlessness", "breathless"),
+    ("greengrocery", "greengroceri"),
+    ("youthful", "youth"),
+    ("punching", "punch"),
+    ("substratum", "substratum"),
+    ("playbills", "playbil"),
+    ("thereby", "therebi"),
+    ("locust", "locust"),
+    ("exhausting", "exhaust"),
+    ("baser", "baser"),
+    ("dukedom", "dukedom"),
+    ("lookee", "looke"),
+    ("fouled", "foul"),
+    ("unfrequently", "unfrequ"),
+    ("hurriedly", "hurriedli"),
+    ("arrear", "arrear"),
+    ("torment", "torment"),
+    ("circumnavigation", "circumnavigaz"),
+    ("etonnement", "etonn"),
+    ("thrashing", "thrash"),
+    ("energetically", "energet"),
+    ("moustache", "moustach"),
+    ("correspondingly", "correspondingli"),
+    ("affluence", "affluenc"),
+    ("confront", "confront"),
+    ("learned", "learn"),
+    ("chemist", "chemist"),
+    ("limit", "limit"),
+    ("probabilities", "probabl"),
+    ("assemblage", "assemblag"),
+    ("level", "level"),
+    ("sixpence", "sixpenc"),
+    ("dispensed", "dispens"),
+    ("extremest", "extremest"),
+    ("railing", "rail"),
+    ("shyest", "shyest"),
+    ("injurious", "injuri"),
+    ("wart", "wart"),
+    ("called", "call"),
+    ("required", "requir"),
+    ("lethargic", "letharg"),
+    ("yataghan", "yataghan"),
+    ("preoccupations", "preoccupaz"),
+    ("annihilated", "annihil"),
+    ("indoors", "indoor"),
+    ("deficiency", "defici"),
+    ("crepitans", "crepitan"),
+    ("denote", "denot"),
+    ("waterfowl", "waterfowl"),
+    ("implicate", "implic"),
+    ("fiercer", "fiercer"),
+    ("uneasily", "uneasili"),
+    ("sensations", "sensaz"),
+    ("gusts", "gust"),
+    ("handsomely", "handsom"),
+    ("septum", "septum"),
+    ("wished", "wish"),
+    ("unchastened", "unchasten"),
+    ("drunkenness", "drunken"),
+    ("yelping", "yelp"),
+    ("barbary", "barbari"),
+    ("deepened", "deepen"),
+    ("stealthily", "stealthili"),
+    ("enact", "enact"),
+    ("ordained", "ordain"),
+    ("bezzemelny", "bezzemelni"),
+    ("incompatibility", "incompat"),
+    ("attempted", "attempt"),
+    ("hauled", "haul"),
+    ("condition", "condit"),
+    ("pestering", "pester"),
+    ("crops", "crop"),
+    ("chiens", "chien"),
+    ("brewster", "brewster"),
+    ("joanna", "joanna"),
+    ("undergo", "undergo"),
+    ("blockaded", "blockad"),
+    ("completes", "complet"),
+    ("artificially", "artifici"),
+    ("magnesium", "magnesium"),
+    ("ascertain", "ascertain"),
+    ("foreboded", "forebod"),
+    ("isn", "isn"),
+    ("cherubim", "cherubim"),
+    ("hinted", "hint"),
+    ("nickname", "nicknam"),
+    ("skim", "skim"),
+    ("peachy", "peachi"),
+    ("dramatists", "dramatist"),
+    ("distantly", "distantli"),
+    ("groove", "groov"),
+    ("elfin", "elfin"),
+    ("famed", "fame"),
+    ("dorsal", "dorsal"),
+    ("slacken", "slacken"),
+    ("rum", "rum"),
+    ("teutons", "teuton"),
+    ("off", "off"),
+    ("church", "church"),
+    ("rottenness", "rotten"),
+    ("sourly", "sourli"),
+    ("creations", "creaz"),
+    ("worrit", "worrit"),
+    ("tentacula", "tentacula"),
+    ("pull", "pull"),
+    ("carranchas", "carrancha"),
+    ("talcahuano", "talcahuano"),
+    ("emerged", "emerg"),
+    ("enormously", "enorm"),
+    ("obviously", "obvious"),
+    ("does", "doe"),
+    ("propping", "prop"),
+    ("chileno", "chileno"),
+    ("maturity", "matur"),
+    ("preceptor", "preceptor"),
+    ("evaded", "evad"),
+    ("borriohoola", "borriohoola"),
+    ("filthiness", "filthi"),
+    ("revenue", "revenu"),
+    ("miserable", "miser"),
+    ("meaningless", "meaningless"),
+    ("relied", "re"),
+    ("presbyterian", "presbyterian"),
+    ("wavered", "waver"),
+    ("bayonet", "bayonet"),
+    ("sadovy", "sadovi"),
+    ("prosaic", "prosaic"),
+    ("strength", "strength"),
+    ("unite", "unit"),
+    ("mos", "mo"),
+    ("graces", "grace"),
+    ("loyalty", "loyalti"),
+    ("recalls", "recal"),
+    ("bivouacking", "bivouack"),
+    ("mechanical", "mechan"),
+    ("gwyneth", "gwyneth"),
+    ("bingley", "binglei"),
+    ("deprive", "depriv"),
+    ("nillandoo", "nillandoo"),
+    ("huechucucuy", "huechucucui"),
+    ("coadjutor", "coadjutor"),
+    ("nicely", "nice"),
+    ("fortunate", "fortun"),
+    ("sulphuratus", "sulphuratu"),
+    ("ember", "ember"),
+    ("madrinas", "madrina"),
+    ("drag", "drag"),
+    ("unshaven", "unshaven"),
+    ("scorched", "scorch"),
+    ("rejecting", "reject"),
+    ("stalk", "stalk"),
+    ("phosphorescent", "phosphoresc"),
+    ("reference", "refer"),
+    ("imprisoned", "imprison"),
+    ("effusion", "effus"),
+    ("objected", "object"),
+    ("curry", "curri"),
+    ("stercovorous", "stercovor"),
+    ("opponents", "oppon"),
+    ("detection", "detect"),
+    ("scorchingly", "scorchingli"),
+    ("suppers", "supper"),
+    ("pleasing", "pleas"),
+    ("boiled", "boil"),
+    ("briton", "briton"),
+    ("ends", "end"),
+    ("catastrophe", "catastroph"),
+    ("amenable", "amen"),
+    ("inquirer", "inquir"),
+    ("noonday", "noondai"),
+    ("allowing", "allow"),
+    ("barrier", "barrier"),
+    ("tufts", "tuft"),
+    ("designs", "design"),
+    ("attention", "attent"),
+    ("straggler", "straggler"),
+    ("how", "how"),
+    ("monthly", "monthli"),
+    ("scanning", "scan"),
+    ("catechism", "catech"),
+    ("greateful", "great"),
+    ("guasos", "guaso"),
+    ("prodigious", "prodigi"),
+    ("instrumental", "instrument"),
+    ("stalked", "stalk"),
+    ("intimidated", "intimid"),
+    ("interjection", "interject"),
+    ("domestics", "domest"),
+    ("avoidance", "avoid"),
+    ("secrete", "secret"),
+    ("angel", "angel"),
+    ("compromises", "compromis"),
+    ("objections", "object"),
+    ("equinox", "equinox"),
+    ("imaginable", "imagin"),
+    ("andalusia", "andalusia"),
+    ("prize", "prize"),
+    ("panics", "panic"),
+    ("calcareous", "calcar"),
+    ("ciphering", "cipher"),
+    ("modelling", "model"),
+    ("flagellation", "flagellaz"),
+    ("tables", "tabl"),
+    ("brazil", "brazil"),
+    ("eras", "era"),
+    ("stirrups", "stirrup"),
+    ("printed", "print"),
+    ("jaca", "jaca"),
+    ("screen", "screen"),
+    ("almanac", "almanac"),
+    ("hummocks", "hummock"),
+    ("proviso", "proviso"),
+    ("stranded", "strand"),
+    ("ascribes", "ascrib"),
+    ("cheques", "chequ"),
+    ("lulling", "lull"),
+    ("breakfast", "breakfast"),
+    ("braggart", "braggart"),
+    ("awaited", "await"),
+    ("mell", "mell"),
+    ("whistling", "whistl"),
+    ("babbling", "babbl"),
+    ("prayer", "prayer"),
+    ("importance", "import"),
+    ("gorse", "gors"),
+    ("misjudged", "misjudg"),
+    ("heeded", "he"),
+    ("repassing", "repass"),
+    ("spark", "spark"),
+    ("soop", "soop"),
+    ("bandy", "bandi"),
+    ("concealed", "conceal"),
+    ("burdensome", "burdensom"),
+    ("goblin", "goblin"),
+    ("meek", "meek"),
+    ("awaking", "awak"),
+    ("requests", "request"),
+    ("untenable", "unten"),
+    ("withers", "wither"),
+    ("whales", "whale"),
+    ("tardy", "tardi"),
+    ("period", "period"),
+    ("bedtime", "bedtim"),
+    ("diernan", "diernan"),
+    ("proof", "proof"),
+    ("gravy", "gravi"),
+    ("glistening", "glisten"),
+    ("transit", "transit"),
+    ("bung", "bung"),
+    ("administrative", "administr"),
+    ("quaint", "quaint"),
+    ("franchise", "franchis"),
+    ("designed", "design"),
+    ("weeds", "weed"),
+    ("bloodless", "bloodless"),
+    ("outs", "out"),
+    ("oars", "oar"),
+    ("salina", "salina"),
+    ("vitae", "vita"),
+    ("reverse", "revers"),
+    ("misericordia", "misericordia"),
+    ("affording", "afford"),
+    ("bosoms", "bosom"),
+    ("willingly", "willingli"),
+    ("realms", "realm"),
+    ("callousness", "callous"),
+    ("bullfinch", "bullfinch"),
+    ("succour", "succour"),
+    ("sacrifice", "sacrific"),
+    ("grapple", "grappl"),
+    ("glean", "glean"),
+    ("purchas", "purcha"),
+    ("cavia", "cavia"),
+    ("afore", "afor"),
+    ("parvula", "parvula"),
+    ("narrowest", "narrowest"),
+    ("led", "led"),
+    ("enfant", "enfant"),
+    ("trafalgar", "trafalgar"),
+    ("unimportant", "unimport"),
+    ("brandon", "brandon"),
+    ("hyacinth", "hyacinth"),
+    ("shivering", "shiver"),
+    ("stopping", "stop"),
+    ("inattention", "inattent"),
+    ("consort", "consort"),
+    ("chains", "chain"),
+    ("unites", "unit"),
+    ("tottering", "totter"),
+    ("signalize", "signal"),
+    ("approaches", "approach"),
+    ("cloistered", "cloister"),
+    ("aureole", "aureol"),
+    ("singleness", "singl"),
+    ("allegation", "allegaz"),
+    ("tippets", "tippet"),
+    ("juncture", "junctur"),
+    ("instinctively", "instinct"),
+    ("ampullariae", "ampullaria"),
+    ("straits", "strait"),
+    ("colman", "colman"),
+    ("contrees", "contre"),
+    ("romantic", "romant"),
+    ("feet", "feet"),
+    ("concerns", "concern"),
+    ("rubbish", "rubbish"),
+    ("pigtail", "pigtail"),
+    ("bout", "bout"),
+    ("rankled", "rankl"),
+    ("greener", "greener"),
+    ("terrible", "terribl"),
+    ("harrowing", "harrow"),
+    ("lucia", "lucia"),
+    ("scrutinised", "scrutinis"),
+    ("washerwoman", "washerwoman"),
+    ("queer", "queer"),
+    ("generalization", "gener"),
+    ("pledges", "pledg"),
+    ("childless", "childless"),
+    ("rankling", "rankl"),
+    ("related", "relat"),
+    ("anastasia", "anastasia"),
+    ("esquimau", "esquimau"),
+    ("capricorn", "capricorn"),
+    ("oversleeping", "oversleep"),
+    ("commiserating", "commiser"),
+    ("elevating", "elev"),
+    ("bisness", "bis"),
+    ("abe", "ab"),
+    ("joviality", "jovial"),
+    ("household", "household"),
+    ("beckons", "beckon"),
+    ("avowedly", "avowedli"),
+    ("concede", "conced"),
+    ("protective", "protect"),
+    ("grievously", "grievous"),
+    ("unchanged", "unchang"),
+    ("adventurers", "adventur"),
+    ("washing", "wash"),
+    ("bats", "bat"),
+    ("ennui", "ennui"),
+    ("vanishes", "vanish"),
+    ("retorting", "retort"),
+    ("shipwreck", "shipwreck"),
+    ("inhabitant", "inhabit"),
+    ("pages", "page"),
+    ("satyr", "satyr"),
+    ("parasite", "parasit"),
+    ("luminous", "lumin"),
+    ("discontinued", "discontinu"),
+    ("illuminating", "illumin"),
+    ("fiddles", "fiddl"),
+    ("mus", "mu"),
+    ("unsteady", "unsteadi"),
+    ("fedosya", "fedosya"),
+    ("inspects", "inspect"),
+    ("pined", "pine"),
+    ("bishopric", "bishopr"),
+    ("ruinas", "ruina"),
+    ("lozenges", "lozeng"),
+    ("encircled", "encircl"),
+    ("mates", "mate"),
+    ("falters", "falter"),
+    ("spares", "spare"),
+    ("alexey", "alexei"),
+    ("tend", "tend"),
+    ("noire", "noir"),
+    ("blinds", "blind"),
+    ("rip", "rip"),
+    ("acquiesces", "acquiesc"),
+    ("exempt", "exempt"),
+    ("clairvoyante", "clairvoyant"),
+    ("scandalising", "scandalis"),
+    ("penas", "pena"),
+    ("emphasizing", "emphas"),
+    ("abusive", "abus"),
+    ("slim", "slim"),
+    ("armful", "arm"),
+    ("unsoftened", "unsoften"),
+    ("niagaras", "niagara"),
+    ("presentation", "presentaz"),
+    ("whence", "whenc"),
+    ("sympathized", "sympath"),
+    ("cogs", "cog"),
+    ("philosopher", "philosoph"),
+    ("confabulations", "confabulaz"),
+    ("patagonians", "patagonian"),
+    ("sniggered", "snigger"),
+    ("hatches", "hatch"),
+    ("potion", "potion"),
+    ("officially", "offici"),
+    ("hatton", "hatton"),
+    ("panama", "panama"),
+    ("distraught", "distraught"),
+    ("superincumbent", "superincumb"),
+    ("characterize", "character"),
+    ("irishmen", "irishmen"),
+    ("nickleby", "nicklebi"),
+    ("originate", "origin"),
+    ("reassume", "reassum"),
+    ("considerately", "consider"),
+    ("viii", "viii"),
+    ("skipping", "skip"),
+    ("feathery", "featheri"),
+    ("reservation", "reservaz"),
+    ("fypunnote", "fypunnot"),
+    ("assassinating", "assassin"),
+    ("friable", "friabl"),
+    ("telemachus", "telemachu"),
+    ("features", "featur"),
+    ("owor", "owor"),
+    ("smuggler", "smuggler"),
+    ("fledgling", "fledgl"),
+    ("graymarsh", "graymarsh"),
+    ("emancipated", "emancip"),
+    ("mangle", "mangl"),
+    ("ma", "ma"),
+    ("noo", "noo"),
+    ("corkscrew", "corkscrew"),
+    ("fussily", "fussili"),
+    ("resigning", "resign"),
+    ("sow", "sow"),
+    ("cosy", "cosi"),
+    ("guess", "guess"),
+    ("image", "imag"),
+    ("stop", "stop"),
+    ("invading", "invad"),
+    ("snail", "snail"),
+    ("cueva", "cueva"),
+    ("variegated", "varieg"),
+    ("wears", "wear"),
+    ("antuco", "antuco"),
+    ("lackadaisical", "lackadais"),
+    ("balustrades", "balustrad"),
+    ("achilles", "achill"),
+    ("bumper", "bumper"),
+    ("role", "role"),
+    ("indigo", "indigo"),
+    ("trembling", "trembl"),
+    ("crypt", "crypt"),
+    ("rings", "ring"),
+    ("verdigris", "verdigri"),
+    ("haven", "haven"),
+    ("noble", "nobl"),
+    ("diver", "diver"),
+    ("misdirected", "misdirect"),
+    ("parentage", "parentag"),
+    ("reprehension", "reprehens"),
+    ("employments", "employment"),
+    ("shied", "shi"),
+    ("depreciation", "depreciaz"),
+    ("audiences", "audienc"),
+    ("standard", "standard"),
+    ("omit", "omit"),
+    ("odds", "odd"),
+    ("beechey", "beechei"),
+    ("weltering", "welter"),
+    ("speechifying", "speechifi"),
+    ("presentments", "present"),
+    ("flush", "flush"),
+    ("amendments", "amend"),
+    ("biddable", "biddabl"),
+    ("leurs", "leur"),
+    ("gauzy", "gauzi"),
+    ("incrustation", "incrustaz"),
+    ("canals", "canal"),
+    ("rarely", "rare"),
+    ("lumbering", "lumber"),
+    ("uncertainty", "uncertainti"),
+    ("wasting", "wast"),
+    ("weight", "weight"),
+    ("nomenclature", "nomenclatur"),
+    ("knell", "knell"),
+    ("mustard", "mustard"),
+    ("diving", "dive"),
+    ("keating", "keat"),
+    ("tahitian", "tahitian"),
+    ("queries", "queri"),
+    ("warrant", "warrant"),
+    ("kicking", "kick"),
+    ("deserve", "deserv"),
+    ("glimpses", "glimps"),
+    ("fight", "fight"),
+    ("turn", "turn"),
+    ("reaped", "reap"),
+    ("wackfords", "wackford"),
+    ("caesar", "caesar"),
+    ("rattlesnake", "rattlesnak"),
+    ("snuffing", "snuf"),
+    ("dolefully", "dolefulli"),
+    ("miner", "miner"),
+    ("rafts", "raft"),
+    ("encamped", "encamp"),
+    ("solemn", "solemn"),
+    ("thoughtfully", "thoughtfulli"),
+    ("elevens", "eleven"),
+    ("escarpment", "escarp"),
+    ("pottery", "potteri"),
+    ("nicest", "nicest"),
+    ("drudges", "drudg"),
+    ("windsor", "windsor"),
+    ("revealed", "reveal"),
+    ("guileless", "guileless"),
+    ("promontories", "promontori"),
+    ("targets", "target"),
+    ("cram", "cram"),
+    ("reveal", "reveal"),
+    ("overtime", "overtim"),
+    ("thriven", "thriven"),
+    ("gownd", "gownd"),
+    ("approves", "approv"),
+    ("zeus", "zeu"),
+    ("flighty", "flighti"),
+    ("leve", "leve"),
+    ("bawled", "bawl"),
+    ("purvided", "purvid"),
+    ("fissured", "fissur"),
+    ("male", "male"),
+    ("crocodile", "crocodil"),
+    ("terminating", "termin"),
+    ("resting", "rest"),
+    ("stic", "stic"),
+    ("inartistic", "inartist"),
+    ("displayed", "displai"),
+    ("volumes", "volum"),
+    ("etage", "etag"),
+    ("reeling", "reel"),
+    ("trash", "trash"),
+    ("reserved", "reserv"),
+    ("reconnoitred", "reconnoitr"),
+    ("triumphs", "triumph"),
+    ("show", "show"),
+    ("cupidity", "cupid"),
+    ("l", "l"),
+    ("locusts", "locust"),
+    ("flame", "flame"),
+    ("brazilian", "brazilian"),
+    ("languidly", "languidli"),
+    ("ms", "m"),
+    ("esprit", "esprit"),
+    ("companionship", "companionship"),
+    ("streak", "streak"),
+    ("over", "over"),
+    ("incrusted", "incrust"),
+    ("tapalguen", "tapalguen"),
+    ("mistake", "mistak"),
+    ("immovable", "immov"),
+    ("vexes", "vex"),
+    ("hemiptera", "hemiptera"),
+    ("apparently", "appar"),
+    ("panels", "panel"),
+    ("cognizant", "cogniz"),
+    ("solidity", "solid"),
+    ("decamped", "decamp"),
+    ("surmise", "surmis"),
+    ("beggars", "beggar"),
+    ("litigation", "litigaz"),
+    ("sangsby", "sangsbi"),
+    ("sulphur", "sulphur"),
+    ("blossoms", "blossom"),
+    ("nominally", "nomin"),
+    ("measure", "measur"),
+    ("spectacled", "spectacl"),
+    ("discoursed", "discours"),
+    ("serves", "serv"),
+    ("dejectedly", "dejectedli"),
+    ("tool", "tool"),
+    ("aces", "ac"),
+    ("gimlets", "gimlet"),
+    ("hoodle", "hoodl"),
+    ("obstacles", "obstacl"),
+    ("partake", "partak"),
+    ("inherent", "inher"),
+    ("eking", "ek"),
+    ("premiers", "premier"),
+    ("jesting", "jest"),
+    ("seventy", "seventi"),
+    ("dasypus", "dasypu"),
+    ("tilts", "tilt"),
+    ("wearers", "wearer"),
+    ("darkest", "darkest"),
+    ("cares", "care"),
+    ("gambrinus", "gambrinu"),
+    ("invest", "invest"),
+    ("pale", "pale"),
+    ("fissures", "fissur"),
+    ("chivying", "chivi"),
+    ("steaming", "steam"),
+    ("persecuted", "persecut"),
+    ("substantial", "substanti"),
+    ("nipped", "nip"),
+    ("brave", "brave"),
+    ("unproducible", "unproduc"),
+    ("buckland", "buckland"),
+    ("saracenic", "saracen"),
+    ("laughingly", "laughingli"),
+    ("painstaking", "painstak"),
+    ("grudgingly", "grudgingli"),
+    ("chimneypiece", "chimneypiec"),
+    ("bismarcks", "bismarck"),
+    ("chirped", "chirp"),
+    ("affect", "affect"),
+    ("whooping", "whoop"),
+    ("cask", "cask"),
+    ("residences", "resid"),
+    ("humph", "humph"),
+    ("deplored", "deplor"),
+    ("landowner", "landown"),
+    ("disintegration", "disintegraz"),
+    ("cormorant", "cormor"),
+    ("matins", "matin"),
+    ("orifices", "orific"),
+    ("dismantled", "dismantl"),
+    ("orthography", "orthographi"),
+    ("stalking", "stalk"),
+    ("exemption", "exempt"),
+    ("varents", "varent"),
+    ("counteracted", "counteract"),
+    ("exclusiveness", "exclus"),
+    ("generous", "gener"),
+    ("hooping", "hoop"),
+    ("bizcacha", "bizcacha"),
+    ("kings", "king"),
+    ("line", "line"),
+    ("redoubtable", "redoubt"),
+    ("extraordinary", "extraordinari"),
+    ("alameda", "alameda"),
+    ("withering", "wither"),
+    ("danke", "dank"),
+    ("plot", "plot"),
+    ("linen", "linen"),
+    ("caffer", "caffer"),
+    ("anyways", "anywai"),
+    ("feijao", "feijao"),
+    ("assemble", "assembl"),
+    ("blain", "blain"),
+    ("maxim", "maxim"),
+    ("rifle", "rifl"),
+    ("sentiment", "sentiment"),
+    ("dissipated", "dissip"),
+    ("annuals", "annual"),
+    ("misconstruction", "misconstruct"),
+    ("aleide", "aleid"),
+    ("server", "server"),
+    ("utensils", "utensil"),
+    ("andrew", "andrew"),
+    ("depth", "depth"),
+    ("mouldy", "mouldi"),
+    ("waa", "waa"),
+    ("deviser", "devis"),
+    ("smile", "smile"),
+    ("artichokes", "artichok"),
+    ("gabble", "gabbl"),
+    ("suckers", "sucker"),
+    ("cautious", "cautiou"),
+    ("guinea", "guinea"),
+    ("bleeding", "bleed"),
+    ("defenceless", "defenceless"),
+    ("lingers", "linger"),
+    ("else", "els"),
+    ("prinking", "prink"),
+    ("unearthed", "unearth"),
+    ("trappe", "trapp"),
+    ("henslow", "henslow"),
+    ("maternal", "matern"),
+    ("bearers", "bearer"),
+    ("congratulated", "congratul"),
+    ("apparent", "appar"),
+    ("affluent", "affluent"),
+    ("busily", "busili"),
+    ("discord", "discord"),
+    ("frocks", "frock"),
+    ("foremost", "foremost"),
+    ("urgently", "urgent"),
+    ("wedding", "wed"),
+    ("overflows", "overflow"),
+    ("refresh", "refresh"),
+    ("uniformly", "uniformli"),
+    ("jasmine", "jasmin"),
+    ("compulsion", "compuls"),
+    ("worshippers", "worshipp"),
+    ("escorting", "escort"),
+    ("what", "what"),
+    ("cautiously", "cautious"),
+    ("independently", "independ"),
+    ("spick", "spick"),
+    ("autograph", "autograph"),
+    ("mirage", "mirag"),
+    ("recopied", "recopi"),
+    ("fundamental", "fundament"),
+    ("hog", "hog"),
+    ("undeniable", "undeni"),
+    ("salinus", "salinu"),
+    ("knowed", "know"),
+    ("dint", "dint"),
+    ("build", "build"),
+    ("slowness", "slow"),
+    ("tantalisation", "tantalisaz"),
+    ("heteromidae", "heteromida"),
+    ("preposterous", "preposter"),
+    ("hydrographer", "hydrograph"),
+    ("pledge", "pledg"),
+    ("colloquially", "colloqui"),
+    ("enchanting", "enchant"),
+    ("fidgetts", "fidgett"),
+    ("brewers", "brewer"),
+    ("base", "base"),
+    ("folks", "folk"),
+    ("partaking", "partak"),
+    ("fleshly", "fleshli"),
+    ("cages", "cage"),
+    ("immorality", "immor"),
+    ("obeys", "obei"),
+    ("shoaler", "shoaler"),
+    ("elaborated", "elabor"),
+    ("rending", "rend"),
+    ("alliance", "allianc"),
+    ("born", "born"),
+    ("commiseration", "commiseraz"),
+    ("extemporary", "extemporari"),
+    ("festering", "fester"),
+    ("successively", "success"),
+    ("heroic", "heroic"),
+    ("i", "i"),
+    ("blithe", "blith"),
+    ("sullenly", "sullenli"),
+    ("ostensible", "ostens"),
+    ("implement", "implement"),
+    ("hostile", "hostil"),
+    ("imaginative", "imagin"),
+    ("skating", "skate"),
+    ("ploughed", "plough"),
+    ("molothrus", "molothru"),
+    ("rummaging", "rummag"),
+    ("truncate", "truncat"),
+    ("liberate", "liber"),
+    ("vent", "vent"),
+    ("habitation", "habitaz"),
+    ("southern", "southern"),
+    ("hippopotamuses", "hippopotamus"),
+    ("strand", "strand"),
+    ("thrown", "thrown"),
+    ("desist", "desist"),
+    ("faux", "faux"),
+    ("garret", "garret"),
+    ("taught", "taught"),
+    ("inclination", "inclinaz"),
+    ("erudite", "erudit"),
+    ("ard", "ard"),
+    ("roam", "roam"),
+    ("register", "regist"),
+    ("threepence", "threepenc"),
+    ("cheered", "cheer"),
+    ("snowing", "snow"),
+    ("frightened", "frighten"),
+    ("orators", "orat"),
+    ("absolved", "absolv"),
+    ("inveighed", "inveigh"),
+    ("vault", "vault"),
+    ("absorbing", "absorb"),
+    ("substance", "substanc"),
+    ("cannons", "cannon"),
+    ("gathers", "gather"),
+    ("edwards", "edward"),
+    ("lawsuit", "lawsuit"),
+    ("blotchy", "blotchi"),
+    ("briefs", "brief"),
+    ("misery", "miseri"),
+    ("maltreatment", "maltreat"),
+    ("inconsistent", "inconsist"),
+    ("examinations", "examinaz"),
+    ("wow", "wow"),
+    ("allure", "allur"),
+    ("drainage", "drainag"),
+    ("loungers", "lounger"),
+    ("respectabilities", "respect"),
+    ("desc", "desc"),
+    ("commandant", "command"),
+    ("astounded", "astound"),
+    ("compensating", "compens"),
+    ("settled", "settl"),
+    ("supposititious", "supposititi"),
+    ("guv", "guv"),
+    ("hanover", "hanov"),
+    ("chilian", "chilian"),
+    ("cat", "cat"),
+    ("resent", "resent"),
+    ("adhesive", "adhes"),
+    ("dancing", "danc"),
+    ("awhile", "awhil"),
+    ("turbid", "turbid"),
+    ("deliberate", "deliber"),
+    ("horsefly", "horsefli"),
+    ("cincindela", "cincindela"),
+    ("brazened", "brazen"),
+    ("tw", "tw"),
+    ("sundays", "sundai"),
+    ("pharaoh", "pharaoh"),
+    ("irregularly", "irregularli"),
+    ("audible", "audibl"),
+    ("scantily", "scantili"),
+    ("originals", "origin"),
+    ("blackhaired", "blackhair"),
+    ("disabled", "disabl"),
+    ("weighing", "weigh"),
+    ("bedridden", "bedridden"),
+    ("shufflings", "shuffl"),
+    ("grumblings", "grumbl"),
+    ("seats", "seat"),
+    ("concessions", "concess"),
+    ("enemies", "enemi"),
+    ("excavation", "excavaz"),
+    ("deeper", "deeper"),
+    ("warehouse", "warehous"),
+    ("ejaculated", "ejacul"),
+    ("swam", "swam"),
+    ("amidst", "amidst"),
+    ("screws", "screw"),
+    ("exemplary", "exemplari"),
+    ("exported", "export"),
+    ("emporiums", "emporium"),
+    ("chupat", "chupat"),
+    ("distractedly", "distractedli"),
+    ("wooed", "woo"),
+    ("esthers", "esther"),
+    ("uninfluenced", "uninfluenc"),
+    ("unbuttoned", "unbutton"),
+    ("separately", "separ"),
+    ("easiness", "easi"),
+    ("precede", "preced"),
+    ("interpreter", "interpret"),
+    ("dabs", "dab"),
+    ("cheating", "cheat"),
+    ("assessor", "assessor"),
+    ("summonses", "summons"),
+    ("passes", "pass"),
+    ("detecting", "detect"),
+    ("brook", "brook"),
+    ("disjointed", "disjoint"),
+    ("ravens", "raven"),
+    ("slammed", "slam"),
+    ("footed", "foot"),
+    ("intensifying", "intensifi"),
+    ("greatest", "greatest"),
+    ("tap", "tap"),
+    ("apologizes", "apolog"),
+    ("basilisk", "basilisk"),
+    ("infrequently", "infrequ"),
+    ("relations", "relaz"),
+    ("fa", "fa"),
+    ("voluntary", "voluntari"),
+    ("disappear", "disappear"),
+    ("sumptuously", "sumptuous"),
+    ("nihilist", "nihilist"),
+    ("foothold", "foothold"),
+    ("sermon", "sermon"),
+    ("mediation", "mediaz"),
+    ("toryism", "toryism"),
+    ("expense", "expens"),
+    ("edward", "edward"),
+    ("onion", "onion"),
+    ("jungles", "jungl"),
+    ("venom", "venom"),
+    ("forbes", "forb"),
+    ("request", "request"),
+    ("neophyte", "neophyt"),
+    ("facetious", "faceti"),
+    ("clattering", "clatter"),
+    ("processes", "process"),
+    ("ophrys", "ophri"),
+    ("conversant", "convers"),
+    ("sunlight", "sunlight"),
+    ("sliders", "slider"),
+    ("efficacious", "efficaci"),
+    ("lipping", "lip"),
+    ("ruffle", "ruffl"),
+    ("heroded", "herod"),
+    ("bolster", "bolster"),
+    ("explained", "explain"),
+    ("woodman", "woodman"),
+    ("weathers", "weather"),
+    ("forenoon", "forenoon"),
+    ("objecting", "object"),
+    ("prick", "prick"),
+    ("corrupts", "corrupt"),
+    ("persons", "person"),
+    ("deludes", "delud"),
+    ("sanctions", "sanction"),
+    ("clay", "clai"),
+    ("clenches", "clench"),
+    ("dashing", "dash"),
+    ("chequered", "chequer"),
+    ("ponsonby", "ponsonbi"),
+    ("foliage", "foliag"),
+    ("amethysts", "amethyst"),
+    ("exceptionally", "exception"),
+    ("renouncing", "renounc"),
+    ("swallows", "swallow"),
+    ("advertised", "advertis"),
+    ("guttering", "gutter"),
+    ("lane", "lane"),
+    ("manly", "manli"),
+    ("tremblingly", "tremblingli"),
+    ("ceaseless", "ceaseless"),
+    ("presumptuously", "presumptu"),
+    ("yawned", "yawn"),
+    ("released", "releas"),
+    ("providentially", "providenti"),
+    ("aeriform", "aeriform"),
+    ("hing", "hing"),
+    ("bayard", "bayard"),
+    ("undisturbed", "undisturb"),
+    ("forceps", "forcep"),
+    ("boring", "bore"),
+    ("deafness", "deaf"),
+    ("souls", "soul"),
+    ("uncontrollably", "uncontrol"),
+    ("washes", "wash"),
+    ("supremacy", "supremaci"),
+    ("feb", "feb"),
+    ("derby", "derbi"),
+    ("attracting", "attract"),
+    ("drop", "drop"),
+    ("baroness", "baro"),
+    ("complimentary", "complimentari"),
+    ("carelessly", "carelessli"),
+    ("treasury", "treasuri"),
+    ("guarding", "guard"),
+    ("willl", "willl"),
+    ("banshee", "banshe"),
+    ("hasp", "hasp"),
+    ("expose", "expos"),
+    ("biographical", "biograph"),
+    ("gardner", "gardner"),
+    ("shrugged", "shrug"),
+    ("pleasantest", "pleasantest"),
+    ("porcupine", "porcupin"),
+    ("equality", "equal"),
+    ("waits", "wait"),
+    ("hotly", "hotli"),
+    ("sinning", "sin"),
+    ("strongest", "strongest"),
+    ("local", "local"),
+    ("stinging", "stinging"),
+    ("oar", "oar"),
+    ("glutinous", "glutin"),
+    ("quickened", "quicken"),
+    ("chained", "chain"),
+    ("ream", "ream"),
+    ("obliterate", "obliter"),
+    ("minstrel", "minstrel"),
+    ("abundance", "abund"),
+    ("splutter", "splutter"),
+    ("quarters", "quarter"),
+    ("cockroaches", "cockroach"),
+    ("platforms", "platform"),
+    ("patroness", "patro"),
+    ("artlessness", "artless"),
+    ("associated", "associ"),
+    ("remands", "remand"),
+    ("curiosity", "curios"),
+    ("traversia", "traversia"),
+    ("yourselves", "yourselv"),
+    ("ligaments", "ligament"),
+    ("commotions", "commot"),
+    ("flour", "flour"),
+    ("absorb", "absorb"),
+    ("shudders", "shudder"),
+    ("dr", "dr"),
+    ("attendants", "attend"),
+    ("griffin", "griffin"),
+    ("prejudiced", "prejud"),
+    ("rash", "rash"),
+    ("brainless", "brainless"),
+    ("logical", "logic"),
+    ("condoled", "condol"),
+    ("withou", "withou"),
+    ("scoondrel", "scoondrel"),
+    ("carne", "carn"),
+    ("supporting", "support"),
+    ("crumbs", "crumb"),
+    ("shabbily", "shabbili"),
+    ("beheld", "beheld"),
+    ("chalking", "chalk"),
+    ("nestlings", "nestl"),
+    ("annul", "annul"),
+    ("bedrooms", "bedroom"),
+    ("lococks", "locock"),
+    ("lugged", "lug"),
+    ("puddings", "pud"),
+    ("ingratiating", "ingrati"),
+    ("devilry", "devilri"),
+    ("unseen", "unseen"),
+    ("ingredient", "ingredi"),
+    ("preserving", "preserv"),
+    ("misdemeanor", "misdemeanor"),
+    ("brooms", "broom"),
+    ("eighth", "eighth"),
+    ("duchesses", "duchess"),
+    ("smut", "smut"),
+    ("papal", "papal"),
+    ("consider", "consid"),
+    ("inexperienced", "inexperienc"),
+    ("safety", "safeti"),
+    ("verisopht", "verisopht"),
+    ("beacons", "beacon"),
+    ("languor", "languor"),
+    ("demons", "demon"),
+    ("wyndham", "wyndham"),
+    ("joseph", "joseph"),
+    ("upholsterers", "upholster"),
+    ("xxxiv", "xxxiv"),
+    ("albert", "albert"),
+    ("swollen", "swollen"),
+    ("tapacolo", "tapacolo"),
+    ("closet", "closet"),
+    ("electors", "elector"),
+    ("licences", "licenc"),
+    ("baronesses", "baro"),
+    ("cuvier", "cuvier"),
+    ("gals", "gal"),
+    ("rascals", "rascal"),
+    ("unworthily", "unworthili"),
+    ("pursuing", "pursu"),
+    ("explaining", "explain"),
+    ("fortified", "fortifi"),
+    ("obediently", "obedi"),
+    ("denotes", "denot"),
+    ("twinge", "twing"),
+    ("victualled", "victual"),
+    ("wedlock", "wedlock"),
+    ("leaping", "leap"),
+    ("entreated", "entreat"),
+    ("ventilated", "ventil"),
+    ("tormented", "torment"),
+    ("wanderers", "wander"),
+    ("indiscretions", "indiscret"),
+    ("literally", "liter"),
+    ("upheavals", "upheav"),
+    ("tubs", "tub"),
+    ("contention", "content"),
+    ("dignified", "dignifi"),
+    ("tracery", "traceri"),
+    ("vanished", "vanish"),
+    ("accredited", "accredit"),
+    ("clicked", "click"),
+    ("considerate", "consider"),
+    ("toiled", "toil"),
+    ("assure", "assur"),
+    ("persian", "persian"),
+    ("polygon", "polygon"),
+    ("museum", "museum"),
+    ("unhurried", "unhurri"),
+    ("illiberal", "illiber"),
+    ("laguna", "laguna"),
+    ("na", "na"),
+    ("sea", "sea"),
+    ("europe", "europ"),
+    ("neame", "neam"),
+    ("risingham", "risingham"),
+    ("neglecting", "neglect"),
+    ("annewum", "annewum"),
+    ("element", "element"),
+    ("villagers", "villag"),
+    ("permeated", "permeat"),
+    ("hunks", "hunk"),
+    ("upheaved", "upheav"),
+    ("enlarge", "enlarg"),
+    ("massive", "massiv"),
+    ("suppleness", "suppl"),
+    ("marvels", "marvel"),
+    ("yards", "yard"),
+    ("polanco", "polanco"),
+    ("frowns", "frown"),
+    ("divide", "divid"),
+    ("temperaments", "tempera"),
+    ("appliances", "applianc"),
+    ("snubbing", "snub"),
+    ("strengthening", "strengthen"),
+    ("eyre", "eyr"),
+    ("misconduct", "misconduct"),
+    ("unmoved", "unmov"),
+    ("substantially", "substanti"),
+    ("dissolute", "dissolut"),
+    ("hydrophilus", "hydrophilu"),
+    ("enclos", "enclo"),
+    ("gloominess", "gloomi"),
+    ("qc", "qc"),
+    ("enclose", "enclos"),
+    ("dislodge", "dislodg"),
+    ("gratifying", "gratifi"),
+    ("harangue", "harangu"),
+    ("zoology", "zoologi"),
+    ("snuffers", "snuffer"),
+    ("entirely", "entir"),
+    ("pictures", "pictur"),
+    ("glasses", "glass"),
+    ("prices", "price"),
+    ("bottle", "bottl"),
+    ("petrel", "petrel"),
+    ("suits", "suit"),
+    ("javelin", "javelin"),
+    ("exudations", "exudaz"),
+    ("lethe", "leth"),
+    ("indulgence", "indulg"),
+    ("treaty", "treati"),
+    ("ardour", "ardour"),
+    ("teaches", "teach"),
+    ("seed", "seed"),
+    ("greta", "greta"),
+    ("wehr", "wehr"),
+    ("sparkle", "sparkl"),
+    ("agriculture", "agricultur"),
+    ("soom", "soom"),
+    ("chimango", "chimango"),
+    ("fancying", "fanc"),
+    ("hue", "hue"),
+    ("epidemics", "epidem"),
+    ("transferring", "transfer"),
+    ("submissively", "submiss"),
+    ("invalided", "invalid"),
+    ("harangued", "harangu"),
+    ("warn", "warn"),
+    ("overworked", "overwork"),
+    ("trinket", "trinket"),
+    ("outposts", "outpost"),
+    ("prompted", "prompt"),
+    ("readiest", "readiest"),
+    ("governorship", "governorship"),
+    ("enrol", "enrol"),
+    ("bandage", "bandag"),
+    ("bludgeon", "bludgeon"),
+    ("greenstone", "greenston"),
+    ("feather", "feather"),
+    ("michael", "michael"),
+    ("gods", "god"),
+    ("vapourings", "vapour"),
+    ("pits", "pit"),
+    ("intwined", "intwin"),
+    ("descripts", "descript"),
+    ("communing", "commun"),
+    ("disproof", "disproof"),
+    ("discouraged", "discourag"),
+    ("liii", "liii"),
+    ("madagascar", "madagascar"),
+    ("booty", "booti"),
+    ("bios", "bio"),
+    ("poisoning", "poison"),
+    ("potato", "potato"),
+    ("quadras", "quadra"),
+    ("undecided", "undecid"),
+    ("precisely", "precis"),
+    ("placid", "placid"),
+    ("schoolboy", "schoolboi"),
+    ("miles", "mile"),
+    ("token", "token"),
+    ("compensation", "compensaz"),
+    ("expediency", "expedi"),
+    ("exiles", "exil"),
+    ("aunts", "aunt"),
+    ("sirocco", "sirocco"),
+    ("uncertainly", "uncertainli"),
+    ("misconstrued", "misconstru"),
+    ("lurk", "lurk"),
+    ("honoured", "honour"),
+    ("kills", "kill"),
+    ("southwards", "southward"),
+    ("byelinsky", "byelinski"),
+    ("journeyman", "journeyman"),
+    ("bernantio", "bernantio"),
+    ("anyhow", "anyhow"),
+    ("operating", "oper"),
+    ("preparing", "prepar"),
+    ("gazelle", "gazell"),
+    ("jolliest", "jolliest"),
+    ("mast", "mast"),
+    ("bows", "bow"),
+    ("justly", "justli"),
+    ("defied", "defi"),
+    ("blindness", "blind"),
+    ("ivanovitch", "ivanovitch"),
+    ("quartern", "quartern"),
+    ("active", "activ"),
+    ("staggering", "stagger"),
+    ("larks", "lark"),
+    ("opium", "opium"),
+    ("entered", "enter"),
+    ("carthagena", "carthagena"),
+    ("merrier", "merrier"),
+    ("turner", "turner"),
+    ("cramp", "cramp"),
+    ("middle", "middl"),
+    ("flow", "flow"),
+    ("contested", "contest"),
+    ("comparing", "compar"),
+    ("tombstones", "tombston"),
+    ("wheezing", "wheez"),
+    ("railed", "rail"),
+    ("rive", "rive"),
+    ("boxed", "box"),
+    ("hugs", "hug"),
+    ("unfolds", "unfold"),
+    ("vain", "vain"),
+    ("leaves", "leav"),
+    ("meteors", "meteor"),
+    ("vicinity", "vicin"),
+    ("fuss", "fuss"),
+    ("truthful", "truth"),
+    ("imagining", "imagin"),
+    ("coinage", "coinag"),
+    ("perceptible", "percept"),
+    ("maintenance", "mainten"),
+    ("opossums", "opossum"),
+    ("coalition", "coalit"),
+    ("ferrule", "ferrul"),
+    ("amputation", "amputaz"),
+    ("configuration", "configuraz"),
+    ("dominant", "domin"),
+    ("masters", "master"),
+    ("reciprocated", "reciproc"),
+    ("rook", "rook"),
+    ("comment", "comment"),
+    ("beating", "beat"),
+    ("hardship", "hardship"),
+    ("vicarious", "vicari"),
+    ("neckcloths", "neckcloth"),
+    ("talus", "talu"),
+    ("malaya", "malaya"),
+    ("sheik", "sheik"),
+    ("crookedly", "crookedli"),
+    ("mazeppa", "mazeppa"),
+    ("mangot", "mangot"),
+    ("recreation", "recreaz"),
+    ("closely", "close"),
+    ("flea", "flea"),
+    ("milliners", "millin"),
+    ("shoulder", "shoulder"),
+    ("looker", "looker"),
+    ("ally", "al"),
+    ("rested", "rest"),
+    ("instil", "instil"),
+    ("inclinations", "inclinaz"),
+    ("bathing", "bath"),
+    ("destroys", "destroi"),
+    ("ladylike", "ladylik"),
+    ("entering", "enter"),
+    ("whirr", "whirr"),
+    ("liabilities", "liabl"),
+    ("beseeching", "beseech"),
+    ("harurn", "harurn"),
+    ("passages", "passag"),
+    ("wended", "wend"),
+    ("paludina", "paludina"),
+    ("wish", "wish"),
+    ("languages", "languag"),
+    ("decide", "decid"),
+    ("toiling", "toil"),
+    ("recoiling", "recoil"),
+    ("coronets", "coronet"),
+    ("starched", "starch"),
+    ("ecclesiastical", "ecclesiast"),
+    ("sharpened", "sharpen"),
+    ("pandemonium", "pandemonium"),
+    ("leagued", "leagu"),
+    ("dewdrops", "dewdrop"),
+    ("span", "span"),
+    ("shouldst", "shouldst"),
+    ("appended", "append"),
+    ("opprobrious", "opprobri"),
+    ("trochus", "trochu"),
+    ("uplift", "uplift"),
+    ("doodleites", "doodleit"),
+    ("costermongers", "costermong"),
+    ("colleagues", "colleagu"),
+    ("outsider", "outsid"),
+    ("arrested", "arrest"),
+    ("forethought", "forethought"),
+    ("fidgeted", "fidget"),
+    ("nasal", "nasal"),
+    ("undue", "undu"),
+    ("art", "art"),
+    ("wondering", "wonder"),
+    ("chirrup", "chirrup"),
+    ("sides", "side"),
+    ("semblances", "semblanc"),
+    ("shamed", "shame"),
+    ("increasing", "increas"),
+    ("supposes", "suppos"),
+    ("interrogator", "interrog"),
+    ("piled", "pile"),
+    ("sideway", "sidewai"),
+    ("conclusively", "conclus"),
+    ("embitter", "embitt"),
+    ("harassing", "harass"),
+    ("traditionally", "tradition"),
+    ("bo", "bo"),
+    ("diptera", "diptera"),
+    ("interpretation", "interpretaz"),
+    ("frills", "frill"),
+    ("promise", "promis"),
+    ("kick", "kick"),
+    ("raising", "rais"),
+    ("vaulting", "vault"),
+    ("confronts", "confront"),
+    ("strongylus", "strongylu"),
+    ("patagonia", "patagonia"),
+    ("compassion", "compass"),
+    ("remonstrances", "remonstr"),
+    ("verily", "verili"),
+    ("franks", "frank"),
+    ("infused", "infus"),
+    ("coveys", "covei"),
+    ("explodes", "explod"),
+    ("succumbed", "succumb"),
+    ("demagogue", "demagogu"),
+    ("relapses", "relaps"),
+    ("pursult", "pursult"),
+    ("cave", "cave"),
+    ("grains", "grain"),
+    ("trilled", "trill"),
+    ("fray", "frai"),
+    ("daisy", "daisi"),
+    ("twig", "twig"),
+    ("progressive", "progress"),
+    ("intermediate", "intermedi"),
+    ("domidors", "domidor"),
+    ("pronounce", "pronounc"),
+    ("ducking", "duck"),
+    ("craft", "craft"),
+    ("signoritas", "signorita"),
+    ("registered", "regist"),
+    ("enlisted", "enlist"),
+    ("cutlets", "cutlet"),
+    ("methodist", "methodist"),
+    ("arica", "arica"),
+    ("fickle", "fickl"),
+    ("parcel", "parcel"),
+    ("equanimity", "equanim"),
+    ("phenomena", "phenomena"),
+    ("forever", "forev"),
+    ("love", "love"),
+    ("fancy", "fanc"),
+    ("extraction", "extract"),
+    ("broken", "broken"),
+    ("articled", "articl"),
+    ("the", "the"),
+    ("mentor", "mentor"),
+    ("corals", "coral"),
+    ("thirteenth", "thirteenth"),
+    ("stairs", "stair"),
+    ("frusta", "frusta"),
+    ("attesting", "attest"),
+    ("sotto", "sotto"),
+    ("intruded", "intrud"),
+    ("infuse", "infus"),
+    ("castor", "castor"),
+    ("disengages", "disengag"),
+    ("quo", "quo"),
+    ("legs", "leg"),
+    ("ied", "i"),
+    ("cleverness", "clever"),
+    ("driest", "driest"),
+    ("pans", "pan"),
+    ("blackwall", "blackwal"),
+    ("halted", "halt"),
+    ("acquit", "acquit"),
+    ("inclemency", "inclem"),
+    ("modify", "modifi"),
+    ("trippingly", "trippingli"),
+    ("inestimably", "inestim"),
+    ("pocketed", "pocket"),
+    ("indiscretion", "indiscret"),
+    ("monde", "mond"),
+    ("governess", "gover"),
+    ("lizard", "lizard"),
+    ("tain", "tain"),
+    ("exception", "except"),
+    ("discuss", "discuss"),
+    ("fed", "fed"),
+    ("unexplained", "unexplain"),
+    ("squalor", "squalor"),
+    ("covenanted", "coven"),
+    ("treasuring", "treasur"),
+    ("ie", "ie"),
+    ("thereabouts", "thereabout"),
+    ("lymington", "lymington"),
+    ("folair", "folair"),
+    ("flaming", "flame"),
+    ("laugh", "laugh"),
+    ("desolated", "desol"),
+    ("stolen", "stolen"),
+    ("poverty", "poverti"),
+    ("disturbance", "disturb"),
+    ("doggedness", "dogged"),
+    ("knocker", "knocker"),
+    ("disputation", "disputaz"),
+    ("potrero", "potrero"),
+    ("creaked", "creak"),
+    ("intimated", "intim"),
+    ("sands", "sand"),
+    ("gutta", "gutta"),
+    ("fir", "fir"),
+    ("careless", "careless"),
+    ("rubbed", "rub"),
+    ("painter", "painter"),
+    ("psychological", "psycholog"),
+    ("shunned", "shun"),
+    ("thinkin", "thinkin"),
+    ("schemes", "scheme"),
+    ("jurisprudence", "jurisprud"),
+    ("quitted", "quit"),
+    ("transient", "transient"),
+    ("rides", "ride"),
+    ("preferred", "prefer"),
+    ("tingled", "tingl"),
+    ("intoning", "inton"),
+    ("disgraces", "disgrac"),
+    ("despaired", "despair"),
+    ("dissimilar", "dissimilar"),
+    ("cyperus", "cyperu"),
+    ("producing", "produc"),
+    ("scouted", "scout"),
+    ("radishes", "radish"),
+    ("henceforth", "henceforth"),
+    ("uncoile", "uncoil"),
+    ("definitively", "definit"),
+    ("trinkets", "trinket"),
+    ("crimes", "crime"),
+    ("quintus", "quintu"),
+    ("broached", "broach"),
+    ("obligations", "obligaz"),
+    ("familiarised", "familiaris"),
+    ("permitted", "permit"),
+    ("improvident", "improvid"),
+    ("deans", "dean"),
+    ("ceillhg", "ceillhg"),
+    ("hollows", "hollow"),
+    ("mickle", "mickl"),
+    ("calabria", "calabria"),
+    ("farther", "farther"),
+    ("endeavouring", "endeavour"),
+    ("rubbing", "rub"),
+    ("delivery", "deliveri"),
+    ("prevailing", "prevail"),
+    ("trichomanes", "trichoman"),
+    ("besides", "besid"),
+    ("stumps", "stump"),
+    ("owlish", "owlish"),
+    ("phoenix", "phoenix"),
+    ("reverent", "rever"),
+    ("dabbled", "dabbl"),
+    ("exist", "exist"),
+    ("obelisks", "obelisk"),
+    ("thousandfold", "thousandfold"),
+    ("virtue", "virtu"),
+    ("gratuitous", "gratuit"),
+    ("staunchness", "staunch"),
+    ("pecking", "peck"),
+    ("perpetuated", "perpetu"),
+    ("fatuity", "fatuiti"),
+    ("porfiry", "porfiri"),
+    ("abdicate", "abdic"),
+    ("luxuriating", "luxuri"),
+    ("clausen", "clausen"),
+    ("vivacity", "vivac"),
+    ("guitron", "guitron"),
+    ("locket", "locket"),
+    ("wretch", "wretch"),
+    ("repeated", "repeat"),
+    ("sterne", "stern"),
+    ("yucca", "yucca"),
+    ("functions", "function"),
+    ("heel", "heel"),
+    ("contingent", "conting"),
+    ("unconsciousness", "unconsci"),
+    ("aspirate", "aspir"),
+    ("whytorseller", "whytorsel"),
+    ("arid", "arid"),
+    ("unpublished", "unpublish"),
+    ("football", "footbal"),
+    ("prettily", "prettili"),
+    ("republics", "republ"),
+    ("confides", "confid"),
+    ("sunbeam", "sunbeam"),
+    ("drawings", "draw"),
+    ("chile", "chile"),
+    ("scales", "scale"),
+    ("broadside", "broadsid"),
+    ("grandest", "grandest"),
+    ("balled", "ball"),
+    ("geographical", "geograph"),
+    ("strangling", "strangl"),
+    ("narcissus", "narcissu"),
+    ("inclined", "inclin"),
+    ("purr", "purr"),
+    ("opinion", "opinion"),
+    ("file", "file"),
+    ("dovercourt", "dovercourt"),
+    ("chirp", "chirp"),
+    ("greasily", "greasili"),
+    ("crickets", "cricket"),
+    ("levelling", "level"),
+    ("enigmatic", "enigmat"),
+    ("remittance", "remitt"),
+    ("intolerably", "intoler"),
+    ("lower", "lower"),
+    ("shortness", "short"),
+    ("qualified", "qualifi"),
+    ("lagoa", "lagoa"),
+    ("peeresses", "peeress"),
+    ("inconsistencies", "inconsist"),
+    ("readers", "reader"),
+    ("proportion", "proport"),
+    ("predominated", "predomin"),
+    ("gran", "gran"),
+    ("enjoins", "enjoin"),
+    ("civil", "civil"),
+    ("ours", "our"),
+    ("liver", "liver"),
+    ("tabor", "tabor"),
+    ("threatenings", "threaten"),
+    ("fiend", "fiend"),
+    ("huitreu", "huitreu"),
+    ("pinion", "pinion"),
+    ("unmasked", "unmask"),
+    ("lowliness", "lowli"),
+    ("tares", "tare"),
+    ("spill", "spill"),
+    ("vivacious", "vivaci"),
+    ("revelled", "revel"),
+    ("clout", "clout"),
+    ("tainting", "taint"),
+    ("portable", "portabl"),
+    ("silk", "silk"),
+    ("tints", "tint"),
+    ("magpie", "magpi"),
+    ("glides", "glide"),
+    ("gymnasium", "gymnasium"),
+    ("jealousy", "jealousi"),
+    ("struggled", "struggl"),
+    ("cravat", "cravat"),
+    ("busted", "bust"),
+    ("butt", "butt"),
+    ("allurement", "allur"),
+    ("reciprocity", "reciproc"),
+    ("abortion", "abort"),
+    ("sharpens", "sharpen"),
+    ("lassonthwayte", "lassonthwayt"),
+    ("satisfying", "satisfi"),
+    ("fagus", "fagu"),
+    ("theodoras", "theodora"),
+    ("bustle", "bustl"),
+    ("newsmen", "newsmen"),
+    ("terra", "terra"),
+    ("foresight", "foresight"),
+    ("gonoph", "gonoph"),
+    ("beeswax", "beeswax"),
+    ("weightily", "weightili"),
+    ("airing", "air"),
+    ("priestly", "priestli"),
+    ("revolts", "revolt"),
+    ("valets", "valet"),
+    ("panorama", "panorama"),
+    ("criticised", "criticis"),
+    ("rustled", "rustl"),
+    ("schegolskoy", "schegolskoi"),
+    ("sunshine", "sunshin"),
+    ("roystering", "royster"),
+    ("curvidens", "curviden"),
+    ("leech", "leech"),
+    ("straightforward", "straightforward"),
+    ("providential", "providenti"),
+    ("disclaimed", "disclaim"),
+    ("equipages", "equipag"),
+    ("borders", "border"),
+    ("ner", "ner"),
+    ("inverness", "inver"),
+    ("savagely", "savag"),
+    ("sweeten", "sweeten"),
+    ("crowns", "crown"),
+    ("theory", "theori"),
+    ("naterally", "nater"),
+    ("elopement", "elop"),
+    ("special", "special"),
+    ("indispensable", "indispens"),
+    ("strikes", "strike"),
+    ("evoke", "evok"),
+    ("interposes", "interpos"),
+    ("conferred", "confer"),
+    ("masterful", "master"),
+    ("scents", "scent"),
+    ("walketh", "walketh"),
+    ("tangled", "tangl"),
+    ("necrophagous", "necrophag"),
+    ("extinction", "extinct"),
+    ("overdo", "overdo"),
+    ("thrive", "thrive"),
+    ("hoarding", "hoard"),
+    ("latch", "latch"),
+    ("fourth", "fourth"),
+    ("perverted", "pervert"),
+    ("sweeps", "sweep"),
+    ("deviation", "deviaz"),
+    ("accomplishing", "accomplish"),
+    ("unwrung", "unwrung"),
+    ("expeditious", "expediti"),
+    ("dodo", "dodo"),
+    ("gentlemanly", "gentlemanli"),
+    ("truant", "truant"),
+    ("victories", "victori"),
+    ("desolee", "desole"),
+    ("furtherance", "further"),
+    ("seeking", "seek"),
+    ("blushingly", "blushingli"),
+    ("spear", "spear"),
+    ("wisit", "wisit"),
+    ("shilling", "shill"),
+    ("hitherward", "hitherward"),
+    ("analyse", "analys"),
+    ("brighter", "brighter"),
+    ("hereby", "herebi"),
+    ("voiced", "voic"),
+    ("henemies", "henemi"),
+    ("paunchy", "paunchi"),
+    ("unsophisticated", "unsophist"),
+    ("chronicle", "chronicl"),
+    ("scarfs", "scarf"),
+    ("surpassed", "surpass"),
+    ("mosquitoes", "mosquito"),
+    ("repair", "repair"),
+    ("ct", "ct"),
+    ("unbusiness", "unbusi"),
+    ("pens", "pen"),
+    ("triumphal", "triumphal"),
+    ("shepherdess", "shepherdess"),
+    ("jilted", "jilt"),
+    ("greetings", "greet"),
+    ("place", "place"),
+    ("serenades", "serenad"),
+    ("owe", "ow"),
+    ("daytime", "daytim"),
+    ("burial", "burial"),
+    ("really", "real"),
+    ("sharpness", "sharp"),
+    ("ackney", "acknei"),
+    ("rivulets", "rivulet"),
+    ("portions", "portion"),
+    ("allayed", "allai"),
+    ("hale", "hale"),
+    ("howls", "howl"),
+    ("rebounded", "rebound"),
+    ("husk", "husk"),
+    ("mak", "mak"),
+    ("pomatum", "pomatum"),
+    ("uplan", "uplan"),
+    ("rebuff", "rebuff"),
+    ("weeping", "weep"),
+    ("bonpland", "bonpland"),
+    ("passions", "passion"),
+    ("clings", "cling"),
+    ("verandahs", "verandah"),
+    ("crooked", "crook"),
+    ("bulls", "bull"),
+    ("secretaryships", "secretaryship"),
+    ("showing", "show"),
+    ("information", "informaz"),
+    ("incautiously", "incauti"),
+    ("irrecoverable", "irrecover"),
+    ("esq", "esq"),
+    ("practising", "practis"),
+    ("recalling", "recal"),
+    ("respectable", "respect"),
+    ("idealist", "idealist"),
+    ("basement", "basement"),
+    ("valuable", "valuabl"),
+    ("discipline", "disciplin"),
+    ("motherless", "motherless"),
+    ("contrived", "contriv"),
+    ("unable", "unabl"),
+    ("darker", "darker"),
+    ("cower", "cower"),
+    ("eccentricities", "eccentr"),
+    ("demoralized", "demor"),
+    ("xlv", "xlv"),
+    ("sweat", "sweat"),
+    ("bivouacked", "bivouack"),
+    ("vacillating", "vacil"),
+    ("weobly", "weobli"),
+    ("symbols", "symbol"),
+    ("firework", "firework"),
+    ("bees", "bee"),
+    ("fulfilled", "fulfil"),
+    ("obstructing", "obstruct"),
+    ("senator", "senat"),
+    ("cu", "cu"),
+    ("lubricates", "lubric"),
+    ("thirstier", "thirstier"),
+    ("tools", "tool"),
+    ("join", "join"),
+    ("silversmith", "silversmith"),
+    ("indomitable", "indomit"),
+    ("powe", "pow"),
+    ("surmised", "surmis"),
+    ("undulating", "undul"),
+    ("poke", "poke"),
+    ("nature", "natur"),
+    ("doomed", "doom"),
+    ("symonds", "symond"),
+    ("harpies", "harpi"),
+    ("movemen", "movemen"),
+    ("impressed", "impress"),
+    ("combat", "combat"),
+    ("bananas", "banana"),
+    ("copy", "copi"),
+    ("seasons", "season"),
+    ("tin", "tin"),
+    ("apprehensions", "apprehens"),
+    ("corroborated", "corrobor"),
+    ("triermain", "triermain"),
+    ("pleads", "plead"),
+    ("booker", "booker"),
+    ("pivot", "pivot"),
+    ("chivalry", "chivalri"),
+    ("kilda", "kilda"),
+    ("halfway", "halfwai"),
+    ("waimate", "waimat"),
+    ("heartedest", "heartedest"),
+    ("loath", "loath"),
+    ("plestcheiev", "plestcheiev"),
+    ("gleaming", "gleam"),
+    ("fillet", "fillet"),
+    ("woo", "woo"),
+    ("graceful", "grace"),
+    ("imparts", "impart"),
+    ("folkestone", "folkeston"),
+    ("benign", "benign"),
+    ("assault", "assault"),
+    ("vertu", "vertu"),
+    ("northward", "northward"),
+    ("hinting", "hint"),
+    ("rot", "rot"),
+    ("llama", "llama"),
+    ("trans", "tran"),
+    ("warking", "wark"),
+    ("accordance", "accord"),
+    ("repenting", "repent"),
+    ("paintings", "paint"),
+    ("appoints", "appoint"),
+    ("compositions", "composit"),
+    ("nightfall", "nightfal"),
+    ("rule", "rule"),
+    ("spent", "spent"),
+    ("eminent", "emin"),
+    ("following", "follow"),
+    ("reversal", "revers"),
+    ("dorker", "dorker"),
+    ("contemporaneous", "contemporan"),
+    ("minute", "minut"),
+    ("breathlessly", "breathlessli"),
+    ("pole", "pole"),
+    ("vaiuly", "vaiuli"),
+    ("less", "less"),
+    ("unaccompanied", "unaccompani"),
+    ("worthless", "worthless"),
+    ("compatible", "compat"),
+    ("skirt", "skirt"),
+    ("whitening", "whiten"),
+    ("lowly", "lowli"),
+    ("rotation", "rotaz"),
+    ("hock", "hock"),
+    ("balusters", "balust"),
+    ("hither", "hither"),
+    ("tithe", "tith"),
+    ("footstools", "footstool"),
+    ("nitric", "nitric"),
+    ("levity", "leviti"),
+    ("loathe", "loath"),
+    ("hortense", "hortens"),
+    ("scorching", "scorch"),
+    ("scourge", "scourg"),
+    ("cleared", "clear"),
+    ("vesuvius", "vesuviu"),
+    ("describes", "describ"),
+    ("growls", "growl"),
+    ("someway", "somewai"),
+    ("munches", "munch"),
+    ("sashed", "sash"),
+    ("buckle", "buckl"),
+    ("deepest", "deepest"),
+    ("proneness", "prone"),
+    ("priest", "priest"),
+    ("dawdle", "dawdl"),
+    ("augustus", "augustu"),
+    ("schooner", "schooner"),
+    ("slipper", "slipper"),
+    ("stinketh", "stinketh"),
+    ("petrels", "petrel"),
+    ("palanquin", "palanquin"),
+    ("wend", "wend"),
+    ("submissive", "submiss"),
+    ("funnel", "funnel"),
+    ("endurance", "endur"),
+    ("railroad", "railroad"),
+    ("cleanliness", "cleanli"),
+    ("livelong", "livelong"),
+    ("insistent", "insist"),
+    ("wroth", "wroth"),
+    ("insinuation", "insinuaz"),
+    ("studying", "studi"),
+    ("viewed", "view"),
+    ("windward", "windward"),
+    ("impregnability", "impregn"),
+    ("administrators", "administr"),
+    ("admonition", "admonit"),
+    ("approximately", "approxim"),
+    ("bed", "bed"),
+    ("inconsiderately", "inconsider"),
+    ("ants", "ant"),
+    ("homicidal", "homicid"),
+    ("reproved", "reprov"),
+    ("crusty", "crusti"),
+    ("relaxe", "relax"),
+    ("bedlam", "bedlam"),
+    ("anywheres", "anywher"),
+    ("bridged", "bridg"),
+    ("comers", "comer"),
+    ("franchises", "franchis"),
+    ("dropping", "drop"),
+    ("grandee", "grande"),
+    ("solar", "solar"),
+    ("casucha", "casucha"),
+    ("alarmingly", "alarmingli"),
+    ("orthoptera", "orthoptera"),
+    ("protestations", "protestaz"),
+    ("applause", "applaus"),
+    ("farewell", "farewel"),
+    ("watches", "watch"),
+    ("constitution", "constitut"),
+    ("sorry", "sorri"),
+    ("uniforms", "uniform"),
+    ("ashy", "ashi"),
+    ("literary", "literari"),
+    ("sustained", "sustain"),
+    ("requires", "requir"),
+    ("speakin", "speakin"),
+    ("dreamt", "dreamt"),
+    ("buyers", "buyer"),
+    ("insure", "insur"),
+    ("individually", "individu"),
+    ("ajar", "ajar"),
+    ("intimately", "intim"),
+    ("legitimacy", "legitimaci"),
+    ("basely", "base"),
+    ("disregards", "disregard"),
+    ("lenders", "lender"),
+    ("triangular", "triangular"),
+    ("congelation", "congelaz"),
+    ("accorded", "accord"),
+    ("wettest", "wettest"),
+    ("brows", "brow"),
+    ("grandsire", "grandsir"),
+    ("flax", "flax"),
+    ("thirst", "thirst"),
+    ("decisive", "decis"),
+    ("owner", "owner"),
+    ("fain", "fain"),
+    ("meekly", "meekli"),
+    ("laconic", "lacon"),
+    ("tittering", "titter"),
+    ("dipping", "dip"),
+    ("critturs", "crittur"),
+    ("preternatural", "preternatur"),
+    ("overtook", "overtook"),
+    ("tusks", "tusk"),
+    ("coorch", "coorch"),
+    ("wandering", "wander"),
+    ("unhinged", "unhing"),
+    ("loop", "loop"),
+    ("bespeaking", "bespeak"),
+    ("rougher", "rougher"),
+    ("flatly", "flatli"),
+    ("destructive", "destruct"),
+    ("unchildlike", "unchildlik"),
+    ("restitution", "restitut"),
+    ("escapes", "escap"),
+    ("thunder", "thunder"),
+    ("scarcel", "scarcel"),
+    ("ineffective", "ineffect"),
+    ("quadrangular", "quadrangular"),
+    ("sheepish", "sheepish"),
+    ("peaceful", "peac"),
+    ("drops", "drop"),
+    ("coralline", "corallin"),
+    ("antelope", "antelop"),
+    ("architecture", "architectur"),
+    ("escape", "escap"),
+    ("igniting", "ignit"),
+    ("clematis", "clemati"),
+    ("youngest", "youngest"),
+    ("blackguards", "blackguard"),
+    ("woodcourt", "woodcourt"),
+    ("tinkers", "tinker"),
+    ("voting", "vote"),
+    ("mongrel", "mongrel"),
+    ("unerring", "uner"),
+    ("sobs", "sob"),
+    ("coxcombical", "coxcomb"),
+    ("droppings", "drop"),
+    ("uphold", "uphold"),
+    ("rapt", "rapt"),
+    ("scorpion", "scorpion"),
+    ("skiddaw", "skiddaw"),
+    ("retains", "retain"),
+    ("application", "applicaz"),
+    ("faggot", "faggot"),
+    ("apprehend", "apprehend"),
+    ("weird", "weird"),
+    ("teachers", "teacher"),
+    ("oban", "oban"),
+    ("year", "year"),
+    ("require", "requir"),
+    ("religions", "religion"),
+    ("jarndyce", "jarndyc"),
+    ("purge", "purg"),
+    ("periods", "period"),
+    ("overturned", "overturn"),
+    ("juror", "juror"),
+    ("elbows", "elbow"),
+    ("bootuns", "bootun"),
+    ("fumes", "fume"),
+    ("shadow", "shadow"),
+    ("blockson", "blockson"),
+    ("cosily", "cosili"),
+    ("lights", "light"),
+    ("afternoon", "afternoon"),
+    ("digest", "digest"),
+    ("cunningly", "cunningli"),
+    ("dissemble", "dissembl"),
+    ("dod", "dod"),
+    ("harmoniously", "harmoni"),
+    ("error", "error"),
+    ("sentiments", "sentiment"),
+    ("rowing", "row"),
+    ("sextus", "sextu"),
+    ("wilderness", "wilder"),
+    ("watered", "water"),
+    ("brooks", "brook"),
+    ("benighted", "benight"),
+    ("indelicate", "indel"),
+    ("risky", "riski"),
+    ("builds", "build"),
+    ("buxom", "buxom"),
+    ("aggravating", "aggrav"),
+    ("write", "write"),
+    ("socego", "socego"),
+    ("cooing", "coo"),
+    ("centring", "centr"),
+    ("administrator", "administr"),
+    ("les", "le"),
+    ("arresting", "arrest"),
+    ("unselfishly", "unselfishli"),
+    ("roaring", "roar"),
+    ("indulgent", "indulg"),
+    ("agonizing", "agon"),
+    ("inflammation", "inflammaz"),
+    ("includes", "includ"),
+    ("altogether", "altogeth"),
+    ("varied", "vari"),
+    ("sincerely", "sincer"),
+    ("lags", "lag"),
+    ("frosty", "frosti"),
+    ("veto", "veto"),
+    ("superintendence", "superintend"),
+    ("satrap", "satrap"),
+    ("initiatory", "initiatori"),
+    ("aids", "aid"),
+    ("undergraduate", "undergradu"),
+    ("bodily", "bodili"),
+    ("eradicate", "erad"),
+    ("nictitating", "nictit"),
+    ("criticism", "critic"),
+    ("crawl", "crawl"),
+    ("leniently", "lenient"),
+    ("unremitting", "unremit"),
+    ("disgracing", "disgrac"),
+    ("suc", "suc"),
+    ("stamped", "stamp"),
+    ("cartload", "cartload"),
+    ("sait", "sait"),
+    ("chiefest", "chiefest"),
+    ("govemment", "govem"),
+    ("brownish", "brownish"),
+    ("inkwhiched", "inkwhich"),
+    ("locking", "lock"),
+    ("interspersed", "interspers"),
+    ("actinia", "actinia"),
+    ("subservient", "subservi"),
+    ("unkindly", "unkindli"),
+    ("guards", "guard"),
+    ("eloped", "elop"),
+    ("coachyard", "coachyard"),
+    ("endure", "endur"),
+    ("fashion", "fashion"),
+    ("prototypes", "prototyp"),
+    ("dictate", "dictat"),
+    ("shropshire", "shropshir"),
+    ("qualification", "qualificaz"),
+    ("hints", "hint"),
+    ("domino", "domino"),
+    ("snore", "snore"),
+    ("dare", "dare"),
+    ("tinamus", "tinamu"),
+    ("sticker", "sticker"),
+    ("yquem", "yquem"),
+    ("imposture", "impostur"),
+    ("gal", "gal"),
+    ("distributed", "distribut"),
+    ("vil", "vil"),
+    ("pathetically", "pathet"),
+    ("rites", "rite"),
+    ("deteriorated", "deterior"),
+    ("fo", "fo"),
+    ("affirmative", "affirm"),
+    ("stewam", "stewam"),
+    ("detritus", "detritu"),
+    ("sterilit", "sterilit"),
+    ("path", "path"),
+    ("brightening", "brighten"),
+    ("feminine", "feminin"),
+    ("exhaled", "exhal"),
+    ("loosening", "loosen"),
+    ("enforce", "enforc"),
+    ("staple", "stapl"),
+    ("extremes", "extrem"),
+    ("toes", "toe"),
+    ("cry", "cri"),
+    ("alta", "alta"),
+    ("abate", "abat"),
+    ("handcuffs", "handcuff"),
+    ("discussion", "discuss"),
+    ("soap", "soap"),
+    ("fossils", "fossil"),
+    ("heading", "head"),
+    ("inaccurate", "inaccur"),
+    ("pailfuls", "pail"),
+    ("purchases", "purchas"),
+    ("muleteers", "mulet"),
+    ("moorish", "moorish"),
+    ("worsteds", "worst"),
+    ("excrescence", "excresc"),
+    ("foundations", "foundaz"),
+    ("trouser", "trouser"),
+    ("reports", "report"),
+    ("expedite", "expedit"),
+    ("differs", "differ"),
+    ("jew", "jew"),
+    ("romanovna", "romanovna"),
+    ("specially", "special"),
+    ("photographer", "photograph"),
+    ("attains", "attain"),
+    ("cumbre", "cumbr"),
+    ("shoal", "shoal"),
+    ("curds", "curd"),
+    ("infliction", "inflict"),
+    ("conversation", "conversaz"),
+    ("confirms", "confirm"),
+    ("mistresses", "mistress"),
+    ("applies", "appli"),
+    ("convergent", "converg"),
+    ("shudder", "shudder"),
+    ("matador", "matador"),
+    ("agreement", "agreement"),
+    ("demur", "demur"),
+    ("missus", "missu"),
+    ("inclining", "inclin"),
+    ("continues", "continu"),
+    ("restlessly", "restlessli"),
+    ("recognisant", "recognis"),
+    ("scotch", "scotch"),
+    ("box", "box"),
+    ("crimsoned", "crimson"),
+    ("tawdry", "tawdri"),
+    ("volumnia", "volumnia"),
+    ("kobelev", "kobelev"),
+    ("snowstorm", "snowstorm"),
+    ("catholics", "cathol"),
+    ("educating", "educ"),
+    ("warm", "warm"),
+    ("falsehood", "falsehood"),
+    ("diodon", "diodon"),
+    ("employment", "employment"),
+    ("price", "price"),
+    ("disagreeably", "disagre"),
+    ("disfavour", "disfavour"),
+    ("bargained", "bargain"),
+    ("mainland", "mainland"),
+    ("referred", "refer"),
+    ("indigenous", "indigen"),
+    ("neared", "near"),
+    ("enjoin", "enjoin"),
+    ("retain", "retain"),
+    ("dignity", "digniti"),
+    ("precious", "preciou"),
+    ("vittles", "vittl"),
+    ("hour", "hour"),
+    ("wetted", "wet"),
+    ("blackfriars", "blackfriar"),
+    ("nonpareil", "nonpareil"),
+    ("hyla", "hyla"),
+    ("grubbing", "grub"),
+    ("occasion", "occas"),
+    ("braided", "braid"),
+    ("dealt", "dealt"),
+    ("consolation", "consolaz"),
+    ("positiveness", "posit"),
+    ("frothy", "frothi"),
+    ("marches", "march"),
+    ("thes", "the"),
+    ("mumbling", "mumbl"),
+    ("uninhabited", "uninhabit"),
+    ("despondent", "despond"),
+    ("ancestors", "ancestor"),
+    ("adam", "adam"),
+    ("persist", "persist"),
+    ("mastered", "master"),
+    ("fumed", "fume"),
+    ("students", "student"),
+    ("guttural", "guttur"),
+    ("badness", "bad"),
+    ("loftiest", "loftiest"),
+    ("th", "th"),
+    ("sphex", "sphex"),
+    ("behindhand", "behindhand"),
+    ("crossin", "crossin"),
+    ("opetiorhynchus", "opetiorhynchu"),
+    ("romanovitch", "romanovitch"),
+    ("abear", "abear"),
+    ("intentioned", "intent"),
+    ("martlets", "martlet"),
+    ("garrets", "garret"),
+    ("profligate", "proflig"),
+    ("privation", "privaz"),
+    ("heavier", "heavier"),
+    ("prefers", "prefer"),
+    ("termagant", "termag"),
+    ("courting", "court"),
+    ("larming", "larm"),
+    ("squeer", "squeer"),
+    ("meantime", "meantim"),
+    ("hilly", "hilli"),
+    ("waded", "wade"),
+    ("choking", "choke"),
+    ("done", "done"),
+    ("outset", "outset"),
+    ("submitting", "submit"),
+    ("arkady", "arkadi"),
+    ("grandchildren", "grandchildren"),
+    ("subverted", "subvert"),
+    ("annoucing", "annouc"),
+    ("sporules", "sporul"),
+    ("voice", "voic"),
+    ("noodles", "noodl"),
+    ("said", "said"),
+    ("rosettes", "rosett"),
+    ("stoppage", "stoppag"),
+    ("bank", "bank"),
+    ("lighter", "lighter"),
+    ("babby", "babbi"),
+    ("commenced", "commenc"),
+    ("prow", "prow"),
+    ("crisis", "crisi"),
+    ("kobilatnikov", "kobilatnikov"),
+    ("twice", "twice"),
+    ("coelum", "coelum"),
+    ("situations", "situaz"),
+    ("renders", "render"),
+    ("malady", "maladi"),
+    ("necromancer", "necromanc"),
+    ("nectarines", "nectarin"),
+    ("ruptured", "ruptur"),
+    ("preponderance", "preponder"),
+    ("mazurka", "mazurka"),
+    ("slap", "slap"),
+    ("communicating", "commun"),
+    ("applicable", "applic"),
+    ("imperatively", "imper"),
+    ("piercing", "pierc"),
+    ("softness", "soft"),
+    ("chalked", "chalk"),
+    ("disobedience", "disobedi"),
+    ("vrow", "vrow"),
+    ("dong", "dong"),
+    ("enamel", "enamel"),
+    ("inspiriting", "inspirit"),
+    ("ankles", "ankl"),
+    ("domineered", "domin"),
+    ("patriotic", "patriot"),
+    ("freischutz", "freischutz"),
+    ("exuded", "exud"),
+    ("verb", "verb"),
+    ("partridge", "partridg"),
+    ("honoria", "honoria"),
+    ("belfry", "belfri"),
+    ("sanctity", "sanctiti"),
+    ("noes", "noe"),
+    ("nanus", "nanu"),
+    ("paralytic", "paralyt"),
+    ("gladsome", "gladsom"),
+    ("flaxen", "flaxen"),
+    ("theatres", "theatr"),
+    ("uninitiated", "uniniti"),
+    ("walcot", "walcot"),
+    ("pisagua", "pisagua"),
+    ("faintest", "faintest"),
+    ("envious", "enviou"),
+    ("doon", "doon"),
+    ("envenomed", "envenom"),
+    ("complains", "complain"),
+    ("existence", "exist"),
+    ("strapped", "strap"),
+    ("seconds", "second"),
+    ("garter", "garter"),
+    ("gliding", "glide"),
+    ("pickpockets", "pickpocket"),
+    ("pelted", "pelt"),
+    ("venture", "ventur"),
+    ("anticipative", "anticip"),
+    ("mathematics", "mathemat"),
+    ("fitful", "fit"),
+    ("nastiness", "nasti"),
+    ("ceteras", "cetera"),
+    ("courcys", "courci"),
+    ("wickedly", "wickedli"),
+    ("trembled", "trembl"),
+    ("sward", "sward"),
+    ("hunger", "hunger"),
+    ("bud", "bud"),
+    ("influencing", "influenc"),
+    ("threatens", "threaten"),
+    ("inkpot", "inkpot"),
+    ("ascendency", "ascend"),
+    ("navarin", "navarin"),
+    ("principle", "principl"),
+    ("hostages", "hostag"),
+    ("pachydermata", "pachydermata"),
+    ("extraneous", "extran"),
+    ("comfortless", "comfortless"),
+    ("perseveres", "persever"),
+    ("relapsed", "relaps"),
+    ("barefaced", "barefac"),
+    ("equipped", "equip"),
+    ("tick", "tick"),
+    ("manifests", "manifest"),
+    ("chanting", "chant"),
+    ("sart", "sart"),
+    ("fracture", "fractur"),
+    ("heigh", "heigh"),
+    ("macae", "maca"),
+    ("collecting", "collect"),
+    ("ruminate", "rumin"),
+    ("clothed", "cloth"),
+    ("watchman", "watchman"),
+    ("herbage", "herbag"),
+    ("damsels", "damsel"),
+    ("battalion", "battalion"),
+    ("amiability", "amiabl"),
+    ("cabbery", "cabberi"),
+    ("lillyvicks", "lillyvick"),
+    ("impulse", "impuls"),
+    ("shipped", "ship"),
+    ("alas", "ala"),
+    ("merrily", "merrili"),
+    ("solid", "solid"),
+    ("gravies", "gravi"),
+    ("lavish", "lavish"),
+    ("vapour", "vapour"),
+    ("pigeons", "pigeon"),
+    ("salitral", "salitr"),
+    ("bewail", "bewail"),
+    ("tens", "ten"),
+    ("lepus", "lepu"),
+    ("shrilly", "shrilli"),
+    ("strangers", "stranger"),
+    ("troifling", "troifl"),
+    ("flabbily", "flabbili"),
+    ("distorted", "distort"),
+    ("pensively", "pensiv"),
+    ("noiseless", "noiseless"),
+    ("sufficed", "suffic"),
+    ("drily", "drili"),
+    ("superintended", "superintend"),
+    ("impotence", "impot"),
+    ("walting", "walt"),
+    ("emblem", "emblem"),
+    ("petorca", "petorca"),
+    ("boom", "boom"),
+    ("with", "with"),
+    ("tsetup", "tsetup"),
+    ("irreclaimable", "irreclaim"),
+    ("miss", "miss"),
+    ("stewing", "stew"),
+    ("unfriended", "unfriend"),
+    ("happened", "happen"),
+    ("reeks", "reek"),
+    ("corroborate", "corrobor"),
+    ("knight", "knight"),
+    ("stratagem", "stratagem"),
+    ("continney", "continnei"),
+    ("tacitly", "tacitli"),
+    ("worms", "worm"),
+    ("cravats", "cravat"),
+    ("minions", "minion"),
+    ("reminiscences", "reminisc"),
+    ("heruvimov", "heruvimov"),
+    ("swore", "swore"),
+    ("richest", "richest"),
+    ("ascended", "ascend"),
+    ("culpeu", "culpeu"),
+    ("coolly", "coolli"),
+    ("solve", "solv"),
+    ("hungry", "hungri"),
+    ("sequel", "sequel"),
+    ("unstrung", "unstrung"),
+    ("madder", "madder"),
+    ("paramount", "paramount"),
+    ("ogles", "ogl"),
+    ("unshackled", "unshackl"),
+    ("ravins", "ravin"),
+    ("instructing", "instruct"),
+    ("arbiter", "arbit"),
+    ("otter", "otter"),
+    ("grogram", "grogram"),
+    ("need", "need"),
+    ("coming", "come"),
+    ("nigra", "nigra"),
+    ("indistinct", "indistinct"),
+    ("rescuing", "rescu"),
+    ("cruelly", "cruelli"),
+    ("sapphires", "sapphir"),
+    ("unfamiliar", "unfamiliar"),
+    ("svidrigailovs", "svidrigailov"),
+    ("flushes", "flush"),
+    ("martins", "martin"),
+    ("nave", "nave"),
+    ("harriett", "harriett"),
+    ("juniorest", "juniorest"),
+    ("administered", "administ"),
+    ("surveys", "survei"),
+    ("cardui", "cardui"),
+    ("flattest", "flattest"),
+    ("comicality", "comic"),
+    ("expands", "expand"),
+    ("preyed", "prei"),
+    ("breeds", "breed"),
+    ("stimulate", "stimul"),
+    ("censorious", "censori"),
+    ("beams", "beam"),
+    ("tropic", "tropic"),
+    ("cylinders", "cylind"),
+    ("surveyor", "surveyor"),
+    ("precaution", "precaut"),
+    ("aqueous", "aqueou"),
+    ("frizzling", "frizzl"),
+    ("pupil", "pupil"),
+    ("breakwater", "breakwat"),
+    ("sensibilities", "sensibl"),
+    ("munchausen", "munchausen"),
+    ("vicar", "vicar"),
+    ("isle", "isl"),
+    ("adjured", "adjur"),
+    ("likeness", "like"),
+    ("should", "should"),
+    ("rejoicing", "rejoic"),
+    ("near", "near"),
+    ("mountainous", "mountain"),
+    ("uncalled", "uncal"),
+    ("mimosae", "mimosa"),
+    ("chichester", "chichest"),
+    ("admires", "admir"),
+    ("prowess", "prowess"),
+    ("engine", "engin"),
+    ("albanians", "albanian"),
+    ("flight", "flight"),
+    ("bang", "bang"),
+    ("lightened", "lighten"),
+    ("erections", "erect"),
+    ("august", "august"),
+    ("years", "year"),
+    ("upstarts", "upstart"),
+    ("discolouring", "discolour"),
+    ("testily", "testili"),
+    ("naughty", "naughti"),
+    ("deadens", "deaden"),
+    ("newmarket", "newmarket"),
+    ("anxiety", "anxieti"),
+    ("bricked", "brick"),
+    ("marring", "mar"),
+    ("purplish", "purplish"),
+    ("unprosperous", "unprosper"),
+    ("trivialities", "trivial"),
+    ("absorbed", "absorb"),
+    ("judging", "judg"),
+    ("momen", "momen"),
+    ("order", "order"),
+    ("catastrophes", "catastroph"),
+    ("unmerited", "unmerit"),
+    ("breakfasts", "breakfast"),
+    ("nile", "nile"),
+    ("mama", "mama"),
+    ("increases", "increas"),
+    ("unlucky", "unlucki"),
+    ("harrows", "harrow"),
+    ("agnes", "agn"),
+    ("pegged", "peg"),
+    ("conchas", "concha"),
+    ("confident", "confid"),
+    ("vivisecting", "vivisect"),
+    ("interfere", "interfer"),
+    ("acquirement", "acquir"),
+    ("composedly", "composedli"),
+    ("muddles", "muddl"),
+    ("count", "count"),
+    ("either", "either"),
+    ("researches", "research"),
+    ("blessings", "bless"),
+    ("teacups", "teacup"),
+    ("indiscreet", "indiscreet"),
+    ("idlest", "idlest"),
+    ("happiest", "happiest"),
+    ("assures", "assur"),
+    ("apartments", "apart"),
+    ("roared", "roar"),
+    ("fervently", "fervent"),
+    ("stable", "stabl"),
+    ("jaunt", "jaunt"),
+    ("indecently", "indec"),
+    ("panoply", "panopli"),
+    ("rooge", "roog"),
+    ("funds", "fund"),
+    ("idiotically", "idiot"),
+    ("playfulness", "play"),
+    ("oxyurus", "oxyuru"),
+    ("intentional", "intent"),
+    ("fraternal", "fratern"),
+    ("swills", "swill"),
+    ("gang", "gang"),
+    ("excellences", "excel"),
+    ("ventilation", "ventilaz"),
+    ("charitable", "charit"),
+    ("strangle", "strangl"),
+    ("strewed", "strew"),
+    ("sail", "sail"),
+    ("constraint", "constraint"),
+    ("helden", "helden"),
+    ("demands", "demand"),
+    ("idolatry", "idolatri"),
+    ("drudge", "drudg"),
+    ("malaria", "malaria"),
+    ("premeditated", "premedit"),
+    ("gentleness", "gentl"),
+    ("glimmering", "glimmer"),
+    ("been", "been"),
+    ("dreaminess", "dreami"),
+    ("soul", "soul"),
+    ("governed", "govern"),
+    ("distinction", "distinct"),
+    ("overpowered", "overpow"),
+    ("temperance", "temper"),
+    ("gloves", "glove"),
+    ("ringing", "ringing"),
+    ("anticipates", "anticip"),
+    ("rights", "right"),
+    ("corrientes", "corrient"),
+    ("laminae", "lamina"),
+    ("scowl", "scowl"),
+    ("woe", "woe"),
+    ("propounded", "propound"),
+    ("manipulation", "manipulaz"),
+    ("simmonds", "simmond"),
+    ("staked", "stake"),
+    ("completel", "completel"),
+    ("fuming", "fume"),
+    ("mistook", "mistook"),
+    ("prov", "prov"),
+    ("exploring", "explor"),
+    ("break", "break"),
+    ("autumnal", "autumn"),
+    ("somewheres", "somewher"),
+    ("course", "cours"),
+    ("inward", "inward"),
+    ("procuring", "procur"),
+    ("erects", "erect"),
+    ("twopence", "twopenc"),
+    ("shove", "shove"),
+    ("golden", "golden"),
+    ("cleanly", "cleanli"),
+    ("linkinwater", "linkinwat"),
+    ("fighting", "fight"),
+    ("inflexibility", "inflex"),
+    ("wrapped", "wrap"),
+    ("intending", "intend"),
+    ("assaults", "assault"),
+    ("depraving", "deprav"),
+    ("mistrusting", "mistrust"),
+    ("sulky", "sulki"),
+    ("pounces", "pounc"),
+    ("atoms", "atom"),
+    ("pasteboard", "pasteboard"),
+    ("mid", "mid"),
+    ("momentum", "momentum"),
+    ("chatted", "chat"),
+    ("augurs", "augur"),
+    ("nucleus", "nucleu"),
+    ("darling", "darl"),
+    ("almost", "almost"),
+    ("churned", "churn"),
+    ("soliloquies", "soliloqui"),
+    ("villains", "villain"),
+    ("left", "left"),
+    ("vibrated", "vibrat"),
+    ("antrum", "antrum"),
+    ("rightly", "rightli"),
+    ("peski", "peski"),
+    ("gropin", "gropin"),
+    ("soar", "soar"),
+    ("lunge", "lung"),
+    ("presidentship", "presidentship"),
+    ("metaphorically", "metaphor"),
+    ("tete", "tete"),
+    ("splendid", "splendid"),
+    ("vacate", "vacat"),
+    ("elation", "elaz"),
+    ("clearing", "clear"),
+    ("embroiders", "embroid"),
+    ("schoolfellow", "schoolfellow"),
+    ("rec", "rec"),
+    ("narrowness", "narrow"),
+    ("weathercock", "weathercock"),
+    ("mamas", "mama"),
+    ("fidelity", "fidel"),
+    ("treatises", "treatis"),
+    ("banters", "banter"),
+    ("lurched", "lurch"),
+    ("personate", "person"),
+    ("akeady", "akeadi"),
+    ("uninterruptedly", "uninterruptedli"),
+    ("freshwater", "freshwat"),
+    ("reproachful", "reproach"),
+    ("fagged", "fag"),
+    ("negress", "negress"),
+    ("victuals", "victual"),
+    ("sallow", "sallow"),
+    ("hypocrisy", "hypocrisi"),
+    ("unfairly", "unfairli"),
+    ("undeservedly", "undeservedli"),
+    ("vade", "vade"),
+    ("diorgeenes", "diorgeen"),
+    ("brooded", "brood"),
+    ("bladders", "bladder"),
+    ("whisperings", "whisper"),
+    ("vendor", "vendor"),
+    ("absurdity", "absurd"),
+    ("closed", "close"),
+    ("thirty", "thirti"),
+    ("quaintly", "quaintli"),
+    ("lagoon", "lagoon"),
+    ("cluskie", "cluski"),
+    ("unbroken", "unbroken"),
+    ("castanet", "castanet"),
+    ("becomes", "becom"),
+    ("irritated", "irrit"),
+    ("cabin", "cabin"),
+    ("quitting", "quit"),
+    ("hazarded", "hazard"),
+    ("guineas", "guinea"),
+    ("guilty", "guilti"),
+    ("peers", "peer"),
+    ("disgusts", "disgust"),
+    ("hurricane", "hurrican"),
+    ("monodonta", "monodonta"),
+    ("bravery", "braveri"),
+    ("marvelling", "marvel"),
+    ("indulging", "indulg"),
+    ("fingered", "finger"),
+    ("alba", "alba"),
+    ("thimble", "thimbl"),
+    ("azingly", "azingli"),
+    ("mildness", "mild"),
+    ("protrude", "protrud"),
+    ("propagated", "propag"),
+    ("petitioning", "petit"),
+    ("dozes", "doze"),
+    ("unsettled", "unsettl"),
+    ("countesses", "countess"),
+    ("metals", "metal"),
+    ("scanned", "scan"),
+    ("trick", "trick"),
+    ("xv", "xv"),
+    ("toucans", "toucan"),
+    ("rambling", "rambl"),
+    ("fresher", "fresher"),
+    ("quibble", "quibbl"),
+    ("irreverent", "irrever"),
+    ("matutina", "matutina"),
+    ("harshness", "harsh"),
+    ("billows", "billow"),
+    ("eke", "ek"),
+    ("swains", "swain"),
+    ("translation", "translaz"),
+    ("disguises", "disguis"),
+    ("tighten", "tighten"),
+    ("tennyson", "tennyson"),
+    ("wedges", "wedg"),
+    ("vithout", "vithout"),
+    ("sarcasm", "sarcasm"),
+    ("anomalies", "anomali"),
+    ("electricity", "electr"),
+    ("issues", "issu"),
+    ("dunes", "dune"),
+    ("yacht", "yacht"),
+    ("hypocritical", "hypocrit"),
+    ("anglified", "anglifi"),
+    ("everything", "everyth"),
+    ("starling", "starl"),
+    ("lachrymatories", "lachrymatori"),
+    ("chamberlain", "chamberlain"),
+    ("beeswaxes", "beeswax"),
+    ("infer", "infer"),
+    ("dangerous", "danger"),
+    ("elbowing", "elbow"),
+    ("usually", "usual"),
+    ("acceded", "ac"),
+    ("thraldom", "thraldom"),
+    ("struck", "struck"),
+    ("homelike", "homelik"),
+    ("disgrace", "disgrac"),
+    ("promptness", "prompt"),
+    ("inflated", "inflat"),
+    ("wrapper", "wrapper"),
+    ("mulatto", "mulatto"),
+    ("overflowed", "overflow"),
+    ("armchairs", "armchair"),
+    ("thrift", "thrift"),
+    ("constellations", "constellaz"),
+    ("sentimentalism", "sentiment"),
+    ("annoying", "annoi"),
+    ("celestial", "celesti"),
+    ("auguries", "auguri"),
+    ("mitten", "mitten"),
+    ("rasper", "rasper"),
+    ("elaters", "elat"),
+    ("rattled", "rattl"),
+    ("monte", "mont"),
+    ("devotion", "devot"),
+    ("declarations", "declaraz"),
+    ("superadded", "superad"),
+    ("conscience", "conscienc"),
+    ("vauxhall", "vauxhal"),
+    ("feasted", "feast"),
+    ("culver", "culver"),
+    ("unthankful", "unthank"),
+    ("cupboard", "cupboard"),
+    ("flipping", "flip"),
+    ("buncombe", "buncomb"),
+    ("cuff", "cuff"),
+    ("contaminated", "contamin"),
+    ("wrangerton", "wrangerton"),
+    ("bertram", "bertram"),
+    ("roundabout", "roundabout"),
+    ("spied", "spi"),
+    ("and", "and"),
+    ("wenches", "wench"),
+    ("braying", "brai"),
+    ("sensitive", "sensit"),
+    ("apologise", "apologis"),
+    ("happens", "happen"),
+    ("papist", "papist"),
+    ("wets", "wet"),
+    ("encircling", "encircl"),
+    ("firstly", "firstli"),
+    ("trimmed", "trim"),
+    ("jingles", "jingl"),
+    ("co", "co"),
+    ("suspect", "suspect"),
+    ("damp", "damp"),
+    ("mindedly", "mindedli"),
+    ("feeds", "feed"),
+    ("despairingly", "despairingli"),
+    ("unkiver", "unkiv"),
+    ("sinister", "sinist"),
+    ("lapsing", "laps"),
+    ("bwoken", "bwoken"),
+    ("bouleversees", "bouleverse"),
+    ("bolas", "bola"),
+    ("winds", "wind"),
+    ("foot", "foot"),
+    ("revel", "revel"),
+    ("cambridge", "cambridg"),
+    ("sack", "sack"),
+    ("ciliae", "cilia"),
+    ("lids", "lid"),
+    ("dogging", "dog"),
+    ("galley", "gallei"),
+    ("bandages", "bandag"),
+    ("oval", "oval"),
+    ("windowed", "window"),
+    ("overtake", "overtak"),
+    ("rivulet", "rivulet"),
+    ("panting", "pant"),
+    ("jews", "jew"),
+    ("wives", "wive"),
+    ("reared", "rear"),
+    ("untried", "untri"),
+    ("contemptible", "contempt"),
+    ("uji", "uji"),
+    ("glowed", "glow"),
+    ("shuffle", "shuffl"),
+    ("vile", "vile"),
+    ("appreciable", "appreci"),
+    ("wars", "war"),
+    ("macquarrie", "macquarri"),
+    ("jerkily", "jerkili"),
+    ("chart", "chart"),
+    ("pavlitch", "pavlitch"),
+    ("palliser", "pallis"),
+    ("insupportable", "insupport"),
+    ("struthio", "struthio"),
+    ("protruding", "protrud"),
+    ("abl", "abl"),
+    ("bigger", "bigger"),
+    ("silks", "silk"),
+    ("spending", "spend"),
+    ("recapitulate", "recapitul"),
+    ("dampest", "dampest"),
+    ("age", "ag"),
+    ("deathlike", "deathlik"),
+    ("southwestern", "southwestern"),
+    ("smote", "smote"),
+    ("enriched", "enrich"),
+    ("accompanies", "accompani"),
+    ("rackings", "rack"),
+    ("beans", "bean"),
+    ("adaptation", "adaptaz"),
+    ("turing", "ture"),
+    ("cowered", "cower"),
+    ("battle", "battl"),
+    ("reminiscent", "reminisc"),
+    ("college", "colleg"),
+    ("eaves", "eav"),
+    ("roberto", "roberto"),
+    ("beneficently", "benefic"),
+    ("spirally", "spiral"),
+    ("compressing", "compress"),
+    ("resents", "resent"),
+    ("rhapsody", "rhapsodi"),
+    ("meeting", "meet"),
+    ("inertness", "inert"),
+    ("fore", "fore"),
+    ("nectar", "nectar"),
+    ("polya", "polya"),
+    ("wrinkled", "wrinkl"),
+    ("limp", "limp"),
+    ("dolt", "dolt"),
+    ("peremptory", "peremptori"),
+    ("greediness", "greedi"),
+    ("invertebrate", "invertebr"),
+    ("recrossing", "recross"),
+    ("comforter", "comfort"),
+    ("monosyllabic", "monosyllab"),
+    ("mastodons", "mastodon"),
+    ("sceptic", "sceptic"),
+    ("pastors", "pastor"),
+    ("raphael", "raphael"),
+    ("reputed", "reput"),
+    ("wretchedness", "wretched"),
+    ("blot", "blot"),
+    ("manages", "manag"),
+    ("waiter", "waiter"),
+    ("effluvium", "effluvium"),
+    ("tantrums", "tantrum"),
+    ("churchyards", "churchyard"),
+    ("hoo", "hoo"),
+    ("froth", "froth"),
+    ("sites", "site"),
+    ("government", "govern"),
+    ("xxiv", "xxiv"),
+    ("tales", "tale"),
+    ("brewed", "brew"),
+    ("hortensio", "hortensio"),
+    ("resists", "resist"),
+    ("opimon", "opimon"),
+    ("branding", "brand"),
+    ("nineteen", "nineteen"),
+    ("familiarly", "familiarli"),
+    ("freckled", "freckl"),
+    ("distinctly", "distinctli"),
+    ("flurry", "flurri"),
+    ("quickens", "quicken"),
+    ("moaned", "moan"),
+    ("lengths", "length"),
+    ("agony", "agoni"),
+    ("grammarian", "grammarian"),
+    ("dried", "dri"),
+    ("temple", "templ"),
+    ("floundering", "flounder"),
+    ("climax", "climax"),
+    ("clover", "clover"),
+    ("chimie", "chimi"),
+    ("growin", "growin"),
+    ("candour", "candour"),
+    ("unhealthy", "unhealthi"),
+    ("amazedly", "amazedli"),
+    ("bell", "bell"),
+    ("giant", "giant"),
+    ("grunts", "grunt"),
+    ("jangled", "jangl"),
+    ("train", "train"),
+    ("shells", "shell"),
+    ("impressively", "impress"),
+    ("nereidae", "nereida"),
+    ("bonfires", "bonfir"),
+    ("hatreds", "hatr"),
+    ("gotten", "gotten"),
+    ("cultivate", "cultiv"),
+    ("officers", "offic"),
+    ("courtesy", "courtesi"),
+    ("paddling", "paddl"),
+    ("wrestle", "wrestl"),
+    ("sleepily", "sleepili"),
+    ("fittings", "fit"),
+    ("seventh", "seventh"),
+    ("simpson", "simpson"),
+    ("cracking", "crack"),
+    ("ghastly", "ghastli"),
+    ("idleness", "idl"),
+    ("decking", "deck"),
+    ("vintems", "vintem"),
+    ("defiles", "defil"),
+    ("wasted", "wast"),
+    ("flitting", "flit"),
+    ("desists", "desist"),
+    ("recovery", "recoveri"),
+    ("knopp", "knopp"),
+    ("detach", "detach"),
+    ("sprouts", "sprout"),
+    ("performers", "perform"),
+    ("wheeze", "wheez"),
+    ("padlocked", "padlock"),
+    ("lithe", "lith"),
+    ("calculations", "calculaz"),
+    ("attempting", "attempt"),
+    ("crancrivora", "crancrivora"),
+    ("reconsider", "reconsid"),
+    ("satraps", "satrap"),
+    ("appendage", "appendag"),
+    ("ruminating", "rumin"),
+    ("disagreement", "disagr"),
+    ("kriegsrath", "kriegsrath"),
+    ("encompassed", "encompass"),
+    ("lobby", "lobbi"),
+    ("ghirlandajo", "ghirlandajo"),
+    ("colossal", "coloss"),
+    ("bury", "buri"),
+    ("confidences", "confid"),
+    ("fruitful", "fruit"),
+    ("jerks", "jerk"),
+    ("bay", "bai"),
+    ("baillie", "bailli"),
+    ("dail", "dail"),
+    ("unmistakably", "unmistak"),
+    ("complacent", "complac"),
+    ("follies", "folli"),
+    ("uneventful", "unev"),
+    ("cordage", "cordag"),
+    ("joints", "joint"),
+    ("deciphered", "deciph"),
+    ("scolded", "scold"),
+    ("quoting", "quot"),
+    ("julius", "juliu"),
+    ("hoard", "hoard"),
+    ("appreciates", "appreci"),
+    ("drummers", "drummer"),
+    ("healths", "health"),
+    ("leant", "leant"),
+    ("grimes", "grime"),
+    ("heartstrings", "heartstr"),
+    ("ribbon", "ribbon"),
+    ("childlike", "childlik"),
+    ("voyage", "voyag"),
+    ("hogs", "hog"),
+    ("obscurely", "obscur"),
+    ("distended", "distend"),
+    ("dutchman", "dutchman"),
+    ("simultaneous", "simultan"),
+    ("bulletin", "bulletin"),
+    ("connects", "connect"),
+    ("figured", "figur"),
+    ("table", "tabl"),
+    ("coerced", "coerc"),
+    ("duplicity", "duplic"),
+    ("bourne", "bourn"),
+    ("malay", "malai"),
+    ("adjourned", "adjourn"),
+    ("chisel", "chisel"),
+    ("postponing", "postpon"),
+    ("provokes", "provok"),
+    ("grunted", "grunt"),
+    ("priceless", "priceless"),
+    ("plays", "plai"),
+    ("twining", "twine"),
+    ("conditions", "condit"),
+    ("smoked", "smoke"),
+    ("hydroporus", "hydroporu"),
+    ("socket", "socket"),
+    ("congenially", "congeni"),
+    ("gulleys", "gullei"),
+    ("azarae", "azara"),
+    ("repeater", "repeat"),
+    ("romish", "romish"),
+    ("swept", "swept"),
+    ("masonry", "masonri"),
+    ("surpasses", "surpass"),
+    ("pleading", "plead"),
+    ("eton", "eton"),
+    ("screeching", "screech"),
+    ("plenty", "plenti"),
+    ("boulder", "boulder"),
+    ("detained", "detain"),
+    ("troubling", "troubl"),
+    ("susceptibility", "suscept"),
+    ("backgammon", "backgammon"),
+    ("witnessing", "wit"),
+    ("hypothesis", "hypothesi"),
+    ("suggestive", "suggest"),
+    ("annexed", "annex"),
+    ("brilliant", "brilliant"),
+    ("cantered", "canter"),
+    ("lecturer", "lectur"),
+    ("paroxysm", "paroxysm"),
+    ("barometer", "baromet"),
+    ("creaking", "creak"),
+    ("jo", "jo"),
+    ("creator", "creat"),
+    ("ensign", "ensign"),
+    ("suffering", "suffer"),
+    ("judge", "judg"),
+    ("enmity", "enmiti"),
+    ("urging", "urg"),
+    ("sciences", "scienc"),
+    ("bestows", "bestow"),
+    ("fanny", "fanni"),
+    ("singeing", "sing"),
+    ("blacks", "black"),
+    ("follerer", "foller"),
+    ("hurries", "hurri"),
+    ("amn", "amn"),
+    ("invectives", "invect"),
+    ("mislead", "mislead"),
+    ("anticipations", "anticipaz"),
+    ("prasant", "prasant"),
+    ("greenness", "green"),
+    ("recollect", "recollect"),
+    ("incivility", "incivil"),
+    ("remunerated", "remuner"),
+    ("wage", "wage"),
+    ("appears", "appear"),
+    ("obscurity", "obscur"),
+    ("pressing", "press"),
+    ("gear", "gear"),
+    ("embarking", "embark"),
+    ("toogather", "toogath"),
+    ("recriminations", "recriminaz"),
+    ("hearty", "hearti"),
+    ("tracts", "tract"),
+    ("timid", "timid"),
+    ("indelible", "indel"),
+    ("naples", "napl"),
+    ("stomachs", "stomach"),
+    ("stuffy", "stuffi"),
+    ("joking", "joke"),
+    ("afsd", "afsd"),
+    ("doze", "doze"),
+    ("misfortunes", "misfortun"),
+    ("developed", "develop"),
+    ("boroughmongering", "boroughmong"),
+    ("cornelia", "cornelia"),
+    ("mucilaginous", "mucilagin"),
+    ("visits", "visit"),
+    ("zelinda", "zelinda"),
+    ("ha", "ha"),
+    ("whit", "whit"),
+    ("elevated", "elev"),
+    ("despair", "despair"),
+    ("curricle", "curricl"),
+    ("township", "township"),
+    ("hunted", "hunt"),
+    ("flamingoes", "flamingo"),
+    ("crystalline", "crystallin"),
+    ("crudest", "crudest"),
+    ("heteromerous", "heteromer"),
+    ("tablecloths", "tablecloth"),
+    ("subterfuges", "subterfug"),
+    ("luckily", "luckili"),
+    ("fuchsia", "fuchsia"),
+    ("foil", "foil"),
+    ("plum", "plum"),
+    ("luis", "lui"),
+    ("omissions", "omiss"),
+    ("manuel", "manuel"),
+    ("calmed", "calm"),
+    ("understood", "understood"),
+    ("corporal", "corpor"),
+    ("flog", "flog"),
+    ("bastard", "bastard"),
+    ("germans", "german"),
+    ("regaling", "regal"),
+    ("passenger", "passeng"),
+    ("quack", "quack"),
+    ("nectarine", "nectarin"),
+    ("entailed", "entail"),
+    ("complaints", "complaint"),
+    ("performer", "perform"),
+    ("spectral", "spectral"),
+    ("chichi", "chichi"),
+    ("idiot", "idiot"),
+    ("edentata", "edentata"),
+    ("phrases", "phrase"),
+    ("avoids", "avoid"),
+    ("wreath", "wreath"),
+    ("hearest", "hearest"),
+    ("spiral", "spiral"),
+    ("lawson", "lawson"),
+    ("injudicious", "injudici"),
+    ("engrosser", "engross"),
+    ("boil", "boil"),
+    ("compares", "compar"),
+    ("palpitating", "palpit"),
+    ("ugliness", "ugli"),
+    ("tarnish", "tarnish"),
+    ("proceedings", "proceed"),
+    ("congregated", "congreg"),
+    ("fist", "fist"),
+    ("more", "more"),
+    ("quail", "quail"),
+    ("rt", "rt"),
+    ("cavy", "cavi"),
+    ("banquets", "banquet"),
+    ("triangularity", "triangular"),
+    ("sed", "sed"),
+    ("divergence", "diverg"),
+    ("assists", "assist"),
+    ("palsy", "palsi"),
+    ("impertinence", "impertin"),
+    ("rearing", "rear"),
+    ("morally", "moral"),
+    ("sounding", "sound"),
+    ("adornments", "adorn"),
+    ("furnarius", "furnariu"),
+    ("disperse", "dispers"),
+    ("katia", "katia"),
+    ("diffident", "diffid"),
+    ("portal", "portal"),
+    ("yseulte", "yseult"),
+    ("wear", "wear"),
+    ("guest", "guest"),
+    ("interested", "interest"),
+    ("joodle", "joodl"),
+    ("vainly", "vainli"),
+    ("committing", "commit"),
+    ("saucily", "saucili"),
+    ("smitten", "smitten"),
+    ("nov", "nov"),
+    ("rectory", "rectori"),
+    ("contrasting", "contrast"),
+    ("tearful", "tear"),
+    ("imperfection", "imperfect"),
+    ("shivered", "shiver"),
+    ("parasitic", "parasit"),
+    ("winnowing", "winnow"),
+    ("mown", "mown"),
+    ("symptomatic", "symptomat"),
+    ("couples", "coupl"),
+    ("bumpers", "bumper"),
+    ("blanche", "blanch"),
+    ("renewal", "renew"),
+    ("insults", "insult"),
+    ("plunged", "plung"),
+    ("tesselated", "tessel"),
+    ("fondling", "fondl"),
+    ("formerly", "formerli"),
+    ("wiry", "wiri"),
+    ("voluta", "voluta"),
+    ("caballed", "cabal"),
+    ("emanating", "eman"),
+    ("tale", "tale"),
+    ("applied", "appli"),
+    ("mysterious", "mysteri"),
+    ("eyes", "eye"),
+    ("eye", "eye"),
+    ("molina", "molina"),
+    ("dictates", "dictat"),
+    ("failures", "failur"),
+    ("hyaena", "hyaena"),
+    ("coxcomb", "coxcomb"),
+    ("consolations", "consolaz"),
+    ("injury", "injuri"),
+    ("fastness", "fast"),
+    ("brighten", "brighten"),
+    ("measuring", "measur"),
+    ("promised", "promis"),
+    ("sploiced", "sploic"),
+    ("isid", "isid"),
+    ("raked", "rake"),
+    ("skumpling", "skumpl"),
+    ("curtseying", "curtsei"),
+    ("patriarchs", "patriarch"),
+    ("attitude", "attitud"),
+    ("invite", "invit"),
+    ("forres", "forr"),
+    ("zone", "zone"),
+    ("loan", "loan"),
+    ("upright", "upright"),
+    ("mouthful", "mouth"),
+    ("fully", "fulli"),
+    ("vienna", "vienna"),
+    ("nudges", "nudg"),
+    ("intrepidity", "intrepid"),
+    ("murphy", "murphi"),
+    ("mention", "mention"),
+    ("outlandish", "outlandish"),
+    ("faculty", "faculti"),
+    ("overdoing", "overdo"),
+    ("predilections", "predilect"),
+    ("untrimmed", "untrim"),
+    ("fitting", "fit"),
+    ("distempered", "distemp"),
+    ("obligation", "obligaz"),
+    ("baths", "bath"),
+    ("stomached", "stomach"),
+    ("biggest", "biggest"),
+    ("quaked", "quak"),
+    ("huge", "huge"),
+    ("questioner", "question"),
+    ("crateriform", "crateriform"),
+    ("sone", "sone"),
+    ("roughing", "rough"),
+    ("repairs", "repair"),
+    ("mimus", "mimu"),
+    ("unclassified", "unclassifi"),
+    ("cormorants", "cormor"),
+    ("hatless", "hatless"),
+    ("mexico", "mexico"),
+    ("introduces", "introduc"),
+    ("allied", "al"),
+    ("district", "district"),
+    ("pestiferous", "pestifer"),
+    ("awfully", "awfulli"),
+    ("suicide", "suicid"),
+    ("endearing", "endear"),
+    ("graves", "grave"),
+    ("pendulum", "pendulum"),
+    ("lombard", "lombard"),
+    ("rusticated", "rustic"),
+    ("rowel", "rowel"),
+    ("comprehends", "comprehend"),
+    ("sprinkle", "sprinkl"),
+    ("irrigate", "irrig"),
+    ("tending", "tend"),
+    ("author", "author"),
+    ("greenwich", "greenwich"),
+    ("globes", "globe"),
+    ("peaks", "peak"),
+    ("clouds", "cloud"),
+    ("lamentable", "lament"),
+    ("symbolic", "symbol"),
+    ("infect", "infect"),
+    ("prediction", "predict"),
+    ("rebuilt", "rebuilt"),
+    ("law", "law"),
+    ("putrefying", "putrefi"),
+    ("gnus", "gnu"),
+    ("trashy", "trashi"),
+    ("alarms", "alarm"),
+    ("unravel", "unravel"),
+    ("content", "content"),
+    ("squeezes", "squeez"),
+    ("lynne", "lynn"),
+    ("utterance", "utter"),
+    ("unprepared", "unprepar"),
+    ("straps", "strap"),
+    ("enduring", "endur"),
+    ("newly", "newli"),
+    ("embassy", "embassi"),
+    ("repelling", "repel"),
+    ("remedies", "remedi"),
+    ("peculation", "peculaz"),
+    ("greyish", "greyish"),
+    ("revelling", "revel"),
+    ("perspired", "perspir"),
+    ("hailing", "hail"),
+    ("postilion", "postilion"),
+    ("civilian", "civilian"),
+    ("excuses", "excus"),
+    ("analysed", "analys"),
+    ("warwick", "warwick"),
+    ("plighting", "plight"),
+    ("furthermore", "furthermor"),
+    ("breach", "breach"),
+    ("mock", "mock"),
+    ("machine", "machin"),
+    ("seat", "seat"),
+    ("seeming", "seem"),
+    ("h", "h"),
+    ("grocer", "grocer"),
+    ("resentments", "resent"),
+    ("alder", "alder"),
+    ("readjusts", "readjust"),
+    ("plead", "plead"),
+    ("mesmerism", "mesmer"),
+    ("punta", "punta"),
+    ("disconsolate", "disconsol"),
+    ("hastens", "hasten"),
+    ("unfrequent", "unfrequ"),
+    ("accommodation", "accommodaz"),
+    ("drier", "drier"),
+    ("continent", "contin"),
+    ("anxiously", "anxious"),
+    ("numerical", "numer"),
+    ("distinctive", "distinct"),
+    ("devon", "devon"),
+    ("cautioned", "caution"),
+    ("tartan", "tartan"),
+    ("steals", "steal"),
+    ("manned", "man"),
+    ("hint", "hint"),
+    ("soc", "soc"),
+    ("fedyaev", "fedyaev"),
+    ("disordered", "disord"),
+    ("touches", "touch"),
+    ("galloped", "gallop"),
+    ("robustly", "robustli"),
+    ("boreas", "borea"),
+    ("cats", "cat"),
+    ("whereat", "whereat"),
+    ("unimaginable", "unimagin"),
+    ("basal", "basal"),
+    ("inspiring", "inspir"),
+    ("call", "call"),
+    ("ninepin", "ninepin"),
+    ("echoings", "echo"),
+    ("peering", "peer"),
+    ("disclosures", "disclosur"),
+    ("schiller", "schiller"),
+    ("engravings", "engrav"),
+    ("monopolising", "monopolis"),
+    ("rocks", "rock"),
+    ("wher", "wher"),
+    ("necked", "neck"),
+    ("quintero", "quintero"),
+    ("acquiescence", "acquiesc"),
+    ("conscientiousness", "conscienti"),
+    ("loomed", "loom"),
+    ("strict", "strict"),
+    ("hilarious", "hilari"),
+    ("climate", "climat"),
+    ("belie", "beli"),
+    ("nonentity", "nonent"),
+    ("demarlii", "demarlii"),
+    ("diaphragm", "diaphragm"),
+    ("molluscous", "mollusc"),
+    ("actions", "action"),
+    ("anywhere", "anywher"),
+    ("booth", "booth"),
+    ("wisiting", "wisit"),
+    ("rehearsed", "rehears"),
+    ("focus", "focu"),
+    ("chuckle", "chuckl"),
+    ("mourn", "mourn"),
+    ("driven", "driven"),
+    ("detest", "detest"),
+    ("hereof", "hereof"),
+    ("rival", "rival"),
+    ("trusty", "trusti"),
+    ("parsimony", "parsimoni"),
+    ("unreasoning", "unreason"),
+    ("muc", "muc"),
+    ("mutual", "mutual"),
+    ("licence", "licenc"),
+    ("guilandina", "guilandina"),
+    ("dark", "dark"),
+    ("nail", "nail"),
+    ("twirled", "twirl"),
+    ("parenthesis", "parenthesi"),
+    ("baked", "bake"),
+    ("pompous", "pompou"),
+    ("throbbing", "throb"),
+    ("marry", "marri"),
+    ("scarcely", "scarc"),
+    ("granny", "granni"),
+    ("unmindful", "unmind"),
+    ("hallowed", "hallow"),
+    ("impersonal", "imperson"),
+    ("disliking", "dislik"),
+    ("clustering", "cluster"),
+    ("stale", "stale"),
+    ("monarch", "monarch"),
+    ("doonstairs", "doonstair"),
+    ("shire", "shire"),
+    ("responsibility", "respons"),
+    ("staking", "stake"),
+    ("recommences", "recomm"),
+    ("suburban", "suburban"),
+    ("pall", "pall"),
+    ("remoteness", "remot"),
+    ("films", "film"),
+    ("wire", "wire"),
+    ("eating", "eat"),
+    ("blameless", "blameless"),
+    ("share", "share"),
+    ("da", "da"),
+    ("frantic", "frantic"),
+    ("directed", "direct"),
+    ("allan", "allan"),
+    ("liliaceous", "liliac"),
+    ("acalypha", "acalypha"),
+    ("miers", "mier"),
+    ("workshops", "workshop"),
+    ("cumnor", "cumnor"),
+    ("overdue", "overdu"),
+    ("boost", "boost"),
+    ("superfluous", "superflu"),
+    ("birthplace", "birthplac"),
+    ("complexioned", "complexion"),
+    ("mandible", "mandibl"),
+    ("swarthy", "swarthi"),
+    ("prussic", "prussic"),
+    ("fungi", "fungi"),
+    ("whity", "whiti"),
+    ("peerage", "peerag"),
+    ("talker", "talker"),
+    ("suppor", "suppor"),
+    ("loneliness", "loneli"),
+    ("stroke", "stroke"),
+    ("cliff", "cliff"),
+    ("quick", "quick"),
+    ("underfoot", "underfoot"),
+    ("ungrown", "ungrown"),
+    ("rivoli", "rivoli"),
+    ("mutters", "mutter"),
+    ("prejudge", "prejudg"),
+    ("agency", "agenc"),
+    ("dominion", "dominion"),
+    ("rid", "rid"),
+    ("delayed", "delai"),
+    ("dyed", "di"),
+    ("flinty", "flinti"),
+    ("lochness", "loch"),
+    ("diametrically", "diametr"),
+    ("practicability", "practic"),
+    ("empower", "empow"),
+    ("locks", "lock"),
+    ("nepean", "nepean"),
+    ("calendars", "calendar"),
+    ("assumes", "assum"),
+    ("pathos", "patho"),
+    ("bor", "bor"),
+    ("rectify", "rectifi"),
+    ("waste", "wast"),
+    ("fled", "fled"),
+    ("swans", "swan"),
+    ("accessories", "accessori"),
+    ("sacred", "sacr"),
+    ("amanuensis", "amanuensi"),
+    ("exotically", "exot"),
+    ("lond", "lond"),
+    ("rug", "rug"),
+    ("gallon", "gallon"),
+    ("frames", "frame"),
+    ("deranged", "derang"),
+    ("grumbled", "grumbl"),
+    ("noddings", "nod"),
+    ("usurer", "usur"),
+    ("appurtenances", "appurten"),
+    ("laid", "laid"),
+    ("gable", "gabl"),
+    ("failing", "fail"),
+    ("swagger", "swagger"),
+    ("suspecting", "suspect"),
+    ("pint", "pint"),
+    ("ral", "ral"),
+    ("theer", "theer"),
+    ("stretches", "stretch"),
+    ("gleesome", "gleesom"),
+];
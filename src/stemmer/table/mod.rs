@@ -0,0 +1,182 @@
+mod generated;
+
+use self::generated::{DISPLACEMENTS, ENTRIES};
+
+// Constant
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+const SEED_MIXER: u64 = 0x9e3779b97f4a7c15;
+
+/// Hash a word with the FNV-1a function mixed with a seed
+///
+/// # Arguments
+///
+/// * `seed` - u32
+/// * `word` - &str
+fn hash(seed: u32, word: &str) -> u64 {
+    let mut hash = FNV_OFFSET ^ (seed as u64).wrapping_mul(SEED_MIXER);
+    for byte in word.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+
+    // mix the high bits into the low bits which are used by the modulo
+    hash ^ (hash >> 29)
+}
+
+/// Get the slot of a word in a table built with the hash and displace algorithm. The word is hashed once
+/// to find its bucket and once more with the displacement of the bucket to find its slot
+///
+/// # Arguments
+///
+/// * `word` - &str
+/// * `displacements` - &[u32]
+/// * `len` - usize
+fn slot(word: &str, displacements: &[u32], len: usize) -> usize {
+    // the modulo is done on the u64 hash so that the slots are the same on 32 bits targets
+    let bucket = (hash(0, word) % displacements.len() as u64) as usize;
+
+    (hash(displacements[bucket], word) % len as u64) as usize
+}
+
+/// Get the precomputed stem of a word of the english vocabulary. The word must be lowercased
+///
+/// # Arguments
+///
+/// * `word` - &str
+pub(crate) fn lookup(word: &str) -> Option<&'static str> {
+    let (key, stem) = ENTRIES[slot(word, &DISPLACEMENTS, ENTRIES.len())];
+
+    match key == word {
+        true => Some(stem),
+        false => None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stemmer::PorterStemmer;
+
+    // the sample vocabulary of the porter stemmer from the snowball-data repository under the BSD license
+    const VOCABULARY: &str = include_str!("../../../tests/data/voc.txt");
+    const GENERATED: &str = include_str!("generated.rs");
+    const BUCKET_SIZE: usize = 4;
+
+    /// Build the displacements and the slots of a minimal perfect hash table
+    ///
+    /// # Arguments
+    ///
+    /// * `words` - &[&'a str]
+    fn build<'a>(words: &[&'a str]) -> (Vec<u32>, Vec<&'a str>) {
+        let len = words.len().div_ceil(BUCKET_SIZE);
+        let mut buckets: Vec<Vec<&str>> = vec![Vec::new(); len];
+        for word in words {
+            buckets[(hash(0, word) % len as u64) as usize].push(word);
+        }
+
+        // the largest buckets are placed first while most of the slots are free
+        let mut order: Vec<usize> = (0..buckets.len()).collect();
+        order.sort_by_key(|idx| std::cmp::Reverse(buckets[*idx].len()));
+
+        let mut displacements = vec![0; buckets.len()];
+        let mut slots: Vec<Option<&str>> = vec![None; words.len()];
+        for idx in order {
+            let bucket = &buckets[idx];
+            let mut seed = 0;
+            loop {
+                let candidates: Vec<usize> = bucket.iter().map(|w| (hash(seed, w) % words.len() as u64) as usize).collect();
+                let mut distinct = candidates.clone();
+                distinct.sort_unstable();
+                distinct.dedup();
+
+                if distinct.len() == bucket.len() && candidates.iter().all(|slot| slots[*slot].is_none()) {
+                    for (word, slot) in bucket.iter().zip(candidates) {
+                        slots[slot] = Some(word);
+                    }
+                    displacements[idx] = seed;
+                    break;
+                }
+
+                seed += 1;
+            }
+        }
+
+        (displacements, slots.into_iter().map(|slot| slot.unwrap_or_default()).collect())
+    }
+
+    /// Get the words of the vocabulary sorted in order to generate the same table whatever the order of the vocabulary.
+    /// The entries which aren't made of letters only are dropped
+    fn vocabulary_words() -> Vec<&'static str> {
+        let mut words: Vec<&str> = VOCABULARY
+            .lines()
+            .filter(|word| !word.is_empty() && word.bytes().all(|b| b.is_ascii_lowercase()))
+            .collect();
+        words.sort_unstable();
+        words.dedup();
+
+        words
+    }
+
+    /// Render the source of the generated table from the vocabulary
+    fn render() -> String {
+        let (displacements, slots) = build(&vocabulary_words());
+
+        let mut out = String::new();
+        out.push_str("// @generated by the generate_table test of src/stemmer/table/mod.rs from tests/data/voc.txt\n");
+        out.push_str("// The vocabulary comes from the snowball-data repository (https://github.com/snowballstem/snowball-data)\n");
+        out.push_str("// under the BSD license\n");
+        out.push_str("// Do not edit this file manually\n\n");
+        out.push_str(&format!("pub(super) static DISPLACEMENTS: [u32; {}] = [\n", displacements.len()));
+        for chunk in displacements.chunks(16) {
+            let line: Vec<String> = chunk.iter().map(u32::to_string).collect();
+            out.push_str(&format!("    {},\n", line.join(", ")));
+        }
+        out.push_str("];\n\n");
+
+        out.push_str(&format!("pub(super) static ENTRIES: [(&str, &str); {}] = [\n", slots.len()));
+        for word in slots {
+            let stem = PorterStemmer::stem_algorithm(word).unwrap();
+            out.push_str(&format!("    ({word:?}, {stem:?}),\n"));
+        }
+        out.push_str("];\n");
+
+        out
+    }
+
+    #[test]
+    fn expect_table_to_match_algorithm() {
+        for &(word, stem) in ENTRIES.iter() {
+            assert_eq!(PorterStemmer::stem_algorithm(word).unwrap(), stem, "{word}");
+            assert_eq!(lookup(word), Some(stem));
+        }
+
+        assert_eq!(lookup("overstemmed"), None);
+        assert_eq!(lookup("Dancing"), None);
+        assert_eq!(lookup(""), None);
+    }
+
+    #[test]
+    fn expect_each_entry_to_be_in_its_slot() {
+        for (idx, &(word, _)) in ENTRIES.iter().enumerate() {
+            assert_eq!(slot(word, &DISPLACEMENTS, ENTRIES.len()), idx, "{word}");
+        }
+    }
+
+    #[test]
+    fn expect_generated_table_to_be_up_to_date() {
+        // a change of the list or of the algorithm must be followed by the generation of the table
+        assert!(
+            render() == GENERATED,
+            "src/stemmer/table/generated.rs is outdated, run: cargo test --features stem-table generate_table -- --ignored"
+        );
+    }
+
+    /// Generate the table from the vocabulary with:
+    /// cargo test --features stem-table generate_table -- --ignored
+    #[test]
+    #[ignore]
+    fn generate_table() {
+        std::fs::write(concat!(env!("CARGO_MANIFEST_DIR"), "/src/stemmer/table/generated.rs"), render()).unwrap();
+    }
+}
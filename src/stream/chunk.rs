@@ -0,0 +1,104 @@
+use crate::error::SimmerError;
+
+// Constant
+// a word longer than this number of chunks is an error in order to bound the memory
+const MAX_WORD_CHUNKS: usize = 4;
+
+/// Accumulate the bytes of an input and split them into chunks of text which end on an ascii whitespace.
+/// A chunk never end inside of a word or inside of a character which means that each chunk can be analyzed on its own.
+/// A word which doesn't fit in MAX_WORD_CHUNKS chunks is reported rather than cut into pieces
+#[derive(Debug, Clone)]
+pub(crate) struct Chunker {
+    pending: Vec<u8>,
+    // byte offset in the input of the first pending byte
+    offset: usize,
+    chunk_size: usize
+}
+
+impl Chunker {
+    /// Create a new Chunker
+    ///
+    /// # Arguments
+    ///
+    /// * `chunk_size` - usize
+    pub(crate) fn new(chunk_size: usize) -> Chunker {
+        let chunk_size = chunk_size.max(1);
+
+        Chunker {
+            pending: Vec::with_capacity(chunk_size),
+            offset: 0,
+            chunk_size
+        }
+    }
+
+    /// Append bytes of the input
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - &[u8]
+    pub(crate) fn push(&mut self, bytes: &[u8]) {
+        self.pending.extend_from_slice(bytes);
+    }
+
+    /// Take the next chunk with the byte offset where it start in the input. None is returned when more bytes
+    /// are needed to end the chunk on a whitespace. Once the input is exhausted the remaining bytes are taken.
+    /// An error is returned with the offset of a word which is longer than MAX_WORD_CHUNKS chunks
+    ///
+    /// # Arguments
+    ///
+    /// * `eof` - bool
+    pub(crate) fn take(&mut self, eof: bool) -> Option<Result<(usize, String), SimmerError>> {
+        if self.pending.is_empty() || (!eof && self.pending.len() < self.chunk_size) {
+            return None;
+        }
+
+        // ascii bytes are never part of a multi bytes character
+        let cut = match self.pending.iter().rposition(u8::is_ascii_whitespace) {
+            _ if eof => self.pending.len(),
+            Some(idx) => idx + 1,
+            None if self.pending.len() >= self.chunk_size * MAX_WORD_CHUNKS => {
+                return Some(Err(SimmerError::WordTooLong(self.offset)));
+            },
+            None => return None
+        };
+
+        let rest = self.pending.split_off(cut);
+        let chunk = std::mem::replace(&mut self.pending, rest);
+        let start = self.offset;
+        self.offset += cut;
+
+        let text = String::from_utf8(chunk)
+            .map_err(|_| SimmerError::Utf8(format!("chunk starting at the byte offset {start}")));
+
+        Some(text.map(|text| (start, text)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_to_end_chunks_on_whitespace() {
+        let mut chunker = Chunker::new(8);
+        chunker.push("His eyes were dan".as_bytes());
+
+        assert_eq!(chunker.take(false), Some(Ok((0, "His eyes were ".to_string()))));
+        assert_eq!(chunker.take(false), None);
+
+        chunker.push(b"cing");
+        assert_eq!(chunker.take(false), None);
+        assert_eq!(chunker.take(true), Some(Ok((14, "dancing".to_string()))));
+        assert_eq!(chunker.take(true), None);
+    }
+
+    #[test]
+    fn expect_to_report_long_words() {
+        let mut chunker = Chunker::new(2);
+        chunker.push("ab ".as_bytes());
+        assert_eq!(chunker.take(false), Some(Ok((0, "ab ".to_string()))));
+
+        chunker.push("aaaaaaé".as_bytes());
+        assert_eq!(chunker.take(false), Some(Err(SimmerError::WordTooLong(3))));
+    }
+}
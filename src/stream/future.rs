@@ -0,0 +1,327 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    task::{Context, Poll}
+};
+use futures_core::Stream;
+#[cfg(feature = "tokio")]
+use tokio::io::{AsyncRead, ReadBuf};
+use crate::{
+    analyzer::Analyzer,
+    error::SimmerError,
+    tokenizer::Token
+};
+use super::{analyze_chunk, Chunker, DEFAULT_CHUNK_SIZE};
+
+// Constant
+// number of reads and chunks processed by a poll before yielding to the other tasks
+const POLL_BUDGET: usize = 32;
+#[cfg(feature = "tokio")]
+const READ_SIZE: usize = 8 * 1024;
+
+/// State of a stream which analyze its input by chunks
+struct Pipeline<'a> {
+    analyzer: &'a Analyzer,
+    chunker: Chunker,
+    tokens: VecDeque<Token>,
+    position: usize,
+    eof: bool,
+    done: bool
+}
+
+impl<'a> Pipeline<'a> {
+    /// Create a new Pipeline
+    ///
+    /// # Arguments
+    ///
+    /// * `analyzer` - &'a Analyzer
+    fn new(analyzer: &'a Analyzer) -> Pipeline<'a> {
+        Pipeline {
+            analyzer,
+            chunker: Chunker::new(DEFAULT_CHUNK_SIZE),
+            tokens: VecDeque::new(),
+            position: 0,
+            eof: false,
+            done: false
+        }
+    }
+
+    /// End the stream with an error
+    ///
+    /// # Arguments
+    ///
+    /// * `err` - SimmerError
+    fn fail(&mut self, err: SimmerError) -> Poll<Option<Result<Token, SimmerError>>> {
+        self.done = true;
+        self.tokens.clear();
+
+        Poll::Ready(Some(Err(err)))
+    }
+
+    /// Poll the next token. The input is only polled when the pending tokens have been consumed which means that
+    /// a slow consumer slow down the reads. poll_input push the bytes which are ready into the chunker and return
+    /// false at the end of the input
+    ///
+    /// # Arguments
+    ///
+    /// * `cx` - &mut Context<'_>
+    /// * `poll_input` - F
+    fn poll_next<F>(&mut self, cx: &mut Context<'_>, mut poll_input: F) -> Poll<Option<Result<Token, SimmerError>>>
+    where
+        F: FnMut(&mut Context<'_>, &mut Chunker) -> Poll<Result<bool, SimmerError>>
+    {
+        let mut budget = POLL_BUDGET;
+
+        loop {
+            if let Some(token) = self.tokens.pop_front() {
+                return Poll::Ready(Some(Ok(token)));
+            }
+
+            if self.done {
+                return Poll::Ready(None);
+            }
+
+            // a large input which is always ready would otherwise starve the other tasks of the executor
+            if budget == 0 {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            budget -= 1;
+
+            let chunk = match self.chunker.take(self.eof) {
+                Some(chunk) => chunk,
+                None if self.eof => {
+                    self.done = true;
+                    continue;
+                },
+                None => match poll_input(cx, &mut self.chunker) {
+                    Poll::Ready(Ok(more)) => {
+                        self.eof = !more;
+                        continue;
+                    },
+                    Poll::Ready(Err(err)) => return self.fail(err),
+                    Poll::Pending => return Poll::Pending
+                }
+            };
+
+            let tokens = chunk.and_then(|(start, text)| analyze_chunk(self.analyzer, start, &text, &mut self.position));
+            match tokens {
+                Ok(tokens) => self.tokens.extend(tokens),
+                Err(err) => return self.fail(err)
+            }
+        }
+    }
+}
+
+/// Stream of the tokens of a stream of text chunks such as the body of a request. The chunks can split a word
+/// or a character anywhere. The offsets and the positions of the tokens refer to the concatenation of the chunks
+pub struct ChunkTokenStream<'a, S> {
+    input: S,
+    pipeline: Pipeline<'a>
+}
+
+impl<S, T> ChunkTokenStream<'static, S>
+where
+    S: Stream<Item = T> + Unpin,
+    T: AsRef<[u8]>
+{
+    /// Create a new ChunkTokenStream which analyze the input the same way as `stem_sentence`
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - S
+    pub fn new(input: S) -> ChunkTokenStream<'static, S> {
+        ChunkTokenStream::with_analyzer(input, crate::sentence_analyzer())
+    }
+}
+
+impl<'a, S, T> ChunkTokenStream<'a, S>
+where
+    S: Stream<Item = T> + Unpin,
+    T: AsRef<[u8]>
+{
+    /// Create a new ChunkTokenStream which analyze the input with an analyzer. The tokenizer must split the words on whitespace
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - S
+    /// * `analyzer` - &'a Analyzer
+    pub fn with_analyzer(input: S, analyzer: &'a Analyzer) -> ChunkTokenStream<'a, S> {
+        ChunkTokenStream {
+            input,
+            pipeline: Pipeline::new(analyzer)
+        }
+    }
+
+    /// Set the number of bytes which are analyzed at once. A word longer than 4 chunks is reported as an error
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - usize
+    pub fn chunk_size(mut self, size: usize) -> Self {
+        self.pipeline.chunker = Chunker::new(size);
+        self
+    }
+}
+
+impl<S, T> Stream for ChunkTokenStream<'_, S>
+where
+    S: Stream<Item = T> + Unpin,
+    T: AsRef<[u8]>
+{
+    type Item = Result<Token, SimmerError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let input = &mut this.input;
+
+        this.pipeline.poll_next(cx, |cx, chunker| match Pin::new(&mut *input).poll_next(cx) {
+            Poll::Ready(Some(chunk)) => {
+                chunker.push(chunk.as_ref());
+                Poll::Ready(Ok(true))
+            },
+            Poll::Ready(None) => Poll::Ready(Ok(false)),
+            Poll::Pending => Poll::Pending
+        })
+    }
+}
+
+/// Stream of the tokens of an AsyncRead such as a file or a socket. The memory used is bounded by the size of a chunk
+/// whatever the size of the input. The offsets and the positions of the tokens refer to the whole input
+#[cfg(feature = "tokio")]
+pub struct AsyncTokenStream<'a, R> {
+    reader: R,
+    buffer: Box<[u8]>,
+    pipeline: Pipeline<'a>
+}
+
+#[cfg(feature = "tokio")]
+impl<R: AsyncRead + Unpin> AsyncTokenStream<'static, R> {
+    /// Create a new AsyncTokenStream which analyze the input the same way as `stem_sentence`
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - R
+    pub fn new(reader: R) -> AsyncTokenStream<'static, R> {
+        AsyncTokenStream::with_analyzer(reader, crate::sentence_analyzer())
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<'a, R: AsyncRead + Unpin> AsyncTokenStream<'a, R> {
+    /// Create a new AsyncTokenStream which analyze the input with an analyzer. The tokenizer must split the words on whitespace
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - R
+    /// * `analyzer` - &'a Analyzer
+    pub fn with_analyzer(reader: R, analyzer: &'a Analyzer) -> AsyncTokenStream<'a, R> {
+        AsyncTokenStream {
+            reader,
+            buffer: vec![0; READ_SIZE].into_boxed_slice(),
+            pipeline: Pipeline::new(analyzer)
+        }
+    }
+
+    /// Set the number of bytes which are analyzed at once. A word longer than 4 chunks is reported as an error
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - usize
+    pub fn chunk_size(mut self, size: usize) -> Self {
+        self.pipeline.chunker = Chunker::new(size);
+        self
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<R: AsyncRead + Unpin> Stream for AsyncTokenStream<'_, R> {
+    type Item = Result<Token, SimmerError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let (reader, buffer) = (&mut this.reader, &mut this.buffer);
+
+        this.pipeline.poll_next(cx, |cx, chunker| {
+            let mut read = ReadBuf::new(buffer);
+            match Pin::new(&mut *reader).poll_read(cx, &mut read) {
+                Poll::Ready(Ok(())) => {
+                    chunker.push(read.filled());
+                    Poll::Ready(Ok(!read.filled().is_empty()))
+                },
+                Poll::Ready(Err(err)) => Poll::Ready(Err(err.into())),
+                Poll::Pending => Poll::Pending
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{stream, StreamExt};
+
+    const TEXT: &str = "His eyes were dancing with humor. Alex doesn't like the café, see https://example.com \
+        or #RustLang!\nThe naïve dancers   were  excellent…";
+
+    /// Split the text into chunks of a number of bytes which can split the characters
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - usize
+    fn chunks(size: usize) -> Vec<Vec<u8>> {
+        TEXT.as_bytes().chunks(size).map(<[u8]>::to_vec).collect()
+    }
+
+    #[tokio::test]
+    async fn expect_same_tokens_as_whole_text_from_chunks() {
+        let expected = crate::stem_sentence_tokens(TEXT).unwrap();
+
+        for size in [1, 3, 7, 4096] {
+            let tokens: Vec<Token> = ChunkTokenStream::new(stream::iter(chunks(size)))
+                .chunk_size(8)
+                .map(Result::unwrap)
+                .collect()
+                .await;
+
+            assert_eq!(tokens, expected, "size {size}");
+        }
+    }
+
+    #[tokio::test]
+    async fn expect_to_yield_on_large_inputs() {
+        // whitespace produce no token which means that the stream must yield by itself
+        let input = stream::iter(vec![" ".repeat(1024); 64]).chain(stream::iter(vec!["dancing".to_string()]));
+        let mut tokens = ChunkTokenStream::new(input).chunk_size(16);
+
+        let waker = futures::task::noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        assert!(Pin::new(&mut tokens).poll_next(&mut cx).is_pending());
+
+        let rest: Vec<Result<Token, SimmerError>> = tokens.collect().await;
+        assert_eq!(rest.len(), 1);
+        assert_eq!(rest[0].as_ref().unwrap().start, 64 * 1024);
+    }
+
+    #[tokio::test]
+    async fn expect_to_report_invalid_utf8() {
+        let input = stream::iter(vec![b"dancing ".to_vec(), vec![0xFF, 0xFE], b" humor".to_vec()]);
+        let results: Vec<Result<Token, SimmerError>> = ChunkTokenStream::new(input).chunk_size(8).collect().await;
+
+        assert_eq!(results.len(), 2);
+        assert!(matches!(results[1], Err(SimmerError::Utf8(_))));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn expect_to_stream_async_reader() {
+        let expected = crate::stem_sentence_tokens(TEXT).unwrap();
+        let tokens: Vec<Token> = AsyncTokenStream::new(TEXT.as_bytes())
+            .chunk_size(5)
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        assert_eq!(tokens, expected);
+    }
+}
@@ -0,0 +1,346 @@
+mod chunk;
+#[cfg(feature = "futures")]
+mod future;
+
+use std::{
+    collections::VecDeque,
+    io::{BufRead, ErrorKind, Write}
+};
+use crate::{
+    analyzer::Analyzer,
+    error::SimmerError,
+    tokenizer::Token
+};
+
+pub(crate) use self::chunk::Chunker;
+#[cfg(feature = "futures")]
+pub use self::future::ChunkTokenStream;
+#[cfg(feature = "tokio")]
+pub use self::future::AsyncTokenStream;
+
+// Constant
+const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Analyze the chunk of an input and shift the offsets and the positions of its tokens so that they refer to the input.
+/// The position is moved after every position consumed by the chunk including the gaps left by the filters
+///
+/// # Arguments
+///
+/// * `analyzer` - &Analyzer
+/// * `start` - usize
+/// * `chunk` - &str
+/// * `position` - &mut usize
+pub(crate) fn analyze_chunk(
+    analyzer: &Analyzer,
+    start: usize,
+    chunk: &str,
+    position: &mut usize
+) -> Result<Vec<Token>, SimmerError> {
+    let (mut tokens, consumed) = analyzer.analyze_chunk(chunk)?;
+
+    for token in tokens.iter_mut() {
+        token.start += start;
+        token.end += start;
+        token.position += *position;
+    }
+    *position += consumed;
+
+    Ok(tokens)
+}
+
+/// Iterator over the tokens of an input read from a BufRead. The input is read and analyzed by chunks which end
+/// on a whitespace which means that the memory used is bounded by the size of a chunk whatever the size of the input.
+/// The offsets and the positions of the tokens refer to the whole input
+pub struct TokenStream<'a, R: BufRead> {
+    reader: R,
+    analyzer: &'a Analyzer,
+    chunker: Chunker,
+    tokens: VecDeque<Token>,
+    position: usize,
+    done: bool
+}
+
+impl<R: BufRead> TokenStream<'static, R> {
+    /// Create a new TokenStream which analyze the input the same way as `stem_sentence`
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - R
+    pub fn new(reader: R) -> TokenStream<'static, R> {
+        TokenStream::with_analyzer(reader, crate::sentence_analyzer())
+    }
+}
+
+impl<'a, R: BufRead> TokenStream<'a, R> {
+    /// Create a new TokenStream which analyze the input with an analyzer. The tokenizer must split the words on whitespace
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - R
+    /// * `analyzer` - &'a Analyzer
+    pub fn with_analyzer(reader: R, analyzer: &'a Analyzer) -> TokenStream<'a, R> {
+        TokenStream {
+            reader,
+            analyzer,
+            chunker: Chunker::new(DEFAULT_CHUNK_SIZE),
+            tokens: VecDeque::new(),
+            position: 0,
+            done: false
+        }
+    }
+
+    /// Set the number of bytes which are analyzed at once. A word longer than 4 chunks is reported as an error
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - usize
+    pub fn chunk_size(mut self, size: usize) -> Self {
+        self.chunker = Chunker::new(size);
+        self
+    }
+
+    /// Read the input until a chunk is ready and analyze it
+    fn fill(&mut self) -> Result<(), SimmerError> {
+        while self.tokens.is_empty() && !self.done {
+            let chunk = match self.chunker.take(false) {
+                Some(chunk) => Some(chunk),
+                None => {
+                    let buffer = match self.reader.fill_buf() {
+                        Ok(buffer) => buffer,
+                        // an interrupted read didn't read anything and can be retried
+                        Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+                        Err(err) => return Err(err.into())
+                    };
+                    let len = buffer.len();
+                    self.chunker.push(buffer);
+                    self.reader.consume(len);
+
+                    if len == 0 {
+                        self.done = true;
+                        self.chunker.take(true)
+                    } else {
+                        None
+                    }
+                }
+            };
+
+            if let Some(chunk) = chunk {
+                let (start, text) = chunk?;
+                let tokens = analyze_chunk(self.analyzer, start, &text, &mut self.position)?;
+                self.tokens.extend(tokens);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: BufRead> Iterator for TokenStream<'_, R> {
+    type Item = Result<Token, SimmerError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Err(err) = self.fill() {
+            // the stream can't recover from a read error or from a token which can't be stemmed
+            self.done = true;
+            self.tokens.clear();
+            return Some(Err(err));
+        }
+
+        self.tokens.pop_front().map(Ok)
+    }
+}
+
+/// Stem an input the same way as `stem_sentence` and write each stem on its own line into the writer.
+/// The input is processed by chunks which means that the memory used doesn't depend on the size of the input.
+/// Return the number of stems written
+///
+/// # Arguments
+///
+/// * `reader` - R
+/// * `writer` - W
+pub fn stem_stream<R: BufRead, W: Write>(reader: R, writer: W) -> Result<usize, SimmerError> {
+    stem_stream_with(crate::sentence_analyzer(), reader, writer)
+}
+
+/// Stem an input with an analyzer and write each term on its own line into the writer. Return the number of terms written
+///
+/// # Arguments
+///
+/// * `analyzer` - &Analyzer
+/// * `reader` - R
+/// * `writer` - W
+pub fn stem_stream_with<R: BufRead, W: Write>(analyzer: &Analyzer, reader: R, mut writer: W) -> Result<usize, SimmerError> {
+    let mut count = 0;
+
+    for token in TokenStream::with_analyzer(reader, analyzer) {
+        let token = token?;
+        if token.skipped {
+            continue;
+        }
+
+        writeln!(writer, "{}", token.term())?;
+        count += 1;
+    }
+
+    writer.flush()?;
+
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufReader, Read};
+    use crate::{
+        filter::{CompoundMode, HyphenFilter, LowercaseFilter, StopWordFilter, StopWordMode},
+        stemmer::PorterStemmer,
+        tokenizer::RuleTokenizer
+    };
+
+    const TEXT: &str = "His eyes were dancing with humor. Alex doesn't like the café, see https://example.com \
+        or #RustLang!\nThe naïve dancers   were  excellent…";
+
+    /// Reader which fail after reading a number of bytes
+    struct FailingReader<'a> {
+        bytes: &'a [u8]
+    }
+
+    impl Read for FailingReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if self.bytes.is_empty() {
+                return Err(std::io::Error::other("disconnected"));
+            }
+
+            let len = self.bytes.len().min(buf.len());
+            buf[..len].copy_from_slice(&self.bytes[..len]);
+            self.bytes = &self.bytes[len..];
+
+            Ok(len)
+        }
+    }
+
+    /// Reader which is interrupted before each read
+    struct InterruptedReader<'a> {
+        bytes: &'a [u8],
+        interrupted: bool
+    }
+
+    impl Read for InterruptedReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            self.interrupted = !self.interrupted;
+            if self.interrupted {
+                return Err(std::io::Error::from(ErrorKind::Interrupted));
+            }
+
+            self.bytes.read(buf)
+        }
+    }
+
+    #[test]
+    fn expect_same_tokens_as_whole_text_across_boundaries() {
+        let expected = crate::stem_sentence_tokens(TEXT).unwrap();
+
+        for (capacity, chunk_size) in [(1, 8), (3, 5), (7, 16), (4096, 4096)] {
+            let reader = BufReader::with_capacity(capacity, TEXT.as_bytes());
+            let tokens: Vec<Token> = TokenStream::new(reader)
+                .chunk_size(chunk_size)
+                .collect::<Result<_, _>>()
+                .unwrap();
+
+            assert_eq!(tokens, expected, "capacity {capacity}, chunk size {chunk_size}");
+        }
+    }
+
+    #[test]
+    fn expect_to_keep_position_gaps_across_boundaries() {
+        let analyzer = Analyzer::builder()
+            .token_filter(LowercaseFilter)
+            .token_filter(StopWordFilter::new(["the", "with"]))
+            .stemmer(PorterStemmer)
+            .build();
+        let text = "eyes dancing with the humor and with the dancers";
+        let expected = analyzer.analyze(text).unwrap();
+
+        // the chunks of 14 bytes end with the stop words which leave gaps at the end of the chunks
+        for chunk_size in [5, 14, 20] {
+            let tokens: Vec<Token> = TokenStream::with_analyzer(text.as_bytes(), &analyzer)
+                .chunk_size(chunk_size)
+                .collect::<Result<_, _>>()
+                .unwrap();
+
+            assert_eq!(tokens, expected, "chunk size {chunk_size}");
+        }
+    }
+
+    #[test]
+    fn expect_to_renumber_positions_across_boundaries() {
+        let analyzer = Analyzer::builder()
+            .tokenizer(RuleTokenizer::new())
+            .token_filter(LowercaseFilter)
+            .token_filter(HyphenFilter::new(CompoundMode::Split))
+            .token_filter(StopWordFilter::new(["the", "with"]).mode(StopWordMode::Remove))
+            .stemmer(PorterStemmer)
+            .build();
+        let text = "eyes dancing with the state-of-the-art humor and with the well-known dancers";
+        let expected = analyzer.analyze(text).unwrap();
+
+        for chunk_size in [5, 14, 20, 33] {
+            let tokens: Vec<Token> = TokenStream::with_analyzer(text.as_bytes(), &analyzer)
+                .chunk_size(chunk_size)
+                .collect::<Result<_, _>>()
+                .unwrap();
+
+            assert_eq!(tokens, expected, "chunk size {chunk_size}");
+        }
+    }
+
+    #[test]
+    fn expect_to_write_stems() {
+        let mut out = Vec::new();
+        let count = stem_stream(BufReader::with_capacity(4, TEXT.as_bytes()), &mut out).unwrap();
+
+        let stems = crate::stem_sentence(TEXT).unwrap();
+        assert_eq!(count, stems.len());
+        assert_eq!(String::from_utf8(out).unwrap(), format!("{}\n", stems.join("\n")));
+    }
+
+    #[test]
+    fn expect_to_report_read_errors() {
+        let reader = BufReader::with_capacity(8, FailingReader { bytes: b"dancing with humor" });
+        let results: Vec<Result<Token, SimmerError>> = TokenStream::new(reader).chunk_size(8).collect();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[1].as_ref().unwrap().term(), "with");
+        assert_eq!(results[2], Err(SimmerError::Io("disconnected".to_string())));
+    }
+
+    #[test]
+    fn expect_to_retry_interrupted_reads() {
+        let reader = BufReader::with_capacity(8, InterruptedReader { bytes: TEXT.as_bytes(), interrupted: false });
+        let tokens: Vec<Token> = TokenStream::new(reader)
+            .chunk_size(8)
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(tokens, crate::stem_sentence_tokens(TEXT).unwrap());
+    }
+
+    #[test]
+    fn expect_to_report_invalid_utf8() {
+        let bytes = [b"dancing ".as_slice(), &[0xFF, 0xFE], b" humor"].concat();
+        let results: Vec<Result<Token, SimmerError>> = TokenStream::new(bytes.as_slice()).collect();
+
+        assert!(matches!(results.last(), Some(Err(SimmerError::Utf8(_)))));
+    }
+
+    #[test]
+    fn expect_to_report_words_longer_than_four_chunks() {
+        let text = format!("dancing {} humor", "a".repeat(40));
+        let reader = BufReader::with_capacity(4, text.as_bytes());
+        let results: Vec<Result<Token, SimmerError>> = TokenStream::new(reader).chunk_size(8).collect();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_ref().unwrap().term(), "danc");
+        assert_eq!(results[1], Err(SimmerError::WordTooLong(8)));
+    }
+}
@@ -0,0 +1,17 @@
+use alloc::string::String;
+use crate::{
+    error::SimmerError,
+    stemmer::{PorterStemmer, Stem}
+};
+
+/// Stemmer which can't stem the word humor and stem the other words with the porter stemmer
+pub(crate) struct FailingStemmer;
+
+impl Stem for FailingStemmer {
+    fn stem(&self, word: &str) -> Result<String, SimmerError> {
+        match word {
+            "humor" => Err(SimmerError::Character),
+            w => PorterStemmer.stem(w)
+        }
+    }
+}
@@ -0,0 +1,23 @@
+mod rule;
+mod special;
+mod token;
+mod unicode;
+mod whitespace;
+
+use alloc::vec::Vec;
+
+pub use self::rule::{CharAction, CharClass, RuleTokenizer};
+pub use self::special::{SpecialAction, SpecialTokenizer};
+pub use self::token::{Token, TokenKind};
+pub use self::unicode::UnicodeTokenizer;
+pub use self::whitespace::WhitespaceTokenizer;
+
+/// Split a text into a list of tokens
+pub trait Tokenizer: Send + Sync {
+    /// Tokenize the text. Each token keep its byte offsets and its position in the text
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - &str
+    fn tokenize(&self, text: &str) -> Vec<Token>;
+}
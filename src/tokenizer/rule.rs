@@ -0,0 +1,289 @@
+use alloc::vec::Vec;
+use super::{Token, Tokenizer};
+
+// Constant
+const DEFAULT_JOINERS: [char; 4] = ['-', '\'', '’', '.'];
+const ASCII_SYMBOLS: [char; 9] = ['$', '+', '<', '=', '>', '^', '`', '|', '~'];
+// ranges of the non ascii punctuation (general punctuation, supplemental punctuation, cjk punctuation...)
+const PUNCTUATION_RANGES: [(char, char); 6] = [
+    ('\u{00A1}', '\u{00BF}'),
+    ('\u{2010}', '\u{2027}'),
+    ('\u{2030}', '\u{205E}'),
+    ('\u{2E00}', '\u{2E7F}'),
+    ('\u{3001}', '\u{3003}'),
+    ('\u{3008}', '\u{301F}')
+];
+// ranges of the combining marks which are part of the previous letter
+const MARK_RANGES: [(char, char); 5] = [
+    ('\u{0300}', '\u{036F}'),
+    ('\u{1AB0}', '\u{1AFF}'),
+    ('\u{1DC0}', '\u{1DFF}'),
+    ('\u{20D0}', '\u{20FF}'),
+    ('\u{FE20}', '\u{FE2F}')
+];
+
+/// Class of a character used by the RuleTokenizer to decide what to do with it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharClass {
+    Whitespace,
+    Letter,
+    Digit,
+    Punctuation,
+    Symbol
+}
+
+/// What to do with a class of character
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharAction {
+    /// The character is part of the token
+    Keep,
+    /// The character split the tokens and is removed
+    Split,
+    /// The character split the tokens and is emitted as its own token
+    Isolate
+}
+
+impl CharClass {
+    /// Get the class of a character
+    ///
+    /// # Arguments
+    ///
+    /// * `c` - char
+    pub fn of(c: char) -> CharClass {
+        let in_ranges = |ranges: &[(char, char)]| ranges.iter().any(|(start, end)| (*start..=*end).contains(&c));
+
+        match c {
+            c if c.is_whitespace() => CharClass::Whitespace,
+            c if c.is_alphabetic() || in_ranges(&MARK_RANGES) => CharClass::Letter,
+            c if c.is_numeric() => CharClass::Digit,
+            c if ASCII_SYMBOLS.contains(&c) => CharClass::Symbol,
+            c if c.is_ascii_punctuation() || in_ranges(&PUNCTUATION_RANGES) => CharClass::Punctuation,
+            _ => CharClass::Symbol
+        }
+    }
+}
+
+/// Tokenizer which split a text depending on a set of rules for the punctuation, the digits and the symbols
+///
+/// By default, punctuation and symbols split the tokens unless the character is a joiner (-, ', ’, .)
+/// between two letters or digits. i.e: end-to-end, don't or 3.14 are kept as a single token.
+/// Acronyms such as U.S. keep their trailing period
+#[derive(Debug, Clone)]
+pub struct RuleTokenizer {
+    punctuation: CharAction,
+    digits: CharAction,
+    symbols: CharAction,
+    joiners: Vec<char>
+}
+
+impl Default for RuleTokenizer {
+    fn default() -> Self {
+        RuleTokenizer {
+            punctuation: CharAction::Split,
+            digits: CharAction::Keep,
+            symbols: CharAction::Split,
+            joiners: DEFAULT_JOINERS.to_vec()
+        }
+    }
+}
+
+/// Token being built by the tokenizer
+struct Pending {
+    start: usize,
+    end: usize
+}
+
+impl RuleTokenizer {
+    /// Create a new RuleTokenizer with the default rules
+    pub fn new() -> RuleTokenizer {
+        RuleTokenizer::default()
+    }
+
+    /// Set the action for the punctuation
+    ///
+    /// # Arguments
+    ///
+    /// * `action` - CharAction
+    pub fn punctuation(mut self, action: CharAction) -> Self {
+        self.punctuation = action;
+        self
+    }
+
+    /// Set the action for the digits
+    ///
+    /// # Arguments
+    ///
+    /// * `action` - CharAction
+    pub fn digits(mut self, action: CharAction) -> Self {
+        self.digits = action;
+        self
+    }
+
+    /// Set the action for the symbols
+    ///
+    /// # Arguments
+    ///
+    /// * `action` - CharAction
+    pub fn symbols(mut self, action: CharAction) -> Self {
+        self.symbols = action;
+        self
+    }
+
+    /// Set the characters which join two letters or digits instead of splitting them
+    ///
+    /// # Arguments
+    ///
+    /// * `joiners` - &[char]
+    pub fn joiners(mut self, joiners: &[char]) -> Self {
+        self.joiners = joiners.to_vec();
+        self
+    }
+
+    /// Get the action for a class of character
+    ///
+    /// # Arguments
+    ///
+    /// * `class` - CharClass
+    fn action(&self, class: CharClass) -> CharAction {
+        match class {
+            CharClass::Whitespace => CharAction::Split,
+            CharClass::Letter => CharAction::Keep,
+            CharClass::Digit => self.digits,
+            CharClass::Punctuation => self.punctuation,
+            CharClass::Symbol => self.symbols
+        }
+    }
+
+    /// Whether a character is kept as part of a token
+    ///
+    /// # Arguments
+    ///
+    /// * `c` - Option<char>
+    fn is_kept(&self, c: Option<char>) -> bool {
+        c.map(|c| self.action(CharClass::of(c)) == CharAction::Keep).unwrap_or(false)
+    }
+
+    /// Whether a token followed by a period is an acronym such as U.S
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - &str
+    fn is_acronym(token: &str) -> bool {
+        token.contains('.') && token.split('.').all(|s| {
+            let mut chars = s.chars();
+            matches!((chars.next(), chars.next()), (Some(c), None) if c.is_alphabetic())
+        })
+    }
+
+    /// Push the pending token to the list of tokens
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - &str
+    /// * `pending` - &mut Option<Pending>
+    /// * `tokens` - &mut Vec<Token>
+    fn flush(text: &str, pending: &mut Option<Pending>, tokens: &mut Vec<Token>) {
+        if let Some(p) = pending.take() {
+            tokens.push(Token::new(&text[p.start..p.end], p.start, p.end, tokens.len()));
+        }
+    }
+}
+
+impl Tokenizer for RuleTokenizer {
+    fn tokenize(&self, text: &str) -> Vec<Token> {
+        let mut tokens = Vec::new();
+        let mut pending: Option<Pending> = None;
+        let mut chars = text.char_indices().peekable();
+        let mut previous: Option<char> = None;
+
+        while let Some((idx, c)) = chars.next() {
+            let end = idx + c.len_utf8();
+            let next = chars.peek().map(|(_, c)| *c);
+
+            match self.action(CharClass::of(c)) {
+                CharAction::Keep => match pending.as_mut() {
+                    Some(p) => p.end = end,
+                    None => pending = Some(Pending { start: idx, end })
+                },
+                CharAction::Split => {
+                    let is_joining = pending.is_some() &&
+                        self.joiners.contains(&c) &&
+                        self.is_kept(previous) &&
+                        self.is_kept(next);
+
+                    match pending.as_mut() {
+                        Some(p) if is_joining => p.end = end,
+                        Some(p) if c == '.' && Self::is_acronym(&text[p.start..p.end]) => {
+                            p.end = end;
+                            Self::flush(text, &mut pending, &mut tokens);
+                        },
+                        _ => Self::flush(text, &mut pending, &mut tokens)
+                    }
+                },
+                CharAction::Isolate => {
+                    Self::flush(text, &mut pending, &mut tokens);
+                    tokens.push(Token::new(&text[idx..end], idx, end, tokens.len()));
+                }
+            }
+
+            previous = Some(c);
+        }
+
+        Self::flush(text, &mut pending, &mut tokens);
+
+        tokens
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(tokens: &[Token]) -> Vec<&str> {
+        tokens.iter().map(|t| t.text.as_str()).collect()
+    }
+
+    #[test]
+    fn expect_to_classify_characters() {
+        assert_eq!(CharClass::of('é'), CharClass::Letter);
+        assert_eq!(CharClass::of('\u{0301}'), CharClass::Letter);
+        assert_eq!(CharClass::of('7'), CharClass::Digit);
+        assert_eq!(CharClass::of(','), CharClass::Punctuation);
+        assert_eq!(CharClass::of('«'), CharClass::Punctuation);
+        assert_eq!(CharClass::of('$'), CharClass::Symbol);
+        assert_eq!(CharClass::of('€'), CharClass::Symbol);
+        assert_eq!(CharClass::of('\u{00A0}'), CharClass::Whitespace);
+    }
+
+    #[test]
+    fn expect_to_split_on_punctuation() {
+        let tokens = RuleTokenizer::new().tokenize("hello,world! (end-to-end) U.S. don't pay 3.14$.");
+
+        assert_eq!(texts(&tokens), vec!["hello", "world", "end-to-end", "U.S.", "don't", "pay", "3.14"]);
+        assert_eq!(tokens[6].position, 6);
+    }
+
+    #[test]
+    fn expect_to_keep_byte_offsets() {
+        let text = "«café», naïve";
+        let tokens = RuleTokenizer::new().tokenize(text);
+
+        for token in &tokens {
+            assert_eq!(&text[token.start..token.end], token.text);
+        }
+        assert_eq!(texts(&tokens), vec!["café", "naïve"]);
+    }
+
+    #[test]
+    fn expect_to_apply_custom_rules() {
+        let tokenizer = RuleTokenizer::new()
+            .punctuation(CharAction::Isolate)
+            .digits(CharAction::Split)
+            .symbols(CharAction::Keep)
+            .joiners(&[]);
+
+        let tokens = tokenizer.tokenize("a-b c42 $5+x");
+
+        assert_eq!(texts(&tokens), vec!["a", "-", "b", "c", "$", "+x"]);
+    }
+}
@@ -0,0 +1,273 @@
+use alloc::{collections::BTreeMap, format, string::{String, ToString}, vec::Vec};
+use super::{Token, TokenKind, Tokenizer, UnicodeTokenizer};
+
+// Constant
+const URL_SCHEMES: [&str; 3] = ["http://", "https://", "ftp://"];
+const LEADING_PUNCTUATION: [char; 11] = ['(', '[', '{', '<', '"', '\'', '¿', '¡', '«', '“', '‘'];
+const TRAILING_PUNCTUATION: [char; 14] = ['.', ',', ';', ':', '!', '?', ')', ']', '}', '>', '"', '\'', '»', '”'];
+
+/// What to do with a special token
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpecialAction {
+    /// Emit the token verbatim
+    Keep,
+    /// Remove the token
+    Drop,
+    /// Emit the normalized token:
+    ///
+    /// - Url: the scheme and the host are lowercased
+    /// - Email: the domain is lowercased
+    /// - Hashtag & Mention: the token is lowercased
+    /// - Number: the thousands separators are removed
+    Normalize
+}
+
+/// Tokenizer which recognize the numbers, the urls, the emails, the hashtags and the mentions as single tokens.
+/// The rest of the text is tokenized with the inner tokenizer. Special tokens are not processed by the filters and the stemmer
+#[derive(Debug, Clone)]
+pub struct SpecialTokenizer<T: Tokenizer = UnicodeTokenizer> {
+    inner: T,
+    actions: BTreeMap<TokenKind, SpecialAction>
+}
+
+impl Default for SpecialTokenizer<UnicodeTokenizer> {
+    fn default() -> Self {
+        SpecialTokenizer::new(UnicodeTokenizer)
+    }
+}
+
+impl<T: Tokenizer> SpecialTokenizer<T> {
+    /// Create a new SpecialTokenizer which keep every special tokens
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - T
+    pub fn new(inner: T) -> SpecialTokenizer<T> {
+        SpecialTokenizer {
+            inner,
+            actions: BTreeMap::new()
+        }
+    }
+
+    /// Set the action for a type of token. The action of the TokenKind::Word is ignored
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - TokenKind
+    /// * `action` - SpecialAction
+    pub fn action(mut self, kind: TokenKind, action: SpecialAction) -> Self {
+        self.actions.insert(kind, action);
+        self
+    }
+
+    /// Get the type of a special token if the text is one
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - &str
+    pub fn classify(text: &str) -> Option<TokenKind> {
+        match text {
+            t if is_url(t) => Some(TokenKind::Url),
+            t if is_email(t) => Some(TokenKind::Email),
+            t if t.starts_with('@') && is_tag(&t[1..]) => Some(TokenKind::Mention),
+            t if t.starts_with('#') && is_tag(&t[1..]) && t.chars().any(char::is_alphabetic) => Some(TokenKind::Hashtag),
+            t if is_number(t) => Some(TokenKind::Number),
+            _ => None
+        }
+    }
+}
+
+/// Whether the text is an url such as https://example.com or www.example.com
+///
+/// # Arguments
+///
+/// * `text` - &str
+fn is_url(text: &str) -> bool {
+    let lower = text.to_ascii_lowercase();
+    let scheme = URL_SCHEMES.iter().find(|scheme| lower.starts_with(*scheme));
+
+    match scheme {
+        Some(scheme) => text.len() > scheme.len(),
+        None => lower.starts_with("www.") && text[4..].contains('.')
+    }
+}
+
+/// Whether the text is an email such as user@example.com
+///
+/// # Arguments
+///
+/// * `text` - &str
+fn is_email(text: &str) -> bool {
+    let Some((local, domain)) = text.split_once('@') else {
+        return false;
+    };
+
+    let valid_local = !local.is_empty() && local.chars().all(|c| c.is_alphanumeric() || "._%+-".contains(c));
+    let labels: Vec<&str> = domain.split('.').collect();
+    let valid_domain = labels.len() > 1 && labels
+        .iter()
+        .all(|l| !l.is_empty() && l.chars().all(|c| c.is_alphanumeric() || c == '-'));
+    let valid_tld = labels.last().map(|tld| tld.chars().all(char::is_alphabetic)).unwrap_or(false);
+
+    valid_local && valid_domain && valid_tld
+}
+
+/// Whether the text is the name of a hashtag or a mention
+///
+/// # Arguments
+///
+/// * `text` - &str
+fn is_tag(text: &str) -> bool {
+    !text.is_empty() && text.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Whether the text is a number such as -3.14 or 1,000
+///
+/// # Arguments
+///
+/// * `text` - &str
+fn is_number(text: &str) -> bool {
+    let digits = text.strip_prefix(['+', '-']).unwrap_or(text);
+
+    !digits.is_empty() && digits
+        .split(['.', ','])
+        .all(|group| !group.is_empty() && group.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Normalize a special token
+///
+/// # Arguments
+///
+/// * `text` - &str
+/// * `kind` - TokenKind
+fn normalize(text: &str, kind: TokenKind) -> String {
+    match kind {
+        TokenKind::Url => {
+            let host_start = text.find("://").map(|idx| idx + 3).unwrap_or(0);
+            let host_end = text[host_start..]
+                .find(['/', '?', '#'])
+                .map(|idx| idx + host_start)
+                .unwrap_or(text.len());
+
+            format!("{}{}", text[..host_end].to_lowercase(), &text[host_end..])
+        },
+        TokenKind::Email => match text.split_once('@') {
+            Some((local, domain)) => format!("{local}@{}", domain.to_lowercase()),
+            None => text.to_string()
+        },
+        TokenKind::Hashtag | TokenKind::Mention => text.to_lowercase(),
+        TokenKind::Number => text.replace(',', ""),
+        TokenKind::Word => text.to_string()
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for SpecialTokenizer<T> {
+    fn tokenize(&self, text: &str) -> Vec<Token> {
+        let mut tokens: Vec<Token> = Vec::new();
+        let mut chunk_start = None;
+
+        // process each chunk of text separated by whitespace
+        let process = |start: usize, end: usize, tokens: &mut Vec<Token>| {
+            let chunk = &text[start..end];
+            let trimmed = chunk
+                .trim_start_matches(LEADING_PUNCTUATION)
+                .trim_end_matches(TRAILING_PUNCTUATION);
+            let core_start = start + chunk.len() - chunk.trim_start_matches(LEADING_PUNCTUATION).len();
+
+            if let Some(kind) = Self::classify(trimmed) {
+                let action = self.actions.get(&kind).copied().unwrap_or(SpecialAction::Keep);
+                let token_text = match action {
+                    SpecialAction::Keep => trimmed.to_string(),
+                    SpecialAction::Normalize => normalize(trimmed, kind),
+                    SpecialAction::Drop => return
+                };
+
+                let mut token = Token::new(trimmed, core_start, core_start + trimmed.len(), tokens.len());
+                token.text = token_text;
+                token.kind = kind;
+                tokens.push(token);
+
+                return;
+            }
+
+            for mut token in self.inner.tokenize(chunk) {
+                token.start += start;
+                token.end += start;
+                token.position = tokens.len();
+                tokens.push(token);
+            }
+        };
+
+        for (idx, c) in text.char_indices() {
+            match (c.is_whitespace(), chunk_start) {
+                (true, Some(start)) => {
+                    process(start, idx, &mut tokens);
+                    chunk_start = None;
+                },
+                (false, None) => chunk_start = Some(idx),
+                _ => {}
+            }
+        }
+
+        if let Some(start) = chunk_start {
+            process(start, text.len(), &mut tokens);
+        }
+
+        tokens
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(tokens: &[Token]) -> Vec<(&str, TokenKind)> {
+        tokens.iter().map(|t| (t.text.as_str(), t.kind)).collect()
+    }
+
+    #[test]
+    fn expect_to_classify_special_tokens() {
+        let text = "See https://Example.com/Items, (user@Example.com) @alice #RustLang costs 3.14 or 1,000.";
+        let tokens = SpecialTokenizer::default().tokenize(text);
+
+        assert_eq!(kinds(&tokens), vec![
+            ("See", TokenKind::Word),
+            ("https://Example.com/Items", TokenKind::Url),
+            ("user@Example.com", TokenKind::Email),
+            ("@alice", TokenKind::Mention),
+            ("#RustLang", TokenKind::Hashtag),
+            ("costs", TokenKind::Word),
+            ("3.14", TokenKind::Number),
+            ("or", TokenKind::Word),
+            ("1,000", TokenKind::Number)
+        ]);
+
+        for token in &tokens {
+            assert_eq!(&text[token.start..token.end], token.surface);
+        }
+        assert_eq!(tokens[8].position, 8);
+    }
+
+    #[test]
+    fn expect_to_apply_actions() {
+        let tokenizer = SpecialTokenizer::default()
+            .action(TokenKind::Url, SpecialAction::Normalize)
+            .action(TokenKind::Email, SpecialAction::Normalize)
+            .action(TokenKind::Hashtag, SpecialAction::Drop)
+            .action(TokenKind::Number, SpecialAction::Normalize);
+
+        let tokens = tokenizer.tokenize("HTTPS://WWW.Example.com/Items user@Example.COM #rust 1,000 dancing");
+        let texts: Vec<&str> = tokens.iter().map(|t| t.text.as_str()).collect();
+
+        assert_eq!(texts, vec!["https://www.example.com/Items", "user@example.com", "1000", "dancing"]);
+        assert_eq!(tokens[3].position, 3);
+    }
+
+    #[test]
+    fn expect_to_not_classify_words() {
+        assert_eq!(SpecialTokenizer::<UnicodeTokenizer>::classify("hello,world"), None);
+        assert_eq!(SpecialTokenizer::<UnicodeTokenizer>::classify("@"), None);
+        assert_eq!(SpecialTokenizer::<UnicodeTokenizer>::classify("#1"), None);
+        assert_eq!(SpecialTokenizer::<UnicodeTokenizer>::classify("www.example.com"), Some(TokenKind::Url));
+    }
+}
@@ -0,0 +1,102 @@
+use alloc::string::{String, ToString};
+use crate::stemmer::restore_case;
+
+/// Type of a token
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TokenKind {
+    #[default]
+    Word,
+    Number,
+    Url,
+    Email,
+    Hashtag,
+    Mention
+}
+
+/// A token extracted from a text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    /// Text of the token as it appears in the input
+    pub surface: String,
+    /// Normalized text of the token, updated by each filter of an analyzer
+    pub text: String,
+    /// Stem of the token, set once a stemmer has processed the token
+    pub stem: Option<String>,
+    /// Byte offset where the token start in the input
+    pub start: usize,
+    /// Byte offset where the token end in the input
+    pub end: usize,
+    /// Position of the token in the token stream
+    pub position: usize,
+    /// Type of the token. Only words are processed by the filters and the stemmer
+    pub kind: TokenKind,
+    /// Whether the token is protected from the stemmer
+    pub keyword: bool,
+    /// Whether the token has been skipped by a filter and won't be stemmed
+    pub skipped: bool
+}
+
+impl Token {
+    /// Create a new Token
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - &str
+    /// * `start` - usize
+    /// * `end` - usize
+    /// * `position` - usize
+    pub fn new(text: &str, start: usize, end: usize, position: usize) -> Token {
+        Token {
+            surface: text.to_string(),
+            text: text.to_string(),
+            stem: None,
+            start,
+            end,
+            position,
+            kind: TokenKind::Word,
+            keyword: false,
+            skipped: false
+        }
+    }
+
+    /// Whether the token is a word
+    pub fn is_word(&self) -> bool {
+        self.kind == TokenKind::Word
+    }
+
+    /// Get the term of the token which is the stem if any or the text otherwise
+    pub fn term(&self) -> &str {
+        match &self.stem {
+            Some(stem) => stem,
+            None => &self.text
+        }
+    }
+
+    /// Get the term of the token with the casing of the surface. i.e: Excellent -> Excel
+    pub fn cased_term(&self) -> String {
+        restore_case(&self.surface, self.term())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_term_to_fallback_to_text() {
+        let mut token = Token::new("dancing", 0, 7, 0);
+        assert_eq!(token.term(), "dancing");
+
+        token.stem = Some("danc".to_string());
+        assert_eq!(token.term(), "danc");
+    }
+
+    #[test]
+    fn expect_cased_term_to_follow_surface() {
+        let mut token = Token::new("Dancing", 0, 7, 0);
+        token.text = "dancing".to_string();
+        token.stem = Some("danc".to_string());
+
+        assert_eq!(token.cased_term(), "Danc");
+    }
+}
@@ -0,0 +1,54 @@
+use alloc::vec::Vec;
+use unicode_segmentation::UnicodeSegmentation;
+use super::{Token, Tokenizer};
+
+/// Split a text into words by following the word boundaries rules of the Unicode Text Segmentation (UAX #29)
+/// Only the segments which contains a letter or a digit are emitted. Whitespace such as non breaking spaces
+/// or ideographic spaces split the words while apostrophes, MidNum and ExtendNumLet characters are kept
+/// i.e: can't, 3.14 or snake_case are single words
+#[derive(Debug, Default, Clone, Copy)]
+pub struct UnicodeTokenizer;
+
+impl Tokenizer for UnicodeTokenizer {
+    fn tokenize(&self, text: &str) -> Vec<Token> {
+        text.unicode_word_indices()
+            .enumerate()
+            .map(|(position, (start, word))| Token::new(word, start, start + word.len(), position))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(tokens: &[Token]) -> Vec<&str> {
+        tokens.iter().map(|t| t.text.as_str()).collect()
+    }
+
+    #[test]
+    fn expect_to_split_on_unicode_spaces() {
+        let tokens = UnicodeTokenizer.tokenize("hello\u{00A0}world\u{3000}again,done");
+
+        assert_eq!(texts(&tokens), vec!["hello", "world", "again", "done"]);
+        assert_eq!(tokens[3].position, 3);
+    }
+
+    #[test]
+    fn expect_to_keep_apostrophes_numbers_and_connectors() {
+        let tokens = UnicodeTokenizer.tokenize("I can't pay 3,141.59 for snake_case");
+
+        assert_eq!(texts(&tokens), vec!["I", "can't", "pay", "3,141.59", "for", "snake_case"]);
+    }
+
+    #[test]
+    fn expect_to_keep_zero_width_joiner_and_offsets() {
+        let text = "le café\u{200D}s 東京";
+        let tokens = UnicodeTokenizer.tokenize(text);
+
+        for token in &tokens {
+            assert_eq!(&text[token.start..token.end], token.text);
+        }
+        assert_eq!(tokens[1].text, "café\u{200D}s");
+    }
+}
@@ -0,0 +1,55 @@
+use alloc::vec::Vec;
+use super::{Token, Tokenizer};
+
+/// Split a text on whitespace the same way as `str::split_whitespace`
+#[derive(Debug, Default, Clone, Copy)]
+pub struct WhitespaceTokenizer;
+
+impl Tokenizer for WhitespaceTokenizer {
+    fn tokenize(&self, text: &str) -> Vec<Token> {
+        let mut tokens = Vec::new();
+        let mut start = None;
+
+        for (idx, c) in text.char_indices() {
+            match (c.is_whitespace(), start) {
+                (true, Some(s)) => {
+                    tokens.push(Token::new(&text[s..idx], s, idx, tokens.len()));
+                    start = None;
+                },
+                (false, None) => start = Some(idx),
+                _ => {}
+            }
+        }
+
+        // push the remaining token
+        if let Some(s) = start {
+            tokens.push(Token::new(&text[s..], s, text.len(), tokens.len()));
+        }
+
+        tokens
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_to_split_on_whitespace() {
+        let tokens = WhitespaceTokenizer.tokenize("  His eyes\twere\n dancing ");
+        let texts: Vec<&str> = tokens.iter().map(|t| t.text.as_str()).collect();
+
+        assert_eq!(texts, vec!["His", "eyes", "were", "dancing"]);
+    }
+
+    #[test]
+    fn expect_to_keep_offsets_and_positions() {
+        let text = "naïve café";
+        let tokens = WhitespaceTokenizer.tokenize(text);
+
+        assert_eq!(tokens[1].start, 7);
+        assert_eq!(tokens[1].end, text.len());
+        assert_eq!(tokens[1].position, 1);
+        assert_eq!(&text[tokens[0].start..tokens[0].end], "naïve");
+    }
+}
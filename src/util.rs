@@ -1,3 +1,4 @@
+use alloc::{string::String, vec::Vec};
 pub trait AsciiUtil {
     /// Remove ascii punctuation from a word
     fn remove_ascii_punctuation(&self) -> String;
@@ -0,0 +1,18 @@
+// The vocabulary comes from the snowball-data repository (https://github.com/snowballstem/snowball-data) under the BSD license.
+// The output is the stem of each word of the vocabulary computed by this crate, it guards the stemmer against regressions
+const VOCABULARY: &str = include_str!("data/voc.txt");
+const OUTPUT: &str = include_str!("data/output.txt");
+
+#[test]
+fn expect_vocabulary_to_match_output() {
+    let mut mismatches = Vec::new();
+    for (word, expected) in VOCABULARY.lines().zip(OUTPUT.lines()) {
+        let stem = simmer::stem(word).unwrap();
+        if stem != expected {
+            mismatches.push(format!("{word}: expected {expected}, got {stem}"));
+        }
+    }
+
+    assert_eq!(VOCABULARY.lines().count(), OUTPUT.lines().count());
+    assert!(mismatches.is_empty(), "{} mismatches:\n{}", mismatches.len(), mismatches[..mismatches.len().min(20)].join("\n"));
+}